@@ -0,0 +1,197 @@
+//! # Build Script
+//!
+//! Confere `src/syscall/numbers.rs` contra o manifesto de ABI do kernel.
+//!
+//! O manifesto (`abi.toml`) é gerado pelo Forge e lido de
+//! `$REDPOWDER_ABI_MANIFEST` ou, por padrão, de `../../forge/abi.toml`:
+//!
+//! ```toml
+//! version = 1
+//!
+//! [syscalls]
+//! exit = 0x01
+//! spawn = 0x02
+//! ```
+//!
+//! Cada chave de `[syscalls]` corresponde a `SYS_<CHAVE>`. O build falha se
+//! um número ou `ABI_VERSION` divergir, ou se duas constantes `SYS_*`
+//! usarem o mesmo número. Sem manifesto, só a checagem local roda.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const NUMBERS: &str = "src/syscall/numbers.rs";
+const DEFAULT_MANIFEST: &str = "../../forge/abi.toml";
+const MANIFEST_ENV: &str = "REDPOWDER_ABI_MANIFEST";
+
+/// Versão e números (`SYS_*` → número) de um lado da ABI.
+struct Abi {
+    version: Option<u32>,
+    syscalls: BTreeMap<String, usize>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", NUMBERS);
+    println!("cargo:rerun-if-env-changed={}", MANIFEST_ENV);
+
+    let source = fs::read_to_string(NUMBERS).expect("ler numbers.rs");
+    let sdk = parse_numbers(&source);
+    let mut errors = check_duplicates(&sdk);
+
+    let explicit = env::var_os(MANIFEST_ENV).map(PathBuf::from);
+    let path = explicit
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_MANIFEST));
+    if path.exists() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(text) => {
+            let kernel = parse_manifest(&text)
+                .unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+            errors.extend(compare(&sdk, &kernel));
+        }
+        Err(err) if explicit.is_some() => {
+            panic!("{} = {}: {}", MANIFEST_ENV, path.display(), err);
+        }
+        Err(_) => println!(
+            "cargo:warning=abi.toml não encontrado ({}); números de syscall não conferidos com o kernel",
+            path.display()
+        ),
+    }
+
+    if !errors.is_empty() {
+        panic!("ABI de syscalls divergente:\n  {}", errors.join("\n  "));
+    }
+}
+
+// =============================================================================
+// PARSING
+// =============================================================================
+
+/// Extrai `pub const SYS_*: usize` e `pub const ABI_VERSION: u32`.
+fn parse_numbers(source: &str) -> Abi {
+    let mut abi = Abi {
+        version: None,
+        syscalls: BTreeMap::new(),
+    };
+
+    for line in source.lines() {
+        let Some(decl) = line.trim().strip_prefix("pub const ") else {
+            continue;
+        };
+        let Some((name, rest)) = decl.split_once(':') else {
+            continue;
+        };
+        let Some((_, value)) = rest.split_once('=') else {
+            continue;
+        };
+        let Some(value) = parse_int(value.trim().trim_end_matches(';')) else {
+            continue;
+        };
+
+        if name == "ABI_VERSION" {
+            abi.version = Some(value as u32);
+        } else if name.starts_with("SYS_") {
+            abi.syscalls.insert(name.to_string(), value);
+        }
+    }
+    abi
+}
+
+/// Lê o subconjunto de TOML usado pelo manifesto: `version` e `[syscalls]`.
+fn parse_manifest(text: &str) -> Result<Abi, String> {
+    let mut abi = Abi {
+        version: None,
+        syscalls: BTreeMap::new(),
+    };
+    let mut section = String::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim().to_string();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("linha {}: esperado `chave = valor`", index + 1))?;
+        let key = key.trim();
+        let value = parse_int(value.trim())
+            .ok_or_else(|| format!("linha {}: valor inválido para `{}`", index + 1, key))?;
+
+        match section.as_str() {
+            "" if key == "version" => abi.version = Some(value as u32),
+            "syscalls" => {
+                let name = format!("SYS_{}", key.to_ascii_uppercase());
+                if abi.syscalls.insert(name, value).is_some() {
+                    return Err(format!("linha {}: `{}` repetido", index + 1, key));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(abi)
+}
+
+/// Inteiro decimal ou `0x` hexadecimal, com `_` opcional.
+fn parse_int(text: &str) -> Option<usize> {
+    let text = text.replace('_', "");
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+// =============================================================================
+// CHECAGENS
+// =============================================================================
+
+/// Duas constantes com o mesmo número (ex.: `SYS_SEEK` e um `SYS_LSEEK`).
+fn check_duplicates(sdk: &Abi) -> Vec<String> {
+    let mut seen: BTreeMap<usize, &str> = BTreeMap::new();
+    let mut errors = Vec::new();
+    for (name, &num) in &sdk.syscalls {
+        if let Some(other) = seen.insert(num, name) {
+            errors.push(format!(
+                "{} e {} usam o mesmo número {:#04x}",
+                other, name, num
+            ));
+        }
+    }
+    errors
+}
+
+/// Diferenças entre `numbers.rs` e o manifesto do kernel.
+///
+/// Syscalls só do kernel são aceitas (a SDK não precisa cobrir todas); só
+/// da SDK geram aviso, pois o kernel as rejeitaria com `InvalidSyscall`.
+fn compare(sdk: &Abi, kernel: &Abi) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if sdk.version != kernel.version {
+        errors.push(format!(
+            "ABI_VERSION = {:?}, kernel = {:?}",
+            sdk.version, kernel.version
+        ));
+    }
+
+    for (name, &num) in &sdk.syscalls {
+        match kernel.syscalls.get(name) {
+            Some(&expected) if expected != num => errors.push(format!(
+                "{} = {:#04x}, kernel = {:#04x}",
+                name, num, expected
+            )),
+            Some(_) => {}
+            None => println!("cargo:warning={} não existe no manifesto do kernel", name),
+        }
+    }
+    errors
+}
@@ -0,0 +1,107 @@
+//! Cria, escreve, relê e remove muitos arquivos, conferindo o conteúdo.
+
+#![no_std]
+#![no_main]
+
+use core::fmt::Write;
+
+use redpowder::fs::{self, File};
+use redpowder::mem::heap::SyscallAllocator;
+use redpowder::prelude::*;
+use redpowder::syscall::{SysError, SysResult};
+use redpowder::time::Stopwatch;
+
+#[global_allocator]
+static ALLOCATOR: SyscallAllocator = SyscallAllocator;
+
+/// Diretório de trabalho (removido ao final).
+const DIR: &str = "/tmp/fs-stress";
+const FILES: usize = 256;
+const FILE_SIZE: usize = 4096;
+
+/// Caminho `DIR/NNNN` num buffer fixo.
+struct PathBuf {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl PathBuf {
+    fn new(index: usize) -> Self {
+        let mut path = Self {
+            buf: [0; 64],
+            len: 0,
+        };
+        let _ = write!(path, "{}/{:04}", DIR, index);
+        path
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for PathBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn fill(buf: &mut [u8], index: usize) {
+    for (i, b) in buf.iter_mut().enumerate() {
+        *b = (i ^ index) as u8;
+    }
+}
+
+fn stress() -> SysResult<()> {
+    if !fs::is_dir(DIR) {
+        fs::ops::mkdir(DIR, 0o755)?;
+    }
+
+    let mut data = [0u8; FILE_SIZE];
+    for index in 0..FILES {
+        fill(&mut data, index);
+        File::create(PathBuf::new(index).as_str())?.write_all(&data)?;
+    }
+
+    let mut expected = [0u8; FILE_SIZE];
+    for index in 0..FILES {
+        fill(&mut expected, index);
+        File::open(PathBuf::new(index).as_str())?.read_exact(&mut data)?;
+        if data != expected {
+            println!("fs-stress: conteúdo divergente em {}", index);
+            return Err(SysError::IoError);
+        }
+    }
+
+    for index in 0..FILES {
+        fs::ops::remove(PathBuf::new(index).as_str())?;
+    }
+    fs::ops::rmdir(DIR)
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    redpowder::runtime::init();
+    let sw = Stopwatch::start();
+    match stress() {
+        Ok(()) => {
+            println!(
+                "fs-stress: {} arquivos de {} bytes em {:?}",
+                FILES,
+                FILE_SIZE,
+                sw.elapsed()
+            );
+            exit(0);
+        }
+        Err(err) => {
+            println!("fs-stress: falhou: {:?}", err);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Abre uma janela, desenha e fecha quando o usuário pede.
+
+#![no_std]
+#![no_main]
+
+use redpowder::event::InputEvent;
+use redpowder::mem::heap::SyscallAllocator;
+use redpowder::prelude::*;
+use redpowder::window::{EventLoop, LoopControl, Window};
+
+#[global_allocator]
+static ALLOCATOR: SyscallAllocator = SyscallAllocator;
+
+const BACKGROUND: u32 = 0x1E1E2E;
+const ACCENT: u32 = 0x89B4FA;
+
+fn draw(ctl: &mut LoopControl<'_>) {
+    let window = ctl.window();
+    window.clear(Color::from_hex(BACKGROUND));
+    window.fill_rect(Rect::new(20, 20, 120, 60), Color::from_hex(ACCENT));
+    let _ = window.present();
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    redpowder::runtime::init();
+    let window = match Window::create(100, 100, 320, 200, "Hello, Redstone") {
+        Ok(window) => window,
+        Err(err) => {
+            println!("hello-window: falha ao criar janela: {:?}", err);
+            exit(1);
+        }
+    };
+
+    let mut event_loop = EventLoop::new();
+    event_loop.add_window(window);
+    event_loop
+        .on_resize(|ctl, _| draw(ctl))
+        .on_input(|ctl, _: InputEvent| draw(ctl));
+    if let Some(window) = event_loop.windows().first_mut() {
+        window.clear(Color::from_hex(BACKGROUND));
+        let _ = window.present();
+    }
+
+    let code = match event_loop.run() {
+        Ok(()) => 0,
+        Err(_) => 1,
+    };
+    exit(code);
+}
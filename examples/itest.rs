@@ -0,0 +1,16 @@
+//! Testes de integração das syscalls, para o CI do kernel.
+
+#![no_std]
+#![no_main]
+
+use redpowder::demos::{itest, suite};
+use redpowder::mem::heap::SyscallAllocator;
+
+#[global_allocator]
+static ALLOCATOR: SyscallAllocator = SyscallAllocator;
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    redpowder::runtime::init();
+    itest::run_and_exit(suite::ALL)
+}
@@ -0,0 +1,47 @@
+//! Servidor de eco: devolve cada mensagem recebida em `echo.server` para a
+//! porta de resposta indicada nos primeiros 32 bytes.
+
+#![no_std]
+#![no_main]
+
+use redpowder::ipc::Port;
+use redpowder::mem::heap::SyscallAllocator;
+use redpowder::prelude::*;
+
+#[global_allocator]
+static ALLOCATOR: SyscallAllocator = SyscallAllocator;
+
+/// Nome da porta do servidor.
+const ECHO_PORT: &str = "echo.server";
+/// Bytes do nome da porta de resposta no início de cada mensagem.
+const REPLY_NAME_LEN: usize = 32;
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    redpowder::runtime::init();
+    let port = match Port::create(ECHO_PORT, 16) {
+        Ok(port) => port,
+        Err(err) => {
+            println!("port-echo: falha ao criar {}: {:?}", ECHO_PORT, err);
+            exit(1);
+        }
+    };
+    println!("port-echo: ouvindo em {}", ECHO_PORT);
+
+    let mut buf = [0u8; 256];
+    loop {
+        let len = match port.recv(&mut buf, 0) {
+            Ok(len) if len > REPLY_NAME_LEN => len,
+            _ => continue,
+        };
+
+        let name = &buf[..REPLY_NAME_LEN];
+        let name_len = name.iter().position(|&b| b == 0).unwrap_or(REPLY_NAME_LEN);
+        let Ok(reply_name) = core::str::from_utf8(&name[..name_len]) else {
+            continue;
+        };
+        if let Ok(reply) = Port::connect(reply_name) {
+            let _ = reply.send(&buf[REPLY_NAME_LEN..len], 0);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! # Mixer
+//!
+//! Controle de volume (master, por stream e por aplicação) e notificações
+//! de mudança — base para o slider de volume do shell.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::audio::mixer::{self, Target};
+//!
+//! mixer::set_volume(Target::App("music"), 40)?;
+//! mixer::mute(Target::Master, false)?;
+//!
+//! for stream in mixer::list_streams()? {
+//!     println!("{}: {}%", stream.name(), stream.volume());
+//! }
+//!
+//! let listener = mixer::subscribe()?;
+//! if let Some(event) = listener.poll() {
+//!     // atualizar UI...
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, recv_reply, str_from_fixed, Port};
+use crate::syscall::SysResult;
+
+use super::protocol::*;
+use super::stream::REPLY_TIMEOUT_MS;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Alvo de uma operação de volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target<'a> {
+    /// Saída master.
+    Master,
+    /// Stream específico (ID de [`StreamInfo::id`]).
+    Stream(u32),
+    /// Todos os streams de uma aplicação.
+    App(&'a str),
+}
+
+/// Estado de um stream.
+#[derive(Clone, Copy)]
+pub struct StreamInfo {
+    entry: StreamEntry,
+}
+
+impl StreamInfo {
+    /// ID do stream.
+    pub fn id(&self) -> u32 {
+        self.entry.stream_id
+    }
+
+    /// PID do processo dono.
+    pub fn pid(&self) -> u32 {
+        self.entry.pid
+    }
+
+    /// Nome do stream / aplicação.
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Volume (0–100).
+    pub fn volume(&self) -> u32 {
+        self.entry.volume
+    }
+
+    /// Retorna se está mudo.
+    pub fn is_muted(&self) -> bool {
+        self.entry.muted != 0
+    }
+}
+
+impl core::fmt::Debug for StreamInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamInfo")
+            .field("id", &self.id())
+            .field("pid", &self.pid())
+            .field("name", &self.name())
+            .field("volume", &self.volume())
+            .field("muted", &self.is_muted())
+            .finish()
+    }
+}
+
+/// Notificação do mixer.
+#[derive(Debug, Clone, Copy)]
+pub enum MixerEvent {
+    /// Volume/mute do master mudou (`volume` e `is_muted` do info).
+    MasterChanged(StreamInfo),
+    /// Volume/mute de um stream mudou.
+    StreamChanged(StreamInfo),
+    /// Novo stream aberto.
+    StreamAdded(StreamInfo),
+    /// Stream fechado.
+    StreamRemoved(StreamInfo),
+}
+
+// =============================================================================
+// CONTROLE
+// =============================================================================
+
+/// Define o volume (0–100, valores maiores são limitados).
+pub fn set_volume(target: Target, level: u32) -> SysResult<()> {
+    send_control(opcodes::SET_VOLUME, target, level.min(VOLUME_MAX))
+}
+
+/// Silencia ou restaura o alvo.
+pub fn mute(target: Target, muted: bool) -> SysResult<()> {
+    send_control(opcodes::SET_MUTE, target, muted as u32)
+}
+
+/// Lista os streams ativos.
+pub fn list_streams() -> SysResult<Vec<StreamInfo>> {
+    let (reply_port, reply_name) = Port::create_unique("mixer.r.", 4)?;
+    let server_port = Port::connect(AUDIO_PORT)?;
+
+    let mut streams = Vec::new();
+    loop {
+        let req = ListStreamsRequest {
+            op: opcodes::LIST_STREAMS,
+            start: streams.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let page = unsafe {
+            recv_reply::<AudioMessage>(
+                &reply_port,
+                opcodes::STREAM_LIST,
+                core::mem::size_of::<StreamListResponse>(),
+                REPLY_TIMEOUT_MS,
+            )?
+            .list_resp
+        };
+
+        let count = (page.count as usize).min(STREAMS_PER_MSG);
+        streams.extend(
+            page.entries[..count]
+                .iter()
+                .map(|&entry| StreamInfo { entry }),
+        );
+        if count == 0 || streams.len() >= page.total as usize {
+            return Ok(streams);
+        }
+    }
+}
+
+fn send_control(op: u32, target: Target, value: u32) -> SysResult<()> {
+    let (target_kind, stream_id, app) = match target {
+        Target::Master => (target_kind::MASTER, 0, [0; 32]),
+        Target::Stream(id) => (target_kind::STREAM, id, [0; 32]),
+        Target::App(name) => (target_kind::APP, 0, fixed_str(name)),
+    };
+
+    let req = MixerControlRequest {
+        op,
+        target_kind,
+        stream_id,
+        value,
+        app,
+    };
+    Port::connect(AUDIO_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+// =============================================================================
+// NOTIFICAÇÕES
+// =============================================================================
+
+/// Receptor de notificações do mixer.
+pub struct MixerListener {
+    port: Port,
+}
+
+/// Registra um receptor de notificações.
+pub fn subscribe() -> SysResult<MixerListener> {
+    let (port, name) = Port::create_unique("mixer.ev.", 16)?;
+    let req = SubscribeRequest {
+        op: opcodes::SUBSCRIBE,
+        listener_port: name,
+    };
+    Port::connect(AUDIO_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(MixerListener { port })
+}
+
+impl MixerListener {
+    /// Retorna a próxima notificação pendente, sem bloquear.
+    pub fn poll(&self) -> Option<MixerEvent> {
+        self.wait(0)
+    }
+
+    /// Espera uma notificação por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<MixerEvent> {
+        loop {
+            let mut msg = AudioMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<MixerEventMessage>() {
+                return None;
+            }
+
+            let event = unsafe { msg.event };
+            let info = StreamInfo {
+                entry: event.stream,
+            };
+            match event.op {
+                opcodes::EVENT_VOLUME if event.target_kind == target_kind::MASTER => {
+                    return Some(MixerEvent::MasterChanged(info))
+                }
+                opcodes::EVENT_VOLUME => return Some(MixerEvent::StreamChanged(info)),
+                opcodes::EVENT_STREAM_ADDED => return Some(MixerEvent::StreamAdded(info)),
+                opcodes::EVENT_STREAM_REMOVED => return Some(MixerEvent::StreamRemoved(info)),
+                // Mensagem desconhecida: descartar e continuar
+                _ => continue,
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,26 @@
+//! # Áudio
+//!
+//! Cliente do servidor de áudio e síntese simples de tons.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo de áudio |
+//! | [`mixer`] | Volume por app/stream e notificações |
+//! | [`stream`] | Stream de saída PCM (AudioStream) |
+//! | [`tone`] | Beep e sintetizador quadrado/senoidal |
+
+pub mod mixer;
+pub mod protocol;
+pub mod stream;
+pub mod tone;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use mixer::{list_streams, mute, set_volume, MixerEvent, MixerListener, StreamInfo, Target};
+pub use protocol::{AUDIO_PORT, MAX_MSG_SIZE, VOLUME_MAX};
+pub use stream::AudioStream;
+pub use tone::{beep, Oscillator, Waveform};
@@ -0,0 +1,226 @@
+//! # Protocolo de Áudio
+//!
+//! Definições de mensagens do protocolo com o servidor de áudio.
+//!
+//! Amostras PCM são trocadas por memória compartilhada: o cliente copia um
+//! bloco para a SHM do stream, envia `WRITE_STREAM` e espera `WRITE_DONE`
+//! antes de reutilizar o buffer.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do servidor de áudio.
+pub const AUDIO_PORT: &str = "audio.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const OPEN_STREAM: u32 = 0x01;
+    pub const CLOSE_STREAM: u32 = 0x02;
+    pub const WRITE_STREAM: u32 = 0x03;
+    pub const SET_VOLUME: u32 = 0x04;
+    pub const SET_MUTE: u32 = 0x05;
+    pub const LIST_STREAMS: u32 = 0x06;
+    pub const SUBSCRIBE: u32 = 0x07;
+
+    // Server -> Client
+    pub const STREAM_OPENED: u32 = 0x10;
+    pub const WRITE_DONE: u32 = 0x11;
+    pub const STREAM_LIST: u32 = 0x12;
+    pub const EVENT_VOLUME: u32 = 0x20;
+    pub const EVENT_STREAM_ADDED: u32 = 0x21;
+    pub const EVENT_STREAM_REMOVED: u32 = 0x22;
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Alvos de controle de volume.
+pub mod target_kind {
+    /// Saída master.
+    pub const MASTER: u32 = 0;
+    /// Um stream específico (por ID).
+    pub const STREAM: u32 = 1;
+    /// Todos os streams de uma aplicação (por nome).
+    pub const APP: u32 = 2;
+}
+
+/// Volume máximo (100%).
+pub const VOLUME_MAX: u32 = 100;
+
+/// Entradas por mensagem de `STREAM_LIST`.
+pub const STREAMS_PER_MSG: usize = 4;
+
+/// Formatos de amostra.
+pub mod sample_format {
+    /// Inteiro 16 bits com sinal, little-endian, canais intercalados.
+    pub const S16_LE: u32 = 1;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request para abrir stream de saída.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OpenStreamRequest {
+    pub op: u32,
+    pub sample_rate: u32,
+    pub channels: u32,
+    pub format: u32,
+    /// Nome da porta onde o servidor deve responder.
+    pub reply_port: [u8; 32],
+    /// Nome do stream / aplicação (exibido no mixer).
+    pub name: [u8; 32],
+}
+
+/// Request genérico para operações de stream.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StreamOpRequest {
+    pub op: u32,
+    pub stream_id: u32,
+}
+
+/// Request de escrita: `len` bytes a partir de `offset` na SHM do stream.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WriteStreamRequest {
+    pub op: u32,
+    pub stream_id: u32,
+    pub offset: u32,
+    pub len: u32,
+}
+
+/// Request de volume/mute. `value` é o volume (0–100) ou 0/1 para mute.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MixerControlRequest {
+    pub op: u32,
+    pub target_kind: u32,
+    pub stream_id: u32,
+    pub value: u32,
+    /// Nome da aplicação (alvo `APP`).
+    pub app: [u8; 32],
+}
+
+/// Request de listagem de streams a partir do índice `start`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListStreamsRequest {
+    pub op: u32,
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request para receber notificações do mixer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    /// Porta para receber eventos.
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Response de stream aberto.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StreamOpenedResponse {
+    pub op: u32,
+    pub stream_id: u32,
+    pub shm_handle: u64,
+    pub buffer_size: u64,
+}
+
+/// Confirmação de escrita (buffer livre para reuso).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WriteDoneResponse {
+    pub op: u32,
+    pub stream_id: u32,
+}
+
+/// Estado de um stream no mixer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StreamEntry {
+    pub stream_id: u32,
+    pub pid: u32,
+    pub volume: u32,
+    pub muted: u32,
+    /// Nome do stream / aplicação.
+    pub name: [u8; 32],
+}
+
+/// Página da lista de streams.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StreamListResponse {
+    pub op: u32,
+    /// Entradas válidas nesta página.
+    pub count: u32,
+    /// Total de streams no servidor.
+    pub total: u32,
+    pub entries: [StreamEntry; STREAMS_PER_MSG],
+}
+
+/// Evento do mixer (volume alterado, stream criado/removido).
+///
+/// Para `EVENT_VOLUME`, `target_kind` indica se mudou o master ou um stream.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MixerEventMessage {
+    pub op: u32,
+    pub target_kind: u32,
+    pub stream: StreamEntry,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union AudioMessage {
+    pub header: u32,
+    pub open_req: OpenStreamRequest,
+    pub op_req: StreamOpRequest,
+    pub write_req: WriteStreamRequest,
+    pub opened_resp: StreamOpenedResponse,
+    pub write_done: WriteDoneResponse,
+    pub control_req: MixerControlRequest,
+    pub list_req: ListStreamsRequest,
+    pub subscribe_req: SubscribeRequest,
+    pub list_resp: StreamListResponse,
+    pub event: MixerEventMessage,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for AudioMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -0,0 +1,133 @@
+//! # Audio Stream
+//!
+//! Stream de saída PCM (S16LE) para o servidor de áudio.
+
+use crate::ipc::{as_bytes, fixed_str, recv_reply, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+pub(super) const REPLY_TIMEOUT_MS: u64 = 1000;
+
+// =============================================================================
+// AUDIO STREAM
+// =============================================================================
+
+/// Stream de saída de áudio.
+pub struct AudioStream {
+    /// ID do stream no servidor.
+    id: u32,
+    /// Buffer compartilhado de amostras.
+    shm: SharedMemory,
+    /// Taxa de amostragem (Hz).
+    sample_rate: u32,
+    /// Número de canais (intercalados).
+    channels: u32,
+    /// Porta do servidor de áudio.
+    server_port: Port,
+    /// Porta de respostas.
+    reply_port: Port,
+}
+
+impl AudioStream {
+    /// Abre stream de saída com o formato dado.
+    pub fn open(sample_rate: u32, channels: u32, name: &str) -> SysResult<Self> {
+        if sample_rate == 0 || channels == 0 {
+            return Err(SysError::InvalidArgument);
+        }
+
+        let (reply_port, reply_name) = Port::create_unique("audio.r.", 8)?;
+        let server_port = Port::connect(AUDIO_PORT)?;
+
+        let req = OpenStreamRequest {
+            op: opcodes::OPEN_STREAM,
+            sample_rate,
+            channels,
+            format: sample_format::S16_LE,
+            reply_port: reply_name,
+            name: fixed_str(name),
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<AudioMessage>(
+            &reply_port,
+            opcodes::STREAM_OPENED,
+            core::mem::size_of::<StreamOpenedResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let opened = unsafe { resp.opened_resp };
+
+        let shm = SharedMemory::open(ShmId(opened.shm_handle))?;
+
+        Ok(Self {
+            id: opened.stream_id,
+            shm,
+            sample_rate,
+            channels,
+            server_port,
+            reply_port,
+        })
+    }
+
+    /// ID do stream no servidor.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Taxa de amostragem (Hz).
+    #[inline]
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Número de canais.
+    #[inline]
+    pub fn channels(&self) -> u32 {
+        self.channels
+    }
+
+    /// Escreve amostras intercaladas, bloqueando até o servidor consumi-las.
+    pub fn write(&mut self, samples: &[i16]) -> SysResult<()> {
+        // Blocos múltiplos de um frame completo
+        let frame = self.channels as usize;
+        let capacity = (self.shm.size() / 2) / frame * frame;
+        if capacity == 0 {
+            return Err(SysError::BufferTooSmall);
+        }
+
+        for chunk in samples.chunks(capacity) {
+            let dst = self.shm.as_mut_slice();
+            for (i, sample) in chunk.iter().enumerate() {
+                dst[i * 2..i * 2 + 2].copy_from_slice(&sample.to_le_bytes());
+            }
+
+            let req = WriteStreamRequest {
+                op: opcodes::WRITE_STREAM,
+                stream_id: self.id,
+                offset: 0,
+                len: (chunk.len() * 2) as u32,
+            };
+            self.server_port.send(as_bytes(&req), 0)?;
+
+            recv_reply::<AudioMessage>(
+                &self.reply_port,
+                opcodes::WRITE_DONE,
+                core::mem::size_of::<WriteDoneResponse>(),
+                REPLY_TIMEOUT_MS,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AudioStream {
+    fn drop(&mut self) {
+        let req = StreamOpRequest {
+            op: opcodes::CLOSE_STREAM,
+            stream_id: self.id,
+        };
+        let _ = self.server_port.send(as_bytes(&req), 0);
+    }
+}
@@ -0,0 +1,128 @@
+//! # Tone
+//!
+//! Beep do sistema e sintetizador simples (onda quadrada/senoidal).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::audio::tone;
+//!
+//! // Alerta: 880 Hz por 150 ms
+//! tone::beep(880, 150)?;
+//! ```
+
+use crate::syscall::safe::sys_beep;
+use crate::syscall::SysResult;
+
+use super::stream::AudioStream;
+
+/// Taxa de amostragem usada por [`play_tone`].
+pub const TONE_SAMPLE_RATE: u32 = 48_000;
+
+/// Amplitude padrão (~25% da escala, para alertas não estourarem).
+pub const DEFAULT_AMPLITUDE: i16 = 8192;
+
+// =============================================================================
+// OSCILADOR
+// =============================================================================
+
+/// Forma de onda.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Waveform {
+    /// Onda quadrada (som de "beep" clássico).
+    #[default]
+    Square,
+    /// Onda senoidal (tom puro).
+    Sine,
+}
+
+/// Oscilador de frequência fixa gerando amostras S16.
+#[derive(Debug, Clone, Copy)]
+pub struct Oscillator {
+    waveform: Waveform,
+    /// Fase atual em ciclos (0.0..1.0).
+    phase: f32,
+    /// Incremento de fase por amostra.
+    step: f32,
+    amplitude: i16,
+}
+
+impl Oscillator {
+    /// Cria oscilador de `freq` Hz para a taxa de amostragem dada.
+    pub fn new(waveform: Waveform, freq: u32, sample_rate: u32) -> Self {
+        Self {
+            waveform,
+            phase: 0.0,
+            step: freq as f32 / sample_rate.max(1) as f32,
+            amplitude: DEFAULT_AMPLITUDE,
+        }
+    }
+
+    /// Define a amplitude (pico).
+    pub fn with_amplitude(mut self, amplitude: i16) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Gera a próxima amostra.
+    pub fn next_sample(&mut self) -> i16 {
+        let value = match self.waveform {
+            Waveform::Square => {
+                if self.phase < 0.5 {
+                    self.amplitude
+                } else {
+                    -self.amplitude
+                }
+            }
+            Waveform::Sine => {
+                (rdsmath::sinf(self.phase * rdsmath::TAU) * self.amplitude as f32) as i16
+            }
+        };
+
+        self.phase += self.step;
+        if self.phase >= 1.0 {
+            self.phase -= rdsmath::floorf(self.phase);
+        }
+        value
+    }
+
+    /// Preenche `buf` com amostras intercaladas, repetindo em `channels` canais.
+    pub fn fill(&mut self, buf: &mut [i16], channels: usize) {
+        for frame in buf.chunks_mut(channels.max(1)) {
+            let sample = self.next_sample();
+            frame.fill(sample);
+        }
+    }
+}
+
+// =============================================================================
+// BEEP
+// =============================================================================
+
+/// Toca um beep de `freq` Hz por `duration_ms`.
+///
+/// Usa o servidor de áudio se disponível; caso contrário, o PC speaker.
+pub fn beep(freq: u32, duration_ms: u32) -> SysResult<()> {
+    play_tone(Waveform::Square, freq, duration_ms).or_else(|_| pc_speaker(freq, duration_ms))
+}
+
+/// Toca um tom pelo servidor de áudio (mono, 48 kHz).
+pub fn play_tone(waveform: Waveform, freq: u32, duration_ms: u32) -> SysResult<()> {
+    let mut stream = AudioStream::open(TONE_SAMPLE_RATE, 1, "beep")?;
+    let mut osc = Oscillator::new(waveform, freq, TONE_SAMPLE_RATE);
+
+    let mut remaining = (TONE_SAMPLE_RATE as u64 * duration_ms as u64 / 1000) as usize;
+    let mut buf = [0i16; 512];
+    while remaining > 0 {
+        let n = remaining.min(buf.len());
+        osc.fill(&mut buf[..n], 1);
+        stream.write(&buf[..n])?;
+        remaining -= n;
+    }
+    Ok(())
+}
+
+/// Toca um tom no PC speaker (bloqueia por `duration_ms`).
+pub fn pc_speaker(freq: u32, duration_ms: u32) -> SysResult<()> {
+    sys_beep(freq, duration_ms)
+}
@@ -0,0 +1,368 @@
+//! # Camera Client
+//!
+//! Sessão de captura com o servidor de câmera.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::camera::Camera;
+//!
+//! let camera = Camera::open(0)?;
+//! camera.start()?;
+//! loop {
+//!     if let Some(frame) = camera.next_frame(100)? {
+//!         let surface = frame.to_surface()?;
+//!         // exibir... o buffer volta ao servidor quando `frame` sai de escopo
+//!     }
+//! }
+//! ```
+
+use crate::graphics::Surface;
+use crate::ipc::{as_bytes, recv_message, recv_reply, Message, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::convert::yuyv_to_argb;
+use super::protocol::*;
+
+/// Tempo máximo de espera pela abertura (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// FORMATOS
+// =============================================================================
+
+/// Formato de frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    /// YUV 4:2:2 intercalado.
+    Yuyv,
+    /// 32 bits por pixel (0xXXRRGGBB).
+    Xrgb8888,
+    /// JPEG por frame (decodificação fica a cargo do app).
+    Mjpeg,
+    /// FourCC não reconhecido.
+    Other(u32),
+}
+
+impl FrameFormat {
+    /// Converte de FourCC.
+    pub fn from_fourcc(code: u32) -> Self {
+        match code {
+            fourcc::YUYV => Self::Yuyv,
+            fourcc::XRGB8888 => Self::Xrgb8888,
+            fourcc::MJPEG => Self::Mjpeg,
+            other => Self::Other(other),
+        }
+    }
+
+    /// Converte para FourCC.
+    pub fn fourcc(self) -> u32 {
+        match self {
+            Self::Yuyv => fourcc::YUYV,
+            Self::Xrgb8888 => fourcc::XRGB8888,
+            Self::Mjpeg => fourcc::MJPEG,
+            Self::Other(code) => code,
+        }
+    }
+}
+
+/// Parâmetros desejados na abertura (o servidor pode ajustar).
+#[derive(Debug, Clone, Copy)]
+pub struct CameraConfig<'a> {
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    /// Formatos aceitos, em ordem de preferência (até 8).
+    pub formats: &'a [FrameFormat],
+}
+
+impl Default for CameraConfig<'_> {
+    fn default() -> Self {
+        Self {
+            width: 640,
+            height: 480,
+            fps: 30,
+            formats: &[FrameFormat::Xrgb8888, FrameFormat::Yuyv],
+        }
+    }
+}
+
+// =============================================================================
+// CAMERA
+// =============================================================================
+
+/// Sessão de captura.
+pub struct Camera {
+    /// Formato negociado.
+    info: CameraOpenedResponse,
+    /// Swapchain compartilhada.
+    shm: SharedMemory,
+    /// Porta do servidor de câmera.
+    server_port: Port,
+    /// Porta de respostas e frames.
+    event_port: Port,
+}
+
+impl Camera {
+    /// Abre a câmera `id` com a configuração padrão.
+    pub fn open(id: u32) -> SysResult<Self> {
+        Self::open_with(id, &CameraConfig::default())
+    }
+
+    /// Abre a câmera `id` negociando formato e resolução.
+    pub fn open_with(id: u32, config: &CameraConfig) -> SysResult<Self> {
+        if config.formats.is_empty() || config.formats.len() > MAX_FORMATS {
+            return Err(SysError::InvalidArgument);
+        }
+
+        let (event_port, event_name) = Port::create_unique("camera.r.", 16)?;
+        let server_port = Port::connect(CAMERA_PORT)?;
+
+        let mut formats = [0u32; MAX_FORMATS];
+        for (slot, format) in formats.iter_mut().zip(config.formats) {
+            *slot = format.fourcc();
+        }
+        let req = OpenCameraRequest {
+            op: opcodes::OPEN_CAMERA,
+            camera_id: id,
+            width: config.width,
+            height: config.height,
+            fps: config.fps,
+            format_count: config.formats.len() as u32,
+            formats,
+            reply_port: event_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<CameraMessage>(
+            &event_port,
+            opcodes::CAMERA_OPENED,
+            core::mem::size_of::<CameraOpenedResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let info = unsafe { resp.opened };
+
+        // Formatos sem compressão ocupam `stride * height` bytes por buffer
+        let frame_size = info.stride as u64 * info.height as u64;
+        let raw = matches!(
+            FrameFormat::from_fourcc(info.format),
+            FrameFormat::Yuyv | FrameFormat::Xrgb8888
+        );
+        if raw && info.buffer_size < frame_size {
+            return Err(SysError::ProtocolError);
+        }
+
+        let shm = SharedMemory::open(ShmId(info.shm_handle))?;
+        match info.buffer_size.checked_mul(info.buffer_count as u64) {
+            Some(total) if total <= shm.size() as u64 => {}
+            _ => return Err(SysError::ProtocolError),
+        }
+
+        Ok(Self {
+            info,
+            shm,
+            server_port,
+            event_port,
+        })
+    }
+
+    /// Formato negociado.
+    pub fn format(&self) -> FrameFormat {
+        FrameFormat::from_fourcc(self.info.format)
+    }
+
+    /// Largura dos frames.
+    pub fn width(&self) -> u32 {
+        self.info.width
+    }
+
+    /// Altura dos frames.
+    pub fn height(&self) -> u32 {
+        self.info.height
+    }
+
+    /// Bytes por linha.
+    pub fn stride(&self) -> u32 {
+        self.info.stride
+    }
+
+    /// Frames por segundo negociados.
+    pub fn fps(&self) -> u32 {
+        self.info.fps
+    }
+
+    /// Inicia a captura.
+    pub fn start(&self) -> SysResult<()> {
+        self.send_op(opcodes::START_CAPTURE)
+    }
+
+    /// Pausa a captura.
+    pub fn stop(&self) -> SysResult<()> {
+        self.send_op(opcodes::STOP_CAPTURE)
+    }
+
+    /// Espera o próximo frame por até `timeout_ms` (None em timeout).
+    pub fn next_frame(&self, timeout_ms: u64) -> SysResult<Option<Frame<'_>>> {
+        loop {
+            let (msg, len) = match recv_message::<CameraMessage>(&self.event_port, timeout_ms) {
+                Ok(received) => received,
+                Err(SysError::Timeout) => return Ok(None),
+                Err(e) => return Err(e),
+            };
+
+            match msg.opcode() {
+                opcodes::FRAME_READY if len >= core::mem::size_of::<FrameReadyEvent>() => {
+                    let event = unsafe { msg.frame };
+                    if event.index >= self.info.buffer_count {
+                        return Err(SysError::ProtocolError);
+                    }
+                    return Ok(Some(Frame {
+                        camera: self,
+                        event,
+                    }));
+                }
+                // FRAME_READY truncado
+                opcodes::FRAME_READY => return Err(SysError::ProtocolError),
+                opcodes::ERROR => return Err(msg.error()),
+                // Mensagem desconhecida: descartar e continuar
+                _ => continue,
+            }
+        }
+    }
+
+    /// Porta de frames (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.event_port
+    }
+
+    fn send_op(&self, op: u32) -> SysResult<()> {
+        let req = SessionOpRequest {
+            op,
+            session_id: self.info.session_id,
+        };
+        self.server_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+}
+
+impl Drop for Camera {
+    fn drop(&mut self) {
+        let _ = self.send_op(opcodes::CLOSE_CAMERA);
+    }
+}
+
+// =============================================================================
+// FRAME
+// =============================================================================
+
+/// Frame capturado; o buffer é devolvido ao servidor no `drop`.
+pub struct Frame<'a> {
+    camera: &'a Camera,
+    event: FrameReadyEvent,
+}
+
+impl Frame<'_> {
+    /// Dados brutos do frame.
+    pub fn data(&self) -> &[u8] {
+        let size = self.camera.info.buffer_size as usize;
+        let start = self.event.index as usize * size;
+        let used = match self.event.bytes_used as usize {
+            0 => size,
+            n => n.min(size),
+        };
+        &self.camera.shm.as_slice()[start..start + used]
+    }
+
+    /// Formato do frame.
+    pub fn format(&self) -> FrameFormat {
+        self.camera.format()
+    }
+
+    /// Largura.
+    pub fn width(&self) -> u32 {
+        self.camera.width()
+    }
+
+    /// Altura.
+    pub fn height(&self) -> u32 {
+        self.camera.height()
+    }
+
+    /// Bytes por linha.
+    pub fn stride(&self) -> u32 {
+        self.camera.stride()
+    }
+
+    /// Instante da captura (ns, clock monotônico).
+    pub fn timestamp_ns(&self) -> u64 {
+        self.event.timestamp_ns
+    }
+
+    /// Número de sequência (detecta frames perdidos).
+    pub fn sequence(&self) -> u64 {
+        self.event.sequence
+    }
+
+    /// Converte para ARGB em `dst` (`width * height` pixels).
+    ///
+    /// Retorna `NotSupported` para formatos comprimidos ou desconhecidos e
+    /// `ProtocolError` se o frame for menor que `stride * height`.
+    pub fn convert_to_argb(&self, dst: &mut [u32]) -> SysResult<()> {
+        let (width, height, stride) = (self.width(), self.height(), self.stride());
+        if dst.len() < width as usize * height as usize {
+            return Err(SysError::BufferTooSmall);
+        }
+
+        // Bytes lidos por linha
+        let row_bytes = match self.format() {
+            FrameFormat::Yuyv => (width as usize & !1) * 2,
+            FrameFormat::Xrgb8888 => width as usize * 4,
+            _ => return Err(SysError::NotSupported),
+        };
+        let data = self.data();
+        if height > 0 {
+            let needed = (stride as usize)
+                .checked_mul(height as usize - 1)
+                .and_then(|n| n.checked_add(row_bytes));
+            if !matches!(needed, Some(n) if n <= data.len()) {
+                return Err(SysError::ProtocolError);
+            }
+        }
+
+        match self.format() {
+            FrameFormat::Yuyv => yuyv_to_argb(data, width, height, stride, dst),
+            _ => {
+                let (width, stride) = (width as usize, stride as usize);
+                for y in 0..height as usize {
+                    let Some(line) = data.get(y * stride..y * stride + row_bytes) else {
+                        return Err(SysError::ProtocolError);
+                    };
+                    let out = &mut dst[y * width..(y + 1) * width];
+                    for (px, bytes) in out.iter_mut().zip(line.chunks_exact(4)) {
+                        *px = 0xFF00_0000
+                            | u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Converte para uma nova [`Surface`].
+    pub fn to_surface(&self) -> SysResult<Surface> {
+        let mut surface = Surface::new(self.width(), self.height());
+        self.convert_to_argb(surface.pixels_mut())?;
+        Ok(surface)
+    }
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        let req = ReleaseFrameRequest {
+            op: opcodes::RELEASE_FRAME,
+            session_id: self.camera.info.session_id,
+            index: self.event.index,
+        };
+        let _ = self.camera.server_port.send(as_bytes(&req), 0);
+    }
+}
@@ -0,0 +1,45 @@
+//! # Conversão de Formatos
+//!
+//! Conversão de frames de câmera para ARGB (formato do [`Canvas`](crate::graphics::Canvas)).
+
+/// Converte YUYV (BT.601, faixa limitada) para ARGB opaco.
+///
+/// `stride` é o número de bytes por linha de `src`; `dst` deve ter pelo menos
+/// `width * height` pixels. Larguras ímpares descartam a última coluna.
+pub fn yuyv_to_argb(src: &[u8], width: u32, height: u32, stride: u32, dst: &mut [u32]) {
+    let (dst_width, height, stride) = (width as usize, height as usize, stride as usize);
+    let pairs_width = dst_width & !1;
+
+    for y in 0..height {
+        let Some(line) = src.get(y * stride..y * stride + pairs_width * 2) else {
+            return;
+        };
+        let Some(out) = dst.get_mut(y * dst_width..y * dst_width + pairs_width) else {
+            return;
+        };
+
+        for (x, quad) in line.chunks_exact(4).enumerate() {
+            let (y0, u, y1, v) = (quad[0], quad[1], quad[2], quad[3]);
+            out[x * 2] = yuv_to_argb(y0, u, v);
+            out[x * 2 + 1] = yuv_to_argb(y1, u, v);
+        }
+    }
+}
+
+/// Converte um pixel YUV (BT.601, faixa limitada) para ARGB opaco.
+#[inline]
+pub fn yuv_to_argb(y: u8, u: u8, v: u8) -> u32 {
+    let c = (y as i32 - 16) * 298;
+    let d = u as i32 - 128;
+    let e = v as i32 - 128;
+
+    let r = clamp_u8((c + 409 * e + 128) >> 8);
+    let g = clamp_u8((c - 100 * d - 208 * e + 128) >> 8);
+    let b = clamp_u8((c + 516 * d + 128) >> 8);
+    0xFF00_0000 | (r << 16) | (g << 8) | b
+}
+
+#[inline]
+fn clamp_u8(v: i32) -> u32 {
+    v.clamp(0, 255) as u32
+}
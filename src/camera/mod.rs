@@ -0,0 +1,24 @@
+//! # Câmera
+//!
+//! Captura de vídeo do servidor de câmera via swapchain em memória
+//! compartilhada.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo de câmera |
+//! | [`client`] | Sessão de captura (Camera, Frame) |
+//! | [`convert`] | Conversão de formatos (YUYV → ARGB) |
+
+pub mod client;
+pub mod convert;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{Camera, CameraConfig, Frame, FrameFormat};
+pub use convert::yuyv_to_argb;
+pub use protocol::CAMERA_PORT;
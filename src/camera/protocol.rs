@@ -0,0 +1,159 @@
+//! # Protocolo de Câmera
+//!
+//! Definições de mensagens do protocolo com o servidor de câmera.
+//!
+//! Ao abrir, o cliente envia seus formatos em ordem de preferência e o
+//! servidor escolhe o primeiro suportado. Os frames ficam em uma SHM com
+//! `buffer_count` buffers consecutivos de `buffer_size` bytes; o servidor
+//! anuncia cada frame com `FRAME_READY` e só reutiliza o buffer após
+//! `RELEASE_FRAME`.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do servidor de câmera.
+pub const CAMERA_PORT: &str = "camera.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+/// Número máximo de formatos em um pedido.
+pub const MAX_FORMATS: usize = 8;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const OPEN_CAMERA: u32 = 0x01;
+    pub const CLOSE_CAMERA: u32 = 0x02;
+    pub const START_CAPTURE: u32 = 0x03;
+    pub const STOP_CAPTURE: u32 = 0x04;
+    pub const RELEASE_FRAME: u32 = 0x05;
+
+    // Server -> Client
+    pub const CAMERA_OPENED: u32 = 0x10;
+    pub const FRAME_READY: u32 = 0x11;
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Formatos de pixel (FourCC).
+pub mod fourcc {
+    /// YUV 4:2:2 intercalado (Y0 U Y1 V).
+    pub const YUYV: u32 = u32::from_le_bytes(*b"YUYV");
+    /// 32 bits por pixel, 0xXXRRGGBB.
+    pub const XRGB8888: u32 = u32::from_le_bytes(*b"XR24");
+    /// JPEG por frame.
+    pub const MJPEG: u32 = u32::from_le_bytes(*b"MJPG");
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request para abrir câmera.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OpenCameraRequest {
+    pub op: u32,
+    pub camera_id: u32,
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    /// Formatos válidos em `formats`.
+    pub format_count: u32,
+    /// FourCCs em ordem de preferência.
+    pub formats: [u32; MAX_FORMATS],
+    /// Nome da porta onde o servidor deve responder e enviar frames.
+    pub reply_port: [u8; 32],
+}
+
+/// Request genérico para operações de sessão.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SessionOpRequest {
+    pub op: u32,
+    pub session_id: u32,
+}
+
+/// Devolve um buffer ao servidor.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ReleaseFrameRequest {
+    pub op: u32,
+    pub session_id: u32,
+    pub index: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Response de câmera aberta (formato negociado).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CameraOpenedResponse {
+    pub op: u32,
+    pub session_id: u32,
+    pub format: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Bytes por linha.
+    pub stride: u32,
+    pub fps: u32,
+    pub buffer_count: u32,
+    pub shm_handle: u64,
+    /// Tamanho de cada buffer da swapchain.
+    pub buffer_size: u64,
+}
+
+/// Frame disponível no buffer `index`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FrameReadyEvent {
+    pub op: u32,
+    pub session_id: u32,
+    pub index: u32,
+    /// Bytes válidos no buffer (relevante para MJPEG).
+    pub bytes_used: u32,
+    /// Instante da captura (ns, clock monotônico).
+    pub timestamp_ns: u64,
+    pub sequence: u64,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union CameraMessage {
+    pub header: u32,
+    pub open_req: OpenCameraRequest,
+    pub op_req: SessionOpRequest,
+    pub release_req: ReleaseFrameRequest,
+    pub opened: CameraOpenedResponse,
+    pub frame: FrameReadyEvent,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for CameraMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
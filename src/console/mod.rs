@@ -2,24 +2,31 @@
 //!
 //! Funções para I/O de console (serial).
 
-use crate::syscall::{check_error, syscall0, syscall2, SysResult};
-use crate::syscall::{SYS_CONSOLE_READ, SYS_CONSOLE_WRITE, SYS_POWEROFF, SYS_REBOOT};
-use core::fmt::{self, Write};
+mod readline;
+pub mod style;
+
+pub use readline::{readline, readline_with_history, History};
+pub use style::{Color, Style, Styled, Stylize};
 
-/// Writer para console
-struct ConsoleWriter;
+use crate::syscall::safe::{
+    sys_console_read, sys_console_set_mode, sys_console_write, sys_poweroff, sys_reboot,
+};
+use crate::syscall::SysResult;
+use core::fmt::{self, Write};
 
-impl Write for ConsoleWriter {
-    fn write_str(&mut self, s: &str) -> fmt::Result {
-        let _ = write_bytes(s.as_bytes());
-        Ok(())
-    }
+/// Flags de modo da console
+pub mod mode {
+    /// Ecoa os bytes recebidos.
+    pub const ECHO: u32 = 1 << 0;
+    /// Entrega a entrada linha a linha (edição feita pelo kernel).
+    pub const CANONICAL: u32 = 1 << 1;
+    /// Modo padrão (cooked).
+    pub const DEFAULT: u32 = ECHO | CANONICAL;
 }
 
 /// Escreve bytes na console
 pub fn write_bytes(buf: &[u8]) -> SysResult<usize> {
-    let ret = syscall2(SYS_CONSOLE_WRITE, buf.as_ptr() as usize, buf.len());
-    check_error(ret)
+    sys_console_write(buf)
 }
 
 /// Escreve string na console
@@ -29,31 +36,39 @@ pub fn write_str(s: &str) -> SysResult<usize> {
 
 /// Lê bytes da console (blocking)
 pub fn read_bytes(buf: &mut [u8]) -> SysResult<usize> {
-    let ret = syscall2(SYS_CONSOLE_READ, buf.as_mut_ptr() as usize, buf.len());
-    check_error(ret)
+    sys_console_read(buf)
+}
+
+/// Define o modo da console, retornando o modo anterior
+pub fn set_mode(flags: u32) -> SysResult<u32> {
+    sys_console_set_mode(flags)
+}
+
+/// Liga/desliga o modo raw (sem eco, byte a byte), retornando o modo anterior
+pub fn set_raw_mode(enabled: bool) -> SysResult<u32> {
+    set_mode(if enabled { 0 } else { mode::DEFAULT })
 }
 
 /// Reinicia o sistema
 pub fn reboot() -> ! {
-    let _ = syscall0(SYS_REBOOT);
-    loop {
-        unsafe { core::arch::asm!("hlt") };
-    }
+    sys_reboot()
 }
 
 /// Desliga o sistema
 pub fn poweroff() -> ! {
-    let _ = syscall0(SYS_POWEROFF);
-    loop {
-        unsafe { core::arch::asm!("hlt") };
-    }
+    sys_poweroff()
 }
 
-/// Função interna para print
+/// Função interna para print (saída padrão)
 #[doc(hidden)]
 pub fn _print(args: fmt::Arguments) {
-    let mut writer = ConsoleWriter;
-    let _ = writer.write_fmt(args);
+    let _ = crate::io::stdout().write_fmt(args);
+}
+
+/// Função interna para eprint (saída de erro)
+#[doc(hidden)]
+pub fn _eprint(args: fmt::Arguments) {
+    let _ = crate::io::stderr().write_fmt(args);
 }
 
 /// Macro print! para console
@@ -73,3 +88,21 @@ macro_rules! println {
         $crate::console::_print(core::format_args!("\n"));
     }};
 }
+
+/// Macro eprint! para saída de erro
+#[macro_export]
+macro_rules! eprint {
+    ($($arg:tt)*) => {{
+        $crate::console::_eprint(core::format_args!($($arg)*));
+    }};
+}
+
+/// Macro eprintln! para saída de erro
+#[macro_export]
+macro_rules! eprintln {
+    () => { $crate::eprint!("\n") };
+    ($($arg:tt)*) => {{
+        $crate::console::_eprint(core::format_args!($($arg)*));
+        $crate::console::_eprint(core::format_args!("\n"));
+    }};
+}
@@ -0,0 +1,356 @@
+//! # Readline
+//!
+//! Leitura de linha com edição (backspace, setas, Ctrl-U/W) e histórico.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::console::{self, History};
+//!
+//! let mut history = History::new(100);
+//! loop {
+//!     match console::readline_with_history("> ", &mut history) {
+//!         Ok(line) => run(&line),
+//!         Err(SysError::EndOfFile) => break, // Ctrl-D
+//!         Err(_) => continue,                // Ctrl-C
+//!     }
+//! }
+//! ```
+//!
+//! ## Teclas
+//!
+//! | Tecla | Ação |
+//! |-------|------|
+//! | Backspace / Delete | Apaga antes / sob o cursor |
+//! | ← / → , Home / End, Ctrl-A / Ctrl-E | Move o cursor |
+//! | ↑ / ↓ | Navega no histórico |
+//! | Ctrl-U | Apaga até o início da linha |
+//! | Ctrl-W | Apaga a palavra anterior |
+//! | Ctrl-C | Cancela (`SysError::Interrupted`) |
+//! | Ctrl-D | Fim de entrada em linha vazia (`SysError::EndOfFile`) |
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::syscall::{SysError, SysResult};
+
+use super::{read_bytes, set_mode, set_raw_mode, write_str};
+
+// =============================================================================
+// HISTÓRICO
+// =============================================================================
+
+/// Histórico de linhas (buffer circular).
+#[derive(Debug, Clone)]
+pub struct History {
+    entries: VecDeque<String>,
+    capacity: usize,
+}
+
+impl History {
+    /// Cria histórico com no máximo `capacity` linhas.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adiciona linha (ignora vazias e repetição da última).
+    pub fn push(&mut self, line: &str) {
+        if self.capacity == 0 || line.trim().is_empty() {
+            return;
+        }
+        if self.entries.back().map(|s| s.as_str()) == Some(line) {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(String::from(line));
+    }
+
+    /// Linha `index` (0 = mais antiga).
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(|s| s.as_str())
+    }
+
+    /// Número de linhas.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Verifica se está vazio.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove todas as linhas.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Itera da mais antiga para a mais recente.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|s| s.as_str())
+    }
+}
+
+// =============================================================================
+// READLINE
+// =============================================================================
+
+/// Lê uma linha com edição.
+pub fn readline(prompt: &str) -> SysResult<String> {
+    read_line(prompt, None)
+}
+
+/// Lê uma linha com edição e navegação no histórico.
+///
+/// A linha lida é adicionada ao histórico.
+pub fn readline_with_history(prompt: &str, history: &mut History) -> SysResult<String> {
+    let line = read_line(prompt, Some(history))?;
+    history.push(&line);
+    Ok(line)
+}
+
+fn read_line(prompt: &str, history: Option<&History>) -> SysResult<String> {
+    let previous = set_raw_mode(true)?;
+    let mut editor = Editor::new(prompt, history);
+    let result = editor.run();
+    let _ = set_mode(previous);
+    result
+}
+
+/// Estado do escape ANSI em curso.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    /// Recebeu ESC.
+    Start,
+    /// Recebeu `ESC [` e parâmetro numérico.
+    Csi(u8),
+}
+
+struct Editor<'a> {
+    prompt: &'a str,
+    history: Option<&'a History>,
+    /// Posição no histórico (`len` = linha nova).
+    history_pos: usize,
+    /// Linha nova guardada ao navegar no histórico.
+    draft: Vec<char>,
+    line: Vec<char>,
+    cursor: usize,
+    escape: Escape,
+    /// Bytes de um caractere UTF-8 incompleto.
+    utf8: [u8; 4],
+    utf8_len: usize,
+}
+
+impl<'a> Editor<'a> {
+    fn new(prompt: &'a str, history: Option<&'a History>) -> Self {
+        Self {
+            prompt,
+            history,
+            history_pos: history.map_or(0, |h| h.len()),
+            draft: Vec::new(),
+            line: Vec::new(),
+            cursor: 0,
+            escape: Escape::None,
+            utf8: [0; 4],
+            utf8_len: 0,
+        }
+    }
+
+    fn run(&mut self) -> SysResult<String> {
+        let _ = write_str(self.prompt);
+
+        let mut buf = [0u8; 16];
+        loop {
+            let n = read_bytes(&mut buf)?;
+            if n == 0 {
+                // Console fechado: fim de entrada, como Ctrl-D
+                let _ = write_str("\r\n");
+                return Err(SysError::EndOfFile);
+            }
+            for &byte in &buf[..n] {
+                if let Some(done) = self.feed(byte) {
+                    let _ = write_str("\r\n");
+                    return done.map(|()| self.line.iter().collect());
+                }
+            }
+        }
+    }
+
+    /// Processa um byte. Retorna `Some` quando a leitura termina.
+    fn feed(&mut self, byte: u8) -> Option<SysResult<()>> {
+        match self.escape {
+            Escape::Start => {
+                self.escape = if byte == b'[' {
+                    Escape::Csi(0)
+                } else {
+                    Escape::None
+                };
+                return None;
+            }
+            Escape::Csi(param) => {
+                self.escape = Escape::None;
+                match byte {
+                    b'0'..=b'9' => {
+                        self.escape =
+                            Escape::Csi(param.saturating_mul(10).saturating_add(byte - b'0'))
+                    }
+                    b'A' => self.history_prev(),
+                    b'B' => self.history_next(),
+                    b'C' => self.move_to(self.cursor + 1),
+                    b'D' => self.move_to(self.cursor.saturating_sub(1)),
+                    b'H' => self.move_to(0),
+                    b'F' => self.move_to(self.line.len()),
+                    b'~' => match param {
+                        1 | 7 => self.move_to(0),
+                        4 | 8 => self.move_to(self.line.len()),
+                        3 => self.delete_at_cursor(),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+                return None;
+            }
+            Escape::None => {}
+        }
+
+        match byte {
+            b'\r' | b'\n' => return Some(Ok(())),
+            0x1B => self.escape = Escape::Start,
+            0x03 => {
+                self.line.clear();
+                return Some(Err(SysError::Interrupted));
+            }
+            0x04 if self.line.is_empty() => return Some(Err(SysError::EndOfFile)),
+            0x04 => self.delete_at_cursor(),
+            0x01 => self.move_to(0),
+            0x05 => self.move_to(self.line.len()),
+            0x08 | 0x7F => self.backspace(),
+            0x15 => {
+                self.line.drain(..self.cursor);
+                self.cursor = 0;
+                self.redraw();
+            }
+            0x17 => self.delete_word(),
+            0x20..=0x7E => self.insert(byte as char),
+            0x80..=0xFF => self.feed_utf8(byte),
+            _ => {}
+        }
+        None
+    }
+
+    fn feed_utf8(&mut self, byte: u8) {
+        if byte & 0xC0 != 0x80 {
+            // Início de nova sequência
+            self.utf8_len = 0;
+        }
+        if self.utf8_len < self.utf8.len() {
+            self.utf8[self.utf8_len] = byte;
+            self.utf8_len += 1;
+        }
+        if let Ok(s) = core::str::from_utf8(&self.utf8[..self.utf8_len]) {
+            if let Some(c) = s.chars().next() {
+                self.utf8_len = 0;
+                self.insert(c);
+            }
+        }
+    }
+
+    fn insert(&mut self, c: char) {
+        self.line.insert(self.cursor, c);
+        self.cursor += 1;
+        self.redraw();
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.line.remove(self.cursor);
+            self.redraw();
+        }
+    }
+
+    fn delete_at_cursor(&mut self) {
+        if self.cursor < self.line.len() {
+            self.line.remove(self.cursor);
+            self.redraw();
+        }
+    }
+
+    fn delete_word(&mut self) {
+        let mut start = self.cursor;
+        while start > 0 && self.line[start - 1] == ' ' {
+            start -= 1;
+        }
+        while start > 0 && self.line[start - 1] != ' ' {
+            start -= 1;
+        }
+        self.line.drain(start..self.cursor);
+        self.cursor = start;
+        self.redraw();
+    }
+
+    fn move_to(&mut self, pos: usize) {
+        let pos = pos.min(self.line.len());
+        if pos != self.cursor {
+            self.cursor = pos;
+            self.redraw();
+        }
+    }
+
+    fn history_prev(&mut self) {
+        if self.history_pos > 0 {
+            if self.history.is_some_and(|h| self.history_pos == h.len()) {
+                self.draft = core::mem::take(&mut self.line);
+            }
+            self.history_pos -= 1;
+            self.load_history();
+        }
+    }
+
+    fn history_next(&mut self) {
+        let Some(len) = self.history.map(|h| h.len()) else {
+            return;
+        };
+        if self.history_pos < len {
+            self.history_pos += 1;
+            if self.history_pos == len {
+                self.line = core::mem::take(&mut self.draft);
+                self.cursor = self.line.len();
+                self.redraw();
+            } else {
+                self.load_history();
+            }
+        }
+    }
+
+    fn load_history(&mut self) {
+        if let Some(entry) = self.history.and_then(|h| h.get(self.history_pos)) {
+            self.line = entry.chars().collect();
+            self.cursor = self.line.len();
+            self.redraw();
+        }
+    }
+
+    /// Redesenha prompt e linha, posicionando o cursor.
+    fn redraw(&self) {
+        let mut out = String::with_capacity(self.prompt.len() + self.line.len() + 16);
+        out.push('\r');
+        out.push_str(self.prompt);
+        out.extend(self.line.iter());
+        out.push_str("\x1b[K");
+        let back = self.line.len() - self.cursor;
+        if back > 0 {
+            let _ = core::fmt::Write::write_fmt(&mut out, format_args!("\x1b[{}D", back));
+        }
+        let _ = write_str(&out);
+    }
+}
@@ -0,0 +1,425 @@
+//! # Estilos ANSI
+//!
+//! Cores e estilos para saída de console via sequências ANSI.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::console::style::{Color, Stylize};
+//!
+//! println!("{}: arquivo não encontrado", "erro".red().bold());
+//! println!("{}", "ok".fg(Color::Rgb(0, 200, 120)));
+//! ```
+//!
+//! Com saída redirecionada (ou [`set_color_mode`] em `Never`), os valores são
+//! escritos sem escapes.
+
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+// =============================================================================
+// COR
+// =============================================================================
+
+/// Cor de texto ou fundo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// Paleta de 256 cores.
+    Indexed(u8),
+    /// Cor 24 bits.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Escreve os parâmetros SGR da cor (`base` = 30 texto, 40 fundo).
+    fn write_sgr(self, f: &mut fmt::Formatter<'_>, base: u8) -> fmt::Result {
+        match self {
+            Self::Indexed(i) => write!(f, "{};5;{}", base + 8, i),
+            Self::Rgb(r, g, b) => write!(f, "{};2;{};{};{}", base + 8, r, g, b),
+            basic => {
+                let index = match basic {
+                    Self::Black | Self::BrightBlack => 0,
+                    Self::Red | Self::BrightRed => 1,
+                    Self::Green | Self::BrightGreen => 2,
+                    Self::Yellow | Self::BrightYellow => 3,
+                    Self::Blue | Self::BrightBlue => 4,
+                    Self::Magenta | Self::BrightMagenta => 5,
+                    Self::Cyan | Self::BrightCyan => 6,
+                    _ => 7,
+                };
+                let bright = matches!(
+                    basic,
+                    Self::BrightBlack
+                        | Self::BrightRed
+                        | Self::BrightGreen
+                        | Self::BrightYellow
+                        | Self::BrightBlue
+                        | Self::BrightMagenta
+                        | Self::BrightCyan
+                        | Self::BrightWhite
+                );
+                // Cores claras: 90–97 / 100–107
+                let code = if bright { base + 60 } else { base } + index;
+                write!(f, "{}", code)
+            }
+        }
+    }
+}
+
+// =============================================================================
+// ESTILO
+// =============================================================================
+
+/// Combinação de cores e atributos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub reverse: bool,
+}
+
+impl Style {
+    /// Estilo vazio.
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            reverse: false,
+        }
+    }
+
+    /// Define a cor do texto.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Define a cor de fundo.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Negrito.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Esmaecido.
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Itálico.
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Sublinhado.
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Cores invertidas.
+    pub const fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Aplica o estilo a um valor.
+    pub fn apply<T>(self, value: T) -> Styled<T> {
+        Styled { value, style: self }
+    }
+
+    /// Verifica se não altera nada.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Escreve a sequência de início (`ESC[...m`).
+    fn write_prefix(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\x1b[")?;
+        let mut first = true;
+        let mut sep = |f: &mut fmt::Formatter<'_>| {
+            if !core::mem::take(&mut first) {
+                f.write_str(";")?;
+            }
+            Ok(())
+        };
+        for (on, code) in [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+        ] {
+            if on {
+                sep(f)?;
+                f.write_str(code)?;
+            }
+        }
+        if let Some(fg) = self.fg {
+            sep(f)?;
+            fg.write_sgr(f, 30)?;
+        }
+        if let Some(bg) = self.bg {
+            sep(f)?;
+            bg.write_sgr(f, 40)?;
+        }
+        f.write_str("m")
+    }
+}
+
+// =============================================================================
+// VALOR ESTILIZADO
+// =============================================================================
+
+/// Valor com estilo, exibido entre as sequências ANSI.
+#[derive(Debug, Clone, Copy)]
+pub struct Styled<T> {
+    value: T,
+    style: Style,
+}
+
+macro_rules! styled_methods {
+    ($($name:ident => $color:ident),* $(,)?) => {
+        $(
+            /// Define a cor do texto.
+            pub fn $name(self) -> Self {
+                self.fg(Color::$color)
+            }
+        )*
+    };
+}
+
+impl<T> Styled<T> {
+    /// Valor original.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Estilo aplicado.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Define a cor do texto.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.style = self.style.fg(color);
+        self
+    }
+
+    /// Define a cor de fundo.
+    pub fn on(mut self, color: Color) -> Self {
+        self.style = self.style.bg(color);
+        self
+    }
+
+    /// Negrito.
+    pub fn bold(mut self) -> Self {
+        self.style = self.style.bold();
+        self
+    }
+
+    /// Esmaecido.
+    pub fn dim(mut self) -> Self {
+        self.style = self.style.dim();
+        self
+    }
+
+    /// Itálico.
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.italic();
+        self
+    }
+
+    /// Sublinhado.
+    pub fn underline(mut self) -> Self {
+        self.style = self.style.underline();
+        self
+    }
+
+    /// Cores invertidas.
+    pub fn reverse(mut self) -> Self {
+        self.style = self.style.reverse();
+        self
+    }
+
+    styled_methods! {
+        black => Black,
+        red => Red,
+        green => Green,
+        yellow => Yellow,
+        blue => Blue,
+        magenta => Magenta,
+        cyan => Cyan,
+        white => White,
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.style.is_plain() || !colors_enabled() {
+            return self.value.fmt(f);
+        }
+        self.style.write_prefix(f)?;
+        self.value.fmt(f)?;
+        f.write_str("\x1b[0m")
+    }
+}
+
+// =============================================================================
+// EXTENSÃO
+// =============================================================================
+
+/// Métodos de estilo para qualquer valor exibível (`"erro".red().bold()`).
+pub trait Stylize: Sized {
+    /// Aplica um estilo completo.
+    fn styled(self, style: Style) -> Styled<Self> {
+        style.apply(self)
+    }
+
+    /// Define a cor do texto.
+    fn fg(self, color: Color) -> Styled<Self> {
+        Style::new().fg(color).apply(self)
+    }
+
+    /// Define a cor de fundo.
+    fn on(self, color: Color) -> Styled<Self> {
+        Style::new().bg(color).apply(self)
+    }
+
+    /// Negrito.
+    fn bold(self) -> Styled<Self> {
+        Style::new().bold().apply(self)
+    }
+
+    /// Esmaecido.
+    fn dim(self) -> Styled<Self> {
+        Style::new().dim().apply(self)
+    }
+
+    /// Itálico.
+    fn italic(self) -> Styled<Self> {
+        Style::new().italic().apply(self)
+    }
+
+    /// Sublinhado.
+    fn underline(self) -> Styled<Self> {
+        Style::new().underline().apply(self)
+    }
+
+    /// Cores invertidas.
+    fn reverse(self) -> Styled<Self> {
+        Style::new().reverse().apply(self)
+    }
+
+    /// Texto preto.
+    fn black(self) -> Styled<Self> {
+        self.fg(Color::Black)
+    }
+
+    /// Texto vermelho.
+    fn red(self) -> Styled<Self> {
+        self.fg(Color::Red)
+    }
+
+    /// Texto verde.
+    fn green(self) -> Styled<Self> {
+        self.fg(Color::Green)
+    }
+
+    /// Texto amarelo.
+    fn yellow(self) -> Styled<Self> {
+        self.fg(Color::Yellow)
+    }
+
+    /// Texto azul.
+    fn blue(self) -> Styled<Self> {
+        self.fg(Color::Blue)
+    }
+
+    /// Texto magenta.
+    fn magenta(self) -> Styled<Self> {
+        self.fg(Color::Magenta)
+    }
+
+    /// Texto ciano.
+    fn cyan(self) -> Styled<Self> {
+        self.fg(Color::Cyan)
+    }
+
+    /// Texto branco.
+    fn white(self) -> Styled<Self> {
+        self.fg(Color::White)
+    }
+}
+
+impl<T: fmt::Display> Stylize for T {}
+
+// =============================================================================
+// DETECÇÃO
+// =============================================================================
+
+/// Política de emissão de escapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ColorMode {
+    /// Apenas quando a saída padrão é a console (não redirecionada).
+    Auto = 0,
+    Always = 1,
+    Never = 2,
+}
+
+static COLOR_MODE: AtomicU8 = AtomicU8::new(ColorMode::Auto as u8);
+
+/// Define a política de cores do processo.
+pub fn set_color_mode(mode: ColorMode) {
+    COLOR_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Política de cores atual.
+pub fn color_mode() -> ColorMode {
+    match COLOR_MODE.load(Ordering::Relaxed) {
+        1 => ColorMode::Always,
+        2 => ColorMode::Never,
+        _ => ColorMode::Auto,
+    }
+}
+
+/// Retorna se os escapes ANSI serão emitidos.
+pub fn colors_enabled() -> bool {
+    match color_mode() {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => crate::io::stdout().handle().is_none(),
+    }
+}
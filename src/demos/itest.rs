@@ -0,0 +1,84 @@
+//! # Integration Test Runner
+//!
+//! Runner mínimo para testes que só fazem sentido dentro do Redstone OS
+//! (syscalls reais). Cada teste imprime uma linha no console:
+//!
+//! ```text
+//! itest: ipc_port_roundtrip ... ok
+//! itest: fs_roundtrip ... FAILED (NotFound)
+//! itest: result: 7 passed; 1 failed
+//! ```
+//!
+//! O CI procura a linha `itest: result:` e usa o código de saída de
+//! [`run_and_exit`] (número de falhas). Um panic encerra o processo com
+//! `PANIC_EXIT_CODE` antes da linha de resultado.
+
+use crate::process;
+use crate::syscall::SysResult;
+use crate::time::Stopwatch;
+
+/// Prefixo das linhas de saída.
+const PREFIX: &str = "itest:";
+
+/// Teste de integração.
+#[derive(Clone, Copy)]
+pub struct TestCase {
+    pub name: &'static str,
+    pub run: fn() -> SysResult<()>,
+}
+
+impl TestCase {
+    pub const fn new(name: &'static str, run: fn() -> SysResult<()>) -> Self {
+        Self { name, run }
+    }
+}
+
+/// Resultado de uma execução.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl Summary {
+    /// Nenhuma falha.
+    pub fn ok(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Roda os testes em ordem, imprimindo uma linha por teste.
+pub fn run(tests: &[TestCase]) -> Summary {
+    let mut summary = Summary::default();
+    crate::println!("{} running {} tests", PREFIX, tests.len());
+
+    for test in tests {
+        let sw = Stopwatch::start();
+        let result = (test.run)();
+        let elapsed = sw.elapsed();
+        match result {
+            Ok(()) => {
+                summary.passed += 1;
+                crate::println!("{} {} ... ok ({:?})", PREFIX, test.name, elapsed);
+            }
+            Err(err) => {
+                summary.failed += 1;
+                crate::println!("{} {} ... FAILED ({:?})", PREFIX, test.name, err);
+            }
+        }
+    }
+
+    crate::println!(
+        "{} result: {} passed; {} failed",
+        PREFIX,
+        summary.passed,
+        summary.failed
+    );
+    summary
+}
+
+/// [`run`] e sai com o número de falhas como código.
+pub fn run_and_exit(tests: &[TestCase]) -> ! {
+    let summary = run(tests);
+    process::exit(summary.failed.min(i32::MAX as usize) as i32)
+}
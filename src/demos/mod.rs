@@ -0,0 +1,33 @@
+//! # Demos
+//!
+//! Apoio aos apps de exemplo (`examples/`) e ao runner de testes de
+//! integração que o CI do kernel boota e executa (feature `demos`).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`itest`] | Runner de testes com saída parseável pelo CI |
+//! | [`suite`] | Testes das syscalls do SDK (processo, memória, IPC, fs, tempo) |
+//!
+//! ## Binários
+//!
+//! | Exemplo | Descrição |
+//! |---------|-----------|
+//! | `hello-window` | Abre uma janela, desenha e fecha ao pedido do usuário |
+//! | `port-echo` | Servidor de eco numa porta IPC nomeada |
+//! | `fs-stress` | Cria, escreve, relê e remove muitos arquivos |
+//! | `itest` | Roda [`suite::ALL`] e sai com o número de falhas |
+//!
+//! ```text
+//! cargo build --release --features demos --examples
+//! ```
+
+pub mod itest;
+pub mod suite;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use itest::{run, run_and_exit, Summary, TestCase};
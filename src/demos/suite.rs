@@ -0,0 +1,112 @@
+//! # Syscall Suite
+//!
+//! Testes de integração dos caminhos de syscall do SDK. Cada teste limpa o
+//! que cria (portas, arquivos, memória).
+
+use core::time::Duration;
+
+use crate::event::{events, poll, PollFd};
+use crate::fs::{self, File};
+use crate::ipc::{str_from_fixed, Port, SharedMemory};
+use crate::mem;
+use crate::process;
+use crate::syscall::{SysError, SysResult};
+use crate::time::{sleep_for, Instant, Interval};
+
+use super::itest::TestCase;
+
+/// Arquivo temporário de [`fs_roundtrip`].
+const TEMP_FILE: &str = "/tmp/itest.txt";
+
+/// Todos os testes, na ordem de execução.
+pub const ALL: &[TestCase] = &[
+    TestCase::new("process_getpid", process_getpid),
+    TestCase::new("time_monotonic", time_monotonic),
+    TestCase::new("time_interval", time_interval),
+    TestCase::new("mem_alloc_free", mem_alloc_free),
+    TestCase::new("shm_roundtrip", shm_roundtrip),
+    TestCase::new("ipc_port_roundtrip", ipc_port_roundtrip),
+    TestCase::new("event_poll_port", event_poll_port),
+    TestCase::new("fs_roundtrip", fs_roundtrip),
+];
+
+/// Falha se a condição não vale.
+fn check(cond: bool) -> SysResult<()> {
+    if cond {
+        Ok(())
+    } else {
+        Err(SysError::ProtocolError)
+    }
+}
+
+pub fn process_getpid() -> SysResult<()> {
+    check(process::getpid() > 0)
+}
+
+pub fn time_monotonic() -> SysResult<()> {
+    let start = Instant::now()?;
+    sleep_for(Duration::from_millis(2))?;
+    check(start.elapsed()? >= Duration::from_millis(2))
+}
+
+pub fn time_interval() -> SysResult<()> {
+    let interval = Interval::every(Duration::from_millis(5))?;
+    check(interval.tick()? >= 1)
+}
+
+pub fn mem_alloc_free() -> SysResult<()> {
+    const SIZE: usize = 4096;
+    let ptr = mem::alloc(SIZE, mem::flags::ZEROED)?;
+    let buf = unsafe { core::slice::from_raw_parts_mut(ptr, SIZE) };
+    let zeroed = buf.iter().all(|&b| b == 0);
+    buf.fill(0xA5);
+    let written = buf.iter().all(|&b| b == 0xA5);
+    // SAFETY: ptr veio de mem::alloc e buf não é mais usado
+    unsafe { mem::free(ptr, SIZE)? };
+    check(zeroed && written)
+}
+
+pub fn shm_roundtrip() -> SysResult<()> {
+    let mut shm = SharedMemory::create(4096)?;
+    shm.as_mut_slice()[..4].copy_from_slice(b"shm!");
+    let other = SharedMemory::open(shm.id())?;
+    check(&other.as_slice()[..4] == b"shm!")
+}
+
+pub fn ipc_port_roundtrip() -> SysResult<()> {
+    let (server, name) = Port::create_unique("itest.", 4)?;
+    let client = Port::connect(str_from_fixed(&name))?;
+    client.send(b"ping", 0)?;
+
+    let mut buf = [0u8; 16];
+    let len = server.recv(&mut buf, 1000)?;
+    check(&buf[..len] == b"ping")
+}
+
+pub fn event_poll_port() -> SysResult<()> {
+    let (server, name) = Port::create_unique("itest.", 4)?;
+    let mut fds = [PollFd::new(server.handle(), events::IN)];
+    check(poll(&mut fds, 0)? == 0)?;
+
+    Port::connect(str_from_fixed(&name))?.send(b"x", 0)?;
+    let mut fds = [PollFd::new(server.handle(), events::IN)];
+    check(poll(&mut fds, 1000)? == 1 && fds[0].has_event(events::IN))
+}
+
+pub fn fs_roundtrip() -> SysResult<()> {
+    const DATA: &[u8] = b"redpowder itest\n";
+    let mut buf = [0u8; 32];
+    let written = (|| -> SysResult<u64> {
+        File::create(TEMP_FILE)?.write_all(DATA)?;
+        let file = File::open(TEMP_FILE)?;
+        let size = file.size()?;
+        file.read_exact(&mut buf[..DATA.len()])?;
+        Ok(size)
+    })();
+
+    // Remove o arquivo mesmo que a escrita ou a leitura tenha falhado
+    let removed = fs::ops::remove(TEMP_FILE);
+    let size = written?;
+    removed?;
+    check(size == DATA.len() as u64 && &buf[..DATA.len()] == DATA && !fs::exists(TEMP_FILE))
+}
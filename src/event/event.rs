@@ -2,9 +2,12 @@
 //!
 //! Multiplexação de I/O.
 
+use crate::graphics::ScaleFactor;
+use crate::input::{RawTouchPoint, TouchPoint};
 use crate::io::Handle;
-use crate::syscall::SYS_POLL;
-use crate::syscall::{check_error, syscall3, SysResult};
+use crate::ipc::str_from_fixed;
+use crate::syscall::safe::sys_poll;
+use crate::syscall::SysResult;
 
 /// Eventos de poll
 pub mod events {
@@ -13,6 +16,7 @@ pub mod events {
     pub const ERR: u16 = 1 << 2; // Erro
     pub const HUP: u16 = 1 << 3; // Hangup
     pub const NVAL: u16 = 1 << 4; // Handle inválido
+    pub const EDGE: u16 = 1 << 15; // Só na borda (apenas EventQueue)
 }
 
 /// Descritor de poll
@@ -49,13 +53,7 @@ impl PollFd {
 /// # Returns
 /// Número de handles com eventos
 pub fn poll(fds: &mut [PollFd], timeout_ms: i64) -> SysResult<usize> {
-    let ret = syscall3(
-        SYS_POLL,
-        fds.as_mut_ptr() as usize,
-        fds.len(),
-        timeout_ms as usize,
-    );
-    check_error(ret)
+    sys_poll(fds, timeout_ms)
 }
 
 // ============================================================================
@@ -69,6 +67,8 @@ pub mod event_type {
     pub const MOUSE_MOVE: u32 = 3;
     pub const MOUSE_DOWN: u32 = 4;
     pub const MOUSE_UP: u32 = 5;
+    /// Movimento relativo com o ponteiro travado (`Window::lock_pointer`).
+    pub const MOUSE_RELATIVE: u32 = 6;
 }
 
 #[repr(C)]
@@ -77,7 +77,15 @@ pub struct InputEvent {
     pub op: u32,         // EVENT_INPUT
     pub event_type: u32, // event_type constants
     pub param1: u32,     // KeyCode ou MouseX
-    pub param2: u32,     // Modifiers ou MouseY
+    pub param2: u32,     // Modifiers (input::Modifiers) ou MouseY
+}
+
+impl InputEvent {
+    /// Deslocamento (dx, dy) de um `MOUSE_RELATIVE`, sem aceleração.
+    pub fn relative_motion(&self) -> Option<(i32, i32)> {
+        (self.event_type == event_type::MOUSE_RELATIVE)
+            .then_some((self.param1 as i32, self.param2 as i32))
+    }
 }
 
 #[repr(C)]
@@ -88,10 +96,225 @@ pub struct ResizeEvent {
     pub height: u32,
 }
 
+/// Frame apresentado (resposta a `Window::request_frame`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FrameEvent {
+    pub op: u32, // EVENT_FRAME
+    pub window_id: u32,
+    /// Instante da apresentação (ns, clock monotônico).
+    pub timestamp_ns: u64,
+    /// Taxa do monitor que apresentou o frame (mHz).
+    pub refresh_rate_mhz: u32,
+}
+
+/// Mudança de tiling aplicada pelo compositor.
+///
+/// Chega antes do [`ResizeEvent`] correspondente.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TilingEvent {
+    pub op: u32, // EVENT_TILING
+    pub window_id: u32,
+    /// Estado (`window::TilingState::from_u32`).
+    pub state: u32,
+}
+
+/// Janela ganhou ou perdeu o foco do teclado.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FocusEvent {
+    pub op: u32, // EVENT_FOCUS
+    pub window_id: u32,
+    /// 1 = ganhou o foco, 0 = perdeu.
+    pub focused: u32,
+}
+
+/// Tamanho máximo do texto de um [`TextInputEvent`] (bytes UTF-8).
+pub const TEXT_INPUT_MAX: usize = 32;
+
+/// Texto composto pelo compositor (layout, modificadores, teclas mortas e
+/// IME já aplicados), entregue com a entrada de texto habilitada
+/// (`Window::start_text_input`).
+///
+/// Textos maiores chegam em vários eventos, sempre divididos entre
+/// caracteres. O [`InputEvent`] da tecla continua sendo entregue.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TextInputEvent {
+    pub op: u32, // EVENT_TEXT_INPUT
+    pub window_id: u32,
+    /// Bytes usados em `text`.
+    pub len: u32,
+    pub text: [u8; TEXT_INPUT_MAX],
+}
+
+impl TextInputEvent {
+    /// Texto do evento (vazio se inválido).
+    pub fn text(&self) -> &str {
+        let len = (self.len as usize).min(TEXT_INPUT_MAX);
+        core::str::from_utf8(&self.text[..len]).unwrap_or("")
+    }
+}
+
+/// Escala da janela mudou (ex.: movida para um monitor HiDPI).
+///
+/// O app deve redesenhar na nova escala; o [`ResizeEvent`] com o novo
+/// tamanho físico do buffer chega em seguida.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScaleChangedEvent {
+    pub op: u32, // EVENT_SCALE_CHANGED
+    pub window_id: u32,
+    /// Nova escala em milésimos.
+    pub scale_milli: u32,
+}
+
+impl ScaleChangedEvent {
+    /// Nova escala.
+    pub fn scale(&self) -> ScaleFactor {
+        ScaleFactor::from_milli(self.scale_milli)
+    }
+}
+
+/// Máximo de pontos em um [`TouchEvent`].
+pub const TOUCH_MAX_POINTS: usize = 8;
+
+/// Pontos de toque que mudaram desde o último evento (multi-toque).
+///
+/// Posições em coordenadas da janela. Um toque que começou na janela
+/// continua sendo entregue a ela até o `End`/`Cancel`, mesmo fora dela.
+/// Mais de [`TOUCH_MAX_POINTS`] mudanças simultâneas chegam em vários
+/// eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TouchEvent {
+    pub op: u32, // EVENT_TOUCH
+    pub window_id: u32,
+    /// Entradas usadas em `points`.
+    pub count: u32,
+    pub points: [RawTouchPoint; TOUCH_MAX_POINTS],
+}
+
+impl TouchEvent {
+    /// Pontos do evento (fases inválidas são descartadas).
+    pub fn points(&self) -> impl Iterator<Item = TouchPoint> + '_ {
+        let count = (self.count as usize).min(TOUCH_MAX_POINTS);
+        self.points[..count]
+            .iter()
+            .filter_map(RawTouchPoint::to_point)
+    }
+}
+
+/// Controle conectado ou desconectado (ver `input::gamepad`).
+///
+/// Entregue a todas as janelas; o estado é lido com `poll_gamepads`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GamepadEvent {
+    pub op: u32, // EVENT_GAMEPAD
+    pub window_id: u32,
+    /// Slot do controle (0..MAX_GAMEPADS).
+    pub slot: u32,
+    /// 1 = conectado, 0 = desconectado.
+    pub connected: u32,
+    /// Nome do dispositivo (UTF-8, terminado em zero).
+    pub name: [u8; 32],
+}
+
+impl GamepadEvent {
+    /// Conectado (true) ou desconectado (false).
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.connected != 0
+    }
+
+    /// Nome do dispositivo.
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.name)
+    }
+}
+
+/// Ponteiro travado ou liberado (resposta a `Window::lock_pointer`).
+///
+/// Também chega quando o compositor quebra a trava (Esc, perda de foco).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PointerLockEvent {
+    pub op: u32, // EVENT_POINTER_LOCK
+    pub window_id: u32,
+    /// 1 = travado, 0 = liberado ou negado.
+    pub locked: u32,
+}
+
+impl PointerLockEvent {
+    /// Ponteiro travado.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.locked != 0
+    }
+}
+
+/// Mudança de estado (maximizada, fullscreen) decidida pelo compositor.
+///
+/// Responde a `set_fullscreen`, `maximize` e `unmaximize`, e também chega
+/// quando o usuário muda o estado pela decoração. Se concedido, é seguido
+/// do [`ResizeEvent`] correspondente.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StateChangedEvent {
+    pub op: u32, // EVENT_STATE_CHANGED
+    pub window_id: u32,
+    /// Estado atual (`window::WindowState::from_u8`).
+    pub state: u32,
+    /// 0 se o pedido foi negado (o estado não mudou).
+    pub granted: u32,
+    /// Nova geometria (coordenadas de tela).
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Evento de drag-and-drop (ver `window::dnd`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndEvent {
+    pub op: u32, // EVENT_DND
+    /// Tipo (`window::dnd_events`).
+    pub event_type: u32,
+    pub window_id: u32,
+    /// Identifica a sessão de arraste.
+    pub serial: u32,
+    /// Posição do ponteiro na janela.
+    pub x: i32,
+    pub y: i32,
+    /// Ações oferecidas (`ENTER`) ou escolhida (`DROP`, `FINISHED`).
+    pub actions: u32,
+    /// Entradas usadas em `mimes`.
+    pub mime_count: u32,
+    /// Tipos oferecidos (`ENTER`) ou pedido (`SEND`).
+    pub mimes: [[u8; 32]; 4],
+}
+
 /// Enum de Eventos de Alto Nível para a API
 #[derive(Debug, Clone, Copy)]
 pub enum Event {
     Input(InputEvent),
     Resize(ResizeEvent),
+    Frame(FrameEvent),
+    Tiling(TilingEvent),
+    StateChanged(StateChangedEvent),
+    ScaleChanged(ScaleChangedEvent),
+    Focus(FocusEvent),
+    TextInput(TextInputEvent),
+    Touch(TouchEvent),
+    Gamepad(GamepadEvent),
+    PointerLock(PointerLockEvent),
+    Dnd(DndEvent),
+    /// O usuário pediu para fechar a janela.
+    Close,
+    /// O compositor fechou o popup (clique fora, Esc); destrua a janela.
+    PopupDismissed,
     Unknown,
 }
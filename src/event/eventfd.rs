@@ -0,0 +1,119 @@
+//! # EventFd
+//!
+//! Contador do kernel para acordar outra thread (estilo eventfd).
+//!
+//! [`signal`](EventFd::signal) soma ao contador e deixa o handle pronto
+//! para leitura; [`reset`](EventFd::reset) lê e zera. Como é um handle
+//! comum, entra em [`poll`](super::poll), [`EventQueue`](super::EventQueue)
+//! e no executor junto com arquivos e portas.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::{events, poll, EventFd, PollFd};
+//!
+//! let wakeup = EventFd::new()?;
+//! let remote = wakeup.try_clone()?;
+//! spawn_thread(move || {
+//!     produce();
+//!     remote.signal()?;
+//! });
+//!
+//! let mut fds = [port.poll_fd(), wakeup.poll_fd()];
+//! poll(&mut fds, -1)?;
+//! if wakeup.reset()? > 0 {
+//!     consume();
+//! }
+//! ```
+
+use crate::io::Handle;
+use crate::syscall::safe::{
+    sys_eventfd_create, sys_handle_close, sys_handle_dup, sys_read, sys_write,
+};
+use crate::syscall::SysResult;
+
+use super::{events, poll, PollFd};
+
+/// Contador sinalizável entre threads.
+pub struct EventFd {
+    handle: Handle,
+}
+
+impl EventFd {
+    /// Cria contador zerado.
+    pub fn new() -> SysResult<Self> {
+        Ok(Self {
+            handle: sys_eventfd_create(0)?,
+        })
+    }
+
+    /// Soma 1 ao contador, acordando quem espera.
+    pub fn signal(&self) -> SysResult<()> {
+        self.add(1)
+    }
+
+    /// Soma `n` ao contador (satura no kernel).
+    pub fn add(&self, n: u64) -> SysResult<()> {
+        sys_write(self.handle, &n.to_ne_bytes())?;
+        Ok(())
+    }
+
+    /// Lê e zera o contador sem bloquear (0 se não sinalizado).
+    pub fn reset(&self) -> SysResult<u64> {
+        let mut fds = [self.poll_fd()];
+        if poll(&mut fds, 0)? == 0 || !fds[0].has_event(events::IN) {
+            return Ok(0);
+        }
+
+        let mut value = [0u8; 8];
+        match sys_read(self.handle, &mut value)? {
+            8 => Ok(u64::from_ne_bytes(value)),
+            _ => Ok(0),
+        }
+    }
+
+    /// Bloqueia até ser sinalizado; retorna o contador (≥ 1) e o zera.
+    pub fn wait(&self) -> SysResult<u64> {
+        loop {
+            let mut fds = [self.poll_fd()];
+            poll(&mut fds, -1)?;
+            match self.reset()? {
+                0 => continue,
+                n => return Ok(n),
+            }
+        }
+    }
+
+    /// [`wait`](Self::wait) assíncrono (suspende a tarefa no executor).
+    pub async fn wait_async(&self) -> SysResult<u64> {
+        loop {
+            match self.reset()? {
+                0 => crate::task::ready(&self.handle, events::IN).await?,
+                n => return Ok(n),
+            };
+        }
+    }
+
+    /// Outro handle para o mesmo contador (para mover a outra thread).
+    pub fn try_clone(&self) -> SysResult<Self> {
+        Ok(Self {
+            handle: sys_handle_dup(self.handle)?,
+        })
+    }
+
+    /// Descritor para `event::poll` (pronto para leitura se sinalizado).
+    pub fn poll_fd(&self) -> PollFd {
+        PollFd::new(&self.handle, events::IN)
+    }
+
+    /// Handle do contador.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
+
+impl Drop for EventFd {
+    fn drop(&mut self) {
+        let _ = sys_handle_close(self.handle);
+    }
+}
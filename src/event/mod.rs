@@ -1,5 +1,11 @@
 //! # Events
 
 mod event;
+mod eventfd;
+mod queue;
+mod source;
 
 pub use event::*;
+pub use eventfd::EventFd;
+pub use queue::{eq_ctl, EventQueue, QueueEvent};
+pub use source::Source;
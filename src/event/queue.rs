@@ -0,0 +1,161 @@
+//! # Event Queue
+//!
+//! Fila de eventos persistente (estilo epoll).
+//!
+//! Com [`poll`](super::poll) o array inteiro de handles é reenviado ao
+//! kernel a cada chamada. Na `EventQueue` os handles são registrados uma
+//! vez, cada um com um `token` escolhido pelo app, e [`wait`](EventQueue::wait)
+//! devolve só os prontos — o custo não cresce com o número de clientes.
+//!
+//! Por padrão a fila é por nível: um handle pronto aparece em todo `wait`
+//! até ser drenado. Com [`events::EDGE`] ele aparece uma vez por transição
+//! para pronto, e o app deve ler até `WouldBlock`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::{events, EventQueue, QueueEvent};
+//!
+//! const LISTENER: u64 = 0;
+//!
+//! let queue = EventQueue::new()?;
+//! queue.add(listener.handle(), events::IN, LISTENER)?;
+//!
+//! let mut ready = [QueueEvent::default(); 32];
+//! loop {
+//!     for event in &ready[..queue.wait(&mut ready, -1)?] {
+//!         match event.token {
+//!             LISTENER => {
+//!                 let client = listener.accept()?;
+//!                 queue.add(client.handle(), events::IN | events::EDGE, next_token())?;
+//!             }
+//!             token => serve(token, event.events),
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::io::Handle;
+use crate::syscall::safe::{sys_eq_create, sys_eq_ctl, sys_eq_wait, sys_handle_close};
+use crate::syscall::SysResult;
+
+use super::{events, PollFd};
+
+/// Operações de `SYS_EQ_CTL`.
+pub mod eq_ctl {
+    pub const ADD: usize = 1;
+    pub const MODIFY: usize = 2;
+    pub const REMOVE: usize = 3;
+}
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Evento de uma [`EventQueue`]: o handle com `token` está pronto.
+///
+/// Também é o formato de registro passado ao kernel (`events` = interesse).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueEvent {
+    /// Token informado no registro.
+    pub token: u64,
+    /// Eventos ocorridos (`events::IN`...; `ERR`/`HUP` sempre reportados).
+    pub events: u16,
+    pub _pad: [u16; 3],
+}
+
+impl QueueEvent {
+    /// Verifica se evento ocorreu
+    #[inline]
+    pub fn has_event(&self, event: u16) -> bool {
+        (self.events & event) != 0
+    }
+}
+
+// =============================================================================
+// EVENT QUEUE
+// =============================================================================
+
+/// Conjunto persistente de handles esperados.
+pub struct EventQueue {
+    handle: Handle,
+}
+
+impl EventQueue {
+    /// Cria fila vazia.
+    pub fn new() -> SysResult<Self> {
+        Ok(Self {
+            handle: sys_eq_create(0)?,
+        })
+    }
+
+    /// Registra `handle` com os eventos de interesse (`events::IN`,
+    /// `events::OUT`, `events::EDGE`...). `token` identifica o handle nos
+    /// eventos retornados.
+    pub fn add(&self, handle: &Handle, interest: u16, token: u64) -> SysResult<()> {
+        self.ctl(eq_ctl::ADD, handle, interest, token)
+    }
+
+    /// Troca interesse e token de um handle registrado.
+    pub fn modify(&self, handle: &Handle, interest: u16, token: u64) -> SysResult<()> {
+        self.ctl(eq_ctl::MODIFY, handle, interest, token)
+    }
+
+    /// Remove `handle` da fila. Handles fechados saem sozinhos.
+    pub fn remove(&self, handle: &Handle) -> SysResult<()> {
+        self.ctl(eq_ctl::REMOVE, handle, 0, 0)
+    }
+
+    fn ctl(&self, op: usize, handle: &Handle, interest: u16, token: u64) -> SysResult<()> {
+        let event = QueueEvent {
+            token,
+            events: interest,
+            _pad: [0; 3],
+        };
+        sys_eq_ctl(self.handle, op, *handle, &event)
+    }
+
+    /// Espera handles prontos.
+    ///
+    /// # Args
+    /// - events: recebe um evento por handle pronto
+    /// - timeout_ms: timeout (-1 = infinito, 0 = não bloqueia)
+    ///
+    /// # Returns
+    /// Número de eventos escritos (0 no timeout)
+    pub fn wait(&self, events: &mut [QueueEvent], timeout_ms: i64) -> SysResult<usize> {
+        if events.is_empty() {
+            return Ok(0);
+        }
+        sys_eq_wait(self.handle, events, timeout_ms)
+    }
+
+    /// [`wait`](Self::wait) assíncrono (suspende a tarefa no executor).
+    pub async fn wait_async(&self, out: &mut [QueueEvent]) -> SysResult<usize> {
+        loop {
+            match self.wait(out, 0)? {
+                0 if !out.is_empty() => {
+                    crate::task::ready(&self.handle, events::IN).await?;
+                }
+                n => return Ok(n),
+            }
+        }
+    }
+
+    /// Descritor para `event::poll` (pronto quando há eventos na fila).
+    pub fn poll_fd(&self) -> PollFd {
+        PollFd::new(&self.handle, events::IN)
+    }
+
+    /// Handle da fila.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
+
+impl Drop for EventQueue {
+    fn drop(&mut self) {
+        let _ = sys_handle_close(self.handle);
+    }
+}
@@ -0,0 +1,80 @@
+//! # Source
+//!
+//! Interface comum dos objetos do kernel que podem ser esperados.
+//!
+//! Portas, arquivos, timers, sockets e [`EventFd`] são todos handles
+//! polláveis; [`Source`] expõe isso de forma uniforme para
+//! [`EventQueue`], o reactor de `task` e o `window::EventLoop`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::{events, EventQueue, Source};
+//!
+//! fn watch(queue: &EventQueue, sources: &[&dyn Source]) -> SysResult<()> {
+//!     for (token, source) in sources.iter().enumerate() {
+//!         source.register(queue, events::IN, token as u64)?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::fs::File;
+use crate::io::Handle;
+use crate::ipc::Port;
+use crate::net::{LocalListener, LocalStream, TcpListener, TcpStream};
+use crate::process::signals::Signals;
+use crate::syscall::SysResult;
+use crate::task::{ready, Ready};
+use crate::time::Interval;
+
+use super::{EventFd, EventQueue};
+
+/// Objeto do kernel esperável por handle.
+pub trait Source {
+    /// Handle esperado.
+    fn handle(&self) -> &Handle;
+
+    /// Registra na fila com os eventos de interesse (`events::IN`...).
+    fn register(&self, queue: &EventQueue, interest: u16, token: u64) -> SysResult<()> {
+        queue.add(self.handle(), interest, token)
+    }
+
+    /// Troca interesse e token do registro.
+    fn reregister(&self, queue: &EventQueue, interest: u16, token: u64) -> SysResult<()> {
+        queue.modify(self.handle(), interest, token)
+    }
+
+    /// Remove da fila.
+    fn deregister(&self, queue: &EventQueue) -> SysResult<()> {
+        queue.remove(self.handle())
+    }
+
+    /// Future que completa quando há algum dos `interest` (reactor de `task`).
+    fn ready(&self, interest: u16) -> Ready {
+        ready(self.handle(), interest)
+    }
+}
+
+macro_rules! impl_source {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Source for $ty {
+            fn handle(&self) -> &Handle {
+                <$ty>::handle(self)
+            }
+        })*
+    };
+}
+
+impl_source!(
+    Port,
+    File,
+    Interval,
+    EventFd,
+    EventQueue,
+    TcpListener,
+    TcpStream,
+    LocalListener,
+    LocalStream,
+    Signals,
+);
@@ -24,9 +24,8 @@
 #[allow(unused)]
 use super::types::{DirEntry, OpenFlags, O_DIRECTORY, O_RDONLY};
 use crate::io::Handle;
-use crate::syscall::{
-    check_error, syscall1, syscall3, syscall4, SysResult, SYS_GETDENTS, SYS_HANDLE_CLOSE, SYS_OPEN,
-};
+use crate::syscall::safe::{sys_getdents, sys_handle_close, sys_open};
+use crate::syscall::SysResult;
 
 /// Diretório aberto
 ///
@@ -51,15 +50,7 @@ impl Dir {
     /// let dir = Dir::open("/apps")?;
     /// ```
     pub fn open(path: &str) -> SysResult<Self> {
-        let flags = O_RDONLY | O_DIRECTORY;
-        let ret = syscall4(
-            SYS_OPEN,
-            path.as_ptr() as usize,
-            path.len(),
-            flags as usize,
-            0,
-        );
-        let handle = Handle::from_raw(check_error(ret)? as u32);
+        let handle = sys_open(path, OpenFlags::new(O_RDONLY | O_DIRECTORY))?;
 
         let mut dir = Self {
             handle,
@@ -86,13 +77,7 @@ impl Dir {
     /// Retorna o número de bytes escritos no buffer (0 se não há mais entradas).
     /// O buffer contém structs DirEntry serializadas.
     pub fn read_raw(&self, buf: &mut [u8]) -> SysResult<usize> {
-        let ret = syscall3(
-            SYS_GETDENTS,
-            self.handle.raw() as usize,
-            buf.as_mut_ptr() as usize,
-            buf.len(),
-        );
-        check_error(ret)
+        sys_getdents(self.handle, buf)
     }
 
     /// Cria um iterador sobre as entradas do diretório
@@ -139,7 +124,7 @@ impl Dir {
 
 impl Drop for Dir {
     fn drop(&mut self) {
-        let _ = syscall1(SYS_HANDLE_CLOSE, self.handle.raw() as usize);
+        let _ = sys_handle_close(self.handle);
     }
 }
 
@@ -22,10 +22,11 @@ use super::types::{
     FileStat, OpenFlags, SeekFrom, O_CREATE, O_DIRECTORY, O_RDONLY, O_TRUNC, O_WRONLY,
 };
 use crate::io::Handle;
-use crate::syscall::{
-    check_error, syscall1, syscall2, syscall3, syscall4, SysResult, SYS_FLUSH, SYS_FSTAT,
-    SYS_HANDLE_CLOSE, SYS_OPEN, SYS_PREAD, SYS_PWRITE, SYS_READ, SYS_SEEK, SYS_TRUNCATE, SYS_WRITE,
+use crate::syscall::safe::{
+    sys_flush, sys_fstat, sys_handle_close, sys_open, sys_pread, sys_pwrite, sys_read, sys_seek,
+    sys_truncate, sys_write,
 };
+use crate::syscall::SysResult;
 
 /// Arquivo aberto
 ///
@@ -58,14 +59,7 @@ impl File {
             return Err(crate::syscall::SysError::InvalidArgument);
         }
 
-        let ret = syscall4(
-            SYS_OPEN,
-            path.as_ptr() as usize,
-            path.len(),
-            flags.0 as usize,
-            0, // mode
-        );
-        let handle = Handle::from_raw(check_error(ret)? as u32);
+        let handle = sys_open(path, flags)?;
         Ok(Self { handle, flags })
     }
 
@@ -103,27 +97,14 @@ impl File {
     /// # Retorno
     /// Número de bytes lidos, ou 0 para EOF.
     pub fn read(&self, buf: &mut [u8]) -> SysResult<usize> {
-        let ret = syscall3(
-            SYS_READ,
-            self.handle.raw() as usize,
-            buf.as_mut_ptr() as usize,
-            buf.len(),
-        );
-        check_error(ret)
+        sys_read(self.handle, buf)
     }
 
     /// Lê dados em um offset específico (sem mover cursor)
     ///
     /// Útil para leitura paralela ou random access.
     pub fn pread(&self, buf: &mut [u8], offset: u64) -> SysResult<usize> {
-        let ret = syscall4(
-            SYS_PREAD,
-            self.handle.raw() as usize,
-            buf.as_mut_ptr() as usize,
-            buf.len(),
-            offset as usize,
-        );
-        check_error(ret)
+        sys_pread(self.handle, buf, offset)
     }
 
     /// Lê exatamente `buf.len()` bytes
@@ -150,25 +131,12 @@ impl File {
     /// # Retorno
     /// Número de bytes escritos.
     pub fn write(&self, buf: &[u8]) -> SysResult<usize> {
-        let ret = syscall3(
-            SYS_WRITE,
-            self.handle.raw() as usize,
-            buf.as_ptr() as usize,
-            buf.len(),
-        );
-        check_error(ret)
+        sys_write(self.handle, buf)
     }
 
     /// Escreve dados em um offset específico (sem mover cursor)
     pub fn pwrite(&self, buf: &[u8], offset: u64) -> SysResult<usize> {
-        let ret = syscall4(
-            SYS_PWRITE,
-            self.handle.raw() as usize,
-            buf.as_ptr() as usize,
-            buf.len(),
-            offset as usize,
-        );
-        check_error(ret)
+        sys_pwrite(self.handle, buf, offset)
     }
 
     /// Escreve todos os bytes do buffer
@@ -199,13 +167,7 @@ impl File {
     /// # Retorno
     /// Nova posição absoluta no arquivo.
     pub fn seek(&self, offset: i64, whence: SeekFrom) -> SysResult<u64> {
-        let ret = syscall3(
-            SYS_SEEK,
-            self.handle.raw() as usize,
-            offset as usize,
-            whence as usize,
-        );
-        check_error(ret).map(|v| v as u64)
+        sys_seek(self.handle, offset, whence)
     }
 
     /// Move para o início do arquivo
@@ -225,14 +187,7 @@ impl File {
 
     /// Obtém informações do arquivo (fstat)
     pub fn stat(&self) -> SysResult<FileStat> {
-        let mut st = FileStat::zeroed();
-        let ret = syscall2(
-            SYS_FSTAT,
-            self.handle.raw() as usize,
-            &mut st as *mut FileStat as usize,
-        );
-        check_error(ret)?;
-        Ok(st)
+        sys_fstat(self.handle)
     }
 
     /// Obtém tamanho do arquivo
@@ -246,16 +201,12 @@ impl File {
 
     /// Força flush de buffers para disco
     pub fn flush(&self) -> SysResult<()> {
-        let ret = syscall1(SYS_FLUSH, self.handle.raw() as usize);
-        check_error(ret)?;
-        Ok(())
+        sys_flush(self.handle)
     }
 
     /// Redimensiona o arquivo
     pub fn truncate(&self, size: u64) -> SysResult<()> {
-        let ret = syscall2(SYS_TRUNCATE, self.handle.raw() as usize, size as usize);
-        check_error(ret)?;
-        Ok(())
+        sys_truncate(self.handle, size)
     }
 
     // =========================================================================
@@ -288,10 +239,26 @@ impl File {
     }
 }
 
+impl crate::io::Read for File {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        File::read(self, buf)
+    }
+}
+
+impl crate::io::Write for File {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        File::write(self, buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        File::flush(self)
+    }
+}
+
 impl Drop for File {
     fn drop(&mut self) {
         // Usa SYS_HANDLE_CLOSE (não SYS_CLOSE que não existe mais)
-        let _ = syscall1(SYS_HANDLE_CLOSE, self.handle.raw() as usize);
+        let _ = sys_handle_close(self.handle);
     }
 }
 
@@ -18,10 +18,10 @@
 //! | `rename` | Renomeia arquivo |
 
 use super::types::FileStat;
-use crate::syscall::{
-    check_error, syscall2, syscall3, syscall4, SysResult, SYS_ACCESS, SYS_CHDIR, SYS_GETCWD,
-    SYS_MKDIR, SYS_RENAME, SYS_RMDIR, SYS_STAT, SYS_UNLINK,
+use crate::syscall::safe::{
+    sys_access, sys_chdir, sys_getcwd, sys_mkdir, sys_rename, sys_rmdir, sys_stat, sys_unlink,
 };
+use crate::syscall::SysResult;
 
 // =============================================================================
 // METADADOS
@@ -35,15 +35,7 @@ use crate::syscall::{
 /// println!("Tamanho: {} bytes", info.size);
 /// ```
 pub fn stat(path: &str) -> SysResult<FileStat> {
-    let mut st = FileStat::zeroed();
-    let ret = syscall3(
-        SYS_STAT,
-        path.as_ptr() as usize,
-        path.len(),
-        &mut st as *mut FileStat as usize,
-    );
-    check_error(ret)?;
-    Ok(st)
+    sys_stat(path)
 }
 
 /// Verifica se um path existe
@@ -55,14 +47,7 @@ pub fn stat(path: &str) -> SysResult<FileStat> {
 /// }
 /// ```
 pub fn exists(path: &str) -> bool {
-    // F_OK = 0
-    let ret = syscall3(
-        SYS_ACCESS,
-        path.as_ptr() as usize,
-        path.len(),
-        0, // F_OK
-    );
-    check_error(ret).is_ok()
+    sys_access(path, 0).is_ok() // F_OK
 }
 
 /// Verifica se path é um arquivo regular
@@ -77,38 +62,17 @@ pub fn is_dir(path: &str) -> bool {
 
 /// Verifica permissão de leitura
 pub fn can_read(path: &str) -> bool {
-    // R_OK = 4
-    let ret = syscall3(
-        SYS_ACCESS,
-        path.as_ptr() as usize,
-        path.len(),
-        4, // R_OK
-    );
-    check_error(ret).is_ok()
+    sys_access(path, 4).is_ok() // R_OK
 }
 
 /// Verifica permissão de escrita
 pub fn can_write(path: &str) -> bool {
-    // W_OK = 2
-    let ret = syscall3(
-        SYS_ACCESS,
-        path.as_ptr() as usize,
-        path.len(),
-        2, // W_OK
-    );
-    check_error(ret).is_ok()
+    sys_access(path, 2).is_ok() // W_OK
 }
 
 /// Verifica permissão de execução
 pub fn can_execute(path: &str) -> bool {
-    // X_OK = 1
-    let ret = syscall3(
-        SYS_ACCESS,
-        path.as_ptr() as usize,
-        path.len(),
-        1, // X_OK
-    );
-    check_error(ret).is_ok()
+    sys_access(path, 1).is_ok() // X_OK
 }
 
 // =============================================================================
@@ -124,8 +88,7 @@ pub fn can_execute(path: &str) -> bool {
 /// println!("CWD: {}", cwd);
 /// ```
 pub fn getcwd(buf: &mut [u8]) -> SysResult<&str> {
-    let ret = syscall2(SYS_GETCWD, buf.as_mut_ptr() as usize, buf.len());
-    let len = check_error(ret)?;
+    let len = sys_getcwd(buf)?;
 
     // len inclui null terminator
     let path_len = if len > 0 { len - 1 } else { 0 };
@@ -139,9 +102,7 @@ pub fn getcwd(buf: &mut [u8]) -> SysResult<&str> {
 /// chdir("/apps")?;
 /// ```
 pub fn chdir(path: &str) -> SysResult<()> {
-    let ret = syscall2(SYS_CHDIR, path.as_ptr() as usize, path.len());
-    check_error(ret)?;
-    Ok(())
+    sys_chdir(path)
 }
 
 // =============================================================================
@@ -154,23 +115,17 @@ pub fn chdir(path: &str) -> SysResult<()> {
 /// - `path` - Caminho do diretório
 /// - `mode` - Permissões (ex: 0o755)
 pub fn mkdir(path: &str, mode: u32) -> SysResult<()> {
-    let ret = syscall3(SYS_MKDIR, path.as_ptr() as usize, path.len(), mode as usize);
-    check_error(ret)?;
-    Ok(())
+    sys_mkdir(path, mode)
 }
 
 /// Remove um diretório vazio
 pub fn rmdir(path: &str) -> SysResult<()> {
-    let ret = syscall2(SYS_RMDIR, path.as_ptr() as usize, path.len());
-    check_error(ret)?;
-    Ok(())
+    sys_rmdir(path)
 }
 
 /// Remove um arquivo
 pub fn unlink(path: &str) -> SysResult<()> {
-    let ret = syscall2(SYS_UNLINK, path.as_ptr() as usize, path.len());
-    check_error(ret)?;
-    Ok(())
+    sys_unlink(path)
 }
 
 /// Remove um arquivo (alias para unlink)
@@ -180,13 +135,5 @@ pub fn remove(path: &str) -> SysResult<()> {
 
 /// Renomeia/move um arquivo
 pub fn rename(old_path: &str, new_path: &str) -> SysResult<()> {
-    let ret = syscall4(
-        SYS_RENAME,
-        old_path.as_ptr() as usize,
-        old_path.len(),
-        new_path.as_ptr() as usize,
-        new_path.len(),
-    );
-    check_error(ret)?;
-    Ok(())
+    sys_rename(old_path, new_path)
 }
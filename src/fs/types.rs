@@ -2,6 +2,8 @@
 //!
 //! Tipos compartilhados para operações de filesystem.
 
+use crate::time::SystemTime;
+
 // =============================================================================
 // OPEN FLAGS
 // =============================================================================
@@ -194,6 +196,21 @@ impl FileStat {
     pub fn is_dir(&self) -> bool {
         self.file_type().is_dir()
     }
+
+    /// Último acesso
+    pub fn accessed(&self) -> SystemTime {
+        SystemTime::from_unix_millis(self.atime)
+    }
+
+    /// Última modificação
+    pub fn modified(&self) -> SystemTime {
+        SystemTime::from_unix_millis(self.mtime)
+    }
+
+    /// Criação
+    pub fn created(&self) -> SystemTime {
+        SystemTime::from_unix_millis(self.ctime)
+    }
 }
 
 impl Default for FileStat {
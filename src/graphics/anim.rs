@@ -0,0 +1,142 @@
+//! # Animation
+//!
+//! Ticker de animação que acompanha a taxa de atualização do monitor onde a
+//! janela está, em vez de assumir 60 Hz.
+//!
+//! Com frame callbacks ([`Window::request_frame`]), o passo de tempo vem do
+//! instante de apresentação de cada frame; sem eles, [`Ticker::wait`] dorme
+//! pelo intervalo de refresh.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::Event;
+//! use redpowder::graphics::anim::Ticker;
+//!
+//! let mut ticker = Ticker::for_window(&window)?;
+//! window.request_frame()?;
+//! loop {
+//!     for event in window.poll_events() {
+//!         if let Event::Frame(frame) = event {
+//!             let dt = ticker.on_frame(&frame);
+//!             animation.advance(dt);
+//!             render(&mut window);
+//!             window.present()?;
+//!             window.request_frame()?;
+//!         }
+//!     }
+//! }
+//! ```
+
+use core::time::Duration;
+
+use crate::event::FrameEvent;
+use crate::syscall::SysResult;
+use crate::time::{Clock, SystemClock};
+use crate::window::Window;
+
+/// Taxa assumida quando o monitor não informa a sua (mHz).
+pub const DEFAULT_REFRESH_MHZ: u32 = 60_000;
+
+/// Maior passo de tempo retornado (s), para animações não saltarem após a
+/// janela ficar minimizada ou o processo parado.
+pub const MAX_FRAME_DT: f32 = 0.25;
+
+// =============================================================================
+// TICKER
+// =============================================================================
+
+/// Relógio de animação por monitor.
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    refresh_mhz: u32,
+    interval: Duration,
+    /// Último frame, desde o boot (apresentação ou deadline de `wait`).
+    last: Option<Duration>,
+}
+
+impl Ticker {
+    /// Ticker para um monitor de `refresh_rate_mhz` (0 = 60 Hz).
+    pub fn new(refresh_rate_mhz: u32) -> Self {
+        let mut ticker = Self {
+            refresh_mhz: 0,
+            interval: Duration::ZERO,
+            last: None,
+        };
+        ticker.set_refresh_rate(refresh_rate_mhz);
+        ticker
+    }
+
+    /// Ticker com a taxa do monitor onde `window` está.
+    pub fn for_window(window: &Window) -> SysResult<Self> {
+        Ok(Self::new(window.output_info()?.refresh_rate_mhz))
+    }
+
+    /// Taxa de atualização atual (Hz).
+    pub fn refresh_rate_hz(&self) -> f32 {
+        self.refresh_mhz as f32 / 1000.0
+    }
+
+    /// Intervalo entre frames.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Troca a taxa (ex: janela movida para outro monitor).
+    pub fn set_refresh_rate(&mut self, refresh_rate_mhz: u32) {
+        let mhz = match refresh_rate_mhz {
+            0 => DEFAULT_REFRESH_MHZ,
+            mhz => mhz,
+        };
+        self.refresh_mhz = mhz;
+        self.interval = Duration::from_nanos(1_000_000_000_000 / mhz as u64);
+    }
+
+    /// Registra um frame apresentado e retorna o passo de tempo (s) desde o
+    /// anterior.
+    ///
+    /// Acompanha a taxa informada no evento, então a troca de monitor é
+    /// detectada sem consultar o compositor.
+    pub fn on_frame(&mut self, frame: &FrameEvent) -> f32 {
+        if frame.refresh_rate_mhz != 0 && frame.refresh_rate_mhz != self.refresh_mhz {
+            self.set_refresh_rate(frame.refresh_rate_mhz);
+        }
+        self.advance(Duration::from_nanos(frame.timestamp_ns))
+    }
+
+    /// Dorme até o próximo frame e retorna o passo de tempo (s).
+    ///
+    /// Para apps sem frame callbacks. Os deadlines seguem o intervalo sem
+    /// acumular drift; se um frame for perdido por inteiro, o ritmo é
+    /// reiniciado a partir de agora.
+    pub fn wait(&mut self) -> SysResult<f32> {
+        self.wait_with(&SystemClock)
+    }
+
+    /// [`wait`](Self::wait) com outro [`Clock`] (ex.: `MockClock` em testes).
+    pub fn wait_with(&mut self, clock: &impl Clock) -> SysResult<f32> {
+        let now = clock.now()?;
+        let deadline = match self.last {
+            Some(last) if now.since_boot() < last + self.interval * 2 => {
+                now + (last + self.interval).saturating_sub(now.since_boot())
+            }
+            _ => now + self.interval,
+        };
+        clock.sleep_until(deadline)?;
+        Ok(self.advance(deadline.since_boot()))
+    }
+
+    /// Esquece o último frame (o próximo passo será de um intervalo).
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    fn advance(&mut self, now: Duration) -> f32 {
+        let dt = match self.last {
+            Some(last) => now.saturating_sub(last).as_secs_f32(),
+            None => self.interval.as_secs_f32(),
+        };
+        self.last = Some(now);
+        dt.min(MAX_FRAME_DT)
+    }
+}
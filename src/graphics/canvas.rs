@@ -20,10 +20,22 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use gfx_types::color::Color;
-use gfx_types::geometry::{Circle, Line, Point, Rect, Size};
-use gfx_types::render::ClipRect;
-
-use super::draw::{circle_points, draw_circle, draw_line, fill_circle, line_points};
+use gfx_types::geometry::{Circle, Line, Point, PointF, Rect, Size, Transform2D};
+use gfx_types::render::{ClipRect, InterpolationQuality};
+use gfx_types::text::TextAlign;
+use rdsmath::{ceilf, floorf, sqrtf};
+
+use super::clip::{row_spans, ClipRegion};
+use super::damage::DamageRegion;
+use super::draw::{
+    aa_circle, aa_line, circle_points, draw_circle, draw_line, fill_circle, line_points,
+};
+use super::font::{GlyphBitmap, GlyphCache};
+use super::gradient::{GradientKind, GradientLut, GradientSpec};
+use super::image::ImageBuffer;
+use super::path::{FillRule, Path, StrokeStyle};
+use super::surface::Surface;
+use super::text::{self, BitmapFont, FONT_8X16};
 
 // =============================================================================
 // CANVAS
@@ -39,8 +51,12 @@ pub struct Canvas<'a> {
     height: u32,
     /// Região de clipping.
     clip: Option<ClipRect>,
+    /// Recorte não retangular (contido em `clip`).
+    mask: Option<ClipRegion>,
+    /// Estados salvos por `push_clip*`.
+    clip_stack: Vec<(Option<ClipRect>, Option<ClipRegion>)>,
     /// Regiões modificadas (damage tracking).
-    damage: Vec<Rect>,
+    damage: DamageRegion,
 }
 
 impl<'a> Canvas<'a> {
@@ -51,7 +67,9 @@ impl<'a> Canvas<'a> {
             width,
             height,
             clip: None,
-            damage: Vec::with_capacity(8),
+            mask: None,
+            clip_stack: Vec::new(),
+            damage: DamageRegion::new(),
         }
     }
 
@@ -84,8 +102,62 @@ impl<'a> Canvas<'a> {
     }
 
     /// Define região de clipping.
+    ///
+    /// Substitui o recorte atual (inclusive um não retangular); a pilha de
+    /// `push_clip` não é alterada.
     pub fn set_clip(&mut self, rect: Option<Rect>) {
         self.clip = rect.map(|r| ClipRect::new(r));
+        self.mask = None;
+    }
+
+    /// Retorna região de clipping atual (envolvente, se não retangular).
+    pub fn clip(&self) -> Option<Rect> {
+        self.clip.map(|c| c.rect)
+    }
+
+    /// Recorte não retangular atual, se houver.
+    pub fn clip_region(&self) -> Option<&ClipRegion> {
+        self.mask.as_ref()
+    }
+
+    /// Empilha recorte: a área visível passa a ser a interseção da atual
+    /// com `rect`, até o `pop_clip` correspondente.
+    pub fn push_clip(&mut self, rect: Rect) {
+        self.save_clip();
+        let visible = self.clip_rect(rect);
+        self.mask = self.mask.take().map(|m| m.intersect_rect(visible));
+        self.clip = Some(ClipRect::new(visible));
+    }
+
+    /// Empilha recorte não retangular (interseção com o atual).
+    pub fn push_clip_region(&mut self, region: &ClipRegion) {
+        self.save_clip();
+        let mut mask = region.intersect_rect(self.clip_rect(region.bounds()));
+        if let Some(current) = &self.mask {
+            mask = mask.intersect(current);
+        }
+        self.clip = Some(ClipRect::new(mask.bounds()));
+        self.mask = Some(mask);
+    }
+
+    /// Empilha o interior de um caminho como recorte.
+    pub fn push_clip_path(&mut self, path: &Path, rule: FillRule) {
+        let region = ClipRegion::from_path(path, rule, self.clip_rect(self.bounds()));
+        self.push_clip_region(&region);
+    }
+
+    /// Restaura o recorte anterior ao último `push_clip*` (sem efeito com a
+    /// pilha vazia).
+    pub fn pop_clip(&mut self) {
+        if let Some((clip, mask)) = self.clip_stack.pop() {
+            self.clip = clip;
+            self.mask = mask;
+        }
+    }
+
+    /// Número de recortes empilhados.
+    pub fn clip_depth(&self) -> usize {
+        self.clip_stack.len()
     }
 
     /// Retorna referência ao buffer.
@@ -148,19 +220,71 @@ impl<'a> Canvas<'a> {
 
         let color_u32 = color.as_u32();
 
-        for y in rect.y.max(0) as u32..((rect.y + rect.height as i32) as u32).min(self.height) {
-            let start = (y as usize * self.width as usize) + rect.x.max(0) as usize;
-            let width = rect.width as usize;
-            let end = (start + width).min(self.buffer.len());
-
-            if start < self.buffer.len() {
-                self.buffer[start..end].fill(color_u32);
+        for y in rect.y..rect.bottom() {
+            let row = y as usize * self.width as usize;
+            for (x0, x1) in row_spans(self.mask.as_ref(), y, rect.x, rect.right()) {
+                self.buffer[row + x0 as usize..row + x1 as usize].fill(color_u32);
             }
         }
 
         self.add_damage(rect);
     }
 
+    /// Preenche retângulo com gradiente linear ou radial.
+    ///
+    /// A geometria do gradiente é relativa a `rect`; paradas translúcidas
+    /// são compostas sobre o conteúdo existente.
+    pub fn fill_rect_gradient(&mut self, rect: Rect, gradient: &GradientSpec) {
+        let area = self.clip_rect(rect);
+        if area.is_empty() {
+            return;
+        }
+        let Some(lut) = GradientLut::new(gradient.stops) else {
+            return;
+        };
+
+        // Posição no gradiente para um ponto relativo (u, v)
+        let position = |u: f32, v: f32| match gradient.kind {
+            GradientKind::Linear { start, end } => {
+                let (dx, dy) = (end.x - start.x, end.y - start.y);
+                let len2 = dx * dx + dy * dy;
+                if len2 > 0.0 {
+                    ((u - start.x) * dx + (v - start.y) * dy) / len2
+                } else {
+                    1.0
+                }
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius > 0.0 {
+                    let (dx, dy) = (u - center.x, v - center.y);
+                    sqrtf(dx * dx + dy * dy) / radius
+                } else {
+                    1.0
+                }
+            }
+        };
+
+        let (w, h) = (rect.width as f32, rect.height as f32);
+        for y in area.y..area.y + area.height as i32 {
+            let v = ((y - rect.y) as f32 + 0.5) / h;
+            let row = y as usize * self.width as usize;
+            for (x0, x1) in row_spans(self.mask.as_ref(), y, area.x, area.right()) {
+                for x in x0..x1 {
+                    let u = ((x - rect.x) as f32 + 0.5) / w;
+                    let color = lut.color_at(position(u, v));
+                    let px = &mut self.buffer[row + x as usize];
+                    *px = if lut.translucent {
+                        blend_over(color, Color::from_raw(*px)).as_u32()
+                    } else {
+                        color.as_u32()
+                    };
+                }
+            }
+        }
+
+        self.add_damage(area);
+    }
+
     /// Desenha borda de retângulo.
     pub fn stroke_rect(&mut self, rect: Rect, color: Color, thickness: u32) {
         let t = thickness;
@@ -218,6 +342,11 @@ impl<'a> Canvas<'a> {
         }
     }
 
+    /// Desenha linha com anti-aliasing (coordenadas em centros de pixel).
+    pub fn draw_line_aa(&mut self, start: PointF, end: PointF, color: Color) {
+        self.draw_aa(aa_line(start, end), color);
+    }
+
     // =========================================================================
     // CÍRCULOS
     // =========================================================================
@@ -236,6 +365,11 @@ impl<'a> Canvas<'a> {
         }
     }
 
+    /// Desenha borda de círculo com anti-aliasing.
+    pub fn stroke_circle_aa(&mut self, center: PointF, radius: f32, color: Color) {
+        self.draw_aa(aa_circle(center, radius), color);
+    }
+
     /// Preenche círculo.
     pub fn fill_circle(&mut self, cx: i32, cy: i32, radius: i32, color: Color) {
         let circle = Circle::from_coords(cx as f32, cy as f32, radius as f32);
@@ -244,103 +378,306 @@ impl<'a> Canvas<'a> {
         }
     }
 
+    // =========================================================================
+    // CAMINHOS VETORIAIS
+    // =========================================================================
+
+    /// Preenche um caminho vetorial.
+    pub fn fill_path(&mut self, path: &Path, rule: FillRule, color: Color) {
+        let clip = self.clip_rect(self.bounds());
+        let mut damage: Option<Rect> = None;
+        path.fill_spans(rule, clip, |x, y, w| {
+            self.fill_span(x, y, w, color);
+            let span = Rect::new(x, y, w, 1);
+            damage = Some(damage.map_or(span, |d| d.union(&span)));
+        });
+        if let Some(rect) = damage {
+            self.add_damage(rect);
+        }
+    }
+
+    /// Desenha o traçado de um caminho vetorial.
+    pub fn stroke_path(&mut self, path: &Path, style: &StrokeStyle, color: Color) {
+        let clip = self.clip_rect(self.bounds());
+        let mut damage: Option<Rect> = None;
+        path.stroke_spans(style, clip, |x, y, w| {
+            self.fill_span(x, y, w, color);
+            let span = Rect::new(x, y, w, 1);
+            damage = Some(damage.map_or(span, |d| d.union(&span)));
+        });
+        if let Some(rect) = damage {
+            self.add_damage(rect);
+        }
+    }
+
+    // =========================================================================
+    // TEXTO
+    // =========================================================================
+
+    /// Desenha texto com a fonte padrão 8x16 (topo-esquerda em `pos`).
+    ///
+    /// `\n` inicia nova linha. Retorna o tamanho ocupado.
+    pub fn draw_text(&mut self, pos: Point, text: &str, color: Color) -> Size {
+        self.draw_text_with(&FONT_8X16, pos, text, color)
+    }
+
+    /// Desenha texto com uma fonte bitmap.
+    pub fn draw_text_with(
+        &mut self,
+        font: &BitmapFont,
+        pos: Point,
+        text: &str,
+        color: Color,
+    ) -> Size {
+        let line_height = font.line_height() as i32;
+        let mut y = pos.y;
+        for line in text.split('\n') {
+            self.draw_text_line(font, pos.x, y, line, color);
+            y += line_height;
+        }
+
+        let size = text::measure(font, text);
+        self.add_damage(self.clip_rect(Rect::from_point_size(pos, size)));
+        size
+    }
+
+    /// Desenha texto com quebra de linha automática dentro de `rect`.
+    ///
+    /// Linhas que não cabem na altura de `rect` são omitidas. Retorna a
+    /// altura usada.
+    pub fn draw_text_wrapped(
+        &mut self,
+        rect: Rect,
+        text: &str,
+        color: Color,
+        align: TextAlign,
+    ) -> u32 {
+        let font = &FONT_8X16;
+        let line_height = font.line_height();
+        let mut used = 0;
+
+        for line in text::wrap(font, text, rect.width) {
+            if used + line_height > rect.height {
+                break;
+            }
+            let width = text::line_cells(line) * font.width();
+            let x = match align {
+                TextAlign::Center => rect.x + (rect.width.saturating_sub(width) / 2) as i32,
+                TextAlign::Right => rect.x + rect.width.saturating_sub(width) as i32,
+                _ => rect.x,
+            };
+            self.draw_text_line(font, x, rect.y + used as i32, line, color);
+            used += line_height;
+        }
+
+        self.add_damage(self.clip_rect(Rect::new(rect.x, rect.y, rect.width, used)));
+        used
+    }
+
+    /// Desenha texto com uma fonte TrueType em `size` px.
+    ///
+    /// `pos` é a origem da primeira linha na baseline; `\n` inicia nova
+    /// linha. Retorna a largura da linha mais longa.
+    pub fn draw_text_font(
+        &mut self,
+        cache: &mut GlyphCache,
+        pos: Point,
+        text: &str,
+        size: f32,
+        color: Color,
+    ) -> f32 {
+        let line_height = cache.font().line_metrics(size).line_height();
+        let mut widest = 0.0f32;
+        let mut baseline = pos.y as f32;
+
+        for line in text.split('\n') {
+            let mut pen = pos.x as f32;
+            for ch in line.chars() {
+                let glyph = cache.glyph_for(ch, size);
+                let x = (pen + 0.5) as i32 + glyph.left;
+                let y = (baseline + 0.5) as i32 - glyph.top;
+                self.draw_coverage(glyph, x, y, color);
+                pen += glyph.advance;
+            }
+            widest = widest.max(pen - pos.x as f32);
+            baseline += line_height;
+        }
+        widest
+    }
+
     // =========================================================================
     // BLIT / COPY
     // =========================================================================
 
     /// Copia região de outro buffer.
     pub fn blit(&mut self, src: &[u32], src_size: Size, src_rect: Rect, dst_point: Point) {
-        let dst_rect = Rect::new(dst_point.x, dst_point.y, src_rect.width, src_rect.height);
-        let dst_rect = self.clip_rect(dst_rect);
-        if dst_rect.is_empty() {
+        let Some((dst_rect, src_origin)) = self.blit_area(src, src_size, src_rect, dst_point)
+        else {
             return;
-        }
+        };
 
         let src_stride = src_size.width as usize;
         let dst_stride = self.width as usize;
+        let width = dst_rect.width as usize;
 
         for y in 0..dst_rect.height as usize {
-            let src_y = src_rect.y as usize + y;
-            let dst_y = dst_rect.y as usize + y;
-
-            if src_y >= src_size.height as usize || dst_y >= self.height as usize {
-                continue;
+            let src_start = (src_origin.y as usize + y) * src_stride + src_origin.x as usize;
+            let dst_start = (dst_rect.y as usize + y) * dst_stride + dst_rect.x as usize;
+            let (src_row, dst_row) = (
+                &src[src_start..src_start + width],
+                &mut self.buffer[dst_start..dst_start + width],
+            );
+            let dy = dst_rect.y + y as i32;
+            for (x0, x1) in row_spans(self.mask.as_ref(), dy, dst_rect.x, dst_rect.right()) {
+                let (a, b) = ((x0 - dst_rect.x) as usize, (x1 - dst_rect.x) as usize);
+                dst_row[a..b].copy_from_slice(&src_row[a..b]);
             }
+        }
 
-            let src_start = src_y * src_stride + src_rect.x as usize;
-            let dst_start = dst_y * dst_stride + dst_rect.x as usize;
-            let width = dst_rect.width as usize;
+        self.add_damage(dst_rect);
+    }
 
-            let src_end = (src_start + width).min(src.len());
-            let dst_end = (dst_start + width).min(self.buffer.len());
-            let actual_width = (src_end - src_start).min(dst_end - dst_start);
+    /// Copia com alpha blending (source over) pelo alfa de cada pixel.
+    pub fn blit_blended(&mut self, src: &[u32], src_size: Size, src_rect: Rect, dst_point: Point) {
+        self.blit_with(src, src_size, src_rect, dst_point, |s, d| match s >> 24 {
+            0xFF => s,
+            0 => d,
+            _ => blend_over(Color(s), Color(d)).as_u32(),
+        });
+    }
 
-            if actual_width > 0 {
-                self.buffer[dst_start..dst_start + actual_width]
-                    .copy_from_slice(&src[src_start..src_start + actual_width]);
+    /// Nome antigo de [`blit_blended`](Self::blit_blended).
+    #[deprecated(note = "use `blit_blended`")]
+    pub fn blit_blend(&mut self, src: &[u32], src_size: Size, src_rect: Rect, dst_point: Point) {
+        self.blit_blended(src, src_size, src_rect, dst_point);
+    }
+
+    /// Copia ignorando pixels iguais a `transparent` (color key).
+    ///
+    /// Apenas RGB é comparado; os pixels copiados ficam opacos.
+    pub fn blit_keyed(
+        &mut self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        dst_point: Point,
+        transparent: Color,
+    ) {
+        let key = transparent.as_u32() & 0x00FF_FFFF;
+        self.blit_with(src, src_size, src_rect, dst_point, |s, d| {
+            if s & 0x00FF_FFFF == key {
+                d
+            } else {
+                s | 0xFF00_0000
             }
-        }
+        });
+    }
 
-        self.add_damage(dst_rect);
+    /// Copia `src_rect` escalado para preencher `dst_rect`, com alpha
+    /// blending.
+    ///
+    /// `Bicubic` e `Lanczos` usam o filtro bilinear.
+    pub fn blit_scaled(
+        &mut self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        dst_rect: Rect,
+        filter: InterpolationQuality,
+    ) {
+        if src_rect.is_empty() || dst_rect.is_empty() {
+            return;
+        }
+        let sx = src_rect.width as f32 / dst_rect.width as f32;
+        let sy = src_rect.height as f32 / dst_rect.height as f32;
+        let transform =
+            Transform2D::translate(-dst_rect.x as f32, -dst_rect.y as f32).then_scale(sx, sy);
+        self.blit_mapped(src, src_size, src_rect, dst_rect, &transform, filter);
     }
 
-    /// Copia com alpha blending.
-    pub fn blit_blend(&mut self, src: &[u32], src_size: Size, src_rect: Rect, dst_point: Point) {
-        let dst_rect = self.clip_rect(Rect::new(
-            dst_point.x,
-            dst_point.y,
-            src_rect.width,
-            src_rect.height,
-        ));
-        if dst_rect.is_empty() {
+    /// Copia `src_rect` aplicando `transform` (rotação, espelhamento,
+    /// escala...), com alpha blending.
+    ///
+    /// `transform` leva coordenadas relativas ao canto de `src_rect` para
+    /// coordenadas do canvas. Transformações não inversíveis são ignoradas.
+    pub fn blit_transformed(
+        &mut self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        transform: &Transform2D,
+        filter: InterpolationQuality,
+    ) {
+        let Some(inverse) = transform.inverse() else {
+            return;
+        };
+        if src_rect.is_empty() {
             return;
         }
 
-        for y in 0..dst_rect.height as usize {
-            for x in 0..dst_rect.width as usize {
-                let src_x = src_rect.x as usize + x;
-                let src_y = src_rect.y as usize + y;
-                let dst_x = dst_rect.x as usize + x;
-                let dst_y = dst_rect.y as usize + y;
-
-                if src_x >= src_size.width as usize
-                    || src_y >= src_size.height as usize
-                    || dst_x >= self.width as usize
-                    || dst_y >= self.height as usize
-                {
-                    continue;
-                }
+        // Caixa envolvente dos cantos transformados
+        let (w, h) = (src_rect.width as f32, src_rect.height as f32);
+        let corners = [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)]
+            .map(|(x, y)| transform.transform_point(PointF::new(x, y)));
+        let (mut x0, mut y0) = (f32::MAX, f32::MAX);
+        let (mut x1, mut y1) = (f32::MIN, f32::MIN);
+        for p in corners {
+            x0 = x0.min(p.x);
+            y0 = y0.min(p.y);
+            x1 = x1.max(p.x);
+            y1 = y1.max(p.y);
+        }
+        let left = floorf(x0) as i32;
+        let top = floorf(y0) as i32;
+        let bounds = Rect::new(
+            left,
+            top,
+            (ceilf(x1) as i32 - left).max(0) as u32,
+            (ceilf(y1) as i32 - top).max(0) as u32,
+        );
+        self.blit_mapped(src, src_size, src_rect, bounds, &inverse, filter);
+    }
 
-                let src_idx = src_y * src_size.width as usize + src_x;
-                let dst_idx = dst_y * self.width as usize + dst_x;
+    /// Desenha uma imagem decodificada com canto superior esquerdo em `pos`,
+    /// respeitando o canal alfa.
+    pub fn draw_image(&mut self, image: &ImageBuffer, pos: Point) {
+        let size = image.size();
+        let rect = Rect::from_point_size(Point::ZERO, size);
+        self.blit_blended(image.pixels(), size, rect, pos);
+    }
 
-                if src_idx < src.len() && dst_idx < self.buffer.len() {
-                    let src_color = Color(src[src_idx]);
-                    let dst_color = Color(self.buffer[dst_idx]);
-                    let blended = blend_over(src_color, dst_color);
-                    self.buffer[dst_idx] = blended.as_u32();
+    /// Compõe uma [`Surface`] em `pos` com opacidade global `alpha`,
+    /// respeitando o canal alfa de cada pixel.
+    pub fn draw_surface(&mut self, surface: &Surface, pos: Point, alpha: u8) {
+        let size = surface.size();
+        let rect = Rect::from_point_size(Point::ZERO, size);
+        match alpha {
+            0 => {}
+            0xFF => self.blit_blended(surface.pixels(), size, rect, pos),
+            _ => self.blit_with(surface.pixels(), size, rect, pos, |s, d| {
+                match (s >> 24) * alpha as u32 / 255 {
+                    0 => d,
+                    a => blend_over(Color(s).with_alpha(a as u8), Color(d)).as_u32(),
                 }
-            }
+            }),
         }
-
-        self.add_damage(dst_rect);
     }
 
     // =========================================================================
     // DAMAGE TRACKING
     // =========================================================================
 
-    /// Retorna regiões danificadas.
-    pub fn damage(&self) -> &[Rect] {
+    /// Retorna a região danificada.
+    pub fn damage(&self) -> &DamageRegion {
         &self.damage
     }
 
-    /// Retorna e limpa regiões danificadas.
-    pub fn take_damage(&mut self) -> Vec<Rect> {
+    /// Retorna e limpa a região danificada.
+    pub fn take_damage(&mut self) -> DamageRegion {
         core::mem::take(&mut self.damage)
     }
 
-    /// Limpa lista de damage.
+    /// Limpa a região danificada.
     pub fn clear_damage(&mut self) {
         self.damage.clear();
     }
@@ -356,10 +693,17 @@ impl<'a> Canvas<'a> {
         }
 
         if let Some(clip) = &self.clip {
-            return clip.rect.contains_point(Point::new(x, y));
+            if !clip.rect.contains_point(Point::new(x, y)) {
+                return false;
+            }
         }
 
-        true
+        self.mask.as_ref().is_none_or(|m| m.contains(x, y))
+    }
+
+    /// Salva o recorte atual na pilha.
+    fn save_clip(&mut self) {
+        self.clip_stack.push((self.clip, self.mask.clone()));
     }
 
     /// Aplica clipping a um retângulo.
@@ -380,41 +724,291 @@ impl<'a> Canvas<'a> {
         result
     }
 
-    /// Adiciona região ao damage tracking.
-    fn add_damage(&mut self, rect: Rect) {
-        if rect.is_empty() {
+    /// Recorta um blit contra o destino e a origem.
+    ///
+    /// Retorna a área de destino visível e o ponto correspondente na origem.
+    fn blit_area(
+        &self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        dst_point: Point,
+    ) -> Option<(Rect, Point)> {
+        // Região de origem dentro do buffer de origem
+        let src_rows = (src.len() / src_size.width.max(1) as usize) as u32;
+        let src_bounds = Rect::new(0, 0, src_size.width, src_size.height.min(src_rows));
+        let clipped = src_rect.intersection(&src_bounds)?;
+
+        // Deslocar o destino junto com o recorte da origem
+        let dst_rect = Rect::new(
+            dst_point.x + (clipped.x - src_rect.x),
+            dst_point.y + (clipped.y - src_rect.y),
+            clipped.width,
+            clipped.height,
+        );
+        let visible = self.clip_rect(dst_rect);
+        if visible.is_empty() {
+            return None;
+        }
+        let origin = Point::new(
+            clipped.x + (visible.x - dst_rect.x),
+            clipped.y + (visible.y - dst_rect.y),
+        );
+        Some((visible, origin))
+    }
+
+    /// Blit pixel a pixel combinando origem e destino com `combine(src, dst)`.
+    fn blit_with(
+        &mut self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        dst_point: Point,
+        combine: impl Fn(u32, u32) -> u32,
+    ) {
+        let Some((dst_rect, src_origin)) = self.blit_area(src, src_size, src_rect, dst_point)
+        else {
+            return;
+        };
+
+        let src_stride = src_size.width as usize;
+        let dst_stride = self.width as usize;
+        let width = dst_rect.width as usize;
+
+        for y in 0..dst_rect.height as usize {
+            let src_start = (src_origin.y as usize + y) * src_stride + src_origin.x as usize;
+            let dst_start = (dst_rect.y as usize + y) * dst_stride + dst_rect.x as usize;
+            let src_row = &src[src_start..src_start + width];
+            let dst_row = &mut self.buffer[dst_start..dst_start + width];
+            let dy = dst_rect.y + y as i32;
+            for (x0, x1) in row_spans(self.mask.as_ref(), dy, dst_rect.x, dst_rect.right()) {
+                let (a, b) = ((x0 - dst_rect.x) as usize, (x1 - dst_rect.x) as usize);
+                for (d, &s) in dst_row[a..b].iter_mut().zip(&src_row[a..b]) {
+                    *d = combine(s, *d);
+                }
+            }
+        }
+
+        self.add_damage(dst_rect);
+    }
+
+    /// Preenche `area` amostrando a origem: `to_src` leva o centro de cada
+    /// pixel do canvas para coordenadas relativas a `src_rect`.
+    fn blit_mapped(
+        &mut self,
+        src: &[u32],
+        src_size: Size,
+        src_rect: Rect,
+        area: Rect,
+        to_src: &Transform2D,
+        filter: InterpolationQuality,
+    ) {
+        let src_rows = (src.len() / src_size.width.max(1) as usize) as u32;
+        let src_bounds = Rect::new(0, 0, src_size.width, src_size.height.min(src_rows));
+        let Some(src_rect) = src_rect.intersection(&src_bounds) else {
+            return;
+        };
+        let visible = self.clip_rect(area);
+        if visible.is_empty() {
             return;
         }
 
-        // Tentar merge com rect existente
-        for existing in &mut self.damage {
-            if existing.intersects(&rect) {
-                *existing = existing.union(&rect);
-                return;
+        let sampler = Sampler {
+            pixels: src,
+            stride: src_size.width as usize,
+            rect: src_rect,
+        };
+        let bilinear = filter != InterpolationQuality::Nearest;
+        let dst_stride = self.width as usize;
+
+        for y in visible.y..visible.bottom() {
+            let columns = row_spans(self.mask.as_ref(), y, visible.x, visible.right())
+                .flat_map(|(x0, x1)| x0..x1);
+            for x in columns {
+                let p = to_src.transform_point(PointF::new(x as f32 + 0.5, y as f32 + 0.5));
+                let sample = if bilinear {
+                    sampler.bilinear(p.x, p.y)
+                } else {
+                    sampler.nearest(p.x, p.y)
+                };
+                let Some(s) = sample else {
+                    continue;
+                };
+                let idx = y as usize * dst_stride + x as usize;
+                self.buffer[idx] = match s >> 24 {
+                    0xFF => s,
+                    0 => continue,
+                    _ => blend_over(Color(s), Color(self.buffer[idx])).as_u32(),
+                };
             }
         }
 
-        self.damage.push(rect);
+        self.add_damage(visible);
+    }
 
-        // Limitar número de rects
-        if self.damage.len() > 8 {
-            self.collapse_damage();
+    /// Preenche span horizontal já recortado pelo retângulo de clip (sem
+    /// damage tracking).
+    fn fill_span(&mut self, x: i32, y: i32, width: u32, color: Color) {
+        let row = y as usize * self.width as usize;
+        for (x0, x1) in row_spans(self.mask.as_ref(), y, x, x + width as i32) {
+            self.buffer[row + x0 as usize..row + x1 as usize].fill(color.as_u32());
         }
     }
 
-    /// Agrupa damage em um único bounding box.
-    fn collapse_damage(&mut self) {
-        if self.damage.len() <= 1 {
+    /// Desenha uma linha de texto (sem `\n`, sem damage tracking).
+    fn draw_text_line(&mut self, font: &BitmapFont, x: i32, y: i32, line: &str, color: Color) {
+        let cell = font.width() as i32;
+        let mut col = 0;
+        for ch in line.chars() {
+            let next = text::advance(col, ch);
+            if !ch.is_whitespace() && !ch.is_control() {
+                self.draw_glyph(font, x + col as i32 * cell, y, ch, color);
+            }
+            col = next;
+        }
+    }
+
+    /// Desenha um glifo com canto superior esquerdo em (x, y).
+    fn draw_glyph(&mut self, font: &BitmapFont, x: i32, y: i32, ch: char, color: Color) {
+        let value = color.as_u32();
+        for (row, bits) in font.glyph(ch).iter().enumerate() {
+            if *bits == 0 {
+                continue;
+            }
+            for col in 0..font.width() as i32 {
+                if bits & (0x80 >> col) != 0 && self.is_visible(x + col, y + row as i32) {
+                    let idx = (y as usize + row) * self.width as usize + (x + col) as usize;
+                    self.buffer[idx] = value;
+                }
+            }
+        }
+    }
+
+    /// Mistura um bitmap de cobertura com canto superior esquerdo em (x, y).
+    fn draw_coverage(&mut self, glyph: &GlyphBitmap, x: i32, y: i32, color: Color) {
+        let area = Rect::new(x, y, glyph.width, glyph.height);
+        let visible = self.clip_rect(area);
+        if visible.is_empty() {
             return;
         }
 
-        let mut bounds = self.damage[0];
-        for rect in &self.damage[1..] {
-            bounds = bounds.union(rect);
+        for py in visible.y..visible.bottom() {
+            for px in visible.x..visible.right() {
+                if self.mask.as_ref().is_some_and(|m| !m.contains(px, py)) {
+                    continue;
+                }
+                let cov = glyph.coverage_at((px - x) as u32, (py - y) as u32);
+                self.blend_pixel(px, py, color, cov);
+            }
         }
+        self.add_damage(visible);
+    }
 
-        self.damage.clear();
-        self.damage.push(bounds);
+    /// Aplica pares `(pixel, cobertura)` como alpha, com damage tracking.
+    fn draw_aa(&mut self, pixels: impl Iterator<Item = (Point, u8)>, color: Color) {
+        let mut damage: Option<Rect> = None;
+        for (p, cov) in pixels {
+            if !self.is_visible(p.x, p.y) {
+                continue;
+            }
+            self.blend_pixel(p.x, p.y, color, cov);
+            let pixel = Rect::new(p.x, p.y, 1, 1);
+            damage = Some(damage.map_or(pixel, |d| d.union(&pixel)));
+        }
+        if let Some(rect) = damage {
+            self.add_damage(rect);
+        }
+    }
+
+    /// Compõe `color` com alpha escalado por `coverage` (pixel visível).
+    #[inline]
+    fn blend_pixel(&mut self, x: i32, y: i32, color: Color, coverage: u8) {
+        if coverage == 0 {
+            return;
+        }
+        let alpha = (color.alpha() as u32 * coverage as u32 / 255) as u8;
+        let idx = y as usize * self.width as usize + x as usize;
+        let dst = Color::from_raw(self.buffer[idx]);
+        self.buffer[idx] = blend_over(color.with_alpha(alpha), dst).as_u32();
+    }
+
+    /// Adiciona região ao damage tracking.
+    #[inline]
+    fn add_damage(&mut self, rect: Rect) {
+        self.damage.add(rect);
+    }
+}
+
+// =============================================================================
+// AMOSTRAGEM
+// =============================================================================
+
+/// Leitura de pixels de uma região de origem em coordenadas contínuas.
+struct Sampler<'s> {
+    pixels: &'s [u32],
+    stride: usize,
+    rect: Rect,
+}
+
+impl Sampler<'_> {
+    #[inline]
+    fn pixel(&self, x: i32, y: i32) -> u32 {
+        // Bordas estendidas (clamp) dentro da região
+        let x = x.clamp(0, self.rect.width as i32 - 1) + self.rect.x;
+        let y = y.clamp(0, self.rect.height as i32 - 1) + self.rect.y;
+        self.pixels[y as usize * self.stride + x as usize]
+    }
+
+    #[inline]
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= 0.0 && y >= 0.0 && x < self.rect.width as f32 && y < self.rect.height as f32
+    }
+
+    fn nearest(&self, x: f32, y: f32) -> Option<u32> {
+        self.contains(x, y)
+            .then(|| self.pixel(floorf(x) as i32, floorf(y) as i32))
+    }
+
+    /// Interpolação bilinear ponderada pelo alfa (sem franjas escuras em
+    /// bordas transparentes).
+    fn bilinear(&self, x: f32, y: f32) -> Option<u32> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        let (fx, fy) = (x - 0.5, y - 0.5);
+        let (x0, y0) = (floorf(fx), floorf(fy));
+        let (tx, ty) = (fx - x0, fy - y0);
+        let (x0, y0) = (x0 as i32, y0 as i32);
+
+        let taps = [
+            (self.pixel(x0, y0), (1.0 - tx) * (1.0 - ty)),
+            (self.pixel(x0 + 1, y0), tx * (1.0 - ty)),
+            (self.pixel(x0, y0 + 1), (1.0 - tx) * ty),
+            (self.pixel(x0 + 1, y0 + 1), tx * ty),
+        ];
+
+        let (mut a, mut r, mut g, mut b) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+        for (px, w) in taps {
+            let c = Color(px);
+            let wa = w * c.alpha() as f32;
+            a += wa;
+            r += wa * c.red() as f32;
+            g += wa * c.green() as f32;
+            b += wa * c.blue() as f32;
+        }
+        if a <= 0.0 {
+            return Some(0);
+        }
+        let channel = |v: f32| (v / a + 0.5).min(255.0) as u8;
+        Some(
+            Color::argb(
+                (a + 0.5).min(255.0) as u8,
+                channel(r),
+                channel(g),
+                channel(b),
+            )
+            .as_u32(),
+        )
     }
 }
 
@@ -0,0 +1,443 @@
+//! # Chart
+//!
+//! Gráficos de linha e de barras sobre um [`Canvas`], com layout de eixos e
+//! ticks.
+//!
+//! O texto dos rótulos é entregue a um callback (`draw_labels`), já
+//! formatado e posicionado, para que o app escolha a fonte.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::chart::{AxisStyle, Chart};
+//!
+//! let chart = Chart::new(Rect::new(40, 10, 300, 150), (0.0, 60.0), (0.0, 100.0));
+//! chart.draw_axes(&mut canvas, &AxisStyle::default());
+//! chart.line_plot(&mut canvas, &cpu_samples, Color::GREEN, 2.0);
+//! chart.draw_labels(&AxisStyle::default(), |label| {
+//!     // desenhar label.text em label.anchor
+//! });
+//! ```
+
+use core::fmt::Write;
+
+use gfx_types::color::Color;
+use gfx_types::geometry::{Point, PointF, Rect};
+use rdsmath::{ceilf, floorf, log10f, powf, roundf};
+
+use super::canvas::Canvas;
+use super::path::{LineJoin, Path, StrokeStyle};
+
+// =============================================================================
+// ESTILO
+// =============================================================================
+
+/// Estilo dos eixos.
+#[derive(Clone, Copy, Debug)]
+pub struct AxisStyle {
+    /// Cor das linhas dos eixos e ticks.
+    pub color: Color,
+    /// Cor das linhas de grade (None = sem grade).
+    pub grid: Option<Color>,
+    /// Comprimento dos ticks em pixels.
+    pub tick_len: u32,
+    /// Número máximo de ticks no eixo X.
+    pub max_ticks_x: usize,
+    /// Número máximo de ticks no eixo Y.
+    pub max_ticks_y: usize,
+    /// Distância entre tick e rótulo.
+    pub label_gap: u32,
+}
+
+impl Default for AxisStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::LIGHT_GRAY,
+            grid: None,
+            tick_len: 4,
+            max_ticks_x: 6,
+            max_ticks_y: 5,
+            label_gap: 2,
+        }
+    }
+}
+
+/// Eixo do gráfico.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+// =============================================================================
+// TICKS
+// =============================================================================
+
+/// Tick de um eixo.
+#[derive(Clone, Copy, Debug)]
+pub struct Tick {
+    /// Valor no domínio dos dados.
+    pub value: f32,
+    /// Posição em pixels no canvas (x para eixo X, y para eixo Y).
+    pub pos: i32,
+}
+
+/// Iterador sobre ticks "redondos" (1, 2, 5 × 10ⁿ) de um intervalo.
+pub struct Ticks {
+    first: f32,
+    step: f32,
+    count: usize,
+    idx: usize,
+    /// Mapeamento valor → pixel: pos = origin + value * scale.
+    origin: f32,
+    scale: f32,
+}
+
+impl Ticks {
+    /// Passo entre ticks.
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+}
+
+impl Iterator for Ticks {
+    type Item = Tick;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.count {
+            return None;
+        }
+        let value = self.first + self.step * self.idx as f32;
+        self.idx += 1;
+        Some(Tick {
+            value,
+            pos: roundf(self.origin + value * self.scale) as i32,
+        })
+    }
+}
+
+/// Calcula passo "redondo" para no máximo `max_ticks` divisões.
+pub fn nice_step(min: f32, max: f32, max_ticks: usize) -> f32 {
+    let range = max - min;
+    if range <= 0.0 || max_ticks == 0 {
+        return 1.0;
+    }
+
+    let raw = range / max_ticks as f32;
+    let magnitude = powf(10.0, floorf(log10f(raw)));
+    let norm = raw / magnitude;
+
+    let nice = if norm <= 1.0 {
+        1.0
+    } else if norm <= 2.0 {
+        2.0
+    } else if norm <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+// =============================================================================
+// RÓTULOS
+// =============================================================================
+
+/// Texto de rótulo formatado em buffer fixo.
+#[derive(Clone, Copy)]
+pub struct LabelText {
+    buf: [u8; 24],
+    len: usize,
+}
+
+impl LabelText {
+    /// Formata valor com o número de casas decimais dado.
+    pub fn format(value: f32, decimals: usize) -> Self {
+        let mut label = Self {
+            buf: [0; 24],
+            len: 0,
+        };
+        // Evita "-0"
+        let value = if value == 0.0 { 0.0 } else { value };
+        let _ = write!(label, "{:.*}", decimals, value);
+        label
+    }
+
+    /// Texto como &str.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for LabelText {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let bytes = s.as_bytes();
+        let n = bytes.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+/// Rótulo de tick pronto para desenho.
+pub struct TickLabel<'a> {
+    /// Eixo ao qual pertence.
+    pub axis: Axis,
+    /// Valor do tick.
+    pub value: f32,
+    /// Ponto de ancoragem.
+    ///
+    /// Eixo X: centro da borda superior do texto.
+    /// Eixo Y: centro da borda direita do texto.
+    pub anchor: Point,
+    /// Texto formatado.
+    pub text: &'a str,
+}
+
+/// Casas decimais necessárias para representar o passo.
+fn decimals_for(step: f32) -> usize {
+    if step >= 1.0 {
+        0
+    } else {
+        (-floorf(log10f(step))) as usize
+    }
+}
+
+// =============================================================================
+// CHART
+// =============================================================================
+
+/// Área de plotagem com mapeamento dados → pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct Chart {
+    /// Retângulo de plotagem no canvas.
+    pub rect: Rect,
+    /// Intervalo do eixo X (min, max).
+    pub x_range: (f32, f32),
+    /// Intervalo do eixo Y (min, max).
+    pub y_range: (f32, f32),
+}
+
+impl Chart {
+    /// Cria gráfico na área `rect`.
+    pub fn new(rect: Rect, x_range: (f32, f32), y_range: (f32, f32)) -> Self {
+        Self {
+            rect,
+            x_range,
+            y_range,
+        }
+    }
+
+    /// Intervalo (min, max) que contém todos os valores, estendido a ticks redondos.
+    pub fn fit_range(values: &[f32], max_ticks: usize) -> (f32, f32) {
+        let mut iter = values.iter().copied();
+        let first = match iter.next() {
+            Some(v) => v,
+            None => return (0.0, 1.0),
+        };
+        let (min, max) = iter.fold((first, first), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        let (min, max) = if min == max {
+            (min - 1.0, max + 1.0)
+        } else {
+            (min, max)
+        };
+        let step = nice_step(min, max, max_ticks);
+        (floorf(min / step) * step, ceilf(max / step) * step)
+    }
+
+    /// Converte ponto de dados em coordenadas do canvas.
+    pub fn map(&self, x: f32, y: f32) -> PointF {
+        let (x0, x1) = self.x_range;
+        let (y0, y1) = self.y_range;
+        let w = (self.rect.width.max(1) - 1) as f32;
+        let h = (self.rect.height.max(1) - 1) as f32;
+
+        let tx = if x1 != x0 { (x - x0) / (x1 - x0) } else { 0.0 };
+        let ty = if y1 != y0 { (y - y0) / (y1 - y0) } else { 0.0 };
+
+        PointF::new(
+            self.rect.x as f32 + tx * w,
+            (self.rect.bottom() - 1) as f32 - ty * h,
+        )
+    }
+
+    /// Ticks do eixo informado.
+    pub fn ticks(&self, axis: Axis, max_ticks: usize) -> Ticks {
+        let (min, max) = match axis {
+            Axis::X => self.x_range,
+            Axis::Y => self.y_range,
+        };
+        let step = nice_step(min, max, max_ticks);
+        let first = ceilf(min / step - 1e-4) * step;
+        let count = if max >= first {
+            (floorf((max - first) / step + 1e-4) as usize) + 1
+        } else {
+            0
+        };
+
+        let (origin, scale) = match axis {
+            Axis::X => {
+                let p0 = self.map(0.0, self.y_range.0).x;
+                let p1 = self.map(1.0, self.y_range.0).x;
+                (p0, p1 - p0)
+            }
+            Axis::Y => {
+                let p0 = self.map(self.x_range.0, 0.0).y;
+                let p1 = self.map(self.x_range.0, 1.0).y;
+                (p0, p1 - p0)
+            }
+        };
+
+        Ticks {
+            first,
+            step,
+            count,
+            idx: 0,
+            origin,
+            scale,
+        }
+    }
+
+    // =========================================================================
+    // DESENHO
+    // =========================================================================
+
+    /// Desenha eixos, ticks e (opcionalmente) grade.
+    pub fn draw_axes(&self, canvas: &mut Canvas, style: &AxisStyle) {
+        let r = self.rect;
+        let bottom = r.bottom() - 1;
+
+        if let Some(grid) = style.grid {
+            for tick in self.ticks(Axis::X, style.max_ticks_x) {
+                canvas.vline(tick.pos, r.y, r.height, grid);
+            }
+            for tick in self.ticks(Axis::Y, style.max_ticks_y) {
+                canvas.hline(r.x, tick.pos, r.width, grid);
+            }
+        }
+
+        canvas.vline(r.x, r.y, r.height, style.color);
+        canvas.hline(r.x, bottom, r.width, style.color);
+
+        for tick in self.ticks(Axis::X, style.max_ticks_x) {
+            canvas.vline(tick.pos, bottom + 1, style.tick_len, style.color);
+        }
+        for tick in self.ticks(Axis::Y, style.max_ticks_y) {
+            canvas.hline(
+                r.x - style.tick_len as i32,
+                tick.pos,
+                style.tick_len,
+                style.color,
+            );
+        }
+    }
+
+    /// Calcula rótulos dos ticks e entrega cada um ao callback de desenho.
+    pub fn draw_labels(&self, style: &AxisStyle, mut draw: impl FnMut(TickLabel)) {
+        let r = self.rect;
+        let offset = (style.tick_len + style.label_gap) as i32;
+
+        let x_ticks = self.ticks(Axis::X, style.max_ticks_x);
+        let decimals = decimals_for(x_ticks.step());
+        for tick in x_ticks {
+            let text = LabelText::format(tick.value, decimals);
+            draw(TickLabel {
+                axis: Axis::X,
+                value: tick.value,
+                anchor: Point::new(tick.pos, r.bottom() - 1 + offset),
+                text: text.as_str(),
+            });
+        }
+
+        let y_ticks = self.ticks(Axis::Y, style.max_ticks_y);
+        let decimals = decimals_for(y_ticks.step());
+        for tick in y_ticks {
+            let text = LabelText::format(tick.value, decimals);
+            draw(TickLabel {
+                axis: Axis::Y,
+                value: tick.value,
+                anchor: Point::new(r.x - offset, tick.pos),
+                text: text.as_str(),
+            });
+        }
+    }
+
+    /// Plota série de amostras igualmente espaçadas (x = índice).
+    pub fn line_plot(&self, canvas: &mut Canvas, values: &[f32], color: Color, width: f32) {
+        let mut path = Path::new();
+        for (i, &v) in values.iter().enumerate() {
+            let p = self.map(i as f32, v);
+            if i == 0 {
+                path.move_to(p.x, p.y);
+            } else {
+                path.line_to(p.x, p.y);
+            }
+        }
+        self.stroke_series(canvas, &path, color, width);
+    }
+
+    /// Plota série de pontos (x, y).
+    pub fn xy_plot(&self, canvas: &mut Canvas, points: &[(f32, f32)], color: Color, width: f32) {
+        let mut path = Path::new();
+        for (i, &(x, y)) in points.iter().enumerate() {
+            let p = self.map(x, y);
+            if i == 0 {
+                path.move_to(p.x, p.y);
+            } else {
+                path.line_to(p.x, p.y);
+            }
+        }
+        self.stroke_series(canvas, &path, color, width);
+    }
+
+    /// Desenha gráfico de barras ocupando toda a largura da área.
+    ///
+    /// `gap` é o espaço em pixels entre barras. As barras partem do valor 0
+    /// (ou da base do eixo Y, se 0 estiver fora do intervalo).
+    pub fn bar_chart(&self, canvas: &mut Canvas, values: &[f32], color: Color, gap: u32) {
+        if values.is_empty() {
+            return;
+        }
+
+        let r = self.rect;
+        let Ok(n) = u32::try_from(values.len()) else {
+            return;
+        };
+        let total_gap = match n.checked_add(1).and_then(|slots| gap.checked_mul(slots)) {
+            Some(total) if total < r.width => total,
+            _ => return,
+        };
+        let bar_w = (r.width - total_gap) / n;
+        if bar_w == 0 {
+            return;
+        }
+
+        let (y0, y1) = self.y_range;
+        let base_value = 0.0f32.clamp(y0.min(y1), y0.max(y1));
+        let base = roundf(self.map(self.x_range.0, base_value).y) as i32;
+
+        for (i, &v) in values.iter().enumerate() {
+            if !v.is_finite() {
+                continue;
+            }
+            // Cabe em u32: gap + i * (bar_w + gap) < total_gap + n * bar_w <= r.width
+            let offset = gap + i as u32 * (bar_w + gap);
+            let x =
+                r.x.saturating_add(i32::try_from(offset).unwrap_or(i32::MAX));
+            let top = roundf(self.map(self.x_range.0, v).y) as i32;
+            let h = top.abs_diff(base).saturating_add(1);
+            canvas.fill_rect(Rect::new(x, top.min(base), bar_w, h), color);
+        }
+    }
+
+    fn stroke_series(&self, canvas: &mut Canvas, path: &Path, color: Color, width: f32) {
+        canvas.push_clip(self.rect);
+        canvas.stroke_path(
+            path,
+            &StrokeStyle::new(width).with_join(LineJoin::Round),
+            color,
+        );
+        canvas.pop_clip();
+    }
+}
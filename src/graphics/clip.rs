@@ -0,0 +1,209 @@
+//! # Clip
+//!
+//! Regiões de recorte não retangulares como listas de spans horizontais,
+//! para [`Canvas::push_clip_region`](crate::graphics::Canvas::push_clip_region).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::clip::ClipRegion;
+//!
+//! // Conteúdo de um cartão com cantos arredondados
+//! let mut shape = Path::new();
+//! shape.move_to(8.0, 0.0) /* ... */ .close();
+//!
+//! canvas.push_clip_path(&shape, FillRule::NonZero);
+//! draw_children(&mut canvas);
+//! canvas.pop_clip();
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::Rect;
+
+use super::path::{FillRule, Path};
+
+// =============================================================================
+// SPAN
+// =============================================================================
+
+/// Faixa horizontal de pixels `[x, x + width)` na linha `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipSpan {
+    pub y: i32,
+    pub x: i32,
+    pub width: u32,
+}
+
+impl ClipSpan {
+    /// Cria um span.
+    pub const fn new(x: i32, y: i32, width: u32) -> Self {
+        Self { y, x, width }
+    }
+
+    /// Primeira coluna após o span.
+    #[inline]
+    pub const fn end(&self) -> i32 {
+        self.x + self.width as i32
+    }
+}
+
+// =============================================================================
+// REGION
+// =============================================================================
+
+/// Região de recorte: spans ordenados por linha e coluna, sem sobreposição.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClipRegion {
+    spans: Vec<ClipSpan>,
+}
+
+impl ClipRegion {
+    /// Região vazia.
+    pub const fn new() -> Self {
+        Self { spans: Vec::new() }
+    }
+
+    /// Região retangular.
+    pub fn from_rect(rect: Rect) -> Self {
+        if rect.is_empty() {
+            return Self::new();
+        }
+        let spans = (rect.y..rect.bottom())
+            .map(|y| ClipSpan::new(rect.x, y, rect.width))
+            .collect();
+        Self { spans }
+    }
+
+    /// Região a partir de spans arbitrários (ordenados e unidos aqui).
+    pub fn from_spans(spans: impl IntoIterator<Item = ClipSpan>) -> Self {
+        let mut input: Vec<ClipSpan> = spans.into_iter().filter(|s| s.width > 0).collect();
+        input.sort_unstable_by_key(|s| (s.y, s.x));
+
+        let mut merged: Vec<ClipSpan> = Vec::with_capacity(input.len());
+        for span in input {
+            match merged.last_mut() {
+                Some(last) if last.y == span.y && span.x <= last.end() => {
+                    let end = last.end().max(span.end());
+                    last.width = (end - last.x) as u32;
+                }
+                _ => merged.push(span),
+            }
+        }
+        Self { spans: merged }
+    }
+
+    /// Interior de um caminho, limitado a `bounds`.
+    pub fn from_path(path: &Path, rule: FillRule, bounds: Rect) -> Self {
+        let mut spans = Vec::new();
+        path.fill_spans(rule, bounds, |x, y, w| spans.push(ClipSpan::new(x, y, w)));
+        Self::from_spans(spans)
+    }
+
+    /// Spans da região.
+    #[inline]
+    pub fn spans(&self) -> &[ClipSpan] {
+        &self.spans
+    }
+
+    /// Região vazia?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Spans da linha `y`.
+    pub fn row(&self, y: i32) -> &[ClipSpan] {
+        let start = self.spans.partition_point(|s| s.y < y);
+        let end = start + self.spans[start..].partition_point(|s| s.y == y);
+        &self.spans[start..end]
+    }
+
+    /// Ponto dentro da região?
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        self.row(y).iter().any(|s| x >= s.x && x < s.end())
+    }
+
+    /// Menor retângulo que contém a região.
+    pub fn bounds(&self) -> Rect {
+        let (Some(first), Some(last)) = (self.spans.first(), self.spans.last()) else {
+            return Rect::ZERO;
+        };
+        let left = self.spans.iter().map(|s| s.x).min().unwrap_or(0);
+        let right = self.spans.iter().map(|s| s.end()).max().unwrap_or(0);
+        Rect::new(
+            left,
+            first.y,
+            (right - left) as u32,
+            (last.y - first.y + 1) as u32,
+        )
+    }
+
+    /// Interseção com um retângulo.
+    pub fn intersect_rect(&self, rect: Rect) -> Self {
+        let spans = self
+            .spans
+            .iter()
+            .filter(|s| s.y >= rect.y && s.y < rect.bottom())
+            .filter_map(|s| {
+                let x0 = s.x.max(rect.x);
+                let x1 = s.end().min(rect.right());
+                (x1 > x0).then(|| ClipSpan::new(x0, s.y, (x1 - x0) as u32))
+            })
+            .collect();
+        Self { spans }
+    }
+
+    /// Interseção com outra região.
+    pub fn intersect(&self, other: &ClipRegion) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        let (a, b) = (&self.spans, &other.spans);
+        while i < a.len() && j < b.len() {
+            let (sa, sb) = (a[i], b[j]);
+            if sa.y != sb.y {
+                if sa.y < sb.y {
+                    i += 1;
+                } else {
+                    j += 1;
+                }
+                continue;
+            }
+            let x0 = sa.x.max(sb.x);
+            let x1 = sa.end().min(sb.end());
+            if x1 > x0 {
+                spans.push(ClipSpan::new(x0, sa.y, (x1 - x0) as u32));
+            }
+            if sa.end() <= sb.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { spans }
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Partes visíveis de `[x0, x1)` na linha `y` (a faixa inteira sem região).
+pub(crate) fn row_spans(
+    region: Option<&ClipRegion>,
+    y: i32,
+    x0: i32,
+    x1: i32,
+) -> impl Iterator<Item = (i32, i32)> + '_ {
+    let (whole, row) = match region {
+        Some(region) => (None, region.row(y)),
+        None => (Some((x0, x1)), &[][..]),
+    };
+    whole.into_iter().chain(row.iter().filter_map(move |s| {
+        let a = s.x.max(x0);
+        let b = s.end().min(x1);
+        (b > a).then_some((a, b))
+    }))
+}
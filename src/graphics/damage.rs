@@ -0,0 +1,293 @@
+//! # Damage
+//!
+//! Regiões danificadas em faixas (bands): retângulos sem sobreposição,
+//! agrupados em faixas horizontais de mesma altura e ordenados por x.
+//!
+//! Diferente de um bounding box, atualizações espalhadas (cursor piscando +
+//! relógio) continuam como áreas separadas, e a região pode ser subtraída
+//! (ex: partes cobertas por uma janela opaca).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{DamageRegion, Rect};
+//!
+//! let mut damage = DamageRegion::new();
+//! damage.add(Rect::new(10, 10, 2, 16)); // cursor
+//! damage.add(Rect::new(700, 4, 80, 16)); // relógio
+//! damage.subtract(Rect::new(600, 0, 200, 10)); // coberto por um popup
+//!
+//! for rect in &damage {
+//!     redraw(rect);
+//! }
+//! window.present_damage(&damage)?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::{Point, Rect};
+
+// =============================================================================
+// REGION
+// =============================================================================
+
+/// Região danificada (união de retângulos sem sobreposição).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DamageRegion {
+    /// Retângulos em faixas, ordenados por (y, x).
+    rects: Vec<Rect>,
+}
+
+impl DamageRegion {
+    /// Região vazia.
+    pub const fn new() -> Self {
+        Self { rects: Vec::new() }
+    }
+
+    /// Região retangular.
+    pub fn from_rect(rect: Rect) -> Self {
+        let mut region = Self::new();
+        if !rect.is_empty() {
+            region.rects.push(rect);
+        }
+        region
+    }
+
+    /// Retângulos da região, ordenados por linha e coluna.
+    #[inline]
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Itera os retângulos da região.
+    #[inline]
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Rect>> {
+        self.rects.iter().copied()
+    }
+
+    /// Número de retângulos.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Região vazia?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Esvazia a região.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Menor retângulo que contém a região.
+    pub fn bounds(&self) -> Rect {
+        let mut rects = self.iter();
+        let Some(first) = rects.next() else {
+            return Rect::ZERO;
+        };
+        rects.fold(first, |acc, r| acc.union(&r))
+    }
+
+    /// Área total em pixels.
+    pub fn area(&self) -> u64 {
+        self.rects.iter().map(Rect::area).sum()
+    }
+
+    /// Ponto dentro da região?
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let p = Point::new(x, y);
+        self.rects.iter().any(|r| r.contains_point(p))
+    }
+
+    // =========================================================================
+    // OPERAÇÕES
+    // =========================================================================
+
+    /// Adiciona um retângulo à região.
+    pub fn add(&mut self, rect: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+        if self.rects.is_empty() {
+            self.rects.push(rect);
+            return;
+        }
+        if self.rects.iter().any(|r| r.contains_rect(&rect)) {
+            return;
+        }
+        self.rects = combine(&self.rects, &[rect], Op::Union);
+    }
+
+    /// Adiciona outra região.
+    pub fn union(&mut self, other: &DamageRegion) {
+        if other.is_empty() {
+            return;
+        }
+        self.rects = combine(&self.rects, &other.rects, Op::Union);
+    }
+
+    /// Remove um retângulo da região.
+    pub fn subtract(&mut self, rect: Rect) {
+        if rect.is_empty() || self.is_empty() {
+            return;
+        }
+        self.rects = combine(&self.rects, &[rect], Op::Subtract);
+    }
+
+    /// Remove outra região.
+    pub fn subtract_region(&mut self, other: &DamageRegion) {
+        if other.is_empty() || self.is_empty() {
+            return;
+        }
+        self.rects = combine(&self.rects, &other.rects, Op::Subtract);
+    }
+
+    /// Mantém apenas a parte dentro de `rect`.
+    pub fn intersect_rect(&mut self, rect: Rect) {
+        self.rects = combine(&self.rects, &[rect], Op::Intersect);
+    }
+}
+
+impl From<Rect> for DamageRegion {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        Self::from_rect(rect)
+    }
+}
+
+impl<'a> IntoIterator for &'a DamageRegion {
+    type Item = Rect;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, Rect>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// =============================================================================
+// ALGORITMO DE FAIXAS
+// =============================================================================
+
+#[derive(Clone, Copy)]
+enum Op {
+    Union,
+    Subtract,
+    Intersect,
+}
+
+/// Combina duas regiões em faixas: para cada intervalo vertical entre
+/// bordas, aplica `op` aos spans das duas e junta faixas iguais vizinhas.
+fn combine(a: &[Rect], b: &[Rect], op: Op) -> Vec<Rect> {
+    let mut ys: Vec<i32> = a.iter().chain(b).flat_map(|r| [r.y, r.bottom()]).collect();
+    ys.sort_unstable();
+    ys.dedup();
+
+    let mut out: Vec<Rect> = Vec::new();
+    let mut band_start = 0;
+    let (mut xa, mut xb, mut xs) = (Vec::new(), Vec::new(), Vec::new());
+
+    for w in ys.windows(2) {
+        let (y0, y1) = (w[0], w[1]);
+        band_spans(a, y0, &mut xa);
+        band_spans(b, y0, &mut xb);
+        xs.clear();
+        match op {
+            Op::Union => union_spans(&xa, &xb, &mut xs),
+            Op::Subtract => subtract_spans(&xa, &xb, &mut xs),
+            Op::Intersect => intersect_spans(&xa, &xb, &mut xs),
+        }
+        if xs.is_empty() {
+            continue;
+        }
+
+        // Estender a faixa anterior se encosta e tem os mesmos spans
+        let prev = &mut out[band_start..];
+        let same = !prev.is_empty()
+            && prev[0].bottom() == y0
+            && prev.len() == xs.len()
+            && prev
+                .iter()
+                .zip(&xs)
+                .all(|(r, &(x0, x1))| r.x == x0 && r.right() == x1);
+        if same {
+            for r in prev {
+                r.height += (y1 - y0) as u32;
+            }
+        } else {
+            band_start = out.len();
+            out.extend(
+                xs.iter()
+                    .map(|&(x0, x1)| Rect::new(x0, y0, (x1 - x0) as u32, (y1 - y0) as u32)),
+            );
+        }
+    }
+    out
+}
+
+/// Spans `[x0, x1)` da faixa que cobre a linha `y`.
+fn band_spans(rects: &[Rect], y: i32, out: &mut Vec<(i32, i32)>) {
+    out.clear();
+    let start = rects.partition_point(|r| r.bottom() <= y);
+    out.extend(
+        rects[start..]
+            .iter()
+            .take_while(|r| r.y <= y)
+            .map(|r| (r.x, r.right())),
+    );
+}
+
+fn union_spans(a: &[(i32, i32)], b: &[(i32, i32)], out: &mut Vec<(i32, i32)>) {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let next = if j >= b.len() || (i < a.len() && a[i].0 <= b[j].0) {
+            i += 1;
+            a[i - 1]
+        } else {
+            j += 1;
+            b[j - 1]
+        };
+        match out.last_mut() {
+            Some(last) if last.1 >= next.0 => last.1 = last.1.max(next.1),
+            _ => out.push(next),
+        }
+    }
+}
+
+fn subtract_spans(a: &[(i32, i32)], b: &[(i32, i32)], out: &mut Vec<(i32, i32)>) {
+    let mut j = 0;
+    for &(mut x0, x1) in a {
+        while j < b.len() && b[j].1 <= x0 {
+            j += 1;
+        }
+        for &(b0, b1) in b[j..].iter().take_while(|s| s.0 < x1) {
+            if b0 > x0 {
+                out.push((x0, b0));
+            }
+            x0 = x0.max(b1);
+        }
+        if x0 < x1 {
+            out.push((x0, x1));
+        }
+    }
+}
+
+fn intersect_spans(a: &[(i32, i32)], b: &[(i32, i32)], out: &mut Vec<(i32, i32)>) {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let x0 = a[i].0.max(b[j].0);
+        let x1 = a[i].1.min(b[j].1);
+        if x1 > x0 {
+            out.push((x0, x1));
+        }
+        if a[i].1 <= b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Funções de desenho de primitivas geométricas.
 
-use gfx_types::geometry::{Circle, Line, Point, Rect};
+use core::f32::consts::FRAC_1_SQRT_2;
+
+use gfx_types::geometry::{Circle, Line, Point, PointF, Rect};
+use rdsmath::{absf, ceilf, floorf, sqrtf};
 
 // =============================================================================
 // LINHA (Bresenham)
@@ -260,6 +263,248 @@ impl Iterator for CircleIterator {
     }
 }
 
+// =============================================================================
+// ANTI-ALIASING (Wu)
+// =============================================================================
+
+/// Linha com anti-aliasing (algoritmo de Wu).
+///
+/// Coordenadas inteiras são centros de pixel. Retorna pares
+/// `(pixel, cobertura)` com cobertura 1-255.
+pub fn aa_line(start: PointF, end: PointF) -> impl Iterator<Item = (Point, u8)> {
+    AaLineIterator::new(start, end)
+}
+
+/// Borda de círculo com anti-aliasing.
+///
+/// Retorna pares `(pixel, cobertura)` com cobertura 1-255.
+pub fn aa_circle(center: PointF, radius: f32) -> impl Iterator<Item = (Point, u8)> {
+    AaCircleIterator::new(center, radius)
+}
+
+/// Converte cobertura fracionária (0.0-1.0) para 0-255.
+#[inline]
+fn coverage(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Divide a cobertura `weight` entre os dois pixels vizinhos de `v` no
+/// eixo menor.
+#[inline]
+fn split(v: f32, weight: f32) -> (i32, u8, u8) {
+    let iv = floorf(v);
+    let f = v - iv;
+    (
+        iv as i32,
+        coverage((1.0 - f) * weight),
+        coverage(f * weight),
+    )
+}
+
+struct AaLineIterator {
+    /// Eixo maior é y? (coordenadas trocadas)
+    steep: bool,
+    /// Origem no eixo maior e valor correspondente no eixo menor.
+    x0: f32,
+    y0: f32,
+    gradient: f32,
+    x: i32,
+    first: i32,
+    last: i32,
+    /// Peso das colunas das extremidades.
+    gap_first: f32,
+    gap_last: f32,
+    pending: Option<(Point, u8)>,
+}
+
+impl AaLineIterator {
+    fn new(start: PointF, end: PointF) -> Self {
+        let steep = absf(end.y - start.y) > absf(end.x - start.x);
+        let (mut x0, mut y0, mut x1, mut y1) = if steep {
+            (start.y, start.x, end.y, end.x)
+        } else {
+            (start.x, start.y, end.x, end.y)
+        };
+        if x0 > x1 {
+            core::mem::swap(&mut x0, &mut x1);
+            core::mem::swap(&mut y0, &mut y1);
+        }
+
+        let dx = x1 - x0;
+        let gradient = if dx == 0.0 { 0.0 } else { (y1 - y0) / dx };
+        let first = floorf(x0 + 0.5) as i32;
+        let last = floorf(x1 + 0.5) as i32;
+
+        // Fração de cada coluna das pontas coberta pela linha
+        let (gap_first, gap_last) = if first == last {
+            (dx, dx)
+        } else {
+            (
+                1.0 - (x0 + 0.5 - floorf(x0 + 0.5)),
+                x1 + 0.5 - floorf(x1 + 0.5),
+            )
+        };
+
+        Self {
+            steep,
+            x0,
+            y0,
+            gradient,
+            x: first,
+            first,
+            last,
+            gap_first,
+            gap_last,
+            pending: None,
+        }
+    }
+
+    fn point(&self, major: i32, minor: i32) -> Point {
+        if self.steep {
+            Point::new(minor, major)
+        } else {
+            Point::new(major, minor)
+        }
+    }
+}
+
+impl Iterator for AaLineIterator {
+    type Item = (Point, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pending.take() {
+            return Some(item);
+        }
+
+        while self.x <= self.last {
+            let x = self.x;
+            self.x += 1;
+
+            let weight = if x == self.first {
+                self.gap_first
+            } else if x == self.last {
+                self.gap_last
+            } else {
+                1.0
+            };
+            let y = self.y0 + self.gradient * (x as f32 - self.x0);
+            let (iy, c0, c1) = split(y, weight);
+
+            if c1 > 0 {
+                self.pending = Some((self.point(x, iy + 1), c1));
+            }
+            if c0 > 0 {
+                return Some((self.point(x, iy), c0));
+            }
+            if let Some(item) = self.pending.take() {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+/// Percorre o círculo em quatro arcos (topo, base, esquerda, direita),
+/// cada um ao longo do eixo em que a borda é mais plana.
+struct AaCircleIterator {
+    center: PointF,
+    radius: f32,
+    arc: u8,
+    i: i32,
+    end: i32,
+    pending: Option<(Point, u8)>,
+}
+
+impl AaCircleIterator {
+    fn new(center: PointF, radius: f32) -> Self {
+        let mut iter = Self {
+            center,
+            radius,
+            arc: 0,
+            i: 0,
+            end: -1,
+            pending: None,
+        };
+        if radius > 0.0 {
+            iter.start_arc();
+        } else {
+            iter.arc = 4;
+        }
+        iter
+    }
+
+    /// Posiciona `i..=end` no início do arco atual.
+    fn start_arc(&mut self) {
+        let h = self.radius * FRAC_1_SQRT_2;
+        let c = if self.arc < 2 {
+            self.center.x
+        } else {
+            self.center.y
+        };
+        if self.arc < 2 {
+            self.i = ceilf(c - h) as i32;
+            self.end = floorf(c + h) as i32;
+        } else {
+            // Exclusivo nas diagonais (já cobertas por topo/base)
+            self.i = floorf(c - h) as i32 + 1;
+            self.end = ceilf(c + h) as i32 - 1;
+        }
+    }
+}
+
+impl Iterator for AaCircleIterator {
+    type Item = (Point, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pending.take() {
+            return Some(item);
+        }
+
+        while self.arc < 4 {
+            if self.i > self.end {
+                self.arc += 1;
+                if self.arc < 4 {
+                    self.start_arc();
+                }
+                continue;
+            }
+            let i = self.i;
+            self.i += 1;
+
+            let (major, minor_center) = if self.arc < 2 {
+                (i as f32 - self.center.x, self.center.y)
+            } else {
+                (i as f32 - self.center.y, self.center.x)
+            };
+            let d = sqrtf((self.radius * self.radius - major * major).max(0.0));
+            let v = if self.arc & 1 == 0 {
+                minor_center - d
+            } else {
+                minor_center + d
+            };
+            let (iv, c0, c1) = split(v, 1.0);
+            let point = |minor: i32| {
+                if self.arc < 2 {
+                    Point::new(i, minor)
+                } else {
+                    Point::new(minor, i)
+                }
+            };
+
+            if c1 > 0 {
+                self.pending = Some((point(iv + 1), c1));
+            }
+            if c0 > 0 {
+                return Some((point(iv), c0));
+            }
+            if let Some(item) = self.pending.take() {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
 // =============================================================================
 // FILLED SHAPES
 // =============================================================================
@@ -0,0 +1,91 @@
+//! # Glyph Cache
+//!
+//! Cache de glifos rasterizados por `(glifo, tamanho)`, com descarte do
+//! mais antigo quando a capacidade é atingida.
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, VecDeque};
+
+use gfx_types::text::GlyphId;
+
+use super::raster::{rasterize_glyph, GlyphBitmap};
+use super::ttf::Font;
+
+/// Capacidade padrão (glifos).
+pub const DEFAULT_CACHE_CAPACITY: usize = 512;
+
+/// Chave do cache: glifo e tamanho em 1/64 px.
+type Key = (u32, u32);
+
+/// Fonte com cache de glifos rasterizados.
+pub struct GlyphCache {
+    font: Font,
+    glyphs: BTreeMap<Key, GlyphBitmap>,
+    /// Ordem de inserção (para descarte).
+    order: VecDeque<Key>,
+    capacity: usize,
+}
+
+impl GlyphCache {
+    /// Cria cache com capacidade padrão.
+    pub fn new(font: Font) -> Self {
+        Self::with_capacity(font, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Cria cache para até `capacity` glifos.
+    pub fn with_capacity(font: Font, capacity: usize) -> Self {
+        Self {
+            font,
+            glyphs: BTreeMap::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Fonte associada.
+    #[inline]
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Número de glifos em cache.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Cache vazio?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Descarta todos os glifos.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.order.clear();
+    }
+
+    /// Glifo de `ch` em `size` px (rasterizado na primeira chamada).
+    pub fn glyph_for(&mut self, ch: char, size: f32) -> &GlyphBitmap {
+        let glyph = self.font.glyph_id(ch);
+        self.glyph(glyph, size)
+    }
+
+    /// Glifo `glyph` em `size` px (rasterizado na primeira chamada).
+    pub fn glyph(&mut self, glyph: GlyphId, size: f32) -> &GlyphBitmap {
+        let key = (glyph.0, (size.max(0.0) * 64.0 + 0.5) as u32);
+        if !self.glyphs.contains_key(&key) {
+            if self.glyphs.len() >= self.capacity {
+                if let Some(old) = self.order.pop_front() {
+                    self.glyphs.remove(&old);
+                }
+            }
+            let bitmap = rasterize_glyph(&self.font, glyph, key.1 as f32 / 64.0);
+            self.glyphs.insert(key, bitmap);
+            self.order.push_back(key);
+        }
+        &self.glyphs[&key]
+    }
+}
@@ -0,0 +1,30 @@
+//! # Fontes TrueType
+//!
+//! Carregamento de fontes `.ttf`, rasterização de glifos com antialiasing e
+//! cache de bitmaps por `(glifo, tamanho)`.
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | `ttf` | Parser das tabelas e contornos dos glifos |
+//! | `raster` | Contorno → bitmap de cobertura (scanline + supersampling) |
+//! | `cache` | Cache de glifos rasterizados |
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{Color, Font, GlyphCache, Point};
+//!
+//! let font = Font::load("/system/fonts/sans.ttf")?;
+//! let mut cache = GlyphCache::new(font);
+//!
+//! // Origem na baseline
+//! canvas.draw_text_font(&mut cache, Point::new(10, 30), "Olá, Redstone!", 18.0, Color::WHITE);
+//! ```
+
+mod cache;
+mod raster;
+mod ttf;
+
+pub use cache::{GlyphCache, DEFAULT_CACHE_CAPACITY};
+pub use raster::{rasterize_glyph, GlyphBitmap};
+pub use ttf::{Font, LineMetrics};
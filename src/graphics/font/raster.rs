@@ -0,0 +1,130 @@
+//! # Rasterização de Glifos
+//!
+//! Converte contornos em bitmaps de cobertura (0-255) com antialiasing por
+//! supersampling, usando o preenchimento por scanline de
+//! [`path::rasterize`](crate::graphics::path::rasterize).
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::{Rect, Transform2D};
+use gfx_types::text::GlyphId;
+use rdsmath::{ceilf, floorf};
+
+use crate::graphics::path::{rasterize, FillRule, DEFAULT_TOLERANCE};
+
+use super::ttf::Font;
+
+/// Amostras por eixo em cada pixel (4x4 = 16 níveis de cobertura).
+const SUBSAMPLES: i32 = 4;
+
+// =============================================================================
+// GLYPH BITMAP
+// =============================================================================
+
+/// Glifo rasterizado em um tamanho.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphBitmap {
+    /// Largura do bitmap em pixels.
+    pub width: u32,
+    /// Altura do bitmap em pixels.
+    pub height: u32,
+    /// Deslocamento da origem da pena até a borda esquerda do bitmap.
+    pub left: i32,
+    /// Deslocamento da baseline até o topo do bitmap (positivo para cima).
+    pub top: i32,
+    /// Avanço horizontal em pixels.
+    pub advance: f32,
+    /// Cobertura por pixel (linha a linha, 0 = vazio, 255 = cheio).
+    pub coverage: Vec<u8>,
+}
+
+impl GlyphBitmap {
+    /// Glifo sem pixels (ex: espaço).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Cobertura do pixel `(x, y)` do bitmap.
+    #[inline]
+    pub fn coverage_at(&self, x: u32, y: u32) -> u8 {
+        if x < self.width && y < self.height {
+            self.coverage[(y * self.width + x) as usize]
+        } else {
+            0
+        }
+    }
+}
+
+/// Rasteriza `glyph` em `size` px.
+pub fn rasterize_glyph(font: &Font, glyph: GlyphId, size: f32) -> GlyphBitmap {
+    let advance = font.advance(glyph, size);
+    let empty = GlyphBitmap {
+        width: 0,
+        height: 0,
+        left: 0,
+        top: 0,
+        advance,
+        coverage: Vec::new(),
+    };
+
+    let Some(outline) = font.scaled_outline(glyph, size) else {
+        return empty;
+    };
+    let contours = outline.flatten(DEFAULT_TOLERANCE);
+
+    // Caixa envolvente em pixels inteiros
+    let mut bounds: Option<(f32, f32, f32, f32)> = None;
+    for p in contours.iter().flat_map(|c| c.points.iter()) {
+        bounds = Some(match bounds {
+            Some((x0, y0, x1, y1)) => (x0.min(p.x), y0.min(p.y), x1.max(p.x), y1.max(p.y)),
+            None => (p.x, p.y, p.x, p.y),
+        });
+    }
+    let Some((x0, y0, x1, y1)) = bounds else {
+        return empty;
+    };
+    let left = floorf(x0) as i32;
+    let top = floorf(y0) as i32;
+    let width = (ceilf(x1) as i32 - left).max(0) as u32;
+    let height = (ceilf(y1) as i32 - top).max(0) as u32;
+    if width == 0 || height == 0 {
+        return empty;
+    }
+
+    // Rasterizar em resolução SUBSAMPLES vezes maior, com o bitmap em (0, 0)
+    let s = SUBSAMPLES as f32;
+    let to_grid = Transform2D::translate(-left as f32, -top as f32).then_scale(s, s);
+    let mut scaled = contours;
+    for contour in &mut scaled {
+        for p in &mut contour.points {
+            *p = to_grid.transform_point(*p);
+        }
+    }
+
+    let clip = Rect::new(0, 0, width * SUBSAMPLES as u32, height * SUBSAMPLES as u32);
+    let mut hits = alloc::vec![0u16; (width * height) as usize];
+    rasterize(&scaled, FillRule::NonZero, clip, |x, y, w| {
+        let row = (y / SUBSAMPLES) as u32 * width;
+        for sx in x..x + w as i32 {
+            hits[(row + (sx / SUBSAMPLES) as u32) as usize] += 1;
+        }
+    });
+
+    let full = (SUBSAMPLES * SUBSAMPLES) as u32;
+    let coverage = hits
+        .iter()
+        .map(|&h| ((h as u32 * 255 + full / 2) / full) as u8)
+        .collect();
+
+    GlyphBitmap {
+        width,
+        height,
+        left,
+        top: -top,
+        advance,
+        coverage,
+    }
+}
@@ -0,0 +1,531 @@
+//! # TrueType
+//!
+//! Parser das tabelas TrueType necessárias para desenhar texto: `cmap`,
+//! `head`, `hhea`, `hmtx`, `maxp`, `loca` e `glyf` (contornos quadráticos,
+//! incluindo glifos compostos).
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::{PointF, Transform2D};
+use gfx_types::text::GlyphId;
+
+use crate::fs::File;
+use crate::graphics::path::Path;
+use crate::syscall::{SysError, SysResult};
+
+/// Profundidade máxima de glifos compostos aninhados.
+const MAX_COMPOSITE_DEPTH: u32 = 8;
+
+// =============================================================================
+// FONT
+// =============================================================================
+
+/// Fonte TrueType carregada em memória.
+pub struct Font {
+    data: Vec<u8>,
+    units_per_em: u16,
+    num_glyphs: u16,
+    long_loca: bool,
+    ascender: i16,
+    descender: i16,
+    line_gap: i16,
+    num_h_metrics: u16,
+    cmap: usize,
+    loca: usize,
+    glyf: usize,
+    glyf_len: usize,
+    hmtx: usize,
+}
+
+/// Métricas verticais de linha em pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineMetrics {
+    /// Altura acima da baseline.
+    pub ascent: f32,
+    /// Profundidade abaixo da baseline (positiva).
+    pub descent: f32,
+    /// Espaço extra entre linhas.
+    pub line_gap: f32,
+}
+
+impl LineMetrics {
+    /// Distância entre baselines consecutivas.
+    #[inline]
+    pub fn line_height(&self) -> f32 {
+        self.ascent + self.descent + self.line_gap
+    }
+}
+
+impl Font {
+    /// Carrega uma fonte `.ttf` do filesystem.
+    pub fn load(path: &str) -> SysResult<Self> {
+        let file = File::open(path)?;
+        let size = usize::try_from(file.size()?).map_err(|_| SysError::OutOfMemory)?;
+        let mut data = alloc::vec![0u8; size];
+        file.read_exact(&mut data)?;
+        Self::from_bytes(data)
+    }
+
+    /// Interpreta uma fonte TrueType já em memória.
+    pub fn from_bytes(data: Vec<u8>) -> SysResult<Self> {
+        let d = &data[..];
+        let version = read_u32(d, 0)?;
+        if version != 0x0001_0000 && version != u32::from_be_bytes(*b"true") {
+            // CFF (`OTTO`) e coleções não são suportados
+            return Err(SysError::NotSupported);
+        }
+
+        let table = |tag: &[u8; 4]| -> SysResult<(usize, usize)> {
+            let count = read_u16(d, 4)? as usize;
+            for i in 0..count {
+                let rec = 12 + i * 16;
+                if d.get(rec..rec + 4) == Some(&tag[..]) {
+                    let offset = read_u32(d, rec + 8)? as usize;
+                    let len = read_u32(d, rec + 12)? as usize;
+                    if offset.checked_add(len).is_none_or(|end| end > d.len()) {
+                        return Err(SysError::InvalidArgument);
+                    }
+                    return Ok((offset, len));
+                }
+            }
+            Err(SysError::NotFound)
+        };
+
+        let (head, _) = table(b"head")?;
+        let (maxp, _) = table(b"maxp")?;
+        let (hhea, _) = table(b"hhea")?;
+        let (hmtx, _) = table(b"hmtx")?;
+        let (loca, _) = table(b"loca")?;
+        let (glyf, glyf_len) = table(b"glyf")?;
+        let (cmap, _) = table(b"cmap")?;
+
+        let units_per_em = read_u16(d, head + 18)?;
+        if units_per_em == 0 {
+            return Err(SysError::InvalidArgument);
+        }
+
+        let mut font = Self {
+            units_per_em,
+            num_glyphs: read_u16(d, maxp + 4)?,
+            long_loca: read_i16(d, head + 50)? != 0,
+            ascender: read_i16(d, hhea + 4)?,
+            descender: read_i16(d, hhea + 6)?,
+            line_gap: read_i16(d, hhea + 8)?,
+            num_h_metrics: read_u16(d, hhea + 34)?.max(1),
+            cmap: 0,
+            loca,
+            glyf,
+            glyf_len,
+            hmtx,
+            data: Vec::new(),
+        };
+        font.cmap = find_cmap_subtable(d, cmap)?;
+        font.data = data;
+        Ok(font)
+    }
+
+    /// Unidades por em (escala das coordenadas do contorno).
+    #[inline]
+    pub fn units_per_em(&self) -> u16 {
+        self.units_per_em
+    }
+
+    /// Número de glifos na fonte.
+    #[inline]
+    pub fn glyph_count(&self) -> u16 {
+        self.num_glyphs
+    }
+
+    /// Fator de escala de unidades da fonte para pixels em `size` px.
+    #[inline]
+    pub fn scale(&self, size: f32) -> f32 {
+        size / self.units_per_em as f32
+    }
+
+    /// Métricas de linha em `size` px.
+    pub fn line_metrics(&self, size: f32) -> LineMetrics {
+        let s = self.scale(size);
+        LineMetrics {
+            ascent: self.ascender as f32 * s,
+            descent: -(self.descender as f32) * s,
+            line_gap: self.line_gap as f32 * s,
+        }
+    }
+
+    /// Glifo de um caractere (`GlyphId::NOTDEF` se ausente).
+    pub fn glyph_id(&self, ch: char) -> GlyphId {
+        GlyphId(self.lookup_cmap(ch as u32).unwrap_or(0) as u32)
+    }
+
+    /// Avanço horizontal do glifo em `size` px.
+    pub fn advance(&self, glyph: GlyphId, size: f32) -> f32 {
+        let index = (glyph.0 as usize).min(self.num_h_metrics as usize - 1);
+        let advance = read_u16(&self.data, self.hmtx + index * 4).unwrap_or(0);
+        advance as f32 * self.scale(size)
+    }
+
+    /// Largura de `text` em uma linha, em `size` px.
+    pub fn measure(&self, text: &str, size: f32) -> f32 {
+        text.chars()
+            .map(|ch| self.advance(self.glyph_id(ch), size))
+            .sum()
+    }
+
+    /// Contorno do glifo em unidades da fonte (y para cima).
+    ///
+    /// Retorna `None` para glifos sem contorno (ex: espaço).
+    pub fn outline(&self, glyph: GlyphId) -> Option<Path> {
+        let mut path = Path::new();
+        self.append_outline(glyph.0 as u16, &Transform2D::identity(), &mut path, 0)
+            .ok()?;
+        (!path.is_empty()).then_some(path)
+    }
+
+    /// Contorno do glifo em pixels para `size` px, com a origem na baseline
+    /// e y para baixo.
+    pub fn scaled_outline(&self, glyph: GlyphId, size: f32) -> Option<Path> {
+        let s = self.scale(size);
+        Some(
+            self.outline(glyph)?
+                .transformed(&Transform2D::scale_xy(s, -s)),
+        )
+    }
+
+    // =========================================================================
+    // CMAP
+    // =========================================================================
+
+    fn lookup_cmap(&self, code: u32) -> Option<u16> {
+        let d = &self.data;
+        let sub = self.cmap;
+        match read_u16(d, sub).ok()? {
+            4 => {
+                if code > 0xFFFF {
+                    return None;
+                }
+                let code = code as u16;
+                let seg_count = read_u16(d, sub + 6).ok()? as usize / 2;
+                let ends = sub + 14;
+                let starts = ends + seg_count * 2 + 2;
+                let deltas = starts + seg_count * 2;
+                let ranges = deltas + seg_count * 2;
+
+                // Busca binária pelo primeiro segmento com end >= code
+                let (mut lo, mut hi) = (0, seg_count);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    if read_u16(d, ends + mid * 2).ok()? < code {
+                        lo = mid + 1;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let i = lo;
+                if i >= seg_count || read_u16(d, starts + i * 2).ok()? > code {
+                    return None;
+                }
+
+                let start = read_u16(d, starts + i * 2).ok()?;
+                let delta = read_u16(d, deltas + i * 2).ok()?;
+                let range = read_u16(d, ranges + i * 2).ok()? as usize;
+                if range == 0 {
+                    return Some(code.wrapping_add(delta));
+                }
+                let addr = ranges + i * 2 + range + (code - start) as usize * 2;
+                match read_u16(d, addr).ok()? {
+                    0 => None,
+                    g => Some(g.wrapping_add(delta)),
+                }
+            }
+            12 => {
+                let groups = read_u32(d, sub + 12).ok()? as usize;
+                let (mut lo, mut hi) = (0, groups);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    let rec = sub + 16 + mid * 12;
+                    let start = read_u32(d, rec).ok()?;
+                    let end = read_u32(d, rec + 4).ok()?;
+                    if code < start {
+                        hi = mid;
+                    } else if code > end {
+                        lo = mid + 1;
+                    } else {
+                        let glyph = read_u32(d, rec + 8).ok()?.checked_add(code - start)?;
+                        return u16::try_from(glyph).ok();
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    // =========================================================================
+    // GLYF
+    // =========================================================================
+
+    /// Intervalo do glifo dentro de `glyf` (vazio para glifos sem contorno).
+    fn glyph_range(&self, glyph: u16) -> SysResult<(usize, usize)> {
+        if glyph >= self.num_glyphs {
+            return Err(SysError::NotFound);
+        }
+        let i = glyph as usize;
+        let (start, end) = if self.long_loca {
+            (
+                read_u32(&self.data, self.loca + i * 4)? as usize,
+                read_u32(&self.data, self.loca + i * 4 + 4)? as usize,
+            )
+        } else {
+            (
+                read_u16(&self.data, self.loca + i * 2)? as usize * 2,
+                read_u16(&self.data, self.loca + i * 2 + 2)? as usize * 2,
+            )
+        };
+        if start > end || end > self.glyf_len {
+            return Err(SysError::InvalidArgument);
+        }
+        Ok((self.glyf + start, self.glyf + end))
+    }
+
+    fn append_outline(
+        &self,
+        glyph: u16,
+        transform: &Transform2D,
+        path: &mut Path,
+        depth: u32,
+    ) -> SysResult<()> {
+        if depth > MAX_COMPOSITE_DEPTH {
+            return Err(SysError::InvalidArgument);
+        }
+        let (start, end) = self.glyph_range(glyph)?;
+        if start == end {
+            return Ok(());
+        }
+        let d = &self.data[..end];
+        let contours = read_i16(d, start)?;
+        if contours >= 0 {
+            append_simple(d, start + 10, contours as usize, transform, path)
+        } else {
+            self.append_composite(d, start + 10, transform, path, depth)
+        }
+    }
+
+    fn append_composite(
+        &self,
+        d: &[u8],
+        mut pos: usize,
+        transform: &Transform2D,
+        path: &mut Path,
+        depth: u32,
+    ) -> SysResult<()> {
+        const ARGS_ARE_WORDS: u16 = 0x0001;
+        const ARGS_ARE_XY: u16 = 0x0002;
+        const HAVE_SCALE: u16 = 0x0008;
+        const MORE_COMPONENTS: u16 = 0x0020;
+        const HAVE_XY_SCALE: u16 = 0x0040;
+        const HAVE_2X2: u16 = 0x0080;
+
+        loop {
+            let flags = read_u16(d, pos)?;
+            let component = read_u16(d, pos + 2)?;
+            pos += 4;
+
+            let (dx, dy) = if flags & ARGS_ARE_WORDS != 0 {
+                pos += 4;
+                (read_i16(d, pos - 4)? as f32, read_i16(d, pos - 2)? as f32)
+            } else {
+                pos += 2;
+                let args = d.get(pos - 2..pos).ok_or(SysError::InvalidArgument)?;
+                (args[0] as i8 as f32, args[1] as i8 as f32)
+            };
+
+            let f2dot14 = |at: usize| -> SysResult<f32> { Ok(read_i16(d, at)? as f32 / 16384.0) };
+            let (a, b, c, dd) = if flags & HAVE_SCALE != 0 {
+                pos += 2;
+                let s = f2dot14(pos - 2)?;
+                (s, 0.0, 0.0, s)
+            } else if flags & HAVE_XY_SCALE != 0 {
+                pos += 4;
+                (f2dot14(pos - 4)?, 0.0, 0.0, f2dot14(pos - 2)?)
+            } else if flags & HAVE_2X2 != 0 {
+                pos += 8;
+                (
+                    f2dot14(pos - 8)?,
+                    f2dot14(pos - 6)?,
+                    f2dot14(pos - 4)?,
+                    f2dot14(pos - 2)?,
+                )
+            } else {
+                (1.0, 0.0, 0.0, 1.0)
+            };
+
+            // Pontos casados (sem ARGS_ARE_XY) são raros; tratados como
+            // deslocamento nulo.
+            let (tx, ty) = if flags & ARGS_ARE_XY != 0 {
+                (dx, dy)
+            } else {
+                (0.0, 0.0)
+            };
+            let local = Transform2D::new(a, b, c, dd, tx, ty);
+            self.append_outline(component, &local.then(transform), path, depth + 1)?;
+
+            if flags & MORE_COMPONENTS == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Converte um glifo simples em segmentos do `Path`.
+fn append_simple(
+    d: &[u8],
+    pos: usize,
+    contours: usize,
+    transform: &Transform2D,
+    path: &mut Path,
+) -> SysResult<()> {
+    const ON_CURVE: u8 = 0x01;
+    const X_SHORT: u8 = 0x02;
+    const Y_SHORT: u8 = 0x04;
+    const REPEAT: u8 = 0x08;
+    const X_SAME: u8 = 0x10;
+    const Y_SAME: u8 = 0x20;
+
+    let mut end_points = Vec::with_capacity(contours);
+    for i in 0..contours {
+        end_points.push(read_u16(d, pos + i * 2)? as usize);
+    }
+    let count = match end_points.last() {
+        Some(&last) => last + 1,
+        None => return Ok(()),
+    };
+
+    let instructions = read_u16(d, pos + contours * 2)? as usize;
+    let mut at = pos + contours * 2 + 2 + instructions;
+
+    // Flags (com repetição)
+    let mut flags = Vec::with_capacity(count);
+    while flags.len() < count {
+        let flag = *d.get(at).ok_or(SysError::InvalidArgument)?;
+        at += 1;
+        flags.push(flag);
+        if flag & REPEAT != 0 {
+            let times = *d.get(at).ok_or(SysError::InvalidArgument)?;
+            at += 1;
+            for _ in 0..times {
+                flags.push(flag);
+            }
+        }
+    }
+    flags.truncate(count);
+
+    // Coordenadas (deltas)
+    let mut read_coords = |short: u8, same: u8| -> SysResult<Vec<i32>> {
+        let mut values = Vec::with_capacity(count);
+        let mut value = 0i32;
+        for &flag in &flags {
+            if flag & short != 0 {
+                let delta = *d.get(at).ok_or(SysError::InvalidArgument)? as i32;
+                at += 1;
+                value += if flag & same != 0 { delta } else { -delta };
+            } else if flag & same == 0 {
+                value += read_i16(d, at)? as i32;
+                at += 2;
+            }
+            values.push(value);
+        }
+        Ok(values)
+    };
+    let xs = read_coords(X_SHORT, X_SAME)?;
+    let ys = read_coords(Y_SHORT, Y_SAME)?;
+
+    let point = |i: usize| transform.transform_point(PointF::new(xs[i] as f32, ys[i] as f32));
+    let mid = |a: PointF, b: PointF| PointF::new((a.x + b.x) * 0.5, (a.y + b.y) * 0.5);
+
+    let mut first = 0;
+    for &last in &end_points {
+        if last < first || last >= count {
+            return Err(SysError::InvalidArgument);
+        }
+        let n = last - first + 1;
+        let on = |i: usize| flags[first + i % n] & ON_CURVE != 0;
+        let pt = |i: usize| point(first + i % n);
+
+        // Começar em um ponto on-curve (ou no meio de dois off-curve)
+        let (start, offset) = match (0..n).find(|&i| on(i)) {
+            Some(i) => (pt(i), i),
+            None => (mid(pt(0), pt(1)), 0),
+        };
+        path.move_to(start.x, start.y);
+
+        let mut control: Option<PointF> = None;
+        for step in 1..=n {
+            let i = offset + step;
+            let p = pt(i);
+            if on(i) {
+                match control.take() {
+                    Some(c) => path.quad_to(c.x, c.y, p.x, p.y),
+                    None => path.line_to(p.x, p.y),
+                };
+            } else {
+                if let Some(c) = control {
+                    let m = mid(c, p);
+                    path.quad_to(c.x, c.y, m.x, m.y);
+                }
+                control = Some(p);
+            }
+        }
+        if let Some(c) = control {
+            path.quad_to(c.x, c.y, start.x, start.y);
+        }
+        path.close();
+        first = last + 1;
+    }
+    Ok(())
+}
+
+/// Escolhe a subtabela Unicode do `cmap` (formato 12 preferido ao 4).
+fn find_cmap_subtable(d: &[u8], cmap: usize) -> SysResult<usize> {
+    let count = read_u16(d, cmap + 2)? as usize;
+    let mut best: Option<(u8, usize)> = None;
+    for i in 0..count {
+        let rec = cmap + 4 + i * 8;
+        let platform = read_u16(d, rec)?;
+        let encoding = read_u16(d, rec + 2)?;
+        let offset = cmap + read_u32(d, rec + 4)? as usize;
+        let format = read_u16(d, offset)?;
+
+        let unicode = platform == 0 || (platform == 3 && (encoding == 1 || encoding == 10));
+        let rank = match format {
+            12 if unicode => 2,
+            4 if unicode => 1,
+            _ => continue,
+        };
+        if best.is_none_or(|(r, _)| rank > r) {
+            best = Some((rank, offset));
+        }
+    }
+    best.map(|(_, offset)| offset).ok_or(SysError::NotSupported)
+}
+
+// =============================================================================
+// LEITURA BIG-ENDIAN
+// =============================================================================
+
+fn read_u16(d: &[u8], at: usize) -> SysResult<u16> {
+    d.get(at..at + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or(SysError::InvalidArgument)
+}
+
+fn read_i16(d: &[u8], at: usize) -> SysResult<i16> {
+    read_u16(d, at).map(|v| v as i16)
+}
+
+fn read_u32(d: &[u8], at: usize) -> SysResult<u32> {
+    d.get(at..at + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(SysError::InvalidArgument)
+}
@@ -1,9 +1,28 @@
 //! # Framebuffer
 //!
-//! Acesso direto ao framebuffer do kernel via syscalls.
+//! Acesso direto ao framebuffer do kernel.
+//!
+//! O [`Framebuffer`] mapeia a memória de vídeo no processo (`SYS_MAP`) e
+//! desenha direto nela; se o mapeamento não estiver disponível, cai para
+//! `SYS_FB_WRITE`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{Color, Framebuffer, Rect};
+//!
+//! let mut fb = Framebuffer::new()?;
+//! let mut canvas = fb.map()?;
+//! canvas.fill_rect(Rect::new(0, 0, 200, 100), Color::BLUE);
+//! ```
+
+use core::ptr::NonNull;
 
-use crate::syscall::{check_error, syscall1, syscall3, SysResult};
-use crate::syscall::{SYS_FB_CLEAR, SYS_FB_INFO, SYS_FB_WRITE};
+use crate::mem::{self, map_flags};
+use crate::syscall::safe::{sys_fb_clear, sys_fb_info, sys_fb_write};
+use crate::syscall::{SysError, SysResult};
+
+use super::canvas::Canvas;
 
 use gfx_types::buffer::BufferDescriptor;
 use gfx_types::color::{Color, PixelFormat};
@@ -68,17 +87,12 @@ impl FramebufferInfo {
 
 /// Obtém informações do framebuffer.
 pub fn get_info() -> SysResult<FramebufferInfo> {
-    let mut info = FramebufferInfo::default();
-    let ret = syscall1(SYS_FB_INFO, &mut info as *mut _ as usize);
-    check_error(ret)?;
-    Ok(info)
+    sys_fb_info()
 }
 
 /// Limpa o framebuffer com uma cor.
 pub fn clear_screen(color: Color) -> SysResult<()> {
-    let ret = syscall1(SYS_FB_CLEAR, color.0 as usize);
-    check_error(ret)?;
-    Ok(())
+    sys_fb_clear(color.0)
 }
 
 /// Escreve dados diretamente no framebuffer.
@@ -86,9 +100,7 @@ pub fn clear_screen(color: Color) -> SysResult<()> {
 /// # Safety
 /// O caller deve garantir que offset + len não excede o tamanho do framebuffer.
 pub fn write_pixels(offset: usize, data: &[u8]) -> SysResult<usize> {
-    let ret = syscall3(SYS_FB_WRITE, offset, data.as_ptr() as usize, data.len());
-    check_error(ret)?;
-    Ok(ret as usize)
+    sys_fb_write(offset, data)
 }
 
 // =============================================================================
@@ -98,13 +110,46 @@ pub fn write_pixels(offset: usize, data: &[u8]) -> SysResult<usize> {
 /// Wrapper do framebuffer com operações de desenho.
 pub struct Framebuffer {
     pub info: FramebufferInfo,
+    /// Memória de vídeo mapeada e seu tamanho em pixels, fixado no
+    /// mapeamento (None = escrita via syscall).
+    ///
+    /// `info` é público e pode mudar depois; os slices usam este tamanho.
+    mapped: Option<(NonNull<u32>, usize)>,
 }
 
 impl Framebuffer {
     /// Cria nova instância obtendo info do kernel.
+    ///
+    /// Tenta mapear a memória de vídeo; sem mapeamento, o desenho usa
+    /// `SYS_FB_WRITE`.
     pub fn new() -> SysResult<Self> {
         let info = get_info()?;
-        Ok(Self { info })
+        let mut fb = Self { info, mapped: None };
+        let _ = fb.map_memory();
+        Ok(fb)
+    }
+
+    /// Mapeia a memória de vídeo e retorna um [`Canvas`] sobre ela.
+    ///
+    /// Retorna `NotSupported` para formatos que não são 32 bpp.
+    pub fn map(&mut self) -> SysResult<Canvas<'_>> {
+        let width = self.info.width;
+        let height = self.info.height;
+        let stride_px = self.info.stride / 4;
+        let pixels = self.map_memory()?;
+
+        let mut canvas = Canvas::new(pixels, stride_px, height);
+        if stride_px != width {
+            // Não desenhar no padding de cada linha
+            canvas.set_clip(Some(Rect::new(0, 0, width, height)));
+        }
+        Ok(canvas)
+    }
+
+    /// A memória de vídeo está mapeada?
+    #[inline]
+    pub fn is_mapped(&self) -> bool {
+        self.mapped.is_some()
     }
 
     /// Largura em pixels.
@@ -139,6 +184,10 @@ impl Framebuffer {
 
     /// Limpa tela com cor.
     pub fn clear(&mut self, color: Color) -> SysResult<()> {
+        if let Some(pixels) = self.mapped_pixels() {
+            pixels.fill(color.as_u32());
+            return Ok(());
+        }
         clear_screen(color)
     }
 
@@ -148,6 +197,14 @@ impl Framebuffer {
             return Ok(());
         }
 
+        let stride_px = self.info.stride as usize / 4;
+        if let Some(pixels) = self.mapped_pixels() {
+            if let Some(pixel) = pixels.get_mut(y as usize * stride_px + x as usize) {
+                *pixel = color.as_u32();
+            }
+            return Ok(());
+        }
+
         let offset = self.info.pixel_offset(x, y);
         let pixel_data = color.0.to_le_bytes();
         write_pixels(offset, &pixel_data)?;
@@ -206,16 +263,42 @@ impl Framebuffer {
 
     /// Desenha uma linha vertical.
     pub fn vline(&mut self, x: u32, y: u32, h: u32, color: Color) -> SysResult<()> {
-        for dy in 0..h {
-            self.put_pixel(x, y + dy, color)?;
-        }
-        Ok(())
+        self.fill_rect_internal(x, y, 1, h, color)
     }
 
     // -------------------------------------------------------------------------
     // Implementação interna otimizada
     // -------------------------------------------------------------------------
 
+    /// Mapeia a memória de vídeo (uma vez) e retorna os pixels.
+    fn map_memory(&mut self) -> SysResult<&mut [u32]> {
+        if self.mapped.is_none() {
+            if self.info.bpp != 32 || self.info.stride & 3 != 0 {
+                return Err(SysError::NotSupported);
+            }
+            let size = self.info.size_bytes();
+            // SAFETY: sem FIXED, o kernel escolhe uma região livre
+            let ptr = unsafe {
+                mem::map(
+                    0,
+                    size,
+                    map_flags::READ | map_flags::WRITE | map_flags::SHARED | map_flags::FRAMEBUFFER,
+                    0,
+                )?
+            };
+            let ptr = NonNull::new(ptr as *mut u32).ok_or(SysError::BadAddress)?;
+            self.mapped = Some((ptr, size / 4));
+        }
+        self.mapped_pixels().ok_or(SysError::NotSupported)
+    }
+
+    /// Pixels mapeados (linhas de `stride / 4` pixels).
+    fn mapped_pixels(&mut self) -> Option<&mut [u32]> {
+        let (ptr, len) = self.mapped?;
+        // SAFETY: o mapeamento cobre `len` pixels e vive até o drop
+        Some(unsafe { core::slice::from_raw_parts_mut(ptr.as_ptr(), len) })
+    }
+
     fn fill_rect_internal(
         &mut self,
         x: u32,
@@ -224,6 +307,24 @@ impl Framebuffer {
         h: u32,
         color: Color,
     ) -> SysResult<()> {
+        let stride_px = self.info.stride as usize / 4;
+        let height = self.info.height;
+        let x_end = x.saturating_add(w).min(self.info.width) as usize;
+        let y_end = y.saturating_add(h).min(height);
+        if let Some(pixels) = self.mapped_pixels() {
+            if x as usize >= x_end {
+                return Ok(());
+            }
+            for py in y..y_end {
+                let row = py as usize * stride_px;
+                match pixels.get_mut(row + x as usize..row + x_end) {
+                    Some(span) => span.fill(color.as_u32()),
+                    None => break,
+                }
+            }
+            return Ok(());
+        }
+
         const CHUNK_WIDTH: usize = 1024;
         let mut line_buffer = [0u8; CHUNK_WIDTH * 4];
 
@@ -235,13 +336,8 @@ impl Framebuffer {
             line_buffer[i * 4 + 3] = pixel[3];
         }
 
-        for dy in 0..h {
-            let py = y + dy;
-            if py >= self.info.height {
-                break;
-            }
-
-            let mut pixels_remaining = w as usize;
+        for py in y..y_end {
+            let mut pixels_remaining = x_end.saturating_sub(x as usize);
             let mut current_x = x as usize;
 
             while pixels_remaining > 0 {
@@ -258,3 +354,12 @@ impl Framebuffer {
         Ok(())
     }
 }
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        if let Some((ptr, len)) = self.mapped.take() {
+            // SAFETY: `mapped` foi tomado; nenhum slice de pixels sobrevive ao drop
+            let _ = unsafe { mem::unmap(ptr.as_ptr() as *mut u8, len * 4) };
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! # Gradient
+//!
+//! Gradientes lineares e radiais com múltiplas paradas de cor, para
+//! [`Canvas::fill_rect_gradient`](crate::graphics::Canvas::fill_rect_gradient).
+//!
+//! As geometrias usam coordenadas relativas ao retângulo preenchido
+//! (`0.0..=1.0` em cada eixo), então a mesma especificação serve para
+//! qualquer tamanho (ex: barras de título do tema).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::gradient::{GradientSpec, GradientStop};
+//!
+//! const TITLE_BAR: [GradientStop; 2] = [
+//!     GradientStop::new(0.0, Color::rgb(0x44, 0x47, 0x5A)),
+//!     GradientStop::new(1.0, Color::rgb(0x28, 0x2A, 0x36)),
+//! ];
+//!
+//! canvas.fill_rect_gradient(bar, &GradientSpec::vertical(&TITLE_BAR));
+//! ```
+
+use gfx_types::color::Color;
+use gfx_types::geometry::PointF;
+
+/// Entradas da tabela de cores pré-calculada.
+const LUT_SIZE: usize = 256;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Parada de cor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    /// Posição no gradiente (`0.0..=1.0`).
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl GradientStop {
+    /// Cria uma parada.
+    pub const fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// Geometria do gradiente (coordenadas relativas ao retângulo).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// Varia ao longo da reta `start -> end`, constante nas perpendiculares.
+    Linear { start: PointF, end: PointF },
+    /// Varia com a distância a `center`; `radius` é relativo ao retângulo
+    /// (em retângulos não quadrados o círculo vira elipse).
+    Radial { center: PointF, radius: f32 },
+}
+
+/// Especificação de gradiente.
+///
+/// As paradas devem estar em ordem crescente de `offset`; antes da primeira
+/// e depois da última a cor é estendida.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientSpec<'a> {
+    pub kind: GradientKind,
+    pub stops: &'a [GradientStop],
+}
+
+impl<'a> GradientSpec<'a> {
+    /// Gradiente linear de `start` a `end`.
+    pub const fn linear(start: PointF, end: PointF, stops: &'a [GradientStop]) -> Self {
+        Self {
+            kind: GradientKind::Linear { start, end },
+            stops,
+        }
+    }
+
+    /// Gradiente radial.
+    pub const fn radial(center: PointF, radius: f32, stops: &'a [GradientStop]) -> Self {
+        Self {
+            kind: GradientKind::Radial { center, radius },
+            stops,
+        }
+    }
+
+    /// Linear de cima para baixo.
+    pub const fn vertical(stops: &'a [GradientStop]) -> Self {
+        Self::linear(PointF::new(0.0, 0.0), PointF::new(0.0, 1.0), stops)
+    }
+
+    /// Linear da esquerda para a direita.
+    pub const fn horizontal(stops: &'a [GradientStop]) -> Self {
+        Self::linear(PointF::new(0.0, 0.0), PointF::new(1.0, 0.0), stops)
+    }
+
+    /// Radial centrado, alcançando as bordas.
+    pub const fn centered(stops: &'a [GradientStop]) -> Self {
+        Self::radial(PointF::new(0.5, 0.5), 0.5, stops)
+    }
+}
+
+// =============================================================================
+// TABELA DE CORES
+// =============================================================================
+
+/// Cores do gradiente amostradas em `LUT_SIZE` posições.
+pub(crate) struct GradientLut {
+    colors: [u32; LUT_SIZE],
+    /// Alguma cor com alpha < 255?
+    pub(crate) translucent: bool,
+}
+
+impl GradientLut {
+    /// Amostra as paradas (None se não houver nenhuma).
+    pub(crate) fn new(stops: &[GradientStop]) -> Option<Self> {
+        let first = stops.first()?;
+        let last = stops[stops.len() - 1];
+        let mut colors = [0u32; LUT_SIZE];
+        let mut translucent = false;
+        let mut next = 0;
+
+        for (i, slot) in colors.iter_mut().enumerate() {
+            let t = i as f32 / (LUT_SIZE - 1) as f32;
+            while next < stops.len() && stops[next].offset <= t {
+                next += 1;
+            }
+            let color = if next == 0 {
+                first.color
+            } else if next == stops.len() {
+                last.color
+            } else {
+                let (a, b) = (stops[next - 1], stops[next]);
+                let span = b.offset - a.offset;
+                let f = if span > 0.0 {
+                    (t - a.offset) / span
+                } else {
+                    1.0
+                };
+                lerp_premultiplied(a.color, b.color, f)
+            };
+            translucent |= color.alpha() != 255;
+            *slot = color.as_u32();
+        }
+
+        Some(Self {
+            colors,
+            translucent,
+        })
+    }
+
+    /// Cor na posição `t` (fora de `0..=1` é estendida).
+    #[inline]
+    pub(crate) fn color_at(&self, t: f32) -> Color {
+        let index = (t.clamp(0.0, 1.0) * (LUT_SIZE - 1) as f32 + 0.5) as usize;
+        Color::from_raw(self.colors[index.min(LUT_SIZE - 1)])
+    }
+}
+
+/// Interpola em alpha pré-multiplicado (evita franjas escuras ao ir para
+/// transparente).
+fn lerp_premultiplied(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (aa, ba) = (a.alpha() as f32, b.alpha() as f32);
+    let alpha = aa + (ba - aa) * t;
+    if alpha <= 0.0 {
+        return Color::argb(0, 0, 0, 0);
+    }
+    let channel = |ca: u8, cb: u8| {
+        let pa = ca as f32 * aa;
+        let pb = cb as f32 * ba;
+        ((pa + (pb - pa) * t) / alpha + 0.5).min(255.0) as u8
+    };
+    Color::argb(
+        (alpha + 0.5) as u8,
+        channel(a.red(), b.red()),
+        channel(a.green(), b.green()),
+        channel(a.blue(), b.blue()),
+    )
+}
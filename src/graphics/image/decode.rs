@@ -0,0 +1,429 @@
+//! # Image Decoding
+//!
+//! Decodificação de BMP e QOI para pixels ARGB8888 (0xAARRGGBB), prontos
+//! para [`Canvas::draw_image`](crate::graphics::Canvas::draw_image).
+//!
+//! Formatos suportados:
+//!
+//! - **BMP**: 1/4/8 bits com paleta, 16/24/32 bits (RGB e bitfields),
+//!   top-down ou bottom-up. Compressão RLE não é suportada.
+//! - **QOI**: RGB e RGBA.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use gfx_types::geometry::Size;
+
+use crate::fs::File;
+use crate::graphics::surface::Surface;
+use crate::syscall::{SysError, SysResult};
+
+/// Limite de pixels por imagem (evita alocações absurdas com headers
+/// corrompidos).
+pub const MAX_IMAGE_PIXELS: u64 = 1 << 26;
+
+// =============================================================================
+// IMAGE BUFFER
+// =============================================================================
+
+/// Formato de origem de uma imagem decodificada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Bmp,
+    Qoi,
+    /// Pixels sem arquivo de origem (ex.: captura de tela).
+    Raw,
+}
+
+impl ImageFormat {
+    /// Detecta o formato pela assinatura.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"BM") {
+            Some(Self::Bmp)
+        } else if bytes.starts_with(b"qoif") {
+            Some(Self::Qoi)
+        } else {
+            None
+        }
+    }
+}
+
+/// Imagem decodificada em ARGB8888.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageBuffer {
+    surface: Surface,
+    format: ImageFormat,
+}
+
+impl ImageBuffer {
+    /// Imagem a partir de pixels já em memória ([`ImageFormat::Raw`]).
+    pub fn from_surface(surface: Surface) -> Self {
+        Self {
+            surface,
+            format: ImageFormat::Raw,
+        }
+    }
+
+    /// Largura em pixels.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.surface.width()
+    }
+
+    /// Altura em pixels.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.surface.height()
+    }
+
+    /// Dimensões.
+    #[inline]
+    pub fn size(&self) -> Size {
+        self.surface.size()
+    }
+
+    /// Formato de origem.
+    #[inline]
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Pixels ARGB, linha a linha.
+    #[inline]
+    pub fn pixels(&self) -> &[u32] {
+        self.surface.pixels()
+    }
+
+    /// Pixels como [`Surface`].
+    #[inline]
+    pub fn as_surface(&self) -> &Surface {
+        &self.surface
+    }
+
+    /// Converte em [`Surface`] (sem cópia).
+    #[inline]
+    pub fn into_surface(self) -> Surface {
+        self.surface
+    }
+}
+
+impl From<ImageBuffer> for Surface {
+    fn from(image: ImageBuffer) -> Self {
+        image.surface
+    }
+}
+
+// =============================================================================
+// API PÚBLICA
+// =============================================================================
+
+/// Decodifica uma imagem BMP ou QOI (detectada pela assinatura).
+pub fn decode(bytes: &[u8]) -> SysResult<ImageBuffer> {
+    let format = ImageFormat::detect(bytes).ok_or(SysError::NotSupported)?;
+    let (width, height, pixels) = match format {
+        ImageFormat::Bmp => decode_bmp(bytes)?,
+        ImageFormat::Qoi => decode_qoi(bytes)?,
+        ImageFormat::Raw => return Err(SysError::NotSupported),
+    };
+    let surface = Surface::from_pixels(width, height, pixels).ok_or(SysError::InvalidArgument)?;
+    Ok(ImageBuffer { surface, format })
+}
+
+/// Lê e decodifica a imagem em `path`.
+pub fn load(path: &str) -> SysResult<ImageBuffer> {
+    let file = File::open(path)?;
+    let size = usize::try_from(file.size()?).map_err(|_| SysError::OutOfMemory)?;
+    let mut bytes = vec![0u8; size];
+    file.read_exact(&mut bytes)?;
+    decode(&bytes)
+}
+
+/// Aloca o buffer de pixels, validando as dimensões.
+fn alloc_pixels(width: u32, height: u32) -> SysResult<Vec<u32>> {
+    let count = width as u64 * height as u64;
+    if count == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+    if count > MAX_IMAGE_PIXELS {
+        return Err(SysError::OutOfMemory);
+    }
+    Ok(vec![0; count as usize])
+}
+
+#[inline]
+fn argb(a: u8, r: u8, g: u8, b: u8) -> u32 {
+    u32::from_be_bytes([a, r, g, b])
+}
+
+// =============================================================================
+// BMP
+// =============================================================================
+
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+/// Máscara de um canal (bitfields).
+#[derive(Clone, Copy)]
+struct Channel {
+    mask: u32,
+    shift: u32,
+    max: u32,
+}
+
+impl Channel {
+    fn new(mask: u32) -> Self {
+        let shift = if mask == 0 { 0 } else { mask.trailing_zeros() };
+        let bits = (mask >> shift).trailing_ones();
+        Self {
+            mask,
+            shift,
+            max: if bits == 0 {
+                0
+            } else {
+                (1u64 << bits) as u32 - 1
+            },
+        }
+    }
+
+    /// Extrai o canal e escala para 8 bits.
+    #[inline]
+    fn extract(&self, px: u32) -> u8 {
+        if self.max == 0 {
+            return 0;
+        }
+        let v = (px & self.mask) >> self.shift;
+        ((v.min(self.max) as u64 * 255 + self.max as u64 / 2) / self.max as u64) as u8
+    }
+}
+
+fn decode_bmp(d: &[u8]) -> SysResult<(u32, u32, Vec<u32>)> {
+    let data_offset = le_u32(d, 10)? as usize;
+    let header_size = le_u32(d, 14)? as usize;
+
+    let (width, raw_height, bpp, compression, colors_used) = if header_size == 12 {
+        // BITMAPCOREHEADER
+        (
+            le_u16(d, 18)? as i32,
+            le_u16(d, 20)? as i16 as i32,
+            le_u16(d, 24)?,
+            BI_RGB,
+            0,
+        )
+    } else if header_size >= 40 {
+        (
+            le_u32(d, 18)? as i32,
+            le_u32(d, 22)? as i32,
+            le_u16(d, 28)?,
+            le_u32(d, 30)?,
+            le_u32(d, 46)? as usize,
+        )
+    } else {
+        return Err(SysError::InvalidArgument);
+    };
+
+    if width <= 0 || raw_height == 0 || raw_height == i32::MIN {
+        return Err(SysError::InvalidArgument);
+    }
+    let top_down = raw_height < 0;
+    let (width, height) = (width as u32, raw_height.unsigned_abs());
+
+    // Máscaras de canal (R, G, B, A)
+    let bitfields = compression == BI_BITFIELDS || compression == BI_ALPHABITFIELDS;
+    let masks = match (compression, bpp) {
+        (BI_RGB, 16) => [0x7C00, 0x03E0, 0x001F, 0],
+        (BI_RGB, 24 | 32) => [0x00FF_0000, 0x0000_FF00, 0x0000_00FF, 0],
+        _ if bitfields && (bpp == 16 || bpp == 32) => {
+            let alpha = if header_size >= 56 || compression == BI_ALPHABITFIELDS {
+                le_u32(d, 66)?
+            } else {
+                0
+            };
+            [le_u32(d, 54)?, le_u32(d, 58)?, le_u32(d, 62)?, alpha]
+        }
+        (BI_RGB, 1 | 4 | 8) => [0; 4],
+        _ => return Err(SysError::NotSupported),
+    };
+    let channels = masks.map(Channel::new);
+
+    // Paleta (BGRX; BGR no header core)
+    let mut palette = Vec::new();
+    if bpp <= 8 {
+        let entry = if header_size == 12 { 3 } else { 4 };
+        let count = match colors_used {
+            0 => 1usize << bpp,
+            n => n.min(1 << bpp),
+        };
+        let mut at = 14 + header_size;
+        if bitfields && header_size == 40 {
+            at += 12;
+        }
+        for _ in 0..count {
+            let c = d.get(at..at + 3).ok_or(SysError::InvalidArgument)?;
+            palette.push(argb(0xFF, c[2], c[1], c[0]));
+            at += entry;
+        }
+    }
+
+    let stride = (width as usize * bpp as usize).div_ceil(32) * 4;
+    let needed = stride
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_add(data_offset))
+        .ok_or(SysError::InvalidArgument)?;
+    if needed > d.len() {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let mut pixels = alloc_pixels(width, height)?;
+    let has_alpha = channels[3].max != 0;
+    let mut any_alpha = false;
+
+    for row in 0..height as usize {
+        let src_row = if top_down {
+            row
+        } else {
+            height as usize - 1 - row
+        };
+        let src = &d[data_offset + src_row * stride..][..stride];
+        let dst = &mut pixels[row * width as usize..][..width as usize];
+
+        for (x, out) in dst.iter_mut().enumerate() {
+            *out = match bpp {
+                1 | 4 | 8 => {
+                    let bit = x * bpp as usize;
+                    let shift = 8 - bpp as usize - bit % 8;
+                    let index = (src[bit / 8] >> shift) as usize & ((1 << bpp) - 1);
+                    // Índice fora da paleta: preto
+                    palette.get(index).copied().unwrap_or(argb(0xFF, 0, 0, 0))
+                }
+                _ => {
+                    let px = match bpp {
+                        16 => le_u16(src, x * 2)? as u32,
+                        24 => {
+                            let p = &src[x * 3..x * 3 + 3];
+                            u32::from_le_bytes([p[0], p[1], p[2], 0])
+                        }
+                        _ => le_u32(src, x * 4)?,
+                    };
+                    let a = if has_alpha {
+                        channels[3].extract(px)
+                    } else {
+                        0xFF
+                    };
+                    any_alpha |= a != 0;
+                    argb(
+                        a,
+                        channels[0].extract(px),
+                        channels[1].extract(px),
+                        channels[2].extract(px),
+                    )
+                }
+            };
+        }
+    }
+
+    // Alfa totalmente zerado indica canal não usado: tratar como opaco
+    if has_alpha && !any_alpha {
+        for px in &mut pixels {
+            *px |= 0xFF00_0000;
+        }
+    }
+
+    Ok((width, height, pixels))
+}
+
+// =============================================================================
+// QOI
+// =============================================================================
+
+const QOI_HEADER_LEN: usize = 14;
+const QOI_END_MARKER: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+const QOI_OP_INDEX: u8 = 0x00;
+const QOI_OP_DIFF: u8 = 0x40;
+const QOI_OP_LUMA: u8 = 0x80;
+const QOI_OP_RUN: u8 = 0xC0;
+const QOI_OP_RGB: u8 = 0xFE;
+const QOI_OP_RGBA: u8 = 0xFF;
+const QOI_MASK_2: u8 = 0xC0;
+
+fn decode_qoi(d: &[u8]) -> SysResult<(u32, u32, Vec<u32>)> {
+    if d.len() < QOI_HEADER_LEN + QOI_END_MARKER.len() {
+        return Err(SysError::InvalidArgument);
+    }
+    let width = u32::from_be_bytes([d[4], d[5], d[6], d[7]]);
+    let height = u32::from_be_bytes([d[8], d[9], d[10], d[11]]);
+    if !matches!(d[12], 3 | 4) {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let mut pixels = alloc_pixels(width, height)?;
+    let data = &d[..d.len() - QOI_END_MARKER.len()];
+    let mut at = QOI_HEADER_LEN;
+
+    let mut index = [[0u8; 4]; 64];
+    let mut px = [0u8, 0, 0, 255];
+    let mut run = 0u32;
+
+    for out in pixels.iter_mut() {
+        if run > 0 {
+            run -= 1;
+        } else if at < data.len() {
+            let op = data[at];
+            at += 1;
+            match op {
+                QOI_OP_RGB | QOI_OP_RGBA => {
+                    let n = if op == QOI_OP_RGB { 3 } else { 4 };
+                    let bytes = data.get(at..at + n).ok_or(SysError::InvalidArgument)?;
+                    px[..n].copy_from_slice(bytes);
+                    at += n;
+                }
+                _ => match op & QOI_MASK_2 {
+                    QOI_OP_INDEX => px = index[op as usize],
+                    QOI_OP_DIFF => {
+                        px[0] = px[0].wrapping_add((op >> 4) & 3).wrapping_sub(2);
+                        px[1] = px[1].wrapping_add((op >> 2) & 3).wrapping_sub(2);
+                        px[2] = px[2].wrapping_add(op & 3).wrapping_sub(2);
+                    }
+                    QOI_OP_LUMA => {
+                        let b2 = *data.get(at).ok_or(SysError::InvalidArgument)?;
+                        at += 1;
+                        let dg = (op & 0x3F).wrapping_sub(32);
+                        px[0] = px[0].wrapping_add(dg.wrapping_sub(8).wrapping_add(b2 >> 4));
+                        px[1] = px[1].wrapping_add(dg);
+                        px[2] = px[2].wrapping_add(dg.wrapping_sub(8).wrapping_add(b2 & 0x0F));
+                    }
+                    QOI_OP_RUN => run = (op & 0x3F) as u32,
+                    _ => unreachable!(),
+                },
+            }
+            let [r, g, b, a] = px;
+            let hash = (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64;
+            index[hash] = px;
+        } else {
+            // Stream truncado
+            return Err(SysError::InvalidArgument);
+        }
+        *out = argb(px[3], px[0], px[1], px[2]);
+    }
+
+    Ok((width, height, pixels))
+}
+
+// =============================================================================
+// LEITURA LITTLE-ENDIAN
+// =============================================================================
+
+fn le_u16(d: &[u8], at: usize) -> SysResult<u16> {
+    d.get(at..at + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or(SysError::InvalidArgument)
+}
+
+fn le_u32(d: &[u8], at: usize) -> SysResult<u32> {
+    d.get(at..at + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(SysError::InvalidArgument)
+}
@@ -0,0 +1,262 @@
+//! # Image Encoding
+//!
+//! Escrita de [`Surface`] em formatos de imagem (PNG e PPM), via
+//! [`io::Write`](crate::io::Write) — arquivos, buffers em memória, etc.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{image, Surface};
+//!
+//! let surface = Surface::new(320, 240);
+//! image::save_png(&surface, "/tmp/screenshot.png")?;
+//! ```
+//!
+//! O PNG é gerado sem compressão (blocos deflate "stored"): o arquivo é
+//! maior, mas a codificação é linear e não precisa de memória extra.
+
+use crate::fs::File;
+use crate::graphics::surface::Surface;
+use crate::io::Write;
+use crate::syscall::{SysError, SysResult};
+
+/// Tamanho do buffer intermediário de escrita.
+const STAGING_SIZE: usize = 4096;
+
+/// Tamanho máximo de um bloco deflate stored.
+const MAX_STORED_BLOCK: usize = 0xFFFF;
+
+/// Assinatura PNG.
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+// =============================================================================
+// API PÚBLICA
+// =============================================================================
+
+/// Codifica a surface como PNG RGBA 8-bit.
+pub fn encode_png<W: Write>(surface: &Surface, out: &mut W) -> SysResult<()> {
+    let (width, height) = (surface.width(), surface.height());
+    if width == 0 || height == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+
+    // Tamanho do stream zlib: header + blocos stored + adler32
+    let raw_len = height as u64 * (1 + width as u64 * 4);
+    let blocks = raw_len.div_ceil(MAX_STORED_BLOCK as u64);
+    let zlib_len = 2 + raw_len + blocks * 5 + 4;
+    if zlib_len > i32::MAX as u64 {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let mut stream = Staging::new(out);
+    stream.put_plain(&PNG_SIGNATURE)?;
+
+    // IHDR: dimensões, 8 bits, RGBA, deflate, filtro 0, sem interlace
+    stream.begin_chunk(b"IHDR", 13)?;
+    stream.put(&width.to_be_bytes())?;
+    stream.put(&height.to_be_bytes())?;
+    stream.put(&[8, 6, 0, 0, 0])?;
+    stream.end_chunk()?;
+
+    stream.begin_chunk(b"IDAT", zlib_len as u32)?;
+    stream.put(&[0x78, 0x01])?;
+    let mut deflate = StoredDeflate::new(raw_len as usize);
+    for y in 0..height {
+        deflate.put(&mut stream, &[0])?;
+        for &argb in surface.row(y) {
+            let [a, r, g, b] = argb.to_be_bytes();
+            deflate.put(&mut stream, &[r, g, b, a])?;
+        }
+    }
+    stream.put(&deflate.adler().to_be_bytes())?;
+    stream.end_chunk()?;
+
+    stream.begin_chunk(b"IEND", 0)?;
+    stream.end_chunk()?;
+    stream.flush()
+}
+
+/// Codifica a surface como PPM binário (P6). O canal alfa é descartado.
+pub fn encode_ppm<W: Write>(surface: &Surface, out: &mut W) -> SysResult<()> {
+    let mut stream = Staging::new(out);
+
+    stream.put_plain(b"P6\n")?;
+    stream.put_decimal(surface.width())?;
+    stream.put_plain(b" ")?;
+    stream.put_decimal(surface.height())?;
+    stream.put_plain(b"\n255\n")?;
+
+    for &argb in surface.pixels() {
+        let [_, r, g, b] = argb.to_be_bytes();
+        stream.put_plain(&[r, g, b])?;
+    }
+    stream.flush()
+}
+
+/// Salva a surface como PNG em `path`.
+pub fn save_png(surface: &Surface, path: &str) -> SysResult<()> {
+    let mut file = File::create(path)?;
+    encode_png(surface, &mut file)
+}
+
+/// Salva a surface como PPM em `path`.
+pub fn save_ppm(surface: &Surface, path: &str) -> SysResult<()> {
+    let mut file = File::create(path)?;
+    encode_ppm(surface, &mut file)
+}
+
+// =============================================================================
+// STAGING + CRC
+// =============================================================================
+
+/// Buffer de escrita com CRC32 incremental (chunks PNG).
+struct Staging<'w, W: Write> {
+    out: &'w mut W,
+    buf: [u8; STAGING_SIZE],
+    len: usize,
+    crc: u32,
+}
+
+impl<'w, W: Write> Staging<'w, W> {
+    fn new(out: &'w mut W) -> Self {
+        Self {
+            out,
+            buf: [0; STAGING_SIZE],
+            len: 0,
+            crc: 0,
+        }
+    }
+
+    /// Escreve sem atualizar o CRC.
+    fn put_plain(&mut self, data: &[u8]) -> SysResult<()> {
+        for &byte in data {
+            self.buf[self.len] = byte;
+            self.len += 1;
+            if self.len == STAGING_SIZE {
+                self.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Escreve atualizando o CRC do chunk atual.
+    fn put(&mut self, data: &[u8]) -> SysResult<()> {
+        self.crc = crc32_update(self.crc, data);
+        self.put_plain(data)
+    }
+
+    fn put_decimal(&mut self, mut value: u32) -> SysResult<()> {
+        let mut digits = [0u8; 10];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        self.put_plain(&digits[i..])
+    }
+
+    /// Inicia chunk PNG: tamanho (fora do CRC) + tipo (dentro do CRC).
+    fn begin_chunk(&mut self, kind: &[u8; 4], length: u32) -> SysResult<()> {
+        self.put_plain(&length.to_be_bytes())?;
+        self.crc = 0xFFFF_FFFF;
+        self.put(kind)
+    }
+
+    fn end_chunk(&mut self) -> SysResult<()> {
+        let crc = !self.crc;
+        self.put_plain(&crc.to_be_bytes())
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        if self.len > 0 {
+            self.out.write_all(&self.buf[..self.len])?;
+            self.len = 0;
+        }
+        self.out.flush()
+    }
+}
+
+/// Tabela CRC32 (polinômio 0xEDB88320), gerada em compile-time.
+static CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+};
+
+fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc = CRC_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+// =============================================================================
+// DEFLATE (STORED)
+// =============================================================================
+
+/// Enquadra dados brutos em blocos deflate stored e calcula o Adler-32.
+struct StoredDeflate {
+    /// Bytes restantes no bloco atual.
+    block_left: usize,
+    /// Bytes restantes no stream inteiro.
+    total_left: usize,
+    a: u32,
+    b: u32,
+}
+
+impl StoredDeflate {
+    fn new(total: usize) -> Self {
+        Self {
+            block_left: 0,
+            total_left: total,
+            a: 1,
+            b: 0,
+        }
+    }
+
+    fn put<W: Write>(&mut self, stream: &mut Staging<'_, W>, mut data: &[u8]) -> SysResult<()> {
+        for &byte in data {
+            self.a = (self.a + byte as u32) % 65521;
+            self.b = (self.b + self.a) % 65521;
+        }
+
+        while !data.is_empty() {
+            if self.block_left == 0 {
+                let len = self.total_left.min(MAX_STORED_BLOCK);
+                let last = (len == self.total_left) as u8;
+                let [lo, hi] = (len as u16).to_le_bytes();
+                stream.put(&[last, lo, hi, !lo, !hi])?;
+                self.block_left = len;
+            }
+
+            let n = data.len().min(self.block_left);
+            stream.put(&data[..n])?;
+            self.block_left -= n;
+            self.total_left -= n;
+            data = &data[n..];
+        }
+        Ok(())
+    }
+
+    fn adler(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
@@ -0,0 +1,27 @@
+//! # Imagens
+//!
+//! Leitura e escrita de imagens.
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`decode`] | Decodificação BMP/QOI para ARGB8888 |
+//! | [`encode`] | Codificação PNG/PPM de uma [`Surface`](super::Surface) |
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{image, Point};
+//!
+//! let icon = image::load("/system/icons/folder.qoi")?;
+//! canvas.draw_image(&icon, Point::new(16, 16));
+//! ```
+
+pub mod decode;
+pub mod encode;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use decode::{decode, load, ImageBuffer, ImageFormat};
+pub use encode::{encode_png, encode_ppm, save_png, save_ppm};
@@ -10,16 +10,40 @@
 //! | Módulo | Descrição |
 //! |--------|-----------|
 //! | [`framebuffer`] | Acesso ao framebuffer do kernel |
+//! | [`anim`] | Ticker de animação pela taxa do monitor |
 //! | [`canvas`] | API de desenho sobre buffers |
+//! | [`clip`] | Regiões de recorte não retangulares |
+//! | [`damage`] | Regiões danificadas em faixas |
 //! | [`draw`] | Primitivas de desenho (linhas, círculos) |
+//! | [`path`] | Caminhos vetoriais (Bézier) e rasterização |
+//! | [`gradient`] | Gradientes lineares e radiais |
+//! | [`chart`] | Gráficos de linha/barras e layout de eixos |
+//! | [`scale`] | Fator de escala (HiDPI), coordenadas lógicas e físicas |
+//! | [`surface`] | Buffer de pixels com dono próprio |
+//! | [`image`] | Decodificação BMP/QOI e codificação PNG/PPM |
+//! | [`qr`] | Gerador de QR Code |
+//! | [`text`] | Fonte bitmap embutida, medição e quebra de texto |
+//! | [`font`] | Fontes TrueType, rasterização e cache de glifos |
 //!
 //! ## Re-exports de gfx_types
 //!
 //! Todos os tipos de `gfx_types` são re-exportados aqui para conveniência.
 
+pub mod anim;
 pub mod canvas;
+pub mod chart;
+pub mod clip;
+pub mod damage;
 pub mod draw;
+pub mod font;
 pub mod framebuffer;
+pub mod gradient;
+pub mod image;
+pub mod path;
+pub mod qr;
+pub mod scale;
+pub mod surface;
+pub mod text;
 
 // =============================================================================
 // RE-EXPORTS DE GFX_TYPES
@@ -41,15 +65,28 @@ pub use gfx_types::color::{
 pub use gfx_types::buffer::{BufferDescriptor, BufferHandle, BufferRegion, BufferUsage};
 
 // Render
-pub use gfx_types::render::{BlitParams, ClipOp, ClipRect, FillParams, RenderOp};
+pub use gfx_types::render::{
+    BlitParams, ClipOp, ClipRect, FillParams, InterpolationQuality, RenderOp,
+};
 
 // Damage
-pub use gfx_types::damage::{DamageHint, DamageRegion};
+pub use gfx_types::damage::DamageHint;
+
+// Text
+pub use gfx_types::text::TextAlign;
 
 // =============================================================================
 // EXPORTS DO MÓDULO
 // =============================================================================
 
 pub use canvas::Canvas;
-pub use draw::{draw_circle, draw_line, draw_rect};
+pub use clip::{ClipRegion, ClipSpan};
+pub use damage::DamageRegion;
+pub use draw::{aa_circle, aa_line, draw_circle, draw_line, draw_rect};
+pub use font::{Font, GlyphCache};
 pub use framebuffer::{clear_screen, get_info, write_pixels, Framebuffer, FramebufferInfo};
+pub use gradient::{GradientKind, GradientSpec, GradientStop};
+pub use path::{FillRule, LineCap, LineJoin, Path, StrokeStyle};
+pub use scale::ScaleFactor;
+pub use surface::Surface;
+pub use text::{BitmapFont, FONT_8X16};
@@ -0,0 +1,716 @@
+//! # Path
+//!
+//! Caminhos vetoriais (linhas e curvas de Bézier) com rasterização por scanline.
+//!
+//! Curvas são achatadas em polilinhas e preenchidas linha a linha, então o
+//! mesmo caminho pode ser desenhado em qualquer escala (ícones, gráficos).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::path::{FillRule, LineCap, LineJoin, Path, StrokeStyle};
+//!
+//! let mut path = Path::new();
+//! path.move_to(10.0, 10.0);
+//! path.line_to(90.0, 10.0);
+//! path.quad_to(90.0, 90.0, 10.0, 90.0);
+//! path.close();
+//!
+//! canvas.fill_path(&path, FillRule::NonZero, Color::RED);
+//! canvas.stroke_path(&path, &StrokeStyle::new(3.0).with_join(LineJoin::Round), Color::WHITE);
+//!
+//! let mut arrow = Path::new();
+//! arrow.move_to(10.0, 50.0).line_to(80.0, 50.0);
+//! canvas.stroke_path(&arrow, &StrokeStyle::new(4.0).with_cap(LineCap::Round), Color::WHITE);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::{PointF, Rect, Transform2D};
+use rdsmath::{absf, ceilf, cosf, floorf, sinf, sqrtf, TAU};
+
+/// Tolerância padrão de achatamento de curvas (em pixels).
+pub const DEFAULT_TOLERANCE: f32 = 0.25;
+
+/// Número máximo de segmentos gerados por curva.
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Segmento de um caminho.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathSegment {
+    /// Inicia novo sub-caminho.
+    MoveTo(PointF),
+    /// Linha reta até o ponto.
+    LineTo(PointF),
+    /// Bézier quadrática (controle, destino).
+    QuadTo(PointF, PointF),
+    /// Bézier cúbica (controle 1, controle 2, destino).
+    CubicTo(PointF, PointF, PointF),
+    /// Fecha o sub-caminho atual.
+    Close,
+}
+
+/// Regra de preenchimento.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Ponto é interno se o winding number for diferente de zero.
+    #[default]
+    NonZero,
+    /// Ponto é interno se cruzar um número ímpar de arestas.
+    EvenOdd,
+}
+
+/// Junção entre segmentos consecutivos de um traçado.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Canto vivo (limitado por `miter_limit`).
+    #[default]
+    Miter,
+    /// Canto arredondado.
+    Round,
+    /// Canto chanfrado.
+    Bevel,
+}
+
+/// Terminação das pontas de sub-caminhos abertos.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// Termina exatamente no ponto final.
+    #[default]
+    Butt,
+    /// Semicírculo de diâmetro igual à largura.
+    Round,
+    /// Estende meia largura além do ponto final.
+    Square,
+}
+
+/// Estilo de traçado.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeStyle {
+    /// Largura da linha em pixels.
+    pub width: f32,
+    /// Tipo de junção.
+    pub join: LineJoin,
+    /// Terminação das pontas.
+    pub cap: LineCap,
+    /// Razão máxima entre comprimento do miter e largura (acima vira bevel).
+    pub miter_limit: f32,
+}
+
+impl StrokeStyle {
+    /// Cria estilo com a largura dada.
+    pub const fn new(width: f32) -> Self {
+        Self {
+            width,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+        }
+    }
+
+    /// Define o tipo de junção.
+    pub const fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Define a terminação das pontas.
+    pub const fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Define o limite de miter.
+    pub const fn with_miter_limit(mut self, limit: f32) -> Self {
+        self.miter_limit = limit;
+        self
+    }
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Sub-caminho achatado em polilinha.
+#[derive(Clone, Debug, Default)]
+pub struct Contour {
+    /// Vértices da polilinha.
+    pub points: Vec<PointF>,
+    /// Sub-caminho foi fechado com `close()`.
+    pub closed: bool,
+}
+
+// =============================================================================
+// PATH
+// =============================================================================
+
+/// Caminho vetorial composto de segmentos.
+#[derive(Clone, Debug, Default)]
+pub struct Path {
+    segments: Vec<PathSegment>,
+    /// Início do sub-caminho atual.
+    start: PointF,
+    /// Posição atual da caneta.
+    current: PointF,
+}
+
+impl Path {
+    /// Cria caminho vazio.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inicia novo sub-caminho em (x, y).
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        let p = PointF::new(x, y);
+        self.segments.push(PathSegment::MoveTo(p));
+        self.start = p;
+        self.current = p;
+        self
+    }
+
+    /// Linha reta até (x, y).
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.ensure_started();
+        let p = PointF::new(x, y);
+        self.segments.push(PathSegment::LineTo(p));
+        self.current = p;
+        self
+    }
+
+    /// Bézier quadrática com controle (cx, cy) até (x, y).
+    pub fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> &mut Self {
+        self.ensure_started();
+        let p = PointF::new(x, y);
+        self.segments
+            .push(PathSegment::QuadTo(PointF::new(cx, cy), p));
+        self.current = p;
+        self
+    }
+
+    /// Bézier cúbica com controles (c1x, c1y) e (c2x, c2y) até (x, y).
+    pub fn cubic_to(
+        &mut self,
+        c1x: f32,
+        c1y: f32,
+        c2x: f32,
+        c2y: f32,
+        x: f32,
+        y: f32,
+    ) -> &mut Self {
+        self.ensure_started();
+        let p = PointF::new(x, y);
+        self.segments.push(PathSegment::CubicTo(
+            PointF::new(c1x, c1y),
+            PointF::new(c2x, c2y),
+            p,
+        ));
+        self.current = p;
+        self
+    }
+
+    /// Fecha o sub-caminho atual (volta ao ponto inicial).
+    pub fn close(&mut self) -> &mut Self {
+        if !self.segments.is_empty() {
+            self.segments.push(PathSegment::Close);
+            self.current = self.start;
+        }
+        self
+    }
+
+    /// Segmentos do caminho.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Verifica se o caminho está vazio.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Posição atual da caneta.
+    pub fn current_point(&self) -> PointF {
+        self.current
+    }
+
+    /// Retorna cópia do caminho com a transformação aplicada.
+    ///
+    /// Útil para desenhar o mesmo ícone em escalas diferentes.
+    pub fn transformed(&self, t: &Transform2D) -> Path {
+        let segments = self
+            .segments
+            .iter()
+            .map(|seg| match *seg {
+                PathSegment::MoveTo(p) => PathSegment::MoveTo(t.transform_point(p)),
+                PathSegment::LineTo(p) => PathSegment::LineTo(t.transform_point(p)),
+                PathSegment::QuadTo(c, p) => {
+                    PathSegment::QuadTo(t.transform_point(c), t.transform_point(p))
+                }
+                PathSegment::CubicTo(c1, c2, p) => PathSegment::CubicTo(
+                    t.transform_point(c1),
+                    t.transform_point(c2),
+                    t.transform_point(p),
+                ),
+                PathSegment::Close => PathSegment::Close,
+            })
+            .collect();
+
+        Path {
+            segments,
+            start: t.transform_point(self.start),
+            current: t.transform_point(self.current),
+        }
+    }
+
+    /// Achata curvas em polilinhas.
+    ///
+    /// `tolerance` é o desvio máximo aceito entre curva e polilinha (pixels).
+    pub fn flatten(&self, tolerance: f32) -> Vec<Contour> {
+        let tolerance = tolerance.max(0.01);
+        let mut contours = Vec::new();
+        let mut contour = Contour::default();
+        let mut last = PointF::ZERO;
+
+        for seg in &self.segments {
+            match *seg {
+                PathSegment::MoveTo(p) => {
+                    if contour.points.len() > 1 {
+                        contours.push(core::mem::take(&mut contour));
+                    }
+                    contour.points.clear();
+                    contour.closed = false;
+                    contour.points.push(p);
+                    last = p;
+                }
+                PathSegment::LineTo(p) => {
+                    contour.points.push(p);
+                    last = p;
+                }
+                PathSegment::QuadTo(c, p) => {
+                    flatten_quad(last, c, p, tolerance, &mut contour.points);
+                    last = p;
+                }
+                PathSegment::CubicTo(c1, c2, p) => {
+                    flatten_cubic(last, c1, c2, p, tolerance, &mut contour.points);
+                    last = p;
+                }
+                PathSegment::Close => {
+                    if let Some(&first) = contour.points.first() {
+                        if contour.points.len() > 1 {
+                            contour.closed = true;
+                            contours.push(core::mem::take(&mut contour));
+                            // Continua a partir do início do sub-caminho fechado
+                            contour.points.push(first);
+                        }
+                        last = first;
+                    }
+                }
+            }
+        }
+
+        if contour.points.len() > 1 {
+            contours.push(contour);
+        }
+
+        contours
+    }
+
+    /// Gera spans horizontais `(x, y, width)` que preenchem o caminho.
+    ///
+    /// Sub-caminhos abertos são fechados implicitamente. Apenas spans dentro
+    /// de `clip` são emitidos.
+    pub fn fill_spans(&self, rule: FillRule, clip: Rect, emit: impl FnMut(i32, i32, u32)) {
+        let contours = self.flatten(DEFAULT_TOLERANCE);
+        rasterize(&contours, rule, clip, emit);
+    }
+
+    /// Gera spans horizontais `(x, y, width)` que cobrem o traçado do caminho.
+    pub fn stroke_spans(&self, style: &StrokeStyle, clip: Rect, emit: impl FnMut(i32, i32, u32)) {
+        let outline = self.stroke_outline(style);
+        rasterize(&outline, FillRule::NonZero, clip, emit);
+    }
+
+    /// Converte o traçado em polígonos preenchíveis (regra NonZero).
+    ///
+    /// Cada segmento, junção e ponta vira um polígono convexo com a mesma
+    /// orientação, então a união é obtida preenchendo com `FillRule::NonZero`.
+    pub fn stroke_outline(&self, style: &StrokeStyle) -> Vec<Contour> {
+        let mut out = Vec::new();
+        let hw = style.width * 0.5;
+        if hw <= 0.0 {
+            return out;
+        }
+
+        for contour in self.flatten(DEFAULT_TOLERANCE) {
+            let points = dedup_points(&contour.points, contour.closed);
+            if points.len() < 2 {
+                continue;
+            }
+
+            let n = points.len();
+            let seg_count = if contour.closed { n } else { n - 1 };
+
+            for i in 0..seg_count {
+                let a = points[i];
+                let b = points[(i + 1) % n];
+                push_polygon(&mut out, segment_quad(a, b, hw));
+            }
+
+            // Junções: vértices internos, mais todos os vértices se fechado
+            let (first, last) = if contour.closed { (0, n) } else { (1, n - 1) };
+            for i in first..last {
+                let prev = points[(i + n - 1) % n];
+                let p = points[i];
+                let next = points[(i + 1) % n];
+                stroke_join(&mut out, prev, p, next, hw, style);
+            }
+
+            if !contour.closed {
+                stroke_cap(&mut out, points[1], points[0], hw, style.cap);
+                stroke_cap(&mut out, points[n - 2], points[n - 1], hw, style.cap);
+            }
+        }
+
+        out
+    }
+
+    fn ensure_started(&mut self) {
+        if self.segments.is_empty() {
+            self.segments.push(PathSegment::MoveTo(self.current));
+            self.start = self.current;
+        }
+    }
+}
+
+// =============================================================================
+// ACHATAMENTO DE CURVAS
+// =============================================================================
+
+fn flatten_quad(p0: PointF, p1: PointF, p2: PointF, tol: f32, out: &mut Vec<PointF>) {
+    // Erro de corda ≤ |P0 - 2P1 + P2| / (4n²)
+    let ddx = p0.x - 2.0 * p1.x + p2.x;
+    let ddy = p0.y - 2.0 * p1.y + p2.y;
+    let dd = sqrtf(ddx * ddx + ddy * ddy);
+    let n = segment_count(dd / (4.0 * tol));
+
+    for i in 1..=n {
+        let t = i as f32 / n as f32;
+        let mt = 1.0 - t;
+        let a = mt * mt;
+        let b = 2.0 * mt * t;
+        let c = t * t;
+        out.push(PointF::new(
+            a * p0.x + b * p1.x + c * p2.x,
+            a * p0.y + b * p1.y + c * p2.y,
+        ));
+    }
+}
+
+fn flatten_cubic(p0: PointF, p1: PointF, p2: PointF, p3: PointF, tol: f32, out: &mut Vec<PointF>) {
+    // Erro de corda ≤ 3·max(|P0 - 2P1 + P2|, |P1 - 2P2 + P3|) / (4n²)
+    let d1x = p0.x - 2.0 * p1.x + p2.x;
+    let d1y = p0.y - 2.0 * p1.y + p2.y;
+    let d2x = p1.x - 2.0 * p2.x + p3.x;
+    let d2y = p1.y - 2.0 * p2.y + p3.y;
+    let dd = sqrtf((d1x * d1x + d1y * d1y).max(d2x * d2x + d2y * d2y));
+    let n = segment_count(3.0 * dd / (4.0 * tol));
+
+    for i in 1..=n {
+        let t = i as f32 / n as f32;
+        let mt = 1.0 - t;
+        let a = mt * mt * mt;
+        let b = 3.0 * mt * mt * t;
+        let c = 3.0 * mt * t * t;
+        let d = t * t * t;
+        out.push(PointF::new(
+            a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+            a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+        ));
+    }
+}
+
+/// Converte n² estimado em número de segmentos.
+fn segment_count(n_squared: f32) -> usize {
+    let n = ceilf(sqrtf(n_squared)) as usize;
+    n.clamp(1, MAX_CURVE_SEGMENTS)
+}
+
+// =============================================================================
+// TRAÇADO
+// =============================================================================
+
+/// Remove pontos consecutivos repetidos (e o ponto de fechamento duplicado).
+fn dedup_points(points: &[PointF], closed: bool) -> Vec<PointF> {
+    let mut out: Vec<PointF> = Vec::with_capacity(points.len());
+    for &p in points {
+        if out.last().is_none_or(|&q| !same_point(p, q)) {
+            out.push(p);
+        }
+    }
+    if closed && out.len() > 2 && same_point(out[0], out[out.len() - 1]) {
+        out.pop();
+    }
+    out
+}
+
+fn same_point(a: PointF, b: PointF) -> bool {
+    absf(a.x - b.x) < 1e-4 && absf(a.y - b.y) < 1e-4
+}
+
+/// Normal unitária (à esquerda) da direção a → b.
+fn unit_normal(a: PointF, b: PointF) -> PointF {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let len = sqrtf(dx * dx + dy * dy);
+    if len == 0.0 {
+        return PointF::ZERO;
+    }
+    PointF::new(-dy / len, dx / len)
+}
+
+fn offset(p: PointF, n: PointF, dist: f32) -> PointF {
+    PointF::new(p.x + n.x * dist, p.y + n.y * dist)
+}
+
+/// Retângulo de largura `2·hw` cobrindo o segmento a → b.
+fn segment_quad(a: PointF, b: PointF, hw: f32) -> Vec<PointF> {
+    let n = unit_normal(a, b);
+    alloc::vec![
+        offset(a, n, hw),
+        offset(b, n, hw),
+        offset(b, n, -hw),
+        offset(a, n, -hw),
+    ]
+}
+
+/// Gera o polígono de junção no vértice `p`.
+fn stroke_join(
+    out: &mut Vec<Contour>,
+    prev: PointF,
+    p: PointF,
+    next: PointF,
+    hw: f32,
+    style: &StrokeStyle,
+) {
+    if style.join == LineJoin::Round {
+        push_polygon(out, circle_polygon(p, hw));
+        return;
+    }
+
+    let n0 = unit_normal(prev, p);
+    let n1 = unit_normal(p, next);
+
+    // Lado externo da curva: oposto ao sentido da rotação
+    let d0 = PointF::new(p.x - prev.x, p.y - prev.y);
+    let d1 = PointF::new(next.x - p.x, next.y - p.y);
+    let cross = d0.x * d1.y - d0.y * d1.x;
+    if absf(cross) < 1e-6 {
+        return; // Colinear: segmentos já se encontram
+    }
+    let side = if cross > 0.0 { -1.0 } else { 1.0 };
+
+    let o0 = offset(p, n0, side * hw);
+    let o1 = offset(p, n1, side * hw);
+
+    if style.join == LineJoin::Miter {
+        let bx = n0.x + n1.x;
+        let by = n0.y + n1.y;
+        let blen = sqrtf(bx * bx + by * by);
+        if blen > 1e-6 {
+            let bisector = PointF::new(bx / blen, by / blen);
+            // cos(θ/2) entre bissetriz e normal
+            let cos_half = bisector.x * n0.x + bisector.y * n0.y;
+            if cos_half > 1e-6 && 1.0 / cos_half <= style.miter_limit {
+                let tip = offset(p, bisector, side * hw / cos_half);
+                push_polygon(out, alloc::vec![p, o0, tip, o1]);
+                return;
+            }
+        }
+    }
+
+    // Bevel (ou miter acima do limite)
+    push_polygon(out, alloc::vec![p, o0, o1]);
+}
+
+/// Gera o polígono da ponta em `end` (segmento vindo de `from`).
+fn stroke_cap(out: &mut Vec<Contour>, from: PointF, end: PointF, hw: f32, cap: LineCap) {
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Round => push_polygon(out, circle_polygon(end, hw)),
+        LineCap::Square => {
+            let n = unit_normal(from, end);
+            // Direção do segmento (normal girada 90° de volta)
+            let ext = offset(end, PointF::new(n.y, -n.x), hw);
+            push_polygon(
+                out,
+                alloc::vec![
+                    offset(end, n, hw),
+                    offset(ext, n, hw),
+                    offset(ext, n, -hw),
+                    offset(end, n, -hw),
+                ],
+            );
+        }
+    }
+}
+
+/// Polígono aproximando um círculo.
+fn circle_polygon(center: PointF, radius: f32) -> Vec<PointF> {
+    // Passo angular tal que o erro de corda fique abaixo da tolerância
+    let steps = segment_count(radius * TAU * TAU / (8.0 * DEFAULT_TOLERANCE)).max(8);
+    (0..steps)
+        .map(|i| {
+            let angle = TAU * i as f32 / steps as f32;
+            PointF::new(
+                center.x + radius * cosf(angle),
+                center.y + radius * sinf(angle),
+            )
+        })
+        .collect()
+}
+
+/// Adiciona polígono fechado, normalizando a orientação.
+fn push_polygon(out: &mut Vec<Contour>, mut points: Vec<PointF>) {
+    if signed_area(&points) < 0.0 {
+        points.reverse();
+    }
+    out.push(Contour {
+        points,
+        closed: true,
+    });
+}
+
+fn signed_area(points: &[PointF]) -> f32 {
+    let n = points.len();
+    let mut area = 0.0;
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        area += a.x * b.y - b.x * a.y;
+    }
+    area * 0.5
+}
+
+// =============================================================================
+// RASTERIZAÇÃO (SCANLINE)
+// =============================================================================
+
+/// Aresta de polígono orientada de cima para baixo.
+#[derive(Clone, Copy)]
+struct Edge {
+    x_top: f32,
+    y_top: f32,
+    y_bottom: f32,
+    /// dx/dy.
+    slope: f32,
+    /// +1 se a aresta original desce, -1 se sobe.
+    winding: i32,
+}
+
+/// Rasteriza contornos em spans `(x, y, width)` amostrando centros de pixel.
+pub fn rasterize(
+    contours: &[Contour],
+    rule: FillRule,
+    clip: Rect,
+    mut emit: impl FnMut(i32, i32, u32),
+) {
+    if clip.is_empty() {
+        return;
+    }
+
+    let mut edges = Vec::new();
+    for contour in contours {
+        let n = contour.points.len();
+        if n < 2 {
+            continue;
+        }
+        for i in 0..n {
+            let a = contour.points[i];
+            let b = contour.points[(i + 1) % n];
+            if a.y == b.y {
+                continue;
+            }
+            let (top, bottom, winding) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+            edges.push(Edge {
+                x_top: top.x,
+                y_top: top.y,
+                y_bottom: bottom.y,
+                slope: (bottom.x - top.x) / (bottom.y - top.y),
+                winding,
+            });
+        }
+    }
+
+    if edges.is_empty() {
+        return;
+    }
+
+    edges.sort_unstable_by(|a, b| a.y_top.total_cmp(&b.y_top));
+
+    let min_y = edges[0].y_top;
+    let max_y = edges.iter().fold(min_y, |m, e| m.max(e.y_bottom));
+
+    let y_start = (floorf(min_y) as i32).max(clip.y);
+    let y_end = (ceilf(max_y) as i32).min(clip.bottom());
+    let x_min = clip.x;
+    let x_max = clip.right();
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut next_edge = 0;
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+
+    for y in y_start..y_end {
+        let sample_y = y as f32 + 0.5;
+
+        while next_edge < edges.len() && edges[next_edge].y_top <= sample_y {
+            active.push(next_edge);
+            next_edge += 1;
+        }
+        active.retain(|&i| edges[i].y_bottom > sample_y);
+
+        crossings.clear();
+        for &i in &active {
+            let e = &edges[i];
+            if e.y_top <= sample_y {
+                let x = e.x_top + (sample_y - e.y_top) * e.slope;
+                crossings.push((x, e.winding));
+            }
+        }
+        crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        let mut span_start = 0.0;
+        for &(x, w) in &crossings {
+            let was_inside = is_inside(winding, rule);
+            winding += w;
+            let inside = is_inside(winding, rule);
+
+            if !was_inside && inside {
+                span_start = x;
+            } else if was_inside && !inside {
+                // Pixel coberto se o centro está em [start, end)
+                let x0 = (ceilf(span_start - 0.5) as i32).max(x_min);
+                let x1 = (ceilf(x - 0.5) as i32).min(x_max);
+                if x1 > x0 {
+                    emit(x0, y, (x1 - x0) as u32);
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_inside(winding: i32, rule: FillRule) -> bool {
+    match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding & 1 != 0,
+    }
+}
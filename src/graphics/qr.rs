@@ -0,0 +1,606 @@
+//! # QR Code
+//!
+//! Codificador de QR Code (modelo 2, versões 1–40, modo byte), sem
+//! dependências — para códigos de pareamento e URLs de setup.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::qr;
+//!
+//! let surface = qr::encode(b"https://redstone-os.org/setup").unwrap();
+//! canvas.draw_surface(&surface, 20, 20);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use gfx_types::color::Color;
+
+use super::surface::Surface;
+
+/// Escala padrão de [`encode`] (pixels por módulo).
+pub const DEFAULT_SCALE: u32 = 4;
+
+/// Zona de silêncio padrão (módulos), exigida pela especificação.
+pub const DEFAULT_BORDER: u32 = 4;
+
+// =============================================================================
+// API PÚBLICA
+// =============================================================================
+
+/// Codifica `data` em um QR Code (correção `Medium`) renderizado em preto
+/// sobre branco, com escala e borda padrão.
+///
+/// Retorna `None` se os dados não couberem na versão 40.
+pub fn encode(data: &[u8]) -> Option<Surface> {
+    let code = QrCode::encode(data, ErrorCorrection::Medium)?;
+    Some(code.to_surface(DEFAULT_SCALE, DEFAULT_BORDER, Color::BLACK, Color::WHITE))
+}
+
+/// Nível de correção de erros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorCorrection {
+    /// ~7% dos codewords recuperáveis.
+    Low,
+    /// ~15%.
+    #[default]
+    Medium,
+    /// ~25%.
+    Quartile,
+    /// ~30%.
+    High,
+}
+
+impl ErrorCorrection {
+    #[inline]
+    fn ordinal(self) -> usize {
+        self as usize
+    }
+
+    /// Bits do nível no campo de formato.
+    #[inline]
+    fn format_bits(self) -> u32 {
+        match self {
+            Self::Low => 1,
+            Self::Medium => 0,
+            Self::Quartile => 3,
+            Self::High => 2,
+        }
+    }
+}
+
+// =============================================================================
+// QR CODE
+// =============================================================================
+
+/// Matriz de módulos de um QR Code.
+#[derive(Debug, Clone)]
+pub struct QrCode {
+    version: u8,
+    size: usize,
+    ecc: ErrorCorrection,
+    mask: u8,
+    /// Módulos escuros (linha a linha).
+    modules: Vec<bool>,
+    /// Módulos de padrões fixos (não recebem dados nem máscara).
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Codifica bytes na menor versão que os comporta.
+    ///
+    /// Retorna `None` se os dados não couberem na versão 40.
+    pub fn encode(data: &[u8], ecc: ErrorCorrection) -> Option<Self> {
+        let version = (1..=40u8).find(|&v| {
+            byte_mode_bits(v, data.len()).is_some_and(|bits| bits <= num_data_codewords(v, ecc) * 8)
+        })?;
+        let mut code = Self::blank(version, ecc);
+        let codewords = code.add_ecc_and_interleave(&encode_data(data, version, ecc));
+        code.draw_codewords(&codewords);
+
+        // Escolher a máscara de menor penalidade
+        let mut best = (0u8, u32::MAX);
+        for mask in 0..8 {
+            code.apply_mask(mask);
+            code.draw_format_bits(mask);
+            let penalty = code.penalty();
+            if penalty < best.1 {
+                best = (mask, penalty);
+            }
+            code.apply_mask(mask);
+        }
+        code.mask = best.0;
+        code.apply_mask(best.0);
+        code.draw_format_bits(best.0);
+        Some(code)
+    }
+
+    /// Versão (1–40).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Lado da matriz em módulos (`17 + 4 * versão`).
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Nível de correção usado.
+    #[inline]
+    pub fn error_correction(&self) -> ErrorCorrection {
+        self.ecc
+    }
+
+    /// Máscara escolhida (0–7).
+    #[inline]
+    pub fn mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// Retorna se o módulo é escuro (fora da matriz = claro).
+    pub fn module(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Renderiza em uma surface, com `scale` pixels por módulo e `border`
+    /// módulos de zona de silêncio.
+    pub fn to_surface(&self, scale: u32, border: u32, dark: Color, light: Color) -> Surface {
+        let scale = scale.max(1);
+        let side = (self.size as u32 + 2 * border) * scale;
+        let mut surface = Surface::new(side, side);
+        surface.fill(light);
+
+        let pixels = surface.pixels_mut();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.modules[y * self.size + x] {
+                    continue;
+                }
+                let px = (x as u32 + border) * scale;
+                let py = (y as u32 + border) * scale;
+                for row in py..py + scale {
+                    let start = (row * side + px) as usize;
+                    pixels[start..start + scale as usize].fill(dark.as_u32());
+                }
+            }
+        }
+        surface
+    }
+
+    // =========================================================================
+    // PADRÕES FIXOS
+    // =========================================================================
+
+    /// Matriz com todos os padrões fixos desenhados.
+    fn blank(version: u8, ecc: ErrorCorrection) -> Self {
+        let size = version as usize * 4 + 17;
+        let mut code = Self {
+            version,
+            size,
+            ecc,
+            mask: 0,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+
+        // Timing
+        for i in 0..size {
+            code.set_function(6, i, i % 2 == 0);
+            code.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Finders (com separadores)
+        let far = size as i32 - 4;
+        for (cx, cy) in [(3, 3), (far, 3), (3, far)] {
+            for dy in -4..=4i32 {
+                for dx in -4..=4i32 {
+                    let (x, y) = (cx + dx, cy + dy);
+                    if x >= 0 && y >= 0 && (x as usize) < size && (y as usize) < size {
+                        let dist = dx.abs().max(dy.abs());
+                        code.set_function(x as usize, y as usize, dist != 2 && dist != 4);
+                    }
+                }
+            }
+        }
+
+        // Alinhamento
+        let positions = alignment_positions(version);
+        let last = positions.len().wrapping_sub(1);
+        for (i, &ay) in positions.iter().enumerate() {
+            for (j, &ax) in positions.iter().enumerate() {
+                // Cantos ocupados pelos finders
+                if (i, j) == (0, 0) || (i, j) == (0, last) || (i, j) == (last, 0) {
+                    continue;
+                }
+                for dy in -2..=2i32 {
+                    for dx in -2..=2i32 {
+                        let dark = dx.abs().max(dy.abs()) != 1;
+                        code.set_function(
+                            (ax as i32 + dx) as usize,
+                            (ay as i32 + dy) as usize,
+                            dark,
+                        );
+                    }
+                }
+            }
+        }
+
+        // Reservar área de formato (valores reais desenhados depois)
+        code.draw_format_bits(0);
+        code.draw_version();
+        code
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let index = y * self.size + x;
+        self.modules[index] = dark;
+        self.function[index] = true;
+    }
+
+    fn draw_format_bits(&mut self, mask: u8) {
+        let data = (self.ecc.format_bits() << 3) | mask as u32;
+        let mut rem = data;
+        for _ in 0..10 {
+            rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | rem) ^ 0x5412;
+        let bit = |i: u32| (bits >> i) & 1 != 0;
+
+        // Cópia ao redor do finder superior esquerdo
+        for i in 0..=5 {
+            self.set_function(8, i as usize, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i as usize, 8, bit(i));
+        }
+
+        // Cópia dividida entre os outros dois finders
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i as usize, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i as usize, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let mut rem = self.version as u32;
+        for _ in 0..12 {
+            rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+        }
+        let bits = ((self.version as u32) << 12) | rem;
+
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    // =========================================================================
+    // DADOS
+    // =========================================================================
+
+    /// Divide em blocos, calcula Reed-Solomon e intercala.
+    fn add_ecc_and_interleave(&self, data: &[u8]) -> Vec<u8> {
+        let ver = self.version as usize;
+        let ecl = self.ecc.ordinal();
+        let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[ecl][ver] as usize;
+        let ecc_len = ECC_CODEWORDS_PER_BLOCK[ecl][ver] as usize;
+        let raw_codewords = num_raw_data_modules(self.version) / 8;
+        let num_short = num_blocks - raw_codewords % num_blocks;
+        let short_len = raw_codewords / num_blocks;
+
+        let divisor = rs_divisor(ecc_len);
+        let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+        let mut offset = 0;
+        for i in 0..num_blocks {
+            let data_len = short_len - ecc_len + usize::from(i >= num_short);
+            let mut block = data[offset..offset + data_len].to_vec();
+            offset += data_len;
+            let ecc = rs_remainder(&block, &divisor);
+            if i < num_short {
+                // Blocos curtos: posição vazia para alinhar a intercalação
+                block.push(0);
+            }
+            block.extend_from_slice(&ecc);
+            blocks.push(block);
+        }
+
+        let mut result = Vec::with_capacity(raw_codewords);
+        for i in 0..blocks[0].len() {
+            for (j, block) in blocks.iter().enumerate() {
+                if i != short_len - ecc_len || j >= num_short {
+                    result.push(block[i]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Posiciona os codewords em zigue-zague, de baixo para cima.
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let size = self.size;
+        let total_bits = data.len() * 8;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    let index = y * size + x;
+                    if !self.function[index] && i < total_bits {
+                        self.modules[index] = (data[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Aplica (ou desfaz — é XOR) uma máscara aos módulos de dados.
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // PENALIDADE
+    // =========================================================================
+
+    fn penalty(&self) -> u32 {
+        let size = self.size;
+        let at = |x: usize, y: usize| self.modules[y * size + x];
+        let mut result = 0;
+
+        // N1 (sequências) e N3 (padrões tipo finder) em linhas e colunas
+        for horizontal in [true, false] {
+            for a in 0..size {
+                let get = |b: usize| if horizontal { at(b, a) } else { at(a, b) };
+                let mut run = 1;
+                for b in 1..size {
+                    if get(b) == get(b - 1) {
+                        run += 1;
+                    } else {
+                        run = 1;
+                    }
+                    if run == 5 {
+                        result += PENALTY_N1;
+                    } else if run > 5 {
+                        result += 1;
+                    }
+                }
+                for b in 0..size.saturating_sub(10) {
+                    let window = |pattern: &[bool; 11]| {
+                        pattern.iter().enumerate().all(|(k, &d)| get(b + k) == d)
+                    };
+                    if window(&FINDER_LIKE_BEFORE) || window(&FINDER_LIKE_AFTER) {
+                        result += PENALTY_N3;
+                    }
+                }
+            }
+        }
+
+        // N2: blocos 2x2 da mesma cor
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = at(x, y);
+                if c == at(x + 1, y) && c == at(x, y + 1) && c == at(x + 1, y + 1) {
+                    result += PENALTY_N2;
+                }
+            }
+        }
+
+        // N4: proporção de módulos escuros longe de 50%
+        let total = (size * size) as i64;
+        let dark = self.modules.iter().filter(|&&d| d).count() as i64;
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        result + k as u32 * PENALTY_N4
+    }
+}
+
+// =============================================================================
+// CODIFICAÇÃO DE DADOS
+// =============================================================================
+
+/// Bits necessários para `len` bytes em modo byte (None se o contador estourar).
+fn byte_mode_bits(version: u8, len: usize) -> Option<usize> {
+    let count_bits = if version <= 9 { 8 } else { 16 };
+    (len < 1 << count_bits).then_some(4 + count_bits + len * 8)
+}
+
+/// Segmento em modo byte + terminador + padding até a capacidade.
+fn encode_data(data: &[u8], version: u8, ecc: ErrorCorrection) -> Vec<u8> {
+    let capacity = num_data_codewords(version, ecc);
+    let mut bits = BitBuffer::default();
+    bits.append(0b0100, 4);
+    bits.append(data.len() as u32, if version <= 9 { 8 } else { 16 });
+    for &byte in data {
+        bits.append(byte as u32, 8);
+    }
+
+    let capacity_bits = capacity * 8;
+    bits.append(0, (capacity_bits - bits.len).min(4) as u8);
+    bits.append(0, ((8 - bits.len % 8) % 8) as u8);
+
+    let mut pad = [0xEC, 0x11].iter().cycle();
+    while bits.bytes.len() < capacity {
+        bits.bytes.push(*pad.next().unwrap());
+    }
+    bits.bytes
+}
+
+#[derive(Default)]
+struct BitBuffer {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitBuffer {
+    fn append(&mut self, value: u32, count: u8) {
+        for i in (0..count).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+// =============================================================================
+// TABELAS E CAPACIDADE
+// =============================================================================
+
+const PENALTY_N1: u32 = 3;
+const PENALTY_N2: u32 = 3;
+const PENALTY_N3: u32 = 40;
+const PENALTY_N4: u32 = 10;
+
+const FINDER_LIKE_BEFORE: [bool; 11] = [
+    false, false, false, false, true, false, true, true, true, false, true,
+];
+const FINDER_LIKE_AFTER: [bool; 11] = [
+    true, false, true, true, true, false, true, false, false, false, false,
+];
+
+/// Codewords de correção por bloco, por nível e versão (índice 0 não usado).
+#[rustfmt::skip]
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+    [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28],
+    [0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+    [0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+];
+
+/// Número de blocos de correção, por nível e versão (índice 0 não usado).
+#[rustfmt::skip]
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25],
+    [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49],
+    [0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68],
+    [0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81],
+];
+
+/// Módulos disponíveis para dados + correção (sem padrões fixos).
+fn num_raw_data_modules(version: u8) -> usize {
+    let ver = version as usize;
+    let mut result = (16 * ver + 128) * ver + 64;
+    if ver >= 2 {
+        let num_align = ver / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if ver >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+/// Codewords de dados disponíveis.
+fn num_data_codewords(version: u8, ecc: ErrorCorrection) -> usize {
+    let (ver, ecl) = (version as usize, ecc.ordinal());
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[ecl][ver] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[ecl][ver] as usize
+}
+
+/// Centros dos padrões de alinhamento (mesma lista para x e y).
+fn alignment_positions(version: u8) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let ver = version as usize;
+    let num_align = ver / 7 + 2;
+    let step = (ver * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+    let size = ver * 4 + 17;
+
+    let mut result = vec![6];
+    for k in (0..num_align - 1).rev() {
+        result.push(size - 7 - k * step);
+    }
+    result
+}
+
+// =============================================================================
+// REED-SOLOMON (GF(2^8), polinômio 0x11D)
+// =============================================================================
+
+fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result[0];
+        result.rotate_left(1);
+        *result.last_mut().unwrap() = 0;
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_mul(d, factor);
+        }
+    }
+    result
+}
+
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
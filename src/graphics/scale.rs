@@ -0,0 +1,159 @@
+//! # Scale
+//!
+//! Fator de escala (DPI) e conversão entre coordenadas lógicas e físicas.
+//!
+//! Coordenadas lógicas são as do layout do app (independentes do monitor);
+//! físicas são pixels do buffer. Em um painel HiDPI a 2x, um botão de
+//! 100x30 lógicos ocupa 200x60 pixels.
+//!
+//! O fator é guardado em milésimos (`1000` = 1x), como as taxas de
+//! atualização em mHz do protocolo, então a conversão é exata e sem `f32`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{Rect, ScaleFactor};
+//!
+//! let scale = window.scale();
+//! let button = scale.rect_to_physical(Rect::new(10, 10, 100, 30));
+//! canvas.fill_rect(button, Color::BLUE);
+//!
+//! // Clique (físico) de volta para o layout
+//! let pos = scale.point_to_logical(Point::new(mouse.x, mouse.y));
+//! ```
+
+use gfx_types::geometry::{Point, Rect, Size};
+
+// =============================================================================
+// SCALE FACTOR
+// =============================================================================
+
+/// Fator de escala em milésimos (`1000` = 1x, `2000` = 2x, `1250` = 125%).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScaleFactor(u32);
+
+impl ScaleFactor {
+    /// 1x (sem escala).
+    pub const ONE: Self = Self(1000);
+    /// 2x (HiDPI típico).
+    pub const TWO: Self = Self(2000);
+
+    /// Fator a partir de milésimos; `0` (compositor sem suporte) vira 1x.
+    #[inline]
+    pub const fn from_milli(milli: u32) -> Self {
+        if milli == 0 {
+            Self::ONE
+        } else {
+            Self(milli)
+        }
+    }
+
+    /// Fator a partir de um `f32` (arredondado ao milésimo).
+    pub fn from_f32(scale: f32) -> Self {
+        if scale.is_nan() || scale <= 0.0 {
+            return Self::ONE;
+        }
+        Self::from_milli((scale * 1000.0 + 0.5) as u32)
+    }
+
+    /// Valor em milésimos.
+    #[inline]
+    pub const fn milli(self) -> u32 {
+        self.0
+    }
+
+    /// Valor como `f32`.
+    #[inline]
+    pub fn as_f32(self) -> f32 {
+        self.0 as f32 / 1000.0
+    }
+
+    /// `true` para 1x, 2x, 3x... (sem pixels fracionários).
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        self.0.is_multiple_of(1000)
+    }
+
+    // =========================================================================
+    // ESCALARES
+    // =========================================================================
+
+    /// Lógico -> físico (arredondado ao pixel mais próximo).
+    #[inline]
+    pub fn to_physical(self, value: i32) -> i32 {
+        div_round(value as i64 * self.0 as i64, 1000) as i32
+    }
+
+    /// Físico -> lógico (arredondado).
+    #[inline]
+    pub fn to_logical(self, value: i32) -> i32 {
+        div_round(value as i64 * 1000, self.0 as i64) as i32
+    }
+
+    // =========================================================================
+    // GEOMETRIA
+    // =========================================================================
+
+    /// Ponto lógico -> físico.
+    pub fn point_to_physical(self, point: Point) -> Point {
+        Point::new(self.to_physical(point.x), self.to_physical(point.y))
+    }
+
+    /// Ponto físico -> lógico.
+    pub fn point_to_logical(self, point: Point) -> Point {
+        Point::new(self.to_logical(point.x), self.to_logical(point.y))
+    }
+
+    /// Tamanho lógico -> físico (arredondado para cima: o conteúdo cabe).
+    pub fn size_to_physical(self, size: Size) -> Size {
+        Size::new(
+            div_ceil(size.width as i64 * self.0 as i64, 1000) as u32,
+            div_ceil(size.height as i64 * self.0 as i64, 1000) as u32,
+        )
+    }
+
+    /// Tamanho físico -> lógico (arredondado para baixo: cabe no buffer).
+    pub fn size_to_logical(self, size: Size) -> Size {
+        Size::new(
+            (size.width as u64 * 1000 / self.0 as u64) as u32,
+            (size.height as u64 * 1000 / self.0 as u64) as u32,
+        )
+    }
+
+    /// Retângulo lógico -> físico, expandido para cobrir pixels parciais
+    /// (seguro para dano e recorte).
+    pub fn rect_to_physical(self, rect: Rect) -> Rect {
+        self.scale_rect(rect, self.0 as i64, 1000)
+    }
+
+    /// Retângulo físico -> lógico, expandido para cobrir pixels parciais.
+    pub fn rect_to_logical(self, rect: Rect) -> Rect {
+        self.scale_rect(rect, 1000, self.0 as i64)
+    }
+
+    fn scale_rect(self, rect: Rect, num: i64, den: i64) -> Rect {
+        let x0 = (rect.x as i64 * num).div_euclid(den);
+        let y0 = (rect.y as i64 * num).div_euclid(den);
+        let x1 = div_ceil((rect.x as i64 + rect.width as i64) * num, den);
+        let y1 = div_ceil((rect.y as i64 + rect.height as i64) * num, den);
+        Rect::new(x0 as i32, y0 as i32, (x1 - x0) as u32, (y1 - y0) as u32)
+    }
+}
+
+impl Default for ScaleFactor {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+/// Divisão arredondando ao inteiro mais próximo (meios para cima).
+#[inline]
+fn div_round(value: i64, den: i64) -> i64 {
+    (2 * value + den).div_euclid(2 * den)
+}
+
+/// Divisão arredondando para cima.
+#[inline]
+fn div_ceil(value: i64, den: i64) -> i64 {
+    -((-value).div_euclid(den))
+}
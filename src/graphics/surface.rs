@@ -0,0 +1,135 @@
+//! # Surface
+//!
+//! Buffer de pixels ARGB com dono próprio.
+//!
+//! Diferente do [`Canvas`](super::Canvas), que desenha sobre um buffer
+//! emprestado, a `Surface` aloca e mantém seus pixels — útil para
+//! screenshots, thumbnails, imagens decodificadas e camadas off-screen.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{Color, Point, Rect, Surface};
+//!
+//! // Renderizar uma subárvore uma vez...
+//! let mut layer = Surface::new(200, 100);
+//! let mut canvas = layer.canvas();
+//! canvas.fill_rect(Rect::new(0, 0, 200, 100), Color::BLUE);
+//!
+//! // ...e compor a cada frame com opacidade
+//! window_canvas.draw_surface(&layer, Point::new(20, 20), 128);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use gfx_types::color::Color;
+use gfx_types::geometry::Size;
+
+use super::canvas::Canvas;
+
+// =============================================================================
+// SURFACE
+// =============================================================================
+
+/// Buffer de pixels ARGB (0xAARRGGBB), linha a linha, sem padding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Surface {
+    pixels: Vec<u32>,
+    width: u32,
+    height: u32,
+}
+
+impl Surface {
+    /// Cria surface preenchida com preto transparente.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            pixels: vec![0; width as usize * height as usize],
+            width,
+            height,
+        }
+    }
+
+    /// Cria surface a partir de pixels existentes.
+    ///
+    /// Retorna `None` se `pixels.len()` não for `width * height`.
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u32>) -> Option<Self> {
+        if pixels.len() != width as usize * height as usize {
+            return None;
+        }
+        Some(Self {
+            pixels,
+            width,
+            height,
+        })
+    }
+
+    /// Retorna largura.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Retorna altura.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Retorna tamanho.
+    #[inline]
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Pixels (somente leitura).
+    #[inline]
+    pub fn pixels(&self) -> &[u32] {
+        &self.pixels
+    }
+
+    /// Pixels (mutável).
+    #[inline]
+    pub fn pixels_mut(&mut self) -> &mut [u32] {
+        &mut self.pixels
+    }
+
+    /// Canvas para desenhar sobre a surface.
+    pub fn canvas(&mut self) -> Canvas<'_> {
+        Canvas::new(&mut self.pixels, self.width, self.height)
+    }
+
+    /// Consome a surface retornando o buffer.
+    pub fn into_pixels(self) -> Vec<u32> {
+        self.pixels
+    }
+
+    /// Retorna uma linha de pixels.
+    #[inline]
+    pub fn row(&self, y: u32) -> &[u32] {
+        let start = (y * self.width) as usize;
+        &self.pixels[start..start + self.width as usize]
+    }
+
+    /// Lê um pixel (None se fora dos limites).
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(Color(self.pixels[(y * self.width + x) as usize]))
+    }
+
+    /// Escreve um pixel (ignorado se fora dos limites).
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = color.as_u32();
+        }
+    }
+
+    /// Preenche toda a surface.
+    pub fn fill(&mut self, color: Color) {
+        self.pixels.fill(color.as_u32());
+    }
+}
@@ -0,0 +1,182 @@
+//! # Texto
+//!
+//! Renderização de texto com fonte bitmap monoespaçada embutida, medição e
+//! quebra de linhas.
+//!
+//! O desenho fica em [`Canvas::draw_text`](super::Canvas::draw_text) e
+//! [`Canvas::draw_text_wrapped`](super::Canvas::draw_text_wrapped); este
+//! módulo fornece a fonte e o layout.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::{text, Canvas, Color, Point, Rect, TextAlign};
+//!
+//! canvas.draw_text(Point::new(10, 10), "Olá, Redstone!", Color::WHITE);
+//!
+//! let size = text::measure(&text::FONT_8X16, "duas\nlinhas"); // 32x32
+//! canvas.draw_text_wrapped(Rect::new(10, 40, 200, 100), long_text, Color::WHITE, TextAlign::Left);
+//! ```
+
+extern crate alloc;
+
+mod font8x16;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::Size;
+
+pub use font8x16::FONT_8X16;
+
+/// Largura de tabulação (em células).
+pub const TAB_WIDTH: u32 = 4;
+
+// =============================================================================
+// BITMAP FONT
+// =============================================================================
+
+/// Fonte bitmap monoespaçada (até 8 pixels de largura, 1 byte por linha).
+pub struct BitmapFont {
+    /// Largura da célula em pixels (≤ 8).
+    width: u32,
+    /// Altura da célula em pixels (bytes por glifo).
+    height: u32,
+    /// Linha da baseline dentro da célula.
+    baseline: u32,
+    /// Primeiro caractere presente em `glyphs`.
+    first: char,
+    /// Glifos consecutivos a partir de `first`.
+    glyphs: &'static [u8],
+    /// Glifo para caracteres ausentes.
+    fallback: &'static [u8],
+}
+
+impl BitmapFont {
+    /// Largura da célula (avanço horizontal) em pixels.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Altura da célula em pixels.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Distância do topo da célula até a baseline.
+    #[inline]
+    pub fn baseline(&self) -> u32 {
+        self.baseline
+    }
+
+    /// Distância entre linhas consecutivas.
+    #[inline]
+    pub fn line_height(&self) -> u32 {
+        self.height
+    }
+
+    /// Verifica se a fonte tem glifo para `ch`.
+    pub fn has_glyph(&self, ch: char) -> bool {
+        self.index(ch).is_some()
+    }
+
+    /// Linhas do glifo de `ch` (MSB = coluna mais à esquerda).
+    pub fn glyph(&self, ch: char) -> &'static [u8] {
+        let h = self.height as usize;
+        match self.index(ch) {
+            Some(i) => &self.glyphs[i * h..(i + 1) * h],
+            None => self.fallback,
+        }
+    }
+
+    fn index(&self, ch: char) -> Option<usize> {
+        let i = (ch as u32).checked_sub(self.first as u32)? as usize;
+        (i < self.glyphs.len() / self.height as usize).then_some(i)
+    }
+}
+
+// =============================================================================
+// LAYOUT
+// =============================================================================
+
+/// Largura de uma linha em células (expande tabulações).
+pub fn line_cells(line: &str) -> u32 {
+    line.chars().fold(0, advance)
+}
+
+/// Coluna após desenhar `ch` a partir de `col`.
+pub(crate) fn advance(col: u32, ch: char) -> u32 {
+    match ch {
+        '\t' => (col / TAB_WIDTH + 1) * TAB_WIDTH,
+        '\r' => col,
+        _ => col + 1,
+    }
+}
+
+/// Tamanho ocupado por `text` (linhas separadas por `\n`).
+pub fn measure(font: &BitmapFont, text: &str) -> Size {
+    let mut lines = 0;
+    let mut cells = 0;
+    for line in text.split('\n') {
+        lines += 1;
+        cells = cells.max(line_cells(line));
+    }
+    Size::new(cells * font.width, lines * font.line_height())
+}
+
+/// Quebra `text` em linhas de no máximo `max_width` pixels.
+///
+/// Respeita `\n`, quebra entre palavras e corta palavras maiores que a
+/// largura. Espaços no ponto de quebra são descartados.
+pub fn wrap<'t>(font: &BitmapFont, text: &'t str, max_width: u32) -> Vec<&'t str> {
+    let max_cells = (max_width / font.width).max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut rest = paragraph;
+        loop {
+            if line_cells(rest) <= max_cells {
+                lines.push(rest);
+                break;
+            }
+
+            // Último espaço que cabe na linha, ou corte no limite
+            let mut col = 0;
+            let mut limit = rest.len();
+            let mut last_space = None;
+            for (i, ch) in rest.char_indices() {
+                if ch == ' ' || ch == '\t' {
+                    last_space = Some(i);
+                }
+                let next = advance(col, ch);
+                if next > max_cells {
+                    limit = i;
+                    break;
+                }
+                col = next;
+            }
+
+            let (line, next) = match last_space {
+                Some(i) if !rest[..i].trim_start_matches([' ', '\t']).is_empty() => {
+                    (&rest[..i], &rest[i..])
+                }
+                _ => {
+                    // Ao menos um caractere por linha
+                    let cut = match limit {
+                        0 => rest.chars().next().map_or(0, char::len_utf8),
+                        n => n,
+                    };
+                    (&rest[..cut], &rest[cut..])
+                }
+            };
+            lines.push(line.trim_end_matches([' ', '\t']));
+            rest = next.trim_start_matches([' ', '\t']);
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+
+    lines
+}
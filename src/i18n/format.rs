@@ -0,0 +1,198 @@
+//! # Format
+//!
+//! Números, tamanhos em bytes e datas no locale configurado.
+//!
+//! Datas são formatadas como recebidas (o SDK não aplica fuso horário).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::i18n::format::{self, DateStyle};
+//! use redpowder::i18n::Locale;
+//! use redpowder::time::SystemTime;
+//!
+//! format::number(1234567, &Locale::PT_BR); // "1.234.567"
+//! format::bytes(1_572_864); // "1,5 MiB" (pt-BR)
+//!
+//! let now = SystemTime::now()?.to_datetime();
+//! format::date(&now, DateStyle::Long); // "16 de outubro de 2026"
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::time::DateTime;
+
+use super::locale::{DateOrder, Locale};
+
+/// Unidades de [`bytes`] (base 1024).
+const BYTE_UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+/// Estilo de [`date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateStyle {
+    /// `31/12/2026`
+    Short,
+    /// `31 de dezembro de 2026`
+    Long,
+    /// `23:59` (ou `11:59 PM`)
+    Time,
+    /// `31/12/2026 23:59`
+    ShortDateTime,
+}
+
+// =============================================================================
+// NÚMEROS
+// =============================================================================
+
+/// Inteiro com separador de milhares do locale.
+pub fn number(n: i64, locale: &Locale) -> String {
+    let mut out = String::new();
+    if n < 0 {
+        out.push('-');
+    }
+    push_grouped(&mut out, n.unsigned_abs(), locale);
+    out
+}
+
+/// Número com `decimals` casas decimais (arredondado).
+pub fn decimal(value: f64, decimals: u32, locale: &Locale) -> String {
+    let scale = 10u64.pow(decimals.min(9));
+    let scaled = value * scale as f64;
+    let rounded = if scaled < 0.0 {
+        scaled - 0.5
+    } else {
+        scaled + 0.5
+    } as i64;
+
+    let mut out = String::new();
+    if rounded < 0 {
+        out.push('-');
+    }
+    let abs = rounded.unsigned_abs();
+    push_grouped(&mut out, abs / scale, locale);
+    if decimals > 0 {
+        out.push(locale.decimal_separator);
+        let _ = write!(out, "{:0width$}", abs % scale, width = decimals as usize);
+    }
+    out
+}
+
+/// Tamanho em bytes (`"512 B"`, `"1,5 MiB"`) no locale do sistema.
+pub fn bytes(n: u64) -> String {
+    bytes_in(n, Locale::current())
+}
+
+/// [`bytes`] num locale específico.
+pub fn bytes_in(n: u64, locale: &Locale) -> String {
+    let mut unit = 0;
+    let mut value = n as f64;
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    let mut out = if unit == 0 {
+        number(n as i64, locale)
+    } else {
+        // Uma casa abaixo de 10 ("1,5 MiB"), nenhuma acima ("512 MiB")
+        let decimals = if value < 10.0 { 1 } else { 0 };
+        decimal(value, decimals, locale)
+    };
+    out.push(' ');
+    out.push_str(BYTE_UNITS[unit]);
+    out
+}
+
+fn push_grouped(out: &mut String, n: u64, locale: &Locale) {
+    let mut digits = [0u8; 20];
+    let mut len = 0;
+    let mut rest = n;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    for i in (0..len).rev() {
+        out.push(digits[i] as char);
+        if i > 0 && i % 3 == 0 && locale.group_separator != '\0' {
+            out.push(locale.group_separator);
+        }
+    }
+}
+
+// =============================================================================
+// DATAS
+// =============================================================================
+
+/// Data no locale do sistema.
+pub fn date(dt: &DateTime, style: DateStyle) -> String {
+    date_in(dt, style, Locale::current())
+}
+
+/// [`date`] num locale específico.
+pub fn date_in(dt: &DateTime, style: DateStyle, locale: &Locale) -> String {
+    let mut out = String::new();
+    match style {
+        DateStyle::Short => push_short_date(&mut out, dt, locale),
+        DateStyle::Long => push_long_date(&mut out, dt, locale),
+        DateStyle::Time => push_time(&mut out, dt, locale),
+        DateStyle::ShortDateTime => {
+            push_short_date(&mut out, dt, locale);
+            out.push(' ');
+            push_time(&mut out, dt, locale);
+        }
+    }
+    out
+}
+
+fn push_short_date(out: &mut String, dt: &DateTime, locale: &Locale) {
+    let sep = locale.date_separator;
+    let (y, m, d) = (dt.year, dt.month, dt.day);
+    let _ = match locale.date_order {
+        DateOrder::DayMonthYear => write!(out, "{:02}{sep}{:02}{sep}{:04}", d, m, y),
+        DateOrder::MonthDayYear => write!(out, "{:02}{sep}{:02}{sep}{:04}", m, d, y),
+        DateOrder::YearMonthDay => write!(out, "{:04}{sep}{:02}{sep}{:02}", y, m, d),
+    };
+}
+
+fn push_long_date(out: &mut String, dt: &DateTime, locale: &Locale) {
+    let month = locale.month_names[(dt.month.clamp(1, 12) - 1) as usize];
+    let mut rest = locale.long_date;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let field = &rest[start..];
+        let _ = if field.starts_with("{d}") {
+            write!(out, "{}", dt.day)
+        } else if field.starts_with("{m}") {
+            write!(out, "{}", month)
+        } else if field.starts_with("{y}") {
+            write!(out, "{}", dt.year)
+        } else {
+            out.push('{');
+            rest = &field[1..];
+            continue;
+        };
+        rest = &field[3..];
+    }
+    out.push_str(rest);
+}
+
+fn push_time(out: &mut String, dt: &DateTime, locale: &Locale) {
+    let _ = if locale.hour12 {
+        let hour = match dt.hour % 12 {
+            0 => 12,
+            h => h,
+        };
+        let suffix = if dt.hour < 12 { "AM" } else { "PM" };
+        write!(out, "{}:{:02} {}", hour, dt.minute, suffix)
+    } else {
+        write!(out, "{:02}:{:02}", dt.hour, dt.minute)
+    };
+}
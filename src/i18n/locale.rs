@@ -0,0 +1,241 @@
+//! # Locale
+//!
+//! Convenções regionais de formatação.
+//!
+//! O locale do sistema é lido uma vez da chave `system.locale` do serviço
+//! de configurações (ex.: `"pt-BR"`); sem serviço ou com um locale
+//! desconhecido, vale [`Locale::PT_BR`].
+
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::ipc::{as_bytes, fixed_str, recv_reply, str_from_fixed, Port};
+use crate::syscall::SysResult;
+use crate::window::session::protocol::{
+    opcodes, GetValueRequest, SettingsMessage, ValueResponse, SETTINGS_PORT,
+};
+
+/// Chave do locale no serviço de configurações.
+pub const LOCALE_KEY: &str = "system.locale";
+
+/// Tempo máximo de espera pelo serviço de configurações (ms).
+const REPLY_TIMEOUT_MS: u64 = 500;
+
+/// Ordem dos campos em datas curtas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// `31/12/2026`
+    DayMonthYear,
+    /// `12/31/2026`
+    MonthDayYear,
+    /// `2026-12-31`
+    YearMonthDay,
+}
+
+/// Convenções de formatação de uma região.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// Tag BCP 47 (`"pt-BR"`).
+    pub tag: &'static str,
+    pub decimal_separator: char,
+    /// Separador de milhares.
+    pub group_separator: char,
+    pub date_order: DateOrder,
+    /// Separador de datas curtas.
+    pub date_separator: char,
+    /// Relógio de 12 horas (AM/PM).
+    pub hour12: bool,
+    pub month_names: [&'static str; 12],
+    /// Formato de data longa: `{d}`, `{m}` (nome do mês) e `{y}`.
+    pub long_date: &'static str,
+}
+
+impl Locale {
+    pub const PT_BR: Locale = Locale {
+        tag: "pt-BR",
+        decimal_separator: ',',
+        group_separator: '.',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+        hour12: false,
+        month_names: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        long_date: "{d} de {m} de {y}",
+    };
+
+    pub const EN_US: Locale = Locale {
+        tag: "en-US",
+        decimal_separator: '.',
+        group_separator: ',',
+        date_order: DateOrder::MonthDayYear,
+        date_separator: '/',
+        hour12: true,
+        month_names: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        long_date: "{m} {d}, {y}",
+    };
+
+    pub const DE_DE: Locale = Locale {
+        tag: "de-DE",
+        decimal_separator: ',',
+        group_separator: '.',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '.',
+        hour12: false,
+        month_names: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        long_date: "{d}. {m} {y}",
+    };
+
+    pub const FR_FR: Locale = Locale {
+        tag: "fr-FR",
+        decimal_separator: ',',
+        group_separator: '\u{202F}',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+        hour12: false,
+        month_names: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        long_date: "{d} {m} {y}",
+    };
+
+    /// Convenções neutras (ISO 8601, sem separador de milhares).
+    pub const ISO: Locale = Locale {
+        tag: "und",
+        decimal_separator: '.',
+        group_separator: '\0',
+        date_order: DateOrder::YearMonthDay,
+        date_separator: '-',
+        hour12: false,
+        month_names: [
+            "01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12",
+        ],
+        long_date: "{y}-{m}-{d}",
+    };
+
+    /// Locales conhecidos.
+    pub const ALL: [&'static Locale; 5] = [
+        &Self::PT_BR,
+        &Self::EN_US,
+        &Self::DE_DE,
+        &Self::FR_FR,
+        &Self::ISO,
+    ];
+
+    /// Locale pela tag (`"pt-BR"`, `"pt_BR"`, `"en"`...).
+    ///
+    /// Sem correspondência exata, usa o primeiro com o mesmo idioma.
+    pub fn from_tag(tag: &str) -> Option<&'static Locale> {
+        let normalized = |c: char| if c == '_' { '-' } else { c };
+        let exact = Self::ALL.iter().find(|l| {
+            l.tag.len() == tag.len()
+                && l.tag
+                    .chars()
+                    .zip(tag.chars().map(normalized))
+                    .all(|(a, b)| a.eq_ignore_ascii_case(&b))
+        });
+        if let Some(locale) = exact {
+            return Some(locale);
+        }
+
+        let language = tag.split(['-', '_']).next()?;
+        Self::ALL
+            .iter()
+            .find(|l| {
+                l.tag
+                    .split('-')
+                    .next()
+                    .is_some_and(|lang| lang.eq_ignore_ascii_case(language))
+            })
+            .copied()
+    }
+
+    /// Locale configurado no sistema (lido uma vez e guardado).
+    pub fn current() -> &'static Locale {
+        let cached = CURRENT.load(Ordering::Acquire);
+        if !cached.is_null() {
+            // SAFETY: só guardamos referências a locales estáticos
+            return unsafe { &*cached };
+        }
+        let locale = query_system_locale().ok().flatten().unwrap_or(&Self::PT_BR);
+        Self::set_current(locale);
+        locale
+    }
+
+    /// Substitui o locale do processo (ex.: opção `--locale` do app).
+    pub fn set_current(locale: &'static Locale) {
+        CURRENT.store(locale as *const Locale as *mut Locale, Ordering::Release);
+    }
+}
+
+/// Locale do processo (nulo até a primeira consulta).
+static CURRENT: AtomicPtr<Locale> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Lê `system.locale` do serviço de configurações (`Ok(None)` se o locale
+/// for desconhecido).
+fn query_system_locale() -> SysResult<Option<&'static Locale>> {
+    let (reply_port, reply_name) = Port::create_unique("settings.r.", 4)?;
+    let req = GetValueRequest {
+        op: opcodes::GET_VALUE,
+        key: fixed_str(LOCALE_KEY),
+        reply_port: reply_name,
+    };
+    Port::connect(SETTINGS_PORT)?.send(as_bytes(&req), 0)?;
+
+    let msg = recv_reply::<SettingsMessage>(
+        &reply_port,
+        opcodes::VALUE,
+        core::mem::size_of::<ValueResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    let value = unsafe { msg.value };
+    let len = (value.len as usize).min(value.value.len());
+    Ok(Locale::from_tag(str_from_fixed(&value.value[..len])))
+}
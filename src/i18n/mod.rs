@@ -0,0 +1,21 @@
+//! # i18n
+//!
+//! Formatação sensível ao locale configurado (números, tamanhos, datas),
+//! para que os apps do sistema exibam valores de forma consistente.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`locale`] | Locales suportados e o locale do sistema |
+//! | [`format`] | Números, tamanhos em bytes e datas |
+
+pub mod format;
+pub mod locale;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use format::DateStyle;
+pub use locale::{DateOrder, Locale};
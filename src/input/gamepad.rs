@@ -0,0 +1,246 @@
+//! # Gamepad Input
+//!
+//! Controles (gamepads/joysticks) no layout de controle padrão.
+//!
+//! O kernel mantém até [`MAX_GAMEPADS`] controles em slots fixos;
+//! [`poll_gamepads`] lê o estado dos conectados e normaliza os eixos
+//! (analógicos em -1.0..=1.0, gatilhos em 0.0..=1.0). Conexões e
+//! desconexões chegam às janelas como `Event::Gamepad`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::{poll_gamepads, GamepadButton, GamepadState, MAX_GAMEPADS};
+//!
+//! let mut previous = [GamepadState::default(); MAX_GAMEPADS];
+//! let mut pads = [GamepadState::default(); MAX_GAMEPADS];
+//! let count = poll_gamepads(&mut pads)?;
+//! for pad in &pads[..count] {
+//!     let stick = pad.left_stick_deadzone(0.15);
+//!     player.walk(stick.x, stick.y);
+//!     if pad.just_pressed(&previous[pad.slot as usize], GamepadButton::A) {
+//!         player.jump();
+//!     }
+//!     previous[pad.slot as usize] = *pad;
+//! }
+//! ```
+
+use gfx_types::geometry::PointF;
+use rdsmath::sqrtf;
+
+use crate::syscall::safe::sys_gamepad_read;
+use crate::syscall::SysResult;
+
+/// Máximo de controles simultâneos.
+pub const MAX_GAMEPADS: usize = 4;
+
+/// Número de eixos (ver [`GamepadAxis`]).
+pub const GAMEPAD_AXES: usize = 6;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Botões do controle padrão (posições do Xbox; Cross/Circle/Square/
+/// Triangle são A/B/X/Y).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    A,
+    B,
+    X,
+    Y,
+    LeftShoulder,
+    RightShoulder,
+    Back,
+    Start,
+    Guide,
+    LeftStick,
+    RightStick,
+    DpadUp,
+    DpadDown,
+    DpadLeft,
+    DpadRight,
+}
+
+impl GamepadButton {
+    /// Todos os botões, na ordem dos bits.
+    pub const ALL: [Self; 15] = [
+        Self::A,
+        Self::B,
+        Self::X,
+        Self::Y,
+        Self::LeftShoulder,
+        Self::RightShoulder,
+        Self::Back,
+        Self::Start,
+        Self::Guide,
+        Self::LeftStick,
+        Self::RightStick,
+        Self::DpadUp,
+        Self::DpadDown,
+        Self::DpadLeft,
+        Self::DpadRight,
+    ];
+
+    /// Retorna a máscara de bits para este botão.
+    pub fn mask(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Eixos do controle padrão.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    /// Analógico esquerdo, -1.0 (esquerda) a 1.0 (direita).
+    LeftX,
+    /// Analógico esquerdo, -1.0 (cima) a 1.0 (baixo).
+    LeftY,
+    RightX,
+    RightY,
+    /// Gatilho esquerdo, 0.0 (solto) a 1.0.
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl GamepadAxis {
+    /// Gatilho (0.0..=1.0) em vez de analógico.
+    #[inline]
+    pub fn is_trigger(self) -> bool {
+        matches!(self, Self::LeftTrigger | Self::RightTrigger)
+    }
+}
+
+/// Estado de um controle no formato do kernel.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RawGamepadState {
+    /// Slot (0..MAX_GAMEPADS), estável enquanto conectado.
+    pub slot: u32,
+    /// 1 se conectado.
+    pub connected: u32,
+    /// Botões (bitmask de [`GamepadButton::mask`]).
+    pub buttons: u32,
+    /// Eixos na ordem de [`GamepadAxis`] (-32768..=32767; gatilhos
+    /// 0..=32767).
+    pub axes: [i16; GAMEPAD_AXES],
+}
+
+/// Estado normalizado de um controle.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct GamepadState {
+    pub slot: u32,
+    pub connected: bool,
+    /// Botões (bitmask de [`GamepadButton::mask`]).
+    pub buttons: u32,
+    /// Eixos na ordem de [`GamepadAxis`].
+    pub axes: [f32; GAMEPAD_AXES],
+}
+
+impl GamepadState {
+    /// Normaliza o estado do kernel.
+    pub fn from_raw(raw: &RawGamepadState) -> Self {
+        let mut axes = [0.0; GAMEPAD_AXES];
+        for (i, (axis, &value)) in axes.iter_mut().zip(&raw.axes).enumerate() {
+            let value = (value as f32 / i16::MAX as f32).clamp(-1.0, 1.0);
+            *axis = if i >= GamepadAxis::LeftTrigger as usize {
+                value.max(0.0)
+            } else {
+                value
+            };
+        }
+        Self {
+            slot: raw.slot,
+            connected: raw.connected != 0,
+            buttons: raw.buttons,
+            axes,
+        }
+    }
+
+    /// Verifica se um botão está pressionado.
+    #[inline]
+    pub fn is_pressed(&self, button: GamepadButton) -> bool {
+        (self.buttons & button.mask()) != 0
+    }
+
+    /// Pressionado agora e solto em `previous`.
+    #[inline]
+    pub fn just_pressed(&self, previous: &Self, button: GamepadButton) -> bool {
+        self.is_pressed(button) && !previous.is_pressed(button)
+    }
+
+    /// Solto agora e pressionado em `previous`.
+    #[inline]
+    pub fn just_released(&self, previous: &Self, button: GamepadButton) -> bool {
+        !self.is_pressed(button) && previous.is_pressed(button)
+    }
+
+    /// Valor de um eixo.
+    #[inline]
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis as usize]
+    }
+
+    /// Analógico esquerdo.
+    #[inline]
+    pub fn left_stick(&self) -> PointF {
+        PointF::new(self.axis(GamepadAxis::LeftX), self.axis(GamepadAxis::LeftY))
+    }
+
+    /// Analógico direito.
+    #[inline]
+    pub fn right_stick(&self) -> PointF {
+        PointF::new(
+            self.axis(GamepadAxis::RightX),
+            self.axis(GamepadAxis::RightY),
+        )
+    }
+
+    /// Analógico esquerdo com zona morta radial (ver [`apply_deadzone`]).
+    pub fn left_stick_deadzone(&self, deadzone: f32) -> PointF {
+        apply_deadzone(self.left_stick(), deadzone)
+    }
+
+    /// Analógico direito com zona morta radial (ver [`apply_deadzone`]).
+    pub fn right_stick_deadzone(&self, deadzone: f32) -> PointF {
+        apply_deadzone(self.right_stick(), deadzone)
+    }
+}
+
+/// Zona morta radial: zera o analógico abaixo de `deadzone` (0.0..1.0) e
+/// reescala o restante para começar em 0.
+pub fn apply_deadzone(stick: PointF, deadzone: f32) -> PointF {
+    let length = sqrtf(stick.x * stick.x + stick.y * stick.y);
+    if length <= deadzone || deadzone >= 1.0 {
+        return PointF::new(0.0, 0.0);
+    }
+    let scale = ((length - deadzone) / (1.0 - deadzone)).min(1.0) / length;
+    PointF::new(stick.x * scale, stick.y * scale)
+}
+
+// =============================================================================
+// FUNÇÕES
+// =============================================================================
+
+/// Lê o estado dos controles conectados.
+///
+/// Retorna o número de estados escritos em `states`.
+pub fn poll_gamepads(states: &mut [GamepadState]) -> SysResult<usize> {
+    let mut raw = [RawGamepadState::default(); MAX_GAMEPADS];
+    let read = sys_gamepad_read(&mut raw)?.min(MAX_GAMEPADS);
+    let mut count = 0;
+    for pad in raw[..read].iter().filter(|pad| pad.connected != 0) {
+        let Some(slot) = states.get_mut(count) else {
+            break;
+        };
+        *slot = GamepadState::from_raw(pad);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Lê o estado do controle no `slot` (se conectado).
+pub fn read_gamepad(slot: u32) -> SysResult<Option<GamepadState>> {
+    let mut states = [GamepadState::default(); MAX_GAMEPADS];
+    let count = poll_gamepads(&mut states)?;
+    Ok(states[..count].iter().find(|pad| pad.slot == slot).copied())
+}
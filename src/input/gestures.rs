@@ -0,0 +1,537 @@
+//! # Gestures
+//!
+//! Reconhecimento de gestos (toque, toque duplo, toque longo, swipe, pan,
+//! pinch, rotate) a partir de toques ou do mouse.
+//!
+//! Como no [`KeyboardState`](super::KeyboardState), o tempo é passado pelo
+//! chamador: os eventos entram por `feed_*` e os gestos saem por
+//! [`poll`](GestureDetector::poll), que também dispara o toque longo quando
+//! o dedo fica parado.
+//!
+//! Gestos contínuos (pan, pinch, rotate) chegam com fase `Begin`, `Move` e
+//! `End`/`Cancel`; os discretos (tap, swipe...) com `End`. Um toque duplo
+//! entrega `Tap` no primeiro toque e `DoubleTap` no segundo.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::{GestureDetector, GestureType};
+//! use redpowder::time::Instant;
+//!
+//! let mut gestures = GestureDetector::new();
+//! for event in window.poll_events() {
+//!     if let Event::Touch(touch) = event {
+//!         gestures.feed_event(&touch, Instant::now()?);
+//!     }
+//! }
+//! while let Some(gesture) = gestures.poll(Instant::now()?) {
+//!     match gesture.kind {
+//!         GestureType::Pinch => zoom(gesture.scale),
+//!         GestureType::Swipe => next_page(gesture.direction),
+//!         GestureType::DoubleTap => reset_zoom(),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use core::time::Duration;
+
+use gfx_types::geometry::PointF;
+use gfx_types::input::{GestureType, SwipeDirection, TouchPhase, TouchPoint};
+use rdsmath::{absf, atan2f, sqrtf, PI, TAU};
+
+use crate::event::TouchEvent;
+use crate::time::Instant;
+
+/// ID usado para o mouse (não colide com IDs de toque reais).
+const MOUSE_ID: u32 = u32::MAX - 1;
+
+/// Gestos enfileirados entre `feed` e `poll`.
+const QUEUE_LEN: usize = 4;
+
+// =============================================================================
+// CONFIGURAÇÃO
+// =============================================================================
+
+/// Limiares e tempos do reconhecimento.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureConfig {
+    /// Movimento máximo (px) para ainda ser um toque ou toque longo.
+    pub tap_slop: f32,
+    /// Duração máxima de um toque.
+    pub tap_timeout: Duration,
+    /// Intervalo máximo entre os dois toques de um toque duplo.
+    pub double_tap_timeout: Duration,
+    /// Distância máxima (px) entre os dois toques de um toque duplo.
+    pub double_tap_slop: f32,
+    /// Tempo parado até o toque longo.
+    pub long_press: Duration,
+    /// Distância mínima (px) de um swipe.
+    pub swipe_min_distance: f32,
+    /// Velocidade mínima (px/s) de um swipe.
+    pub swipe_min_velocity: f32,
+    /// Variação mínima da escala (ex.: 0.1 = 10%) para iniciar um pinch.
+    pub pinch_threshold: f32,
+    /// Ângulo mínimo (radianos) para iniciar um rotate.
+    pub rotate_threshold: f32,
+}
+
+impl GestureConfig {
+    /// Valores para telas de toque comuns.
+    pub const DEFAULT: Self = Self {
+        tap_slop: 10.0,
+        tap_timeout: Duration::from_millis(300),
+        double_tap_timeout: Duration::from_millis(300),
+        double_tap_slop: 40.0,
+        long_press: Duration::from_millis(500),
+        swipe_min_distance: 50.0,
+        swipe_min_velocity: 300.0,
+        pinch_threshold: 0.1,
+        rotate_threshold: 0.26,
+    };
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// =============================================================================
+// GESTURE
+// =============================================================================
+
+/// Gesto reconhecido.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gesture {
+    pub kind: GestureType,
+    /// Fase (gestos discretos sempre `End`).
+    pub phase: TouchPhase,
+    /// Ponto do toque; posição atual no pan; centro no pinch/rotate.
+    pub position: PointF,
+    /// Pan: movimento desde o último evento. Swipe: deslocamento total.
+    pub delta: PointF,
+    /// Direção do swipe.
+    pub direction: Option<SwipeDirection>,
+    /// Escala acumulada do pinch/rotate (1.0 nos demais).
+    pub scale: f32,
+    /// Rotação acumulada do pinch/rotate em radianos (0.0 nos demais).
+    pub rotation: f32,
+}
+
+impl Gesture {
+    fn new(kind: GestureType, phase: TouchPhase, position: PointF) -> Self {
+        Self {
+            kind,
+            phase,
+            position,
+            delta: PointF::new(0.0, 0.0),
+            direction: None,
+            scale: 1.0,
+            rotation: 0.0,
+        }
+    }
+}
+
+// =============================================================================
+// DETECTOR
+// =============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct Contact {
+    id: u32,
+    start: PointF,
+    pos: PointF,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    None,
+    Pan,
+    Pinch,
+    Rotate,
+}
+
+/// Reconhecedor de gestos de um ou dois dedos.
+///
+/// Dedos além do segundo são ignorados.
+#[derive(Debug, Clone)]
+pub struct GestureDetector {
+    config: GestureConfig,
+    contacts: [Option<Contact>; 2],
+    /// Início da sequência (primeiro dedo).
+    started: Option<Instant>,
+    /// Passou do `tap_slop`: não é mais toque.
+    moved: bool,
+    /// Teve dois dedos: não vira toque, pan ou swipe.
+    multi: bool,
+    long_pressed: bool,
+    mode: Mode,
+    /// Última posição entregue no pan.
+    last_pan: PointF,
+    /// Distância e ângulo iniciais entre os dois dedos.
+    pinch_start: (f32, f32),
+    /// Escala e rotação atuais do pinch/rotate.
+    pinch: (f32, f32),
+    /// Posição e instante do último toque (para o toque duplo).
+    last_tap: Option<(PointF, Instant)>,
+    queue: [Option<Gesture>; QUEUE_LEN],
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GestureDetector {
+    /// Detector com [`GestureConfig::DEFAULT`].
+    pub const fn new() -> Self {
+        Self::with_config(GestureConfig::DEFAULT)
+    }
+
+    /// Detector com limiares próprios.
+    pub const fn with_config(config: GestureConfig) -> Self {
+        Self {
+            config,
+            contacts: [None; 2],
+            started: None,
+            moved: false,
+            multi: false,
+            long_pressed: false,
+            mode: Mode::None,
+            last_pan: PointF::new(0.0, 0.0),
+            pinch_start: (0.0, 0.0),
+            pinch: (1.0, 0.0),
+            last_tap: None,
+            queue: [None; QUEUE_LEN],
+        }
+    }
+
+    /// Configuração atual.
+    pub fn config(&self) -> &GestureConfig {
+        &self.config
+    }
+
+    /// Troca a configuração.
+    pub fn set_config(&mut self, config: GestureConfig) {
+        self.config = config;
+    }
+
+    /// Descarta toques em andamento e gestos pendentes (ex.: ao perder o
+    /// foco).
+    pub fn reset(&mut self) {
+        *self = Self::with_config(self.config);
+    }
+
+    // =========================================================================
+    // ENTRADA
+    // =========================================================================
+
+    /// Registra os pontos de um [`TouchEvent`] recebido em `now`.
+    pub fn feed_event(&mut self, event: &TouchEvent, now: Instant) {
+        for point in event.points() {
+            self.feed_touch(&point, now);
+        }
+    }
+
+    /// Registra o mouse como um dedo (botão primário pressionado ou não).
+    pub fn feed_mouse(&mut self, pos: PointF, pressed: bool, now: Instant) {
+        let down = self.contact(MOUSE_ID).is_some();
+        let phase = match (pressed, down) {
+            (true, false) => TouchPhase::Begin,
+            (true, true) => TouchPhase::Move,
+            (false, true) => TouchPhase::End,
+            (false, false) => return,
+        };
+        self.feed(MOUSE_ID, phase, pos, now);
+    }
+
+    /// Registra um ponto de toque recebido em `now`.
+    pub fn feed_touch(&mut self, point: &TouchPoint, now: Instant) {
+        self.feed(point.id.0, point.phase, point.position, now);
+    }
+
+    fn feed(&mut self, id: u32, phase: TouchPhase, pos: PointF, now: Instant) {
+        match phase {
+            TouchPhase::Begin => self.begin(id, pos, now),
+            TouchPhase::Move => self.moved_to(id, pos),
+            TouchPhase::End | TouchPhase::Cancel => self.end(id, pos, phase, now),
+        }
+    }
+
+    fn begin(&mut self, id: u32, pos: PointF, now: Instant) {
+        if self.contact(id).is_some() {
+            return;
+        }
+        let Some(slot) = self.contacts.iter().position(Option::is_none) else {
+            return;
+        };
+        if self.count() == 0 {
+            self.started = Some(now);
+            self.moved = false;
+            self.multi = false;
+            self.long_pressed = false;
+            self.mode = Mode::None;
+        }
+        self.contacts[slot] = Some(Contact {
+            id,
+            start: pos,
+            pos,
+        });
+
+        if let [Some(a), Some(b)] = self.contacts {
+            if self.mode == Mode::Pan {
+                self.push(Gesture::new(
+                    GestureType::Pan,
+                    TouchPhase::End,
+                    self.last_pan,
+                ));
+            }
+            self.multi = true;
+            self.mode = Mode::None;
+            self.pinch_start = (distance(a.pos, b.pos), angle(a.pos, b.pos));
+            self.pinch = (1.0, 0.0);
+        }
+    }
+
+    fn moved_to(&mut self, id: u32, pos: PointF) {
+        let Some(contact) = self.contact_mut(id) else {
+            return;
+        };
+        contact.pos = pos;
+        let start = contact.start;
+
+        if let [Some(a), Some(b)] = self.contacts {
+            self.two_finger_move(a, b);
+            return;
+        }
+        if self.multi {
+            return;
+        }
+
+        let phase = if self.mode == Mode::Pan {
+            TouchPhase::Move
+        } else if !self.moved && distance(start, pos) > self.config.tap_slop {
+            self.moved = true;
+            self.mode = Mode::Pan;
+            self.last_pan = start;
+            TouchPhase::Begin
+        } else {
+            return;
+        };
+        self.push_pan(phase, pos);
+    }
+
+    fn two_finger_move(&mut self, a: Contact, b: Contact) {
+        let (start_distance, start_angle) = self.pinch_start;
+        let scale = if start_distance > 0.0 {
+            distance(a.pos, b.pos) / start_distance
+        } else {
+            1.0
+        };
+        let rotation = wrap_angle(angle(a.pos, b.pos) - start_angle);
+
+        let phase = match self.mode {
+            Mode::Pinch | Mode::Rotate => TouchPhase::Move,
+            _ if absf(scale - 1.0) >= self.config.pinch_threshold => {
+                self.mode = Mode::Pinch;
+                TouchPhase::Begin
+            }
+            _ if absf(rotation) >= self.config.rotate_threshold => {
+                self.mode = Mode::Rotate;
+                TouchPhase::Begin
+            }
+            _ => return,
+        };
+        self.moved = true;
+        self.pinch = (scale, rotation);
+        self.push_pinch(phase, midpoint(a.pos, b.pos));
+    }
+
+    fn end(&mut self, id: u32, pos: PointF, phase: TouchPhase, now: Instant) {
+        let Some(slot) = self
+            .contacts
+            .iter()
+            .position(|c| c.is_some_and(|c| c.id == id))
+        else {
+            return;
+        };
+        let Some(Contact { start, .. }) = self.contacts[slot].take() else {
+            return;
+        };
+
+        if matches!(self.mode, Mode::Pinch | Mode::Rotate) {
+            let other = self.contacts.iter().flatten().next().map_or(pos, |c| c.pos);
+            self.push_pinch(phase, midpoint(pos, other));
+            self.mode = Mode::None;
+        }
+        if self.count() > 0 || self.multi {
+            return;
+        }
+
+        let started = self.started.take().unwrap_or(now);
+        let duration = now.saturating_duration_since(started);
+
+        if self.mode == Mode::Pan {
+            self.mode = Mode::None;
+            self.push_pan(phase, pos);
+            if phase == TouchPhase::End {
+                self.swipe(start, pos, duration);
+            }
+            return;
+        }
+
+        let is_tap = phase == TouchPhase::End
+            && !self.moved
+            && !self.long_pressed
+            && duration <= self.config.tap_timeout;
+        if !is_tap {
+            return;
+        }
+        let double = self.last_tap.is_some_and(|(at, when)| {
+            now.saturating_duration_since(when) <= self.config.double_tap_timeout
+                && distance(at, pos) <= self.config.double_tap_slop
+        });
+        if double {
+            self.last_tap = None;
+            self.push(Gesture::new(GestureType::DoubleTap, TouchPhase::End, pos));
+        } else {
+            self.last_tap = Some((pos, now));
+            self.push(Gesture::new(GestureType::Tap, TouchPhase::End, pos));
+        }
+    }
+
+    fn swipe(&mut self, start: PointF, end: PointF, duration: Duration) {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let length = sqrtf(dx * dx + dy * dy);
+        if length < self.config.swipe_min_distance {
+            return;
+        }
+        let secs = duration.as_secs_f32();
+        if secs > 0.0 && length / secs < self.config.swipe_min_velocity {
+            return;
+        }
+        let direction = if absf(dx) >= absf(dy) {
+            if dx < 0.0 {
+                SwipeDirection::Left
+            } else {
+                SwipeDirection::Right
+            }
+        } else if dy < 0.0 {
+            SwipeDirection::Up
+        } else {
+            SwipeDirection::Down
+        };
+        let mut gesture = Gesture::new(GestureType::Swipe, TouchPhase::End, end);
+        gesture.delta = PointF::new(dx, dy);
+        gesture.direction = Some(direction);
+        self.push(gesture);
+    }
+
+    // =========================================================================
+    // SAÍDA
+    // =========================================================================
+
+    /// Próximo gesto reconhecido até `now`.
+    pub fn poll(&mut self, now: Instant) -> Option<Gesture> {
+        if let Some(gesture) = self.pop() {
+            return Some(gesture);
+        }
+        let deadline = self.next_deadline()?;
+        if now < deadline {
+            return None;
+        }
+        self.long_pressed = true;
+        let pos = self.contacts.iter().flatten().next()?.pos;
+        Some(Gesture::new(GestureType::LongPress, TouchPhase::End, pos))
+    }
+
+    /// Instante em que o toque longo dispara (para o timeout do loop de
+    /// eventos), se houver um dedo parado.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        if self.count() != 1 || self.multi || self.moved || self.long_pressed {
+            return None;
+        }
+        self.started?.checked_add(self.config.long_press)
+    }
+
+    /// Há dedos (ou o botão do mouse) pressionados.
+    pub fn is_active(&self) -> bool {
+        self.count() > 0
+    }
+
+    // =========================================================================
+    // AUXILIARES
+    // =========================================================================
+
+    fn count(&self) -> usize {
+        self.contacts.iter().flatten().count()
+    }
+
+    fn contact(&self, id: u32) -> Option<&Contact> {
+        self.contacts.iter().flatten().find(|c| c.id == id)
+    }
+
+    fn contact_mut(&mut self, id: u32) -> Option<&mut Contact> {
+        self.contacts.iter_mut().flatten().find(|c| c.id == id)
+    }
+
+    fn push_pan(&mut self, phase: TouchPhase, pos: PointF) {
+        let mut gesture = Gesture::new(GestureType::Pan, phase, pos);
+        gesture.delta = PointF::new(pos.x - self.last_pan.x, pos.y - self.last_pan.y);
+        self.last_pan = pos;
+        self.push(gesture);
+    }
+
+    fn push_pinch(&mut self, phase: TouchPhase, center: PointF) {
+        let kind = if self.mode == Mode::Rotate {
+            GestureType::Rotate
+        } else {
+            GestureType::Pinch
+        };
+        let mut gesture = Gesture::new(kind, phase, center);
+        (gesture.scale, gesture.rotation) = self.pinch;
+        self.push(gesture);
+    }
+
+    fn push(&mut self, gesture: Gesture) {
+        if let Some(slot) = self.queue.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(gesture);
+        } else {
+            // Fila cheia: o gesto mais antigo é descartado
+            self.queue.rotate_left(1);
+            self.queue[QUEUE_LEN - 1] = Some(gesture);
+        }
+    }
+
+    fn pop(&mut self) -> Option<Gesture> {
+        let gesture = self.queue[0].take()?;
+        self.queue.rotate_left(1);
+        Some(gesture)
+    }
+}
+
+fn distance(a: PointF, b: PointF) -> f32 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    sqrtf(dx * dx + dy * dy)
+}
+
+fn angle(a: PointF, b: PointF) -> f32 {
+    atan2f(b.y - a.y, b.x - a.x)
+}
+
+fn midpoint(a: PointF, b: PointF) -> PointF {
+    PointF::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+/// Normaliza para (-PI, PI].
+fn wrap_angle(mut angle: f32) -> f32 {
+    while angle > PI {
+        angle -= TAU;
+    }
+    while angle <= -PI {
+        angle += TAU;
+    }
+    angle
+}
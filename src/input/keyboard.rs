@@ -2,8 +2,8 @@
 //!
 //! Funções e tipos para entrada de teclado.
 
-use crate::syscall::SYS_KEYBOARD_READ;
-use crate::syscall::{check_error, syscall2, SysResult};
+use crate::syscall::safe::sys_keyboard_read;
+use crate::syscall::SysResult;
 
 use super::keycodes::KeyCode;
 
@@ -52,22 +52,14 @@ pub fn poll_keyboard(buffer: &mut [KeyEvent]) -> SysResult<usize> {
     if buffer.is_empty() {
         return Ok(0);
     }
-    let ret = syscall2(
-        SYS_KEYBOARD_READ,
-        buffer.as_mut_ptr() as usize,
-        buffer.len(),
-    );
-    check_error(ret)?;
-    Ok(ret as usize)
+    sys_keyboard_read(buffer)
 }
 
 /// Lê um único evento de teclado (se disponível).
 pub fn read_key() -> SysResult<Option<KeyEvent>> {
-    let mut event = KeyEvent::default();
-    let ret = syscall2(SYS_KEYBOARD_READ, &mut event as *mut _ as usize, 1);
-    check_error(ret)?;
-    if ret > 0 {
-        Ok(Some(event))
+    let mut event = [KeyEvent::default()];
+    if sys_keyboard_read(&mut event)? > 0 {
+        Ok(Some(event[0]))
     } else {
         Ok(None)
     }
@@ -0,0 +1,248 @@
+//! # Layout
+//!
+//! Tabela de um layout de teclado: para cada scancode (set 1), o símbolo
+//! em quatro níveis — normal, Shift, AltGr e Shift+AltGr.
+//!
+//! ## Formato de arquivo
+//!
+//! Uma tecla por linha, `#` inicia comentário:
+//!
+//! ```text
+//! name abnt2
+//! # scancode normal shift altgr shift+altgr
+//! 0x27 ç Ç
+//! 0x1A dead_acute dead_grave
+//! 0x10 q Q /
+//! 0x39 space space
+//! ```
+//!
+//! Níveis omitidos ficam vazios; `-` também marca um nível vazio. Aceita
+//! `U+XXXX`, `space`, `tab`, `enter` e `dead_acute`, `dead_grave`,
+//! `dead_circumflex`, `dead_tilde`, `dead_diaeresis`. Linhas posteriores
+//! substituem as anteriores.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+
+use crate::fs::File;
+use crate::syscall::{SysError, SysResult};
+
+use super::layouts;
+
+/// Scancodes cobertos (set 1, sem o bit de liberação).
+pub const SCANCODES: usize = 128;
+
+/// Níveis por tecla: normal, Shift, AltGr, Shift+AltGr.
+pub const LEVELS: usize = 4;
+
+/// Tamanho máximo de um arquivo de layout.
+const MAX_FILE_SIZE: u64 = 64 * 1024;
+
+// =============================================================================
+// SÍMBOLOS
+// =============================================================================
+
+/// Acento de uma tecla morta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadKey {
+    Acute,
+    Grave,
+    Circumflex,
+    Tilde,
+    Diaeresis,
+}
+
+impl DeadKey {
+    /// Caractere do acento sozinho (tecla morta seguida de espaço).
+    pub const fn spacing(self) -> char {
+        match self {
+            Self::Acute => '´',
+            Self::Grave => '`',
+            Self::Circumflex => '^',
+            Self::Tilde => '~',
+            Self::Diaeresis => '¨',
+        }
+    }
+
+    /// Compõe o acento com `base` (ex.: agudo + `a` = `á`).
+    pub fn compose(self, base: char) -> Option<char> {
+        let (plain, accented) = match self {
+            Self::Acute => ("aeiouyAEIOUY", "áéíóúýÁÉÍÓÚÝ"),
+            Self::Grave => ("aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+            Self::Circumflex => ("aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+            Self::Tilde => ("aonAON", "ãõñÃÕÑ"),
+            Self::Diaeresis => ("aeiouyAEIOU", "äëïöüÿÄËÏÖÜ"),
+        };
+        let index = plain.chars().position(|c| c == base)?;
+        accented.chars().nth(index)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "dead_acute" => Self::Acute,
+            "dead_grave" => Self::Grave,
+            "dead_circumflex" => Self::Circumflex,
+            "dead_tilde" => Self::Tilde,
+            "dead_diaeresis" => Self::Diaeresis,
+            _ => return None,
+        })
+    }
+}
+
+/// Símbolo de uma tecla num nível.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Sym {
+    /// Sem caractere (modificadores, teclas de função, nível vazio).
+    #[default]
+    None,
+    Char(char),
+    Dead(DeadKey),
+}
+
+impl Sym {
+    fn parse(token: &str) -> Option<Self> {
+        if token == "-" {
+            return Some(Self::None);
+        }
+        if let Some(dead) = DeadKey::from_name(token) {
+            return Some(Self::Dead(dead));
+        }
+        let c = match token {
+            "space" => ' ',
+            "tab" => '\t',
+            "enter" => '\n',
+            _ => match token.strip_prefix("U+") {
+                Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                None => {
+                    let mut chars = token.chars();
+                    let c = chars.next()?;
+                    if chars.next().is_some() {
+                        return None;
+                    }
+                    c
+                }
+            },
+        };
+        Some(Self::Char(c))
+    }
+}
+
+/// Entrada de uma tabela embutida: scancode e os quatro níveis.
+pub type KeyEntry = (u8, [Sym; LEVELS]);
+
+// =============================================================================
+// KEYMAP
+// =============================================================================
+
+/// Layout de teclado.
+#[derive(Clone)]
+pub struct Keymap {
+    name: String,
+    keys: [[Sym; LEVELS]; SCANCODES],
+}
+
+impl Keymap {
+    /// Layout vazio.
+    pub fn empty(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            keys: [[Sym::None; LEVELS]; SCANCODES],
+        }
+    }
+
+    /// Layout a partir de tabelas; entradas posteriores substituem as
+    /// anteriores.
+    pub fn from_tables(name: &str, tables: &[&[KeyEntry]]) -> Self {
+        let mut keymap = Self::empty(name);
+        for &(scancode, levels) in tables.iter().flat_map(|table| table.iter()) {
+            keymap.set(scancode, levels);
+        }
+        keymap
+    }
+
+    /// Layout embutido: `"us"`, `"abnt2"` ou `"de"`.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let table = match name {
+            "us" => layouts::US,
+            "abnt2" => layouts::ABNT2,
+            "de" => layouts::DE,
+            _ => return None,
+        };
+        Some(Self::from_tables(name, &[layouts::COMMON, table]))
+    }
+
+    /// Interpreta um layout no formato de arquivo (ver o módulo).
+    pub fn parse(text: &str) -> SysResult<Self> {
+        let mut keymap = Self::empty("custom");
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let mut tokens = line.split_whitespace();
+            let Some(first) = tokens.next() else {
+                continue;
+            };
+            if first == "name" {
+                keymap.name = String::from(tokens.next().ok_or(SysError::InvalidArgument)?);
+                continue;
+            }
+
+            let scancode = parse_scancode(first).ok_or(SysError::InvalidArgument)?;
+            let mut levels = [Sym::None; LEVELS];
+            for (level, token) in tokens.enumerate() {
+                if level >= LEVELS {
+                    return Err(SysError::InvalidArgument);
+                }
+                levels[level] = Sym::parse(token).ok_or(SysError::InvalidArgument)?;
+            }
+            keymap.set(scancode, levels);
+        }
+        Ok(keymap)
+    }
+
+    /// Carrega um layout de arquivo.
+    pub fn load(path: &str) -> SysResult<Self> {
+        let file = File::open(path)?;
+        let size = file.size()?;
+        if size > MAX_FILE_SIZE {
+            return Err(SysError::BufferTooSmall);
+        }
+        let mut bytes = vec![0u8; size as usize];
+        file.read_exact(&mut bytes)?;
+        let text = core::str::from_utf8(&bytes).map_err(|_| SysError::InvalidArgument)?;
+        Self::parse(text)
+    }
+
+    /// Nome do layout.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Símbolo de `scancode` em `level` (0..[`LEVELS`]).
+    pub fn lookup(&self, scancode: u8, level: usize) -> Sym {
+        self.keys
+            .get((scancode & 0x7F) as usize)
+            .and_then(|levels| levels.get(level))
+            .copied()
+            .unwrap_or(Sym::None)
+    }
+
+    /// Troca os níveis de `scancode`.
+    pub fn set(&mut self, scancode: u8, levels: [Sym; LEVELS]) {
+        self.keys[(scancode & 0x7F) as usize] = levels;
+    }
+}
+
+impl core::fmt::Debug for Keymap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Keymap").field("name", &self.name).finish()
+    }
+}
+
+fn parse_scancode(token: &str) -> Option<u8> {
+    let value = match token.strip_prefix("0x") {
+        Some(hex) => u8::from_str_radix(hex, 16).ok()?,
+        None => token.parse().ok()?,
+    };
+    ((value as usize) < SCANCODES).then_some(value)
+}
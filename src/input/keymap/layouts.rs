@@ -0,0 +1,170 @@
+//! # Layouts
+//!
+//! Layouts embutidos. [`COMMON`] tem as teclas iguais em todos (letras
+//! fixas, espaço, Enter, Tab); cada layout completa com as suas.
+
+use super::layout::{DeadKey, KeyEntry, Sym};
+
+const NO: Sym = Sym::None;
+
+const fn c(c: char) -> Sym {
+    Sym::Char(c)
+}
+
+const fn d(dead: DeadKey) -> Sym {
+    Sym::Dead(dead)
+}
+
+/// Letra ASCII sem AltGr.
+const fn letter(scancode: u8, lower: char) -> KeyEntry {
+    (scancode, [c(lower), c(lower.to_ascii_uppercase()), NO, NO])
+}
+
+/// Tecla com normal e Shift.
+const fn pair(scancode: u8, normal: char, shift: char) -> KeyEntry {
+    (scancode, [c(normal), c(shift), NO, NO])
+}
+
+/// Tecla com normal, Shift e AltGr.
+const fn triple(scancode: u8, normal: char, shift: char, altgr: char) -> KeyEntry {
+    (scancode, [c(normal), c(shift), c(altgr), NO])
+}
+
+// =============================================================================
+// COMUM
+// =============================================================================
+
+/// Teclas iguais em US, ABNT2 e DE.
+pub const COMMON: &[KeyEntry] = &[
+    (0x0F, [c('\t'), c('\t'), NO, NO]),
+    (0x1C, [c('\n'), c('\n'), NO, NO]),
+    (0x39, [c(' '), c(' '), c(' '), c(' ')]),
+    letter(0x11, 'w'),
+    letter(0x13, 'r'),
+    letter(0x14, 't'),
+    letter(0x16, 'u'),
+    letter(0x17, 'i'),
+    letter(0x18, 'o'),
+    letter(0x19, 'p'),
+    letter(0x1E, 'a'),
+    letter(0x1F, 's'),
+    letter(0x20, 'd'),
+    letter(0x21, 'f'),
+    letter(0x22, 'g'),
+    letter(0x23, 'h'),
+    letter(0x24, 'j'),
+    letter(0x25, 'k'),
+    letter(0x26, 'l'),
+    letter(0x2D, 'x'),
+    letter(0x2E, 'c'),
+    letter(0x2F, 'v'),
+    letter(0x30, 'b'),
+    letter(0x31, 'n'),
+];
+
+// =============================================================================
+// US
+// =============================================================================
+
+/// Inglês (EUA), QWERTY.
+pub const US: &[KeyEntry] = &[
+    pair(0x02, '1', '!'),
+    pair(0x03, '2', '@'),
+    pair(0x04, '3', '#'),
+    pair(0x05, '4', '$'),
+    pair(0x06, '5', '%'),
+    pair(0x07, '6', '^'),
+    pair(0x08, '7', '&'),
+    pair(0x09, '8', '*'),
+    pair(0x0A, '9', '('),
+    pair(0x0B, '0', ')'),
+    pair(0x0C, '-', '_'),
+    pair(0x0D, '=', '+'),
+    letter(0x10, 'q'),
+    letter(0x12, 'e'),
+    letter(0x15, 'y'),
+    pair(0x1A, '[', '{'),
+    pair(0x1B, ']', '}'),
+    pair(0x27, ';', ':'),
+    pair(0x28, '\'', '"'),
+    pair(0x29, '`', '~'),
+    pair(0x2B, '\\', '|'),
+    letter(0x2C, 'z'),
+    letter(0x32, 'm'),
+    pair(0x33, ',', '<'),
+    pair(0x34, '.', '>'),
+    pair(0x35, '/', '?'),
+    pair(0x56, '\\', '|'),
+];
+
+// =============================================================================
+// ABNT2
+// =============================================================================
+
+/// Português (Brasil), ABNT2.
+pub const ABNT2: &[KeyEntry] = &[
+    triple(0x02, '1', '!', '¹'),
+    triple(0x03, '2', '@', '²'),
+    triple(0x04, '3', '#', '³'),
+    triple(0x05, '4', '$', '£'),
+    triple(0x06, '5', '%', '¢'),
+    (0x07, [c('6'), d(DeadKey::Diaeresis), c('¬'), NO]),
+    pair(0x08, '7', '&'),
+    pair(0x09, '8', '*'),
+    pair(0x0A, '9', '('),
+    pair(0x0B, '0', ')'),
+    pair(0x0C, '-', '_'),
+    triple(0x0D, '=', '+', '§'),
+    triple(0x10, 'q', 'Q', '/'),
+    triple(0x11, 'w', 'W', '?'),
+    triple(0x12, 'e', 'E', '°'),
+    letter(0x15, 'y'),
+    (0x1A, [d(DeadKey::Acute), d(DeadKey::Grave), NO, NO]),
+    triple(0x1B, '[', '{', 'ª'),
+    pair(0x27, 'ç', 'Ç'),
+    (0x28, [d(DeadKey::Tilde), d(DeadKey::Circumflex), NO, NO]),
+    pair(0x29, '\'', '"'),
+    triple(0x2B, ']', '}', 'º'),
+    letter(0x2C, 'z'),
+    letter(0x32, 'm'),
+    pair(0x33, ',', '<'),
+    pair(0x34, '.', '>'),
+    pair(0x35, ';', ':'),
+    pair(0x56, '\\', '|'),
+    triple(0x73, '/', '?', '°'),
+];
+
+// =============================================================================
+// DE
+// =============================================================================
+
+/// Alemão, QWERTZ.
+pub const DE: &[KeyEntry] = &[
+    pair(0x02, '1', '!'),
+    triple(0x03, '2', '"', '²'),
+    triple(0x04, '3', '§', '³'),
+    pair(0x05, '4', '$'),
+    pair(0x06, '5', '%'),
+    pair(0x07, '6', '&'),
+    triple(0x08, '7', '/', '{'),
+    triple(0x09, '8', '(', '['),
+    triple(0x0A, '9', ')', ']'),
+    triple(0x0B, '0', '=', '}'),
+    triple(0x0C, 'ß', '?', '\\'),
+    (0x0D, [d(DeadKey::Acute), d(DeadKey::Grave), NO, NO]),
+    triple(0x10, 'q', 'Q', '@'),
+    triple(0x12, 'e', 'E', '€'),
+    letter(0x15, 'z'),
+    pair(0x1A, 'ü', 'Ü'),
+    triple(0x1B, '+', '*', '~'),
+    pair(0x27, 'ö', 'Ö'),
+    pair(0x28, 'ä', 'Ä'),
+    (0x29, [d(DeadKey::Circumflex), c('°'), NO, NO]),
+    pair(0x2B, '#', '\''),
+    letter(0x2C, 'y'),
+    triple(0x32, 'm', 'M', 'µ'),
+    pair(0x33, ',', ';'),
+    pair(0x34, '.', ':'),
+    pair(0x35, '-', '_'),
+    triple(0x56, '<', '>', '|'),
+];
@@ -0,0 +1,43 @@
+//! # Keymap
+//!
+//! Tradução de scancodes em caracteres Unicode conforme o layout do
+//! teclado, com Shift, AltGr, Caps Lock e teclas mortas (acentos).
+//!
+//! [`Keymap`] é a tabela do layout (embutida ou carregada de arquivo);
+//! [`KeyTranslator`] guarda o estado (modificadores seguros, Caps Lock,
+//! acento pendente) e converte cada [`KeyEvent`](super::KeyEvent).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`layout`] | Tabela de layout e formato de arquivo |
+//! | [`layouts`] | Layouts embutidos (US, ABNT2, DE) |
+//! | [`translator`] | Tradutor com estado (modificadores, teclas mortas) |
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::keymap::{KeyTranslator, Keymap};
+//! use redpowder::input::read_key;
+//!
+//! let mut keys = KeyTranslator::new(Keymap::builtin("abnt2").unwrap());
+//!
+//! // ´ seguido de a digita "á"
+//! while let Some(event) = read_key()? {
+//!     for c in keys.feed(&event).chars() {
+//!         print!("{}", c);
+//!     }
+//! }
+//! ```
+
+pub mod layout;
+pub mod layouts;
+pub mod translator;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use layout::{DeadKey, Keymap, Sym};
+pub use translator::{KeyTranslator, Typed};
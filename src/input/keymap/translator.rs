@@ -0,0 +1,213 @@
+//! # Translator
+//!
+//! Tradutor com estado: acompanha Shift, Ctrl, Alt e Meta seguros, o
+//! Caps Lock e o acento de uma tecla morta pendente.
+//!
+//! O scancode de 8 bits não distingue o Alt direito, então AltGr é
+//! Ctrl+Alt (como no Windows). Ctrl, Alt ou Meta sozinhos não digitam nada
+//! (atalhos).
+
+use crate::input::{KeyEvent, Modifiers};
+
+use super::layout::{DeadKey, Keymap, Sym};
+
+// Scancodes dos modificadores (set 1)
+const LEFT_SHIFT: u8 = 0x2A;
+const RIGHT_SHIFT: u8 = 0x36;
+const CTRL: u8 = 0x1D;
+const ALT: u8 = 0x38;
+const CAPS_LOCK: u8 = 0x3A;
+const LEFT_META: u8 = 0x5B;
+const RIGHT_META: u8 = 0x5C;
+
+// =============================================================================
+// TYPED
+// =============================================================================
+
+/// Caracteres produzidos por uma tecla (até dois: acento não composto
+/// seguido da tecla).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Typed {
+    chars: [char; 2],
+    len: u8,
+}
+
+impl Typed {
+    fn one(c: char) -> Self {
+        Self {
+            chars: [c, '\0'],
+            len: 1,
+        }
+    }
+
+    fn two(first: char, second: char) -> Self {
+        Self {
+            chars: [first, second],
+            len: 2,
+        }
+    }
+
+    /// Nada digitado?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Caracteres digitados, em ordem.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chars[..self.len as usize].iter().copied()
+    }
+
+    /// Único caractere (se exatamente um).
+    pub fn single(&self) -> Option<char> {
+        (self.len == 1).then_some(self.chars[0])
+    }
+}
+
+// =============================================================================
+// TRANSLATOR
+// =============================================================================
+
+/// Converte eventos de tecla em texto com um [`Keymap`].
+#[derive(Debug, Clone)]
+pub struct KeyTranslator {
+    keymap: Keymap,
+    left_shift: bool,
+    right_shift: bool,
+    ctrl: bool,
+    alt: bool,
+    meta: bool,
+    caps_lock: bool,
+    pending: Option<DeadKey>,
+}
+
+impl KeyTranslator {
+    /// Tradutor sem teclas seguras.
+    pub fn new(keymap: Keymap) -> Self {
+        Self {
+            keymap,
+            left_shift: false,
+            right_shift: false,
+            ctrl: false,
+            alt: false,
+            meta: false,
+            caps_lock: false,
+            pending: None,
+        }
+    }
+
+    /// Layout em uso.
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Troca o layout (descarta o acento pendente).
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
+        self.pending = None;
+    }
+
+    /// Modificadores ativos.
+    pub fn modifiers(&self) -> Modifiers {
+        let mut modifiers = Modifiers::NONE;
+        for (on, flag) in [
+            (self.left_shift || self.right_shift, Modifiers::SHIFT),
+            (self.ctrl, Modifiers::CTRL),
+            (self.alt, Modifiers::ALT),
+            (self.meta, Modifiers::META),
+            (self.ctrl && self.alt, Modifiers::ALT_GR),
+            (self.caps_lock, Modifiers::CAPS_LOCK),
+        ] {
+            if on {
+                modifiers |= flag;
+            }
+        }
+        modifiers
+    }
+
+    /// Acento de tecla morta aguardando a próxima tecla.
+    pub fn pending(&self) -> Option<DeadKey> {
+        self.pending
+    }
+
+    /// Solta todas as teclas e descarta o acento (ex.: ao perder o foco).
+    /// O Caps Lock é mantido.
+    pub fn reset(&mut self) {
+        self.left_shift = false;
+        self.right_shift = false;
+        self.ctrl = false;
+        self.alt = false;
+        self.meta = false;
+        self.pending = None;
+    }
+
+    /// Processa um evento de teclado.
+    pub fn feed(&mut self, event: &KeyEvent) -> Typed {
+        self.feed_scancode(event.scancode, event.pressed)
+    }
+
+    /// Processa um scancode (set 1; o bit 7 é ignorado).
+    pub fn feed_scancode(&mut self, scancode: u8, pressed: bool) -> Typed {
+        let scancode = scancode & 0x7F;
+        let modifier = match scancode {
+            LEFT_SHIFT => Some(&mut self.left_shift),
+            RIGHT_SHIFT => Some(&mut self.right_shift),
+            CTRL => Some(&mut self.ctrl),
+            ALT => Some(&mut self.alt),
+            LEFT_META | RIGHT_META => Some(&mut self.meta),
+            _ => None,
+        };
+        if let Some(held) = modifier {
+            *held = pressed;
+            return Typed::default();
+        }
+        if !pressed {
+            return Typed::default();
+        }
+        if scancode == CAPS_LOCK {
+            self.caps_lock = !self.caps_lock;
+            return Typed::default();
+        }
+
+        let altgr = self.ctrl && self.alt;
+        if self.meta || (!altgr && (self.ctrl || self.alt)) {
+            return Typed::default();
+        }
+
+        let shift = self.left_shift || self.right_shift;
+        let level = (altgr as usize) * 2 + shift as usize;
+        let mut sym = self.keymap.lookup(scancode, level);
+
+        // Caps Lock inverte o Shift só para letras
+        if !altgr && self.caps_lock {
+            if let Sym::Char(c) = sym {
+                if c.is_alphabetic() {
+                    sym = self.keymap.lookup(scancode, level ^ 1);
+                }
+            }
+        }
+
+        match (sym, self.pending.take()) {
+            (Sym::None, pending) => {
+                self.pending = pending;
+                Typed::default()
+            }
+            (Sym::Dead(dead), None) => {
+                self.pending = Some(dead);
+                Typed::default()
+            }
+            // Mesma tecla morta duas vezes: o acento sozinho
+            (Sym::Dead(dead), Some(pending)) if dead == pending => Typed::one(dead.spacing()),
+            (Sym::Dead(dead), Some(pending)) => {
+                self.pending = Some(dead);
+                Typed::one(pending.spacing())
+            }
+            (Sym::Char(c), None) => Typed::one(c),
+            (Sym::Char(' '), Some(pending)) => Typed::one(pending.spacing()),
+            (Sym::Char(c), Some(pending)) => match pending.compose(c) {
+                Some(composed) => Typed::one(composed),
+                None => Typed::two(pending.spacing(), c),
+            },
+        }
+    }
+}
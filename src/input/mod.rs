@@ -8,15 +8,31 @@
 //! |--------|-----------|
 //! | [`mouse`] | Funções e tipos de mouse |
 //! | [`keyboard`] | Funções e tipos de teclado |
+//! | [`gamepad`] | Controles (botões, eixos normalizados) |
+//! | [`gestures`] | Reconhecimento de gestos (tap, swipe, pinch...) |
 //! | [`keycodes`] | Códigos de teclas |
+//! | [`keymap`] | Layouts (US, ABNT2, DE) e tradução para Unicode |
+//! | [`modifiers`] | Teclas modificadoras (Shift, Ctrl, Alt...) |
+//! | [`state`] | Teclas seguras, modificadores e auto-repetição |
+//! | [`touch`] | Leitura de toques |
+//! | [`osk`] | Teclado virtual do shell |
+//! | [`record`] | Gravação e reprodução de input (testes de UI) |
 //!
 //! ## Re-exports de gfx_types
 //!
 //! Tipos de input são re-exportados de `gfx_types::input`.
 
+pub mod gamepad;
+pub mod gestures;
 pub mod keyboard;
 pub mod keycodes;
+pub mod keymap;
+pub mod modifiers;
 pub mod mouse;
+pub mod osk;
+pub mod record;
+pub mod state;
+pub mod touch;
 
 // =============================================================================
 // RE-EXPORTS DE GFX_TYPES
@@ -30,6 +46,15 @@ pub use gfx_types::input::{
 // EXPORTS DO MÓDULO
 // =============================================================================
 
+pub use gamepad::{
+    poll_gamepads, read_gamepad, GamepadAxis, GamepadButton, GamepadState, MAX_GAMEPADS,
+};
+pub use gestures::{Gesture, GestureConfig, GestureDetector};
 pub use keyboard::{poll_keyboard, read_key, KeyEvent};
 pub use keycodes::KeyCode;
+pub use keymap::{KeyTranslator, Keymap};
+pub use modifiers::Modifiers;
 pub use mouse::{poll_mouse, MouseButton, MouseState};
+pub use record::{Player, Recorder};
+pub use state::{KeyboardState, RepeatRate};
+pub use touch::{poll_touch, read_touch, RawTouchPoint};
@@ -0,0 +1,95 @@
+//! # Modifiers
+//!
+//! Teclas modificadoras ativas num evento de tecla.
+//!
+//! É o `param2` dos eventos `KEY_DOWN`/`KEY_UP` enviados pelo compositor.
+
+// =============================================================================
+// MODIFIERS
+// =============================================================================
+
+/// Conjunto de modificadores (bits de `InputEvent::param2` em eventos de
+/// tecla).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers(pub u32);
+
+impl Modifiers {
+    /// Nenhum modificador.
+    pub const NONE: Self = Self(0);
+    pub const SHIFT: Self = Self(1 << 0);
+    pub const CTRL: Self = Self(1 << 1);
+    pub const ALT: Self = Self(1 << 2);
+    /// Tecla Super/Windows.
+    pub const META: Self = Self(1 << 3);
+    /// AltGr (caracteres de terceiro nível).
+    pub const ALT_GR: Self = Self(1 << 4);
+    /// Caps Lock travado.
+    pub const CAPS_LOCK: Self = Self(1 << 5);
+    /// Num Lock travado.
+    pub const NUM_LOCK: Self = Self(1 << 6);
+
+    /// Cria a partir do valor raw.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Valor raw.
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Verifica se todos os modificadores de `other` estão ativos.
+    #[inline]
+    pub const fn has(&self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Combina modificadores.
+    #[inline]
+    pub const fn with(&self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Remove modificadores.
+    #[inline]
+    pub const fn without(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Nenhum modificador ativo?
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Maiúsculas efetivas (Shift xor Caps Lock), para letras.
+    #[inline]
+    pub const fn is_uppercase(&self) -> bool {
+        self.has(Self::SHIFT) != self.has(Self::CAPS_LOCK)
+    }
+}
+
+impl core::ops::BitOr for Modifiers {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitAnd for Modifiers {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for Modifiers {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
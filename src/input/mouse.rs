@@ -2,8 +2,8 @@
 //!
 //! Funções e tipos para entrada de mouse.
 
-use crate::syscall::SYS_MOUSE_READ;
-use crate::syscall::{check_error, syscall1, SysResult};
+use crate::syscall::safe::sys_mouse_read;
+use crate::syscall::SysResult;
 
 use gfx_types::geometry::Point;
 
@@ -101,8 +101,5 @@ impl MouseState {
 
 /// Lê estado atual do mouse.
 pub fn poll_mouse() -> SysResult<MouseState> {
-    let mut state = MouseState::default();
-    let ret = syscall1(SYS_MOUSE_READ, &mut state as *mut _ as usize);
-    check_error(ret)?;
-    Ok(state)
+    sys_mouse_read()
 }
@@ -0,0 +1,122 @@
+//! # OSK Client
+//!
+//! Exibe e esconde o teclado virtual e recebe suas mudanças de área.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::osk::{self, OskEvent};
+//!
+//! // Campo de texto ganhou foco
+//! osk::request_show(field_rect_on_screen)?;
+//!
+//! let events = osk::events()?;
+//! while let Some(event) = events.poll() {
+//!     if let OskEvent::Shown(area) = event {
+//!         scroll_into_view_above(area.y);
+//!     }
+//! }
+//!
+//! // Campo perdeu foco
+//! osk::hide()?;
+//! ```
+
+use gfx_types::geometry::Rect;
+
+use crate::ipc::{as_bytes, as_bytes_mut, Port};
+use crate::syscall::SysResult;
+
+use super::protocol::*;
+
+// =============================================================================
+// EXIBIÇÃO
+// =============================================================================
+
+/// Pede o teclado virtual para um campo de texto.
+///
+/// `anchor` é a área do campo em coordenadas de tela. Em dispositivos sem
+/// tela de toque o shell ignora o pedido.
+pub fn request_show(anchor: Rect) -> SysResult<()> {
+    let req = OskShowRequest {
+        op: opcodes::OSK_SHOW,
+        anchor_x: anchor.x,
+        anchor_y: anchor.y,
+        anchor_width: anchor.width,
+        anchor_height: anchor.height,
+    };
+    Port::connect(IME_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+/// Esconde o teclado virtual.
+pub fn hide() -> SysResult<()> {
+    let req = OskHideRequest {
+        op: opcodes::OSK_HIDE,
+    };
+    Port::connect(IME_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Mudança de estado do teclado virtual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OskEvent {
+    /// Teclado visível, ocupando a área dada (coordenadas de tela).
+    Shown(Rect),
+    /// Teclado escondido.
+    Hidden,
+}
+
+/// Receptor de eventos do teclado virtual.
+pub struct OskListener {
+    port: Port,
+}
+
+/// Registra um receptor de eventos do teclado virtual.
+pub fn events() -> SysResult<OskListener> {
+    let (port, name) = Port::create_unique("osk.ev.", 4)?;
+    let req = OskSubscribeRequest {
+        op: opcodes::OSK_SUBSCRIBE,
+        listener_port: name,
+    };
+    Port::connect(IME_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(OskListener { port })
+}
+
+impl OskListener {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<OskEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<OskEvent> {
+        loop {
+            let mut msg = ImeMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<OskEventMessage>() {
+                return None;
+            }
+            let ev = unsafe { msg.osk };
+            match ev.op {
+                opcodes::EVENT_OSK_SHOWN => {
+                    let area = Rect::new(ev.x, ev.y, ev.width, ev.height);
+                    return Some(OskEvent::Shown(area));
+                }
+                opcodes::EVENT_OSK_HIDDEN => return Some(OskEvent::Hidden),
+                // Mensagem desconhecida: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,22 @@
+//! # Teclado Virtual (OSK)
+//!
+//! Integração com o teclado virtual do shell, coordenada pelo servidor de
+//! IME: widgets de texto pedem o teclado ao ganhar foco em dispositivos de
+//! toque e o escondem ao perder o foco.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do servidor de IME |
+//! | [`client`] | Exibição, ocultação e eventos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{events, hide, request_show, OskEvent, OskListener};
+pub use protocol::IME_PORT;
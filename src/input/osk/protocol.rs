@@ -0,0 +1,94 @@
+//! # Protocolo de IME
+//!
+//! Subconjunto do protocolo do servidor de métodos de entrada (IME) usado
+//! pelo teclado virtual do shell.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do servidor de IME.
+pub const IME_PORT: &str = "ime.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 64;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const OSK_SHOW: u32 = 0x40;
+    pub const OSK_HIDE: u32 = 0x41;
+    pub const OSK_SUBSCRIBE: u32 = 0x42;
+
+    // Server -> Client
+    pub const EVENT_OSK_SHOWN: u32 = 0x50;
+    pub const EVENT_OSK_HIDDEN: u32 = 0x51;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Pede o teclado virtual para o campo em `anchor` (coordenadas de tela).
+///
+/// O shell posiciona o teclado sem cobrir o campo, se possível. As teclas
+/// chegam à janela com foco como eventos de input comuns.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OskShowRequest {
+    pub op: u32,
+    pub anchor_x: i32,
+    pub anchor_y: i32,
+    pub anchor_width: u32,
+    pub anchor_height: u32,
+}
+
+/// Esconde o teclado virtual.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OskHideRequest {
+    pub op: u32,
+}
+
+/// Registro para eventos do teclado virtual.
+///
+/// O servidor descarta o registro quando a porta deixa de existir.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OskSubscribeRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// EVENTOS (Server -> Client)
+// =============================================================================
+
+/// Teclado visível (`EVENT_OSK_SHOWN`) ou escondido (`EVENT_OSK_HIDDEN`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OskEventMessage {
+    pub op: u32,
+    /// Área ocupada pelo teclado (coordenadas de tela; zero se escondido).
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// =============================================================================
+// MESSAGE UNION
+// =============================================================================
+
+/// União das mensagens recebidas pelo cliente.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union ImeMessage {
+    pub header: u32,
+    pub osk: OskEventMessage,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
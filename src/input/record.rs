@@ -0,0 +1,228 @@
+//! # Input Recording
+//!
+//! Gravação de sequências de input com tempo relativo e reprodução via
+//! injeção no compositor, para testes de regressão de UI.
+//!
+//! A reprodução usa um opcode de teste do compositor, aceito apenas com o
+//! compositor em modo de teste.
+//!
+//! ## Formato do arquivo
+//!
+//! Little-endian: `RECORDING_MAGIC`, versão (`u32`) e número de eventos
+//! (`u32`), seguidos de um registro por evento:
+//! `tempo_us (u64), event_type (u32), param1 (u32), param2 (u32)`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::record::{Player, Recorder};
+//!
+//! // Gravação (durante uso manual)
+//! let mut recorder = Recorder::new()?;
+//! for event in window.poll_events() {
+//!     recorder.record(&event)?;
+//!     // ... tratar evento normalmente
+//! }
+//! recorder.save("/tmp/login.rec")?;
+//!
+//! // Reprodução (no teste)
+//! Player::load("/tmp/login.rec")?.play(&window)?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::event::{Event, InputEvent};
+use crate::fs::File;
+use crate::syscall::{SysError, SysResult};
+use crate::time::{sleep_until, Instant};
+use crate::window::{opcodes, Window};
+
+/// Assinatura do arquivo de gravação.
+pub const RECORDING_MAGIC: [u8; 8] = *b"RDINREC\0";
+
+/// Versão do formato.
+pub const RECORDING_VERSION: u32 = 1;
+
+/// Tamanho do cabeçalho.
+const HEADER_SIZE: usize = 16;
+
+/// Tamanho de cada registro.
+const RECORD_SIZE: usize = 20;
+
+// =============================================================================
+// EVENTO GRAVADO
+// =============================================================================
+
+/// Evento com o instante relativo ao início da gravação.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordedEvent {
+    pub at: Duration,
+    pub event: InputEvent,
+}
+
+// =============================================================================
+// RECORDER
+// =============================================================================
+
+/// Grava eventos de input com tempo relativo.
+pub struct Recorder {
+    start: Instant,
+    events: Vec<RecordedEvent>,
+}
+
+impl Recorder {
+    /// Inicia uma gravação (o tempo conta a partir de agora).
+    pub fn new() -> SysResult<Self> {
+        Ok(Self {
+            start: Instant::now()?,
+            events: Vec::new(),
+        })
+    }
+
+    /// Grava um evento de janela (apenas `Event::Input`; outros são ignorados).
+    pub fn record(&mut self, event: &Event) -> SysResult<()> {
+        if let Event::Input(input) = event {
+            self.record_input(input)?;
+        }
+        Ok(())
+    }
+
+    /// Grava um evento de input.
+    pub fn record_input(&mut self, event: &InputEvent) -> SysResult<()> {
+        let at = self.start.elapsed()?;
+        self.events.push(RecordedEvent { at, event: *event });
+        Ok(())
+    }
+
+    /// Eventos gravados.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Número de eventos gravados.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Nenhum evento gravado?
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Serializa a gravação.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_SIZE + self.events.len() * RECORD_SIZE);
+        out.extend_from_slice(&RECORDING_MAGIC);
+        out.extend_from_slice(&RECORDING_VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.events.len() as u32).to_le_bytes());
+        for rec in &self.events {
+            let micros = rec.at.as_micros().min(u64::MAX as u128) as u64;
+            out.extend_from_slice(&micros.to_le_bytes());
+            out.extend_from_slice(&rec.event.event_type.to_le_bytes());
+            out.extend_from_slice(&rec.event.param1.to_le_bytes());
+            out.extend_from_slice(&rec.event.param2.to_le_bytes());
+        }
+        out
+    }
+
+    /// Salva a gravação em `path`.
+    pub fn save(&self, path: &str) -> SysResult<()> {
+        File::create(path)?.write_all(&self.to_bytes())
+    }
+}
+
+// =============================================================================
+// PLAYER
+// =============================================================================
+
+/// Reproduz uma gravação injetando os eventos em uma janela.
+pub struct Player {
+    events: Vec<RecordedEvent>,
+}
+
+impl Player {
+    /// Carrega uma gravação de `path`.
+    pub fn load(path: &str) -> SysResult<Self> {
+        let file = File::open(path)?;
+        let size = usize::try_from(file.size()?).map_err(|_| SysError::OutOfMemory)?;
+        let mut data = alloc::vec![0u8; size];
+        file.read_exact(&mut data)?;
+        Self::from_bytes(&data)
+    }
+
+    /// Interpreta uma gravação já em memória.
+    pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+        if data.len() < HEADER_SIZE || data[..8] != RECORDING_MAGIC {
+            return Err(SysError::InvalidArgument);
+        }
+        if read_u32(data, 8) != RECORDING_VERSION {
+            return Err(SysError::NotSupported);
+        }
+        let count = read_u32(data, 12) as usize;
+        let body = &data[HEADER_SIZE..];
+        if body.len() / RECORD_SIZE < count {
+            return Err(SysError::EndOfFile);
+        }
+
+        let mut events = Vec::with_capacity(count);
+        for rec in body.chunks_exact(RECORD_SIZE).take(count) {
+            let micros = u64::from_le_bytes(rec[..8].try_into().unwrap());
+            events.push(RecordedEvent {
+                at: Duration::from_micros(micros),
+                event: InputEvent {
+                    op: opcodes::EVENT_INPUT,
+                    event_type: read_u32(rec, 8),
+                    param1: read_u32(rec, 12),
+                    param2: read_u32(rec, 16),
+                },
+            });
+        }
+        Ok(Self { events })
+    }
+
+    /// Eventos da gravação.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Reproduz em tempo real.
+    pub fn play(&self, window: &Window) -> SysResult<()> {
+        self.play_at_speed(window, 1.0)
+    }
+
+    /// Reproduz com os intervalos divididos por `speed` (ex: 2.0 = dobro da
+    /// velocidade). A ordem dos eventos é sempre preservada.
+    ///
+    /// Retorna `InvalidArgument`, sem injetar nenhum evento, se algum
+    /// instante reescalado não couber num [`Instant`] (ex: `speed` muito
+    /// pequeno).
+    pub fn play_at_speed(&self, window: &Window, speed: f32) -> SysResult<()> {
+        if speed.is_nan() || speed <= 0.0 {
+            return Err(SysError::InvalidArgument);
+        }
+        let start = Instant::now()?;
+        let deadlines = self
+            .events
+            .iter()
+            .map(|rec| {
+                Duration::try_from_secs_f64(rec.at.as_secs_f64() / speed as f64)
+                    .ok()
+                    .and_then(|at| start.checked_add(at))
+                    .ok_or(SysError::InvalidArgument)
+            })
+            .collect::<SysResult<Vec<_>>>()?;
+
+        for (rec, at) in self.events.iter().zip(deadlines) {
+            sleep_until(at)?;
+            window.inject_input(&rec.event)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
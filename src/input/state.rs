@@ -0,0 +1,270 @@
+//! # Keyboard State
+//!
+//! Estado do teclado a partir dos [`KeyEvent`]s: teclas seguras,
+//! modificadores e auto-repetição.
+//!
+//! A repetição é sintetizada aqui, então funciona igual com qualquer fonte
+//! de eventos (`read_key`, eventos de janela, gravações). O tempo é
+//! passado pelo chamador, o que permite testar com `MockClock`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::input::{read_key, KeyCode, KeyboardState, Modifiers};
+//! use redpowder::time::Instant;
+//!
+//! let mut keyboard = KeyboardState::new();
+//! loop {
+//!     let now = Instant::now()?;
+//!     while let Some(event) = read_key()? {
+//!         keyboard.feed(&event, now);
+//!         handle(event);
+//!     }
+//!     while let Some(repeat) = keyboard.poll_repeat(now) {
+//!         handle(repeat);
+//!     }
+//!     if keyboard.modifiers().has(Modifiers::CTRL) && keyboard.is_pressed(KeyCode::S) {
+//!         save();
+//!     }
+//! }
+//! ```
+
+use core::time::Duration;
+
+use crate::time::Instant;
+
+use super::keyboard::KeyEvent;
+use super::keycodes::KeyCode;
+use super::modifiers::Modifiers;
+
+// Scancodes dos modificadores (set 1)
+const LEFT_SHIFT: u8 = 0x2A;
+const RIGHT_SHIFT: u8 = 0x36;
+const CTRL: u8 = 0x1D;
+const ALT: u8 = 0x38;
+const CAPS_LOCK: u8 = 0x3A;
+const NUM_LOCK: u8 = 0x45;
+const LEFT_META: u8 = 0x5B;
+const RIGHT_META: u8 = 0x5C;
+
+/// Atraso máximo (em intervalos) recuperado por `poll_repeat`; além dele
+/// a cadência recomeça (evita rajadas depois de um travamento).
+const MAX_CATCH_UP: u32 = 1;
+
+// =============================================================================
+// REPEAT RATE
+// =============================================================================
+
+/// Configuração da auto-repetição.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatRate {
+    /// Espera entre o pressionamento e a primeira repetição.
+    pub delay: Duration,
+    /// Intervalo entre repetições.
+    pub interval: Duration,
+}
+
+impl RepeatRate {
+    /// 500 ms de espera, 30 repetições por segundo.
+    pub const DEFAULT: Self = Self {
+        delay: Duration::from_millis(500),
+        interval: Duration::from_millis(33),
+    };
+
+    /// Taxa em repetições por segundo.
+    pub fn from_rate(delay: Duration, per_second: u32) -> Self {
+        Self {
+            delay,
+            interval: Duration::from_secs(1) / per_second.max(1),
+        }
+    }
+}
+
+impl Default for RepeatRate {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// =============================================================================
+// KEYBOARD STATE
+// =============================================================================
+
+/// Teclas seguras, modificadores e repetição.
+#[derive(Debug, Clone)]
+pub struct KeyboardState {
+    /// Bit por scancode (0..128).
+    pressed: [u64; 2],
+    caps_lock: bool,
+    num_lock: bool,
+    repeat: Option<RepeatRate>,
+    /// Tecla repetindo e instante da próxima repetição.
+    repeating: Option<(u8, Instant)>,
+}
+
+impl Default for KeyboardState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyboardState {
+    /// Nenhuma tecla segura, repetição com [`RepeatRate::DEFAULT`].
+    pub const fn new() -> Self {
+        Self {
+            pressed: [0; 2],
+            caps_lock: false,
+            num_lock: false,
+            repeat: Some(RepeatRate::DEFAULT),
+            repeating: None,
+        }
+    }
+
+    /// Troca a repetição; `None` desliga.
+    pub fn set_repeat(&mut self, repeat: Option<RepeatRate>) {
+        self.repeat = repeat;
+        self.repeating = None;
+    }
+
+    /// Repetição configurada.
+    pub fn repeat(&self) -> Option<RepeatRate> {
+        self.repeat
+    }
+
+    // =========================================================================
+    // EVENTOS
+    // =========================================================================
+
+    /// Registra um evento recebido em `now`.
+    pub fn feed(&mut self, event: &KeyEvent, now: Instant) {
+        let scancode = event.scancode & 0x7F;
+        let (word, bit) = ((scancode / 64) as usize, 1u64 << (scancode % 64));
+        let was_pressed = self.pressed[word] & bit != 0;
+
+        if event.pressed {
+            self.pressed[word] |= bit;
+        } else {
+            self.pressed[word] &= !bit;
+        }
+
+        if event.pressed && !was_pressed {
+            match scancode {
+                CAPS_LOCK => self.caps_lock = !self.caps_lock,
+                NUM_LOCK => self.num_lock = !self.num_lock,
+                _ => {}
+            }
+        }
+
+        if is_modifier(scancode) {
+            return;
+        }
+        if event.pressed {
+            // Repetição do teclado/kernel não reinicia a espera
+            if !was_pressed {
+                self.repeating = self.repeat.map(|rate| (scancode, now + rate.delay));
+            }
+        } else if matches!(self.repeating, Some((key, _)) if key == scancode) {
+            self.repeating = None;
+        }
+    }
+
+    /// Próxima repetição devida até `now`, como um `KeyEvent` de
+    /// pressionamento.
+    pub fn poll_repeat(&mut self, now: Instant) -> Option<KeyEvent> {
+        let rate = self.repeat?;
+        let (scancode, next) = self.repeating?;
+        if now < next {
+            return None;
+        }
+        // Atrasado demais: retoma a cadência a partir de agora
+        let behind = now.saturating_duration_since(next);
+        let next = if behind > rate.interval * MAX_CATCH_UP {
+            now + rate.interval
+        } else {
+            next + rate.interval
+        };
+        self.repeating = Some((scancode, next));
+        Some(KeyEvent {
+            scancode,
+            pressed: true,
+            ..KeyEvent::default()
+        })
+    }
+
+    /// Instante da próxima repetição (para o timeout do loop de eventos).
+    pub fn next_repeat(&self) -> Option<Instant> {
+        self.repeat?;
+        self.repeating.map(|(_, next)| next)
+    }
+
+    /// Solta todas as teclas (ex.: ao perder o foco). Caps/Num Lock são
+    /// mantidos.
+    pub fn release_all(&mut self) {
+        self.pressed = [0; 2];
+        self.repeating = None;
+    }
+
+    // =========================================================================
+    // CONSULTAS
+    // =========================================================================
+
+    /// `key` está segura?
+    pub fn is_pressed(&self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::None => false,
+            KeyCode::Shift => {
+                self.is_scancode_pressed(LEFT_SHIFT) || self.is_scancode_pressed(RIGHT_SHIFT)
+            }
+            _ => self
+                .pressed_scancodes()
+                .any(|scancode| KeyCode::from_scancode(scancode) == key),
+        }
+    }
+
+    /// Scancode (set 1) está seguro?
+    pub fn is_scancode_pressed(&self, scancode: u8) -> bool {
+        let scancode = scancode & 0x7F;
+        self.pressed[(scancode / 64) as usize] & (1 << (scancode % 64)) != 0
+    }
+
+    /// Scancodes seguros, em ordem crescente.
+    pub fn pressed_scancodes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..128u8).filter(move |&scancode| self.is_scancode_pressed(scancode))
+    }
+
+    /// Alguma tecla segura?
+    pub fn any_pressed(&self) -> bool {
+        self.pressed != [0; 2]
+    }
+
+    /// Modificadores ativos (AltGr = Ctrl+Alt).
+    pub fn modifiers(&self) -> Modifiers {
+        let ctrl = self.is_scancode_pressed(CTRL);
+        let alt = self.is_scancode_pressed(ALT);
+        let mut modifiers = Modifiers::NONE;
+        for (on, flag) in [
+            (self.is_pressed(KeyCode::Shift), Modifiers::SHIFT),
+            (ctrl, Modifiers::CTRL),
+            (alt, Modifiers::ALT),
+            (
+                self.is_scancode_pressed(LEFT_META) || self.is_scancode_pressed(RIGHT_META),
+                Modifiers::META,
+            ),
+            (ctrl && alt, Modifiers::ALT_GR),
+            (self.caps_lock, Modifiers::CAPS_LOCK),
+            (self.num_lock, Modifiers::NUM_LOCK),
+        ] {
+            if on {
+                modifiers |= flag;
+            }
+        }
+        modifiers
+    }
+}
+
+fn is_modifier(scancode: u8) -> bool {
+    matches!(
+        scancode,
+        LEFT_SHIFT | RIGHT_SHIFT | CTRL | ALT | CAPS_LOCK | NUM_LOCK | LEFT_META | RIGHT_META
+    )
+}
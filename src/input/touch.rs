@@ -0,0 +1,85 @@
+//! # Touch Input
+//!
+//! Leitura de toques (tablets, telas sensíveis ao toque).
+//!
+//! O kernel entrega [`RawTouchPoint`], o formato de fio também usado pelo
+//! compositor em `Event::Touch`; [`RawTouchPoint::to_point`] converte para o
+//! [`TouchPoint`] de `gfx_types`, descartando fases inválidas.
+
+use gfx_types::geometry::PointF;
+use gfx_types::input::{TouchId, TouchPhase, TouchPoint};
+
+use crate::syscall::safe::sys_touch_read;
+use crate::syscall::SysResult;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Ponto de toque no formato do kernel/protocolo.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RawTouchPoint {
+    /// ID do toque (estável do `Begin` ao `End`).
+    pub id: u32,
+    /// Fase (`TouchPhase::from_u8`).
+    pub phase: u8,
+    pub _pad: [u8; 3],
+    pub x: f32,
+    pub y: f32,
+    /// Pressão (0.0 - 1.0, 0 se não suportado).
+    pub pressure: f32,
+    /// Raio do toque em pixels (0 se não suportado).
+    pub radius: f32,
+}
+
+impl RawTouchPoint {
+    /// Converte para [`TouchPoint`]; `None` se a fase for inválida.
+    pub fn to_point(&self) -> Option<TouchPoint> {
+        let phase = TouchPhase::from_u8(self.phase)?;
+        Some(
+            TouchPoint::new(TouchId(self.id), phase, PointF::new(self.x, self.y))
+                .with_pressure(self.pressure)
+                .with_radius(self.radius),
+        )
+    }
+
+    /// Converte de [`TouchPoint`].
+    pub fn from_point(point: &TouchPoint) -> Self {
+        Self {
+            id: point.id.0,
+            phase: point.phase as u8,
+            _pad: [0; 3],
+            x: point.position.x,
+            y: point.position.y,
+            pressure: point.pressure,
+            radius: point.radius,
+        }
+    }
+}
+
+// =============================================================================
+// FUNÇÕES
+// =============================================================================
+
+/// Lê pontos de toque pendentes (coordenadas de tela).
+///
+/// Retorna o número de pontos lidos.
+pub fn poll_touch(buffer: &mut [RawTouchPoint]) -> SysResult<usize> {
+    if buffer.is_empty() {
+        return Ok(0);
+    }
+    sys_touch_read(buffer)
+}
+
+/// Lê um único ponto de toque (se disponível).
+///
+/// Pontos com fase inválida são descartados.
+pub fn read_touch() -> SysResult<Option<TouchPoint>> {
+    let mut raw = [RawTouchPoint::default()];
+    if sys_touch_read(&mut raw)? > 0 {
+        Ok(raw[0].to_point())
+    } else {
+        Ok(None)
+    }
+}
@@ -1,5 +1,9 @@
 //! # IO Primitives
 
 mod io;
+mod stdio;
+mod traits;
 
 pub use io::*;
+pub use stdio::{stderr, stdout, Stderr, Stdout, STDERR_TAG, STDIN_TAG, STDOUT_TAG};
+pub use traits::{Read, Write};
@@ -0,0 +1,135 @@
+//! # Streams Padrão
+//!
+//! Saída padrão e saída de erro do processo.
+//!
+//! O pai redireciona os streams passando handles com as tags [`STDOUT_TAG`] e
+//! [`STDERR_TAG`] (ver [`crate::process::Command::stdout`]). Sem handle, o
+//! stream escreve na console.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::{eprintln, println};
+//!
+//! println!("resultado: {}", 42);      // stdout
+//! eprintln!("aviso: cache ausente");  // stderr
+//! ```
+
+use core::fmt;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::io::Handle;
+use crate::process::HandleKind;
+use crate::syscall::safe::sys_write;
+use crate::syscall::SysResult;
+
+// =============================================================================
+// TAGS
+// =============================================================================
+
+/// Tag do handle de entrada padrão.
+pub const STDIN_TAG: u32 = 0;
+/// Tag do handle de saída padrão.
+pub const STDOUT_TAG: u32 = 1;
+/// Tag do handle de saída de erro.
+pub const STDERR_TAG: u32 = 2;
+
+/// Handle ainda não procurado nos startup handles.
+const UNRESOLVED: u32 = u32::MAX - 1;
+/// Sem handle: escrever na console.
+const CONSOLE: u32 = u32::MAX;
+
+static STDOUT_HANDLE: AtomicU32 = AtomicU32::new(UNRESOLVED);
+static STDERR_HANDLE: AtomicU32 = AtomicU32::new(UNRESOLVED);
+
+// =============================================================================
+// STREAMS
+// =============================================================================
+
+/// Saída padrão.
+#[derive(Debug, Clone, Copy)]
+pub struct Stdout;
+
+/// Saída de erro.
+#[derive(Debug, Clone, Copy)]
+pub struct Stderr;
+
+/// Retorna a saída padrão.
+pub fn stdout() -> Stdout {
+    Stdout
+}
+
+/// Retorna a saída de erro.
+pub fn stderr() -> Stderr {
+    Stderr
+}
+
+impl Stdout {
+    /// Handle redirecionado pelo pai (None se console).
+    pub fn handle(&self) -> Option<Handle> {
+        redirected(&STDOUT_HANDLE, STDOUT_TAG)
+    }
+}
+
+impl Stderr {
+    /// Handle redirecionado pelo pai (None se console).
+    pub fn handle(&self) -> Option<Handle> {
+        redirected(&STDERR_HANDLE, STDERR_TAG)
+    }
+}
+
+impl crate::io::Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        write_to(self.handle(), buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        Ok(())
+    }
+}
+
+impl crate::io::Write for Stderr {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        write_to(self.handle(), buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        Ok(())
+    }
+}
+
+impl fmt::Write for Stdout {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        crate::io::Write::write_all(self, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+impl fmt::Write for Stderr {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        crate::io::Write::write_all(self, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Procura (uma vez) o handle do stream nos startup handles.
+fn redirected(slot: &AtomicU32, tag: u32) -> Option<Handle> {
+    let mut raw = slot.load(Ordering::Relaxed);
+    if raw == UNRESOLVED {
+        raw = crate::runtime::startup_handle(tag)
+            .filter(|h| h.kind() == HandleKind::Generic)
+            .and_then(|h| h.handle())
+            .map_or(CONSOLE, |h| h.raw());
+        slot.store(raw, Ordering::Relaxed);
+    }
+    (raw != CONSOLE).then(|| Handle::from_raw(raw))
+}
+
+fn write_to(handle: Option<Handle>, buf: &[u8]) -> SysResult<usize> {
+    match handle {
+        Some(handle) => sys_write(handle, buf),
+        None => crate::console::write_bytes(buf),
+    }
+}
@@ -0,0 +1,82 @@
+//! # Stream Traits
+//!
+//! Traits de leitura/escrita de bytes (arquivos, buffers, streams).
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::syscall::{SysError, SysResult};
+
+/// Origem de bytes.
+pub trait Read {
+    /// Lê dados para o buffer, retornando quantos bytes foram lidos (0 = EOF).
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize>;
+
+    /// Lê exatamente `buf.len()` bytes.
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> SysResult<()> {
+        while !buf.is_empty() {
+            let n = self.read(buf)?;
+            if n == 0 {
+                return Err(SysError::EndOfFile);
+            }
+            buf = &mut buf[n..];
+        }
+        Ok(())
+    }
+}
+
+/// Destino de bytes.
+pub trait Write {
+    /// Escreve dados do buffer, retornando quantos bytes foram escritos.
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize>;
+
+    /// Escreve todo o buffer.
+    fn write_all(&mut self, mut buf: &[u8]) -> SysResult<()> {
+        while !buf.is_empty() {
+            let n = self.write(buf)?;
+            if n == 0 {
+                return Err(SysError::IoError);
+            }
+            buf = &buf[n..];
+        }
+        Ok(())
+    }
+
+    /// Força envio de dados pendentes.
+    fn flush(&mut self) -> SysResult<()> {
+        Ok(())
+    }
+}
+
+impl<R: Read + ?Sized> Read for &mut R {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        (**self).read(buf)
+    }
+}
+
+impl<W: Write + ?Sized> Write for &mut W {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        (**self).write(buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        (**self).flush()
+    }
+}
+
+impl Read for &[u8] {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        let n = buf.len().min(self.len());
+        buf[..n].copy_from_slice(&self[..n]);
+        *self = &self[n..];
+        Ok(n)
+    }
+}
+
+impl Write for Vec<u8> {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+}
@@ -3,10 +3,11 @@
 //! Comunicação entre processos via portas e memória compartilhada.
 
 use crate::io::Handle;
-use crate::syscall::{
-    check_error, syscall1, syscall2, syscall4, SysResult, SYS_CREATE_PORT, SYS_HANDLE_DUP,
-    SYS_PORT_CONNECT, SYS_RECV_MSG, SYS_SEND_MSG, SYS_SHM_ATTACH, SYS_SHM_CREATE, SYS_SHM_GET_SIZE,
+use crate::syscall::safe::{
+    sys_create_port, sys_handle_close, sys_handle_dup, sys_port_connect, sys_recv_msg,
+    sys_send_msg, sys_shm_attach, sys_shm_create, sys_shm_get_size,
 };
+use crate::syscall::{SysError, SysResult};
 
 /// Flags de mensagem
 pub mod flags {
@@ -22,34 +23,72 @@ pub struct Port {
 impl Port {
     /// Cria nova porta nomeada
     pub fn create(name: &str, capacity: usize) -> SysResult<Self> {
-        let ret = syscall4(
-            SYS_CREATE_PORT,
-            name.as_ptr() as usize,
-            name.len(),
-            capacity,
-            0,
-        );
-        let handle = Handle::from_raw(check_error(ret)? as u32);
+        let handle = sys_create_port(name, capacity)?;
         Ok(Self { handle })
     }
 
+    /// Cria porta com nome único `<prefix><n>`.
+    ///
+    /// Retorna a porta e o nome (preenchido com zeros) para ser enviado como
+    /// porta de resposta em requests de serviços. Só tenta outro `<n>` se o
+    /// nome já existir; qualquer outro erro é retornado imediatamente.
+    pub fn create_unique(prefix: &str, capacity: usize) -> SysResult<(Self, [u8; 32])> {
+        let prefix = prefix.as_bytes();
+        if prefix.len() > 21 {
+            return Err(SysError::InvalidArgument);
+        }
+
+        for seed in 0..=100u32 {
+            let mut name = [0u8; 32];
+            name[..prefix.len()].copy_from_slice(prefix);
+
+            // Dígitos de trás para frente
+            let mut digits = [0u8; 10];
+            let mut count = 0;
+            let mut n = seed;
+            loop {
+                digits[count] = b'0' + (n % 10) as u8;
+                count += 1;
+                n /= 10;
+                if n == 0 {
+                    break;
+                }
+            }
+            for i in 0..count {
+                name[prefix.len() + i] = digits[count - 1 - i];
+            }
+
+            let len = prefix.len() + count;
+            let name_str = core::str::from_utf8(&name[..len]).unwrap_or("");
+            match Self::create(name_str, capacity) {
+                Ok(port) => return Ok((port, name)),
+                // Nome em uso: tentar o próximo
+                Err(SysError::AlreadyExists) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(SysError::AlreadyExists)
+    }
+
     /// Conecta a uma porta nomeada
     pub fn connect(name: &str) -> SysResult<Self> {
-        let ret = syscall2(SYS_PORT_CONNECT, name.as_ptr() as usize, name.len());
-        let handle = Handle::from_raw(check_error(ret)? as u32);
+        let handle = sys_port_connect(name)?;
         Ok(Self { handle })
     }
 
+    /// Cria porta a partir de um handle raw existente
+    ///
+    /// # Safety
+    /// O chamador deve garantir que o handle é válido e representa uma porta.
+    pub unsafe fn from_raw_handle(handle: u32) -> Self {
+        Self {
+            handle: Handle::from_raw(handle),
+        }
+    }
+
     /// Envia mensagem
     pub fn send(&self, data: &[u8], flags: u32) -> SysResult<usize> {
-        let ret = syscall4(
-            SYS_SEND_MSG,
-            self.handle.raw() as usize,
-            data.as_ptr() as usize,
-            data.len(),
-            flags as usize,
-        );
-        check_error(ret)
+        sys_send_msg(self.handle, data, flags)
     }
 
     /// Recebe mensagem
@@ -59,15 +98,7 @@ impl Port {
         let poll_interval = 10;
 
         loop {
-            let ret = syscall4(
-                SYS_RECV_MSG,
-                self.handle.raw() as usize,
-                buf.as_mut_ptr() as usize,
-                buf.len(),
-                0, // Kernel ignora timeout param por enquanto
-            );
-
-            match check_error(ret) {
+            match sys_recv_msg(self.handle, buf) {
                 Ok(len) => {
                     if len > 0 {
                         return Ok(len);
@@ -100,12 +131,7 @@ impl Port {
 
 impl Clone for Port {
     fn clone(&self) -> Self {
-        let ret = syscall2(
-            SYS_HANDLE_DUP,
-            self.handle.raw() as usize,
-            0xFFFFFFFFFFFFFFFF,
-        );
-        let new_handle = Handle::from_raw(check_error(ret).unwrap_or(0) as u32);
+        let new_handle = sys_handle_dup(self.handle).unwrap_or(Handle::from_raw(0));
         Self { handle: new_handle }
     }
 }
@@ -113,8 +139,7 @@ impl Clone for Port {
 impl Drop for Port {
     fn drop(&mut self) {
         if self.handle.is_valid() {
-            use crate::syscall::{syscall1, SYS_HANDLE_CLOSE};
-            let _ = syscall1(SYS_HANDLE_CLOSE, self.handle.raw() as usize);
+            let _ = sys_handle_close(self.handle);
         }
     }
 }
@@ -137,12 +162,10 @@ pub struct SharedMemory {
 impl SharedMemory {
     /// Cria nova região de memória compartilhada
     pub fn create(size: usize) -> SysResult<Self> {
-        let ret = syscall1(SYS_SHM_CREATE, size);
-        let id = ShmId(check_error(ret)? as u64);
+        let id = sys_shm_create(size)?;
 
         // Mapear automaticamente
-        let ret = syscall2(SYS_SHM_ATTACH, id.0 as usize, 0);
-        let addr = check_error(ret)? as *mut u8;
+        let addr = sys_shm_attach(id)?;
 
         Ok(Self { id, addr, size })
     }
@@ -150,12 +173,10 @@ impl SharedMemory {
     /// Abre região existente pelo ID
     pub fn open(id: ShmId) -> SysResult<Self> {
         // Primeiro, obter o tamanho real da região SHM
-        let size_ret = syscall1(SYS_SHM_GET_SIZE, id.0 as usize);
-        let size = check_error(size_ret)?;
+        let size = sys_shm_get_size(id)?;
 
         // Agora mapear a região
-        let ret = syscall2(SYS_SHM_ATTACH, id.0 as usize, 0);
-        let addr = check_error(ret)? as *mut u8;
+        let addr = sys_shm_attach(id)?;
 
         Ok(Self { id, addr, size })
     }
@@ -190,3 +211,102 @@ impl SharedMemory {
         unsafe { core::slice::from_raw_parts_mut(self.addr, self.size) }
     }
 }
+
+// ============================================================================
+// HELPERS DE PROTOCOLO
+// ============================================================================
+
+/// Visão em bytes de uma mensagem `#[repr(C)]`.
+pub(crate) fn as_bytes<T: Copy>(msg: &T) -> &[u8] {
+    unsafe { core::slice::from_raw_parts(msg as *const T as *const u8, core::mem::size_of::<T>()) }
+}
+
+/// Visão mutável em bytes de uma mensagem `#[repr(C)]` (para `recv`).
+pub(crate) fn as_bytes_mut<T: Copy>(msg: &mut T) -> &mut [u8] {
+    unsafe { core::slice::from_raw_parts_mut(msg as *mut T as *mut u8, core::mem::size_of::<T>()) }
+}
+
+/// Copia uma string para um campo de tamanho fixo (truncando, com zeros).
+pub(crate) fn fixed_str<const N: usize>(s: &str) -> [u8; N] {
+    let mut buf = [0u8; N];
+    let len = s.len().min(N);
+    buf[..len].copy_from_slice(&s.as_bytes()[..len]);
+    buf
+}
+
+/// Lê uma string de um campo de tamanho fixo (até o primeiro zero).
+pub(crate) fn str_from_fixed(buf: &[u8]) -> &str {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    match core::str::from_utf8(&buf[..len]) {
+        Ok(s) => s,
+        Err(e) => unsafe { core::str::from_utf8_unchecked(&buf[..e.valid_up_to()]) },
+    }
+}
+
+// ============================================================================
+// RESPOSTAS DE SERVIÇO
+// ============================================================================
+
+/// União de mensagens de um protocolo de serviço (ex.: `AudioMessage`).
+///
+/// Cada protocolo implementa no seu `protocol.rs`.
+///
+/// # Safety
+///
+/// `Self` deve ser `#[repr(C)]`, aceitar qualquer padrão de bytes (inclusive
+/// zeros) e começar pelo opcode `u32`. Se [`error`](Self::error) não for
+/// sobrescrito, a mensagem `ERROR` deve trazer o código `u32` logo após o
+/// opcode e `MIN_LEN` deve ser ao menos 4.
+pub(crate) unsafe trait Message: Copy {
+    /// Opcode de erro do protocolo.
+    const ERROR: u32;
+    /// Menor mensagem válida, em bytes (derivada do `ErrorResponse`).
+    const MIN_LEN: usize;
+
+    /// Opcode da mensagem.
+    fn opcode(&self) -> u32 {
+        let bytes = as_bytes(self);
+        u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    /// Erro carregado por uma mensagem `ERROR`.
+    fn error(&self) -> SysError {
+        let bytes = as_bytes(self);
+        let code = u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        SysError::from_code(code as i32 as isize)
+    }
+}
+
+/// Recebe uma mensagem qualquer do protocolo `M`, com seu tamanho em bytes.
+///
+/// `Timeout` se nada chegar em `timeout_ms`; `ProtocolError` se a mensagem
+/// for menor que `M::MIN_LEN`. O chamador valida o tamanho da variante que
+/// ler (bytes além de `len` são zeros).
+pub(crate) fn recv_message<M: Message>(port: &Port, timeout_ms: u64) -> SysResult<(M, usize)> {
+    // SAFETY: M aceita qualquer padrão de bytes (contrato de Message).
+    let mut msg: M = unsafe { core::mem::zeroed() };
+    match port.recv(as_bytes_mut(&mut msg), timeout_ms)? {
+        0 => Err(SysError::Timeout),
+        len if len < M::MIN_LEN => Err(SysError::ProtocolError),
+        len => Ok((msg, len)),
+    }
+}
+
+/// Recebe a resposta com opcode `expected` e ao menos `min_len` bytes
+/// (o `size_of` da struct de resposta; 0 se ela traz só o opcode).
+///
+/// Uma mensagem `ERROR` vira o erro que ela carrega; qualquer outro opcode,
+/// ou uma resposta truncada, é `ProtocolError`.
+pub(crate) fn recv_reply<M: Message>(
+    port: &Port,
+    expected: u32,
+    min_len: usize,
+    timeout_ms: u64,
+) -> SysResult<M> {
+    let (msg, len) = recv_message::<M>(port, timeout_ms)?;
+    match msg.opcode() {
+        op if op == expected && len >= min_len => Ok(msg),
+        op if op == M::ERROR => Err(msg.error()),
+        _ => Err(SysError::ProtocolError),
+    }
+}
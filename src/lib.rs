@@ -13,18 +13,34 @@
 //! | Módulo | Função |
 //! |--------|--------|
 //! | [`syscall`] | Invocação de syscalls (inline asm) |
-//! | [`console`] | print!, println!, reboot, poweroff |
+//! | [`console`] | print!, println!, eprint!, eprintln!, readline, estilos ANSI, reboot, poweroff |
 //! | [`fs`] | Arquivos e diretórios (File, Dir, stat) |
-//! | [`process`] | Processos (exit, spawn, yield) |
+//! | [`process`] | Processos (exit, spawn, Command, yield) |
+//! | [`runtime`] | Runtime do processo (handles de inicialização, at_exit) |
 //! | [`mem`] | Memória (alloc, free, map) |
 //! | [`ipc`] | IPC (Port, send, recv) |
-//! | [`time`] | Tempo (sleep, clock) |
-//! | [`io`] | Handle, Rights |
+//! | [`task`] | Executor assíncrono (block_on, spawn) |
+//! | [`thread`] | Threads (spawn, Builder com nome, join) |
+//! | [`time`] | Tempo (sleep, Instant, Interval, SystemTime, DateTime) |
+//! | [`i18n`] | Formatação por locale (números, bytes, datas) |
+//! | [`io`] | Handle, Rights, Read/Write, stdout/stderr |
 //! | [`event`] | Eventos e polling |
+//! | `demos` | Runner de testes de integração e apoio aos exemplos (feature `demos`) |
 //! | [`sys`] | sysinfo, debug |
 //! | [`graphics`] | Framebuffer, canvas, desenho |
 //! | [`input`] | Mouse, teclado, touch |
+//! | [`trace`] | Spans e eventos para profiling (buffer compartilhado) |
 //! | [`window`] | Janelas (protocolo Firefly) |
+//! | [`ui`] | Infraestrutura de UI (navegação de foco, diálogos) |
+//! | [`undo`] | Desfazer/refazer (History, Command) |
+//! | [`audio`] | Áudio (streams, mixer, beep, tons) |
+//! | [`camera`] | Captura de câmera (swapchain SHM) |
+//! | [`location`] | Localização (posição atual e atualizações) |
+//! | `log` | Logger do crate `log` para o kernel (feature `log`) |
+//! | [`media`] | Controle de players de mídia (Player, Controller) |
+//! | [`print`](mod@print) | Impressão (impressoras, trabalhos via spooler) |
+//! | [`scan`] | Digitalização (scanners, preview, aquisição) |
+//! | [`net`] | Rede (TCP, sockets locais, Bluetooth, Wi-Fi) |
 //! | [`gfx`] | Re-export completo de `gfx_types` |
 //! | [`math`] | Re-export de `rdsmath` |
 //!
@@ -53,18 +69,36 @@
 // MÓDULOS INTERNOS
 // =============================================================================
 
+pub mod audio;
+pub mod camera;
 pub mod console;
+#[cfg(feature = "demos")]
+pub mod demos;
 pub mod event;
 pub mod fs;
 pub mod graphics;
+pub mod i18n;
 pub mod input;
 pub mod io;
 pub mod ipc;
+pub mod location;
+#[cfg(feature = "log")]
+pub mod log;
+pub mod media;
 pub mod mem;
+pub mod net;
+pub mod print;
 pub mod process;
+pub mod runtime;
+pub mod scan;
 pub mod sys;
 pub mod syscall;
+pub mod task;
+pub mod thread;
 pub mod time;
+pub mod trace;
+pub mod ui;
+pub mod undo;
 pub mod window;
 
 // =============================================================================
@@ -85,6 +119,10 @@ pub mod math {
     pub use rdsmath::*;
 }
 
+/// Macros do facade `log` (feature `log`).
+#[cfg(feature = "log")]
+pub use ::log::{debug, error, info, log, trace, warn};
+
 // =============================================================================
 // PRELUDE
 // =============================================================================
@@ -157,7 +195,7 @@ pub mod prelude {
     };
 
     // Damage
-    pub use gfx_types::damage::DamageRegion;
+    pub use crate::graphics::DamageRegion;
 
     // Input (gfx_types)
     pub use gfx_types::input::{CursorType, GestureType, TouchPoint};
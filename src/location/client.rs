@@ -0,0 +1,177 @@
+//! # Location Client
+//!
+//! Consulta e assinatura de posição.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::location;
+//!
+//! if let Some(pos) = location::current() {
+//!     println!("{:.4}, {:.4} (±{} m)", pos.latitude, pos.longitude, pos.accuracy_m);
+//! }
+//!
+//! let listener = location::subscribe(60_000)?;
+//! while let Some(pos) = listener.wait(1000) {
+//!     // atualizar widget...
+//! }
+//! ```
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, Port};
+use crate::syscall::{SysError, SysResult};
+use crate::time::SystemTime;
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Origem da posição.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSource {
+    Unknown,
+    /// Estimada por redes Wi-Fi próximas.
+    Wifi,
+    /// Configurada pelo usuário.
+    Manual,
+}
+
+/// Posição geográfica (WGS 84).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// Graus (-90 a 90).
+    pub latitude: f64,
+    /// Graus (-180 a 180).
+    pub longitude: f64,
+    /// Raio de precisão (metros).
+    pub accuracy_m: f32,
+    pub source: PositionSource,
+    /// Instante da medição.
+    pub timestamp: SystemTime,
+}
+
+impl Position {
+    fn from_msg(msg: &PositionMessage) -> Option<Self> {
+        if msg.valid == 0 {
+            return None;
+        }
+        Some(Self {
+            latitude: msg.latitude,
+            longitude: msg.longitude,
+            accuracy_m: msg.accuracy_m,
+            source: match msg.source {
+                sources::WIFI => PositionSource::Wifi,
+                sources::MANUAL => PositionSource::Manual,
+                _ => PositionSource::Unknown,
+            },
+            timestamp: SystemTime::from_unix_millis(msg.timestamp_ms),
+        })
+    }
+}
+
+// =============================================================================
+// CONSULTA
+// =============================================================================
+
+/// Posição atual, ou None se desconhecida, sem permissão ou sem serviço.
+pub fn current() -> Option<Position> {
+    query().ok().flatten()
+}
+
+/// Posição atual (`Ok(None)` se desconhecida).
+pub fn query() -> SysResult<Option<Position>> {
+    let (reply_port, reply_name) = Port::create_unique("loc.r.", 4)?;
+    let req = GetPositionRequest {
+        op: opcodes::GET_POSITION,
+        reply_port: reply_name,
+    };
+    Port::connect(LOCATION_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<LocationMessage>(
+        &reply_port,
+        opcodes::POSITION,
+        core::mem::size_of::<PositionMessage>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(Position::from_msg(unsafe { &resp.position }))
+}
+
+/// Fixa a posição manualmente (configurações do sistema).
+pub fn set_manual(latitude: f64, longitude: f64) -> SysResult<()> {
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(SysError::InvalidArgument);
+    }
+    let req = SetManualRequest {
+        op: opcodes::SET_MANUAL,
+        _pad: 0,
+        latitude,
+        longitude,
+    };
+    Port::connect(LOCATION_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+/// Remove a posição manual (volta à estimativa automática).
+pub fn clear_manual() -> SysResult<()> {
+    let req = SimpleRequest {
+        op: opcodes::CLEAR_MANUAL,
+    };
+    Port::connect(LOCATION_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+// =============================================================================
+// ASSINATURA
+// =============================================================================
+
+/// Receptor de atualizações de posição.
+pub struct LocationListener {
+    port: Port,
+}
+
+/// Assina atualizações, no máximo uma a cada `min_interval_ms`.
+pub fn subscribe(min_interval_ms: u32) -> SysResult<LocationListener> {
+    let (port, name) = Port::create_unique("loc.ev.", 8)?;
+    let req = SubscribeRequest {
+        op: opcodes::SUBSCRIBE,
+        min_interval_ms,
+        listener_port: name,
+    };
+    Port::connect(LOCATION_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(LocationListener { port })
+}
+
+impl LocationListener {
+    /// Retorna a próxima atualização pendente, sem bloquear.
+    pub fn poll(&self) -> Option<Position> {
+        self.wait(0)
+    }
+
+    /// Espera uma atualização por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<Position> {
+        loop {
+            let mut msg = LocationMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<PositionMessage>() {
+                return None;
+            }
+            if unsafe { msg.header } == opcodes::POSITION {
+                if let Some(position) = Position::from_msg(unsafe { &msg.position }) {
+                    return Some(position);
+                }
+            }
+            // Posição desconhecida ou mensagem inesperada: continuar
+        }
+    }
+
+    /// Porta de atualizações (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,26 @@
+//! # Localização
+//!
+//! Cliente do serviço de localização (origem: scan de Wi-Fi ou configuração
+//! manual), para sugestão de fuso horário e widgets de clima.
+//!
+//! O acesso é controlado pelo serviço de permissões: apps sem permissão
+//! recebem `SysError::PermissionDenied` (ou `None` em [`current`]).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo de localização |
+//! | [`client`] | Consulta, assinatura e posição manual |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    clear_manual, current, query, set_manual, subscribe, LocationListener, Position, PositionSource,
+};
+pub use protocol::LOCATION_PORT;
@@ -0,0 +1,131 @@
+//! # Protocolo de Localização
+//!
+//! Definições de mensagens do protocolo com o serviço de localização.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de localização.
+pub const LOCATION_PORT: &str = "location.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 128;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const GET_POSITION: u32 = 0x01;
+    pub const SUBSCRIBE: u32 = 0x02;
+    pub const SET_MANUAL: u32 = 0x03;
+    pub const CLEAR_MANUAL: u32 = 0x04;
+
+    // Server -> Client
+    pub const POSITION: u32 = 0x10;
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Origens de posição.
+pub mod sources {
+    pub const UNKNOWN: u32 = 0;
+    pub const WIFI: u32 = 1;
+    pub const MANUAL: u32 = 2;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de posição atual.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GetPositionRequest {
+    pub op: u32,
+    /// Nome da porta onde o servidor deve responder.
+    pub reply_port: [u8; 32],
+}
+
+/// Request para receber atualizações de posição.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    /// Intervalo mínimo entre atualizações (ms).
+    pub min_interval_ms: u32,
+    /// Porta para receber atualizações.
+    pub listener_port: [u8; 32],
+}
+
+/// Request para fixar posição manual.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetManualRequest {
+    pub op: u32,
+    pub _pad: u32,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Request sem parâmetros (ex: `CLEAR_MANUAL`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SimpleRequest {
+    pub op: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Posição (resposta de `GET_POSITION` e atualizações de assinatura).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PositionMessage {
+    pub op: u32,
+    /// 0 se a posição é desconhecida.
+    pub valid: u32,
+    pub source: u32,
+    /// Raio de precisão (metros).
+    pub accuracy_m: f32,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Instante da medição (ms desde a época).
+    pub timestamp_ms: u64,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LocationMessage {
+    pub header: u32,
+    pub get_req: GetPositionRequest,
+    pub subscribe_req: SubscribeRequest,
+    pub manual_req: SetManualRequest,
+    pub position: PositionMessage,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for LocationMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -0,0 +1,110 @@
+//! # Logger
+//!
+//! Implementação de [`log::Log`](::log::Log) que escreve no log do kernel.
+
+use core::fmt::{self, Write};
+
+use ::log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::task::Lock;
+
+// =============================================================================
+// CONFIGURAÇÃO
+// =============================================================================
+
+static LOGGER: KernelLogger = KernelLogger;
+static TAG: Lock<Option<&'static str>> = Lock::new(None);
+
+/// Instala o logger do kernel no facade `log` e liga o log no nível dado,
+/// com a tag `pid <n>`.
+///
+/// Se outro logger já foi instalado, ele continua recebendo os registros;
+/// só o nível é alterado.
+pub fn init(level: LevelFilter) {
+    let _ = ::log::set_logger(&LOGGER);
+    ::log::set_max_level(level);
+}
+
+/// [`init`] com a tag do processo.
+pub fn init_with_tag(tag: &'static str, level: LevelFilter) {
+    TAG.with(|current| *current = Some(tag).filter(|t| !t.is_empty()));
+    init(level);
+}
+
+fn tag() -> Option<&'static str> {
+    TAG.with(|tag| *tag)
+}
+
+// =============================================================================
+// ESCRITA
+// =============================================================================
+
+/// Tamanho máximo de uma linha de log (excedente é truncado).
+pub const MAX_LINE: usize = 256;
+
+/// Logger instalado por [`init`].
+struct KernelLogger;
+
+impl Log for KernelLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= ::log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            write_line(record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Buffer de linha que trunca ao encher.
+struct LineBuffer {
+    buf: [u8; MAX_LINE],
+    len: usize,
+}
+
+impl Write for LineBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Reservar 1 byte para o '\n' final
+        let room = MAX_LINE - 1 - self.len;
+        let mut take = s.len().min(room);
+        while !s.is_char_boundary(take) {
+            take -= 1;
+        }
+        self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+        self.len += take;
+        Ok(())
+    }
+}
+
+fn write_line(level: Level, args: &fmt::Arguments) {
+    let mut line = LineBuffer {
+        buf: [0; MAX_LINE],
+        len: 0,
+    };
+
+    let uptime = crate::time::Instant::now()
+        .map(|now| now.since_boot())
+        .unwrap_or_default();
+    let _ = write!(
+        line,
+        "[{:>5}.{:06}] {:<5} ",
+        uptime.as_secs(),
+        uptime.subsec_micros(),
+        level
+    );
+    let _ = match tag() {
+        Some(tag) => write!(line, "{}: ", tag),
+        None => write!(line, "pid {}: ", crate::process::getpid()),
+    };
+    let _ = line.write_fmt(*args);
+
+    line.buf[line.len] = b'\n';
+    line.len += 1;
+    // SAFETY: só bytes de &str, cortados em fronteira de caractere
+    let text = unsafe { core::str::from_utf8_unchecked(&line.buf[..line.len]) };
+    let _ = crate::sys::kprint(text);
+    crate::runtime::report::record_log(text);
+}
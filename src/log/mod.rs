@@ -0,0 +1,33 @@
+//! # Log
+//!
+//! Logger do crate [`log`](::log) para o log do kernel
+//! ([`crate::sys::kprint`]).
+//!
+//! [`init`] instala o logger, então `log::info!` e afins, inclusive em crates
+//! de terceiros, chegam ao kernel. Cada registro sai em uma linha, com
+//! timestamp desde o boot, nível e a tag do processo:
+//!
+//! ```text
+//! [    12.345678] WARN  compositor: buffer atrasado (3 frames)
+//! ```
+//!
+//! As macros e tipos do facade são reexportados aqui (e as macros também na
+//! raiz do crate).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::log::{self, LevelFilter};
+//! use redpowder::{info, warn};
+//!
+//! log::init_with_tag("compositor", LevelFilter::Info);
+//! info!("iniciado em {}x{}", w, h);
+//! warn!("buffer atrasado ({} frames)", n);
+//! ```
+
+mod logger;
+
+pub use ::log::{
+    debug, error, info, log, max_level, set_max_level, trace, warn, Level, LevelFilter,
+};
+pub use logger::*;
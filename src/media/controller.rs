@@ -0,0 +1,148 @@
+//! # Media Controller
+//!
+//! Lado do shell/teclas de mídia: acompanha o player ativo e envia comandos.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::media::{Command, Controller};
+//!
+//! let mut controller = Controller::connect()?;
+//! controller.send(Command::PlayPause)?;
+//!
+//! while let Some(_event) = controller.poll() {}
+//! if let Some(state) = controller.active() {
+//!     println!("{} - {}", state.artist(), state.title());
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use crate::ipc::{as_bytes, as_bytes_mut, Port};
+use crate::syscall::SysResult;
+
+use super::protocol::*;
+use super::{Command, PlayerState};
+
+// =============================================================================
+// CONTROLLER
+// =============================================================================
+
+/// Evento recebido pelo controlador.
+#[derive(Debug, Clone)]
+pub enum MediaEvent {
+    /// Estado/metadados de um player mudaram.
+    StateChanged(Box<PlayerState>),
+    /// Player encerrado (ID).
+    PlayerRemoved(u32),
+}
+
+/// Controlador de players de mídia.
+pub struct Controller {
+    /// Último estado do player ativo.
+    active: Option<PlayerState>,
+    /// Porta do servidor de mídia.
+    server_port: Port,
+    /// Porta de eventos.
+    event_port: Port,
+}
+
+impl Controller {
+    /// Conecta ao servidor de mídia e assina mudanças de estado.
+    ///
+    /// O servidor envia o estado atual do player ativo logo após a assinatura.
+    pub fn connect() -> SysResult<Self> {
+        let (event_port, event_name) = Port::create_unique("media.c.", 16)?;
+        let server_port = Port::connect(MEDIA_PORT)?;
+
+        let req = SubscribeRequest {
+            op: opcodes::SUBSCRIBE,
+            listener_port: event_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        Ok(Self {
+            active: None,
+            server_port,
+            event_port,
+        })
+    }
+
+    /// Envia comando ao player ativo.
+    pub fn send(&self, command: Command) -> SysResult<()> {
+        self.send_to(ACTIVE_PLAYER, command)
+    }
+
+    /// Envia comando a um player específico.
+    pub fn send_to(&self, player_id: u32, command: Command) -> SysResult<()> {
+        let (command, arg) = command.to_raw();
+        let msg = CommandMessage {
+            op: opcodes::SEND_COMMAND,
+            player_id,
+            command,
+            _pad: 0,
+            arg,
+        };
+        self.server_port.send(as_bytes(&msg), 0)?;
+        Ok(())
+    }
+
+    /// Estado do player ativo (conforme os eventos já processados).
+    pub fn active(&self) -> Option<&PlayerState> {
+        self.active.as_ref()
+    }
+
+    /// Processa o próximo evento pendente, sem bloquear.
+    pub fn poll(&mut self) -> Option<MediaEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&mut self, timeout_ms: u64) -> Option<MediaEvent> {
+        loop {
+            let mut msg = MediaMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self
+                .event_port
+                .recv(as_bytes_mut(&mut msg), timeout_ms)
+                .ok()?;
+            if len < core::mem::size_of::<PlayerOpRequest>() {
+                return None;
+            }
+
+            match unsafe { msg.header } {
+                opcodes::STATE_CHANGED if len >= core::mem::size_of::<PlayerStateMessage>() => {
+                    let state = PlayerState {
+                        msg: unsafe { msg.state },
+                    };
+                    if state.is_active() {
+                        self.active = Some(state);
+                    } else if self
+                        .active
+                        .is_some_and(|a| a.player_id() == state.player_id())
+                    {
+                        self.active = None;
+                    }
+                    return Some(MediaEvent::StateChanged(Box::new(state)));
+                }
+                opcodes::PLAYER_REMOVED => {
+                    let player_id = unsafe { msg.op_req.player_id };
+                    if self.active.is_some_and(|a| a.player_id() == player_id) {
+                        self.active = None;
+                    }
+                    return Some(MediaEvent::PlayerRemoved(player_id));
+                }
+                // Mensagem desconhecida: descartar e continuar
+                _ => continue,
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.event_port
+    }
+}
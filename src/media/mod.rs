@@ -0,0 +1,184 @@
+//! # Media
+//!
+//! Controle de reprodução no estilo MPRIS: players expõem estado e
+//! metadados; o shell e as teclas de mídia controlam o player ativo.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo de mídia |
+//! | [`player`] | Lado do app de mídia (Player) |
+//! | [`controller`] | Lado do shell/teclas de mídia (Controller) |
+
+pub mod controller;
+pub mod player;
+pub mod protocol;
+
+use crate::ipc::str_from_fixed;
+
+use self::protocol::{commands, status, PlayerStateMessage};
+
+// =============================================================================
+// TIPOS COMPARTILHADOS
+// =============================================================================
+
+/// Estado de reprodução.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackStatus {
+    #[default]
+    Stopped,
+    Playing,
+    Paused,
+}
+
+impl PlaybackStatus {
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            status::PLAYING => Self::Playing,
+            status::PAUSED => Self::Paused,
+            _ => Self::Stopped,
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Stopped => status::STOPPED,
+            Self::Playing => status::PLAYING,
+            Self::Paused => status::PAUSED,
+        }
+    }
+}
+
+/// Comando de controle de reprodução.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Play,
+    Pause,
+    PlayPause,
+    Stop,
+    Next,
+    Previous,
+    /// Avança (ou volta, se negativo) em milissegundos.
+    Seek(i64),
+    /// Posição absoluta em milissegundos.
+    SetPosition(u64),
+}
+
+impl Command {
+    fn from_raw(command: u32, arg: i64) -> Option<Self> {
+        Some(match command {
+            commands::PLAY => Self::Play,
+            commands::PAUSE => Self::Pause,
+            commands::PLAY_PAUSE => Self::PlayPause,
+            commands::STOP => Self::Stop,
+            commands::NEXT => Self::Next,
+            commands::PREVIOUS => Self::Previous,
+            commands::SEEK => Self::Seek(arg),
+            commands::SET_POSITION => Self::SetPosition(arg.max(0) as u64),
+            _ => return None,
+        })
+    }
+
+    fn to_raw(self) -> (u32, i64) {
+        match self {
+            Self::Play => (commands::PLAY, 0),
+            Self::Pause => (commands::PAUSE, 0),
+            Self::PlayPause => (commands::PLAY_PAUSE, 0),
+            Self::Stop => (commands::STOP, 0),
+            Self::Next => (commands::NEXT, 0),
+            Self::Previous => (commands::PREVIOUS, 0),
+            Self::Seek(ms) => (commands::SEEK, ms),
+            Self::SetPosition(ms) => (commands::SET_POSITION, ms as i64),
+        }
+    }
+}
+
+/// Metadados da faixa atual.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Metadata<'a> {
+    pub title: &'a str,
+    pub artist: &'a str,
+    pub album: &'a str,
+    /// Duração em milissegundos (0 = desconhecida).
+    pub length_ms: u64,
+}
+
+/// Estado publicado por um player.
+#[derive(Clone, Copy)]
+pub struct PlayerState {
+    msg: PlayerStateMessage,
+}
+
+impl PlayerState {
+    /// ID do player no servidor.
+    pub fn player_id(&self) -> u32 {
+        self.msg.player_id
+    }
+
+    /// Retorna se é o player ativo (alvo das teclas de mídia).
+    pub fn is_active(&self) -> bool {
+        self.msg.active != 0
+    }
+
+    /// Nome da aplicação.
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.msg.name)
+    }
+
+    /// Estado de reprodução.
+    pub fn status(&self) -> PlaybackStatus {
+        PlaybackStatus::from_raw(self.msg.status)
+    }
+
+    /// Capacidades (bits de [`protocol::capabilities`]).
+    pub fn capabilities(&self) -> u32 {
+        self.msg.capabilities
+    }
+
+    /// Posição em milissegundos.
+    pub fn position_ms(&self) -> u64 {
+        self.msg.position_ms
+    }
+
+    /// Duração em milissegundos (0 = desconhecida).
+    pub fn length_ms(&self) -> u64 {
+        self.msg.length_ms
+    }
+
+    /// Título da faixa.
+    pub fn title(&self) -> &str {
+        str_from_fixed(&self.msg.title)
+    }
+
+    /// Artista.
+    pub fn artist(&self) -> &str {
+        str_from_fixed(&self.msg.artist)
+    }
+
+    /// Álbum.
+    pub fn album(&self) -> &str {
+        str_from_fixed(&self.msg.album)
+    }
+}
+
+impl core::fmt::Debug for PlayerState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PlayerState")
+            .field("player_id", &self.player_id())
+            .field("name", &self.name())
+            .field("status", &self.status())
+            .field("title", &self.title())
+            .field("artist", &self.artist())
+            .field("position_ms", &self.position_ms())
+            .finish()
+    }
+}
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use controller::{Controller, MediaEvent};
+pub use player::Player;
+pub use protocol::{capabilities, MEDIA_PORT};
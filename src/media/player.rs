@@ -0,0 +1,160 @@
+//! # Media Player
+//!
+//! Lado do app de mídia: publica estado/metadados e recebe comandos.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::media::{capabilities, Command, Metadata, PlaybackStatus, Player};
+//!
+//! let mut player = Player::register("music", capabilities::CAN_PLAY | capabilities::CAN_PAUSE)?;
+//! player.set_metadata(&Metadata { title: "Song", artist: "Band", ..Default::default() })?;
+//! player.set_status(PlaybackStatus::Playing, 0)?;
+//!
+//! while let Some(cmd) = player.poll_command() {
+//!     match cmd {
+//!         Command::PlayPause => { /* ... */ }
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, recv_reply, Port};
+use crate::syscall::SysResult;
+
+use super::protocol::*;
+use super::{Command, Metadata, PlaybackStatus};
+
+/// Tempo máximo de espera pelo registro (ms).
+const REPLY_TIMEOUT_MS: u64 = 1000;
+
+// =============================================================================
+// PLAYER
+// =============================================================================
+
+/// Player registrado no servidor de mídia.
+pub struct Player {
+    /// Estado atual (reenviado inteiro a cada mudança).
+    state: PlayerStateMessage,
+    /// Porta do servidor de mídia.
+    server_port: Port,
+    /// Porta de comandos.
+    command_port: Port,
+}
+
+impl Player {
+    /// Registra um player com as capacidades dadas.
+    pub fn register(name: &str, caps: u32) -> SysResult<Self> {
+        let (command_port, command_name) = Port::create_unique("media.p.", 16)?;
+        let server_port = Port::connect(MEDIA_PORT)?;
+
+        let req = RegisterPlayerRequest {
+            op: opcodes::REGISTER_PLAYER,
+            capabilities: caps,
+            command_port: command_name,
+            name: fixed_str(name),
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<MediaMessage>(
+            &command_port,
+            opcodes::PLAYER_REGISTERED,
+            core::mem::size_of::<PlayerRegisteredResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let player_id = unsafe { resp.registered.player_id };
+
+        let state = PlayerStateMessage {
+            op: opcodes::UPDATE_STATE,
+            player_id,
+            status: PlaybackStatus::Stopped.to_raw(),
+            capabilities: caps,
+            active: 0,
+            _pad: 0,
+            position_ms: 0,
+            length_ms: 0,
+            name: fixed_str(name),
+            title: [0; 64],
+            artist: [0; 48],
+            album: [0; 48],
+        };
+
+        Ok(Self {
+            state,
+            server_port,
+            command_port,
+        })
+    }
+
+    /// ID do player no servidor.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.state.player_id
+    }
+
+    /// Publica estado de reprodução e posição.
+    pub fn set_status(&mut self, status: PlaybackStatus, position_ms: u64) -> SysResult<()> {
+        self.state.status = status.to_raw();
+        self.state.position_ms = position_ms;
+        self.publish()
+    }
+
+    /// Publica metadados da faixa atual (posição volta a 0).
+    pub fn set_metadata(&mut self, metadata: &Metadata) -> SysResult<()> {
+        self.state.title = fixed_str(metadata.title);
+        self.state.artist = fixed_str(metadata.artist);
+        self.state.album = fixed_str(metadata.album);
+        self.state.length_ms = metadata.length_ms;
+        self.state.position_ms = 0;
+        self.publish()
+    }
+
+    /// Retorna o próximo comando pendente, sem bloquear.
+    pub fn poll_command(&self) -> Option<Command> {
+        self.wait_command(0)
+    }
+
+    /// Espera um comando por até `timeout_ms`.
+    pub fn wait_command(&self, timeout_ms: u64) -> Option<Command> {
+        loop {
+            let mut msg = MediaMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self
+                .command_port
+                .recv(as_bytes_mut(&mut msg), timeout_ms)
+                .ok()?;
+            if len < core::mem::size_of::<CommandMessage>() {
+                return None;
+            }
+
+            let cmd = unsafe { msg.command };
+            if cmd.op == opcodes::COMMAND {
+                if let Some(command) = Command::from_raw(cmd.command, cmd.arg) {
+                    return Some(command);
+                }
+            }
+            // Mensagem desconhecida: descartar e continuar
+        }
+    }
+
+    /// Porta de comandos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.command_port
+    }
+
+    fn publish(&self) -> SysResult<()> {
+        self.server_port.send(as_bytes(&self.state), 0)?;
+        Ok(())
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let req = PlayerOpRequest {
+            op: opcodes::UNREGISTER_PLAYER,
+            player_id: self.state.player_id,
+        };
+        let _ = self.server_port.send(as_bytes(&req), 0);
+    }
+}
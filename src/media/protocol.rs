@@ -0,0 +1,179 @@
+//! # Protocolo de Mídia
+//!
+//! Definições de mensagens entre players, controladores e o servidor de
+//! mídia, que roteia comandos para o player ativo.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do servidor de mídia.
+pub const MEDIA_PORT: &str = "media.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+/// `player_id` que endereça o player ativo.
+pub const ACTIVE_PLAYER: u32 = 0;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Player -> Server
+    pub const REGISTER_PLAYER: u32 = 0x01;
+    pub const UNREGISTER_PLAYER: u32 = 0x02;
+    pub const UPDATE_STATE: u32 = 0x03;
+
+    // Controller -> Server
+    pub const SUBSCRIBE: u32 = 0x04;
+    pub const SEND_COMMAND: u32 = 0x05;
+
+    // Server -> Player
+    pub const PLAYER_REGISTERED: u32 = 0x10;
+    pub const COMMAND: u32 = 0x11;
+
+    // Server -> Controller
+    pub const STATE_CHANGED: u32 = 0x20;
+    pub const PLAYER_REMOVED: u32 = 0x21;
+
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Comandos de reprodução.
+pub mod commands {
+    pub const PLAY: u32 = 1;
+    pub const PAUSE: u32 = 2;
+    pub const PLAY_PAUSE: u32 = 3;
+    pub const STOP: u32 = 4;
+    pub const NEXT: u32 = 5;
+    pub const PREVIOUS: u32 = 6;
+    pub const SEEK: u32 = 7;
+    pub const SET_POSITION: u32 = 8;
+}
+
+/// Estados de reprodução.
+pub mod status {
+    pub const STOPPED: u32 = 0;
+    pub const PLAYING: u32 = 1;
+    pub const PAUSED: u32 = 2;
+}
+
+/// Capacidades anunciadas pelo player (bits).
+pub mod capabilities {
+    pub const CAN_PLAY: u32 = 1 << 0;
+    pub const CAN_PAUSE: u32 = 1 << 1;
+    pub const CAN_SEEK: u32 = 1 << 2;
+    pub const CAN_GO_NEXT: u32 = 1 << 3;
+    pub const CAN_GO_PREVIOUS: u32 = 1 << 4;
+}
+
+// =============================================================================
+// MENSAGENS
+// =============================================================================
+
+/// Request para registrar player.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RegisterPlayerRequest {
+    pub op: u32,
+    pub capabilities: u32,
+    /// Porta onde o player recebe comandos (e a resposta do registro).
+    pub command_port: [u8; 32],
+    /// Nome da aplicação.
+    pub name: [u8; 32],
+}
+
+/// Request genérico para operações de player.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerOpRequest {
+    pub op: u32,
+    pub player_id: u32,
+}
+
+/// Estado de um player (`UPDATE_STATE` do player, `STATE_CHANGED` do servidor).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerStateMessage {
+    pub op: u32,
+    pub player_id: u32,
+    pub status: u32,
+    pub capabilities: u32,
+    /// Preenchido pelo servidor: 1 se for o player ativo.
+    pub active: u32,
+    pub _pad: u32,
+    pub position_ms: u64,
+    pub length_ms: u64,
+    pub name: [u8; 32],
+    pub title: [u8; 64],
+    pub artist: [u8; 48],
+    pub album: [u8; 48],
+}
+
+/// Request para receber mudanças de estado.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    /// Porta para receber eventos.
+    pub listener_port: [u8; 32],
+}
+
+/// Comando (`SEND_COMMAND` do controlador, `COMMAND` para o player).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CommandMessage {
+    pub op: u32,
+    /// Player alvo ([`ACTIVE_PLAYER`] = ativo).
+    pub player_id: u32,
+    pub command: u32,
+    pub _pad: u32,
+    /// Argumento (ms) de `SEEK`/`SET_POSITION`.
+    pub arg: i64,
+}
+
+/// Response de registro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerRegisteredResponse {
+    pub op: u32,
+    pub player_id: u32,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union MediaMessage {
+    pub header: u32,
+    pub register_req: RegisterPlayerRequest,
+    pub op_req: PlayerOpRequest,
+    pub state: PlayerStateMessage,
+    pub subscribe_req: SubscribeRequest,
+    pub command: CommandMessage,
+    pub registered: PlayerRegisteredResponse,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for MediaMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
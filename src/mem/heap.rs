@@ -28,6 +28,7 @@ unsafe impl GlobalAlloc for SyscallAllocator {
 
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
         // Syscall free
+        // SAFETY: ptr veio de alloc com o mesmo layout (contrato de GlobalAlloc)
         let _ = crate::mem::mem::free(ptr, layout.size());
     }
 }
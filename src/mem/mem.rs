@@ -2,8 +2,8 @@
 //!
 //! Alocação e mapeamento de memória.
 
-use crate::syscall::{check_error, syscall2, syscall4, SysResult};
-use crate::syscall::{SYS_ALLOC, SYS_FREE, SYS_MAP, SYS_UNMAP};
+use crate::syscall::safe::{sys_alloc, sys_free, sys_map, sys_unmap};
+use crate::syscall::SysResult;
 
 /// Flags de alocação
 pub mod flags {
@@ -19,6 +19,8 @@ pub mod map_flags {
     pub const SHARED: u32 = 1 << 3;
     pub const PRIVATE: u32 = 1 << 4;
     pub const FIXED: u32 = 1 << 5;
+    /// Mapeia o framebuffer do kernel (handle ignorado).
+    pub const FRAMEBUFFER: u32 = 1 << 6;
 }
 
 /// Aloca memória virtual
@@ -30,14 +32,15 @@ pub mod map_flags {
 /// # Returns
 /// Ponteiro para memória alocada
 pub fn alloc(size: usize, flags: u32) -> SysResult<*mut u8> {
-    let ret = syscall2(SYS_ALLOC, size, flags as usize);
-    check_error(ret).map(|v| v as *mut u8)
+    sys_alloc(size, flags)
 }
 
 /// Libera memória alocada
-pub fn free(ptr: *mut u8, size: usize) -> SysResult<()> {
-    check_error(syscall2(SYS_FREE, ptr as usize, size))?;
-    Ok(())
+///
+/// # Safety
+/// `ptr`/`size` devem vir de [`alloc`] e a região não pode mais ser usada.
+pub unsafe fn free(ptr: *mut u8, size: usize) -> SysResult<()> {
+    sys_free(ptr, size)
 }
 
 /// Mapeia memória
@@ -47,13 +50,17 @@ pub fn free(ptr: *mut u8, size: usize) -> SysResult<()> {
 /// - size: tamanho
 /// - flags: permissões
 /// - handle: handle do objeto (0 = anônimo)
-pub fn map(addr: usize, size: usize, flags: u32, handle: u32) -> SysResult<*mut u8> {
-    let ret = syscall4(SYS_MAP, addr, size, flags as usize, handle as usize);
-    check_error(ret).map(|v| v as *mut u8)
+///
+/// # Safety
+/// Com `map_flags::FIXED`, um mapeamento existente em `addr` é substituído.
+pub unsafe fn map(addr: usize, size: usize, flags: u32, handle: u32) -> SysResult<*mut u8> {
+    sys_map(addr, size, flags, handle)
 }
 
 /// Remove mapeamento
-pub fn unmap(addr: *mut u8, size: usize) -> SysResult<()> {
-    check_error(syscall2(SYS_UNMAP, addr as usize, size))?;
-    Ok(())
+///
+/// # Safety
+/// A região não pode mais ser acessada.
+pub unsafe fn unmap(addr: *mut u8, size: usize) -> SysResult<()> {
+    sys_unmap(addr, size)
 }
@@ -0,0 +1,499 @@
+//! # Bluetooth Client
+//!
+//! Gerenciamento de adaptadores e dispositivos Bluetooth.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::bluetooth::{self, BluetoothEvent, PairingPrompt};
+//!
+//! let adapter = bluetooth::adapters()?[0].id();
+//! let events = bluetooth::subscribe()?;
+//! bluetooth::scan(adapter, 10_000)?;
+//!
+//! while let Some(event) = events.wait(1000) {
+//!     match event {
+//!         BluetoothEvent::DeviceFound(dev) => println!("{} {}", dev.address(), dev.name()),
+//!         BluetoothEvent::ScanFinished(_) => break,
+//!         _ => {}
+//!     }
+//! }
+//!
+//! bluetooth::pair(adapter, addr, |prompt| match prompt {
+//!     PairingPrompt::Display(code) => { println!("Digite {:06}", code); true }
+//!     PairingPrompt::Confirm(code) => ask_user(code),
+//! })?;
+//! bluetooth::connect(adapter, addr)?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_message, recv_reply, str_from_fixed, Message, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Tempo máximo de pareamento/conexão, incluindo confirmação (ms).
+const PAIR_TIMEOUT_MS: u64 = 30_000;
+
+// =============================================================================
+// ENDEREÇO
+// =============================================================================
+
+/// Endereço de dispositivo Bluetooth (BD_ADDR), na ordem de exibição.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BdAddr(pub [u8; 6]);
+
+impl BdAddr {
+    /// Parseia `AA:BB:CC:DD:EE:FF`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut addr = [0u8; 6];
+        let mut parts = s.split(':');
+        for byte in addr.iter_mut() {
+            let part = parts.next()?;
+            if part.len() != 2 {
+                return None;
+            }
+            *byte = u8::from_str_radix(part, 16).ok()?;
+        }
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self(addr))
+    }
+}
+
+impl core::fmt::Display for BdAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let a = self.0;
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+            a[0], a[1], a[2], a[3], a[4], a[5]
+        )
+    }
+}
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Adaptador Bluetooth local.
+#[derive(Clone, Copy)]
+pub struct Adapter {
+    entry: AdapterEntry,
+}
+
+impl Adapter {
+    /// ID do adaptador no serviço.
+    pub fn id(&self) -> u32 {
+        self.entry.adapter_id
+    }
+
+    /// Endereço do adaptador.
+    pub fn address(&self) -> BdAddr {
+        BdAddr(self.entry.address)
+    }
+
+    /// Nome do adaptador.
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Retorna se o rádio está ligado.
+    pub fn is_powered(&self) -> bool {
+        self.entry.powered != 0
+    }
+}
+
+impl core::fmt::Debug for Adapter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Adapter")
+            .field("id", &self.id())
+            .field("address", &self.address())
+            .field("name", &self.name())
+            .field("powered", &self.is_powered())
+            .finish()
+    }
+}
+
+/// Categoria do dispositivo (derivada do Class of Device).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    Computer,
+    Phone,
+    /// Fones, caixas de som, headsets.
+    Audio,
+    Keyboard,
+    /// Mouse, touchpad.
+    Pointer,
+    /// Gamepads e outros periféricos.
+    Peripheral,
+    Other,
+}
+
+impl DeviceKind {
+    /// Classifica a partir do Class of Device (CoD).
+    pub fn from_class(class: u32) -> Self {
+        let major = (class >> 8) & 0x1F;
+        let minor = (class >> 2) & 0x3F;
+        match major {
+            1 => Self::Computer,
+            2 => Self::Phone,
+            4 => Self::Audio,
+            5 => match minor >> 4 {
+                0b01 => Self::Keyboard,
+                0b10 => Self::Pointer,
+                _ => Self::Peripheral,
+            },
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Dispositivo remoto conhecido ou descoberto.
+#[derive(Clone, Copy)]
+pub struct Device {
+    entry: DeviceEntry,
+}
+
+impl Device {
+    /// Endereço do dispositivo.
+    pub fn address(&self) -> BdAddr {
+        BdAddr(self.entry.address)
+    }
+
+    /// ID do adaptador que vê o dispositivo.
+    pub fn adapter_id(&self) -> u32 {
+        self.entry.adapter_id
+    }
+
+    /// Nome anunciado (pode ser vazio).
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Class of Device (CoD) bruto.
+    pub fn class(&self) -> u32 {
+        self.entry.class
+    }
+
+    /// Categoria do dispositivo.
+    pub fn kind(&self) -> DeviceKind {
+        DeviceKind::from_class(self.entry.class)
+    }
+
+    /// Intensidade do sinal (dBm), se conhecida.
+    pub fn rssi(&self) -> Option<i32> {
+        (self.entry.rssi != 0).then_some(self.entry.rssi)
+    }
+
+    /// Retorna se está pareado.
+    pub fn is_paired(&self) -> bool {
+        self.entry.flags & device_flags::PAIRED != 0
+    }
+
+    /// Retorna se está conectado.
+    pub fn is_connected(&self) -> bool {
+        self.entry.flags & device_flags::CONNECTED != 0
+    }
+
+    /// Retorna se reconecta automaticamente.
+    pub fn is_trusted(&self) -> bool {
+        self.entry.flags & device_flags::TRUSTED != 0
+    }
+}
+
+impl core::fmt::Debug for Device {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Device")
+            .field("address", &self.address())
+            .field("name", &self.name())
+            .field("kind", &self.kind())
+            .field("paired", &self.is_paired())
+            .field("connected", &self.is_connected())
+            .finish()
+    }
+}
+
+/// Confirmação pedida durante o pareamento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingPrompt {
+    /// Confirmar que o código exibido no dispositivo é este.
+    Confirm(u32),
+    /// Mostrar o código para o usuário digitar no dispositivo.
+    Display(u32),
+}
+
+/// Evento do serviço Bluetooth.
+#[derive(Debug, Clone, Copy)]
+pub enum BluetoothEvent {
+    /// Dispositivo encontrado durante a descoberta.
+    DeviceFound(Device),
+    /// Estado de pareamento/conexão (ou nome/RSSI) mudou.
+    DeviceChanged(Device),
+    /// Dispositivo saiu de alcance ou foi esquecido.
+    DeviceLost(Device),
+    /// Descoberta terminou no adaptador (ID).
+    ScanFinished(u32),
+}
+
+// =============================================================================
+// ADAPTADORES
+// =============================================================================
+
+/// Lista os adaptadores locais.
+pub fn adapters() -> SysResult<Vec<Adapter>> {
+    let (reply_port, reply_name) = Port::create_unique("bt.r.", 4)?;
+    let server_port = Port::connect(BLUETOOTH_PORT)?;
+
+    let mut adapters = Vec::new();
+    loop {
+        let req = ListRequest {
+            op: opcodes::LIST_ADAPTERS,
+            adapter_id: 0,
+            start: adapters.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<BluetoothMessage>(
+            &reply_port,
+            opcodes::ADAPTER_LIST,
+            core::mem::size_of::<AdapterListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.adapter_list };
+
+        let count = (page.count as usize).min(ADAPTERS_PER_MSG);
+        adapters.extend(page.entries[..count].iter().map(|&entry| Adapter { entry }));
+        if count == 0 || adapters.len() >= page.total as usize {
+            return Ok(adapters);
+        }
+    }
+}
+
+/// Liga ou desliga o rádio do adaptador.
+pub fn set_powered(adapter_id: u32, powered: bool) -> SysResult<()> {
+    adapter_request(opcodes::SET_POWERED, adapter_id, powered as u32)
+}
+
+/// Inicia descoberta por `duration_ms`.
+///
+/// Dispositivos chegam como [`BluetoothEvent::DeviceFound`] em [`subscribe`],
+/// seguidos de [`BluetoothEvent::ScanFinished`].
+pub fn scan(adapter_id: u32, duration_ms: u32) -> SysResult<()> {
+    adapter_request(opcodes::START_SCAN, adapter_id, duration_ms)
+}
+
+/// Interrompe a descoberta.
+pub fn stop_scan(adapter_id: u32) -> SysResult<()> {
+    adapter_request(opcodes::STOP_SCAN, adapter_id, 0)
+}
+
+// =============================================================================
+// DISPOSITIVOS
+// =============================================================================
+
+/// Lista os dispositivos conhecidos pelo adaptador (pareados e descobertos).
+pub fn devices(adapter_id: u32) -> SysResult<Vec<Device>> {
+    let (reply_port, reply_name) = Port::create_unique("bt.r.", 4)?;
+    let server_port = Port::connect(BLUETOOTH_PORT)?;
+
+    let mut devices = Vec::new();
+    loop {
+        let req = ListRequest {
+            op: opcodes::LIST_DEVICES,
+            adapter_id,
+            start: devices.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<BluetoothMessage>(
+            &reply_port,
+            opcodes::DEVICE_LIST,
+            core::mem::size_of::<DeviceListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.device_list };
+
+        let count = (page.count as usize).min(DEVICES_PER_MSG);
+        devices.extend(page.entries[..count].iter().map(|&entry| Device { entry }));
+        if count == 0 || devices.len() >= page.total as usize {
+            return Ok(devices);
+        }
+    }
+}
+
+/// Pareia com o dispositivo, bloqueando até concluir.
+///
+/// `confirm` é chamado para cada confirmação pedida pelo dispositivo; retornar
+/// `false` recusa o pareamento. Em [`PairingPrompt::Display`] o retorno apenas
+/// indica se o usuário não cancelou.
+pub fn pair<F>(adapter_id: u32, address: BdAddr, mut confirm: F) -> SysResult<()>
+where
+    F: FnMut(PairingPrompt) -> bool,
+{
+    let (reply_port, reply_name) = Port::create_unique("bt.r.", 4)?;
+    let server_port = Port::connect(BLUETOOTH_PORT)?;
+    server_port.send(
+        as_bytes(&device_request(
+            opcodes::PAIR,
+            adapter_id,
+            address,
+            0,
+            reply_name,
+        )),
+        0,
+    )?;
+
+    loop {
+        let (msg, len) = recv_message::<BluetoothMessage>(&reply_port, PAIR_TIMEOUT_MS)?;
+        match unsafe { msg.header } {
+            opcodes::DONE => return Ok(()),
+            opcodes::PAIRING_PROMPT if len >= core::mem::size_of::<PairingPromptMessage>() => {
+                let prompt = unsafe { msg.prompt };
+                let prompt = match prompt.kind {
+                    prompt_kind::DISPLAY => PairingPrompt::Display(prompt.passkey),
+                    _ => PairingPrompt::Confirm(prompt.passkey),
+                };
+                let accept = confirm(prompt);
+                let req = device_request(
+                    opcodes::CONFIRM_PAIRING,
+                    adapter_id,
+                    address,
+                    accept as u32,
+                    reply_name,
+                );
+                server_port.send(as_bytes(&req), 0)?;
+            }
+            opcodes::ERROR => return Err(msg.error()),
+            _ => return Err(SysError::ProtocolError),
+        }
+    }
+}
+
+/// Remove o pareamento.
+pub fn unpair(adapter_id: u32, address: BdAddr) -> SysResult<()> {
+    device_op(opcodes::UNPAIR, adapter_id, address, REPLY_TIMEOUT_MS)
+}
+
+/// Conecta ao dispositivo (que deve estar pareado).
+pub fn connect(adapter_id: u32, address: BdAddr) -> SysResult<()> {
+    device_op(opcodes::CONNECT, adapter_id, address, PAIR_TIMEOUT_MS)
+}
+
+/// Desconecta o dispositivo.
+pub fn disconnect(adapter_id: u32, address: BdAddr) -> SysResult<()> {
+    device_op(opcodes::DISCONNECT, adapter_id, address, REPLY_TIMEOUT_MS)
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Receptor de eventos de dispositivos.
+pub struct BluetoothListener {
+    port: Port,
+}
+
+/// Registra um receptor de eventos.
+pub fn subscribe() -> SysResult<BluetoothListener> {
+    let (port, name) = Port::create_unique("bt.ev.", 32)?;
+    let req = SubscribeRequest {
+        op: opcodes::SUBSCRIBE,
+        listener_port: name,
+    };
+    Port::connect(BLUETOOTH_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(BluetoothListener { port })
+}
+
+impl BluetoothListener {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<BluetoothEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<BluetoothEvent> {
+        loop {
+            let mut msg = BluetoothMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<DeviceEventMessage>() {
+                return None;
+            }
+
+            let event = unsafe { msg.event };
+            let device = Device {
+                entry: event.device,
+            };
+            match event.op {
+                opcodes::EVENT_DEVICE_FOUND => return Some(BluetoothEvent::DeviceFound(device)),
+                opcodes::EVENT_DEVICE_CHANGED => {
+                    return Some(BluetoothEvent::DeviceChanged(device))
+                }
+                opcodes::EVENT_DEVICE_LOST => return Some(BluetoothEvent::DeviceLost(device)),
+                opcodes::EVENT_SCAN_FINISHED => {
+                    return Some(BluetoothEvent::ScanFinished(event.device.adapter_id))
+                }
+                // Mensagem desconhecida: descartar e continuar
+                _ => continue,
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+fn adapter_request(op: u32, adapter_id: u32, value: u32) -> SysResult<()> {
+    let (reply_port, reply_name) = Port::create_unique("bt.r.", 4)?;
+    let req = AdapterRequest {
+        op,
+        adapter_id,
+        value,
+        reply_port: reply_name,
+    };
+    Port::connect(BLUETOOTH_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<BluetoothMessage>(&reply_port, opcodes::DONE, 0, REPLY_TIMEOUT_MS)?;
+    Ok(())
+}
+
+fn device_op(op: u32, adapter_id: u32, address: BdAddr, timeout_ms: u64) -> SysResult<()> {
+    let (reply_port, reply_name) = Port::create_unique("bt.r.", 4)?;
+    let req = device_request(op, adapter_id, address, 0, reply_name);
+    Port::connect(BLUETOOTH_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<BluetoothMessage>(&reply_port, opcodes::DONE, 0, timeout_ms)?;
+    Ok(())
+}
+
+fn device_request(
+    op: u32,
+    adapter_id: u32,
+    address: BdAddr,
+    value: u32,
+    reply_port: [u8; 32],
+) -> DeviceRequest {
+    DeviceRequest {
+        op,
+        adapter_id,
+        value,
+        address: address.0,
+        _pad: [0; 2],
+        reply_port,
+    }
+}
@@ -0,0 +1,24 @@
+//! # Bluetooth
+//!
+//! Cliente do serviço Bluetooth: adaptadores, descoberta, pareamento e
+//! conexão de dispositivos (teclados, fones...).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo Bluetooth |
+//! | [`client`] | API de gerenciamento e eventos de dispositivos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    adapters, connect, devices, disconnect, pair, scan, set_powered, stop_scan, subscribe, unpair,
+    Adapter, BdAddr, BluetoothEvent, BluetoothListener, Device, DeviceKind, PairingPrompt,
+};
+pub use protocol::BLUETOOTH_PORT;
@@ -0,0 +1,220 @@
+//! # Protocolo Bluetooth
+//!
+//! Definições de mensagens do protocolo com o serviço Bluetooth.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço Bluetooth.
+pub const BLUETOOTH_PORT: &str = "bluetooth.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+/// Adaptadores por página de `ADAPTER_LIST`.
+pub const ADAPTERS_PER_MSG: usize = 4;
+
+/// Dispositivos por página de `DEVICE_LIST`.
+pub const DEVICES_PER_MSG: usize = 4;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const LIST_ADAPTERS: u32 = 0x01;
+    pub const SET_POWERED: u32 = 0x02;
+    pub const START_SCAN: u32 = 0x03;
+    pub const STOP_SCAN: u32 = 0x04;
+    pub const LIST_DEVICES: u32 = 0x05;
+    pub const PAIR: u32 = 0x06;
+    pub const UNPAIR: u32 = 0x07;
+    pub const CONNECT: u32 = 0x08;
+    pub const DISCONNECT: u32 = 0x09;
+    pub const CONFIRM_PAIRING: u32 = 0x0A;
+    pub const SUBSCRIBE: u32 = 0x0B;
+
+    // Server -> Client
+    pub const ADAPTER_LIST: u32 = 0x10;
+    pub const DEVICE_LIST: u32 = 0x11;
+    pub const DONE: u32 = 0x12;
+    pub const PAIRING_PROMPT: u32 = 0x13;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> Listener)
+    pub const EVENT_DEVICE_FOUND: u32 = 0x20;
+    pub const EVENT_DEVICE_CHANGED: u32 = 0x21;
+    pub const EVENT_DEVICE_LOST: u32 = 0x22;
+    pub const EVENT_SCAN_FINISHED: u32 = 0x23;
+}
+
+/// Flags de estado de dispositivo.
+pub mod device_flags {
+    pub const PAIRED: u32 = 1 << 0;
+    pub const CONNECTED: u32 = 1 << 1;
+    pub const TRUSTED: u32 = 1 << 2;
+}
+
+/// Tipos de confirmação de pareamento.
+pub mod prompt_kind {
+    /// Comparação numérica: confirmar que o código é igual no dispositivo.
+    pub const CONFIRM: u32 = 0;
+    /// Exibir código para ser digitado no dispositivo (teclados).
+    pub const DISPLAY: u32 = 1;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de listagem (adaptadores ou dispositivos de um adaptador).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListRequest {
+    pub op: u32,
+    pub adapter_id: u32,
+    /// Índice da primeira entrada da página.
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request sobre um adaptador (`SET_POWERED`, `START_SCAN`, `STOP_SCAN`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AdapterRequest {
+    pub op: u32,
+    pub adapter_id: u32,
+    /// Ligado (0/1) ou duração da descoberta (ms).
+    pub value: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request sobre um dispositivo (`PAIR`, `UNPAIR`, `CONNECT`, `DISCONNECT`,
+/// `CONFIRM_PAIRING`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceRequest {
+    pub op: u32,
+    pub adapter_id: u32,
+    /// Aceitar (0/1) em `CONFIRM_PAIRING`.
+    pub value: u32,
+    pub address: [u8; 6],
+    pub _pad: [u8; 2],
+    pub reply_port: [u8; 32],
+}
+
+/// Request de assinatura de eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Descrição de um adaptador.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AdapterEntry {
+    pub adapter_id: u32,
+    pub powered: u32,
+    pub address: [u8; 6],
+    pub _pad: [u8; 2],
+    pub name: [u8; 32],
+}
+
+/// Descrição de um dispositivo.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceEntry {
+    pub address: [u8; 6],
+    pub _pad: [u8; 2],
+    pub adapter_id: u32,
+    /// Class of Device (CoD).
+    pub class: u32,
+    /// Combinação de `device_flags`.
+    pub flags: u32,
+    /// Intensidade de sinal (dBm), 0 se desconhecida.
+    pub rssi: i32,
+    pub name: [u8; 32],
+}
+
+/// Página da lista de adaptadores.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AdapterListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [AdapterEntry; ADAPTERS_PER_MSG],
+}
+
+/// Página da lista de dispositivos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [DeviceEntry; DEVICES_PER_MSG],
+}
+
+/// Pedido de confirmação durante `PAIR` (enviado à porta de resposta).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PairingPromptMessage {
+    pub op: u32,
+    pub kind: u32,
+    pub passkey: u32,
+}
+
+/// Evento de dispositivo ou adaptador.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceEventMessage {
+    pub op: u32,
+    pub device: DeviceEntry,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union BluetoothMessage {
+    pub header: u32,
+    pub list_req: ListRequest,
+    pub adapter_req: AdapterRequest,
+    pub device_req: DeviceRequest,
+    pub adapter_list: AdapterListResponse,
+    pub device_list: DeviceListResponse,
+    pub prompt: PairingPromptMessage,
+    pub event: DeviceEventMessage,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for BluetoothMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    /// Respostas como `DONE` trazem só o opcode.
+    const MIN_LEN: usize = core::mem::offset_of!(ErrorResponse, code);
+}
@@ -0,0 +1,216 @@
+//! # Sockets Locais
+//!
+//! Streams de bytes entre processos endereçados por caminho (estilo Unix
+//! domain), com passagem de handles e credenciais do par — para protocolos
+//! que pedem um stream (shims de compatibilidade, clientes de banco de dados)
+//! em vez de mensagens de porta.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::local::{LocalListener, LocalStream};
+//!
+//! let listener = LocalListener::bind("/run/db.sock")?;
+//! let client = LocalStream::connect("/run/db.sock")?;
+//! let (server, _) = listener.accept()?;
+//!
+//! let cred = server.peer_cred()?;
+//! client.send_with_handles(b"fd", &[file.handle()])?;
+//! ```
+
+use crate::event::events;
+use crate::io::Handle;
+use crate::syscall::{SysError, SysResult};
+
+use super::socket::{domain, msg_flags, sock_type, Shutdown, Socket};
+
+/// Tamanho máximo do caminho de um socket local (bytes).
+pub const LOCAL_PATH_MAX: usize = 108;
+
+/// Endereço local no formato do kernel.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct RawLocalAddr {
+    family: u16,
+    len: u16,
+    path: [u8; LOCAL_PATH_MAX],
+}
+
+impl RawLocalAddr {
+    fn new(path: &str) -> SysResult<Self> {
+        if path.is_empty() || path.len() > LOCAL_PATH_MAX {
+            return Err(SysError::InvalidArgument);
+        }
+        let mut raw = Self::empty();
+        raw.len = path.len() as u16;
+        raw.path[..path.len()].copy_from_slice(path.as_bytes());
+        Ok(raw)
+    }
+
+    fn empty() -> Self {
+        Self {
+            family: domain::LOCAL as u16,
+            len: 0,
+            path: [0; LOCAL_PATH_MAX],
+        }
+    }
+}
+
+// =============================================================================
+// CREDENCIAIS
+// =============================================================================
+
+/// Credenciais do processo do outro lado (capturadas no `connect`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerCred {
+    pub pid: u32,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+// =============================================================================
+// LOCAL STREAM
+// =============================================================================
+
+/// Conexão por socket local.
+pub struct LocalStream {
+    socket: Socket,
+}
+
+impl LocalStream {
+    /// Conecta ao socket no caminho.
+    pub fn connect(path: &str) -> SysResult<Self> {
+        let addr = RawLocalAddr::new(path)?;
+        let socket = Socket::new(domain::LOCAL, sock_type::STREAM)?;
+        socket.connect(&addr, 0)?;
+        Ok(Self { socket })
+    }
+
+    /// Lê dados (0 = conexão encerrada pelo par).
+    pub fn read(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv(buf, 0)
+    }
+
+    /// Escreve dados, retornando quantos bytes foram aceitos.
+    pub fn write(&self, buf: &[u8]) -> SysResult<usize> {
+        self.socket.send(buf, 0)
+    }
+
+    /// Escreve dados anexando handles (duplicados no processo do par).
+    ///
+    /// Os handles chegam junto ao primeiro byte de `data`, que não pode ser
+    /// vazio.
+    pub fn send_with_handles(&self, data: &[u8], handles: &[Handle]) -> SysResult<usize> {
+        if data.is_empty() {
+            return Err(SysError::InvalidArgument);
+        }
+        self.socket.sendmsg(data, handles, 0)
+    }
+
+    /// Lê dados e handles anexados.
+    ///
+    /// Retorna (bytes lidos, handles recebidos). Handles que não couberem em
+    /// `handles` são fechados pelo kernel.
+    pub fn recv_with_handles(
+        &self,
+        data: &mut [u8],
+        handles: &mut [Handle],
+    ) -> SysResult<(usize, usize)> {
+        self.socket.recvmsg(data, handles, 0)
+    }
+
+    /// Credenciais do processo do outro lado.
+    pub fn peer_cred(&self) -> SysResult<PeerCred> {
+        self.socket.peer_cred()
+    }
+
+    /// Encerra leitura, escrita ou ambas.
+    pub fn shutdown(&self, how: Shutdown) -> SysResult<()> {
+        self.socket.shutdown(how)
+    }
+
+    /// [`read`](Self::read) assíncrono.
+    pub async fn read_async(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv_async(buf, 0).await
+    }
+
+    /// [`write`](Self::write) assíncrono.
+    pub async fn write_async(&self, buf: &[u8]) -> SysResult<usize> {
+        self.socket.send_async(buf).await
+    }
+
+    /// Handle do socket (para uso com `event::poll`).
+    pub fn handle(&self) -> &Handle {
+        self.socket.handle()
+    }
+}
+
+impl crate::io::Read for LocalStream {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        LocalStream::read(self, buf)
+    }
+}
+
+impl crate::io::Write for LocalStream {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        LocalStream::write(self, buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        Ok(())
+    }
+}
+
+// =============================================================================
+// LOCAL LISTENER
+// =============================================================================
+
+/// Socket local aceitando conexões.
+pub struct LocalListener {
+    socket: Socket,
+}
+
+impl LocalListener {
+    /// Cria o socket no caminho e começa a escutar.
+    ///
+    /// Falha com `SysError::AlreadyExists` se o caminho já existe.
+    pub fn bind(path: &str) -> SysResult<Self> {
+        let addr = RawLocalAddr::new(path)?;
+        let socket = Socket::new(domain::LOCAL, sock_type::STREAM)?;
+        socket.bind(&addr)?;
+        socket.listen(super::DEFAULT_BACKLOG)?;
+        Ok(Self { socket })
+    }
+
+    /// Aceita a próxima conexão, com as credenciais do cliente.
+    pub fn accept(&self) -> SysResult<(LocalStream, PeerCred)> {
+        let mut peer = RawLocalAddr::empty();
+        let stream = LocalStream {
+            socket: self.socket.accept(&mut peer, 0)?,
+        };
+        let cred = stream.peer_cred()?;
+        Ok((stream, cred))
+    }
+
+    /// [`accept`](Self::accept) assíncrono.
+    pub async fn accept_async(&self) -> SysResult<(LocalStream, PeerCred)> {
+        let mut peer = RawLocalAddr::empty();
+        loop {
+            match self.socket.accept(&mut peer, msg_flags::DONTWAIT) {
+                Ok(socket) => {
+                    let stream = LocalStream { socket };
+                    let cred = stream.peer_cred()?;
+                    return Ok((stream, cred));
+                }
+                Err(SysError::Busy) => self.socket.ready(events::IN).await?,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Handle do socket (para uso com `event::poll`).
+    pub fn handle(&self) -> &Handle {
+        self.socket.handle()
+    }
+}
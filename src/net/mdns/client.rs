@@ -0,0 +1,344 @@
+//! # mDNS Client
+//!
+//! Busca e anúncio de serviços DNS-SD.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::mdns::{self, BrowseEvent};
+//!
+//! // Anunciar enquanto `_ad` estiver vivo
+//! let _ad = mdns::advertise("Estação._rdshare._tcp", 7070)?;
+//!
+//! let browser = mdns::browse("_rdshare._tcp")?;
+//! while let Some(event) = browser.wait(5000) {
+//!     if let BrowseEvent::Found(service) = event {
+//!         println!("{} em {:?}", service.instance(), service.addr());
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, recv_reply, str_from_fixed, Port};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use crate::syscall::{SysError, SysResult};
+use crate::time::Instant;
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+///
+/// O registro inclui a sondagem de conflito de nome na rede (~750 ms).
+const REPLY_TIMEOUT_MS: u64 = 3000;
+
+// =============================================================================
+// SERVICE
+// =============================================================================
+
+/// Serviço encontrado na rede.
+#[derive(Clone, Copy)]
+pub struct Service {
+    msg: ServiceMessage,
+}
+
+impl Service {
+    /// Nome da instância (ex: `Estação`).
+    pub fn instance(&self) -> &str {
+        str_from_fixed(&self.msg.instance)
+    }
+
+    /// Tipo do serviço (ex: `_rdshare._tcp`).
+    pub fn service_type(&self) -> &str {
+        str_from_fixed(&self.msg.service_type)
+    }
+
+    /// Nome do host (ex: `estacao.local`).
+    pub fn host(&self) -> &str {
+        str_from_fixed(&self.msg.host)
+    }
+
+    /// Porta do serviço.
+    pub fn port(&self) -> u16 {
+        self.msg.port
+    }
+
+    /// Endereço IPv4 do host.
+    pub fn ipv4(&self) -> Option<Ipv4Addr> {
+        (self.msg.ipv4 != [0; 4]).then(|| Ipv4Addr::from(self.msg.ipv4))
+    }
+
+    /// Endereço IPv6 do host.
+    pub fn ipv6(&self) -> Option<Ipv6Addr> {
+        (self.msg.ipv6 != [0; 16]).then(|| Ipv6Addr::from(self.msg.ipv6))
+    }
+
+    /// Endereço para conexão (IPv4 preferido).
+    pub fn addr(&self) -> Option<SocketAddr> {
+        let ip = match (self.ipv4(), self.ipv6()) {
+            (Some(v4), _) => IpAddr::V4(v4),
+            (None, Some(v6)) => IpAddr::V6(v6),
+            (None, None) => return None,
+        };
+        Some(SocketAddr::new(ip, self.port()))
+    }
+
+    /// Valor de uma chave do registro TXT (`chave=valor`).
+    ///
+    /// Chaves sem `=` retornam `Some("")`.
+    pub fn txt(&self, key: &str) -> Option<&str> {
+        self.txt_entries().find_map(|entry| {
+            let (k, v) = entry.split_once('=').unwrap_or((entry, ""));
+            k.eq_ignore_ascii_case(key).then_some(v)
+        })
+    }
+
+    /// Entradas do registro TXT.
+    pub fn txt_entries(&self) -> impl Iterator<Item = &str> {
+        let len = (self.msg.txt_len as usize).min(TXT_MAX);
+        TxtIter {
+            data: &self.msg.txt[..len],
+        }
+    }
+}
+
+impl core::fmt::Debug for Service {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Service")
+            .field("instance", &self.instance())
+            .field("service_type", &self.service_type())
+            .field("host", &self.host())
+            .field("addr", &self.addr())
+            .finish()
+    }
+}
+
+/// Iterador sobre strings com prefixo de tamanho do registro TXT.
+struct TxtIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for TxtIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            let (&len, rest) = self.data.split_first()?;
+            let len = (len as usize).min(rest.len());
+            let (entry, rest) = rest.split_at(len);
+            self.data = rest;
+            if let Ok(s) = core::str::from_utf8(entry) {
+                return Some(s);
+            }
+            // Entrada binária: ignorar
+        }
+    }
+}
+
+// =============================================================================
+// BROWSE
+// =============================================================================
+
+/// Evento de busca.
+#[derive(Debug, Clone, Copy)]
+pub enum BrowseEvent {
+    /// Serviço anunciado (ou atualizado).
+    Found(Service),
+    /// Serviço saiu da rede (goodbye ou TTL expirado).
+    Lost(Service),
+}
+
+/// Busca contínua por um tipo de serviço.
+///
+/// Serviços já conhecidos pelo responder são entregues logo após o início;
+/// a busca termina quando o `Browser` é descartado.
+pub struct Browser {
+    port: Port,
+    request: BrowseRequest,
+}
+
+/// Inicia busca por `service_type` (ex: `_rdshare._tcp`).
+pub fn browse(service_type: &str) -> SysResult<Browser> {
+    validate_service_type(service_type)?;
+
+    let (port, name) = Port::create_unique("mdns.ev.", 32)?;
+    let request = BrowseRequest {
+        op: opcodes::BROWSE,
+        service_type: fixed_str(service_type),
+        listener_port: name,
+    };
+    Port::connect(MDNS_PORT)?.send(as_bytes(&request), 0)?;
+    Ok(Browser { port, request })
+}
+
+impl Browser {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<BrowseEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<BrowseEvent> {
+        loop {
+            let mut msg = MdnsMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<ServiceMessage>() {
+                return None;
+            }
+            let service = Service {
+                msg: unsafe { msg.service },
+            };
+            match unsafe { msg.header } {
+                opcodes::SERVICE_FOUND => return Some(BrowseEvent::Found(service)),
+                opcodes::SERVICE_LOST => return Some(BrowseEvent::Lost(service)),
+                // Mensagem desconhecida: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Coleta os serviços presentes após `timeout_ms` de busca.
+    pub fn collect(&self, timeout_ms: u64) -> SysResult<Vec<Service>> {
+        let timeout = Duration::from_millis(timeout_ms);
+        let start = Instant::now()?;
+        let mut services: Vec<Service> = Vec::new();
+
+        loop {
+            let remaining = timeout.saturating_sub(start.elapsed()?);
+            if remaining.is_zero() {
+                return Ok(services);
+            }
+            let Some(event) = self.wait(remaining.as_millis() as u64) else {
+                continue;
+            };
+            let (service, found) = match event {
+                BrowseEvent::Found(s) => (s, true),
+                BrowseEvent::Lost(s) => (s, false),
+            };
+            services.retain(|s| s.instance() != service.instance());
+            if found {
+                services.push(service);
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
+
+impl Drop for Browser {
+    fn drop(&mut self) {
+        let mut req = self.request;
+        req.op = opcodes::STOP_BROWSE;
+        if let Ok(server) = Port::connect(MDNS_PORT) {
+            let _ = server.send(as_bytes(&req), 0);
+        }
+    }
+}
+
+// =============================================================================
+// ADVERTISE
+// =============================================================================
+
+/// Anúncio de serviço ativo (removido da rede ao ser descartado).
+pub struct Advertisement {
+    id: u32,
+    instance: [u8; INSTANCE_MAX + 1],
+}
+
+/// Anuncia um serviço na rede local.
+///
+/// `name` é o nome completo `<instância>.<tipo>`, ex:
+/// `Estação._rdshare._tcp`. O tipo começa no primeiro `._`.
+pub fn advertise(name: &str, port: u16) -> SysResult<Advertisement> {
+    advertise_with_txt(name, port, &[])
+}
+
+/// Anuncia um serviço com registro TXT (`chave=valor`).
+pub fn advertise_with_txt(name: &str, port: u16, txt: &[&str]) -> SysResult<Advertisement> {
+    let split = name.find("._").ok_or(SysError::InvalidArgument)?;
+    let (instance, service_type) = (&name[..split], &name[split + 1..]);
+    if instance.is_empty() || instance.len() > INSTANCE_MAX || port == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+    validate_service_type(service_type)?;
+
+    let (reply_port, reply_name) = Port::create_unique("mdns.r.", 4)?;
+    let mut req = RegisterRequest {
+        op: opcodes::REGISTER,
+        port,
+        txt_len: 0,
+        instance: fixed_str(instance),
+        service_type: fixed_str(service_type),
+        txt: [0; TXT_MAX],
+        reply_port: reply_name,
+    };
+
+    let mut len = 0;
+    for entry in txt {
+        if entry.is_empty() || entry.len() > 255 || len + 1 + entry.len() > TXT_MAX {
+            return Err(SysError::InvalidArgument);
+        }
+        req.txt[len] = entry.len() as u8;
+        req.txt[len + 1..len + 1 + entry.len()].copy_from_slice(entry.as_bytes());
+        len += 1 + entry.len();
+    }
+    req.txt_len = len as u16;
+
+    Port::connect(MDNS_PORT)?.send(as_bytes(&req), 0)?;
+    let resp = recv_reply::<MdnsMessage>(
+        &reply_port,
+        opcodes::REGISTERED,
+        core::mem::size_of::<RegisteredResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    let registered = unsafe { resp.registered };
+    Ok(Advertisement {
+        id: registered.registration_id,
+        instance: registered.instance,
+    })
+}
+
+impl Advertisement {
+    /// Nome de instância anunciado (pode ter sido renomeado por conflito,
+    /// ex: `Estação (2)`).
+    pub fn instance(&self) -> &str {
+        str_from_fixed(&self.instance)
+    }
+}
+
+impl Drop for Advertisement {
+    fn drop(&mut self) {
+        let req = UnregisterRequest {
+            op: opcodes::UNREGISTER,
+            registration_id: self.id,
+        };
+        if let Ok(server) = Port::connect(MDNS_PORT) {
+            let _ = server.send(as_bytes(&req), 0);
+        }
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Valida tipo de serviço `_nome._tcp` ou `_nome._udp`.
+fn validate_service_type(service_type: &str) -> SysResult<()> {
+    let valid = service_type.len() <= SERVICE_TYPE_MAX
+        && service_type.starts_with('_')
+        && (service_type.ends_with("._tcp") || service_type.ends_with("._udp"))
+        && service_type.len() > "_._tcp".len();
+    if valid {
+        Ok(())
+    } else {
+        Err(SysError::InvalidArgument)
+    }
+}
@@ -0,0 +1,28 @@
+//! # mDNS
+//!
+//! Descoberta de serviços na rede local (mDNS/DNS-SD) pelo responder do
+//! sistema, para encontrar compartilhamento de arquivos e tela remota de
+//! outras máquinas Redstone sem digitar IPs.
+//!
+//! O responder mantém os anúncios vivos na rede (respostas a consultas,
+//! renomeação em conflitos e "goodbye" ao remover), então o app apenas
+//! guarda o [`Advertisement`] enquanto o serviço estiver disponível.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo mDNS |
+//! | [`client`] | Busca e anúncio de serviços |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    advertise, advertise_with_txt, browse, Advertisement, BrowseEvent, Browser, Service,
+};
+pub use protocol::MDNS_PORT;
@@ -0,0 +1,148 @@
+//! # Protocolo mDNS
+//!
+//! Definições de mensagens do protocolo com o responder mDNS/DNS-SD do
+//! sistema.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do responder mDNS.
+pub const MDNS_PORT: &str = "mdns.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 512;
+
+/// Tamanho máximo do nome de instância (bytes, limite de um label DNS).
+pub const INSTANCE_MAX: usize = 63;
+
+/// Tamanho máximo do tipo de serviço (ex: `_rdshare._tcp`).
+pub const SERVICE_TYPE_MAX: usize = 32;
+
+/// Tamanho máximo do nome de host (ex: `estacao.local`).
+pub const HOST_MAX: usize = 64;
+
+/// Tamanho máximo do registro TXT (strings com prefixo de tamanho).
+pub const TXT_MAX: usize = 192;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const BROWSE: u32 = 0x01;
+    pub const STOP_BROWSE: u32 = 0x02;
+    pub const REGISTER: u32 = 0x03;
+    pub const UNREGISTER: u32 = 0x04;
+
+    // Server -> Client
+    pub const REGISTERED: u32 = 0x10;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> Browser)
+    pub const SERVICE_FOUND: u32 = 0x20;
+    pub const SERVICE_LOST: u32 = 0x21;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de busca (`BROWSE`, `STOP_BROWSE`).
+///
+/// A busca é identificada pela porta de eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct BrowseRequest {
+    pub op: u32,
+    pub service_type: [u8; SERVICE_TYPE_MAX],
+    pub listener_port: [u8; 32],
+}
+
+/// Request de anúncio de serviço.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RegisterRequest {
+    pub op: u32,
+    /// Porta TCP/UDP do serviço.
+    pub port: u16,
+    pub txt_len: u16,
+    pub instance: [u8; INSTANCE_MAX + 1],
+    pub service_type: [u8; SERVICE_TYPE_MAX],
+    pub txt: [u8; TXT_MAX],
+    pub reply_port: [u8; 32],
+}
+
+/// Request de remoção de anúncio.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct UnregisterRequest {
+    pub op: u32,
+    pub registration_id: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Confirmação de anúncio.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RegisteredResponse {
+    pub op: u32,
+    pub registration_id: u32,
+    /// Nome final (renomeado pelo responder em caso de conflito na rede).
+    pub instance: [u8; INSTANCE_MAX + 1],
+}
+
+/// Serviço encontrado ou removido (`SERVICE_FOUND`, `SERVICE_LOST`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ServiceMessage {
+    pub op: u32,
+    pub port: u16,
+    pub txt_len: u16,
+    /// Endereço IPv4 (zeros se ausente).
+    pub ipv4: [u8; 4],
+    /// Endereço IPv6 (zeros se ausente).
+    pub ipv6: [u8; 16],
+    pub instance: [u8; INSTANCE_MAX + 1],
+    pub service_type: [u8; SERVICE_TYPE_MAX],
+    pub host: [u8; HOST_MAX],
+    pub txt: [u8; TXT_MAX],
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union MdnsMessage {
+    pub header: u32,
+    pub browse_req: BrowseRequest,
+    pub register_req: RegisterRequest,
+    pub registered: RegisteredResponse,
+    pub service: ServiceMessage,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for MdnsMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
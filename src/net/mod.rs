@@ -0,0 +1,38 @@
+//! # Rede
+//!
+//! Sockets TCP e locais e clientes de serviços de conectividade.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | `tcp` | [`TcpStream`], [`TcpListener`] e opções de socket |
+//! | [`tls`] | Cliente TLS 1.3 com backend plugável (feature `tls`) |
+//! | [`local`] | Sockets locais com passagem de handles e credenciais |
+//! | [`mdns`] | Descoberta e anúncio de serviços na rede local |
+//! | [`share`] | Cliente de compartilhamento de arquivos (`rdshare`) |
+//! | [`remote`] | Cliente de shell remoto sobre TLS (feature `tls`) |
+//! | [`bluetooth`] | Adaptadores, descoberta, pareamento e conexão Bluetooth |
+//! | [`wifi`] | Varredura, conexão e estado do Wi-Fi |
+
+pub mod bluetooth;
+pub mod local;
+pub mod mdns;
+#[cfg(feature = "tls")]
+pub mod remote;
+pub(crate) mod sha256;
+pub mod share;
+mod socket;
+mod tcp;
+#[cfg(feature = "tls")]
+pub mod tls;
+pub mod wifi;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+pub use local::{LocalListener, LocalStream, PeerCred};
+pub use socket::Shutdown;
+pub use tcp::{TcpListener, TcpStream, DEFAULT_BACKLOG};
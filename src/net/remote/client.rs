@@ -0,0 +1,429 @@
+//! # Remote Client
+//!
+//! Sessão autenticada com um servidor `rdremote` e canais multiplexados.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::remote::{Session, WindowSize};
+//!
+//! let mut session = Session::connect("estacao.local", addr, "ana", "senha")?;
+//! let shell = session.open_shell("xterm-256color", WindowSize::new(80, 24))?;
+//!
+//! session.set_read_timeout(Some(Duration::from_millis(16)))?;
+//!
+//! // Loop do app: canal -> widget de terminal, teclado -> canal
+//! let status = loop {
+//!     match session.pump(shell, &mut terminal) {
+//!         Ok(Some(status)) => break status,
+//!         Ok(None) | Err(SysError::Timeout) => {}
+//!         Err(e) => return Err(e),
+//!     }
+//!     for bytes in terminal.take_input() {
+//!         session.write(shell, &bytes)?;
+//!     }
+//! };
+//! ```
+//!
+//! [`Session::pump`] faz a ponte com qualquer [`Terminal`]: entrega a saída
+//! do canal e propaga mudanças de tamanho da janela.
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::io::{Read, Write};
+use crate::net::tls::{TlsConfig, TlsStream};
+use crate::net::{SocketAddr, TcpStream};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Identificador de canal dentro de uma sessão.
+pub type ChannelId = u32;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Tamanho da janela do terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WindowSize {
+    pub cols: u16,
+    pub rows: u16,
+    /// Largura em pixels (0 = desconhecida).
+    pub width_px: u16,
+    /// Altura em pixels (0 = desconhecida).
+    pub height_px: u16,
+}
+
+impl WindowSize {
+    /// Cria tamanho em células.
+    pub const fn new(cols: u16, rows: u16) -> Self {
+        Self {
+            cols,
+            rows,
+            width_px: 0,
+            height_px: 0,
+        }
+    }
+
+    /// Define o tamanho em pixels.
+    pub const fn with_pixels(mut self, width: u16, height: u16) -> Self {
+        self.width_px = width;
+        self.height_px = height;
+        self
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        for v in [self.cols, self.rows, self.width_px, self.height_px] {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+}
+
+/// Destino da saída de um shell remoto (ex: widget de terminal).
+pub trait Terminal {
+    /// Recebe bytes de saída (stdout e stderr) para interpretar e exibir.
+    fn write_output(&mut self, data: &[u8]) -> SysResult<()>;
+
+    /// Tamanho atual da área do terminal.
+    fn window_size(&self) -> WindowSize;
+}
+
+/// Evento recebido do servidor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEvent {
+    /// Saída padrão do canal.
+    Data(ChannelId, Vec<u8>),
+    /// Saída de erro do canal.
+    Stderr(ChannelId, Vec<u8>),
+    /// O processo remoto fechou a saída.
+    Eof(ChannelId),
+    /// O processo remoto terminou com `status`.
+    Exit(ChannelId, i32),
+    /// Canal encerrado pelo servidor.
+    Closed(ChannelId),
+}
+
+impl SessionEvent {
+    /// Canal do evento.
+    pub fn channel(&self) -> ChannelId {
+        match *self {
+            Self::Data(ch, _) | Self::Stderr(ch, _) => ch,
+            Self::Eof(ch) | Self::Exit(ch, _) | Self::Closed(ch) => ch,
+        }
+    }
+}
+
+/// Estado local de um canal aberto.
+struct ChannelState {
+    id: ChannelId,
+    /// Último tamanho enviado (canais de shell).
+    size: Option<WindowSize>,
+}
+
+// =============================================================================
+// SESSION
+// =============================================================================
+
+/// Sessão autenticada com canais multiplexados.
+pub struct Session<S: Read + Write = TlsStream<TcpStream>> {
+    stream: S,
+    /// Bytes recebidos ainda não formando um quadro completo.
+    rx: Vec<u8>,
+    /// Eventos recebidos enquanto se esperava outra resposta.
+    pending: VecDeque<SessionEvent>,
+    channels: Vec<ChannelState>,
+}
+
+impl Session<TlsStream<TcpStream>> {
+    /// Conecta via TLS a `addr`, validando o certificado para `host`, e
+    /// autentica com usuário e senha.
+    ///
+    /// Usa a configuração instalada com
+    /// [`TlsConfig::install_default`], oferecendo [`REMOTE_ALPN`].
+    pub fn connect(host: &str, addr: SocketAddr, user: &str, password: &str) -> SysResult<Self> {
+        let config = TlsConfig::default_config()?.with_alpn(&[REMOTE_ALPN]);
+        let tcp = TcpStream::connect(addr)?;
+        let _ = tcp.set_nodelay(true);
+        let tls = TlsStream::connect_with(&config, host, tcp)?;
+        if tls.alpn_protocol().is_some_and(|p| p != REMOTE_ALPN) {
+            return Err(SysError::ProtocolError);
+        }
+        Self::authenticate(tls, user, password)
+    }
+
+    /// Define o tempo máximo de espera de [`next_event`](Self::next_event)
+    /// e [`pump`](Self::pump) (`None` = bloquear).
+    ///
+    /// Ao expirar, retornam `SysError::Timeout` sem perder dados parciais.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> SysResult<()> {
+        self.stream.get_ref().set_read_timeout(timeout)
+    }
+}
+
+impl<S: Read + Write> Session<S> {
+    /// Autentica sobre um transporte já estabelecido.
+    ///
+    /// Credenciais rejeitadas retornam `SysError::PermissionDenied`.
+    pub fn authenticate(stream: S, user: &str, password: &str) -> SysResult<Self> {
+        let mut session = Self {
+            stream,
+            rx: Vec::new(),
+            pending: VecDeque::new(),
+            channels: Vec::new(),
+        };
+
+        let mut auth = Vec::new();
+        auth.extend_from_slice(&PROTOCOL_VERSION.to_le_bytes());
+        put_str(&mut auth, user)?;
+        put_str(&mut auth, password)?;
+        session.send(opcodes::AUTH, SESSION_CHANNEL, &auth)?;
+
+        session.expect(opcodes::AUTH_OK)?;
+        Ok(session)
+    }
+
+    /// Abre um shell interativo com terminal `term` (ex: `xterm-256color`).
+    pub fn open_shell(&mut self, term: &str, size: WindowSize) -> SysResult<ChannelId> {
+        let mut req = Vec::new();
+        put_str(&mut req, term)?;
+        size.encode(&mut req);
+        self.send(opcodes::OPEN_SHELL, SESSION_CHANNEL, &req)?;
+        let id = self.expect(opcodes::OPENED)?;
+        self.channels.push(ChannelState {
+            id,
+            size: Some(size),
+        });
+        Ok(id)
+    }
+
+    /// Executa `command` remotamente, sem terminal.
+    pub fn exec(&mut self, command: &str) -> SysResult<ChannelId> {
+        let mut req = Vec::new();
+        put_str(&mut req, command)?;
+        self.send(opcodes::OPEN_EXEC, SESSION_CHANNEL, &req)?;
+        let id = self.expect(opcodes::OPENED)?;
+        self.channels.push(ChannelState { id, size: None });
+        Ok(id)
+    }
+
+    /// Envia bytes para a entrada do canal.
+    pub fn write(&mut self, channel: ChannelId, data: &[u8]) -> SysResult<()> {
+        self.check_open(channel)?;
+        for chunk in data.chunks(MAX_FRAME) {
+            self.send(opcodes::DATA, channel, chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Informa novo tamanho de janela (ignorado se não mudou).
+    pub fn resize(&mut self, channel: ChannelId, size: WindowSize) -> SysResult<()> {
+        let state = self
+            .channels
+            .iter_mut()
+            .find(|c| c.id == channel)
+            .ok_or(SysError::NotFound)?;
+        if state.size.is_none() {
+            // Canal sem terminal
+            return Err(SysError::NotSupported);
+        }
+        if state.size == Some(size) {
+            return Ok(());
+        }
+        state.size = Some(size);
+
+        let mut payload = Vec::with_capacity(8);
+        size.encode(&mut payload);
+        self.send(opcodes::WINDOW_SIZE, channel, &payload)
+    }
+
+    /// Fecha a entrada do canal (o processo remoto recebe EOF).
+    pub fn send_eof(&mut self, channel: ChannelId) -> SysResult<()> {
+        self.check_open(channel)?;
+        self.send(opcodes::EOF, channel, &[])
+    }
+
+    /// Encerra o canal.
+    pub fn close(&mut self, channel: ChannelId) -> SysResult<()> {
+        self.check_open(channel)?;
+        self.channels.retain(|c| c.id != channel);
+        self.send(opcodes::CLOSE, channel, &[])
+    }
+
+    /// Canais abertos.
+    pub fn channels(&self) -> impl Iterator<Item = ChannelId> + '_ {
+        self.channels.iter().map(|c| c.id)
+    }
+
+    /// Espera o próximo evento de qualquer canal.
+    pub fn next_event(&mut self) -> SysResult<SessionEvent> {
+        if let Some(event) = self.pending.pop_front() {
+            return Ok(event);
+        }
+        loop {
+            let (op, channel, payload) = self.recv_frame()?;
+            if let Some(event) = self.channel_event(op, channel, payload)? {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Processa um evento de `channel` com `terminal`: entrega a saída e
+    /// propaga mudanças de tamanho da janela.
+    ///
+    /// Retorna `Some(status)` quando o processo remoto termina. Eventos de
+    /// outros canais ficam guardados para [`next_event`](Self::next_event).
+    pub fn pump<T: Terminal + ?Sized>(
+        &mut self,
+        channel: ChannelId,
+        terminal: &mut T,
+    ) -> SysResult<Option<i32>> {
+        if self
+            .channels
+            .iter()
+            .any(|c| c.id == channel && c.size.is_some())
+        {
+            self.resize(channel, terminal.window_size())?;
+        }
+
+        let event = match self.pending.iter().position(|e| e.channel() == channel) {
+            Some(i) => self.pending.remove(i).ok_or(SysError::NotFound)?,
+            None => loop {
+                let event = self.next_event()?;
+                if event.channel() == channel {
+                    break event;
+                }
+                self.pending.push_back(event);
+            },
+        };
+
+        match event {
+            SessionEvent::Data(_, data) | SessionEvent::Stderr(_, data) => {
+                terminal.write_output(&data)?;
+                Ok(None)
+            }
+            SessionEvent::Eof(_) => Ok(None),
+            SessionEvent::Exit(_, status) => Ok(Some(status)),
+            SessionEvent::Closed(_) => Err(SysError::EndOfFile),
+        }
+    }
+
+    // =========================================================================
+    // QUADROS
+    // =========================================================================
+
+    fn check_open(&self, channel: ChannelId) -> SysResult<()> {
+        if self.channels.iter().any(|c| c.id == channel) {
+            Ok(())
+        } else {
+            Err(SysError::NotFound)
+        }
+    }
+
+    /// Converte um quadro de canal em evento.
+    fn channel_event(
+        &mut self,
+        op: u8,
+        channel: ChannelId,
+        payload: Vec<u8>,
+    ) -> SysResult<Option<SessionEvent>> {
+        let event = match op {
+            opcodes::DATA => SessionEvent::Data(channel, payload),
+            opcodes::STDERR => SessionEvent::Stderr(channel, payload),
+            opcodes::EOF => SessionEvent::Eof(channel),
+            opcodes::EXIT => SessionEvent::Exit(channel, read_i32(&payload)?),
+            opcodes::CLOSE => {
+                let was_open = self.channels.iter().any(|c| c.id == channel);
+                self.channels.retain(|c| c.id != channel);
+                if !was_open {
+                    // Confirmação de um close local
+                    return Ok(None);
+                }
+                SessionEvent::Closed(channel)
+            }
+            _ => return Err(SysError::ProtocolError),
+        };
+        Ok(Some(event))
+    }
+
+    /// Espera a resposta `expected` no canal de sessão, guardando eventos
+    /// de canais que cheguem antes. Retorna o canal do quadro.
+    fn expect(&mut self, expected: u8) -> SysResult<ChannelId> {
+        loop {
+            let (op, channel, payload) = self.recv_frame()?;
+            match op {
+                op if op == expected => return Ok(channel),
+                opcodes::ERROR => {
+                    return Err(SysError::from_code(read_i32(&payload)? as isize));
+                }
+                _ => {
+                    if let Some(event) = self.channel_event(op, channel, payload)? {
+                        self.pending.push_back(event);
+                    }
+                }
+            }
+        }
+    }
+
+    fn send(&mut self, op: u8, channel: ChannelId, payload: &[u8]) -> SysResult<()> {
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        frame.push(op);
+        frame.extend_from_slice(&channel.to_le_bytes());
+        frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame.extend_from_slice(payload);
+        self.stream.write_all(&frame)
+    }
+
+    /// Lê um quadro completo, acumulando leituras parciais em `rx` (uma
+    /// leitura interrompida por timeout não perde dados).
+    fn recv_frame(&mut self) -> SysResult<(u8, ChannelId, Vec<u8>)> {
+        loop {
+            if self.rx.len() >= FRAME_HEADER_LEN {
+                let len =
+                    u32::from_le_bytes([self.rx[5], self.rx[6], self.rx[7], self.rx[8]]) as usize;
+                if len > MAX_FRAME {
+                    return Err(SysError::ProtocolError);
+                }
+                if self.rx.len() >= FRAME_HEADER_LEN + len {
+                    let op = self.rx[0];
+                    let channel =
+                        u32::from_le_bytes([self.rx[1], self.rx[2], self.rx[3], self.rx[4]]);
+                    let payload = self
+                        .rx
+                        .drain(..FRAME_HEADER_LEN + len)
+                        .skip(FRAME_HEADER_LEN)
+                        .collect();
+                    return Ok((op, channel, payload));
+                }
+            }
+
+            let mut chunk = [0u8; 4096];
+            match self.stream.read(&mut chunk)? {
+                0 => return Err(SysError::EndOfFile),
+                n => self.rx.extend_from_slice(&chunk[..n]),
+            }
+        }
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+fn put_str(out: &mut Vec<u8>, s: &str) -> SysResult<()> {
+    let len = u16::try_from(s.len()).map_err(|_| SysError::InvalidArgument)?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+fn read_i32(payload: &[u8]) -> SysResult<i32> {
+    payload
+        .get(..4)
+        .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(SysError::ProtocolError)
+}
@@ -0,0 +1,25 @@
+//! # Console Remoto
+//!
+//! Cliente de shell remoto entre máquinas Redstone (`rdremote`): sessão
+//! autenticada sobre [`TlsStream`](super::tls::TlsStream), canais de shell
+//! interativo (com terminal e tamanho de janela) e de execução de comandos.
+//!
+//! Habilitado pela feature `tls`. Servidores são anunciados via
+//! [`mdns`](super::mdns) com o tipo [`REMOTE_SERVICE_TYPE`].
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Formato de quadros e opcodes |
+//! | [`client`] | Sessão, canais e integração com terminais |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{ChannelId, Session, SessionEvent, Terminal, WindowSize};
+pub use protocol::{REMOTE_ALPN, REMOTE_DEFAULT_PORT, REMOTE_SERVICE_TYPE};
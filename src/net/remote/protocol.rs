@@ -0,0 +1,80 @@
+//! # Protocolo de Console Remoto
+//!
+//! Formato de quadros do protocolo `rdremote`, transportado sobre TLS.
+//!
+//! Cada quadro é `[op: u8][channel: u32 LE][len: u32 LE][payload]`;
+//! inteiros no payload são little-endian e strings têm prefixo `u16` de
+//! tamanho. Quadros de sessão usam o canal 0.
+//!
+//! ## Sessão
+//!
+//! ```text
+//! C -> S  AUTH           version: u32, user: str, password: str
+//! S -> C  AUTH_OK
+//!
+//! C -> S  OPEN_SHELL     term: str, cols: u16, rows: u16, width_px: u16, height_px: u16
+//! C -> S  OPEN_EXEC      command: str
+//! S -> C  OPENED         (canal do quadro = canal atribuído)
+//!
+//! C <-> S DATA           bytes
+//! S -> C  STDERR         bytes
+//! C -> S  WINDOW_SIZE    cols: u16, rows: u16, width_px: u16, height_px: u16
+//! C <-> S EOF
+//! S -> C  EXIT           status: i32
+//! C <-> S CLOSE
+//! ```
+//!
+//! A senha trafega dentro do túnel TLS, cujo certificado é validado pelo
+//! [`ServerCertVerifier`](crate::net::tls::ServerCertVerifier) instalado.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Porta TCP padrão do serviço.
+pub const REMOTE_DEFAULT_PORT: u16 = 7171;
+
+/// Tipo de serviço anunciado via mDNS.
+pub const REMOTE_SERVICE_TYPE: &str = "_rdremote._tcp";
+
+/// Protocolo ALPN negociado no handshake TLS.
+pub const REMOTE_ALPN: &[u8] = b"rdremote/1";
+
+/// Versão do protocolo.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Tamanho do cabeçalho de quadro.
+pub const FRAME_HEADER_LEN: usize = 9;
+
+/// Maior payload aceito.
+pub const MAX_FRAME: usize = 1 << 16;
+
+/// Canal reservado para quadros de sessão.
+pub const SESSION_CHANNEL: u32 = 0;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de quadro (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const AUTH: u8 = 0x01;
+    pub const OPEN_SHELL: u8 = 0x02;
+    pub const OPEN_EXEC: u8 = 0x03;
+    pub const WINDOW_SIZE: u8 = 0x04;
+
+    // Server -> Client
+    pub const AUTH_OK: u8 = 0x10;
+    pub const OPENED: u8 = 0x11;
+    pub const STDERR: u8 = 0x12;
+    pub const EXIT: u8 = 0x13;
+
+    // Ambos os sentidos
+    pub const DATA: u8 = 0x20;
+    pub const EOF: u8 = 0x21;
+    pub const CLOSE: u8 = 0x22;
+
+    /// Payload: código de `SysError` (i32).
+    pub const ERROR: u8 = 0xFF;
+}
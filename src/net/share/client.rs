@@ -0,0 +1,362 @@
+//! # Share Client
+//!
+//! Sessão autenticada com um servidor `rdshare`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::share::{self, Peer};
+//!
+//! let peer = Peer::from_service(&service, "ana", "senha")?;
+//! for entry in share::list(&peer, "/")? {
+//!     println!("{} ({} bytes)", entry.name, entry.size);
+//! }
+//!
+//! let data = share::fetch(&peer, "/fotos/praia.qoi", |done, total| {
+//!     progress_bar.set(done, total);
+//! })?;
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::io::{Read, Write};
+use crate::net::mdns::Service;
+use crate::net::sha256::{hmac, Sha256, HASH_LEN};
+use crate::net::{SocketAddr, TcpStream};
+use crate::syscall::{SysError, SysResult};
+use crate::time::{Instant, SystemTime};
+
+use super::protocol::*;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Servidor de compartilhamento e credenciais de acesso.
+#[derive(Clone)]
+pub struct Peer {
+    addr: SocketAddr,
+    user: String,
+    password: String,
+}
+
+impl Peer {
+    /// Cria peer com endereço e credenciais.
+    pub fn new(addr: SocketAddr, user: &str, password: &str) -> Self {
+        Self {
+            addr,
+            user: String::from(user),
+            password: String::from(password),
+        }
+    }
+
+    /// Cria peer a partir de um serviço encontrado via mDNS.
+    pub fn from_service(service: &Service, user: &str, password: &str) -> SysResult<Self> {
+        let addr = service.addr().ok_or(SysError::NotFound)?;
+        Ok(Self::new(addr, user, password))
+    }
+
+    /// Endereço do servidor.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Usuário.
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+}
+
+impl core::fmt::Debug for Peer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Nunca exibir a senha
+        f.debug_struct("Peer")
+            .field("addr", &self.addr)
+            .field("user", &self.user)
+            .finish()
+    }
+}
+
+impl Drop for Peer {
+    fn drop(&mut self) {
+        // Não deixar a senha no heap
+        for byte in unsafe { self.password.as_bytes_mut() } {
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+    }
+}
+
+/// Tipo de entrada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    File,
+    Directory,
+}
+
+/// Entrada de diretório remoto.
+#[derive(Debug, Clone)]
+pub struct ShareEntry {
+    /// Nome (sem caminho).
+    pub name: String,
+    pub kind: EntryKind,
+    /// Tamanho em bytes (0 para diretórios).
+    pub size: u64,
+    /// Última modificação.
+    pub modified: SystemTime,
+}
+
+impl ShareEntry {
+    /// É diretório?
+    pub fn is_dir(&self) -> bool {
+        self.kind == EntryKind::Directory
+    }
+}
+
+// =============================================================================
+// SESSION
+// =============================================================================
+
+/// Conexão autenticada (reutilizável para várias operações).
+pub struct Session {
+    stream: TcpStream,
+}
+
+impl Session {
+    /// Conecta e autentica no servidor.
+    ///
+    /// Senha incorreta retorna `SysError::PermissionDenied`; um servidor que
+    /// não prova conhecer a senha é rejeitado da mesma forma.
+    pub fn connect(peer: &Peer) -> SysResult<Self> {
+        let mut session = Self {
+            stream: TcpStream::connect(peer.addr)?,
+        };
+        let _ = session.stream.set_nodelay(true);
+
+        let mut hello = Vec::new();
+        hello.extend_from_slice(&PROTOCOL_VERSION.to_le_bytes());
+        put_str(&mut hello, &peer.user)?;
+        session.send(opcodes::HELLO, &hello)?;
+
+        let challenge = session.recv(opcodes::CHALLENGE)?;
+        let server_nonce = Cursor::new(&challenge).bytes(NONCE_LEN)?;
+        let client_nonce = new_nonce(peer);
+
+        let key = peer.password.as_bytes();
+        let proof = hmac(key, &[b"client", server_nonce, &client_nonce]);
+        let mut auth = Vec::with_capacity(NONCE_LEN + HASH_LEN);
+        auth.extend_from_slice(&client_nonce);
+        auth.extend_from_slice(&proof);
+        session.send(opcodes::AUTH, &auth)?;
+
+        let ok = session.recv(opcodes::AUTH_OK)?;
+        let expected = hmac(key, &[b"server", &client_nonce, server_nonce]);
+        if !ct_eq(Cursor::new(&ok).bytes(HASH_LEN)?, &expected) {
+            return Err(SysError::PermissionDenied);
+        }
+        Ok(session)
+    }
+
+    /// Lista o diretório remoto `path`.
+    pub fn list(&mut self, path: &str) -> SysResult<Vec<ShareEntry>> {
+        let mut req = Vec::new();
+        put_str(&mut req, path)?;
+        self.send(opcodes::LIST, &req)?;
+
+        let payload = self.recv(opcodes::ENTRIES)?;
+        let mut cur = Cursor::new(&payload);
+        let count = cur.u32()? as usize;
+        let mut entries = Vec::with_capacity(count.min(1024));
+        for _ in 0..count {
+            let kind = match cur.u8()? {
+                entry_kind::DIRECTORY => EntryKind::Directory,
+                _ => EntryKind::File,
+            };
+            let size = cur.u64()?;
+            let modified = SystemTime::from_unix_millis(cur.u64()?);
+            let name = String::from(cur.str()?);
+            entries.push(ShareEntry {
+                name,
+                kind,
+                size,
+                modified,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Baixa `path` para `dest`, chamando `progress(recebidos, total)` a
+    /// cada bloco. Retorna o total de bytes escritos.
+    pub fn fetch_to<W: Write>(
+        &mut self,
+        path: &str,
+        dest: &mut W,
+        mut progress: impl FnMut(u64, u64),
+    ) -> SysResult<u64> {
+        let mut req = Vec::new();
+        put_str(&mut req, path)?;
+        req.extend_from_slice(&0u64.to_le_bytes());
+        self.send(opcodes::GET, &req)?;
+
+        let info = self.recv(opcodes::FILE_INFO)?;
+        let total = Cursor::new(&info).u64()?;
+        progress(0, total);
+
+        let mut done = 0u64;
+        loop {
+            let chunk = self.recv(opcodes::DATA)?;
+            if chunk.is_empty() {
+                break;
+            }
+            dest.write_all(&chunk)?;
+            done += chunk.len() as u64;
+            progress(done, total.max(done));
+        }
+
+        if done != total {
+            // Arquivo mudou durante a transferência ou conexão truncada
+            return Err(SysError::IoError);
+        }
+        Ok(done)
+    }
+
+    /// Baixa `path` para a memória.
+    pub fn fetch(&mut self, path: &str, progress: impl FnMut(u64, u64)) -> SysResult<Vec<u8>> {
+        let mut data = Vec::new();
+        self.fetch_to(path, &mut data, progress)?;
+        Ok(data)
+    }
+
+    // =========================================================================
+    // QUADROS
+    // =========================================================================
+
+    fn send(&mut self, op: u8, payload: &[u8]) -> SysResult<()> {
+        let mut header = [0u8; FRAME_HEADER_LEN];
+        header[0] = op;
+        header[1..].copy_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.stream.write_all(&header)?;
+        self.stream.write_all(payload)
+    }
+
+    fn recv(&mut self, expected: u8) -> SysResult<Vec<u8>> {
+        let mut header = [0u8; FRAME_HEADER_LEN];
+        self.stream.read_exact(&mut header)?;
+        let len = u32::from_le_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        if len > MAX_FRAME {
+            return Err(SysError::ProtocolError);
+        }
+        let mut payload = alloc::vec![0u8; len];
+        self.stream.read_exact(&mut payload)?;
+
+        match header[0] {
+            op if op == expected => Ok(payload),
+            opcodes::ERROR => Err(SysError::from_code(
+                Cursor::new(&payload).u32()? as i32 as isize
+            )),
+            _ => Err(SysError::ProtocolError),
+        }
+    }
+}
+
+// =============================================================================
+// OPERAÇÕES
+// =============================================================================
+
+/// Lista o diretório `path` do peer (uma sessão por chamada).
+pub fn list(peer: &Peer, path: &str) -> SysResult<Vec<ShareEntry>> {
+    Session::connect(peer)?.list(path)
+}
+
+/// Baixa `path` do peer para a memória.
+pub fn fetch(peer: &Peer, path: &str, progress: impl FnMut(u64, u64)) -> SysResult<Vec<u8>> {
+    Session::connect(peer)?.fetch(path, progress)
+}
+
+/// Baixa `path` do peer para `dest` (ex: um `fs::File`).
+pub fn fetch_to<W: Write>(
+    peer: &Peer,
+    path: &str,
+    dest: &mut W,
+    progress: impl FnMut(u64, u64),
+) -> SysResult<u64> {
+    Session::connect(peer)?.fetch_to(path, dest, progress)
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Nonce único por sessão (não precisa ser secreto, apenas não se repetir).
+fn new_nonce(peer: &Peer) -> [u8; NONCE_LEN] {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut h = Sha256::new();
+    h.update(&COUNTER.fetch_add(1, Ordering::Relaxed).to_le_bytes());
+    if let Ok(now) = Instant::now() {
+        h.update(&now.since_boot().as_nanos().to_le_bytes());
+    }
+    if let Ok(now) = SystemTime::now() {
+        h.update(&now.unix_secs().to_le_bytes());
+        h.update(&now.subsec_nanos().to_le_bytes());
+    }
+    h.update(peer.user.as_bytes());
+    h.update(&crate::process::getpid().to_le_bytes());
+    h.finish()
+}
+
+/// Comparação em tempo constante.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn put_str(out: &mut Vec<u8>, s: &str) -> SysResult<()> {
+    let len = u16::try_from(s.len()).map_err(|_| SysError::InvalidArgument)?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+/// Cursor sobre o payload de um quadro.
+struct Cursor<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn bytes(&mut self, len: usize) -> SysResult<&'a [u8]> {
+        if self.buf.len() < len {
+            return Err(SysError::ProtocolError);
+        }
+        let (head, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> SysResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> SysResult<u32> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u64(&mut self) -> SysResult<u64> {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.bytes(8)?);
+        Ok(u64::from_le_bytes(b))
+    }
+
+    fn str(&mut self) -> SysResult<&'a str> {
+        let b = self.bytes(2)?;
+        let len = u16::from_le_bytes([b[0], b[1]]) as usize;
+        core::str::from_utf8(self.bytes(len)?).map_err(|_| SysError::ProtocolError)
+    }
+}
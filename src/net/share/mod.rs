@@ -0,0 +1,25 @@
+//! # Compartilhamento de Arquivos
+//!
+//! Cliente do serviço de compartilhamento entre máquinas Redstone
+//! (`rdshare`): listagem de diretórios e download de arquivos sobre TCP,
+//! com autenticação mútua por senha.
+//!
+//! Servidores são anunciados via [`mdns`](super::mdns) com o tipo
+//! [`SHARE_SERVICE_TYPE`].
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Formato de quadros e opcodes |
+//! | [`client`] | Sessão autenticada, listagem e download |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{fetch, fetch_to, list, EntryKind, Peer, Session, ShareEntry};
+pub use protocol::{SHARE_DEFAULT_PORT, SHARE_SERVICE_TYPE};
@@ -0,0 +1,77 @@
+//! # Protocolo de Compartilhamento
+//!
+//! Formato de quadros do protocolo `rdshare` sobre TCP.
+//!
+//! Cada quadro é `[op: u8][len: u32 LE][payload: len bytes]`; inteiros no
+//! payload são little-endian e strings têm prefixo `u16` de tamanho.
+//!
+//! ## Sessão
+//!
+//! ```text
+//! C -> S  HELLO      version: u32, user: str
+//! S -> C  CHALLENGE  server_nonce: [u8; 32]
+//! C -> S  AUTH       client_nonce: [u8; 32], proof: [u8; 32]
+//! S -> C  AUTH_OK    proof: [u8; 32]
+//!
+//! C -> S  LIST       path: str
+//! S -> C  ENTRIES    count: u32, (kind: u8, size: u64, modified_ms: u64, name: str)*
+//!
+//! C -> S  GET        path: str, offset: u64
+//! S -> C  FILE_INFO  size: u64
+//! S -> C  DATA*      bytes (vazio = fim)
+//! ```
+//!
+//! As provas são HMAC-SHA256 com a senha do compartilhamento:
+//! `client = HMAC(senha, "client" || server_nonce || client_nonce)` e
+//! `server = HMAC(senha, "server" || client_nonce || server_nonce)`, então
+//! os dois lados provam conhecer a senha sem enviá-la.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Porta TCP padrão do serviço.
+pub const SHARE_DEFAULT_PORT: u16 = 7170;
+
+/// Tipo de serviço anunciado via mDNS.
+pub const SHARE_SERVICE_TYPE: &str = "_rdshare._tcp";
+
+/// Versão do protocolo.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Tamanho do cabeçalho de quadro.
+pub const FRAME_HEADER_LEN: usize = 5;
+
+/// Maior payload aceito.
+pub const MAX_FRAME: usize = 1 << 20;
+
+/// Tamanho dos nonces de autenticação.
+pub const NONCE_LEN: usize = 32;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de quadro (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const HELLO: u8 = 0x01;
+    pub const AUTH: u8 = 0x02;
+    pub const LIST: u8 = 0x03;
+    pub const GET: u8 = 0x04;
+
+    // Server -> Client
+    pub const CHALLENGE: u8 = 0x10;
+    pub const AUTH_OK: u8 = 0x11;
+    pub const ENTRIES: u8 = 0x12;
+    pub const FILE_INFO: u8 = 0x13;
+    pub const DATA: u8 = 0x14;
+    /// Payload: código de `SysError` (i32).
+    pub const ERROR: u8 = 0xFF;
+}
+
+/// Tipos de entrada em `ENTRIES`.
+pub mod entry_kind {
+    pub const FILE: u8 = 0;
+    pub const DIRECTORY: u8 = 1;
+}
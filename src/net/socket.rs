@@ -0,0 +1,281 @@
+//! # Socket
+//!
+//! Camada fina sobre as syscalls de socket (0x90–0x9F), compartilhada pelos
+//! tipos de alto nível (TCP, local).
+
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::time::Duration;
+
+use crate::event::events;
+use crate::io::Handle;
+use crate::ipc::{as_bytes, as_bytes_mut};
+use crate::syscall::safe::{
+    sys_accept, sys_bind, sys_connect, sys_getsockopt, sys_handle_close, sys_listen, sys_peercred,
+    sys_recv, sys_send, sys_setsockopt, sys_shutdown, sys_sock_recvmsg, sys_sock_sendmsg,
+    sys_sockaddr, sys_socket,
+};
+use crate::syscall::{SysError, SysResult};
+
+use super::PeerCred;
+
+// =============================================================================
+// CONSTANTES (ABI)
+// =============================================================================
+
+/// Famílias de endereço.
+pub(crate) mod domain {
+    pub const LOCAL: usize = 1;
+    pub const INET: usize = 2;
+    pub const INET6: usize = 10;
+}
+
+/// Tipos de socket.
+pub(crate) mod sock_type {
+    pub const STREAM: usize = 1;
+}
+
+/// Flags de `SYS_SEND` / `SYS_RECV` / `SYS_ACCEPT` / `SYS_CONNECT`.
+pub(crate) mod msg_flags {
+    /// Lê sem consumir.
+    pub const PEEK: usize = 1 << 0;
+    /// Não bloquear: retorna `SysError::Busy` se a operação bloquearia
+    /// (em `connect`, a conexão segue em andamento).
+    pub const DONTWAIT: usize = 1 << 1;
+}
+
+/// Opções de `SYS_SETSOCKOPT` / `SYS_GETSOCKOPT`.
+pub(crate) mod sockopt {
+    /// TCP_NODELAY (0/1).
+    pub const NODELAY: usize = 1;
+    /// Intervalo de keepalive em segundos (0 = desligado).
+    pub const KEEPALIVE: usize = 2;
+    /// Timeout de leitura em ms (0 = sem timeout).
+    pub const RECV_TIMEOUT: usize = 3;
+    /// Timeout de escrita em ms (0 = sem timeout).
+    pub const SEND_TIMEOUT: usize = 4;
+    /// Tamanho da fila de conexões pendentes.
+    pub const BACKLOG: usize = 5;
+    /// Erro pendente (magnitude do código de `SysError`, 0 = nenhum).
+    pub const ERROR: usize = 6;
+}
+
+/// Seleção de endereço em `SYS_SOCKADDR`.
+const ADDR_LOCAL: usize = 0;
+const ADDR_PEER: usize = 1;
+
+/// Endereço no formato do kernel (IPv4 usa os 4 primeiros bytes de `addr`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawSockAddr {
+    pub family: u16,
+    /// Porta (ordem do host).
+    pub port: u16,
+    pub flowinfo: u32,
+    pub addr: [u8; 16],
+    pub scope_id: u32,
+}
+
+impl RawSockAddr {
+    pub fn from_addr(addr: &SocketAddr) -> Self {
+        match addr {
+            SocketAddr::V4(v4) => {
+                let mut raw = [0u8; 16];
+                raw[..4].copy_from_slice(&v4.ip().octets());
+                Self {
+                    family: domain::INET as u16,
+                    port: v4.port(),
+                    flowinfo: 0,
+                    addr: raw,
+                    scope_id: 0,
+                }
+            }
+            SocketAddr::V6(v6) => Self {
+                family: domain::INET6 as u16,
+                port: v6.port(),
+                flowinfo: v6.flowinfo(),
+                addr: v6.ip().octets(),
+                scope_id: v6.scope_id(),
+            },
+        }
+    }
+
+    pub fn to_addr(self) -> SysResult<SocketAddr> {
+        match self.family as usize {
+            domain::INET => {
+                let ip = Ipv4Addr::new(self.addr[0], self.addr[1], self.addr[2], self.addr[3]);
+                Ok(SocketAddr::V4(SocketAddrV4::new(ip, self.port)))
+            }
+            domain::INET6 => Ok(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(self.addr),
+                self.port,
+                self.flowinfo,
+                self.scope_id,
+            ))),
+            _ => Err(SysError::ProtocolError),
+        }
+    }
+}
+
+/// Família de socket para o endereço.
+pub(crate) fn domain_of(addr: &SocketAddr) -> usize {
+    match addr {
+        SocketAddr::V4(_) => domain::INET,
+        SocketAddr::V6(_) => domain::INET6,
+    }
+}
+
+// =============================================================================
+// SHUTDOWN
+// =============================================================================
+
+/// Direção a encerrar em `shutdown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Shutdown {
+    Read = 0,
+    Write = 1,
+    Both = 2,
+}
+
+// =============================================================================
+// SOCKET
+// =============================================================================
+
+/// Handle de socket do kernel (fechado no drop).
+pub(crate) struct Socket {
+    handle: Handle,
+}
+
+impl Socket {
+    pub fn new(domain: usize, ty: usize) -> SysResult<Self> {
+        Ok(Self {
+            handle: sys_socket(domain, ty, 0)?,
+        })
+    }
+
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Associa a um endereço (`T` no formato do kernel para a família).
+    pub fn bind<T: Copy>(&self, addr: &T) -> SysResult<()> {
+        sys_bind(self.handle, as_bytes(addr))
+    }
+
+    pub fn listen(&self, backlog: u32) -> SysResult<()> {
+        sys_listen(self.handle, backlog)
+    }
+
+    /// Aceita conexão, preenchendo o endereço remoto.
+    pub fn accept<T: Copy>(&self, peer: &mut T, flags: usize) -> SysResult<Socket> {
+        Ok(Socket {
+            handle: sys_accept(self.handle, as_bytes_mut(peer), flags)?,
+        })
+    }
+
+    pub fn connect<T: Copy>(&self, addr: &T, flags: usize) -> SysResult<()> {
+        sys_connect(self.handle, as_bytes(addr), flags)
+    }
+
+    pub fn send(&self, buf: &[u8], flags: usize) -> SysResult<usize> {
+        sys_send(self.handle, buf, flags)
+    }
+
+    pub fn recv(&self, buf: &mut [u8], flags: usize) -> SysResult<usize> {
+        sys_recv(self.handle, buf, flags)
+    }
+
+    pub fn shutdown(&self, how: Shutdown) -> SysResult<()> {
+        sys_shutdown(self.handle, how as usize)
+    }
+
+    pub fn set_option(&self, option: usize, value: usize) -> SysResult<()> {
+        sys_setsockopt(self.handle, option, value)
+    }
+
+    pub fn option(&self, option: usize) -> SysResult<usize> {
+        sys_getsockopt(self.handle, option)
+    }
+
+    /// Define um timeout em ms (`None` = sem timeout; zero é inválido).
+    pub fn set_timeout(&self, option: usize, timeout: Option<Duration>) -> SysResult<()> {
+        let ms = match timeout {
+            Some(d) if d.is_zero() => return Err(SysError::InvalidArgument),
+            Some(d) => (d.as_millis() as usize).max(1),
+            None => 0,
+        };
+        self.set_option(option, ms)
+    }
+
+    pub fn timeout(&self, option: usize) -> SysResult<Option<Duration>> {
+        let ms = self.option(option)?;
+        Ok((ms != 0).then(|| Duration::from_millis(ms as u64)))
+    }
+
+    pub fn sendmsg(&self, data: &[u8], handles: &[Handle], flags: usize) -> SysResult<usize> {
+        sys_sock_sendmsg(self.handle, data, handles, flags)
+    }
+
+    /// Retorna (bytes, handles recebidos).
+    pub fn recvmsg(
+        &self,
+        data: &mut [u8],
+        handles: &mut [Handle],
+        flags: usize,
+    ) -> SysResult<(usize, usize)> {
+        sys_sock_recvmsg(self.handle, data, handles, flags)
+    }
+
+    pub fn peer_cred(&self) -> SysResult<PeerCred> {
+        sys_peercred(self.handle)
+    }
+
+    /// Erro pendente (ex: resultado de `connect` não bloqueante).
+    pub fn take_error(&self) -> SysResult<Option<SysError>> {
+        let code = self.option(sockopt::ERROR)?;
+        Ok((code != 0).then(|| SysError::from_code(-(code as isize))))
+    }
+
+    /// Espera o handle ficar pronto para `events` (no executor).
+    pub async fn ready(&self, events: u16) -> SysResult<()> {
+        crate::task::ready(&self.handle, events).await.map(|_| ())
+    }
+
+    /// `recv` que suspende a tarefa em vez de bloquear.
+    pub async fn recv_async(&self, buf: &mut [u8], flags: usize) -> SysResult<usize> {
+        loop {
+            match self.recv(buf, flags | msg_flags::DONTWAIT) {
+                Err(SysError::Busy) => self.ready(events::IN).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// `send` que suspende a tarefa em vez de bloquear.
+    pub async fn send_async(&self, buf: &[u8]) -> SysResult<usize> {
+        loop {
+            match self.send(buf, msg_flags::DONTWAIT) {
+                Err(SysError::Busy) => self.ready(events::OUT).await?,
+                result => return result,
+            }
+        }
+    }
+
+    pub fn local_addr<T: Copy>(&self, out: &mut T) -> SysResult<()> {
+        self.sockaddr(ADDR_LOCAL, out)
+    }
+
+    pub fn peer_addr<T: Copy>(&self, out: &mut T) -> SysResult<()> {
+        self.sockaddr(ADDR_PEER, out)
+    }
+
+    fn sockaddr<T: Copy>(&self, which: usize, out: &mut T) -> SysResult<()> {
+        sys_sockaddr(self.handle, which, as_bytes_mut(out))
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        let _ = sys_handle_close(self.handle);
+    }
+}
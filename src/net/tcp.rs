@@ -0,0 +1,253 @@
+//! # TCP
+//!
+//! Streams e listeners TCP.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::{SocketAddr, TcpListener, TcpStream};
+//!
+//! let listener = TcpListener::bind("0.0.0.0:8080".parse().unwrap())?;
+//! listener.set_backlog(512)?;
+//!
+//! let (stream, peer) = listener.accept()?;
+//! stream.set_nodelay(true)?;
+//! stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+//! ```
+//!
+//! As variantes `*_async` suspendem a tarefa no [`crate::task::Executor`]
+//! até o socket ficar pronto, em vez de bloquear a thread.
+
+use core::net::SocketAddr;
+use core::time::Duration;
+
+use crate::event::events;
+use crate::io::Handle;
+use crate::syscall::{SysError, SysResult};
+
+use super::socket::{domain_of, msg_flags, sock_type, sockopt, RawSockAddr, Shutdown, Socket};
+
+/// Backlog padrão de [`TcpListener::bind`].
+pub const DEFAULT_BACKLOG: u32 = 128;
+
+// =============================================================================
+// TCP STREAM
+// =============================================================================
+
+/// Conexão TCP.
+pub struct TcpStream {
+    socket: Socket,
+}
+
+impl TcpStream {
+    /// Conecta ao endereço (bloqueia até o handshake).
+    pub fn connect(addr: SocketAddr) -> SysResult<Self> {
+        let socket = Socket::new(domain_of(&addr), sock_type::STREAM)?;
+        socket.connect(&RawSockAddr::from_addr(&addr), 0)?;
+        Ok(Self { socket })
+    }
+
+    /// Conecta sem bloquear a thread (suspende a tarefa no executor).
+    pub async fn connect_async(addr: SocketAddr) -> SysResult<Self> {
+        let socket = Socket::new(domain_of(&addr), sock_type::STREAM)?;
+        match socket.connect(&RawSockAddr::from_addr(&addr), msg_flags::DONTWAIT) {
+            Ok(()) => {}
+            // Handshake em andamento: esperar o socket ficar gravável
+            Err(SysError::Busy) => {
+                socket.ready(events::OUT).await?;
+                if let Some(err) = socket.take_error()? {
+                    return Err(err);
+                }
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(Self { socket })
+    }
+
+    /// Lê dados (0 = conexão encerrada pelo par).
+    pub fn read(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv(buf, 0)
+    }
+
+    /// Lê dados sem removê-los do buffer de recepção.
+    pub fn peek(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv(buf, msg_flags::PEEK)
+    }
+
+    /// Escreve dados, retornando quantos bytes foram aceitos.
+    pub fn write(&self, buf: &[u8]) -> SysResult<usize> {
+        self.socket.send(buf, 0)
+    }
+
+    /// [`read`](Self::read) assíncrono.
+    pub async fn read_async(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv_async(buf, 0).await
+    }
+
+    /// [`peek`](Self::peek) assíncrono.
+    pub async fn peek_async(&self, buf: &mut [u8]) -> SysResult<usize> {
+        self.socket.recv_async(buf, msg_flags::PEEK).await
+    }
+
+    /// [`write`](Self::write) assíncrono.
+    pub async fn write_async(&self, buf: &[u8]) -> SysResult<usize> {
+        self.socket.send_async(buf).await
+    }
+
+    /// Escreve todo o buffer de forma assíncrona.
+    pub async fn write_all_async(&self, mut buf: &[u8]) -> SysResult<()> {
+        while !buf.is_empty() {
+            match self.write_async(buf).await? {
+                0 => return Err(SysError::BrokenPipe),
+                n => buf = &buf[n..],
+            }
+        }
+        Ok(())
+    }
+
+    /// Encerra leitura, escrita ou ambas.
+    pub fn shutdown(&self, how: Shutdown) -> SysResult<()> {
+        self.socket.shutdown(how)
+    }
+
+    /// Endereço do par.
+    pub fn peer_addr(&self) -> SysResult<SocketAddr> {
+        let mut raw = RawSockAddr::default();
+        self.socket.peer_addr(&mut raw)?;
+        raw.to_addr()
+    }
+
+    /// Endereço local.
+    pub fn local_addr(&self) -> SysResult<SocketAddr> {
+        let mut raw = RawSockAddr::default();
+        self.socket.local_addr(&mut raw)?;
+        raw.to_addr()
+    }
+
+    // =========================================================================
+    // OPÇÕES
+    // =========================================================================
+
+    /// Desliga o algoritmo de Nagle (envia segmentos pequenos imediatamente).
+    pub fn set_nodelay(&self, nodelay: bool) -> SysResult<()> {
+        self.socket.set_option(sockopt::NODELAY, nodelay as usize)
+    }
+
+    /// Retorna se o Nagle está desligado.
+    pub fn nodelay(&self) -> SysResult<bool> {
+        Ok(self.socket.option(sockopt::NODELAY)? != 0)
+    }
+
+    /// Liga keepalive com o intervalo dado (`None` desliga).
+    ///
+    /// O intervalo é arredondado para segundos (mínimo 1).
+    pub fn set_keepalive(&self, interval: Option<Duration>) -> SysResult<()> {
+        let secs = interval.map_or(0, |d| d.as_secs().max(1) as usize);
+        self.socket.set_option(sockopt::KEEPALIVE, secs)
+    }
+
+    /// Intervalo de keepalive atual.
+    pub fn keepalive(&self) -> SysResult<Option<Duration>> {
+        let secs = self.socket.option(sockopt::KEEPALIVE)?;
+        Ok((secs != 0).then(|| Duration::from_secs(secs as u64)))
+    }
+
+    /// Timeout de leitura (`None` bloqueia indefinidamente).
+    ///
+    /// Leituras que expiram retornam `SysError::Timeout`. `Some(ZERO)` é
+    /// inválido.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> SysResult<()> {
+        self.socket.set_timeout(sockopt::RECV_TIMEOUT, timeout)
+    }
+
+    /// Timeout de leitura atual.
+    pub fn read_timeout(&self) -> SysResult<Option<Duration>> {
+        self.socket.timeout(sockopt::RECV_TIMEOUT)
+    }
+
+    /// Timeout de escrita (`None` bloqueia indefinidamente).
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> SysResult<()> {
+        self.socket.set_timeout(sockopt::SEND_TIMEOUT, timeout)
+    }
+
+    /// Timeout de escrita atual.
+    pub fn write_timeout(&self) -> SysResult<Option<Duration>> {
+        self.socket.timeout(sockopt::SEND_TIMEOUT)
+    }
+
+    /// Handle do socket (para uso com `event::poll`).
+    pub fn handle(&self) -> &Handle {
+        self.socket.handle()
+    }
+}
+
+impl crate::io::Read for TcpStream {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        TcpStream::read(self, buf)
+    }
+}
+
+impl crate::io::Write for TcpStream {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        TcpStream::write(self, buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        Ok(())
+    }
+}
+
+// =============================================================================
+// TCP LISTENER
+// =============================================================================
+
+/// Socket TCP aceitando conexões.
+pub struct TcpListener {
+    socket: Socket,
+}
+
+impl TcpListener {
+    /// Associa ao endereço e começa a escutar (backlog [`DEFAULT_BACKLOG`]).
+    pub fn bind(addr: SocketAddr) -> SysResult<Self> {
+        let socket = Socket::new(domain_of(&addr), sock_type::STREAM)?;
+        socket.bind(&RawSockAddr::from_addr(&addr))?;
+        socket.listen(DEFAULT_BACKLOG)?;
+        Ok(Self { socket })
+    }
+
+    /// Aceita a próxima conexão (bloqueia).
+    pub fn accept(&self) -> SysResult<(TcpStream, SocketAddr)> {
+        let mut peer = RawSockAddr::default();
+        let socket = self.socket.accept(&mut peer, 0)?;
+        Ok((TcpStream { socket }, peer.to_addr()?))
+    }
+
+    /// [`accept`](Self::accept) assíncrono.
+    pub async fn accept_async(&self) -> SysResult<(TcpStream, SocketAddr)> {
+        let mut peer = RawSockAddr::default();
+        loop {
+            match self.socket.accept(&mut peer, msg_flags::DONTWAIT) {
+                Ok(socket) => return Ok((TcpStream { socket }, peer.to_addr()?)),
+                Err(SysError::Busy) => self.socket.ready(events::IN).await?,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Ajusta o tamanho da fila de conexões pendentes.
+    pub fn set_backlog(&self, backlog: u32) -> SysResult<()> {
+        self.socket.set_option(sockopt::BACKLOG, backlog as usize)
+    }
+
+    /// Endereço local (útil após bind na porta 0).
+    pub fn local_addr(&self) -> SysResult<SocketAddr> {
+        let mut raw = RawSockAddr::default();
+        self.socket.local_addr(&mut raw)?;
+        raw.to_addr()
+    }
+
+    /// Handle do socket (para uso com `event::poll`).
+    pub fn handle(&self) -> &Handle {
+        self.socket.handle()
+    }
+}
@@ -0,0 +1,749 @@
+//! # TLS Client
+//!
+//! Handshake TLS 1.3 (RFC 8446) e camada de registros sobre qualquer
+//! transporte de bytes.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::io::{Read, Write};
+use crate::net::{IpAddr, TcpStream};
+use crate::syscall::{SysError, SysResult};
+
+use super::codec::*;
+use super::provider::{Aead, CipherSuite, CryptoProvider, TlsConfig};
+use crate::net::sha256::{derive_secret, hkdf_expand_label, hkdf_extract, hmac, Sha256, HASH_LEN};
+
+/// Maior fragmento de texto claro por registro.
+const MAX_FRAGMENT: usize = 16384;
+
+/// Maior registro cifrado aceito (fragmento + tipo + padding + tag).
+const MAX_CIPHERTEXT: usize = MAX_FRAGMENT + 256;
+
+/// Maior mensagem de handshake aceita (cadeias de certificados longas
+/// ocupam alguns registros).
+const MAX_HANDSHAKE: usize = 4 * MAX_FRAGMENT;
+
+/// `random` de um HelloRetryRequest (SHA-256 de "HelloRetryRequest").
+const HRR_RANDOM: [u8; 32] = [
+    0xCF, 0x21, 0xAD, 0x74, 0xE5, 0x9A, 0x61, 0x11, 0xBE, 0x1D, 0x8C, 0x02, 0x1E, 0x65, 0xB8, 0x91,
+    0xC2, 0xA2, 0x11, 0x16, 0x7A, 0xBB, 0x8C, 0x5E, 0x07, 0x9E, 0x09, 0xE2, 0xC8, 0xA8, 0x33, 0x9C,
+];
+
+/// Versão TLS 1.3 em `supported_versions`.
+const TLS13: u16 = 0x0304;
+
+// =============================================================================
+// CHAVES DE REGISTRO
+// =============================================================================
+
+/// Chave de tráfego de uma direção.
+struct TrafficKey {
+    aead: Box<dyn Aead>,
+    iv: [u8; 12],
+    seq: u64,
+    secret: [u8; HASH_LEN],
+}
+
+impl TrafficKey {
+    fn new(
+        provider: &dyn CryptoProvider,
+        suite: CipherSuite,
+        secret: [u8; HASH_LEN],
+    ) -> SysResult<Self> {
+        let key = hkdf_expand_label(&secret, "key", &[], suite.key_len());
+        let mut iv = [0u8; 12];
+        iv.copy_from_slice(&hkdf_expand_label(&secret, "iv", &[], 12));
+        Ok(Self {
+            aead: provider.aead(suite, &key)?,
+            iv,
+            seq: 0,
+            secret,
+        })
+    }
+
+    /// Próxima geração da chave (KeyUpdate).
+    fn update(&self, provider: &dyn CryptoProvider, suite: CipherSuite) -> SysResult<Self> {
+        let mut next = [0u8; HASH_LEN];
+        next.copy_from_slice(&hkdf_expand_label(
+            &self.secret,
+            "traffic upd",
+            &[],
+            HASH_LEN,
+        ));
+        Self::new(provider, suite, next)
+    }
+
+    /// Nonce do registro atual (IV XOR número de sequência).
+    fn next_nonce(&mut self) -> SysResult<[u8; 12]> {
+        let mut nonce = self.iv;
+        for (n, s) in nonce[4..].iter_mut().zip(self.seq.to_be_bytes()) {
+            *n ^= s;
+        }
+        self.seq = self.seq.checked_add(1).ok_or(SysError::ProtocolError)?;
+        Ok(nonce)
+    }
+}
+
+/// Chave de Finished derivada de um segredo de handshake.
+fn finished_mac(secret: &[u8], transcript_hash: &[u8]) -> [u8; HASH_LEN] {
+    let key = hkdf_expand_label(secret, "finished", &[], HASH_LEN);
+    hmac(&key, &[transcript_hash])
+}
+
+/// Comparação em tempo constante.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+// =============================================================================
+// TLS STREAM
+// =============================================================================
+
+/// Conexão TLS 1.3 (cliente) sobre um transporte de bytes.
+pub struct TlsStream<S: Read + Write = TcpStream> {
+    transport: S,
+    provider: Arc<dyn CryptoProvider>,
+    suite: CipherSuite,
+    read_key: Option<TrafficKey>,
+    write_key: Option<TrafficKey>,
+    /// Bytes recebidos ainda não processados.
+    rx: Vec<u8>,
+    /// Texto claro de aplicação pendente de leitura.
+    plaintext: Vec<u8>,
+    plaintext_pos: usize,
+    /// Mensagens de handshake parciais.
+    handshake: Vec<u8>,
+    alpn: Option<Vec<u8>>,
+    server_name: String,
+    /// `close_notify` recebido.
+    eof: bool,
+}
+
+impl TlsStream<TcpStream> {
+    /// Executa o handshake com `domain` sobre `tcp`, usando a configuração
+    /// instalada com [`TlsConfig::install_default`].
+    pub fn connect(domain: &str, tcp: TcpStream) -> SysResult<Self> {
+        Self::connect_with(&TlsConfig::default_config()?, domain, tcp)
+    }
+}
+
+impl<S: Read + Write> TlsStream<S> {
+    /// Executa o handshake com `domain` sobre `transport`.
+    pub fn connect_with(config: &TlsConfig, domain: &str, transport: S) -> SysResult<Self> {
+        let suite = *config
+            .provider
+            .cipher_suites()
+            .first()
+            .ok_or(SysError::NotSupported)?;
+
+        let mut stream = Self {
+            transport,
+            provider: config.provider.clone(),
+            suite,
+            read_key: None,
+            write_key: None,
+            rx: Vec::new(),
+            plaintext: Vec::new(),
+            plaintext_pos: 0,
+            handshake: Vec::new(),
+            alpn: None,
+            server_name: String::from(domain),
+            eof: false,
+        };
+
+        if let Err((alert, err)) = stream.handshake(config) {
+            let _ = stream.send_alert(alert);
+            return Err(err);
+        }
+        Ok(stream)
+    }
+
+    /// Nome do servidor validado no certificado.
+    pub fn server_name(&self) -> &str {
+        &self.server_name
+    }
+
+    /// Suite negociada.
+    pub fn cipher_suite(&self) -> CipherSuite {
+        self.suite
+    }
+
+    /// Protocolo ALPN escolhido pelo servidor.
+    pub fn alpn_protocol(&self) -> Option<&[u8]> {
+        self.alpn.as_deref()
+    }
+
+    /// Transporte subjacente.
+    pub fn get_ref(&self) -> &S {
+        &self.transport
+    }
+
+    /// Lê dados de aplicação (0 = servidor encerrou com `close_notify`).
+    pub fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        while self.plaintext_pos >= self.plaintext.len() {
+            if self.eof {
+                return Ok(0);
+            }
+            match self.read_record() {
+                Ok((content_type::APPLICATION_DATA, data)) => {
+                    self.plaintext = data;
+                    self.plaintext_pos = 0;
+                }
+                Ok((content_type::HANDSHAKE, data)) => {
+                    self.handshake.extend_from_slice(&data);
+                    self.post_handshake()?;
+                }
+                Ok(_) => {
+                    let _ = self.send_alert(alert::UNEXPECTED_MESSAGE);
+                    return Err(SysError::ProtocolError);
+                }
+                Err(SysError::EndOfFile) if self.eof => return Ok(0),
+                Err(e) => return Err(e),
+            }
+        }
+
+        let pending = &self.plaintext[self.plaintext_pos..];
+        let n = pending.len().min(buf.len());
+        buf[..n].copy_from_slice(&pending[..n]);
+        self.plaintext_pos += n;
+        Ok(n)
+    }
+
+    /// Escreve dados de aplicação.
+    pub fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        for chunk in buf.chunks(MAX_FRAGMENT) {
+            self.write_record(content_type::APPLICATION_DATA, chunk)?;
+        }
+        Ok(buf.len())
+    }
+
+    /// Envia `close_notify` e devolve o transporte.
+    pub fn close(mut self) -> SysResult<S> {
+        self.send_alert(alert::CLOSE_NOTIFY)?;
+        Ok(self.transport)
+    }
+
+    // =========================================================================
+    // HANDSHAKE
+    // =========================================================================
+
+    fn handshake(&mut self, config: &TlsConfig) -> Result<(), (u8, SysError)> {
+        let decode = |e: SysError| (alert::DECODE_ERROR, e);
+        let provider = config.provider.clone();
+        let mut transcript = Sha256::new();
+
+        // ClientHello
+        let kex = provider
+            .start_key_exchange()
+            .map_err(|e| (alert::HANDSHAKE_FAILURE, e))?;
+        let mut random = [0u8; 32];
+        let mut session_id = [0u8; 32];
+        provider
+            .fill_random(&mut random)
+            .and_then(|_| provider.fill_random(&mut session_id))
+            .map_err(|e| (alert::HANDSHAKE_FAILURE, e))?;
+
+        let hello = self.client_hello(config, &random, &session_id, kex.group(), kex.public_key());
+        transcript.update(&hello);
+        self.write_record(content_type::HANDSHAKE, &hello)
+            .map_err(|e| (alert::HANDSHAKE_FAILURE, e))?;
+
+        // ServerHello
+        let (ty, msg) = self.next_handshake().map_err(decode)?;
+        if ty != handshake_type::SERVER_HELLO {
+            return Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError));
+        }
+        let (suite, server_share) = self
+            .parse_server_hello(&msg[4..], &session_id, kex.group())
+            .map_err(|e| match e {
+                SysError::NotSupported => (alert::HANDSHAKE_FAILURE, e),
+                _ => (alert::ILLEGAL_PARAMETER, e),
+            })?;
+        transcript.update(&msg);
+        self.suite = suite;
+        self.key_boundary()?;
+
+        let shared = kex
+            .complete(&server_share)
+            .map_err(|e| (alert::ILLEGAL_PARAMETER, e))?;
+
+        // Key schedule
+        let zeros = [0u8; HASH_LEN];
+        let empty_hash = Sha256::new().finish();
+        let early = hkdf_extract(&[], &zeros);
+        let derived = derive_secret(&early, "derived", &empty_hash);
+        let handshake_secret = hkdf_extract(&derived, &shared);
+        let hello_hash = transcript.clone().finish();
+        let client_hs = derive_secret(&handshake_secret, "c hs traffic", &hello_hash);
+        let server_hs = derive_secret(&handshake_secret, "s hs traffic", &hello_hash);
+        let derived = derive_secret(&handshake_secret, "derived", &empty_hash);
+        let master = hkdf_extract(&derived, &zeros);
+
+        self.read_key = Some(
+            TrafficKey::new(&*provider, suite, server_hs)
+                .map_err(|e| (alert::HANDSHAKE_FAILURE, e))?,
+        );
+
+        // EncryptedExtensions
+        let (ty, msg) = self.next_handshake().map_err(decode)?;
+        if ty != handshake_type::ENCRYPTED_EXTENSIONS {
+            return Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError));
+        }
+        self.alpn = parse_encrypted_extensions(&msg[4..], &config.alpn)
+            .map_err(|e| (alert::ILLEGAL_PARAMETER, e))?;
+        transcript.update(&msg);
+
+        // CertificateRequest (opcional) e Certificate
+        let (mut ty, mut msg) = self.next_handshake().map_err(decode)?;
+        let mut cert_request_context = None;
+        if ty == handshake_type::CERTIFICATE_REQUEST {
+            let mut r = Reader::new(&msg[4..]);
+            cert_request_context = Some(r.vec8().map_err(decode)?.to_vec());
+            transcript.update(&msg);
+            (ty, msg) = self.next_handshake().map_err(decode)?;
+        }
+        if ty != handshake_type::CERTIFICATE {
+            return Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError));
+        }
+        let chain = parse_certificate(&msg[4..]).map_err(decode)?;
+        let end_entity = chain
+            .first()
+            .cloned()
+            .ok_or((alert::BAD_CERTIFICATE, SysError::ProtocolError))?;
+        {
+            let refs: Vec<&[u8]> = chain.iter().map(|c| c.as_slice()).collect();
+            config
+                .verifier
+                .verify_server_cert(&refs, &self.server_name)
+                .map_err(|e| (alert::BAD_CERTIFICATE, e))?;
+        }
+        transcript.update(&msg);
+
+        // CertificateVerify
+        let (ty, msg) = self.next_handshake().map_err(decode)?;
+        if ty != handshake_type::CERTIFICATE_VERIFY {
+            return Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError));
+        }
+        let (scheme, signature) = {
+            let mut r = Reader::new(&msg[4..]);
+            let scheme = r.u16().map_err(decode)?;
+            (scheme, r.vec16().map_err(decode)?.to_vec())
+        };
+        if !config.verifier.supported_schemes().contains(&scheme) {
+            return Err((alert::ILLEGAL_PARAMETER, SysError::NotSupported));
+        }
+        let mut signed = Vec::with_capacity(64 + 34 + HASH_LEN);
+        signed.extend_from_slice(&[0x20; 64]);
+        signed.extend_from_slice(b"TLS 1.3, server CertificateVerify\0");
+        signed.extend_from_slice(&transcript.clone().finish());
+        config
+            .verifier
+            .verify_signature(scheme, &signed, &end_entity, &signature)
+            .map_err(|e| (alert::DECRYPT_ERROR, e))?;
+        transcript.update(&msg);
+
+        // Finished do servidor
+        let (ty, msg) = self.next_handshake().map_err(decode)?;
+        if ty != handshake_type::FINISHED {
+            return Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError));
+        }
+        let expected = finished_mac(&server_hs, &transcript.clone().finish());
+        if !ct_eq(&msg[4..], &expected) {
+            return Err((alert::DECRYPT_ERROR, SysError::PermissionDenied));
+        }
+        transcript.update(&msg);
+        self.key_boundary()?;
+
+        let server_hash = transcript.clone().finish();
+        let client_ap = derive_secret(&master, "c ap traffic", &server_hash);
+        let server_ap = derive_secret(&master, "s ap traffic", &server_hash);
+
+        // Voo do cliente: CCS de compatibilidade, Certificate vazio se
+        // solicitado e Finished.
+        let io_err = |e: SysError| (alert::HANDSHAKE_FAILURE, e);
+        self.write_record(content_type::CHANGE_CIPHER_SPEC, &[1])
+            .map_err(io_err)?;
+        self.write_key = Some(TrafficKey::new(&*provider, suite, client_hs).map_err(io_err)?);
+
+        if let Some(context) = cert_request_context {
+            let cert = handshake_message(handshake_type::CERTIFICATE, |b| {
+                put_vec8(b, |b| b.extend_from_slice(&context));
+                put_vec24(b, |_| {});
+            });
+            transcript.update(&cert);
+            self.write_record(content_type::HANDSHAKE, &cert)
+                .map_err(io_err)?;
+        }
+
+        let verify = finished_mac(&client_hs, &transcript.clone().finish());
+        let finished =
+            handshake_message(handshake_type::FINISHED, |b| b.extend_from_slice(&verify));
+        self.write_record(content_type::HANDSHAKE, &finished)
+            .map_err(io_err)?;
+
+        self.read_key = Some(TrafficKey::new(&*provider, suite, server_ap).map_err(io_err)?);
+        self.write_key = Some(TrafficKey::new(&*provider, suite, client_ap).map_err(io_err)?);
+        Ok(())
+    }
+
+    fn client_hello(
+        &self,
+        config: &TlsConfig,
+        random: &[u8; 32],
+        session_id: &[u8; 32],
+        group: u16,
+        public_key: &[u8],
+    ) -> Vec<u8> {
+        let send_sni = self.server_name.parse::<IpAddr>().is_err();
+
+        handshake_message(handshake_type::CLIENT_HELLO, |b| {
+            put_u16(b, 0x0303);
+            b.extend_from_slice(random);
+            put_vec8(b, |b| b.extend_from_slice(session_id));
+            put_vec16(b, |b| {
+                for suite in config.provider.cipher_suites() {
+                    put_u16(b, *suite as u16);
+                }
+            });
+            put_vec8(b, |b| b.push(0));
+
+            put_vec16(b, |b| {
+                if send_sni {
+                    put_extension(b, extension::SERVER_NAME, |b| {
+                        put_vec16(b, |b| {
+                            b.push(0);
+                            put_vec16(b, |b| b.extend_from_slice(self.server_name.as_bytes()));
+                        });
+                    });
+                }
+                put_extension(b, extension::SUPPORTED_GROUPS, |b| {
+                    put_vec16(b, |b| put_u16(b, group));
+                });
+                put_extension(b, extension::SIGNATURE_ALGORITHMS, |b| {
+                    put_vec16(b, |b| {
+                        for scheme in config.verifier.supported_schemes() {
+                            put_u16(b, *scheme);
+                        }
+                    });
+                });
+                put_extension(b, extension::SUPPORTED_VERSIONS, |b| {
+                    put_vec8(b, |b| put_u16(b, TLS13));
+                });
+                put_extension(b, extension::KEY_SHARE, |b| {
+                    put_vec16(b, |b| {
+                        put_u16(b, group);
+                        put_vec16(b, |b| b.extend_from_slice(public_key));
+                    });
+                });
+                if !config.alpn.is_empty() {
+                    put_extension(b, extension::ALPN, |b| {
+                        put_vec16(b, |b| {
+                            for proto in &config.alpn {
+                                put_vec8(b, |b| b.extend_from_slice(proto));
+                            }
+                        });
+                    });
+                }
+            });
+        })
+    }
+
+    /// Valida o ServerHello e retorna a suite e a chave pública do servidor.
+    fn parse_server_hello(
+        &self,
+        body: &[u8],
+        session_id: &[u8],
+        group: u16,
+    ) -> SysResult<(CipherSuite, Vec<u8>)> {
+        let mut r = Reader::new(body);
+        r.u16()?;
+        if r.bytes(32)? == HRR_RANDOM {
+            // HelloRetryRequest: só oferecemos um grupo
+            return Err(SysError::NotSupported);
+        }
+        if r.vec8()? != session_id {
+            return Err(SysError::ProtocolError);
+        }
+        let suite = CipherSuite::from_u16(r.u16()?)
+            .filter(|s| self.provider.cipher_suites().contains(s))
+            .ok_or(SysError::ProtocolError)?;
+        if r.u8()? != 0 {
+            return Err(SysError::ProtocolError);
+        }
+
+        let mut version = 0;
+        let mut share = None;
+        let mut exts = Reader::new(r.vec16()?);
+        while !exts.is_empty() {
+            let ty = exts.u16()?;
+            let mut data = Reader::new(exts.vec16()?);
+            match ty {
+                extension::SUPPORTED_VERSIONS => version = data.u16()?,
+                extension::KEY_SHARE => {
+                    if data.u16()? != group {
+                        return Err(SysError::ProtocolError);
+                    }
+                    share = Some(data.vec16()?.to_vec());
+                }
+                _ => {}
+            }
+        }
+
+        if version != TLS13 {
+            return Err(SysError::NotSupported);
+        }
+        Ok((suite, share.ok_or(SysError::ProtocolError)?))
+    }
+
+    /// Processa mensagens de handshake após o handshake (tickets, KeyUpdate).
+    fn post_handshake(&mut self) -> SysResult<()> {
+        loop {
+            let (ty, msg) = match self.take_handshake() {
+                Ok(Some(msg)) => msg,
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    let _ = self.send_alert(alert::DECODE_ERROR);
+                    return Err(e);
+                }
+            };
+            match ty {
+                handshake_type::NEW_SESSION_TICKET => {}
+                handshake_type::KEY_UPDATE => {
+                    // KeyUpdateRequest: 0 = update_not_requested, 1 = update_requested
+                    let request = match msg[4..] {
+                        [request @ (0 | 1)] => request,
+                        [_] => {
+                            let _ = self.send_alert(alert::ILLEGAL_PARAMETER);
+                            return Err(SysError::ProtocolError);
+                        }
+                        _ => {
+                            let _ = self.send_alert(alert::DECODE_ERROR);
+                            return Err(SysError::ProtocolError);
+                        }
+                    };
+                    if let Err((desc, e)) = self.key_boundary() {
+                        let _ = self.send_alert(desc);
+                        return Err(e);
+                    }
+                    let provider = self.provider.clone();
+                    if let Some(key) = &self.read_key {
+                        self.read_key = Some(key.update(&*provider, self.suite)?);
+                    }
+                    if request == 1 {
+                        let reply = handshake_message(handshake_type::KEY_UPDATE, |b| b.push(0));
+                        self.write_record(content_type::HANDSHAKE, &reply)?;
+                        if let Some(key) = &self.write_key {
+                            self.write_key = Some(key.update(&*provider, self.suite)?);
+                        }
+                    }
+                }
+                _ => {
+                    let _ = self.send_alert(alert::UNEXPECTED_MESSAGE);
+                    return Err(SysError::ProtocolError);
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // CAMADA DE REGISTROS
+    // =========================================================================
+
+    /// Próxima mensagem de handshake completa (tipo, mensagem com cabeçalho).
+    fn next_handshake(&mut self) -> SysResult<(u8, Vec<u8>)> {
+        loop {
+            if let Some(msg) = self.take_handshake()? {
+                return Ok(msg);
+            }
+            match self.read_record()? {
+                (content_type::HANDSHAKE, data) => self.handshake.extend_from_slice(&data),
+                // CCS de compatibilidade com middleboxes
+                (content_type::CHANGE_CIPHER_SPEC, _) if self.write_key.is_none() => {}
+                _ => return Err(SysError::ProtocolError),
+            }
+        }
+    }
+
+    /// Troca de chaves só no fim de um registro: nada de handshake pode ter
+    /// chegado sob a chave antiga além da mensagem que a encerra (RFC 8446 §5.1).
+    fn key_boundary(&self) -> Result<(), (u8, SysError)> {
+        if self.handshake.is_empty() {
+            Ok(())
+        } else {
+            Err((alert::UNEXPECTED_MESSAGE, SysError::ProtocolError))
+        }
+    }
+
+    /// Extrai uma mensagem de handshake completa do buffer, se houver.
+    ///
+    /// `ProtocolError` (alerta `decode_error`) se a mensagem declarar mais
+    /// que [`MAX_HANDSHAKE`] bytes.
+    fn take_handshake(&mut self) -> SysResult<Option<(u8, Vec<u8>)>> {
+        if self.handshake.len() < 4 {
+            return Ok(None);
+        }
+        let len = Reader::new(&self.handshake[1..4]).u24()?;
+        if len > MAX_HANDSHAKE {
+            return Err(SysError::ProtocolError);
+        }
+        if self.handshake.len() < 4 + len {
+            return Ok(None);
+        }
+        let msg: Vec<u8> = self.handshake.drain(..4 + len).collect();
+        Ok(Some((msg[0], msg)))
+    }
+
+    /// Lê e decifra um registro (tipo real, conteúdo).
+    fn read_record(&mut self) -> SysResult<(u8, Vec<u8>)> {
+        loop {
+            if self.rx.len() >= 5 {
+                let len = u16::from_be_bytes([self.rx[3], self.rx[4]]) as usize;
+                if len > MAX_CIPHERTEXT {
+                    return Err(SysError::ProtocolError);
+                }
+                if self.rx.len() >= 5 + len {
+                    break;
+                }
+            }
+            let mut chunk = [0u8; 4096];
+            match self.transport.read(&mut chunk)? {
+                0 => return Err(SysError::EndOfFile),
+                n => self.rx.extend_from_slice(&chunk[..n]),
+            }
+        }
+
+        let len = u16::from_be_bytes([self.rx[3], self.rx[4]]) as usize;
+        let header: [u8; 5] = [self.rx[0], self.rx[1], self.rx[2], self.rx[3], self.rx[4]];
+        let mut payload: Vec<u8> = self.rx.drain(..5 + len).skip(5).collect();
+        let mut ty = header[0];
+
+        if ty == content_type::APPLICATION_DATA {
+            let key = self.read_key.as_mut().ok_or(SysError::ProtocolError)?;
+            let nonce = key.next_nonce()?;
+            if key.aead.open(&nonce, &header, &mut payload).is_err() {
+                let _ = self.send_alert(alert::BAD_RECORD_MAC);
+                return Err(SysError::ProtocolError);
+            }
+            // Remove padding e extrai o tipo interno
+            while payload.last() == Some(&0) {
+                payload.pop();
+            }
+            ty = payload.pop().ok_or(SysError::ProtocolError)?;
+        } else if self.read_key.is_some() && ty != content_type::CHANGE_CIPHER_SPEC {
+            return Err(SysError::ProtocolError);
+        }
+
+        if ty == content_type::ALERT {
+            return match payload.get(1) {
+                Some(&alert::CLOSE_NOTIFY) => {
+                    self.eof = true;
+                    Err(SysError::EndOfFile)
+                }
+                Some(&alert::PROTOCOL_VERSION) => Err(SysError::NotSupported),
+                _ => Err(SysError::ProtocolError),
+            };
+        }
+        Ok((ty, payload))
+    }
+
+    /// Cifra (se houver chave) e envia um registro.
+    fn write_record(&mut self, ty: u8, data: &[u8]) -> SysResult<()> {
+        let record = match self.write_key.as_mut() {
+            Some(key) => {
+                let mut payload = Vec::with_capacity(data.len() + 17);
+                payload.extend_from_slice(data);
+                payload.push(ty);
+                let len = (payload.len() + 16) as u16;
+                let mut header = [content_type::APPLICATION_DATA, 0x03, 0x03, 0, 0];
+                header[3..].copy_from_slice(&len.to_be_bytes());
+
+                let nonce = key.next_nonce()?;
+                key.aead.seal(&nonce, &header, &mut payload)?;
+                if payload.len() != len as usize {
+                    return Err(SysError::ProtocolError);
+                }
+                let mut record = header.to_vec();
+                record.append(&mut payload);
+                record
+            }
+            None => {
+                // 0x0301 só no ClientHello (compatibilidade), 0x0303 depois
+                let minor = if self.read_key.is_none() { 0x01 } else { 0x03 };
+                let mut record = Vec::with_capacity(5 + data.len());
+                record.extend_from_slice(&[ty, 0x03, minor]);
+                put_u16(&mut record, data.len() as u16);
+                record.extend_from_slice(data);
+                record
+            }
+        };
+        self.transport.write_all(&record)
+    }
+
+    /// Envia um alerta (fatal, exceto `close_notify`).
+    fn send_alert(&mut self, description: u8) -> SysResult<()> {
+        let level = if description == alert::CLOSE_NOTIFY {
+            1
+        } else {
+            2
+        };
+        self.write_record(content_type::ALERT, &[level, description])
+    }
+}
+
+impl<S: Read + Write> Read for TlsStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> SysResult<usize> {
+        TlsStream::read(self, buf)
+    }
+}
+
+impl<S: Read + Write> Write for TlsStream<S> {
+    fn write(&mut self, buf: &[u8]) -> SysResult<usize> {
+        TlsStream::write(self, buf)
+    }
+
+    fn flush(&mut self) -> SysResult<()> {
+        self.transport.flush()
+    }
+}
+
+// =============================================================================
+// PARSERS
+// =============================================================================
+
+/// Extrai o protocolo ALPN escolhido, validando contra os oferecidos.
+fn parse_encrypted_extensions(body: &[u8], offered: &[Vec<u8>]) -> SysResult<Option<Vec<u8>>> {
+    let mut r = Reader::new(body);
+    let mut exts = Reader::new(r.vec16()?);
+    let mut alpn = None;
+    while !exts.is_empty() {
+        let ty = exts.u16()?;
+        let data = exts.vec16()?;
+        if ty == extension::ALPN {
+            let mut list = Reader::new(Reader::new(data).vec16()?);
+            let proto = list.vec8()?;
+            if !offered.iter().any(|p| p.as_slice() == proto) {
+                return Err(SysError::ProtocolError);
+            }
+            alpn = Some(proto.to_vec());
+        }
+    }
+    Ok(alpn)
+}
+
+/// Extrai a cadeia de certificados (DER).
+fn parse_certificate(body: &[u8]) -> SysResult<Vec<Vec<u8>>> {
+    let mut r = Reader::new(body);
+    r.vec8()?;
+    let mut list = Reader::new(r.vec24()?);
+    let mut chain = Vec::new();
+    while !list.is_empty() {
+        chain.push(list.vec24()?.to_vec());
+        list.vec16()?;
+    }
+    Ok(chain)
+}
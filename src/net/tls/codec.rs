@@ -0,0 +1,161 @@
+//! # Codec
+//!
+//! Leitura e escrita dos campos com prefixo de tamanho do TLS.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::syscall::{SysError, SysResult};
+
+/// Tipos de registro (ContentType).
+pub mod content_type {
+    pub const CHANGE_CIPHER_SPEC: u8 = 20;
+    pub const ALERT: u8 = 21;
+    pub const HANDSHAKE: u8 = 22;
+    pub const APPLICATION_DATA: u8 = 23;
+}
+
+/// Tipos de mensagem de handshake (HandshakeType).
+pub mod handshake_type {
+    pub const CLIENT_HELLO: u8 = 1;
+    pub const SERVER_HELLO: u8 = 2;
+    pub const NEW_SESSION_TICKET: u8 = 4;
+    pub const ENCRYPTED_EXTENSIONS: u8 = 8;
+    pub const CERTIFICATE: u8 = 11;
+    pub const CERTIFICATE_REQUEST: u8 = 13;
+    pub const CERTIFICATE_VERIFY: u8 = 15;
+    pub const FINISHED: u8 = 20;
+    pub const KEY_UPDATE: u8 = 24;
+}
+
+/// Tipos de extensão (ExtensionType).
+pub mod extension {
+    pub const SERVER_NAME: u16 = 0;
+    pub const SUPPORTED_GROUPS: u16 = 10;
+    pub const SIGNATURE_ALGORITHMS: u16 = 13;
+    pub const ALPN: u16 = 16;
+    pub const SUPPORTED_VERSIONS: u16 = 43;
+    pub const KEY_SHARE: u16 = 51;
+}
+
+/// Descrições de alerta (AlertDescription).
+pub mod alert {
+    pub const CLOSE_NOTIFY: u8 = 0;
+    pub const UNEXPECTED_MESSAGE: u8 = 10;
+    pub const BAD_RECORD_MAC: u8 = 20;
+    pub const HANDSHAKE_FAILURE: u8 = 40;
+    pub const BAD_CERTIFICATE: u8 = 42;
+    pub const ILLEGAL_PARAMETER: u8 = 47;
+    pub const DECODE_ERROR: u8 = 50;
+    pub const DECRYPT_ERROR: u8 = 51;
+    pub const PROTOCOL_VERSION: u8 = 70;
+}
+
+// =============================================================================
+// LEITURA
+// =============================================================================
+
+/// Cursor sobre uma mensagem recebida.
+pub struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    pub fn bytes(&mut self, len: usize) -> SysResult<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or(SysError::ProtocolError)?;
+        let out = self.buf.get(self.pos..end).ok_or(SysError::ProtocolError)?;
+        self.pos = end;
+        Ok(out)
+    }
+
+    pub fn u8(&mut self) -> SysResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> SysResult<u16> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    pub fn u24(&mut self) -> SysResult<usize> {
+        let b = self.bytes(3)?;
+        Ok(((b[0] as usize) << 16) | ((b[1] as usize) << 8) | b[2] as usize)
+    }
+
+    /// Campo com prefixo de 1 byte.
+    pub fn vec8(&mut self) -> SysResult<&'a [u8]> {
+        let len = self.u8()? as usize;
+        self.bytes(len)
+    }
+
+    /// Campo com prefixo de 2 bytes.
+    pub fn vec16(&mut self) -> SysResult<&'a [u8]> {
+        let len = self.u16()? as usize;
+        self.bytes(len)
+    }
+
+    /// Campo com prefixo de 3 bytes.
+    pub fn vec24(&mut self) -> SysResult<&'a [u8]> {
+        let len = self.u24()?;
+        self.bytes(len)
+    }
+}
+
+// =============================================================================
+// ESCRITA
+// =============================================================================
+
+/// Escreve `u16` big-endian.
+pub fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Escreve um campo com prefixo de 1 byte preenchido por `f`.
+pub fn put_vec8(out: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>)) {
+    let start = out.len();
+    out.push(0);
+    f(out);
+    out[start] = (out.len() - start - 1) as u8;
+}
+
+/// Escreve um campo com prefixo de 2 bytes preenchido por `f`.
+pub fn put_vec16(out: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>)) {
+    let start = out.len();
+    out.extend_from_slice(&[0, 0]);
+    f(out);
+    let len = (out.len() - start - 2) as u16;
+    out[start..start + 2].copy_from_slice(&len.to_be_bytes());
+}
+
+/// Escreve um campo com prefixo de 3 bytes preenchido por `f`.
+pub fn put_vec24(out: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>)) {
+    let start = out.len();
+    out.extend_from_slice(&[0, 0, 0]);
+    f(out);
+    let len = (out.len() - start - 3) as u32;
+    out[start..start + 3].copy_from_slice(&len.to_be_bytes()[1..]);
+}
+
+/// Escreve uma extensão.
+pub fn put_extension(out: &mut Vec<u8>, ty: u16, f: impl FnOnce(&mut Vec<u8>)) {
+    put_u16(out, ty);
+    put_vec16(out, f);
+}
+
+/// Monta uma mensagem de handshake (tipo + tamanho + corpo).
+pub fn handshake_message(ty: u8, f: impl FnOnce(&mut Vec<u8>)) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.push(ty);
+    put_vec24(&mut out, f);
+    out
+}
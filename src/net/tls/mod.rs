@@ -0,0 +1,44 @@
+//! # TLS
+//!
+//! Cliente TLS 1.3 sobre [`TcpStream`](super::TcpStream) (ou qualquer
+//! transporte `Read + Write`), habilitado pela feature `tls`.
+//!
+//! O SDK implementa o protocolo (handshake, key schedule com SHA-256 e
+//! camada de registros); as primitivas criptográficas e a validação de
+//! certificados vêm de um backend plugável ([`CryptoProvider`] e
+//! [`ServerCertVerifier`]).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | `client` | [`TlsStream`], handshake e camada de registros |
+//! | `provider` | Traits do backend e [`TlsConfig`] |
+//! | `codec` | Campos com prefixo de tamanho e constantes do protocolo |
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::tls::{TlsConfig, TlsStream};
+//! use redpowder::net::TcpStream;
+//!
+//! TlsConfig::new(provider, verifier).install_default();
+//!
+//! let tcp = TcpStream::connect(addr)?;
+//! let mut tls = TlsStream::connect("example.org", tcp)?;
+//! tls.write(b"GET / HTTP/1.1\r\nHost: example.org\r\n\r\n")?;
+//! ```
+
+mod client;
+mod codec;
+mod provider;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::TlsStream;
+pub use provider::{
+    named_group, signature_scheme, Aead, CipherSuite, CryptoProvider, KeyExchange,
+    ServerCertVerifier, TlsConfig,
+};
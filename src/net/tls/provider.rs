@@ -0,0 +1,160 @@
+//! # Backend de Criptografia
+//!
+//! Traits que um backend implementa para o cliente TLS: troca de chaves,
+//! cifra AEAD, aleatoriedade e validação de certificados.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::syscall::{SysError, SysResult};
+use crate::task::Lock;
+
+// =============================================================================
+// IDENTIFICADORES
+// =============================================================================
+
+/// Cipher suites TLS 1.3 suportadas pelo key schedule (SHA-256).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum CipherSuite {
+    Aes128GcmSha256 = 0x1301,
+    Chacha20Poly1305Sha256 = 0x1303,
+}
+
+impl CipherSuite {
+    /// Tamanho da chave AEAD (bytes).
+    pub fn key_len(&self) -> usize {
+        match self {
+            Self::Aes128GcmSha256 => 16,
+            Self::Chacha20Poly1305Sha256 => 32,
+        }
+    }
+
+    pub(super) fn from_u16(value: u16) -> Option<Self> {
+        match value {
+            0x1301 => Some(Self::Aes128GcmSha256),
+            0x1303 => Some(Self::Chacha20Poly1305Sha256),
+            _ => None,
+        }
+    }
+}
+
+/// Grupos de troca de chaves (NamedGroup).
+pub mod named_group {
+    pub const SECP256R1: u16 = 0x0017;
+    pub const X25519: u16 = 0x001D;
+}
+
+/// Esquemas de assinatura (SignatureScheme).
+pub mod signature_scheme {
+    pub const RSA_PKCS1_SHA256: u16 = 0x0401;
+    pub const ECDSA_SECP256R1_SHA256: u16 = 0x0403;
+    pub const RSA_PSS_RSAE_SHA256: u16 = 0x0804;
+    pub const ED25519: u16 = 0x0807;
+}
+
+// =============================================================================
+// TRAITS
+// =============================================================================
+
+/// Troca de chaves efêmera (um uso).
+pub trait KeyExchange: Send {
+    /// Grupo (`named_group`).
+    fn group(&self) -> u16;
+
+    /// Chave pública a enviar no `key_share`.
+    fn public_key(&self) -> &[u8];
+
+    /// Calcula o segredo compartilhado com a chave pública do servidor.
+    fn complete(self: Box<Self>, peer_public_key: &[u8]) -> SysResult<Vec<u8>>;
+}
+
+/// Cifra AEAD com chave fixa.
+pub trait Aead: Send + Sync {
+    /// Cifra `buf` no lugar e anexa a tag.
+    fn seal(&self, nonce: &[u8; 12], aad: &[u8], buf: &mut Vec<u8>) -> SysResult<()>;
+
+    /// Verifica e remove a tag, decifrando `buf` no lugar.
+    ///
+    /// Deve falhar (sem alterar o significado de `buf`) se a tag não confere.
+    fn open(&self, nonce: &[u8; 12], aad: &[u8], buf: &mut Vec<u8>) -> SysResult<()>;
+}
+
+/// Primitivas criptográficas do backend.
+pub trait CryptoProvider: Send + Sync {
+    /// Suites oferecidas, em ordem de preferência.
+    fn cipher_suites(&self) -> &[CipherSuite];
+
+    /// Inicia uma troca de chaves efêmera.
+    fn start_key_exchange(&self) -> SysResult<Box<dyn KeyExchange>>;
+
+    /// Cria a cifra AEAD da suite com a chave dada.
+    fn aead(&self, suite: CipherSuite, key: &[u8]) -> SysResult<Box<dyn Aead>>;
+
+    /// Preenche `buf` com bytes aleatórios criptograficamente seguros.
+    fn fill_random(&self, buf: &mut [u8]) -> SysResult<()>;
+}
+
+/// Validação da identidade do servidor.
+pub trait ServerCertVerifier: Send + Sync {
+    /// Esquemas aceitos em `CertificateVerify`.
+    fn supported_schemes(&self) -> &[u16];
+
+    /// Valida a cadeia (DER, certificado final primeiro) para `server_name`.
+    fn verify_server_cert(&self, chain: &[&[u8]], server_name: &str) -> SysResult<()>;
+
+    /// Verifica a assinatura de `message` com a chave do certificado final.
+    fn verify_signature(
+        &self,
+        scheme: u16,
+        message: &[u8],
+        end_entity: &[u8],
+        signature: &[u8],
+    ) -> SysResult<()>;
+}
+
+// =============================================================================
+// CONFIGURAÇÃO
+// =============================================================================
+
+/// Configuração do cliente TLS.
+#[derive(Clone)]
+pub struct TlsConfig {
+    pub(super) provider: Arc<dyn CryptoProvider>,
+    pub(super) verifier: Arc<dyn ServerCertVerifier>,
+    pub(super) alpn: Vec<Vec<u8>>,
+}
+
+static DEFAULT_CONFIG: Lock<Option<TlsConfig>> = Lock::new(None);
+
+impl TlsConfig {
+    /// Cria configuração com o backend e o verificador de certificados.
+    pub fn new(provider: Arc<dyn CryptoProvider>, verifier: Arc<dyn ServerCertVerifier>) -> Self {
+        Self {
+            provider,
+            verifier,
+            alpn: Vec::new(),
+        }
+    }
+
+    /// Protocolos ALPN oferecidos (ex: `b"http/1.1"`), em ordem de preferência.
+    pub fn with_alpn(mut self, protocols: &[&[u8]]) -> Self {
+        self.alpn = protocols.iter().map(|p| p.to_vec()).collect();
+        self
+    }
+
+    /// Instala como configuração usada por [`TlsStream::connect`](super::TlsStream::connect).
+    pub fn install_default(self) {
+        DEFAULT_CONFIG.with(|c| *c = Some(self));
+    }
+
+    /// Configuração padrão instalada.
+    pub fn default_config() -> SysResult<Self> {
+        DEFAULT_CONFIG
+            .with(|c| c.clone())
+            .ok_or(SysError::NotSupported)
+    }
+}
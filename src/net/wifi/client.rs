@@ -0,0 +1,418 @@
+//! # Wi-Fi Client
+//!
+//! Varredura, conexão e estado do Wi-Fi.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::wifi::{self, Credentials};
+//!
+//! for net in wifi::scan()? {
+//!     println!("{} {} dBm {:?}", net.ssid(), net.signal_dbm(), net.security());
+//! }
+//!
+//! wifi::connect("casa", Credentials::Wpa2 { passphrase: "segredo", remember: true })?;
+//! let status = wifi::status()?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, str_from_fixed, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Tempo máximo de varredura ou associação + DHCP (ms).
+const CONNECT_TIMEOUT_MS: u64 = 30_000;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Tipo de segurança da rede.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Security {
+    Open,
+    Wep,
+    Wpa2,
+    Wpa3,
+}
+
+impl Security {
+    fn from_u32(value: u32) -> Self {
+        match value {
+            security::WEP => Self::Wep,
+            security::WPA2_PSK => Self::Wpa2,
+            security::WPA3_SAE => Self::Wpa3,
+            _ => Self::Open,
+        }
+    }
+}
+
+/// Credenciais para [`connect`].
+#[derive(Clone, Copy)]
+pub enum Credentials<'a> {
+    /// Rede aberta.
+    Open,
+    /// WPA2-Personal; `remember` guarda a senha no keyring.
+    Wpa2 { passphrase: &'a str, remember: bool },
+    /// WPA3-Personal (SAE); `remember` guarda a senha no keyring.
+    Wpa3 { passphrase: &'a str, remember: bool },
+    /// Usar a credencial salva no keyring para o SSID.
+    Saved,
+}
+
+impl core::fmt::Debug for Credentials<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Nunca exibir a senha
+        match self {
+            Self::Open => f.write_str("Open"),
+            Self::Wpa2 { .. } => f.write_str("Wpa2 { .. }"),
+            Self::Wpa3 { .. } => f.write_str("Wpa3 { .. }"),
+            Self::Saved => f.write_str("Saved"),
+        }
+    }
+}
+
+/// Rede encontrada na varredura.
+#[derive(Clone, Copy)]
+pub struct Network {
+    entry: NetworkEntry,
+}
+
+impl Network {
+    /// Nome da rede.
+    pub fn ssid(&self) -> &str {
+        let len = (self.entry.ssid_len as usize).min(SSID_MAX);
+        str_from_fixed(&self.entry.ssid[..len])
+    }
+
+    /// Endereço do ponto de acesso.
+    pub fn bssid(&self) -> [u8; 6] {
+        self.entry.bssid
+    }
+
+    /// Tipo de segurança.
+    pub fn security(&self) -> Security {
+        Security::from_u32(self.entry.security)
+    }
+
+    /// Intensidade do sinal (dBm).
+    pub fn signal_dbm(&self) -> i32 {
+        self.entry.signal_dbm
+    }
+
+    /// Intensidade do sinal em barras (0–4).
+    pub fn bars(&self) -> u8 {
+        signal_bars(self.entry.signal_dbm)
+    }
+
+    /// Frequência (MHz).
+    pub fn frequency(&self) -> u32 {
+        self.entry.frequency
+    }
+
+    /// Retorna se há credencial salva para a rede.
+    pub fn is_saved(&self) -> bool {
+        self.entry.flags & network_flags::SAVED != 0
+    }
+
+    /// Retorna se é a rede conectada.
+    pub fn is_connected(&self) -> bool {
+        self.entry.flags & network_flags::CONNECTED != 0
+    }
+}
+
+impl core::fmt::Debug for Network {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Network")
+            .field("ssid", &self.ssid())
+            .field("security", &self.security())
+            .field("signal_dbm", &self.signal_dbm())
+            .field("saved", &self.is_saved())
+            .field("connected", &self.is_connected())
+            .finish()
+    }
+}
+
+/// Estado da conexão.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Rádio desligado.
+    Disabled,
+    Disconnected,
+    Connecting,
+    Connected,
+}
+
+/// Estado atual do Wi-Fi.
+#[derive(Clone, Copy)]
+pub struct Status {
+    msg: StatusMessage,
+}
+
+impl Status {
+    /// Estado da conexão.
+    pub fn state(&self) -> ConnectionState {
+        match self.msg.state {
+            state::DISABLED => ConnectionState::Disabled,
+            state::CONNECTING => ConnectionState::Connecting,
+            state::CONNECTED => ConnectionState::Connected,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+
+    /// Rede atual (ou em conexão).
+    pub fn ssid(&self) -> Option<&str> {
+        let len = (self.msg.ssid_len as usize).min(SSID_MAX);
+        (len > 0).then(|| str_from_fixed(&self.msg.ssid[..len]))
+    }
+
+    /// Segurança da rede atual.
+    pub fn security(&self) -> Security {
+        Security::from_u32(self.msg.security)
+    }
+
+    /// Intensidade do sinal (dBm).
+    pub fn signal_dbm(&self) -> i32 {
+        self.msg.signal_dbm
+    }
+
+    /// Intensidade do sinal em barras (0–4, 0 se desconectado).
+    pub fn bars(&self) -> u8 {
+        match self.state() {
+            ConnectionState::Connected => signal_bars(self.msg.signal_dbm),
+            _ => 0,
+        }
+    }
+
+    /// Endereço IPv4 atribuído.
+    pub fn ipv4(&self) -> Option<[u8; 4]> {
+        (self.msg.ipv4 != [0; 4]).then_some(self.msg.ipv4)
+    }
+}
+
+impl core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Status")
+            .field("state", &self.state())
+            .field("ssid", &self.ssid())
+            .field("signal_dbm", &self.signal_dbm())
+            .field("ipv4", &self.ipv4())
+            .finish()
+    }
+}
+
+// =============================================================================
+// OPERAÇÕES
+// =============================================================================
+
+/// Varre as redes visíveis (bloqueia até o fim da varredura).
+pub fn scan() -> SysResult<Vec<Network>> {
+    let (reply_port, reply_name) = Port::create_unique("wifi.r.", 4)?;
+    let server_port = Port::connect(WIFI_PORT)?;
+
+    let mut networks = Vec::new();
+    loop {
+        let req = SimpleRequest {
+            op: opcodes::SCAN,
+            value: networks.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<WifiMessage>(
+            &reply_port,
+            opcodes::NETWORK_LIST,
+            core::mem::size_of::<NetworkListResponse>(),
+            CONNECT_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.network_list };
+
+        let count = (page.count as usize).min(NETWORKS_PER_MSG);
+        networks.extend(page.entries[..count].iter().map(|&entry| Network { entry }));
+        if count == 0 || networks.len() >= page.total as usize {
+            return Ok(networks);
+        }
+    }
+}
+
+/// Conecta à rede, bloqueando até obter endereço ou falhar.
+///
+/// Senha incorreta retorna `SysError::PermissionDenied`.
+pub fn connect(ssid: &str, credentials: Credentials) -> SysResult<()> {
+    if ssid.is_empty() || ssid.len() > SSID_MAX {
+        return Err(SysError::InvalidArgument);
+    }
+    let (security, passphrase, remember) = match credentials {
+        Credentials::Open => (security::OPEN, "", false),
+        Credentials::Wpa2 {
+            passphrase,
+            remember,
+        } => (security::WPA2_PSK, passphrase, remember),
+        Credentials::Wpa3 {
+            passphrase,
+            remember,
+        } => (security::WPA3_SAE, passphrase, remember),
+        Credentials::Saved => (security::SAVED, "", false),
+    };
+    if passphrase.len() > PASSPHRASE_MAX {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let (reply_port, reply_name) = Port::create_unique("wifi.r.", 4)?;
+    let mut req = connect_request(opcodes::CONNECT, ssid, reply_name);
+    req.security = security;
+    req.flags = if remember { connect_flags::REMEMBER } else { 0 };
+    req.passphrase[..passphrase.len()].copy_from_slice(passphrase.as_bytes());
+
+    let sent = Port::connect(WIFI_PORT).and_then(|port| port.send(as_bytes(&req), 0));
+
+    // Não deixar a senha na pilha
+    for byte in req.passphrase.iter_mut() {
+        unsafe { core::ptr::write_volatile(byte, 0) };
+    }
+    sent?;
+
+    recv_reply::<WifiMessage>(&reply_port, opcodes::DONE, 0, CONNECT_TIMEOUT_MS)?;
+    Ok(())
+}
+
+/// Desconecta da rede atual.
+pub fn disconnect() -> SysResult<()> {
+    simple_op(opcodes::DISCONNECT, 0)
+}
+
+/// Esquece a rede (remove a credencial do keyring).
+pub fn forget(ssid: &str) -> SysResult<()> {
+    if ssid.is_empty() || ssid.len() > SSID_MAX {
+        return Err(SysError::InvalidArgument);
+    }
+    let (reply_port, reply_name) = Port::create_unique("wifi.r.", 4)?;
+    let req = connect_request(opcodes::FORGET, ssid, reply_name);
+    Port::connect(WIFI_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<WifiMessage>(&reply_port, opcodes::DONE, 0, REPLY_TIMEOUT_MS)?;
+    Ok(())
+}
+
+/// Liga ou desliga o rádio.
+pub fn set_enabled(enabled: bool) -> SysResult<()> {
+    simple_op(opcodes::SET_ENABLED, enabled as u32)
+}
+
+/// Estado atual.
+pub fn status() -> SysResult<Status> {
+    let (reply_port, reply_name) = Port::create_unique("wifi.r.", 4)?;
+    let req = SimpleRequest {
+        op: opcodes::GET_STATUS,
+        value: 0,
+        reply_port: reply_name,
+    };
+    Port::connect(WIFI_PORT)?.send(as_bytes(&req), 0)?;
+    let resp = recv_reply::<WifiMessage>(
+        &reply_port,
+        opcodes::STATUS,
+        core::mem::size_of::<StatusMessage>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(Status {
+        msg: unsafe { resp.status },
+    })
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Receptor de mudanças de estado (para o indicador de rede).
+pub struct WifiListener {
+    port: Port,
+}
+
+/// Registra um receptor de mudanças de estado.
+pub fn subscribe() -> SysResult<WifiListener> {
+    let (port, name) = Port::create_unique("wifi.ev.", 16)?;
+    let req = SubscribeRequest {
+        op: opcodes::SUBSCRIBE,
+        listener_port: name,
+    };
+    Port::connect(WIFI_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(WifiListener { port })
+}
+
+impl WifiListener {
+    /// Retorna a próxima mudança pendente, sem bloquear.
+    pub fn poll(&self) -> Option<Status> {
+        self.wait(0)
+    }
+
+    /// Espera uma mudança por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<Status> {
+        loop {
+            let mut msg = WifiMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<StatusMessage>() {
+                return None;
+            }
+            if unsafe { msg.header } == opcodes::EVENT_STATUS {
+                return Some(Status {
+                    msg: unsafe { msg.status },
+                });
+            }
+            // Mensagem desconhecida: descartar e continuar
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Converte dBm em barras (0–4).
+fn signal_bars(dbm: i32) -> u8 {
+    match dbm {
+        d if d >= -55 => 4,
+        d if d >= -67 => 3,
+        d if d >= -75 => 2,
+        d if d >= -85 => 1,
+        _ => 0,
+    }
+}
+
+fn connect_request(op: u32, ssid: &str, reply_port: [u8; 32]) -> ConnectRequest {
+    let mut req = ConnectRequest {
+        op,
+        security: 0,
+        flags: 0,
+        ssid_len: ssid.len() as u32,
+        ssid: [0; SSID_MAX],
+        passphrase: [0; PASSPHRASE_MAX + 1],
+        reply_port,
+    };
+    req.ssid[..ssid.len()].copy_from_slice(ssid.as_bytes());
+    req
+}
+
+fn simple_op(op: u32, value: u32) -> SysResult<()> {
+    let (reply_port, reply_name) = Port::create_unique("wifi.r.", 4)?;
+    let req = SimpleRequest {
+        op,
+        value,
+        reply_port: reply_name,
+    };
+    Port::connect(WIFI_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<WifiMessage>(&reply_port, opcodes::DONE, 0, REPLY_TIMEOUT_MS)?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! # Wi-Fi
+//!
+//! Cliente do serviço de Wi-Fi: varredura, conexão e estado.
+//!
+//! As senhas são enviadas apenas ao serviço, que as guarda no keyring do
+//! sistema quando a conexão pede para lembrar a rede; depois basta conectar
+//! com [`Credentials::Saved`].
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo Wi-Fi |
+//! | [`client`] | Varredura, conexão, estado e eventos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    connect, disconnect, forget, scan, set_enabled, status, subscribe, ConnectionState,
+    Credentials, Network, Security, Status, WifiListener,
+};
+pub use protocol::WIFI_PORT;
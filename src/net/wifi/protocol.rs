@@ -0,0 +1,192 @@
+//! # Protocolo Wi-Fi
+//!
+//! Definições de mensagens do protocolo com o serviço de Wi-Fi.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de Wi-Fi.
+pub const WIFI_PORT: &str = "wifi.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+/// Tamanho máximo de SSID (bytes).
+pub const SSID_MAX: usize = 32;
+
+/// Tamanho máximo de senha WPA (bytes).
+pub const PASSPHRASE_MAX: usize = 63;
+
+/// Redes por página de `NETWORK_LIST`.
+pub const NETWORKS_PER_MSG: usize = 4;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const SCAN: u32 = 0x01;
+    pub const CONNECT: u32 = 0x02;
+    pub const DISCONNECT: u32 = 0x03;
+    pub const FORGET: u32 = 0x04;
+    pub const GET_STATUS: u32 = 0x05;
+    pub const SET_ENABLED: u32 = 0x06;
+    pub const SUBSCRIBE: u32 = 0x07;
+
+    // Server -> Client
+    pub const NETWORK_LIST: u32 = 0x10;
+    pub const STATUS: u32 = 0x11;
+    pub const DONE: u32 = 0x12;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> Listener)
+    pub const EVENT_STATUS: u32 = 0x20;
+}
+
+/// Tipos de segurança.
+pub mod security {
+    pub const OPEN: u32 = 0;
+    pub const WEP: u32 = 1;
+    pub const WPA2_PSK: u32 = 2;
+    pub const WPA3_SAE: u32 = 3;
+    /// Usar credencial salva no keyring.
+    pub const SAVED: u32 = 0xFF;
+}
+
+/// Estados da conexão.
+pub mod state {
+    pub const DISABLED: u32 = 0;
+    pub const DISCONNECTED: u32 = 1;
+    pub const CONNECTING: u32 = 2;
+    pub const CONNECTED: u32 = 3;
+}
+
+/// Flags de `CONNECT`.
+pub mod connect_flags {
+    /// Guardar a senha no keyring para reconexão.
+    pub const REMEMBER: u32 = 1 << 0;
+}
+
+/// Flags de rede.
+pub mod network_flags {
+    /// Credencial salva no keyring.
+    pub const SAVED: u32 = 1 << 0;
+    /// Rede atual.
+    pub const CONNECTED: u32 = 1 << 1;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request genérico com valor e porta de resposta (`SCAN`, `DISCONNECT`,
+/// `GET_STATUS`, `SET_ENABLED`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SimpleRequest {
+    pub op: u32,
+    /// Índice inicial (`SCAN`) ou habilitado (`SET_ENABLED`).
+    pub value: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de conexão (ou `FORGET`, apenas com SSID).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ConnectRequest {
+    pub op: u32,
+    pub security: u32,
+    pub flags: u32,
+    pub ssid_len: u32,
+    pub ssid: [u8; SSID_MAX],
+    pub passphrase: [u8; PASSPHRASE_MAX + 1],
+    pub reply_port: [u8; 32],
+}
+
+/// Request de assinatura de eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Rede encontrada na varredura.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkEntry {
+    pub bssid: [u8; 6],
+    pub _pad: [u8; 2],
+    pub security: u32,
+    /// Intensidade do sinal (dBm).
+    pub signal_dbm: i32,
+    /// Frequência (MHz).
+    pub frequency: u32,
+    pub flags: u32,
+    pub ssid_len: u32,
+    pub ssid: [u8; SSID_MAX],
+}
+
+/// Página da lista de redes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [NetworkEntry; NETWORKS_PER_MSG],
+}
+
+/// Estado atual (resposta de `GET_STATUS` e `EVENT_STATUS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StatusMessage {
+    pub op: u32,
+    pub state: u32,
+    pub security: u32,
+    pub signal_dbm: i32,
+    /// Endereço IPv4 (zeros se não atribuído).
+    pub ipv4: [u8; 4],
+    pub ssid_len: u32,
+    pub ssid: [u8; SSID_MAX],
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union WifiMessage {
+    pub header: u32,
+    pub simple_req: SimpleRequest,
+    pub connect_req: ConnectRequest,
+    pub network_list: NetworkListResponse,
+    pub status: StatusMessage,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for WifiMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -0,0 +1,409 @@
+//! # Print Client
+//!
+//! Listagem de impressoras e submissão de trabalhos ao spooler.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::print::{self, Job, JobState};
+//!
+//! let pages = [page1, page2]; // Surfaces já renderizadas
+//! let job = print::submit(&Job::pages("Relatório", &pages).with_copies(2))?;
+//!
+//! while let Some(status) = job.wait(5000) {
+//!     println!("{}/{}", status.pages_printed, status.total_pages);
+//!     if status.state.is_final() {
+//!         break;
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::graphics::Surface;
+use crate::ipc::{
+    as_bytes, as_bytes_mut, fixed_str, recv_reply, str_from_fixed, Port, SharedMemory,
+};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// IMPRESSORAS
+// =============================================================================
+
+/// Estado da impressora.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrinterState {
+    Idle,
+    Printing,
+    Offline,
+    /// Exige intervenção (sem papel, tampa aberta...).
+    Error,
+}
+
+/// Impressora conhecida pelo spooler.
+#[derive(Clone, Copy)]
+pub struct Printer {
+    entry: PrinterEntry,
+}
+
+impl Printer {
+    /// Identificador (para [`Job::with_printer`]).
+    pub fn id(&self) -> u32 {
+        self.entry.printer_id
+    }
+
+    /// Nome (ex: `HP LaserJet 400`).
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Localização ou descrição configurada.
+    pub fn location(&self) -> &str {
+        str_from_fixed(&self.entry.location)
+    }
+
+    /// Estado atual.
+    pub fn state(&self) -> PrinterState {
+        match self.entry.state {
+            printer_states::PRINTING => PrinterState::Printing,
+            printer_states::OFFLINE => PrinterState::Offline,
+            printer_states::ERROR => PrinterState::Error,
+            _ => PrinterState::Idle,
+        }
+    }
+
+    /// Impressora padrão do sistema?
+    pub fn is_default(&self) -> bool {
+        self.entry.is_default != 0
+    }
+
+    /// Trabalhos na fila.
+    pub fn queued_jobs(&self) -> u32 {
+        self.entry.queued_jobs
+    }
+
+    /// Imprime em cores?
+    pub fn supports_color(&self) -> bool {
+        self.entry.caps & caps::COLOR != 0
+    }
+
+    /// Imprime frente e verso?
+    pub fn supports_duplex(&self) -> bool {
+        self.entry.caps & caps::DUPLEX != 0
+    }
+
+    /// Aceita PDF (sem PDF, o spooler rejeita trabalhos PDF).
+    pub fn supports_pdf(&self) -> bool {
+        self.entry.caps & caps::PDF != 0
+    }
+}
+
+impl core::fmt::Debug for Printer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Printer")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .field("state", &self.state())
+            .field("is_default", &self.is_default())
+            .finish()
+    }
+}
+
+/// Impressoras disponíveis.
+pub fn printers() -> SysResult<Vec<Printer>> {
+    let (reply_port, reply_name) = Port::create_unique("print.r.", 4)?;
+    let server_port = Port::connect(SPOOLER_PORT)?;
+
+    let mut printers = Vec::new();
+    loop {
+        let req = ListPrintersRequest {
+            op: opcodes::LIST_PRINTERS,
+            start: printers.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<PrintMessage>(
+            &reply_port,
+            opcodes::PRINTER_LIST,
+            core::mem::size_of::<PrinterListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.printer_list };
+
+        let count = (page.count as usize).min(PRINTERS_PER_MSG);
+        printers.extend(page.entries[..count].iter().map(|&entry| Printer { entry }));
+        if count == 0 || printers.len() >= page.total as usize {
+            return Ok(printers);
+        }
+    }
+}
+
+// =============================================================================
+// TRABALHOS
+// =============================================================================
+
+/// Conteúdo de um trabalho.
+#[derive(Clone, Copy)]
+pub enum JobContent<'a> {
+    /// Páginas já rasterizadas, uma [`Surface`] por página.
+    Pages(&'a [Surface]),
+    /// Documento PDF.
+    Pdf(&'a [u8]),
+}
+
+/// Trabalho de impressão a submeter.
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    pub title: &'a str,
+    pub content: JobContent<'a>,
+    /// Impressora (None = padrão do sistema).
+    pub printer: Option<u32>,
+    pub copies: u32,
+    pub duplex: bool,
+    pub grayscale: bool,
+}
+
+impl<'a> Job<'a> {
+    /// Trabalho com páginas rasterizadas.
+    pub fn pages(title: &'a str, pages: &'a [Surface]) -> Self {
+        Self::new(title, JobContent::Pages(pages))
+    }
+
+    /// Trabalho com um documento PDF.
+    pub fn pdf(title: &'a str, bytes: &'a [u8]) -> Self {
+        Self::new(title, JobContent::Pdf(bytes))
+    }
+
+    fn new(title: &'a str, content: JobContent<'a>) -> Self {
+        Self {
+            title,
+            content,
+            printer: None,
+            copies: 1,
+            duplex: false,
+            grayscale: false,
+        }
+    }
+
+    /// Seleciona a impressora.
+    pub fn with_printer(mut self, printer: &Printer) -> Self {
+        self.printer = Some(printer.id());
+        self
+    }
+
+    /// Número de cópias.
+    pub fn with_copies(mut self, copies: u32) -> Self {
+        self.copies = copies;
+        self
+    }
+
+    /// Frente e verso.
+    pub fn with_duplex(mut self, duplex: bool) -> Self {
+        self.duplex = duplex;
+        self
+    }
+
+    /// Força impressão em tons de cinza.
+    pub fn with_grayscale(mut self, grayscale: bool) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+}
+
+/// Estado de um trabalho.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Queued,
+    Printing,
+    Completed,
+    Cancelled,
+    Failed(SysError),
+}
+
+impl JobState {
+    /// Estado terminal (não haverá mais eventos)?
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Completed | Self::Cancelled | Self::Failed(_))
+    }
+}
+
+/// Progresso de um trabalho.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobStatus {
+    pub state: JobState,
+    pub pages_printed: u32,
+    /// 0 se ainda desconhecido (ex: PDF ainda não interpretado).
+    pub total_pages: u32,
+}
+
+/// Submete um trabalho ao spooler.
+///
+/// O conteúdo é copiado para memória compartilhada; após o retorno, os
+/// buffers de `job` podem ser descartados.
+pub fn submit(job: &Job) -> SysResult<PrintJob> {
+    if job.copies == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let (format, page_count, size) = match job.content {
+        JobContent::Pages(pages) => {
+            if pages.is_empty() {
+                return Err(SysError::InvalidArgument);
+            }
+            let pixels: usize = pages.iter().map(|p| p.pixels().len()).sum();
+            let size = pages.len() * core::mem::size_of::<PageHeader>() + pixels * 4;
+            (formats::RASTER, pages.len() as u32, size)
+        }
+        JobContent::Pdf(bytes) => {
+            if bytes.is_empty() {
+                return Err(SysError::InvalidArgument);
+            }
+            (formats::PDF, 0, bytes.len())
+        }
+    };
+
+    let mut shm = SharedMemory::create(size)?;
+    let data = shm.as_mut_slice();
+    match job.content {
+        JobContent::Pages(pages) => {
+            let header_size = core::mem::size_of::<PageHeader>();
+            let (headers, mut pixels) = data.split_at_mut(pages.len() * header_size);
+            for (page, header) in pages.iter().zip(headers.chunks_exact_mut(header_size)) {
+                let h = PageHeader {
+                    width: page.width(),
+                    height: page.height(),
+                };
+                header.copy_from_slice(as_bytes(&h));
+                let (out, rest) = pixels.split_at_mut(page.pixels().len() * 4);
+                for (bytes, px) in out.chunks_exact_mut(4).zip(page.pixels()) {
+                    bytes.copy_from_slice(&px.to_le_bytes());
+                }
+                pixels = rest;
+            }
+        }
+        JobContent::Pdf(bytes) => data[..bytes.len()].copy_from_slice(bytes),
+    }
+
+    let mut flags = 0;
+    if job.duplex {
+        flags |= job_flags::DUPLEX;
+    }
+    if job.grayscale {
+        flags |= job_flags::GRAYSCALE;
+    }
+
+    let (port, port_name) = Port::create_unique("print.job.", 16)?;
+    let server_port = Port::connect(SPOOLER_PORT)?;
+    let req = SubmitRequest {
+        op: opcodes::SUBMIT,
+        printer_id: job.printer.unwrap_or(DEFAULT_PRINTER),
+        shm_handle: shm.id().0,
+        content_size: size as u64,
+        format,
+        page_count,
+        copies: job.copies,
+        flags,
+        title: fixed_str(job.title),
+        reply_port: port_name,
+    };
+    server_port.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<PrintMessage>(
+        &port,
+        opcodes::JOB_ACCEPTED,
+        core::mem::size_of::<JobAcceptedResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    let accepted = unsafe { resp.accepted };
+    Ok(PrintJob {
+        id: accepted.job_id,
+        printer_id: accepted.printer_id,
+        port,
+        server_port,
+    })
+}
+
+/// Trabalho aceito pelo spooler.
+///
+/// Descartar o `PrintJob` não cancela a impressão; apenas deixa de receber
+/// eventos.
+pub struct PrintJob {
+    id: u32,
+    printer_id: u32,
+    /// Porta de eventos do trabalho.
+    port: Port,
+    server_port: Port,
+}
+
+impl PrintJob {
+    /// Identificador do trabalho.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Impressora escolhida pelo spooler.
+    pub fn printer_id(&self) -> u32 {
+        self.printer_id
+    }
+
+    /// Cancela o trabalho (confirmado por um evento `Cancelled`).
+    pub fn cancel(&self) -> SysResult<()> {
+        let req = CancelRequest {
+            op: opcodes::CANCEL,
+            job_id: self.id,
+        };
+        self.server_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Retorna o próximo evento de progresso pendente, sem bloquear.
+    pub fn poll(&self) -> Option<JobStatus> {
+        self.wait(0)
+    }
+
+    /// Espera um evento de progresso por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<JobStatus> {
+        loop {
+            let mut msg = PrintMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<JobStatusEvent>() {
+                return None;
+            }
+            let event = unsafe { msg.status };
+            if event.op != opcodes::JOB_STATUS || event.job_id != self.id {
+                // Mensagem desconhecida: descartar e continuar
+                continue;
+            }
+            let state = match event.state {
+                job_states::QUEUED => JobState::Queued,
+                job_states::PRINTING => JobState::Printing,
+                job_states::COMPLETED => JobState::Completed,
+                job_states::CANCELLED => JobState::Cancelled,
+                job_states::FAILED => {
+                    JobState::Failed(SysError::from_code(event.error as i32 as isize))
+                }
+                _ => continue,
+            };
+            return Some(JobStatus {
+                state,
+                pages_printed: event.pages_printed,
+                total_pages: event.total_pages,
+            });
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
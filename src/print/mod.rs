@@ -0,0 +1,23 @@
+//! # Impressão
+//!
+//! Cliente do spooler de impressão: impressoras disponíveis, submissão de
+//! páginas rasterizadas ou PDF e acompanhamento dos trabalhos.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do spooler |
+//! | [`client`] | Impressoras e trabalhos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    printers, submit, Job, JobContent, JobState, JobStatus, PrintJob, Printer, PrinterState,
+};
+pub use protocol::SPOOLER_PORT;
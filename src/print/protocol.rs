@@ -0,0 +1,227 @@
+//! # Protocolo de Impressão
+//!
+//! Definições de mensagens do protocolo com o spooler de impressão.
+//!
+//! O conteúdo do trabalho vai em uma SHM criada pelo cliente. Para páginas
+//! rasterizadas, a SHM começa com `page_count` [`PageHeader`]s seguidos dos
+//! pixels (ARGB, linha a linha) de cada página, na mesma ordem. Para PDF,
+//! a SHM contém o arquivo inteiro.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do spooler.
+pub const SPOOLER_PORT: &str = "print.spooler";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 512;
+
+/// Tamanho máximo do nome da impressora.
+pub const PRINTER_NAME_MAX: usize = 48;
+
+/// Tamanho máximo da localização/descrição da impressora.
+pub const PRINTER_LOCATION_MAX: usize = 64;
+
+/// Tamanho máximo do título do trabalho.
+pub const JOB_TITLE_MAX: usize = 64;
+
+/// Impressoras por página de `PRINTER_LIST`.
+pub const PRINTERS_PER_MSG: usize = 3;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const LIST_PRINTERS: u32 = 0x01;
+    pub const SUBMIT: u32 = 0x02;
+    pub const CANCEL: u32 = 0x03;
+
+    // Server -> Client
+    pub const PRINTER_LIST: u32 = 0x10;
+    pub const JOB_ACCEPTED: u32 = 0x11;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> porta do trabalho)
+    pub const JOB_STATUS: u32 = 0x20;
+}
+
+/// Formatos de conteúdo.
+pub mod formats {
+    /// Páginas rasterizadas (ARGB).
+    pub const RASTER: u32 = 1;
+    pub const PDF: u32 = 2;
+}
+
+/// Estados de impressora.
+pub mod printer_states {
+    pub const IDLE: u32 = 0;
+    pub const PRINTING: u32 = 1;
+    pub const OFFLINE: u32 = 2;
+    /// Erro que exige intervenção (sem papel, tampa aberta...).
+    pub const ERROR: u32 = 3;
+}
+
+/// Capacidades de impressora (bitflags).
+pub mod caps {
+    pub const COLOR: u32 = 1 << 0;
+    pub const DUPLEX: u32 = 1 << 1;
+    /// Aceita PDF diretamente.
+    pub const PDF: u32 = 1 << 2;
+}
+
+/// Opções de trabalho (bitflags).
+pub mod job_flags {
+    pub const DUPLEX: u32 = 1 << 0;
+    pub const GRAYSCALE: u32 = 1 << 1;
+}
+
+/// Estados de trabalho.
+pub mod job_states {
+    pub const QUEUED: u32 = 0;
+    pub const PRINTING: u32 = 1;
+    pub const COMPLETED: u32 = 2;
+    pub const CANCELLED: u32 = 3;
+    pub const FAILED: u32 = 4;
+}
+
+/// Id de impressora que seleciona a padrão do sistema.
+pub const DEFAULT_PRINTER: u32 = 0;
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de listagem de impressoras (paginada a partir de `start`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListPrintersRequest {
+    pub op: u32,
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de submissão de trabalho.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubmitRequest {
+    pub op: u32,
+    /// Impressora (`DEFAULT_PRINTER` = padrão).
+    pub printer_id: u32,
+    /// SHM com o conteúdo.
+    pub shm_handle: u64,
+    /// Bytes válidos na SHM.
+    pub content_size: u64,
+    pub format: u32,
+    /// Páginas (apenas `RASTER`).
+    pub page_count: u32,
+    pub copies: u32,
+    pub flags: u32,
+    pub title: [u8; JOB_TITLE_MAX],
+    /// Porta de resposta e de eventos do trabalho.
+    pub reply_port: [u8; 32],
+}
+
+/// Cabeçalho de página rasterizada (na SHM).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PageHeader {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Request de cancelamento.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CancelRequest {
+    pub op: u32,
+    pub job_id: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Impressora.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PrinterEntry {
+    pub printer_id: u32,
+    pub state: u32,
+    pub caps: u32,
+    /// Impressora padrão do sistema?
+    pub is_default: u8,
+    pub _pad: [u8; 3],
+    /// Trabalhos na fila.
+    pub queued_jobs: u32,
+    pub name: [u8; PRINTER_NAME_MAX],
+    pub location: [u8; PRINTER_LOCATION_MAX],
+}
+
+/// Página da lista de impressoras.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PrinterListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [PrinterEntry; PRINTERS_PER_MSG],
+}
+
+/// Trabalho aceito pelo spooler.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct JobAcceptedResponse {
+    pub op: u32,
+    pub job_id: u32,
+    pub printer_id: u32,
+}
+
+/// Evento de progresso do trabalho.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct JobStatusEvent {
+    pub op: u32,
+    pub job_id: u32,
+    pub state: u32,
+    /// Código de `SysError` (`FAILED`).
+    pub error: u32,
+    pub pages_printed: u32,
+    /// 0 se desconhecido (ex: PDF ainda não interpretado).
+    pub total_pages: u32,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PrintMessage {
+    pub header: u32,
+    pub printer_list: PrinterListResponse,
+    pub accepted: JobAcceptedResponse,
+    pub status: JobStatusEvent,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for PrintMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
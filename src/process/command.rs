@@ -0,0 +1,202 @@
+//! # Command
+//!
+//! Builder para criação de processos com passagem explícita de handles.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::process::Command;
+//!
+//! let log = File::create("/tmp/worker.log")?;
+//! let pid = Command::new("/apps/worker")
+//!     .arg("--verbose")
+//!     .handle(log.handle(), 1)
+//!     .port(&port, 2)
+//!     .spawn()?;
+//! ```
+//!
+//! O filho obtém os handles via [`crate::runtime::startup_handles`].
+
+use crate::io::Handle;
+use crate::ipc::{Port, ShmId};
+use crate::syscall::safe::sys_spawn_ex;
+use crate::syscall::{SysError, SysResult};
+
+/// Número máximo de argumentos por comando.
+pub const MAX_ARGS: usize = 16;
+
+/// Número máximo de handles passados ao filho.
+pub const MAX_STARTUP_HANDLES: usize = 16;
+
+/// Flags de spawn.
+pub mod spawn_flags {
+    /// Filho herda todos os handles herdáveis do pai (além dos explícitos).
+    pub const INHERIT_HANDLES: u32 = 1 << 0;
+}
+
+// =============================================================================
+// TIPOS DE ABI
+// =============================================================================
+
+/// Tipo de recurso transferido ao filho.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleKind {
+    /// Handle genérico (arquivo, diretório, etc).
+    Generic = 0,
+    /// Porta de IPC.
+    Port = 1,
+    /// Região de memória compartilhada.
+    Shm = 2,
+}
+
+impl HandleKind {
+    /// Converte de u32.
+    pub fn from_u32(value: u32) -> Self {
+        match value {
+            1 => Self::Port,
+            2 => Self::Shm,
+            _ => Self::Generic,
+        }
+    }
+}
+
+/// Entrada da tabela de handles (layout compatível com kernel).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HandleTransfer {
+    /// Tipo do recurso (`HandleKind`).
+    pub kind: u32,
+    /// Identificador escolhido pelo pai para o filho localizar o handle.
+    pub tag: u32,
+    /// Handle raw ou ID de SHM.
+    pub value: u64,
+}
+
+impl HandleTransfer {
+    /// Entrada vazia.
+    pub const EMPTY: Self = Self {
+        kind: 0,
+        tag: 0,
+        value: 0,
+    };
+}
+
+// =============================================================================
+// COMMAND
+// =============================================================================
+
+/// Builder de processo.
+pub struct Command<'a> {
+    path: &'a str,
+    args: [&'a str; MAX_ARGS],
+    arg_count: usize,
+    handles: [HandleTransfer; MAX_STARTUP_HANDLES],
+    handle_count: usize,
+    flags: u32,
+    /// Estourou algum limite durante a construção.
+    overflow: bool,
+}
+
+impl<'a> Command<'a> {
+    /// Cria comando para o executável em `path`.
+    pub fn new(path: &'a str) -> Self {
+        Self {
+            path,
+            args: [""; MAX_ARGS],
+            arg_count: 0,
+            handles: [HandleTransfer::EMPTY; MAX_STARTUP_HANDLES],
+            handle_count: 0,
+            flags: 0,
+            overflow: false,
+        }
+    }
+
+    /// Adiciona um argumento.
+    pub fn arg(&mut self, arg: &'a str) -> &mut Self {
+        if self.arg_count < MAX_ARGS {
+            self.args[self.arg_count] = arg;
+            self.arg_count += 1;
+        } else {
+            self.overflow = true;
+        }
+        self
+    }
+
+    /// Adiciona vários argumentos.
+    pub fn args(&mut self, args: &[&'a str]) -> &mut Self {
+        for arg in args {
+            self.arg(arg);
+        }
+        self
+    }
+
+    /// Passa um handle genérico (arquivo, diretório) ao filho.
+    pub fn handle(&mut self, handle: &Handle, tag: u32) -> &mut Self {
+        self.push_handle(HandleKind::Generic, tag, handle.raw() as u64)
+    }
+
+    /// Redireciona a entrada padrão do filho.
+    pub fn stdin(&mut self, handle: &Handle) -> &mut Self {
+        self.handle(handle, crate::io::STDIN_TAG)
+    }
+
+    /// Redireciona a saída padrão do filho (`print!`).
+    pub fn stdout(&mut self, handle: &Handle) -> &mut Self {
+        self.handle(handle, crate::io::STDOUT_TAG)
+    }
+
+    /// Redireciona a saída de erro do filho (`eprint!`, panics).
+    pub fn stderr(&mut self, handle: &Handle) -> &mut Self {
+        self.handle(handle, crate::io::STDERR_TAG)
+    }
+
+    /// Passa uma porta de IPC ao filho.
+    pub fn port(&mut self, port: &Port, tag: u32) -> &mut Self {
+        self.push_handle(HandleKind::Port, tag, port.handle().raw() as u64)
+    }
+
+    /// Concede acesso a uma região de memória compartilhada ao filho.
+    pub fn shm(&mut self, id: ShmId, tag: u32) -> &mut Self {
+        self.push_handle(HandleKind::Shm, tag, id.0)
+    }
+
+    /// Define se o filho herda os handles herdáveis do pai.
+    pub fn inherit_handles(&mut self, inherit: bool) -> &mut Self {
+        if inherit {
+            self.flags |= spawn_flags::INHERIT_HANDLES;
+        } else {
+            self.flags &= !spawn_flags::INHERIT_HANDLES;
+        }
+        self
+    }
+
+    /// Cria o processo.
+    ///
+    /// # Returns
+    /// PID do novo processo
+    pub fn spawn(&self) -> SysResult<usize> {
+        if self.overflow {
+            return Err(SysError::LimitReached);
+        }
+
+        let args = &self.args[..self.arg_count];
+        let handles = &self.handles[..self.handle_count];
+
+        sys_spawn_ex(self.path, args, handles, self.flags)
+    }
+
+    fn push_handle(&mut self, kind: HandleKind, tag: u32, value: u64) -> &mut Self {
+        if self.handle_count < MAX_STARTUP_HANDLES {
+            self.handles[self.handle_count] = HandleTransfer {
+                kind: kind as u32,
+                tag,
+                value,
+            };
+            self.handle_count += 1;
+        } else {
+            self.overflow = true;
+        }
+        self
+    }
+}
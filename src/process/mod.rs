@@ -1,5 +1,8 @@
 //! # Process Control
 
+mod command;
 mod process;
+pub mod signals;
 
+pub use command::*;
 pub use process::*;
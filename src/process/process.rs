@@ -2,56 +2,75 @@
 //!
 //! Controle de processos.
 
-use crate::syscall::{check_error, syscall0, syscall1, syscall4, SysResult};
-use crate::syscall::{SYS_EXIT, SYS_GETPID, SYS_SPAWN, SYS_WAIT, SYS_YIELD};
-use core::arch::asm;
+use crate::syscall::safe::{
+    sys_exit, sys_getpid, sys_set_task_name, sys_spawn, sys_wait, sys_yield,
+};
+use crate::syscall::{SysError, SysResult};
 
 /// Encerra o processo atual
 ///
-/// Esta função nunca retorna.
+/// Executa os hooks de [`runtime::at_exit`](crate::runtime::at_exit) antes
+/// de sair. Esta função nunca retorna.
 pub fn exit(code: i32) -> ! {
-    let _ = syscall1(SYS_EXIT, code as usize);
-    // Nunca deveria chegar aqui
-    loop {
-        unsafe { asm!("hlt") };
-    }
+    crate::runtime::run_exit_hooks();
+    exit_now(code)
+}
+
+/// Encerra o processo sem executar os hooks de saída.
+pub fn exit_now(code: i32) -> ! {
+    sys_exit(code)
 }
 
 /// Cede o restante do quantum de tempo
 pub fn yield_now() -> SysResult<()> {
-    check_error(syscall0(SYS_YIELD))?;
-    Ok(())
+    sys_yield()
 }
 
 /// Obtém PID do processo atual
 pub fn getpid() -> usize {
-    syscall0(SYS_GETPID) as usize
+    sys_getpid()
+}
+
+/// Tamanho máximo de nomes de processo/thread (bytes UTF-8).
+pub const MAX_TASK_NAME_LEN: usize = 32;
+
+/// Alvo de `SYS_SET_TASK_NAME`.
+const NAME_PROCESS: usize = 0;
+const NAME_THREAD: usize = 1;
+
+/// Dá nome ao processo (ex.: `"firefly"`), exibido no gerenciador de
+/// tarefas, no tracer e em relatórios de crash no lugar do PID.
+///
+/// Nomes vazios ou com mais de [`MAX_TASK_NAME_LEN`] bytes são rejeitados.
+pub fn set_name(name: &str) -> SysResult<()> {
+    set_task_name(NAME_PROCESS, name)
+}
+
+/// Dá nome à thread atual (ex.: `"firefly-render"`).
+///
+/// Threads criadas com [`thread::Builder::name`](crate::thread::Builder::name)
+/// já iniciam nomeadas.
+pub fn set_thread_name(name: &str) -> SysResult<()> {
+    set_task_name(NAME_THREAD, name)
+}
+
+fn set_task_name(target: usize, name: &str) -> SysResult<()> {
+    if name.is_empty() || name.len() > MAX_TASK_NAME_LEN {
+        return Err(SysError::InvalidArgument);
+    }
+    sys_set_task_name(target, name)
 }
 
 /// Cria novo processo
 ///
 /// # Args
 /// - path: caminho do executável
-/// - args: argumentos (pode ser vazio)
+/// - args: argumentos (pode ser vazio, até [`MAX_ARGS`](crate::process::MAX_ARGS))
 ///
 /// # Returns
 /// PID do novo processo
 pub fn spawn(path: &str, args: &[&str]) -> SysResult<usize> {
-    let args_ptr = if args.is_empty() {
-        0
-    } else {
-        args.as_ptr() as usize
-    };
-
-    let ret = syscall4(
-        SYS_SPAWN,
-        path.as_ptr() as usize,
-        path.len(),
-        args_ptr,
-        args.len(),
-    );
-
-    check_error(ret)
+    sys_spawn(path, args)
 }
 
 /// Espera processo terminar
@@ -63,9 +82,5 @@ pub fn spawn(path: &str, args: &[&str]) -> SysResult<usize> {
 /// # Returns
 /// Exit code do processo
 pub fn wait(pid: usize, timeout_ms: u64) -> SysResult<i32> {
-    let ret = syscall2(SYS_WAIT, pid, timeout_ms as usize);
-    check_error(ret).map(|v| v as i32)
+    sys_wait(pid, timeout_ms)
 }
-
-// Importar syscall2
-use crate::syscall::syscall2;
@@ -0,0 +1,255 @@
+//! # Signals
+//!
+//! Notificações assíncronas do kernel (Ctrl-C, pedido de término, filho
+//! encerrado).
+//!
+//! Sem uma porta registrada, o kernel aplica a ação padrão: `Interrupt` e
+//! `Terminate` encerram o processo e `ChildExited` é descartado. Com uma
+//! porta registrada ([`Signals::new`] ou as funções deste módulo), os sinais
+//! chegam como mensagens e o app decide o que fazer.
+//!
+//! O kernel não interrompe a thread: os handlers de [`on_ctrl_c`] rodam
+//! quando o app drena os sinais, em [`wait`], [`dispatch`] ou
+//! [`interrupted`]. Para loops de eventos, a porta é pollável
+//! ([`handle`], ou [`Signals`] como [`Source`](crate::event::Source)).
+//!
+//! O kernel guarda uma porta por processo: o último registro vale.
+//!
+//! A porta das funções do módulo não recebe `Terminate`, que mantém a ação
+//! padrão do kernel. Para tratar `Terminate`, use um [`Signals`] próprio.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::process::signals;
+//!
+//! signals::on_ctrl_c(|| println!("cancelando..."))?;
+//! for file in files {
+//!     if signals::interrupted() {
+//!         break;
+//!     }
+//!     copy(file)?;
+//! }
+//! ```
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::event::{events, poll, PollFd};
+use crate::io::Handle;
+use crate::ipc::{as_bytes_mut, Port};
+use crate::syscall::safe::sys_signal_port;
+use crate::syscall::SysResult;
+use crate::task::Lock;
+
+/// Capacidade da porta de sinais.
+const PORT_CAPACITY: usize = 16;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Sinal entregue pelo kernel.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// Pedido de interrupção (Ctrl-C no terminal).
+    Interrupt = 1,
+    /// Pedido de término (shell, gerenciador de tarefas).
+    Terminate = 2,
+    /// Um processo filho terminou.
+    ChildExited = 3,
+}
+
+impl Signal {
+    /// Todos os sinais.
+    pub const ALL: [Self; 3] = [Self::Interrupt, Self::Terminate, Self::ChildExited];
+
+    /// Converte de u32.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Interrupt),
+            2 => Some(Self::Terminate),
+            3 => Some(Self::ChildExited),
+            _ => None,
+        }
+    }
+
+    /// Bit do sinal na máscara de `SYS_SIGNAL_PORT`.
+    #[inline]
+    pub fn mask(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Mensagem de sinal no formato do kernel.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SignalMessage {
+    pub signal: u32,
+    /// Processo de origem (`ChildExited`: o filho).
+    pub pid: u32,
+    /// Código de saída (`ChildExited`).
+    pub status: i32,
+    pub _pad: u32,
+}
+
+/// Sinal recebido.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignalInfo {
+    pub signal: Signal,
+    /// Processo de origem (`ChildExited`: o filho).
+    pub pid: usize,
+    /// Código de saída (`ChildExited`; 0 nos demais).
+    pub status: i32,
+}
+
+// =============================================================================
+// SIGNALS
+// =============================================================================
+
+/// Porta registrada para receber sinais.
+pub struct Signals {
+    port: Port,
+}
+
+impl Signals {
+    /// Registra uma porta para `signals`; os demais mantêm a ação padrão.
+    pub fn new(signals: &[Signal]) -> SysResult<Self> {
+        let (port, _) = Port::create_unique("sig.", PORT_CAPACITY)?;
+        let mask = signals.iter().fold(0, |mask, signal| mask | signal.mask());
+        sys_signal_port(*port.handle(), mask)?;
+        Ok(Self { port })
+    }
+
+    /// Próximo sinal pendente (não bloqueante).
+    pub fn try_next(&self) -> SysResult<Option<SignalInfo>> {
+        loop {
+            let mut msg = SignalMessage::default();
+            if self.port.recv(as_bytes_mut(&mut msg), 0)? == 0 {
+                return Ok(None);
+            }
+            // Sinais desconhecidos (kernel mais novo) são ignorados
+            if let Some(signal) = Signal::from_u32(msg.signal) {
+                return Ok(Some(SignalInfo {
+                    signal,
+                    pid: msg.pid as usize,
+                    status: msg.status,
+                }));
+            }
+        }
+    }
+
+    /// Bloqueia até o próximo sinal.
+    pub fn wait(&self) -> SysResult<SignalInfo> {
+        loop {
+            if let Some(info) = self.try_next()? {
+                return Ok(info);
+            }
+            let mut fds = [self.poll_fd()];
+            poll(&mut fds, -1)?;
+        }
+    }
+
+    /// [`wait`](Self::wait) assíncrono (suspende a tarefa no executor).
+    pub async fn wait_async(&self) -> SysResult<SignalInfo> {
+        loop {
+            if let Some(info) = self.try_next()? {
+                return Ok(info);
+            }
+            crate::task::ready(self.port.handle(), events::IN).await?;
+        }
+    }
+
+    /// Descritor para `event::poll` (pronto com sinais pendentes).
+    pub fn poll_fd(&self) -> PollFd {
+        PollFd::new(self.port.handle(), events::IN)
+    }
+
+    /// Handle da porta.
+    pub fn handle(&self) -> &Handle {
+        self.port.handle()
+    }
+}
+
+// =============================================================================
+// PORTA DO PROCESSO
+// =============================================================================
+
+/// Sinais da porta das funções do módulo (`Terminate` fica com o kernel).
+const DEFAULT_SET: [Signal; 2] = [Signal::Interrupt, Signal::ChildExited];
+
+/// Porta registrada pelas funções do módulo ([`DEFAULT_SET`]).
+static DEFAULT: Lock<Option<Signals>> = Lock::new(None);
+static CTRL_C: Lock<Option<fn()>> = Lock::new(None);
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Registra a porta do processo na primeira chamada.
+fn with_default<R>(f: impl FnOnce(&Signals) -> R) -> SysResult<R> {
+    DEFAULT.with(|default| {
+        if default.is_none() {
+            *default = Some(Signals::new(&DEFAULT_SET)?);
+        }
+        Ok(f(default.as_ref().unwrap()))
+    })
+}
+
+/// Chama o handler de Ctrl-C (fora do lock).
+fn deliver(info: &SignalInfo) {
+    if info.signal == Signal::Interrupt {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        if let Some(handler) = CTRL_C.with(|handler| *handler) {
+            handler();
+        }
+    }
+}
+
+/// Chama `handler` a cada Ctrl-C em vez de encerrar o processo.
+///
+/// Registra a porta do processo para `Interrupt` e `ChildExited`;
+/// `Terminate` continua encerrando o processo.
+pub fn on_ctrl_c(handler: fn()) -> SysResult<()> {
+    with_default(|_| ())?;
+    CTRL_C.with(|slot| *slot = Some(handler));
+    Ok(())
+}
+
+/// Bloqueia até o próximo sinal da porta do processo.
+pub fn wait() -> SysResult<SignalInfo> {
+    loop {
+        if let Some(info) = with_default(Signals::try_next)?? {
+            deliver(&info);
+            return Ok(info);
+        }
+        let mut fds = [with_default(Signals::poll_fd)?];
+        poll(&mut fds, -1)?;
+    }
+}
+
+/// Drena os sinais pendentes, chamando os handlers; retorna quantos havia.
+pub fn dispatch() -> SysResult<usize> {
+    let mut count = 0;
+    while let Some(info) = with_default(Signals::try_next)?? {
+        deliver(&info);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Houve Ctrl-C desde o início (ou desde [`clear_interrupted`]).
+///
+/// Drena os sinais pendentes antes de responder.
+pub fn interrupted() -> bool {
+    let _ = dispatch();
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Esquece os Ctrl-C já recebidos.
+pub fn clear_interrupted() {
+    INTERRUPTED.store(false, Ordering::SeqCst);
+}
+
+/// Handle da porta do processo, para `event::poll` ou `EventQueue`; chame
+/// [`dispatch`] quando estiver pronta.
+pub fn handle() -> SysResult<Handle> {
+    with_default(|signals| *signals.handle())
+}
@@ -0,0 +1,106 @@
+//! # Exit Hooks
+//!
+//! Funções executadas por [`process::exit`](crate::process::exit) antes do
+//! `SYS_EXIT` final — RAII não roda quando o processo sai diretamente.
+//!
+//! Os hooks rodam em ordem inversa de registro (LIFO), uma única vez cada.
+//! A console do SDK não tem buffer; writers com buffer próprio devem
+//! registrar um hook que faça `flush`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::runtime;
+//!
+//! fn cleanup() {
+//!     // remover arquivos temporários...
+//! }
+//!
+//! runtime::at_exit(cleanup)?;
+//! runtime::at_exit_with(move || log.flush())?;
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::syscall::{SysError, SysResult};
+
+/// Número máximo de hooks registrados.
+pub const MAX_EXIT_HOOKS: usize = 32;
+
+/// Closure de limpeza registrada.
+type Cleanup = Box<dyn FnOnce() + Send>;
+
+/// Entrada da tabela: trampolim `fn(usize)` + argumento.
+struct Hook {
+    func: AtomicUsize,
+    data: AtomicUsize,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_HOOK: Hook = Hook {
+    func: AtomicUsize::new(0),
+    data: AtomicUsize::new(0),
+};
+
+static HOOKS: [Hook; MAX_EXIT_HOOKS] = [EMPTY_HOOK; MAX_EXIT_HOOKS];
+static HOOK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Registra uma função para rodar no `exit`.
+///
+/// Retorna `LimitReached` se a tabela estiver cheia.
+pub fn at_exit(hook: fn()) -> SysResult<()> {
+    register(call_fn, hook as usize)
+}
+
+/// Registra uma closure para rodar (e ser descartada) no `exit`.
+pub fn at_exit_with<F: FnOnce() + Send + 'static>(hook: F) -> SysResult<()> {
+    let cleanup: Box<Cleanup> = Box::new(Box::new(hook));
+    let data = Box::into_raw(cleanup) as usize;
+    register(call_boxed, data).inspect_err(|_| {
+        // Não registrada: liberar sem executar
+        drop(unsafe { Box::from_raw(data as *mut Cleanup) });
+    })
+}
+
+/// Executa os hooks registrados (LIFO).
+///
+/// Chamada por `process::exit`; hooks já executados não rodam de novo,
+/// então um hook que chame `exit` apenas executa os restantes.
+pub fn run_exit_hooks() {
+    let count = HOOK_COUNT.load(Ordering::Acquire).min(MAX_EXIT_HOOKS);
+    for hook in HOOKS[..count].iter().rev() {
+        let func = hook.func.swap(0, Ordering::AcqRel);
+        if func == 0 {
+            continue;
+        }
+        let data = hook.data.load(Ordering::Acquire);
+        let func: fn(usize) = unsafe { core::mem::transmute(func) };
+        func(data);
+    }
+}
+
+fn register(func: fn(usize), data: usize) -> SysResult<()> {
+    let index = HOOK_COUNT.fetch_add(1, Ordering::AcqRel);
+    if index >= MAX_EXIT_HOOKS {
+        HOOK_COUNT.fetch_sub(1, Ordering::AcqRel);
+        return Err(SysError::LimitReached);
+    }
+
+    // `func` por último: entrada só fica visível quando completa
+    HOOKS[index].data.store(data, Ordering::Release);
+    HOOKS[index].func.store(func as usize, Ordering::Release);
+    Ok(())
+}
+
+fn call_fn(data: usize) {
+    let hook: fn() = unsafe { core::mem::transmute(data) };
+    hook();
+}
+
+fn call_boxed(data: usize) {
+    let cleanup = unsafe { Box::from_raw(data as *mut Cleanup) };
+    cleanup();
+}
@@ -0,0 +1,31 @@
+//! # Runtime
+//!
+//! Suporte de runtime do processo.
+//!
+//! Programas chamam [`init`] no início de `_start`, antes de qualquer outra
+//! syscall.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`startup`] | Handles recebidos do processo pai |
+//! | [`exit`] | Hooks executados no `exit` |
+//! | [`report`] | Pacote de diagnóstico para relatórios de bug |
+//! | `panic` | `#[panic_handler]` padrão (feature `panic-handler`) e `catch_unwind` (feature `unwind`) |
+
+pub mod exit;
+#[cfg(feature = "panic-handler")]
+pub mod panic;
+pub mod report;
+pub mod startup;
+
+pub use exit::{at_exit, at_exit_with, run_exit_hooks, MAX_EXIT_HOOKS};
+pub use startup::{startup_handle, startup_handles, StartupHandle, StartupHandles};
+
+/// Inicializa o processo: confere a ABI do kernel
+/// ([`require_abi`](crate::sys::require_abi)), encerrando o processo se ela
+/// divergir da ABI com que a SDK foi compilada.
+pub fn init() {
+    crate::sys::require_abi();
+}
@@ -0,0 +1,454 @@
+//! # Panic Handler
+//!
+//! `#[panic_handler]` padrão do SDK (feature `panic-handler`).
+//!
+//! Imprime mensagem e localização na console e no log do kernel, opcionalmente
+//! um backtrace raso via frame pointers, e encerra o processo com código 101.
+//!
+//! O backtrace só é confiável se o app for compilado com
+//! `-C force-frame-pointers=yes`.
+//!
+//! ## Recuperação (feature `unwind`)
+//!
+//! [`catch_unwind`] executa uma closure e, se ela entrar em panic, retorna
+//! `Err(PanicPayload)` em vez de encerrar o processo — para isolar falhas em
+//! plugins e callbacks de serviços longos (compositor, shell).
+//!
+//! O alvo é `panic=abort`, então a recuperação restaura o contexto salvo na
+//! entrada do `catch_unwind` (setjmp/longjmp): os frames intermediários são
+//! descartados **sem executar destrutores**. Por isso `catch_unwind` é
+//! `unsafe` — ver o contrato em sua documentação. Não há unwinding real: um
+//! `Drop` entre o panic e o `catch_unwind` nunca executa.
+//!
+//! Um panic com um `task::Lock` do SDK adquirido dentro do `catch_unwind`
+//! não é recuperado: o lock ficaria preso e o próximo acesso travaria o
+//! serviço, então o processo é encerrado como sem `catch_unwind`.
+//!
+//! Cada thread tem seu próprio ponto de recuperação (tabela indexada por
+//! `SYS_GETTID`, até [`MAX_RECOVERY_THREADS`] threads simultâneas); um panic
+//! só retorna ao `catch_unwind` da thread que o causou.
+
+use core::fmt::{self, Write};
+use core::panic::PanicInfo;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Código de saída de processos que sofreram panic.
+pub const PANIC_EXIT_CODE: i32 = 101;
+
+/// Número máximo de frames no backtrace.
+const MAX_FRAMES: usize = 16;
+
+/// Backtrace habilitado.
+static BACKTRACE: AtomicBool = AtomicBool::new(false);
+
+/// Panic em andamento em thread sem ponto de recuperação (detecta panic
+/// dentro do handler). Threads dentro de `catch_unwind` usam o flag do slot.
+static PANICKING: AtomicBool = AtomicBool::new(false);
+
+/// Habilita ou desabilita o backtrace no panic.
+pub fn set_backtrace(enabled: bool) {
+    BACKTRACE.store(enabled, Ordering::Relaxed);
+}
+
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    #[cfg(feature = "unwind")]
+    let (recovery, recursive) = begin_panic();
+    #[cfg(not(feature = "unwind"))]
+    let recursive = PANICKING.swap(true, Ordering::SeqCst);
+
+    if recursive {
+        // Panic recursivo (inclusive dentro de um hook de saída):
+        // sair sem formatar nem executar mais nada
+        crate::process::exit_now(PANIC_EXIT_CODE);
+    }
+
+    let mut line = LineBuffer::new();
+    let _ = match info.location() {
+        Some(loc) => write!(
+            line,
+            "[panic] pid {} panicked at {}:{}:{}: {}",
+            crate::process::getpid(),
+            loc.file(),
+            loc.line(),
+            loc.column(),
+            info.message()
+        ),
+        None => write!(
+            line,
+            "[panic] pid {} panicked: {}",
+            crate::process::getpid(),
+            info.message()
+        ),
+    };
+
+    crate::eprintln!("{}", line.as_str());
+    let _ = crate::sys::kprint(line.as_str());
+
+    #[cfg(feature = "unwind")]
+    if !recovery.is_null() {
+        if holds_locks(recovery) {
+            crate::eprintln!("[panic] lock held inside catch_unwind; not recovering");
+        } else {
+            unsafe { recover(recovery, info) };
+        }
+    }
+
+    if BACKTRACE.load(Ordering::Relaxed) {
+        print_backtrace();
+    }
+
+    crate::process::exit(PANIC_EXIT_CODE);
+}
+
+/// Imprime os endereços de retorno da pilha.
+fn print_backtrace() {
+    crate::eprintln!("[panic] backtrace:");
+    super::report::walk_frames(MAX_FRAMES, |i, ret| {
+        crate::eprintln!("  #{:02} {:#018x}", i, ret);
+    });
+}
+
+// =============================================================================
+// RECUPERAÇÃO (CATCH_UNWIND)
+// =============================================================================
+
+#[cfg(feature = "unwind")]
+use self::unwind::{begin_panic, holds_locks, recover};
+#[cfg(feature = "unwind")]
+pub use self::unwind::{catch_unwind, PanicPayload, MAX_RECOVERY_THREADS};
+#[cfg(feature = "unwind")]
+pub(crate) use self::unwind::{lock_acquired, lock_released};
+
+#[cfg(feature = "unwind")]
+mod unwind {
+    extern crate alloc;
+
+    use alloc::boxed::Box;
+    use core::panic::{PanicInfo, UnwindSafe};
+    use core::ptr;
+    use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    use super::{LineBuffer, Write, PANICKING};
+    use crate::syscall::safe::sys_gettid;
+
+    /// Máximo de threads dentro de [`catch_unwind`] ao mesmo tempo.
+    pub const MAX_RECOVERY_THREADS: usize = 16;
+
+    /// Ponto de recuperação de uma thread.
+    struct Slot {
+        /// `tid + 1` da dona (0 = livre).
+        owner: AtomicUsize,
+        /// Ponto ativo mais interno (lista ligada pela pilha).
+        point: AtomicPtr<RecoveryPoint>,
+        /// `task::Lock`s adquiridos pela dona desde que reservou o slot.
+        locks: AtomicUsize,
+        /// Panic em andamento na dona.
+        panicking: AtomicBool,
+    }
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const FREE: Slot = Slot {
+        owner: AtomicUsize::new(0),
+        point: AtomicPtr::new(ptr::null_mut()),
+        locks: AtomicUsize::new(0),
+        panicking: AtomicBool::new(false),
+    };
+
+    static SLOTS: [Slot; MAX_RECOVERY_THREADS] = [FREE; MAX_RECOVERY_THREADS];
+
+    /// Slots reservados (evita `SYS_GETTID` em cada lock sem `catch_unwind`).
+    static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+    fn find(owner: usize) -> Option<&'static Slot> {
+        SLOTS
+            .iter()
+            .find(|slot| slot.owner.load(Ordering::Acquire) == owner)
+    }
+
+    /// Slot da thread atual, reservando um livre se preciso.
+    fn claim(owner: usize) -> Option<&'static Slot> {
+        find(owner).or_else(|| {
+            let slot = SLOTS.iter().find(|slot| {
+                slot.owner
+                    .compare_exchange(0, owner, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_ok()
+            })?;
+            ACTIVE.fetch_add(1, Ordering::AcqRel);
+            Some(slot)
+        })
+    }
+
+    /// Slot da thread atual, se ela estiver dentro de `catch_unwind`.
+    fn current_slot() -> Option<&'static Slot> {
+        if ACTIVE.load(Ordering::Acquire) == 0 {
+            return None;
+        }
+        find(sys_gettid() + 1)
+    }
+
+    /// Marca o panic da thread atual.
+    ///
+    /// Retorna o ponto de recuperação ativo (nulo se nenhum) e se a thread
+    /// já estava em panic. Sem ponto de recuperação, vale o flag global.
+    pub(super) fn begin_panic() -> (*mut RecoveryPoint, bool) {
+        match current_slot() {
+            Some(slot) => {
+                let point = slot.point.load(Ordering::Acquire);
+                (point, slot.panicking.swap(true, Ordering::SeqCst))
+            }
+            None => (ptr::null_mut(), PANICKING.swap(true, Ordering::SeqCst)),
+        }
+    }
+
+    /// Chamado por `task::Lock` ao adquirir o lock.
+    pub(crate) fn lock_acquired() {
+        if let Some(slot) = current_slot() {
+            slot.locks.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Chamado por `task::Lock` ao liberar o lock.
+    pub(crate) fn lock_released() {
+        if let Some(slot) = current_slot() {
+            // Locks adquiridos antes de reservar o slot não foram contados
+            let _ = slot
+                .locks
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1));
+        }
+    }
+
+    /// A thread adquiriu um lock depois de entrar em `point` e não o liberou.
+    pub(super) fn holds_locks(point: *mut RecoveryPoint) -> bool {
+        match current_slot() {
+            Some(slot) => slot.locks.load(Ordering::Relaxed) > unsafe { (*point).locks },
+            None => false,
+        }
+    }
+
+    /// Informações de um panic capturado.
+    pub struct PanicPayload {
+        message: LineBuffer,
+        file: LineBuffer,
+        line: u32,
+        column: u32,
+    }
+
+    impl PanicPayload {
+        /// Mensagem do panic (truncada em 256 bytes).
+        pub fn message(&self) -> &str {
+            self.message.as_str()
+        }
+
+        /// Arquivo onde ocorreu o panic (vazio se desconhecido).
+        pub fn file(&self) -> &str {
+            self.file.as_str()
+        }
+
+        /// Linha onde ocorreu o panic.
+        pub fn line(&self) -> u32 {
+            self.line
+        }
+
+        /// Coluna onde ocorreu o panic.
+        pub fn column(&self) -> u32 {
+            self.column
+        }
+    }
+
+    impl core::fmt::Debug for PanicPayload {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(
+                f,
+                "panicked at {}:{}:{}: {}",
+                self.file(),
+                self.line,
+                self.column,
+                self.message()
+            )
+        }
+    }
+
+    /// Contexto salvo na entrada de [`catch_unwind`].
+    #[repr(C)]
+    pub(super) struct RecoveryPoint {
+        /// rbx, rbp, r12–r15, rsp e endereço de retorno (layout usado pelo asm).
+        regs: [u64; 8],
+        prev: *mut RecoveryPoint,
+        /// `Slot::locks` na entrada.
+        locks: usize,
+        payload: Option<PanicPayload>,
+    }
+
+    extern "C" {
+        fn __redpowder_call_with_recovery(
+            regs: *mut [u64; 8],
+            f: extern "C" fn(*mut u8),
+            data: *mut u8,
+        ) -> u64;
+        fn __redpowder_resume(regs: *const [u64; 8]) -> !;
+    }
+
+    // Salva os registradores callee-saved, chama `f(data)` e retorna 0.
+    // `__redpowder_resume` restaura o contexto e faz a chamada retornar 1.
+    core::arch::global_asm!(
+        ".global __redpowder_call_with_recovery",
+        "__redpowder_call_with_recovery:",
+        "mov [rdi + 0], rbx",
+        "mov [rdi + 8], rbp",
+        "mov [rdi + 16], r12",
+        "mov [rdi + 24], r13",
+        "mov [rdi + 32], r14",
+        "mov [rdi + 40], r15",
+        "lea rax, [rsp + 8]",
+        "mov [rdi + 48], rax",
+        "mov rax, [rsp]",
+        "mov [rdi + 56], rax",
+        "sub rsp, 8",
+        "mov rdi, rdx",
+        "call rsi",
+        "add rsp, 8",
+        "xor eax, eax",
+        "ret",
+        ".global __redpowder_resume",
+        "__redpowder_resume:",
+        "mov rbx, [rdi + 0]",
+        "mov rbp, [rdi + 8]",
+        "mov r12, [rdi + 16]",
+        "mov r13, [rdi + 24]",
+        "mov r14, [rdi + 32]",
+        "mov r15, [rdi + 40]",
+        "mov rsp, [rdi + 48]",
+        "mov eax, 1",
+        "jmp qword ptr [rdi + 56]",
+    );
+
+    /// Executa `f`, retornando `Err` se ela entrar em panic.
+    ///
+    /// Com [`MAX_RECOVERY_THREADS`] threads já dentro de `catch_unwind`,
+    /// `f` executa sem ponto de recuperação (um panic encerra o processo).
+    ///
+    /// # Safety
+    /// Um panic descarta os frames entre esta chamada e o ponto do panic
+    /// **sem executar destrutores**. Nesses frames (inclusive as capturas
+    /// de `f`) não pode haver valores cujo `Drop` importe: guards de lock,
+    /// handles (`Port`, `File`...), `Box`/`Vec` ou qualquer tipo que mantenha
+    /// um invariante no `Drop`. O estado que `f` modifica deve poder ser
+    /// descartado depois de um `Err`.
+    ///
+    /// Se `f` entrar em panic com um `task::Lock` do SDK adquirido, o
+    /// processo é encerrado em vez de retornar `Err`.
+    pub unsafe fn catch_unwind<F, R>(f: F) -> Result<R, Box<PanicPayload>>
+    where
+        F: FnOnce() -> R + UnwindSafe,
+    {
+        extern "C" fn trampoline<F: FnOnce() -> R, R>(data: *mut u8) {
+            let slot = unsafe { &mut *(data as *mut (Option<F>, Option<R>)) };
+            if let Some(f) = slot.0.take() {
+                slot.1 = Some(f());
+            }
+        }
+
+        let Some(thread) = claim(sys_gettid() + 1) else {
+            return Ok(f());
+        };
+
+        let mut slot: (Option<F>, Option<R>) = (Some(f), None);
+        let mut point = RecoveryPoint {
+            regs: [0; 8],
+            prev: thread.point.load(Ordering::Acquire),
+            locks: thread.locks.load(Ordering::Relaxed),
+            payload: None,
+        };
+
+        thread.point.store(&mut point, Ordering::Release);
+        let caught = unsafe {
+            __redpowder_call_with_recovery(
+                &mut point.regs,
+                trampoline::<F, R>,
+                &mut slot as *mut (Option<F>, Option<R>) as *mut u8,
+            )
+        };
+        release(thread, point.prev);
+
+        match (caught, slot.1.take()) {
+            (0, Some(value)) => Ok(value),
+            _ => Err(Box::new(point.payload.take().unwrap_or(PanicPayload {
+                message: LineBuffer::new(),
+                file: LineBuffer::new(),
+                line: 0,
+                column: 0,
+            }))),
+        }
+    }
+
+    /// Preenche o payload e retorna ao `catch_unwind` mais interno.
+    pub(super) unsafe fn recover(point: *mut RecoveryPoint, info: &PanicInfo) -> ! {
+        let mut payload = PanicPayload {
+            message: LineBuffer::new(),
+            file: LineBuffer::new(),
+            line: 0,
+            column: 0,
+        };
+        let _ = write!(payload.message, "{}", info.message());
+        if let Some(loc) = info.location() {
+            let _ = payload.file.write_str(loc.file());
+            payload.line = loc.line();
+            payload.column = loc.column();
+        }
+
+        unsafe { (*point).payload = Some(payload) };
+        if let Some(slot) = current_slot() {
+            slot.panicking.store(false, Ordering::SeqCst);
+        }
+        unsafe { __redpowder_resume(&(*point).regs) }
+    }
+
+    /// Restaura o ponto anterior; libera o slot ao sair do mais externo.
+    fn release(thread: &Slot, prev: *mut RecoveryPoint) {
+        thread.point.store(prev, Ordering::Release);
+        if prev.is_null() {
+            thread.locks.store(0, Ordering::Relaxed);
+            thread.panicking.store(false, Ordering::Relaxed);
+            thread.owner.store(0, Ordering::Release);
+            ACTIVE.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+// =============================================================================
+// BUFFER DE LINHA
+// =============================================================================
+
+/// Buffer fixo para formatar a mensagem sem alocação.
+struct LineBuffer {
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl LineBuffer {
+    fn new() -> Self {
+        Self {
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Truncamento pode cortar um caractere multi-byte no meio
+        match core::str::from_utf8(&self.buf[..self.len]) {
+            Ok(s) => s,
+            Err(e) => unsafe { core::str::from_utf8_unchecked(&self.buf[..e.valid_up_to()]) },
+        }
+    }
+}
+
+impl Write for LineBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        let n = bytes.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+//! # Report
+//!
+//! Pacote de diagnóstico para relatórios de bug: identificação do app,
+//! informações do sistema, backtrace e as últimas linhas de log do
+//! processo.
+//!
+//! O pacote é um documento de texto UTF-8 com uma seção por fonte (o SDK
+//! ainda não tem módulos de arquivo/compressão); o app decide como
+//! enviá-lo ou salvá-lo.
+//!
+//! As linhas de log só são coletadas com a feature `log`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::runtime::report;
+//!
+//! report::set_app_info("editor", "1.4.0");
+//!
+//! if let Err(err) = save_document() {
+//!     let bundle = report::bundle_with_error(&err);
+//!     fs::write_file("/tmp/editor-report.txt", &bundle)?;
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::arch::asm;
+use core::fmt::{self, Write};
+
+use crate::task::Lock;
+
+/// Linhas de log guardadas para o pacote.
+pub const LOG_TAIL_LINES: usize = 64;
+
+/// Número máximo de frames no backtrace.
+const MAX_FRAMES: usize = 32;
+
+/// Nome e versão do app.
+static APP_INFO: Lock<Option<(String, String)>> = Lock::new(None);
+
+/// Últimas linhas de log do processo.
+static LOG_TAIL: Lock<VecDeque<String>> = Lock::new(VecDeque::new());
+
+// =============================================================================
+// COLETA
+// =============================================================================
+
+/// Define o nome e a versão do app incluídos nos relatórios.
+pub fn set_app_info(name: &str, version: &str) {
+    APP_INFO.with(|info| *info = Some((name.to_string(), version.to_string())));
+}
+
+/// Guarda uma linha de log (chamado pelo logger).
+#[cfg_attr(not(feature = "log"), allow(dead_code))]
+pub(crate) fn record_log(line: &str) {
+    LOG_TAIL.with(|tail| {
+        if tail.len() == LOG_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(line.trim_end().to_string());
+    });
+}
+
+/// Endereços de retorno da pilha atual (frame pointers).
+pub fn backtrace() -> Vec<usize> {
+    let mut frames = Vec::new();
+    walk_frames(MAX_FRAMES, |_, ret| frames.push(ret));
+    frames
+}
+
+/// Percorre a cadeia de RBP chamando `f(índice, endereço de retorno)`.
+///
+/// Só é confiável com `-C force-frame-pointers=yes`.
+#[inline(always)]
+pub(crate) fn walk_frames(max_frames: usize, mut f: impl FnMut(usize, usize)) {
+    let mut rbp: usize;
+    unsafe { asm!("mov {}, rbp", out(reg) rbp, options(nomem, nostack)) };
+
+    for i in 0..max_frames {
+        if rbp == 0 || !rbp.is_multiple_of(core::mem::align_of::<usize>()) {
+            break;
+        }
+
+        // Layout do frame: [rbp] = rbp anterior, [rbp + 8] = endereço de retorno
+        let (next, ret) = unsafe {
+            let frame = rbp as *const usize;
+            (frame.read(), frame.add(1).read())
+        };
+        if ret == 0 {
+            break;
+        }
+
+        f(i, ret);
+
+        // A pilha cresce para baixo: frames anteriores ficam em endereços maiores
+        if next <= rbp {
+            break;
+        }
+        rbp = next;
+    }
+}
+
+// =============================================================================
+// PACOTE
+// =============================================================================
+
+/// Gera o pacote de diagnóstico.
+pub fn bundle() -> Vec<u8> {
+    build(None)
+}
+
+/// Gera o pacote de diagnóstico com a descrição do erro.
+pub fn bundle_with_error(error: &dyn fmt::Display) -> Vec<u8> {
+    build(Some(error))
+}
+
+fn build(error: Option<&dyn fmt::Display>) -> Vec<u8> {
+    let mut out = String::new();
+    let _ = write_report(&mut out, error);
+    out.into_bytes()
+}
+
+fn write_report(out: &mut String, error: Option<&dyn fmt::Display>) -> fmt::Result {
+    writeln!(out, "# Relatório de erro")?;
+    match APP_INFO.with(|info| info.clone()) {
+        Some((name, version)) => writeln!(out, "app: {} {}", name, version)?,
+        None => writeln!(out, "app: (desconhecido)")?,
+    }
+    writeln!(out, "pid: {}", crate::process::getpid())?;
+
+    if let Some(error) = error {
+        writeln!(out, "\n## Erro\n{}", error)?;
+    }
+
+    writeln!(out, "\n## Sistema")?;
+    match crate::sys::sysinfo() {
+        Ok(info) => {
+            writeln!(
+                out,
+                "kernel: {} (abi {})",
+                info.kernel_version, info.abi_version
+            )?;
+            writeln!(
+                out,
+                "memória: {} KiB livres de {} KiB",
+                info.free_memory / 1024,
+                info.total_memory / 1024
+            )?;
+            writeln!(out, "uptime: {} ms", info.uptime_ms)?;
+            writeln!(out, "cpus: {}", info.num_cpus)?;
+            writeln!(out, "processos: {}", info.num_processes)?;
+        }
+        Err(err) => writeln!(out, "indisponível ({:?})", err)?,
+    }
+
+    writeln!(out, "\n## Backtrace")?;
+    for (i, ret) in backtrace().into_iter().enumerate() {
+        writeln!(out, "#{:02} {:#018x}", i, ret)?;
+    }
+
+    writeln!(out, "\n## Log")?;
+    LOG_TAIL.with(|tail| tail.iter().try_for_each(|line| writeln!(out, "{}", line)))
+}
@@ -0,0 +1,200 @@
+//! # Startup Handles
+//!
+//! Handles passados pelo processo pai via [`crate::process::Command`].
+//!
+//! A tabela é lida do kernel na primeira consulta e cada handle é entregue
+//! uma única vez: [`startup_handle`] e [`startup_handles`] retiram as
+//! entradas devolvidas, então um mesmo handle nunca ganha dois donos.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::runtime;
+//!
+//! if let Some(h) = runtime::startup_handle(2) {
+//!     let port = h.into_port().unwrap();
+//! }
+//! ```
+
+use crate::fs::{File, OpenFlags};
+use crate::io::Handle;
+use crate::ipc::{Port, ShmId};
+use crate::process::{HandleKind, HandleTransfer, MAX_STARTUP_HANDLES};
+use crate::syscall::safe::sys_get_startup_handles;
+use crate::syscall::SysResult;
+use crate::task::Lock;
+
+// =============================================================================
+// STARTUP HANDLE
+// =============================================================================
+
+/// Handle recebido do processo pai.
+///
+/// Não é `Copy`: [`into_port`](Self::into_port) e
+/// [`into_file`](Self::into_file) criam o dono do handle.
+#[derive(Debug)]
+pub struct StartupHandle {
+    raw: HandleTransfer,
+}
+
+impl StartupHandle {
+    /// Tipo do recurso.
+    pub fn kind(&self) -> HandleKind {
+        HandleKind::from_u32(self.raw.kind)
+    }
+
+    /// Tag escolhida pelo pai.
+    pub fn tag(&self) -> u32 {
+        self.raw.tag
+    }
+
+    /// Handle no processo atual (None para SHM).
+    pub fn handle(&self) -> Option<Handle> {
+        match self.kind() {
+            HandleKind::Shm => None,
+            _ => Some(Handle::from_raw(self.raw.value as u32)),
+        }
+    }
+
+    /// ID da região compartilhada (apenas para SHM).
+    pub fn shm_id(&self) -> Option<ShmId> {
+        match self.kind() {
+            HandleKind::Shm => Some(ShmId(self.raw.value)),
+            _ => None,
+        }
+    }
+
+    /// Converte em porta de IPC.
+    pub fn into_port(self) -> Option<Port> {
+        match self.kind() {
+            HandleKind::Port => Some(unsafe { Port::from_raw_handle(self.raw.value as u32) }),
+            _ => None,
+        }
+    }
+
+    /// Converte em arquivo aberto com as flags informadas.
+    pub fn into_file(self, flags: OpenFlags) -> Option<File> {
+        match self.kind() {
+            HandleKind::Generic => {
+                Some(unsafe { File::from_raw_handle(self.raw.value as u32, flags) })
+            }
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// TABELA
+// =============================================================================
+
+/// Handles recebidos na inicialização, já retirados da tabela.
+pub struct StartupHandles {
+    entries: [HandleTransfer; MAX_STARTUP_HANDLES],
+    len: usize,
+    pos: usize,
+}
+
+impl StartupHandles {
+    /// Número de handles recebidos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica se nenhum handle foi recebido.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Iterator for StartupHandles {
+    type Item = StartupHandle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let raw = self.entries[self.pos];
+        self.pos += 1;
+        Some(StartupHandle { raw })
+    }
+}
+
+/// Retira todos os handles ainda não entregues.
+///
+/// Chamadas seguintes (e [`startup_handle`]) não os devolvem de novo.
+pub fn startup_handles() -> SysResult<StartupHandles> {
+    let mut handles = StartupHandles {
+        entries: [HandleTransfer::EMPTY; MAX_STARTUP_HANDLES],
+        len: 0,
+        pos: 0,
+    };
+    take(
+        |_| true,
+        |raw| {
+            handles.entries[handles.len] = raw;
+            handles.len += 1;
+            true
+        },
+    )?;
+    Ok(handles)
+}
+
+/// Retira o handle com a tag informada.
+///
+/// Retorna `None` se não existir ou já tiver sido entregue.
+pub fn startup_handle(tag: u32) -> Option<StartupHandle> {
+    let mut found = None;
+    take(
+        |raw| raw.tag == tag,
+        |raw| {
+            found = Some(StartupHandle { raw });
+            false
+        },
+    )
+    .ok()?;
+    found
+}
+
+// =============================================================================
+// ESTADO DO PROCESSO
+// =============================================================================
+
+/// Cópia da tabela do kernel e quais entradas já foram entregues.
+struct Table {
+    entries: [HandleTransfer; MAX_STARTUP_HANDLES],
+    taken: [bool; MAX_STARTUP_HANDLES],
+    len: usize,
+    loaded: bool,
+}
+
+static TABLE: Lock<Table> = Lock::new(Table {
+    entries: [HandleTransfer::EMPTY; MAX_STARTUP_HANDLES],
+    taken: [false; MAX_STARTUP_HANDLES],
+    len: 0,
+    loaded: false,
+});
+
+/// Entrega a `out` as entradas pendentes que satisfazem `matches`, até
+/// `out` retornar `false`. Carrega a tabela na primeira chamada.
+fn take(
+    matches: impl Fn(&HandleTransfer) -> bool,
+    mut out: impl FnMut(HandleTransfer) -> bool,
+) -> SysResult<()> {
+    TABLE.with(|table| {
+        if !table.loaded {
+            let len = sys_get_startup_handles(&mut table.entries)?;
+            table.len = len.min(MAX_STARTUP_HANDLES);
+            table.loaded = true;
+        }
+        for i in 0..table.len {
+            if table.taken[i] || !matches(&table.entries[i]) {
+                continue;
+            }
+            table.taken[i] = true;
+            if !out(table.entries[i]) {
+                break;
+            }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,390 @@
+//! # Scan Client
+//!
+//! Listagem de scanners e aquisição de imagens.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::scan::{self, ColorMode, ScanOptions};
+//!
+//! let preview = scan::preview(&ScanOptions::default())?;
+//! // mostrar preview, usuário escolhe a área (em mm)...
+//!
+//! let options = ScanOptions::default()
+//!     .with_dpi(300)
+//!     .with_mode(ColorMode::Gray)
+//!     .with_area(area);
+//! let page = scan::acquire_with_progress(&options, |p| {
+//!     println!("{}%", p.percent());
+//!     true // false cancela
+//! })?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::geometry::RectF;
+
+use crate::graphics::Surface;
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, str_from_fixed, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Tempo máximo sem notícias do scanner durante a aquisição (ms).
+const SCAN_IDLE_TIMEOUT_MS: u64 = 60_000;
+
+// =============================================================================
+// SCANNERS
+// =============================================================================
+
+/// Origem do papel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanSource {
+    /// Mesa de vidro.
+    #[default]
+    Flatbed,
+    /// Alimentador automático de documentos.
+    Adf,
+}
+
+/// Modo de cor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    Color,
+    Gray,
+    /// Preto e branco (1 bit).
+    Lineart,
+}
+
+/// Scanner conhecido pelo serviço.
+#[derive(Clone, Copy)]
+pub struct Scanner {
+    entry: ScannerEntry,
+}
+
+impl Scanner {
+    /// Identificador (para [`ScanOptions::with_scanner`]).
+    pub fn id(&self) -> u32 {
+        self.entry.scanner_id
+    }
+
+    /// Nome do dispositivo.
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Scanner padrão do sistema?
+    pub fn is_default(&self) -> bool {
+        self.entry.is_default != 0
+    }
+
+    /// Suporta a origem?
+    pub fn has_source(&self, source: ScanSource) -> bool {
+        self.entry.sources & source_flag(source) != 0
+    }
+
+    /// Suporta o modo de cor?
+    pub fn has_mode(&self, mode: ColorMode) -> bool {
+        self.entry.modes & mode_flag(mode) != 0
+    }
+
+    /// Resolução óptica máxima.
+    pub fn max_dpi(&self) -> u32 {
+        self.entry.max_dpi
+    }
+
+    /// Área máxima digitalizável (mm).
+    pub fn max_area_mm(&self) -> (f32, f32) {
+        (
+            self.entry.max_width as f32 / 10.0,
+            self.entry.max_height as f32 / 10.0,
+        )
+    }
+}
+
+impl core::fmt::Debug for Scanner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Scanner")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .field("max_dpi", &self.max_dpi())
+            .field("is_default", &self.is_default())
+            .finish()
+    }
+}
+
+/// Scanners disponíveis.
+pub fn devices() -> SysResult<Vec<Scanner>> {
+    let (reply_port, reply_name) = Port::create_unique("scan.r.", 4)?;
+    let server_port = Port::connect(SCANNER_PORT)?;
+
+    let mut scanners = Vec::new();
+    loop {
+        let req = ListScannersRequest {
+            op: opcodes::LIST_SCANNERS,
+            start: scanners.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<ScanMessage>(
+            &reply_port,
+            opcodes::SCANNER_LIST,
+            core::mem::size_of::<ScannerListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.scanner_list };
+
+        let count = (page.count as usize).min(SCANNERS_PER_MSG);
+        scanners.extend(page.entries[..count].iter().map(|&entry| Scanner { entry }));
+        if count == 0 || scanners.len() >= page.total as usize {
+            return Ok(scanners);
+        }
+    }
+}
+
+// =============================================================================
+// AQUISIÇÃO
+// =============================================================================
+
+/// Parâmetros de digitalização.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScanOptions {
+    /// Scanner (None = padrão do sistema).
+    pub scanner: Option<u32>,
+    pub source: ScanSource,
+    pub mode: ColorMode,
+    pub dpi: u32,
+    /// Área em milímetros a partir do canto do vidro (None = inteira).
+    pub area: Option<RectF>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            scanner: None,
+            source: ScanSource::Flatbed,
+            mode: ColorMode::Color,
+            dpi: 150,
+            area: None,
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Seleciona o scanner.
+    pub fn with_scanner(mut self, scanner: &Scanner) -> Self {
+        self.scanner = Some(scanner.id());
+        self
+    }
+
+    /// Origem do papel.
+    pub fn with_source(mut self, source: ScanSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Modo de cor.
+    pub fn with_mode(mut self, mode: ColorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Resolução.
+    pub fn with_dpi(mut self, dpi: u32) -> Self {
+        self.dpi = dpi;
+        self
+    }
+
+    /// Área a digitalizar (mm).
+    pub fn with_area(mut self, area: RectF) -> Self {
+        self.area = Some(area);
+        self
+    }
+}
+
+/// Progresso de uma digitalização.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// Dimensões da imagem final.
+    pub width: u32,
+    pub height: u32,
+    pub lines_done: u32,
+    pub total_lines: u32,
+}
+
+impl ScanProgress {
+    /// Percentual concluído (0-100).
+    pub fn percent(&self) -> u32 {
+        if self.total_lines == 0 {
+            return 0;
+        }
+        (self.lines_done.min(self.total_lines) as u64 * 100 / self.total_lines as u64) as u32
+    }
+}
+
+/// Digitaliza uma imagem.
+pub fn acquire(options: &ScanOptions) -> SysResult<Surface> {
+    scan(options, 0, |_| true)
+}
+
+/// Digitaliza informando o progresso; `progress` retornando `false`
+/// cancela (resultado `Err(Interrupted)`).
+pub fn acquire_with_progress<F>(options: &ScanOptions, progress: F) -> SysResult<Surface>
+where
+    F: FnMut(ScanProgress) -> bool,
+{
+    scan(options, 0, progress)
+}
+
+/// Pré-visualização rápida em baixa resolução (`dpi` é ignorado).
+pub fn preview(options: &ScanOptions) -> SysResult<Surface> {
+    scan(options, scan_flags::PREVIEW, |_| true)
+}
+
+fn scan<F>(options: &ScanOptions, flags: u32, mut progress: F) -> SysResult<Surface>
+where
+    F: FnMut(ScanProgress) -> bool,
+{
+    if options.dpi == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+    // Área em décimos de mm
+    let (ax, ay, aw, ah) = match options.area {
+        Some(r) if r.width <= 0.0 || r.height <= 0.0 || r.x < 0.0 || r.y < 0.0 => {
+            return Err(SysError::InvalidArgument)
+        }
+        Some(r) => (
+            (r.x * 10.0) as u32,
+            (r.y * 10.0) as u32,
+            (r.width * 10.0) as u32,
+            (r.height * 10.0) as u32,
+        ),
+        None => (0, 0, 0, 0),
+    };
+
+    let (reply_port, reply_name) = Port::create_unique("scan.r.", 16)?;
+    let server_port = Port::connect(SCANNER_PORT)?;
+    let req = StartScanRequest {
+        op: opcodes::START_SCAN,
+        scanner_id: options.scanner.unwrap_or(DEFAULT_SCANNER),
+        source: source_flag(options.source),
+        mode: mode_flag(options.mode),
+        dpi: options.dpi,
+        flags,
+        area_x: ax,
+        area_y: ay,
+        area_width: aw,
+        area_height: ah,
+        reply_port: reply_name,
+    };
+    server_port.send(as_bytes(&req), 0)?;
+
+    let started = unsafe {
+        recv_reply::<ScanMessage>(
+            &reply_port,
+            opcodes::SCAN_STARTED,
+            core::mem::size_of::<ScanStartedResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?
+        .started
+    };
+    let cancel = || {
+        let req = CancelRequest {
+            op: opcodes::CANCEL,
+            scan_id: started.scan_id,
+        };
+        let _ = server_port.send(as_bytes(&req), 0);
+    };
+
+    loop {
+        let mut msg = ScanMessage {
+            raw: [0; MAX_MSG_SIZE],
+        };
+        if reply_port.recv(as_bytes_mut(&mut msg), SCAN_IDLE_TIMEOUT_MS)? == 0 {
+            cancel();
+            return Err(SysError::Timeout);
+        }
+
+        match unsafe { msg.header } {
+            opcodes::SCAN_PROGRESS => {
+                let event = unsafe { msg.progress };
+                if event.scan_id != started.scan_id {
+                    continue;
+                }
+                let keep_going = progress(ScanProgress {
+                    width: started.width,
+                    height: started.height,
+                    lines_done: event.lines_done,
+                    total_lines: event.total_lines,
+                });
+                if !keep_going {
+                    cancel();
+                    return Err(SysError::Interrupted);
+                }
+            }
+            opcodes::SCAN_DONE => {
+                let done = unsafe { msg.done };
+                if done.scan_id != started.scan_id {
+                    continue;
+                }
+                return read_image(&done);
+            }
+            opcodes::ERROR => {
+                return Err(SysError::from_code(
+                    unsafe { msg.error.code } as i32 as isize
+                ))
+            }
+            // Mensagem desconhecida: descartar e continuar
+            _ => continue,
+        }
+    }
+}
+
+/// Copia a imagem da SHM para uma [`Surface`].
+fn read_image(done: &ScanDoneResponse) -> SysResult<Surface> {
+    let (width, height, stride) = (done.width, done.height, done.stride as usize);
+    if stride < width as usize * 4 {
+        return Err(SysError::ProtocolError);
+    }
+    let shm = SharedMemory::open(ShmId(done.shm_handle))?;
+    let data = shm.as_slice();
+    if data.len() < stride * height as usize {
+        return Err(SysError::ProtocolError);
+    }
+
+    let mut surface = Surface::new(width, height);
+    let pixels = surface.pixels_mut();
+    for (y, line) in data.chunks_exact(stride).take(height as usize).enumerate() {
+        let out = &mut pixels[y * width as usize..(y + 1) * width as usize];
+        for (px, bytes) in out.iter_mut().zip(line.chunks_exact(4)) {
+            *px = 0xFF00_0000 | u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+    }
+    Ok(surface)
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+fn source_flag(source: ScanSource) -> u32 {
+    match source {
+        ScanSource::Flatbed => sources::FLATBED,
+        ScanSource::Adf => sources::ADF,
+    }
+}
+
+fn mode_flag(mode: ColorMode) -> u32 {
+    match mode {
+        ColorMode::Color => modes::COLOR,
+        ColorMode::Gray => modes::GRAY,
+        ColorMode::Lineart => modes::LINEART,
+    }
+}
@@ -0,0 +1,24 @@
+//! # Digitalização
+//!
+//! Cliente do serviço de scanners: dispositivos disponíveis, pré-visualização
+//! e aquisição de imagens com progresso.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do serviço de scanners |
+//! | [`client`] | Scanners e aquisição |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    acquire, acquire_with_progress, devices, preview, ColorMode, ScanOptions, ScanProgress,
+    ScanSource, Scanner,
+};
+pub use protocol::SCANNER_PORT;
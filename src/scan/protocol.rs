@@ -0,0 +1,206 @@
+//! # Protocolo de Digitalização
+//!
+//! Definições de mensagens do protocolo com o serviço de scanners.
+//!
+//! Uma digitalização responde `SCAN_STARTED`, envia `SCAN_PROGRESS`
+//! durante a aquisição e termina com `SCAN_DONE`, que traz uma SHM com a
+//! imagem (XRGB8888, `stride` bytes por linha), ou `ERROR`.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de scanners.
+pub const SCANNER_PORT: &str = "scan.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 512;
+
+/// Tamanho máximo do nome do scanner.
+pub const SCANNER_NAME_MAX: usize = 48;
+
+/// Scanners por página de `SCANNER_LIST`.
+pub const SCANNERS_PER_MSG: usize = 4;
+
+/// Id de scanner que seleciona o padrão do sistema.
+pub const DEFAULT_SCANNER: u32 = 0;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const LIST_SCANNERS: u32 = 0x01;
+    pub const START_SCAN: u32 = 0x02;
+    pub const CANCEL: u32 = 0x03;
+
+    // Server -> Client
+    pub const SCANNER_LIST: u32 = 0x10;
+    pub const SCAN_STARTED: u32 = 0x11;
+    pub const SCAN_PROGRESS: u32 = 0x12;
+    pub const SCAN_DONE: u32 = 0x13;
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Fontes de papel (bitflags em `ScannerEntry::sources`).
+pub mod sources {
+    pub const FLATBED: u32 = 1 << 0;
+    /// Alimentador automático.
+    pub const ADF: u32 = 1 << 1;
+}
+
+/// Modos de cor (bitflags em `ScannerEntry::modes`).
+pub mod modes {
+    pub const COLOR: u32 = 1 << 0;
+    pub const GRAY: u32 = 1 << 1;
+    /// Preto e branco (1 bit, entregue como 0x000000/0xFFFFFF).
+    pub const LINEART: u32 = 1 << 2;
+}
+
+/// Opções de digitalização (bitflags).
+pub mod scan_flags {
+    /// Pré-visualização rápida em baixa resolução (ignora `dpi`).
+    pub const PREVIEW: u32 = 1 << 0;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de listagem de scanners (paginada a partir de `start`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListScannersRequest {
+    pub op: u32,
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de digitalização.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StartScanRequest {
+    pub op: u32,
+    /// Scanner (`DEFAULT_SCANNER` = padrão).
+    pub scanner_id: u32,
+    /// Uma das flags de [`sources`].
+    pub source: u32,
+    /// Uma das flags de [`modes`].
+    pub mode: u32,
+    pub dpi: u32,
+    pub flags: u32,
+    /// Área em décimos de milímetro (largura 0 = área inteira).
+    pub area_x: u32,
+    pub area_y: u32,
+    pub area_width: u32,
+    pub area_height: u32,
+    /// Porta de resposta e progresso.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de cancelamento.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CancelRequest {
+    pub op: u32,
+    pub scan_id: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Scanner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScannerEntry {
+    pub scanner_id: u32,
+    pub sources: u32,
+    pub modes: u32,
+    pub max_dpi: u32,
+    /// Área máxima em décimos de milímetro.
+    pub max_width: u32,
+    pub max_height: u32,
+    pub is_default: u8,
+    pub _pad: [u8; 3],
+    pub name: [u8; SCANNER_NAME_MAX],
+}
+
+/// Página da lista de scanners.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScannerListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [ScannerEntry; SCANNERS_PER_MSG],
+}
+
+/// Digitalização iniciada.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScanStartedResponse {
+    pub op: u32,
+    pub scan_id: u32,
+    /// Dimensões finais da imagem.
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Progresso da digitalização.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScanProgressEvent {
+    pub op: u32,
+    pub scan_id: u32,
+    pub lines_done: u32,
+    pub total_lines: u32,
+}
+
+/// Imagem pronta.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ScanDoneResponse {
+    pub op: u32,
+    pub scan_id: u32,
+    pub shm_handle: u64,
+    pub width: u32,
+    pub height: u32,
+    /// Bytes por linha.
+    pub stride: u32,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union ScanMessage {
+    pub header: u32,
+    pub scanner_list: ScannerListResponse,
+    pub started: ScanStartedResponse,
+    pub progress: ScanProgressEvent,
+    pub done: ScanDoneResponse,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for ScanMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -0,0 +1,251 @@
+//! # Drivers Client
+//!
+//! Listagem de dispositivos/drivers e eventos de hotplug.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::sys::drivers::{self, DriverEvent};
+//!
+//! for dev in drivers::list()? {
+//!     println!("{} -> {}", dev.path(), dev.driver().unwrap_or("(nenhum)"));
+//! }
+//!
+//! let events = drivers::events()?;
+//! while let Some(event) = events.wait(1000) {
+//!     if let DriverEvent::Bound(dev) = event {
+//!         println!("novo: {}", dev.description());
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, str_from_fixed, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Barramento do dispositivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bus {
+    Unknown,
+    Pci,
+    Usb,
+    Virtio,
+    Acpi,
+    Platform,
+}
+
+/// Estado de associação com um driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindState {
+    /// Nenhum driver compatível.
+    Unbound,
+    Bound,
+    /// O driver falhou ao inicializar.
+    Failed(SysError),
+}
+
+/// Dispositivo e o driver associado a ele.
+#[derive(Clone, Copy)]
+pub struct DeviceBinding {
+    entry: DeviceEntry,
+}
+
+impl DeviceBinding {
+    /// Identificador do dispositivo (estável enquanto conectado).
+    pub fn id(&self) -> u32 {
+        self.entry.device_id
+    }
+
+    /// Barramento.
+    pub fn bus(&self) -> Bus {
+        match self.entry.bus {
+            buses::PCI => Bus::Pci,
+            buses::USB => Bus::Usb,
+            buses::VIRTIO => Bus::Virtio,
+            buses::ACPI => Bus::Acpi,
+            buses::PLATFORM => Bus::Platform,
+            _ => Bus::Unknown,
+        }
+    }
+
+    /// Caminho no barramento (ex: `pci/0000:00:03.0`, `usb/1-2`).
+    pub fn path(&self) -> &str {
+        str_from_fixed(&self.entry.path)
+    }
+
+    /// Vendor ID (PCI/USB).
+    pub fn vendor_id(&self) -> u16 {
+        self.entry.vendor_id
+    }
+
+    /// Product/device ID (PCI/USB).
+    pub fn product_id(&self) -> u16 {
+        self.entry.product_id
+    }
+
+    /// Classe do dispositivo (PCI/USB).
+    pub fn class(&self) -> u32 {
+        self.entry.class
+    }
+
+    /// Descrição legível (ex: `Intel 82540EM Gigabit Ethernet`).
+    pub fn description(&self) -> &str {
+        str_from_fixed(&self.entry.description)
+    }
+
+    /// Driver associado (ou que falhou ao associar).
+    pub fn driver(&self) -> Option<&str> {
+        let name = str_from_fixed(&self.entry.driver);
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Estado da associação.
+    pub fn state(&self) -> BindState {
+        match self.entry.state {
+            states::BOUND => BindState::Bound,
+            states::FAILED => {
+                BindState::Failed(SysError::from_code(self.entry.error as i32 as isize))
+            }
+            _ => BindState::Unbound,
+        }
+    }
+
+    /// Driver ativo?
+    pub fn is_bound(&self) -> bool {
+        self.entry.state == states::BOUND
+    }
+}
+
+impl core::fmt::Debug for DeviceBinding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DeviceBinding")
+            .field("id", &self.id())
+            .field("bus", &self.bus())
+            .field("path", &self.path())
+            .field("driver", &self.driver())
+            .field("state", &self.state())
+            .finish()
+    }
+}
+
+// =============================================================================
+// LISTAGEM
+// =============================================================================
+
+/// Dispositivos conhecidos e seus drivers.
+pub fn list() -> SysResult<Vec<DeviceBinding>> {
+    let (reply_port, reply_name) = Port::create_unique("devmgr.r.", 4)?;
+    let server_port = Port::connect(DEVMGR_PORT)?;
+
+    let mut devices = Vec::new();
+    loop {
+        let req = ListRequest {
+            op: opcodes::LIST,
+            start: devices.len() as u32,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<DevmgrMessage>(
+            &reply_port,
+            opcodes::DEVICE_LIST,
+            core::mem::size_of::<DeviceListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.device_list };
+
+        let count = (page.count as usize).min(DEVICES_PER_MSG);
+        devices.extend(
+            page.entries[..count]
+                .iter()
+                .map(|&entry| DeviceBinding { entry }),
+        );
+        if count == 0 || devices.len() >= page.total as usize {
+            return Ok(devices);
+        }
+    }
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Mudança de dispositivo ou driver.
+#[derive(Debug, Clone, Copy)]
+pub enum DriverEvent {
+    /// Dispositivo conectado (ainda sem driver).
+    Added(DeviceBinding),
+    /// Dispositivo desconectado.
+    Removed(DeviceBinding),
+    /// Driver associado ao dispositivo.
+    Bound(DeviceBinding),
+    /// Driver desassociado (descarregado ou dispositivo removido).
+    Unbound(DeviceBinding),
+    /// O driver falhou ao inicializar (ver [`DeviceBinding::state`]).
+    BindFailed(DeviceBinding),
+}
+
+/// Receptor de eventos de dispositivos.
+pub struct DriverListener {
+    port: Port,
+}
+
+/// Registra um receptor de eventos (hotplug e associação de drivers).
+pub fn events() -> SysResult<DriverListener> {
+    let (port, name) = Port::create_unique("devmgr.ev.", 16)?;
+    let req = SubscribeRequest {
+        op: opcodes::SUBSCRIBE,
+        listener_port: name,
+    };
+    Port::connect(DEVMGR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(DriverListener { port })
+}
+
+impl DriverListener {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<DriverEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<DriverEvent> {
+        loop {
+            let mut msg = DevmgrMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<DeviceEvent>() {
+                return None;
+            }
+            let device = DeviceBinding {
+                entry: unsafe { msg.event.entry },
+            };
+            match unsafe { msg.header } {
+                opcodes::DEVICE_ADDED => return Some(DriverEvent::Added(device)),
+                opcodes::DEVICE_REMOVED => return Some(DriverEvent::Removed(device)),
+                opcodes::DRIVER_BOUND => return Some(DriverEvent::Bound(device)),
+                opcodes::DRIVER_UNBOUND => return Some(DriverEvent::Unbound(device)),
+                opcodes::BIND_FAILED => return Some(DriverEvent::BindFailed(device)),
+                // Mensagem desconhecida: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
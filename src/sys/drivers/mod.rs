@@ -0,0 +1,22 @@
+//! # Drivers
+//!
+//! Cliente do gerenciador de dispositivos: quais drivers estão associados a
+//! quais dispositivos e eventos de hotplug, para a tela de configurações de
+//! dispositivos.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do gerenciador de dispositivos |
+//! | [`client`] | Listagem e eventos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{events, list, BindState, Bus, DeviceBinding, DriverEvent, DriverListener};
+pub use protocol::DEVMGR_PORT;
@@ -0,0 +1,161 @@
+//! # Protocolo de Drivers
+//!
+//! Definições de mensagens do protocolo com o gerenciador de dispositivos,
+//! que enumera os barramentos e associa drivers aos dispositivos.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do gerenciador de dispositivos.
+pub const DEVMGR_PORT: &str = "devmgr.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 512;
+
+/// Tamanho máximo do caminho do dispositivo (ex: `pci/0000:00:03.0`).
+pub const DEVICE_PATH_MAX: usize = 48;
+
+/// Tamanho máximo do nome do driver.
+pub const DRIVER_NAME_MAX: usize = 32;
+
+/// Tamanho máximo da descrição do dispositivo.
+pub const DESCRIPTION_MAX: usize = 64;
+
+/// Dispositivos por página de `DEVICE_LIST`.
+pub const DEVICES_PER_MSG: usize = 2;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const LIST: u32 = 0x01;
+    pub const SUBSCRIBE: u32 = 0x02;
+
+    // Server -> Client
+    pub const DEVICE_LIST: u32 = 0x10;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> Listener)
+    pub const DEVICE_ADDED: u32 = 0x20;
+    pub const DEVICE_REMOVED: u32 = 0x21;
+    pub const DRIVER_BOUND: u32 = 0x22;
+    pub const DRIVER_UNBOUND: u32 = 0x23;
+    pub const BIND_FAILED: u32 = 0x24;
+}
+
+/// Barramentos.
+pub mod buses {
+    pub const UNKNOWN: u32 = 0;
+    pub const PCI: u32 = 1;
+    pub const USB: u32 = 2;
+    pub const VIRTIO: u32 = 3;
+    pub const ACPI: u32 = 4;
+    pub const PLATFORM: u32 = 5;
+}
+
+/// Estados de associação.
+pub mod states {
+    /// Nenhum driver compatível.
+    pub const UNBOUND: u32 = 0;
+    pub const BOUND: u32 = 1;
+    /// Driver encontrado, mas a inicialização falhou.
+    pub const FAILED: u32 = 2;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de listagem (paginada a partir de `start`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListRequest {
+    pub op: u32,
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de assinatura de eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Dispositivo e driver associado.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceEntry {
+    pub device_id: u32,
+    pub bus: u32,
+    pub state: u32,
+    /// Código de `SysError` da última falha (`FAILED`).
+    pub error: u32,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    /// Classe/subclasse/interface (PCI ou USB).
+    pub class: u32,
+    pub path: [u8; DEVICE_PATH_MAX],
+    /// Vazio se não associado.
+    pub driver: [u8; DRIVER_NAME_MAX],
+    pub description: [u8; DESCRIPTION_MAX],
+}
+
+/// Página da lista de dispositivos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceListResponse {
+    pub op: u32,
+    pub count: u32,
+    pub total: u32,
+    pub entries: [DeviceEntry; DEVICES_PER_MSG],
+}
+
+/// Evento de dispositivo (`DEVICE_*`, `DRIVER_*`, `BIND_FAILED`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceEvent {
+    pub op: u32,
+    pub entry: DeviceEntry,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union DevmgrMessage {
+    pub header: u32,
+    pub list_req: ListRequest,
+    pub device_list: DeviceListResponse,
+    pub event: DeviceEvent,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for DevmgrMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
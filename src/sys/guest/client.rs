@@ -0,0 +1,236 @@
+//! # Guest Client
+//!
+//! Ponte de clipboard e pastas compartilhadas com o host.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::sys::guest;
+//!
+//! if guest::is_virtualized() {
+//!     // Sincroniza o clipboard enquanto `_bridge` estiver vivo
+//!     let _bridge = guest::clipboard_bridge()?;
+//!
+//!     let folder = guest::shared_folder_mount("src", "/mnt/host")?;
+//!     println!("host em {}", folder.mount_point());
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, recv_reply, str_from_fixed, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// CLIPBOARD
+// =============================================================================
+
+/// Direção de uma cópia sincronizada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncDirection {
+    /// Host → convidado.
+    HostToGuest,
+    /// Convidado → host.
+    GuestToHost,
+}
+
+/// Cópia feita pela ponte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipboardSync {
+    pub direction: SyncDirection,
+    /// Tamanho do conteúdo (bytes).
+    pub size: u64,
+}
+
+/// Ponte de clipboard ativa (desligada ao ser descartada).
+///
+/// O serviço copia o conteúdo entre a área de transferência do host e a do
+/// sistema sempre que uma delas muda.
+pub struct ClipboardBridge {
+    port: Port,
+    request: ClipboardRequest,
+}
+
+/// Liga a sincronização de clipboard nos dois sentidos.
+///
+/// Retorna `SysError::NotSupported` se o hypervisor não oferece o canal de
+/// clipboard (ex: QEMU sem agente `vdagent`).
+pub fn clipboard_bridge() -> SysResult<ClipboardBridge> {
+    clipboard_bridge_with(direction::BOTH)
+}
+
+/// Liga a sincronização apenas do host para o convidado.
+pub fn clipboard_bridge_from_host() -> SysResult<ClipboardBridge> {
+    clipboard_bridge_with(direction::HOST_TO_GUEST)
+}
+
+fn clipboard_bridge_with(directions: u32) -> SysResult<ClipboardBridge> {
+    let (port, name) = Port::create_unique("guest.ev.", 16)?;
+    let (reply_port, reply_name) = Port::create_unique("guest.r.", 4)?;
+    let request = ClipboardRequest {
+        op: opcodes::CLIPBOARD_START,
+        directions,
+        listener_port: name,
+        reply_port: reply_name,
+    };
+    Port::connect(GUEST_PORT)?.send(as_bytes(&request), 0)?;
+    recv_reply::<GuestMessage>(
+        &reply_port,
+        opcodes::OK,
+        core::mem::size_of::<OkResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(ClipboardBridge { port, request })
+}
+
+impl ClipboardBridge {
+    /// Retorna a próxima cópia pendente, sem bloquear.
+    pub fn poll(&self) -> Option<ClipboardSync> {
+        self.wait(0)
+    }
+
+    /// Espera uma cópia por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<ClipboardSync> {
+        loop {
+            let mut msg = GuestMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<ClipboardSyncedEvent>() {
+                return None;
+            }
+            if unsafe { msg.header } == opcodes::CLIPBOARD_SYNCED {
+                let event = unsafe { msg.clipboard_synced };
+                let direction = match event.direction {
+                    direction::HOST_TO_GUEST => SyncDirection::HostToGuest,
+                    _ => SyncDirection::GuestToHost,
+                };
+                return Some(ClipboardSync {
+                    direction,
+                    size: event.size,
+                });
+            }
+            // Mensagem desconhecida: descartar e continuar
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
+
+impl Drop for ClipboardBridge {
+    fn drop(&mut self) {
+        let mut req = self.request;
+        req.op = opcodes::CLIPBOARD_STOP;
+        if let Ok(server) = Port::connect(GUEST_PORT) {
+            let _ = server.send(as_bytes(&req), 0);
+        }
+    }
+}
+
+// =============================================================================
+// PASTAS COMPARTILHADAS
+// =============================================================================
+
+/// Pasta do host montada no convidado.
+#[derive(Debug, Clone)]
+pub struct SharedFolder {
+    tag: String,
+    mount_point: String,
+}
+
+impl SharedFolder {
+    /// Tag da pasta (nome definido no host, ex: `-virtfs ...,mount_tag=src`).
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Caminho onde a pasta foi montada.
+    pub fn mount_point(&self) -> &str {
+        &self.mount_point
+    }
+
+    /// Desmonta a pasta.
+    pub fn unmount(self) -> SysResult<()> {
+        mount_request(opcodes::UNMOUNT_FOLDER, &self.tag, &self.mount_point, false)
+    }
+}
+
+/// Tags das pastas compartilhadas pelo host.
+pub fn shared_folders() -> SysResult<Vec<String>> {
+    let (reply_port, reply_name) = Port::create_unique("guest.r.", 4)?;
+    let req = ListFoldersRequest {
+        op: opcodes::LIST_FOLDERS,
+        reply_port: reply_name,
+    };
+    Port::connect(GUEST_PORT)?.send(as_bytes(&req), 0)?;
+
+    let msg = recv_reply::<GuestMessage>(
+        &reply_port,
+        opcodes::FOLDERS,
+        core::mem::size_of::<FoldersResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    let folders = unsafe { msg.folders };
+    let count = (folders.count as usize).min(MAX_FOLDERS);
+    Ok(folders.tags[..count]
+        .iter()
+        .map(|tag| String::from(str_from_fixed(tag)))
+        .collect())
+}
+
+/// Monta a pasta compartilhada `tag` em `mount_point`.
+///
+/// A montagem permanece após o fim do processo até
+/// [`SharedFolder::unmount`].
+pub fn shared_folder_mount(tag: &str, mount_point: &str) -> SysResult<SharedFolder> {
+    mount_with(tag, mount_point, false)
+}
+
+/// Monta a pasta compartilhada `tag` somente leitura.
+pub fn shared_folder_mount_read_only(tag: &str, mount_point: &str) -> SysResult<SharedFolder> {
+    mount_with(tag, mount_point, true)
+}
+
+fn mount_with(tag: &str, mount_point: &str, read_only: bool) -> SysResult<SharedFolder> {
+    if tag.is_empty() || tag.len() >= TAG_MAX {
+        return Err(SysError::InvalidArgument);
+    }
+    if !mount_point.starts_with('/') || mount_point.len() >= PATH_MAX {
+        return Err(SysError::InvalidArgument);
+    }
+    mount_request(opcodes::MOUNT_FOLDER, tag, mount_point, read_only)?;
+    Ok(SharedFolder {
+        tag: String::from(tag),
+        mount_point: String::from(mount_point),
+    })
+}
+
+fn mount_request(op: u32, tag: &str, mount_point: &str, read_only: bool) -> SysResult<()> {
+    let (reply_port, reply_name) = Port::create_unique("guest.r.", 4)?;
+    let req = MountRequest {
+        op,
+        read_only: read_only as u32,
+        tag: fixed_str(tag),
+        mount_point: fixed_str(mount_point),
+        reply_port: reply_name,
+    };
+    Port::connect(GUEST_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<GuestMessage>(
+        &reply_port,
+        opcodes::OK,
+        core::mem::size_of::<OkResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(())
+}
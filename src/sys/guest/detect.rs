@@ -0,0 +1,73 @@
+//! # Detecção de Hypervisor
+//!
+//! Identificação pelo CPUID: bit "hypervisor present" (folha 1, ECX bit 31)
+//! e assinatura do fornecedor na folha `0x4000_0000`.
+
+use core::arch::x86_64::__cpuid;
+
+/// Bit de hypervisor presente em CPUID.1:ECX.
+const CPUID_HYPERVISOR_BIT: u32 = 1 << 31;
+
+/// Folha CPUID reservada para hypervisors.
+const CPUID_HYPERVISOR_LEAF: u32 = 0x4000_0000;
+
+/// Hypervisor detectado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hypervisor {
+    /// QEMU com aceleração KVM.
+    Kvm,
+    /// QEMU em emulação (TCG).
+    Qemu,
+    VMware,
+    HyperV,
+    VirtualBox,
+    Xen,
+    /// Assinatura desconhecida (12 bytes de EBX, ECX, EDX).
+    Other([u8; 12]),
+}
+
+impl Hypervisor {
+    /// Nome legível.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kvm => "KVM",
+            Self::Qemu => "QEMU",
+            Self::VMware => "VMware",
+            Self::HyperV => "Hyper-V",
+            Self::VirtualBox => "VirtualBox",
+            Self::Xen => "Xen",
+            Self::Other(sig) => {
+                let len = sig.iter().position(|&b| b == 0).unwrap_or(sig.len());
+                core::str::from_utf8(&sig[..len]).unwrap_or("?")
+            }
+        }
+    }
+}
+
+/// Executando sob um hypervisor?
+pub fn is_virtualized() -> bool {
+    __cpuid(1).ecx & CPUID_HYPERVISOR_BIT != 0
+}
+
+/// Hypervisor em uso (`None` em hardware real).
+pub fn hypervisor() -> Option<Hypervisor> {
+    if !is_virtualized() {
+        return None;
+    }
+
+    let leaf = __cpuid(CPUID_HYPERVISOR_LEAF);
+    let mut sig = [0u8; 12];
+    sig[0..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+    sig[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+    sig[8..12].copy_from_slice(&leaf.edx.to_le_bytes());
+
+    Some(match &sig {
+        b"KVMKVMKVM\0\0\0" => Hypervisor::Kvm,
+        b"TCGTCGTCGTCG" => Hypervisor::Qemu,
+        b"VMwareVMware" => Hypervisor::VMware,
+        b"Microsoft Hv" => Hypervisor::HyperV,
+        b"VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        b"XenVMMXenVMM" => Hypervisor::Xen,
+        _ => Hypervisor::Other(sig),
+    })
+}
@@ -0,0 +1,33 @@
+//! # Serviços de Convidado
+//!
+//! Integração com o hypervisor quando o Redstone roda em uma máquina virtual
+//! (QEMU/KVM durante o desenvolvimento): detecção, ponte de clipboard com o
+//! host e montagem de pastas compartilhadas.
+//!
+//! A detecção usa CPUID e funciona sem serviço algum; clipboard e pastas
+//! passam pelo serviço de convidado, que fala com o dispositivo de guest
+//! services do hypervisor.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do protocolo de convidado |
+//! | [`client`] | Ponte de clipboard e pastas compartilhadas |
+//! | `detect` | Identificação do hypervisor |
+
+pub mod client;
+mod detect;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    clipboard_bridge, clipboard_bridge_from_host, shared_folder_mount,
+    shared_folder_mount_read_only, shared_folders, ClipboardBridge, ClipboardSync, SharedFolder,
+    SyncDirection,
+};
+pub use detect::{hypervisor, is_virtualized, Hypervisor};
+pub use protocol::GUEST_PORT;
@@ -0,0 +1,155 @@
+//! # Protocolo de Serviços de Convidado
+//!
+//! Definições de mensagens do protocolo com o serviço de convidado, que
+//! conversa com o dispositivo de guest services do hypervisor (agente
+//! virtio-serial do QEMU, VMware Tools, etc).
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de convidado.
+pub const GUEST_PORT: &str = "guest.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 512;
+
+/// Tamanho máximo da tag de uma pasta compartilhada.
+pub const TAG_MAX: usize = 32;
+
+/// Tamanho máximo de um caminho de montagem.
+pub const PATH_MAX: usize = 128;
+
+/// Máximo de pastas compartilhadas por resposta.
+pub const MAX_FOLDERS: usize = 8;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const CLIPBOARD_START: u32 = 0x01;
+    pub const CLIPBOARD_STOP: u32 = 0x02;
+    pub const LIST_FOLDERS: u32 = 0x03;
+    pub const MOUNT_FOLDER: u32 = 0x04;
+    pub const UNMOUNT_FOLDER: u32 = 0x05;
+
+    // Server -> Client
+    pub const OK: u32 = 0x10;
+    pub const FOLDERS: u32 = 0x11;
+    pub const ERROR: u32 = 0xFF;
+
+    // Eventos (Server -> Bridge)
+    pub const CLIPBOARD_SYNCED: u32 = 0x20;
+}
+
+/// Direções de sincronização da área de transferência.
+pub mod direction {
+    pub const HOST_TO_GUEST: u32 = 1 << 0;
+    pub const GUEST_TO_HOST: u32 = 1 << 1;
+    pub const BOTH: u32 = HOST_TO_GUEST | GUEST_TO_HOST;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de início/fim da ponte de clipboard (`CLIPBOARD_START`,
+/// `CLIPBOARD_STOP`).
+///
+/// A ponte é identificada pela porta de eventos.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardRequest {
+    pub op: u32,
+    /// Bits de [`direction`].
+    pub directions: u32,
+    pub listener_port: [u8; 32],
+    pub reply_port: [u8; 32],
+}
+
+/// Request de listagem de pastas compartilhadas.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListFoldersRequest {
+    pub op: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de montagem/desmontagem (`MOUNT_FOLDER`, `UNMOUNT_FOLDER`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MountRequest {
+    pub op: u32,
+    /// Montar somente leitura.
+    pub read_only: u32,
+    pub tag: [u8; TAG_MAX],
+    pub mount_point: [u8; PATH_MAX],
+    pub reply_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Confirmação sem dados.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OkResponse {
+    pub op: u32,
+}
+
+/// Pastas compartilhadas pelo host.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FoldersResponse {
+    pub op: u32,
+    pub count: u32,
+    pub tags: [[u8; TAG_MAX]; MAX_FOLDERS],
+}
+
+/// Conteúdo sincronizado entre host e convidado.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardSyncedEvent {
+    pub op: u32,
+    /// Direção da cópia (um bit de [`direction`]).
+    pub direction: u32,
+    /// Tamanho do conteúdo copiado (bytes).
+    pub size: u64,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union GuestMessage {
+    pub header: u32,
+    pub ok: OkResponse,
+    pub folders: FoldersResponse,
+    pub clipboard_synced: ClipboardSyncedEvent,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for GuestMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    /// Respostas como `OK` trazem só o opcode.
+    const MIN_LEN: usize = core::mem::offset_of!(ErrorResponse, code);
+}
@@ -0,0 +1,187 @@
+//! # Idle Client
+//!
+//! Tempo sem input, inibição de proteção de tela e avisos de ociosidade.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use redpowder::sys::idle::{self, IdleChange};
+//!
+//! // Player: sem proteção de tela enquanto `_guard` existir
+//! let _guard = idle::inhibit("reproduzindo vídeo")?;
+//!
+//! // Tela de bloqueio: bloquear após 5 minutos sem input
+//! let watcher = idle::watch(Duration::from_secs(300))?;
+//! while let Some(change) = watcher.wait(u64::MAX) {
+//!     if let IdleChange::Idle(_) = change {
+//!         lock_screen();
+//!     }
+//! }
+//! ```
+
+use core::time::Duration;
+
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, recv_reply, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// CONSULTA
+// =============================================================================
+
+/// Tempo desde o último input do usuário (teclado, mouse, toque).
+pub fn time_since_input() -> SysResult<Duration> {
+    let resp = query_idle()?;
+    Ok(Duration::from_millis(resp.idle_ms))
+}
+
+/// Existe alguma inibição ativa (de qualquer app)?
+pub fn is_inhibited() -> SysResult<bool> {
+    Ok(query_idle()?.inhibitors > 0)
+}
+
+fn query_idle() -> SysResult<IdleTimeResponse> {
+    let (reply_port, reply_name) = Port::create_unique("power.r.", 4)?;
+    let req = GetIdleTimeRequest {
+        op: opcodes::GET_IDLE_TIME,
+        reply_port: reply_name,
+    };
+    Port::connect(POWER_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<PowerMessage>(
+        &reply_port,
+        opcodes::IDLE_TIME,
+        core::mem::size_of::<IdleTimeResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(unsafe { resp.idle_time })
+}
+
+// =============================================================================
+// INIBIÇÃO
+// =============================================================================
+
+/// Inibição ativa; removida no `drop`.
+#[must_use = "a inibição termina quando o guard é descartado"]
+pub struct InhibitGuard {
+    cookie: u32,
+}
+
+impl InhibitGuard {
+    /// Identificador da inibição no serviço.
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+}
+
+impl Drop for InhibitGuard {
+    fn drop(&mut self) {
+        let req = UninhibitRequest {
+            op: opcodes::UNINHIBIT,
+            cookie: self.cookie,
+        };
+        if let Ok(server) = Port::connect(POWER_PORT) {
+            let _ = server.send(as_bytes(&req), 0);
+        }
+    }
+}
+
+/// Suspende proteção de tela, blanking e bloqueio por ociosidade.
+///
+/// `reason` aparece nas configurações de energia (ex: `"reproduzindo
+/// vídeo"`). Se o processo terminar, o serviço remove a inibição.
+pub fn inhibit(reason: &str) -> SysResult<InhibitGuard> {
+    let (reply_port, reply_name) = Port::create_unique("power.r.", 4)?;
+    let req = InhibitRequest {
+        op: opcodes::INHIBIT,
+        reason: fixed_str(reason),
+        reply_port: reply_name,
+    };
+    Port::connect(POWER_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<PowerMessage>(
+        &reply_port,
+        opcodes::INHIBITED,
+        core::mem::size_of::<InhibitedResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(InhibitGuard {
+        cookie: unsafe { resp.inhibited }.cookie,
+    })
+}
+
+// =============================================================================
+// AVISOS
+// =============================================================================
+
+/// Mudança de estado de ociosidade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleChange {
+    /// Sem input pelo timeout do watcher (tempo ocioso atual).
+    Idle(Duration),
+    /// Input recebido após `Idle`.
+    Active,
+}
+
+/// Receptor de avisos de ociosidade.
+///
+/// Inibições suprimem `Idle` enquanto estiverem ativas.
+pub struct IdleWatcher {
+    port: Port,
+}
+
+/// Avisa quando o usuário ficar `timeout` sem input e quando voltar.
+pub fn watch(timeout: Duration) -> SysResult<IdleWatcher> {
+    let timeout_ms = timeout.as_millis().min(u32::MAX as u128) as u32;
+    if timeout_ms == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let (port, name) = Port::create_unique("power.ev.", 4)?;
+    let req = WatchIdleRequest {
+        op: opcodes::WATCH_IDLE,
+        timeout_ms,
+        listener_port: name,
+    };
+    Port::connect(POWER_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(IdleWatcher { port })
+}
+
+impl IdleWatcher {
+    /// Retorna o próximo aviso pendente, sem bloquear.
+    pub fn poll(&self) -> Option<IdleChange> {
+        self.wait(0)
+    }
+
+    /// Espera um aviso por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<IdleChange> {
+        loop {
+            let mut msg = PowerMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<IdleEvent>() {
+                return None;
+            }
+            match unsafe { msg.header } {
+                opcodes::EVENT_IDLE => {
+                    let idle = Duration::from_millis(unsafe { msg.event }.idle_ms);
+                    return Some(IdleChange::Idle(idle));
+                }
+                opcodes::EVENT_ACTIVE => return Some(IdleChange::Active),
+                // Mensagem desconhecida: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Porta de avisos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,24 @@
+//! # Ociosidade
+//!
+//! Cliente do serviço de energia para ociosidade: tempo desde o último
+//! input, inibição da proteção de tela (players de vídeo, apresentações) e
+//! avisos de ociosidade (tela de bloqueio).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do serviço de energia |
+//! | [`client`] | Consulta, inibição e avisos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{
+    inhibit, is_inhibited, time_since_input, watch, IdleChange, IdleWatcher, InhibitGuard,
+};
+pub use protocol::POWER_PORT;
@@ -0,0 +1,139 @@
+//! # Protocolo de Ociosidade
+//!
+//! Definições de mensagens do protocolo com o serviço de energia
+//! (ociosidade e inibição de proteção de tela).
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de energia.
+pub const POWER_PORT: &str = "power.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 128;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const GET_IDLE_TIME: u32 = 0x01;
+    pub const INHIBIT: u32 = 0x02;
+    pub const UNINHIBIT: u32 = 0x03;
+    pub const WATCH_IDLE: u32 = 0x04;
+
+    // Server -> Client
+    pub const IDLE_TIME: u32 = 0x10;
+    pub const INHIBITED: u32 = 0x11;
+    pub const EVENT_IDLE: u32 = 0x20;
+    pub const EVENT_ACTIVE: u32 = 0x21;
+    pub const ERROR: u32 = 0xFF;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request do tempo desde o último input.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GetIdleTimeRequest {
+    pub op: u32,
+    /// Nome da porta onde o servidor deve responder.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de inibição (proteção de tela, blanking e bloqueio por
+/// ociosidade ficam suspensos enquanto houver inibições).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct InhibitRequest {
+    pub op: u32,
+    /// Motivo exibido nas configurações de energia.
+    pub reason: [u8; 64],
+    pub reply_port: [u8; 32],
+}
+
+/// Remove uma inibição.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct UninhibitRequest {
+    pub op: u32,
+    pub cookie: u32,
+}
+
+/// Registro para eventos de ociosidade após `timeout_ms` sem input.
+///
+/// O servidor descarta o registro quando a porta deixa de existir.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WatchIdleRequest {
+    pub op: u32,
+    pub timeout_ms: u32,
+    pub listener_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Tempo desde o último input.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct IdleTimeResponse {
+    pub op: u32,
+    /// Inibições ativas (de todos os apps).
+    pub inhibitors: u32,
+    pub idle_ms: u64,
+}
+
+/// Inibição registrada.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct InhibitedResponse {
+    pub op: u32,
+    pub cookie: u32,
+}
+
+/// Entrada/saída do estado ocioso (`EVENT_IDLE` / `EVENT_ACTIVE`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct IdleEvent {
+    pub op: u32,
+    pub _pad: u32,
+    pub idle_ms: u64,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    pub code: u32,
+}
+
+// =============================================================================
+// MESSAGE UNION
+// =============================================================================
+
+/// União das mensagens recebidas pelo cliente.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PowerMessage {
+    pub header: u32,
+    pub idle_time: IdleTimeResponse,
+    pub inhibited: InhibitedResponse,
+    pub event: IdleEvent,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for PowerMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -1,5 +1,10 @@
 //! # System
 
+pub mod drivers;
+pub mod guest;
+pub mod idle;
+pub mod session;
 mod sys;
+pub mod usb;
 
 pub use sys::*;
@@ -0,0 +1,138 @@
+//! # Session Client
+//!
+//! Bloqueio de tela e eventos de sessão.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::sys::session;
+//!
+//! // Atalho Super+L
+//! session::lock_now()?;
+//! ```
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, Port};
+use crate::syscall::SysResult;
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// BLOQUEIO
+// =============================================================================
+
+/// Bloqueia a sessão imediatamente.
+///
+/// O gerenciador de sessão inicia a tela de bloqueio, que cobre a tela com
+/// uma janela segura (ver [`window::create_secure`](crate::window::create_secure)).
+pub fn lock_now() -> SysResult<()> {
+    send_op(opcodes::LOCK)
+}
+
+/// Desbloqueia a sessão.
+///
+/// Apenas a tela de bloqueio pode chamar (após validar a senha); outros
+/// processos recebem `PermissionDenied`.
+pub fn unlock() -> SysResult<()> {
+    let (reply_port, reply_name) = Port::create_unique("session.r.", 4)?;
+    let req = SessionPortRequest {
+        op: opcodes::UNLOCK,
+        port: reply_name,
+    };
+    Port::connect(SESSION_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<SessionMessage>(
+        &reply_port,
+        opcodes::STATE,
+        core::mem::size_of::<StateResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(())
+}
+
+/// A sessão está bloqueada?
+pub fn is_locked() -> SysResult<bool> {
+    let (reply_port, reply_name) = Port::create_unique("session.r.", 4)?;
+    let req = SessionPortRequest {
+        op: opcodes::QUERY_STATE,
+        port: reply_name,
+    };
+    Port::connect(SESSION_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<SessionMessage>(
+        &reply_port,
+        opcodes::STATE,
+        core::mem::size_of::<StateResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(unsafe { resp.state }.locked != 0)
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Mudança de estado da sessão.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEvent {
+    Locked,
+    Unlocked,
+}
+
+/// Receptor de eventos de sessão.
+pub struct SessionListener {
+    port: Port,
+}
+
+/// Registra um receptor de eventos de bloqueio/desbloqueio.
+pub fn events() -> SysResult<SessionListener> {
+    let (port, name) = Port::create_unique("session.ev.", 4)?;
+    let req = SessionPortRequest {
+        op: opcodes::SUBSCRIBE,
+        port: name,
+    };
+    Port::connect(SESSION_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(SessionListener { port })
+}
+
+impl SessionListener {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<SessionEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<SessionEvent> {
+        loop {
+            let mut msg = SessionMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < 4 {
+                return None;
+            }
+            match unsafe { msg.header } {
+                opcodes::EVENT_LOCKED => return Some(SessionEvent::Locked),
+                opcodes::EVENT_UNLOCKED => return Some(SessionEvent::Unlocked),
+                // Mensagem desconhecida: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+fn send_op(op: u32) -> SysResult<()> {
+    let req = SessionOpRequest { op };
+    Port::connect(SESSION_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! # Sessão
+//!
+//! Cliente do gerenciador de sessão: bloqueio de tela e eventos de
+//! bloqueio/desbloqueio, para atalhos de teclado, tela de bloqueio e apps
+//! que pausam ao bloquear.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do gerenciador de sessão |
+//! | [`client`] | Bloqueio, estado e eventos |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{events, is_locked, lock_now, unlock, SessionEvent, SessionListener};
+pub use protocol::SESSION_PORT;
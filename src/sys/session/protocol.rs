@@ -0,0 +1,95 @@
+//! # Protocolo de Sessão
+//!
+//! Definições de mensagens do protocolo com o gerenciador de sessão
+//! (bloqueio de tela).
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do gerenciador de sessão.
+pub const SESSION_PORT: &str = "session.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 64;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const LOCK: u32 = 0x01;
+    /// Apenas a tela de bloqueio.
+    pub const UNLOCK: u32 = 0x02;
+    pub const QUERY_STATE: u32 = 0x03;
+    pub const SUBSCRIBE: u32 = 0x04;
+
+    // Server -> Client
+    pub const STATE: u32 = 0x10;
+    pub const EVENT_LOCKED: u32 = 0x20;
+    pub const EVENT_UNLOCKED: u32 = 0x21;
+    pub const ERROR: u32 = 0xFF;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request simples (`LOCK`, `UNLOCK`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SessionOpRequest {
+    pub op: u32,
+}
+
+/// Request com resposta (`QUERY_STATE`) ou registro de eventos
+/// (`SUBSCRIBE`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SessionPortRequest {
+    pub op: u32,
+    pub port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Estado da sessão.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StateResponse {
+    pub op: u32,
+    pub locked: u32,
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    pub code: u32,
+}
+
+// =============================================================================
+// MESSAGE UNION
+// =============================================================================
+
+/// União das mensagens recebidas pelo cliente.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SessionMessage {
+    pub header: u32,
+    pub state: StateResponse,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for SessionMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
@@ -2,8 +2,10 @@
 //!
 //! Informações do sistema e debug.
 
-use crate::syscall::{check_error, syscall2, syscall3, SysResult};
-use crate::syscall::{SYS_DEBUG, SYS_SYSINFO};
+use core::fmt::{self, Write};
+
+use crate::syscall::safe::{sys_debug_breakpoint, sys_debug_write, sys_sysinfo};
+use crate::syscall::{SysError, SysResult, ABI_VERSION};
 
 /// Informações do sistema
 #[repr(C)]
@@ -20,23 +22,73 @@ pub struct SysInfo {
 
 /// Obtém informações do sistema
 pub fn sysinfo() -> SysResult<SysInfo> {
-    let mut info = SysInfo::default();
-    let ret = syscall2(
-        SYS_SYSINFO,
-        &mut info as *mut SysInfo as usize,
-        core::mem::size_of::<SysInfo>(),
+    sys_sysinfo()
+}
+
+/// Versão da ABI de syscalls do kernel em execução.
+pub fn abi_version() -> SysResult<u32> {
+    Ok(sys_sysinfo()?.abi_version)
+}
+
+/// Confere se o kernel fala a ABI com que a SDK foi compilada.
+///
+/// Retorna `SysError::NotSupported` se a versão divergir. Para encerrar o
+/// processo na divergência, use [`require_abi`] (chamada por
+/// [`runtime::init`](crate::runtime::init)).
+pub fn check_abi() -> SysResult<()> {
+    if abi_version()? == ABI_VERSION {
+        Ok(())
+    } else {
+        Err(SysError::NotSupported)
+    }
+}
+
+/// [`check_abi`] que encerra o processo em caso de divergência.
+///
+/// Registra as duas versões no log do kernel e sai com
+/// `SysError::NotSupported` como código, sem rodar hooks de saída.
+pub fn require_abi() {
+    let kernel = match abi_version() {
+        Ok(version) if version == ABI_VERSION => return,
+        Ok(version) => version,
+        Err(_) => 0,
+    };
+
+    let mut msg = Message {
+        buf: [0; 64],
+        len: 0,
+    };
+    let _ = writeln!(
+        msg,
+        "ABI incompatível: sdk {}, kernel {}",
+        ABI_VERSION, kernel
     );
-    check_error(ret)?;
-    Ok(info)
+    let _ = kprint(core::str::from_utf8(&msg.buf[..msg.len]).unwrap_or("ABI incompatível\n"));
+    crate::process::exit_now(SysError::NotSupported.code());
 }
 
 /// Debug: imprime no log do kernel
 pub fn kprint(s: &str) -> SysResult<usize> {
-    let ret = syscall3(SYS_DEBUG, 0x01, s.as_ptr() as usize, s.len());
-    check_error(ret)
+    sys_debug_write(s)
 }
 
 /// Debug: breakpoint
 pub fn breakpoint() {
-    let _ = syscall3(SYS_DEBUG, 0x04, 0, 0);
+    sys_debug_breakpoint();
+}
+
+/// Buffer fixo para a mensagem de [`require_abi`] (sem alocação).
+struct Message {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl Write for Message {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        let n = bytes.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        Ok(())
+    }
 }
@@ -0,0 +1,395 @@
+//! # USB Client
+//!
+//! Acesso a dispositivos USB para drivers em espaço de usuário.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::sys::usb::{self, ControlSetup};
+//!
+//! let mut dev = usb::open(device_id)?;
+//! dev.claim_interface(0)?;
+//!
+//! // GET_DESCRIPTOR (device)
+//! let mut desc = [0u8; 18];
+//! let setup = ControlSetup::new(0x80, 0x06, 0x0100, 0);
+//! dev.control_transfer(setup, &mut desc, 1000)?;
+//!
+//! dev.bulk_write(0x02, b"ping", 1000)?;
+//! let mut buf = [0u8; 512];
+//! let n = dev.bulk_read(0x81, &mut buf, 1000)?;
+//! ```
+
+use crate::ipc::{as_bytes, recv_reply, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do servidor (ms), além do timeout
+/// da própria transferência.
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Bit de direção do endereço de endpoint (1 = IN).
+const ENDPOINT_IN: u8 = 0x80;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Velocidade do barramento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsbSpeed {
+    Unknown,
+    /// 1.5 Mbit/s.
+    Low,
+    /// 12 Mbit/s.
+    Full,
+    /// 480 Mbit/s.
+    High,
+    /// 5 Gbit/s ou mais.
+    Super,
+}
+
+/// Campos do pacote SETUP de uma transferência de controle.
+///
+/// O tamanho (`wLength`) vem do buffer passado a
+/// [`UsbDevice::control_transfer`]; a direção, do bit 7 de `request_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlSetup {
+    /// `bmRequestType`.
+    pub request_type: u8,
+    /// `bRequest`.
+    pub request: u8,
+    /// `wValue`.
+    pub value: u16,
+    /// `wIndex`.
+    pub index: u16,
+}
+
+impl ControlSetup {
+    /// Cria um pacote SETUP.
+    pub const fn new(request_type: u8, request: u8, value: u16, index: u16) -> Self {
+        Self {
+            request_type,
+            request,
+            value,
+            index,
+        }
+    }
+
+    /// Transferência de dispositivo para host?
+    #[inline]
+    pub const fn is_in(&self) -> bool {
+        self.request_type & ENDPOINT_IN != 0
+    }
+
+    fn to_bytes(self, length: u16) -> [u8; 8] {
+        let [v0, v1] = self.value.to_le_bytes();
+        let [i0, i1] = self.index.to_le_bytes();
+        let [l0, l1] = length.to_le_bytes();
+        [self.request_type, self.request, v0, v1, i0, i1, l0, l1]
+    }
+}
+
+// =============================================================================
+// DEVICE
+// =============================================================================
+
+/// Abre o dispositivo USB `device_id` (ver [`sys::drivers`](crate::sys::drivers)).
+pub fn open(device_id: u32) -> SysResult<UsbDevice> {
+    UsbDevice::open(device_id)
+}
+
+/// Sessão com um dispositivo USB; fechada no `drop`.
+///
+/// Transferências tomam `&mut self`: há no máximo uma em andamento por
+/// sessão, pois todas usam o mesmo buffer compartilhado.
+///
+/// Endpoint em STALL retorna `SysError::BrokenPipe` (use
+/// [`clear_halt`](Self::clear_halt)); dispositivo removido retorna
+/// `SysError::NotFound`.
+pub struct UsbDevice {
+    /// Descritor e sessão.
+    info: OpenedResponse,
+    /// Buffer de transferência compartilhado.
+    shm: SharedMemory,
+    /// Porta do serviço USB.
+    server_port: Port,
+    /// Porta de respostas.
+    reply_port: Port,
+}
+
+impl UsbDevice {
+    /// Abre o dispositivo USB `device_id`.
+    pub fn open(device_id: u32) -> SysResult<Self> {
+        let (reply_port, reply_name) = Port::create_unique("usb.r.", 8)?;
+        let server_port = Port::connect(USB_PORT)?;
+
+        let req = OpenRequest {
+            op: opcodes::OPEN,
+            device_id,
+            reply_port: reply_name,
+        };
+        server_port.send(as_bytes(&req), 0)?;
+
+        let msg = recv_reply::<UsbMessage>(
+            &reply_port,
+            opcodes::OPENED,
+            core::mem::size_of::<OpenedResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let info = unsafe { msg.opened };
+
+        let shm = SharedMemory::open(ShmId(info.shm_handle))?;
+        if info.shm_size == 0 || shm.size() < info.shm_size as usize {
+            return Err(SysError::ProtocolError);
+        }
+
+        Ok(Self {
+            info,
+            shm,
+            server_port,
+            reply_port,
+        })
+    }
+
+    /// Vendor ID (`idVendor`).
+    pub fn vendor_id(&self) -> u16 {
+        self.info.vendor_id
+    }
+
+    /// Product ID (`idProduct`).
+    pub fn product_id(&self) -> u16 {
+        self.info.product_id
+    }
+
+    /// Classe, subclasse e protocolo do dispositivo.
+    pub fn class(&self) -> (u8, u8, u8) {
+        (self.info.class, self.info.subclass, self.info.protocol)
+    }
+
+    /// Velocidade do barramento.
+    pub fn speed(&self) -> UsbSpeed {
+        match self.info.speed {
+            speeds::LOW => UsbSpeed::Low,
+            speeds::FULL => UsbSpeed::Full,
+            speeds::HIGH => UsbSpeed::High,
+            speeds::SUPER => UsbSpeed::Super,
+            _ => UsbSpeed::Unknown,
+        }
+    }
+
+    /// Número de interfaces da configuração ativa.
+    pub fn num_interfaces(&self) -> u8 {
+        self.info.num_interfaces
+    }
+
+    /// Maior transferência feita em uma única requisição ao serviço.
+    pub fn max_transfer_size(&self) -> usize {
+        self.info.shm_size as usize
+    }
+
+    /// Reivindica a interface (necessário antes de usar seus endpoints).
+    ///
+    /// Retorna `Busy` se outro driver já a reivindicou.
+    pub fn claim_interface(&self, interface: u8) -> SysResult<()> {
+        self.session_op(opcodes::CLAIM_INTERFACE, interface as u32)
+    }
+
+    /// Libera a interface.
+    pub fn release_interface(&self, interface: u8) -> SysResult<()> {
+        self.session_op(opcodes::RELEASE_INTERFACE, interface as u32)
+    }
+
+    /// Limpa a condição de STALL de um endpoint.
+    pub fn clear_halt(&self, endpoint: u8) -> SysResult<()> {
+        self.session_op(opcodes::CLEAR_HALT, endpoint as u32)
+    }
+
+    /// Transferência de controle no endpoint 0.
+    ///
+    /// Em requisições IN, `data` recebe a resposta; em OUT, é enviado.
+    /// Retorna o número de bytes transferidos.
+    pub fn control_transfer(
+        &mut self,
+        setup: ControlSetup,
+        data: &mut [u8],
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        if data.len() > u16::MAX as usize || data.len() > self.max_transfer_size() {
+            return Err(SysError::InvalidArgument);
+        }
+        let bytes = setup.to_bytes(data.len() as u16);
+        if setup.is_in() {
+            self.transfer_in(transfer_kind::CONTROL, 0, bytes, data, timeout_ms)
+        } else {
+            self.transfer_out(transfer_kind::CONTROL, 0, bytes, data, timeout_ms)
+        }
+    }
+
+    /// Lê de um endpoint bulk IN.
+    ///
+    /// Leituras maiores que [`max_transfer_size`](Self::max_transfer_size)
+    /// são divididas; termina no primeiro pacote curto.
+    pub fn bulk_read(&mut self, endpoint: u8, buf: &mut [u8], timeout_ms: u32) -> SysResult<usize> {
+        check_endpoint(endpoint, true)?;
+        let mut total = 0;
+        for chunk in buf.chunks_mut(self.max_transfer_size()) {
+            let n = self.transfer_in(transfer_kind::BULK, endpoint, [0; 8], chunk, timeout_ms)?;
+            total += n;
+            if n < chunk.len() {
+                break;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Escreve em um endpoint bulk OUT.
+    ///
+    /// Escritas maiores que [`max_transfer_size`](Self::max_transfer_size)
+    /// são divididas.
+    pub fn bulk_write(&mut self, endpoint: u8, data: &[u8], timeout_ms: u32) -> SysResult<usize> {
+        check_endpoint(endpoint, false)?;
+        let mut total = 0;
+        for chunk in data.chunks(self.max_transfer_size()) {
+            let n = self.transfer_out(transfer_kind::BULK, endpoint, [0; 8], chunk, timeout_ms)?;
+            total += n;
+            if n < chunk.len() {
+                break;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Lê um relatório de um endpoint interrupt IN.
+    pub fn interrupt_read(
+        &mut self,
+        endpoint: u8,
+        buf: &mut [u8],
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        check_endpoint(endpoint, true)?;
+        if buf.len() > self.max_transfer_size() {
+            return Err(SysError::InvalidArgument);
+        }
+        self.transfer_in(transfer_kind::INTERRUPT, endpoint, [0; 8], buf, timeout_ms)
+    }
+
+    /// Envia um relatório a um endpoint interrupt OUT.
+    pub fn interrupt_write(
+        &mut self,
+        endpoint: u8,
+        data: &[u8],
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        check_endpoint(endpoint, false)?;
+        if data.len() > self.max_transfer_size() {
+            return Err(SysError::InvalidArgument);
+        }
+        self.transfer_out(transfer_kind::INTERRUPT, endpoint, [0; 8], data, timeout_ms)
+    }
+
+    // =========================================================================
+    // INTERNOS
+    // =========================================================================
+
+    fn transfer_in(
+        &mut self,
+        kind: u32,
+        endpoint: u8,
+        setup: [u8; 8],
+        buf: &mut [u8],
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        let n = self.transfer(kind, endpoint, setup, buf.len(), timeout_ms)?;
+        buf[..n].copy_from_slice(&self.shm.as_slice()[..n]);
+        Ok(n)
+    }
+
+    fn transfer_out(
+        &mut self,
+        kind: u32,
+        endpoint: u8,
+        setup: [u8; 8],
+        data: &[u8],
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        self.shm.as_mut_slice()[..data.len()].copy_from_slice(data);
+        self.transfer(kind, endpoint, setup, data.len(), timeout_ms)
+    }
+
+    fn transfer(
+        &self,
+        kind: u32,
+        endpoint: u8,
+        setup: [u8; 8],
+        length: usize,
+        timeout_ms: u32,
+    ) -> SysResult<usize> {
+        let req = TransferRequest {
+            op: opcodes::TRANSFER,
+            session_id: self.info.session_id,
+            kind,
+            endpoint: endpoint as u32,
+            setup,
+            length: length as u32,
+            timeout_ms,
+        };
+        self.server_port.send(as_bytes(&req), 0)?;
+
+        // timeout 0 = sem limite na transferência
+        let wait = match timeout_ms {
+            0 => u64::MAX,
+            t => t as u64 + REPLY_TIMEOUT_MS,
+        };
+        let msg = recv_reply::<UsbMessage>(
+            &self.reply_port,
+            opcodes::TRANSFER_DONE,
+            core::mem::size_of::<TransferDoneResponse>(),
+            wait,
+        )?;
+        let actual = unsafe { msg.transfer_done.actual_length } as usize;
+        if actual > length {
+            return Err(SysError::ProtocolError);
+        }
+        Ok(actual)
+    }
+
+    fn session_op(&self, op: u32, value: u32) -> SysResult<()> {
+        self.send_op(op, value)?;
+        recv_reply::<UsbMessage>(
+            &self.reply_port,
+            opcodes::DONE,
+            core::mem::size_of::<DoneResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        Ok(())
+    }
+
+    fn send_op(&self, op: u32, value: u32) -> SysResult<()> {
+        let req = SessionRequest {
+            op,
+            session_id: self.info.session_id,
+            value,
+        };
+        self.server_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+}
+
+impl Drop for UsbDevice {
+    fn drop(&mut self) {
+        let _ = self.send_op(opcodes::CLOSE, 0);
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+fn check_endpoint(endpoint: u8, is_in: bool) -> SysResult<()> {
+    if endpoint & 0x0F == 0 || (endpoint & ENDPOINT_IN != 0) != is_in {
+        return Err(SysError::InvalidArgument);
+    }
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! # USB
+//!
+//! Cliente do serviço de host USB para drivers em espaço de usuário:
+//! transferências de controle, bulk e interrupt em dispositivos abertos
+//! a partir de [`sys::drivers`](crate::sys::drivers).
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens e opcodes do serviço USB |
+//! | [`client`] | Sessão com o dispositivo e transferências |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{open, ControlSetup, UsbDevice, UsbSpeed};
+pub use protocol::USB_PORT;
@@ -0,0 +1,174 @@
+//! # Protocolo USB
+//!
+//! Definições de mensagens do protocolo com o serviço de host USB.
+//!
+//! Ao abrir um dispositivo o serviço cria uma SHM de transferência: dados
+//! de saída são copiados para ela antes de `TRANSFER`, e dados de entrada
+//! são lidos dela após `TRANSFER_DONE`. Há no máximo uma transferência em
+//! andamento por sessão.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de host USB.
+pub const USB_PORT: &str = "usb.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 128;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const OPEN: u32 = 0x01;
+    pub const CLOSE: u32 = 0x02;
+    pub const CLAIM_INTERFACE: u32 = 0x03;
+    pub const RELEASE_INTERFACE: u32 = 0x04;
+    pub const TRANSFER: u32 = 0x05;
+    pub const CLEAR_HALT: u32 = 0x06;
+
+    // Server -> Client
+    pub const OPENED: u32 = 0x10;
+    pub const DONE: u32 = 0x11;
+    pub const TRANSFER_DONE: u32 = 0x12;
+    pub const ERROR: u32 = 0xFF;
+}
+
+/// Tipos de transferência.
+pub mod transfer_kind {
+    pub const CONTROL: u32 = 0;
+    pub const BULK: u32 = 2;
+    pub const INTERRUPT: u32 = 3;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de abertura de dispositivo.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OpenRequest {
+    pub op: u32,
+    /// Id do dispositivo (como em `sys::drivers`).
+    pub device_id: u32,
+    /// Porta de respostas da sessão.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de operação da sessão (`CLOSE`, `CLAIM_INTERFACE`,
+/// `RELEASE_INTERFACE`, `CLEAR_HALT`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SessionRequest {
+    pub op: u32,
+    pub session_id: u32,
+    /// Interface ou endpoint.
+    pub value: u32,
+}
+
+/// Request de transferência.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TransferRequest {
+    pub op: u32,
+    pub session_id: u32,
+    pub kind: u32,
+    /// Endereço do endpoint (bit 7 = IN). 0 para control.
+    pub endpoint: u32,
+    /// Pacote SETUP (apenas control).
+    pub setup: [u8; 8],
+    /// Bytes a enviar ou tamanho máximo a receber (na SHM).
+    pub length: u32,
+    pub timeout_ms: u32,
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Dispositivo aberto.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OpenedResponse {
+    pub op: u32,
+    pub session_id: u32,
+    pub shm_handle: u64,
+    /// Tamanho da SHM (maior transferência única).
+    pub shm_size: u32,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub class: u8,
+    pub subclass: u8,
+    pub protocol: u8,
+    /// Velocidade (ver [`speeds`]).
+    pub speed: u8,
+    pub num_configurations: u8,
+    pub num_interfaces: u8,
+    pub _pad: [u8; 2],
+}
+
+/// Velocidades de barramento.
+pub mod speeds {
+    pub const UNKNOWN: u8 = 0;
+    pub const LOW: u8 = 1;
+    pub const FULL: u8 = 2;
+    pub const HIGH: u8 = 3;
+    pub const SUPER: u8 = 4;
+}
+
+/// Confirmação sem dados.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DoneResponse {
+    pub op: u32,
+}
+
+/// Transferência concluída.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TransferDoneResponse {
+    pub op: u32,
+    /// Bytes efetivamente transferidos.
+    pub actual_length: u32,
+}
+
+/// Response de erro.
+///
+/// Endpoint em STALL retorna `SysError::BrokenPipe`; dispositivo removido,
+/// `SysError::NotFound`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    /// Código de `SysError` (negativo, como i32).
+    pub code: u32,
+}
+
+// =============================================================================
+// PROTOCOL MESSAGE UNION
+// =============================================================================
+
+/// União de todas as mensagens possíveis (para leitura genérica).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union UsbMessage {
+    pub header: u32,
+    pub opened: OpenedResponse,
+    pub done: DoneResponse,
+    pub transfer_done: TransferDoneResponse,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for UsbMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    /// Respostas como `DONE` trazem só o opcode.
+    const MIN_LEN: usize = core::mem::offset_of!(ErrorResponse, code);
+}
@@ -0,0 +1,318 @@
+//! # Batch
+//!
+//! Várias syscalls em uma única entrada no kernel (`SYS_BATCH`).
+//!
+//! Clientes de IPC costumam emitir sequências curtas (`send`, `recv`,
+//! `poll`) uma atrás da outra; cada uma paga uma troca de modo. Um
+//! [`Batch`] enfileira as operações e as submete de uma vez, com um
+//! resultado por operação.
+//!
+//! Os buffers ficam emprestados ao `Batch` até [`submit`](Batch::submit),
+//! que o consome: depois dele os buffers de recepção já podem ser lidos.
+//!
+//! Em kernels sem `SYS_BATCH`, `submit` executa as operações uma a uma com
+//! a mesma semântica.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::syscall::batch::Batch;
+//!
+//! let mut reply = [0u8; 64];
+//! let mut batch = Batch::new();
+//! let sent = batch.send_msg(&server, &request, 0)?;
+//! let recv = batch.recv_msg(&client, &mut reply)?;
+//! batch.stop_on_error();
+//! let results = batch.submit()?;
+//!
+//! results.get(sent).unwrap()?;
+//! if let Some(Ok(len)) = results.get(recv) {
+//!     handle_reply(&reply[..len]);
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use super::numbers::*;
+use super::safe::{sys_batch, sys_batch_op};
+use super::{check_error, SysError, SysResult};
+use crate::event::PollFd;
+use crate::io::Handle;
+use crate::ipc::Port;
+
+/// Máximo de operações por batch.
+pub const MAX_BATCH_OPS: usize = 16;
+
+/// Flags de `SYS_BATCH`.
+pub mod batch_flags {
+    /// Para na primeira operação com erro; as seguintes não executam.
+    pub const STOP_ON_ERROR: u32 = 1 << 0;
+}
+
+/// Resultado de operação não executada.
+const NOT_RUN: isize = isize::MIN;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Operação de `SYS_BATCH` no formato do kernel.
+///
+/// O kernel escreve o retorno da syscall em `result`; operações não
+/// executadas (após erro com `STOP_ON_ERROR`) mantêm o valor inicial.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BatchOp {
+    pub num: u32,
+    pub _pad: u32,
+    pub args: [usize; 4],
+    pub result: isize,
+}
+
+impl BatchOp {
+    const EMPTY: Self = Self {
+        num: 0,
+        _pad: 0,
+        args: [0; 4],
+        result: NOT_RUN,
+    };
+}
+
+/// Resultados de um batch submetido.
+#[derive(Debug, Clone)]
+pub struct BatchResults {
+    results: [isize; MAX_BATCH_OPS],
+    len: usize,
+    executed: usize,
+}
+
+impl BatchResults {
+    /// Resultado da operação `index` (`None` se não executou).
+    pub fn get(&self, index: usize) -> Option<SysResult<usize>> {
+        match self.results[..self.len].get(index) {
+            Some(&NOT_RUN) | None => None,
+            Some(&ret) => Some(check_error(ret)),
+        }
+    }
+
+    /// Número de operações enfileiradas.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Nenhuma operação enfileirada.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Número de operações executadas (menor que `len` se parou em erro).
+    pub fn executed(&self) -> usize {
+        self.executed
+    }
+
+    /// Primeiro erro, com o índice da operação.
+    pub fn first_error(&self) -> Option<(usize, SysError)> {
+        (0..self.len).find_map(|i| match self.get(i) {
+            Some(Err(err)) => Some((i, err)),
+            _ => None,
+        })
+    }
+
+    /// Resultados em ordem (`None` para não executadas).
+    pub fn iter(&self) -> impl Iterator<Item = Option<SysResult<usize>>> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+}
+
+// =============================================================================
+// BATCH
+// =============================================================================
+
+/// Fila de syscalls submetida de uma vez.
+///
+/// Cada método de enfileiramento retorna o índice da operação em
+/// [`BatchResults`], ou `SysError::LimitReached` com a fila cheia.
+pub struct Batch<'a> {
+    ops: [BatchOp; MAX_BATCH_OPS],
+    len: usize,
+    flags: u32,
+    _buffers: PhantomData<&'a mut [u8]>,
+}
+
+impl Default for Batch<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Batch<'a> {
+    /// Batch vazio.
+    pub const fn new() -> Self {
+        Self {
+            ops: [BatchOp::EMPTY; MAX_BATCH_OPS],
+            len: 0,
+            flags: 0,
+            _buffers: PhantomData,
+        }
+    }
+
+    /// Para na primeira operação com erro.
+    pub fn stop_on_error(&mut self) -> &mut Self {
+        self.flags |= batch_flags::STOP_ON_ERROR;
+        self
+    }
+
+    /// Número de operações enfileiradas.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Nenhuma operação enfileirada.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, num: usize, args: [usize; 4]) -> SysResult<usize> {
+        let slot = self.ops.get_mut(self.len).ok_or(SysError::LimitReached)?;
+        *slot = BatchOp {
+            num: num as u32,
+            _pad: 0,
+            args,
+            result: NOT_RUN,
+        };
+        self.len += 1;
+        Ok(self.len - 1)
+    }
+
+    // =========================================================================
+    // OPERAÇÕES
+    // =========================================================================
+
+    /// [`Port::send`]: envia `data` (resultado: bytes enviados).
+    pub fn send_msg(&mut self, port: &Port, data: &'a [u8], flags: u32) -> SysResult<usize> {
+        let handle = port.handle().raw() as usize;
+        let args = [handle, data.as_ptr() as usize, data.len(), flags as usize];
+        self.push(SYS_SEND_MSG, args)
+    }
+
+    /// Recebe uma mensagem sem bloquear (resultado: bytes, 0 = fila vazia).
+    pub fn recv_msg(&mut self, port: &Port, buf: &'a mut [u8]) -> SysResult<usize> {
+        let handle = port.handle().raw() as usize;
+        let args = [handle, buf.as_mut_ptr() as usize, buf.len(), 0];
+        self.push(SYS_RECV_MSG, args)
+    }
+
+    /// [`event::poll`](crate::event::poll) (resultado: handles prontos).
+    pub fn poll(&mut self, fds: &'a mut [PollFd], timeout_ms: i64) -> SysResult<usize> {
+        let args = [fds.as_mut_ptr() as usize, fds.len(), timeout_ms as usize, 0];
+        self.push(SYS_POLL, args)
+    }
+
+    /// Lê do handle (resultado: bytes lidos).
+    pub fn read(&mut self, handle: &Handle, buf: &'a mut [u8]) -> SysResult<usize> {
+        let args = [
+            handle.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            0,
+        ];
+        self.push(SYS_READ, args)
+    }
+
+    /// Escreve no handle (resultado: bytes escritos).
+    pub fn write(&mut self, handle: &Handle, buf: &'a [u8]) -> SysResult<usize> {
+        let args = [handle.raw() as usize, buf.as_ptr() as usize, buf.len(), 0];
+        self.push(SYS_WRITE, args)
+    }
+
+    /// Lê em `offset` sem mover o cursor (resultado: bytes lidos).
+    pub fn pread(&mut self, handle: &Handle, buf: &'a mut [u8], offset: u64) -> SysResult<usize> {
+        let args = [
+            handle.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        ];
+        self.push(SYS_PREAD, args)
+    }
+
+    /// Escreve em `offset` sem mover o cursor (resultado: bytes escritos).
+    pub fn pwrite(&mut self, handle: &Handle, buf: &'a [u8], offset: u64) -> SysResult<usize> {
+        let args = [
+            handle.raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        ];
+        self.push(SYS_PWRITE, args)
+    }
+
+    /// Envia pelo socket (resultado: bytes enviados).
+    pub fn send(&mut self, socket: &Handle, buf: &'a [u8], flags: usize) -> SysResult<usize> {
+        let args = [
+            socket.raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            flags,
+        ];
+        self.push(SYS_SEND, args)
+    }
+
+    /// Recebe do socket (resultado: bytes recebidos).
+    pub fn recv(&mut self, socket: &Handle, buf: &'a mut [u8], flags: usize) -> SysResult<usize> {
+        let args = [
+            socket.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            flags,
+        ];
+        self.push(SYS_RECV, args)
+    }
+
+    // =========================================================================
+    // SUBMISSÃO
+    // =========================================================================
+
+    /// Executa as operações em ordem e libera os buffers.
+    ///
+    /// Erros de operações individuais ficam nos resultados; `Err` aqui só
+    /// se o batch inteiro for rejeitado.
+    pub fn submit(mut self) -> SysResult<BatchResults> {
+        let ops = &mut self.ops[..self.len];
+        // SAFETY: cada op foi montada a partir de slices emprestados por 'a,
+        // vivos até o fim desta função.
+        let executed = match unsafe { sys_batch(ops, self.flags) } {
+            Err(SysError::InvalidSyscall | SysError::NotImplemented) => {
+                // SAFETY: idem
+                unsafe { run_each(ops, self.flags) }
+            }
+            result => result?,
+        };
+
+        let mut results = [NOT_RUN; MAX_BATCH_OPS];
+        for (result, op) in results.iter_mut().zip(ops.iter()) {
+            *result = op.result;
+        }
+        Ok(BatchResults {
+            results,
+            len: self.len,
+            executed,
+        })
+    }
+}
+
+/// Execução sem `SYS_BATCH` (kernels antigos).
+///
+/// # Safety
+/// Mesmo contrato de [`sys_batch`].
+unsafe fn run_each(ops: &mut [BatchOp], flags: u32) -> usize {
+    let mut executed = 0;
+    for op in ops {
+        let result = sys_batch_op(op);
+        executed += 1;
+        if result < 0 && flags & batch_flags::STOP_ON_ERROR != 0 {
+            break;
+        }
+    }
+    executed
+}
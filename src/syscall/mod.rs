@@ -1,11 +1,25 @@
 //! # Syscall Interface
 //!
 //! Invocação direta de syscalls usando instrução `syscall`.
+//!
+//! | Submódulo | Conteúdo                                          |
+//! |-----------|---------------------------------------------------|
+//! | `safe`    | Wrappers tipados (`sys_open`, `sys_read`...)      |
+//! | `batch`   | Várias syscalls em uma submissão ([`batch::Batch`]) |
+//! | `numbers` | Números `SYS_*` (reexportados aqui)               |
+//! | `error`   | [`SysError`], [`SysResult`], [`check_error`]      |
+//! | `trace`   | Modo strace (feature `syscall-trace`)             |
+//!
+//! As funções `syscallN` são internas (`pub(crate) unsafe`): fora da crate,
+//! use [`safe`] ou a API de alto nível.
 
+pub mod batch;
 mod error;
 mod numbers;
 mod raw;
+pub mod safe;
+#[cfg(feature = "syscall-trace")]
+pub mod trace;
 
 pub use error::{check_error, SysError, SysResult};
 pub use numbers::*;
-pub use raw::*;
@@ -3,6 +3,9 @@
 //! Números de syscall do Redstone OS.
 //!
 //! ⚠️ DEVEM corresponder exatamente ao kernel (forge/src/syscall/numbers.rs).
+//! O `build.rs` confere estes valores contra o `abi.toml` do Forge e o
+//! build falha se divergirem; em runtime, [`crate::sys::check_abi`] compara
+//! [`ABI_VERSION`] com a versão informada pelo kernel.
 //!
 //! ## Organização
 //!
@@ -16,8 +19,15 @@
 //! | 0x50-0x5F | Tempo            |
 //! | 0x60-0x7F | Filesystem       |
 //! | 0x80-0x8F | Events           |
+//! | 0x90-0x9F | Rede (sockets)   |
 //! | 0xF0-0xFF | Sistema/Debug    |
 
+/// Versão da ABI descrita por este arquivo.
+///
+/// Incrementar junto com o `version` do `abi.toml` sempre que um número
+/// mudar ou uma syscall mudar de assinatura.
+pub const ABI_VERSION: u32 = 1;
+
 // =============================================================================
 // PROCESSO (0x01 - 0x0F)
 // =============================================================================
@@ -31,6 +41,10 @@ pub const SYS_GETTASKINFO: usize = 0x06;
 pub const SYS_GETTID: usize = 0x07;
 pub const SYS_THREAD_CREATE: usize = 0x08;
 pub const SYS_THREAD_EXIT: usize = 0x09;
+pub const SYS_SPAWN_EX: usize = 0x0A;
+pub const SYS_GET_STARTUP_HANDLES: usize = 0x0B;
+pub const SYS_SET_TASK_NAME: usize = 0x0C;
+pub const SYS_SIGNAL_PORT: usize = 0x0D;
 
 // =============================================================================
 // MEMÓRIA (0x10 - 0x1F)
@@ -79,6 +93,8 @@ pub const SYS_FB_WRITE: usize = 0x41;
 pub const SYS_FB_CLEAR: usize = 0x42;
 pub const SYS_MOUSE_READ: usize = 0x48;
 pub const SYS_KEYBOARD_READ: usize = 0x49;
+pub const SYS_TOUCH_READ: usize = 0x4A;
+pub const SYS_GAMEPAD_READ: usize = 0x4B;
 
 // =============================================================================
 // TEMPO (0x50 - 0x5F)
@@ -219,6 +235,65 @@ pub const SYS_CHDIR: usize = 0x7F;
 
 pub const SYS_POLL: usize = 0x80;
 
+/// Cria fila de eventos persistente: (flags) -> handle
+pub const SYS_EQ_CREATE: usize = 0x81;
+
+/// Adiciona/altera/remove handle da fila: (queue, op, handle, event_ptr)
+pub const SYS_EQ_CTL: usize = 0x82;
+
+/// Espera eventos da fila: (queue, events_ptr, max, timeout_ms) -> n
+pub const SYS_EQ_WAIT: usize = 0x83;
+
+/// Cria contador sinalizável (lido/escrito com SYS_READ/SYS_WRITE de 8
+/// bytes): (flags) -> handle
+pub const SYS_EVENTFD_CREATE: usize = 0x84;
+
+// =============================================================================
+// REDE - SOCKETS (0x90 - 0x9F)
+// =============================================================================
+
+/// Cria socket: (domain, type, flags) -> handle
+pub const SYS_SOCKET: usize = 0x90;
+
+/// Associa endereço local: (handle, addr_ptr, addr_len)
+pub const SYS_BIND: usize = 0x91;
+
+/// Passa a aceitar conexões: (handle, backlog)
+pub const SYS_LISTEN: usize = 0x92;
+
+/// Aceita conexão: (handle, addr_out_ptr, addr_len, flags) -> handle
+pub const SYS_ACCEPT: usize = 0x93;
+
+/// Conecta a endereço remoto: (handle, addr_ptr, addr_len, flags)
+pub const SYS_CONNECT: usize = 0x94;
+
+/// Envia dados: (handle, buf_ptr, len, flags) -> bytes
+pub const SYS_SEND: usize = 0x95;
+
+/// Recebe dados: (handle, buf_ptr, len, flags) -> bytes
+pub const SYS_RECV: usize = 0x96;
+
+/// Encerra leitura/escrita: (handle, how)
+pub const SYS_SHUTDOWN: usize = 0x97;
+
+/// Define opção: (handle, option, value)
+pub const SYS_SETSOCKOPT: usize = 0x98;
+
+/// Lê opção: (handle, option) -> value
+pub const SYS_GETSOCKOPT: usize = 0x99;
+
+/// Endereço local/remoto: (handle, which, addr_out_ptr, addr_len)
+pub const SYS_SOCKADDR: usize = 0x9A;
+
+/// Envia dados e handles: (handle, msghdr_ptr, flags) -> bytes
+pub const SYS_SOCK_SENDMSG: usize = 0x9B;
+
+/// Recebe dados e handles: (handle, msghdr_ptr, flags) -> bytes
+pub const SYS_SOCK_RECVMSG: usize = 0x9C;
+
+/// Credenciais do par: (handle, cred_out_ptr)
+pub const SYS_PEERCRED: usize = 0x9D;
+
 // =============================================================================
 // SISTEMA / DEBUG (0xF0 - 0xFF)
 // =============================================================================
@@ -228,4 +303,10 @@ pub const SYS_REBOOT: usize = 0xF1;
 pub const SYS_POWEROFF: usize = 0xF2;
 pub const SYS_CONSOLE_WRITE: usize = 0xF3;
 pub const SYS_CONSOLE_READ: usize = 0xF4;
+pub const SYS_BEEP: usize = 0xF5;
+pub const SYS_CONSOLE_SET_MODE: usize = 0xF6;
+
+/// Executa várias syscalls em sequência: (ops_ptr, count, flags) -> executadas
+pub const SYS_BATCH: usize = 0xF7;
+
 pub const SYS_DEBUG: usize = 0xFF;
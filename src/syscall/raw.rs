@@ -11,102 +11,142 @@
 //! - R8: arg5
 //! - R9: arg6
 //! - RAX: retorno
+//!
+//! Uso interno: o kernel acessa ponteiros passados como `usize`, então
+//! chamar estas funções é `unsafe`. Fora de [`safe`](super::safe), só o
+//! tracer (que escreve suas linhas sem se rastrear) as usa.
 
 use core::arch::asm;
 
 /// Syscall com 0 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall0(num: usize) -> isize {
+pub(crate) unsafe fn syscall0(num: usize) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            out("rcx") _,  // clobbered by syscall
-            out("r11") _,  // clobbered by syscall
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        out("rcx") _,  // clobbered by syscall
+        out("r11") _,  // clobbered by syscall
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[], ret);
     ret
 }
 
 /// Syscall com 1 argumento
+///
+/// # Safety
+/// Argumentos que são ponteiros devem ser válidos para o acesso que a
+/// syscall `num` faz, durante toda a chamada. Use os wrappers de
+/// [`safe`](super::safe).
 #[inline(always)]
-pub fn syscall1(num: usize, arg1: usize) -> isize {
+pub(crate) unsafe fn syscall1(num: usize, arg1: usize) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1], ret);
     ret
 }
 
 /// Syscall com 2 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall2(num: usize, arg1: usize, arg2: usize) -> isize {
+pub(crate) unsafe fn syscall2(num: usize, arg1: usize, arg2: usize) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1, arg2]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            in("rsi") arg2,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        in("rsi") arg2,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1, arg2], ret);
     ret
 }
 
 /// Syscall com 3 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall3(num: usize, arg1: usize, arg2: usize, arg3: usize) -> isize {
+pub(crate) unsafe fn syscall3(num: usize, arg1: usize, arg2: usize, arg3: usize) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1, arg2, arg3]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            in("rsi") arg2,
-            in("rdx") arg3,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        in("rsi") arg2,
+        in("rdx") arg3,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1, arg2, arg3], ret);
     ret
 }
 
 /// Syscall com 4 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall4(num: usize, arg1: usize, arg2: usize, arg3: usize, arg4: usize) -> isize {
+pub(crate) unsafe fn syscall4(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1, arg2, arg3, arg4]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            in("rsi") arg2,
-            in("rdx") arg3,
-            in("r10") arg4,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        in("rsi") arg2,
+        in("rdx") arg3,
+        in("r10") arg4,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1, arg2, arg3, arg4], ret);
     ret
 }
 
 /// Syscall com 5 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall5(
+pub(crate) unsafe fn syscall5(
     num: usize,
     arg1: usize,
     arg2: usize,
@@ -114,27 +154,33 @@ pub fn syscall5(
     arg4: usize,
     arg5: usize,
 ) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1, arg2, arg3, arg4, arg5]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            in("rsi") arg2,
-            in("rdx") arg3,
-            in("r10") arg4,
-            in("r8") arg5,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        in("rsi") arg2,
+        in("rdx") arg3,
+        in("r10") arg4,
+        in("r8") arg5,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1, arg2, arg3, arg4, arg5], ret);
     ret
 }
 
 /// Syscall com 6 argumentos
+///
+/// # Safety
+/// Ver [`syscall1`].
 #[inline(always)]
-pub fn syscall6(
+#[allow(dead_code)] // nenhuma syscall atual usa 6 argumentos
+pub(crate) unsafe fn syscall6(
     num: usize,
     arg1: usize,
     arg2: usize,
@@ -143,21 +189,49 @@ pub fn syscall6(
     arg5: usize,
     arg6: usize,
 ) -> isize {
+    #[cfg(feature = "syscall-trace")]
+    super::trace::before(num, &[arg1, arg2, arg3, arg4, arg5, arg6]);
     let ret: isize;
-    unsafe {
-        asm!(
-            "syscall",
-            inlateout("rax") num => ret,
-            in("rdi") arg1,
-            in("rsi") arg2,
-            in("rdx") arg3,
-            in("r10") arg4,
-            in("r8") arg5,
-            in("r9") arg6,
-            out("rcx") _,
-            out("r11") _,
-            options(nostack, preserves_flags)
-        );
-    }
+    asm!(
+        "syscall",
+        inlateout("rax") num => ret,
+        in("rdi") arg1,
+        in("rsi") arg2,
+        in("rdx") arg3,
+        in("r10") arg4,
+        in("r8") arg5,
+        in("r9") arg6,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+    #[cfg(feature = "syscall-trace")]
+    super::trace::after(num, &[arg1, arg2, arg3, arg4, arg5, arg6], ret);
     ret
 }
+
+/// Escreve `value` em `word`, acorda quem espera nele (`SYS_FUTEX_WAKE`) e
+/// encerra a thread (`SYS_THREAD_EXIT`), tudo em registradores: depois do
+/// store a pilha da thread não é mais tocada.
+///
+/// # Safety
+/// `word` deve ser válido e alinhado no momento do store; depois dele o
+/// kernel só usa o endereço como chave.
+pub(crate) unsafe fn thread_exit_wake(word: *const u32, value: u32, code: usize) -> ! {
+    asm!(
+        "mov dword ptr [rdi], esi",
+        "mov esi, 1",
+        "mov eax, {wake}",
+        "syscall",
+        "mov rdi, rdx",
+        "mov eax, {exit}",
+        "syscall",
+        "ud2",
+        wake = const super::numbers::SYS_FUTEX_WAKE,
+        exit = const super::numbers::SYS_THREAD_EXIT,
+        in("rdi") word,
+        in("esi") value,
+        in("rdx") code,
+        options(noreturn, nostack)
+    );
+}
@@ -0,0 +1,1330 @@
+//! # Safe Syscalls
+//!
+//! Wrappers tipados sobre [`syscallN`](super::raw): slices no lugar de
+//! pares ponteiro/tamanho, [`Handle`] no lugar de `usize` e structs do
+//! formato do kernel por referência. Toda a crate passa por aqui; os
+//! `syscallN` são `pub(crate) unsafe` e não saem do módulo `syscall`.
+//!
+//! Os wrappers são finos: um por syscall, sem laços, retentativas ou
+//! validação além do que o tipo garante. A API de alto nível (`fs::File`,
+//! `ipc::Port`...) continua sendo o caminho recomendado.
+//!
+//! Funções que podem invalidar memória do processo ([`sys_free`],
+//! [`sys_map`], [`sys_unmap`], [`sys_mprotect`]...), criar threads
+//! ([`sys_thread_create`]) ou que recebem ponteiros opacos ao SDK
+//! ([`sys_ioctl`], [`sys_batch`]) continuam `unsafe`.
+//!
+//! Registros cujo layout o SDK ainda não define (`SYS_GETTASKINFO`,
+//! `SYS_MEMINFO`) são copiados para um buffer de bytes.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::fs::{OpenFlags, O_RDONLY};
+//! use redpowder::syscall::safe::{sys_handle_close, sys_open, sys_read};
+//!
+//! let handle = sys_open("/apps/config.txt", OpenFlags::new(O_RDONLY))?;
+//! let mut buf = [0u8; 256];
+//! let n = sys_read(handle, &mut buf)?;
+//! sys_handle_close(handle)?;
+//! ```
+
+use core::mem::size_of;
+use core::sync::atomic::AtomicU32;
+
+use super::batch::BatchOp;
+use super::numbers::*;
+use super::raw::{syscall0, syscall1, syscall2, syscall3, syscall4, syscall5, thread_exit_wake};
+use super::{check_error, SysError, SysResult};
+use crate::event::{PollFd, QueueEvent};
+use crate::fs::types::FsStat;
+use crate::fs::{FileStat, OpenFlags, SeekFrom};
+use crate::graphics::framebuffer::FramebufferInfo;
+use crate::input::gamepad::RawGamepadState;
+use crate::input::{KeyEvent, MouseState, RawTouchPoint};
+use crate::io::{Handle, HandleRights};
+use crate::ipc::ShmId;
+use crate::net::PeerCred;
+use crate::process::{HandleTransfer, MAX_ARGS};
+use crate::sys::SysInfo;
+use crate::time::{ClockId, TimeSpec, TimerSpec};
+
+#[inline]
+fn to_handle(ret: isize) -> SysResult<Handle> {
+    Ok(Handle::from_raw(check_error(ret)? as u32))
+}
+
+#[inline]
+fn to_unit(ret: isize) -> SysResult<()> {
+    check_error(ret).map(|_| ())
+}
+
+/// Ponteiro de slice opcional (0 se vazio).
+#[inline]
+fn ptr_or_null<T>(slice: &[T]) -> usize {
+    if slice.is_empty() {
+        0
+    } else {
+        slice.as_ptr() as usize
+    }
+}
+
+/// Argumento de processo no formato do kernel.
+///
+/// `&str` é um fat pointer sem layout estável; o kernel recebe um array
+/// destes pares.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct StrArg {
+    ptr: usize,
+    len: usize,
+}
+
+/// Converte `args` para o array lido pelo kernel (`LimitReached` acima de
+/// [`MAX_ARGS`]).
+fn marshal_args(args: &[&str]) -> SysResult<[StrArg; MAX_ARGS]> {
+    if args.len() > MAX_ARGS {
+        return Err(SysError::LimitReached);
+    }
+    let mut out = [StrArg { ptr: 0, len: 0 }; MAX_ARGS];
+    for (slot, arg) in out.iter_mut().zip(args) {
+        *slot = StrArg {
+            ptr: arg.as_ptr() as usize,
+            len: arg.len(),
+        };
+    }
+    Ok(out)
+}
+
+// =============================================================================
+// PROCESSO (0x01 - 0x0F)
+// =============================================================================
+
+/// Encerra o processo (`SYS_EXIT`).
+pub fn sys_exit(code: i32) -> ! {
+    // SAFETY: argumento escalar.
+    unsafe { syscall1(SYS_EXIT, code as usize) };
+    // Nunca deveria chegar aqui
+    loop {
+        unsafe { core::arch::asm!("hlt") };
+    }
+}
+
+/// Cria processo; retorna o PID (`SYS_SPAWN`).
+///
+/// No máximo [`MAX_ARGS`] argumentos.
+pub fn sys_spawn(path: &str, args: &[&str]) -> SysResult<usize> {
+    let argv = marshal_args(args)?;
+    // SAFETY: path, argv e as strings apontadas são lidos pelo kernel
+    // durante a chamada.
+    check_error(unsafe {
+        syscall4(
+            SYS_SPAWN,
+            path.as_ptr() as usize,
+            path.len(),
+            ptr_or_null(&argv[..args.len()]),
+            args.len(),
+        )
+    })
+}
+
+/// Espera processo terminar; retorna o exit code (`SYS_WAIT`).
+///
+/// `pid` 0 = qualquer filho; `timeout_ms` 0 = infinito.
+pub fn sys_wait(pid: usize, timeout_ms: u64) -> SysResult<i32> {
+    // SAFETY: argumentos escalares.
+    check_error(unsafe { syscall2(SYS_WAIT, pid, timeout_ms as usize) }).map(|v| v as i32)
+}
+
+/// Cede o restante do quantum (`SYS_YIELD`).
+pub fn sys_yield() -> SysResult<()> {
+    // SAFETY: sem argumentos.
+    to_unit(unsafe { syscall0(SYS_YIELD) })
+}
+
+/// PID do processo atual (`SYS_GETPID`).
+pub fn sys_getpid() -> usize {
+    // SAFETY: sem argumentos.
+    unsafe { syscall0(SYS_GETPID) as usize }
+}
+
+/// Copia as informações da tarefa `pid` (0 = atual) para `out`, no
+/// formato do kernel; retorna bytes escritos (`SYS_GETTASKINFO`).
+pub fn sys_gettaskinfo(pid: usize, out: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo out.len() bytes.
+    check_error(unsafe { syscall3(SYS_GETTASKINFO, pid, out.as_mut_ptr() as usize, out.len()) })
+}
+
+/// ID da thread atual (`SYS_GETTID`).
+pub fn sys_gettid() -> usize {
+    // SAFETY: sem argumentos.
+    unsafe { syscall0(SYS_GETTID) as usize }
+}
+
+/// Cria thread que executa `entry(arg)`; retorna o TID
+/// (`SYS_THREAD_CREATE`).
+///
+/// # Safety
+/// `stack_top` deve ser o topo (alinhado a 16 bytes) de uma pilha válida e
+/// de uso exclusivo da nova thread até ela chamar [`sys_thread_exit`].
+/// `arg` é repassado sem verificação.
+pub unsafe fn sys_thread_create(
+    entry: extern "C" fn(usize) -> !,
+    stack_top: *mut u8,
+    arg: usize,
+) -> SysResult<usize> {
+    check_error(syscall3(
+        SYS_THREAD_CREATE,
+        entry as usize,
+        stack_top as usize,
+        arg,
+    ))
+}
+
+/// Encerra a thread atual (`SYS_THREAD_EXIT`).
+pub fn sys_thread_exit(code: i32) -> ! {
+    // SAFETY: argumento escalar.
+    unsafe { syscall1(SYS_THREAD_EXIT, code as usize) };
+    // Nunca deveria chegar aqui
+    loop {
+        unsafe { core::arch::asm!("hlt") };
+    }
+}
+
+/// Publica `*word = value`, acorda uma thread esperando em `word` e
+/// encerra a thread atual (`SYS_FUTEX_WAKE` + `SYS_THREAD_EXIT`).
+///
+/// A pilha não é tocada depois do store: quem vê `value` em `word` pode
+/// liberá-la.
+///
+/// # Safety
+/// `word` deve ser válido até o store (depois dele pode ser liberado).
+pub unsafe fn sys_thread_exit_wake(word: *const AtomicU32, value: u32, code: i32) -> ! {
+    thread_exit_wake(word as *const u32, value, code as usize)
+}
+
+/// Argumentos de `SYS_SPAWN_EX` (layout compatível com kernel).
+#[repr(C)]
+struct SpawnArgs {
+    path_ptr: usize,
+    path_len: usize,
+    /// Array de [`StrArg`].
+    args_ptr: usize,
+    args_len: usize,
+    handles_ptr: usize,
+    handles_len: usize,
+    flags: u32,
+    _pad: u32,
+}
+
+/// Cria processo com handles herdados; retorna o PID (`SYS_SPAWN_EX`).
+///
+/// No máximo [`MAX_ARGS`] argumentos.
+pub fn sys_spawn_ex(
+    path: &str,
+    args: &[&str],
+    handles: &[HandleTransfer],
+    flags: u32,
+) -> SysResult<usize> {
+    let argv = marshal_args(args)?;
+    let req = SpawnArgs {
+        path_ptr: path.as_ptr() as usize,
+        path_len: path.len(),
+        args_ptr: ptr_or_null(&argv[..args.len()]),
+        args_len: args.len(),
+        handles_ptr: ptr_or_null(handles),
+        handles_len: handles.len(),
+        flags,
+        _pad: 0,
+    };
+    // SAFETY: req, argv e os slices apontados vivem até o fim da chamada.
+    check_error(unsafe {
+        syscall2(
+            SYS_SPAWN_EX,
+            &req as *const SpawnArgs as usize,
+            size_of::<SpawnArgs>(),
+        )
+    })
+}
+
+/// Preenche `entries` com os handles herdados do pai; retorna quantos há
+/// (`SYS_GET_STARTUP_HANDLES`).
+pub fn sys_get_startup_handles(entries: &mut [HandleTransfer]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo entries.len() entradas.
+    check_error(unsafe {
+        syscall2(
+            SYS_GET_STARTUP_HANDLES,
+            entries.as_mut_ptr() as usize,
+            entries.len(),
+        )
+    })
+}
+
+/// Dá nome ao processo (`target` 0) ou à thread atual (1)
+/// (`SYS_SET_TASK_NAME`).
+pub fn sys_set_task_name(target: usize, name: &str) -> SysResult<()> {
+    // SAFETY: name é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_SET_TASK_NAME,
+            target,
+            name.as_ptr() as usize,
+            name.len(),
+        )
+    })
+}
+
+/// Registra `port` para receber os sinais de `mask` (`SYS_SIGNAL_PORT`).
+pub fn sys_signal_port(port: Handle, mask: u32) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_SIGNAL_PORT, port.raw() as usize, mask as usize) })
+}
+
+// =============================================================================
+// MEMÓRIA (0x10 - 0x1F)
+// =============================================================================
+
+/// Aloca memória virtual (`SYS_ALLOC`).
+pub fn sys_alloc(size: usize, flags: u32) -> SysResult<*mut u8> {
+    // SAFETY: argumentos escalares; a região é nova.
+    check_error(unsafe { syscall2(SYS_ALLOC, size, flags as usize) }).map(|v| v as *mut u8)
+}
+
+/// Libera memória alocada (`SYS_FREE`).
+///
+/// # Safety
+/// `ptr`/`size` devem vir de [`sys_alloc`] e não podem mais ser usados.
+pub unsafe fn sys_free(ptr: *mut u8, size: usize) -> SysResult<()> {
+    to_unit(syscall2(SYS_FREE, ptr as usize, size))
+}
+
+/// Mapeia memória (`SYS_MAP`); `handle` 0 = anônimo.
+///
+/// # Safety
+/// Com `map_flags::FIXED`, um mapeamento existente em `addr` é substituído.
+pub unsafe fn sys_map(addr: usize, size: usize, flags: u32, handle: u32) -> SysResult<*mut u8> {
+    check_error(syscall4(
+        SYS_MAP,
+        addr,
+        size,
+        flags as usize,
+        handle as usize,
+    ))
+    .map(|v| v as *mut u8)
+}
+
+/// Remove mapeamento (`SYS_UNMAP`).
+///
+/// # Safety
+/// A região não pode mais ser acessada.
+pub unsafe fn sys_unmap(addr: *mut u8, size: usize) -> SysResult<()> {
+    to_unit(syscall2(SYS_UNMAP, addr as usize, size))
+}
+
+/// Altera a proteção (`map_flags::READ/WRITE/EXEC`) de uma região
+/// (`SYS_MPROTECT`).
+///
+/// # Safety
+/// Referências à região não podem sobreviver à perda do acesso que usam.
+pub unsafe fn sys_mprotect(addr: *mut u8, size: usize, flags: u32) -> SysResult<()> {
+    to_unit(syscall3(SYS_MPROTECT, addr as usize, size, flags as usize))
+}
+
+/// Copia as estatísticas de memória do processo para `out`, no formato do
+/// kernel; retorna bytes escritos (`SYS_MEMINFO`).
+pub fn sys_meminfo(out: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo out.len() bytes.
+    check_error(unsafe { syscall2(SYS_MEMINFO, out.as_mut_ptr() as usize, out.len()) })
+}
+
+/// Aloca memória no endereço `addr` (`SYS_ALLOC_AT`).
+///
+/// # Safety
+/// Como [`sys_map`] com `FIXED`: um mapeamento existente em `addr` é
+/// substituído.
+pub unsafe fn sys_alloc_at(addr: usize, size: usize, flags: u32) -> SysResult<*mut u8> {
+    check_error(syscall3(SYS_ALLOC_AT, addr, size, flags as usize)).map(|v| v as *mut u8)
+}
+
+/// Cria região de memória compartilhada (`SYS_SHM_CREATE`).
+pub fn sys_shm_create(size: usize) -> SysResult<ShmId> {
+    // SAFETY: argumento escalar.
+    check_error(unsafe { syscall1(SYS_SHM_CREATE, size) }).map(|id| ShmId(id as u64))
+}
+
+/// Mapeia a região no processo (`SYS_SHM_ATTACH`).
+pub fn sys_shm_attach(id: ShmId) -> SysResult<*mut u8> {
+    // SAFETY: argumentos escalares; o kernel escolhe o endereço.
+    check_error(unsafe { syscall2(SYS_SHM_ATTACH, id.0 as usize, 0) }).map(|v| v as *mut u8)
+}
+
+/// Libera a referência do processo à região (`SYS_SHM_RELEASE`).
+///
+/// # Safety
+/// Mapeamentos da região feitos por [`sys_shm_attach`] não podem mais ser
+/// acessados.
+pub unsafe fn sys_shm_release(id: ShmId) -> SysResult<()> {
+    to_unit(syscall1(SYS_SHM_RELEASE, id.0 as usize))
+}
+
+/// Fecha o mapeamento que começa em `addr` (`SYS_CLOSE_MAPPING`).
+///
+/// # Safety
+/// Como [`sys_unmap`]: a região não pode mais ser acessada.
+pub unsafe fn sys_close_mapping(addr: *mut u8) -> SysResult<()> {
+    to_unit(syscall1(SYS_CLOSE_MAPPING, addr as usize))
+}
+
+/// Grava no objeto de origem as alterações de uma região mapeada
+/// (`SYS_MSYNC`).
+pub fn sys_msync(region: &[u8], flags: u32) -> SysResult<()> {
+    // SAFETY: a região é válida durante a chamada e não é modificada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_MSYNC,
+            region.as_ptr() as usize,
+            region.len(),
+            flags as usize,
+        )
+    })
+}
+
+/// Informa o padrão de uso de uma região (`SYS_MADVISE`).
+///
+/// # Safety
+/// Conselhos que descartam páginas zeram o conteúdo: nenhum dado da região
+/// pode ser necessário depois.
+pub unsafe fn sys_madvise(addr: *mut u8, size: usize, advice: u32) -> SysResult<()> {
+    to_unit(syscall3(SYS_MADVISE, addr as usize, size, advice as usize))
+}
+
+/// Tamanho da região em bytes (`SYS_SHM_GET_SIZE`).
+pub fn sys_shm_get_size(id: ShmId) -> SysResult<usize> {
+    // SAFETY: argumento escalar.
+    check_error(unsafe { syscall1(SYS_SHM_GET_SIZE, id.0 as usize) })
+}
+
+// =============================================================================
+// HANDLES (0x20 - 0x2F)
+// =============================================================================
+
+/// Duplica `handle` com os mesmos direitos (`SYS_HANDLE_DUP`).
+pub fn sys_handle_dup(handle: Handle) -> SysResult<Handle> {
+    // SAFETY: argumentos escalares.
+    to_handle(unsafe { syscall2(SYS_HANDLE_DUP, handle.raw() as usize, usize::MAX) })
+}
+
+/// Fecha `handle` (`SYS_HANDLE_CLOSE`).
+pub fn sys_handle_close(handle: Handle) -> SysResult<()> {
+    // SAFETY: argumento escalar.
+    to_unit(unsafe { syscall1(SYS_HANDLE_CLOSE, handle.raw() as usize) })
+}
+
+/// `Ok` se `handle` tem todos os `rights`, senão `PermissionDenied`
+/// (`SYS_CHECK_RIGHTS`).
+pub fn sys_check_rights(handle: Handle, rights: HandleRights) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe {
+        syscall2(
+            SYS_CHECK_RIGHTS,
+            handle.raw() as usize,
+            rights.bits() as usize,
+        )
+    })
+}
+
+// =============================================================================
+// IPC (0x30 - 0x3F)
+// =============================================================================
+
+/// Cria porta nomeada (`SYS_CREATE_PORT`).
+pub fn sys_create_port(name: &str, capacity: usize) -> SysResult<Handle> {
+    // SAFETY: name é lido pelo kernel durante a chamada.
+    to_handle(unsafe {
+        syscall4(
+            SYS_CREATE_PORT,
+            name.as_ptr() as usize,
+            name.len(),
+            capacity,
+            0,
+        )
+    })
+}
+
+/// Envia mensagem; retorna bytes enviados (`SYS_SEND_MSG`).
+pub fn sys_send_msg(port: Handle, data: &[u8], flags: u32) -> SysResult<usize> {
+    // SAFETY: data é lido pelo kernel durante a chamada.
+    check_error(unsafe {
+        syscall4(
+            SYS_SEND_MSG,
+            port.raw() as usize,
+            data.as_ptr() as usize,
+            data.len(),
+            flags as usize,
+        )
+    })
+}
+
+/// Recebe mensagem sem bloquear; retorna bytes (0 = fila vazia)
+/// (`SYS_RECV_MSG`).
+pub fn sys_recv_msg(port: Handle, buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes. O timeout (arg4)
+    // é ignorado pelo kernel por enquanto.
+    check_error(unsafe {
+        syscall4(
+            SYS_RECV_MSG,
+            port.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            0,
+        )
+    })
+}
+
+/// Dorme enquanto `word` valer `expected`; `timeout_ms` 0 = infinito
+/// (`SYS_FUTEX_WAIT`).
+///
+/// Retorna `Ok` ao ser acordado ou se o valor já era outro.
+pub fn sys_futex_wait(word: &AtomicU32, expected: u32, timeout_ms: u64) -> SysResult<()> {
+    // SAFETY: word é um u32 alinhado e válido durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_FUTEX_WAIT,
+            word.as_ptr() as usize,
+            expected as usize,
+            timeout_ms as usize,
+        )
+    })
+}
+
+/// Acorda até `count` threads esperando em `word`; retorna quantas
+/// (`SYS_FUTEX_WAKE`).
+pub fn sys_futex_wake(word: &AtomicU32, count: usize) -> SysResult<usize> {
+    // SAFETY: o kernel só usa o endereço como chave.
+    check_error(unsafe { syscall2(SYS_FUTEX_WAKE, word.as_ptr() as usize, count) })
+}
+
+/// Conecta a uma porta nomeada (`SYS_PORT_CONNECT`).
+pub fn sys_port_connect(name: &str) -> SysResult<Handle> {
+    // SAFETY: name é lido pelo kernel durante a chamada.
+    to_handle(unsafe { syscall2(SYS_PORT_CONNECT, name.as_ptr() as usize, name.len()) })
+}
+
+// =============================================================================
+// GRÁFICOS / INPUT (0x40 - 0x4F)
+// =============================================================================
+
+/// Informações do framebuffer (`SYS_FB_INFO`).
+pub fn sys_fb_info() -> SysResult<FramebufferInfo> {
+    let mut info = FramebufferInfo::default();
+    // SAFETY: o kernel escreve um FramebufferInfo em info.
+    to_unit(unsafe { syscall1(SYS_FB_INFO, &mut info as *mut FramebufferInfo as usize) })?;
+    Ok(info)
+}
+
+/// Copia `data` para o framebuffer a partir de `offset`; retorna bytes
+/// escritos (`SYS_FB_WRITE`).
+pub fn sys_fb_write(offset: usize, data: &[u8]) -> SysResult<usize> {
+    // SAFETY: data é lido pelo kernel, que limita a escrita ao framebuffer.
+    check_error(unsafe { syscall3(SYS_FB_WRITE, offset, data.as_ptr() as usize, data.len()) })
+}
+
+/// Preenche o framebuffer com `color` (ARGB) (`SYS_FB_CLEAR`).
+pub fn sys_fb_clear(color: u32) -> SysResult<()> {
+    // SAFETY: argumento escalar.
+    to_unit(unsafe { syscall1(SYS_FB_CLEAR, color as usize) })
+}
+
+/// Estado atual do mouse (`SYS_MOUSE_READ`).
+pub fn sys_mouse_read() -> SysResult<MouseState> {
+    let mut state = MouseState::default();
+    // SAFETY: o kernel escreve um MouseState em state.
+    to_unit(unsafe { syscall1(SYS_MOUSE_READ, &mut state as *mut MouseState as usize) })?;
+    Ok(state)
+}
+
+/// Lê eventos de teclado pendentes; retorna quantos (`SYS_KEYBOARD_READ`).
+pub fn sys_keyboard_read(events: &mut [KeyEvent]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo events.len() eventos.
+    check_error(unsafe {
+        syscall2(
+            SYS_KEYBOARD_READ,
+            events.as_mut_ptr() as usize,
+            events.len(),
+        )
+    })
+}
+
+/// Lê pontos de toque pendentes; retorna quantos (`SYS_TOUCH_READ`).
+pub fn sys_touch_read(points: &mut [RawTouchPoint]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo points.len() pontos.
+    check_error(unsafe { syscall2(SYS_TOUCH_READ, points.as_mut_ptr() as usize, points.len()) })
+}
+
+/// Lê o estado dos slots de gamepad; retorna quantos (`SYS_GAMEPAD_READ`).
+pub fn sys_gamepad_read(pads: &mut [RawGamepadState]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo pads.len() estados.
+    check_error(unsafe { syscall2(SYS_GAMEPAD_READ, pads.as_mut_ptr() as usize, pads.len()) })
+}
+
+// =============================================================================
+// TEMPO (0x50 - 0x5F)
+// =============================================================================
+
+/// Lê o clock (`SYS_CLOCK_GET`).
+pub fn sys_clock_get(clock: ClockId) -> SysResult<TimeSpec> {
+    let mut ts = TimeSpec::default();
+    // SAFETY: o kernel escreve um TimeSpec em ts.
+    to_unit(unsafe {
+        syscall2(
+            SYS_CLOCK_GET,
+            clock as usize,
+            &mut ts as *mut TimeSpec as usize,
+        )
+    })?;
+    Ok(ts)
+}
+
+/// Dorme `ms` milissegundos; retorna o tempo restante se interrompido
+/// (`SYS_SLEEP`).
+pub fn sys_sleep(ms: u64) -> SysResult<u64> {
+    // SAFETY: argumento escalar.
+    check_error(unsafe { syscall1(SYS_SLEEP, ms as usize) }).map(|v| v as u64)
+}
+
+/// Cria timer desarmado (`SYS_TIMER_CREATE`).
+pub fn sys_timer_create(clock: ClockId) -> SysResult<Handle> {
+    // SAFETY: argumentos escalares.
+    to_handle(unsafe { syscall2(SYS_TIMER_CREATE, clock as usize, 0) })
+}
+
+/// Arma ou desarma o timer (`SYS_TIMER_SET`); `flags` de `timer_flags`.
+pub fn sys_timer_set(timer: Handle, spec: &TimerSpec, flags: usize) -> SysResult<()> {
+    // SAFETY: spec é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_TIMER_SET,
+            timer.raw() as usize,
+            spec as *const TimerSpec as usize,
+            flags,
+        )
+    })
+}
+
+// =============================================================================
+// FILESYSTEM (0x60 - 0x7F)
+// =============================================================================
+
+/// Abre arquivo ou diretório (`SYS_OPEN`).
+pub fn sys_open(path: &str, flags: OpenFlags) -> SysResult<Handle> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_handle(unsafe {
+        syscall4(
+            SYS_OPEN,
+            path.as_ptr() as usize,
+            path.len(),
+            flags.0 as usize,
+            0, // mode
+        )
+    })
+}
+
+/// Lê do handle; retorna bytes lidos (`SYS_READ`).
+pub fn sys_read(handle: Handle, buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe {
+        syscall3(
+            SYS_READ,
+            handle.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        )
+    })
+}
+
+/// Escreve no handle; retorna bytes escritos (`SYS_WRITE`).
+pub fn sys_write(handle: Handle, buf: &[u8]) -> SysResult<usize> {
+    // SAFETY: buf é lido pelo kernel durante a chamada.
+    check_error(unsafe {
+        syscall3(
+            SYS_WRITE,
+            handle.raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+        )
+    })
+}
+
+/// Move o cursor; retorna a nova posição (`SYS_SEEK`).
+pub fn sys_seek(handle: Handle, offset: i64, whence: SeekFrom) -> SysResult<u64> {
+    // SAFETY: argumentos escalares.
+    check_error(unsafe {
+        syscall3(
+            SYS_SEEK,
+            handle.raw() as usize,
+            offset as usize,
+            whence as usize,
+        )
+    })
+    .map(|v| v as u64)
+}
+
+/// Lê em `offset` sem mover o cursor (`SYS_PREAD`).
+pub fn sys_pread(handle: Handle, buf: &mut [u8], offset: u64) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe {
+        syscall4(
+            SYS_PREAD,
+            handle.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        )
+    })
+}
+
+/// Escreve em `offset` sem mover o cursor (`SYS_PWRITE`).
+pub fn sys_pwrite(handle: Handle, buf: &[u8], offset: u64) -> SysResult<usize> {
+    // SAFETY: buf é lido pelo kernel durante a chamada.
+    check_error(unsafe {
+        syscall4(
+            SYS_PWRITE,
+            handle.raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        )
+    })
+}
+
+/// Força flush dos buffers do handle (`SYS_FLUSH`).
+pub fn sys_flush(handle: Handle) -> SysResult<()> {
+    // SAFETY: argumento escalar.
+    to_unit(unsafe { syscall1(SYS_FLUSH, handle.raw() as usize) })
+}
+
+/// Redimensiona o arquivo (`SYS_TRUNCATE`).
+pub fn sys_truncate(handle: Handle, size: u64) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_TRUNCATE, handle.raw() as usize, size as usize) })
+}
+
+/// Metadados por caminho (`SYS_STAT`).
+pub fn sys_stat(path: &str) -> SysResult<FileStat> {
+    let mut st = FileStat::zeroed();
+    // SAFETY: path é lido e st escrito pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_STAT,
+            path.as_ptr() as usize,
+            path.len(),
+            &mut st as *mut FileStat as usize,
+        )
+    })?;
+    Ok(st)
+}
+
+/// Metadados por handle (`SYS_FSTAT`).
+pub fn sys_fstat(handle: Handle) -> SysResult<FileStat> {
+    let mut st = FileStat::zeroed();
+    // SAFETY: o kernel escreve um FileStat em st.
+    to_unit(unsafe {
+        syscall2(
+            SYS_FSTAT,
+            handle.raw() as usize,
+            &mut st as *mut FileStat as usize,
+        )
+    })?;
+    Ok(st)
+}
+
+/// Altera permissões (`SYS_CHMOD`).
+pub fn sys_chmod(path: &str, mode: u32) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall3(SYS_CHMOD, path.as_ptr() as usize, path.len(), mode as usize) })
+}
+
+/// Altera dono e grupo (`SYS_CHOWN`).
+pub fn sys_chown(path: &str, uid: u32, gid: u32) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_CHOWN,
+            path.as_ptr() as usize,
+            path.len(),
+            uid as usize,
+            gid as usize,
+        )
+    })
+}
+
+/// Lê entradas do diretório em formato bruto; retorna bytes
+/// (`SYS_GETDENTS`).
+pub fn sys_getdents(dir: Handle, buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe {
+        syscall3(
+            SYS_GETDENTS,
+            dir.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        )
+    })
+}
+
+/// Cria diretório (`SYS_MKDIR`).
+pub fn sys_mkdir(path: &str, mode: u32) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall3(SYS_MKDIR, path.as_ptr() as usize, path.len(), mode as usize) })
+}
+
+/// Remove diretório vazio (`SYS_RMDIR`).
+pub fn sys_rmdir(path: &str) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall2(SYS_RMDIR, path.as_ptr() as usize, path.len()) })
+}
+
+/// Copia o diretório atual para `buf` (com terminador nulo); retorna bytes
+/// incluindo o terminador (`SYS_GETCWD`).
+pub fn sys_getcwd(buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe { syscall2(SYS_GETCWD, buf.as_mut_ptr() as usize, buf.len()) })
+}
+
+/// Cria arquivo vazio (`SYS_CREATE`).
+pub fn sys_create(path: &str, mode: u32) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_CREATE,
+            path.as_ptr() as usize,
+            path.len(),
+            mode as usize,
+        )
+    })
+}
+
+/// Remove arquivo (`SYS_UNLINK`).
+pub fn sys_unlink(path: &str) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall2(SYS_UNLINK, path.as_ptr() as usize, path.len()) })
+}
+
+/// Renomeia/move (`SYS_RENAME`).
+pub fn sys_rename(old_path: &str, new_path: &str) -> SysResult<()> {
+    // SAFETY: os caminhos são lidos pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_RENAME,
+            old_path.as_ptr() as usize,
+            old_path.len(),
+            new_path.as_ptr() as usize,
+            new_path.len(),
+        )
+    })
+}
+
+/// Cria hard link `new_path` para `existing` (`SYS_LINK`).
+pub fn sys_link(existing: &str, new_path: &str) -> SysResult<()> {
+    // SAFETY: os caminhos são lidos pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_LINK,
+            existing.as_ptr() as usize,
+            existing.len(),
+            new_path.as_ptr() as usize,
+            new_path.len(),
+        )
+    })
+}
+
+/// Cria link simbólico `link_path` apontando para `target` (`SYS_SYMLINK`).
+pub fn sys_symlink(target: &str, link_path: &str) -> SysResult<()> {
+    // SAFETY: os caminhos são lidos pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_SYMLINK,
+            target.as_ptr() as usize,
+            target.len(),
+            link_path.as_ptr() as usize,
+            link_path.len(),
+        )
+    })
+}
+
+/// Copia o destino do link para `buf`; retorna bytes (`SYS_READLINK`).
+pub fn sys_readlink(path: &str, buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: path é lido e no máximo buf.len() bytes são escritos.
+    check_error(unsafe {
+        syscall4(
+            SYS_READLINK,
+            path.as_ptr() as usize,
+            path.len(),
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        )
+    })
+}
+
+/// Copia o caminho canônico de `path` para `buf`; retorna bytes
+/// (`SYS_REALPATH`).
+pub fn sys_realpath(path: &str, buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: path é lido e no máximo buf.len() bytes são escritos.
+    check_error(unsafe {
+        syscall4(
+            SYS_REALPATH,
+            path.as_ptr() as usize,
+            path.len(),
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        )
+    })
+}
+
+/// Monta `source` em `target` (`SYS_MOUNT`).
+pub fn sys_mount(source: &str, target: &str, flags: u32) -> SysResult<()> {
+    // SAFETY: os caminhos são lidos pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall5(
+            SYS_MOUNT,
+            source.as_ptr() as usize,
+            source.len(),
+            target.as_ptr() as usize,
+            target.len(),
+            flags as usize,
+        )
+    })
+}
+
+/// Desmonta o filesystem em `target` (`SYS_UMOUNT`).
+pub fn sys_umount(target: &str) -> SysResult<()> {
+    // SAFETY: target é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall2(SYS_UMOUNT, target.as_ptr() as usize, target.len()) })
+}
+
+/// Informações do filesystem que contém `path` (`SYS_STATFS`).
+pub fn sys_statfs(path: &str) -> SysResult<FsStat> {
+    let mut st = FsStat::default();
+    // SAFETY: path é lido e st escrito pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_STATFS,
+            path.as_ptr() as usize,
+            path.len(),
+            &mut st as *mut FsStat as usize,
+        )
+    })?;
+    Ok(st)
+}
+
+/// Grava todos os buffers pendentes (`SYS_SYNC`).
+pub fn sys_sync() -> SysResult<()> {
+    // SAFETY: sem argumentos.
+    to_unit(unsafe { syscall0(SYS_SYNC) })
+}
+
+/// Comando específico do dispositivo (`SYS_IOCTL`).
+///
+/// # Safety
+/// Se `cmd` trata `arg` como ponteiro, ele deve ser válido para o acesso
+/// que o driver faz durante a chamada.
+pub unsafe fn sys_ioctl(handle: Handle, cmd: u32, arg: usize) -> SysResult<usize> {
+    check_error(syscall3(
+        SYS_IOCTL,
+        handle.raw() as usize,
+        cmd as usize,
+        arg,
+    ))
+}
+
+/// Lê ou altera flags do handle (`arg` escalar) (`SYS_FCNTL`).
+pub fn sys_fcntl(handle: Handle, cmd: u32, arg: usize) -> SysResult<usize> {
+    // SAFETY: argumentos escalares.
+    check_error(unsafe { syscall3(SYS_FCNTL, handle.raw() as usize, cmd as usize, arg) })
+}
+
+/// Adquire ou libera lock do arquivo (`SYS_FLOCK`).
+pub fn sys_flock(handle: Handle, op: u32) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_FLOCK, handle.raw() as usize, op as usize) })
+}
+
+/// Verifica acesso (`mode`: 0 existe, 4 leitura, 2 escrita, 1 execução)
+/// (`SYS_ACCESS`).
+pub fn sys_access(path: &str, mode: u32) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_ACCESS,
+            path.as_ptr() as usize,
+            path.len(),
+            mode as usize,
+        )
+    })
+}
+
+/// Altera o diretório de trabalho (`SYS_CHDIR`).
+pub fn sys_chdir(path: &str) -> SysResult<()> {
+    // SAFETY: path é lido pelo kernel durante a chamada.
+    to_unit(unsafe { syscall2(SYS_CHDIR, path.as_ptr() as usize, path.len()) })
+}
+
+// =============================================================================
+// EVENTS (0x80 - 0x8F)
+// =============================================================================
+
+/// Espera eventos nos descritores; retorna quantos estão prontos
+/// (`SYS_POLL`).
+pub fn sys_poll(fds: &mut [PollFd], timeout_ms: i64) -> SysResult<usize> {
+    // SAFETY: o kernel atualiza no máximo fds.len() descritores.
+    check_error(unsafe {
+        syscall3(
+            SYS_POLL,
+            fds.as_mut_ptr() as usize,
+            fds.len(),
+            timeout_ms as usize,
+        )
+    })
+}
+
+/// Cria fila de eventos (`SYS_EQ_CREATE`).
+pub fn sys_eq_create(flags: u32) -> SysResult<Handle> {
+    // SAFETY: argumento escalar.
+    to_handle(unsafe { syscall1(SYS_EQ_CREATE, flags as usize) })
+}
+
+/// Adiciona/altera/remove `handle` da fila (`op` de `eq_ctl`)
+/// (`SYS_EQ_CTL`).
+pub fn sys_eq_ctl(queue: Handle, op: usize, handle: Handle, event: &QueueEvent) -> SysResult<()> {
+    // SAFETY: event é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_EQ_CTL,
+            queue.raw() as usize,
+            op,
+            handle.raw() as usize,
+            event as *const QueueEvent as usize,
+        )
+    })
+}
+
+/// Espera eventos da fila; retorna quantos (`SYS_EQ_WAIT`).
+pub fn sys_eq_wait(queue: Handle, events: &mut [QueueEvent], timeout_ms: i64) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo events.len() eventos.
+    check_error(unsafe {
+        syscall4(
+            SYS_EQ_WAIT,
+            queue.raw() as usize,
+            events.as_mut_ptr() as usize,
+            events.len(),
+            timeout_ms as usize,
+        )
+    })
+}
+
+/// Cria contador sinalizável (`SYS_EVENTFD_CREATE`).
+pub fn sys_eventfd_create(flags: u32) -> SysResult<Handle> {
+    // SAFETY: argumento escalar.
+    to_handle(unsafe { syscall1(SYS_EVENTFD_CREATE, flags as usize) })
+}
+
+// =============================================================================
+// REDE - SOCKETS (0x90 - 0x9F)
+// =============================================================================
+
+/// Cria socket (`SYS_SOCKET`).
+pub fn sys_socket(domain: usize, ty: usize, flags: usize) -> SysResult<Handle> {
+    // SAFETY: argumentos escalares.
+    to_handle(unsafe { syscall3(SYS_SOCKET, domain, ty, flags) })
+}
+
+/// Associa endereço local (bytes no formato do kernel) (`SYS_BIND`).
+pub fn sys_bind(socket: Handle, addr: &[u8]) -> SysResult<()> {
+    // SAFETY: addr é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall3(
+            SYS_BIND,
+            socket.raw() as usize,
+            addr.as_ptr() as usize,
+            addr.len(),
+        )
+    })
+}
+
+/// Passa a aceitar conexões (`SYS_LISTEN`).
+pub fn sys_listen(socket: Handle, backlog: u32) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_LISTEN, socket.raw() as usize, backlog as usize) })
+}
+
+/// Aceita conexão, escrevendo o endereço remoto em `peer`
+/// (`SYS_ACCEPT`).
+pub fn sys_accept(socket: Handle, peer: &mut [u8], flags: usize) -> SysResult<Handle> {
+    // SAFETY: o kernel escreve no máximo peer.len() bytes.
+    to_handle(unsafe {
+        syscall4(
+            SYS_ACCEPT,
+            socket.raw() as usize,
+            peer.as_mut_ptr() as usize,
+            peer.len(),
+            flags,
+        )
+    })
+}
+
+/// Conecta a endereço remoto (`SYS_CONNECT`).
+pub fn sys_connect(socket: Handle, addr: &[u8], flags: usize) -> SysResult<()> {
+    // SAFETY: addr é lido pelo kernel durante a chamada.
+    to_unit(unsafe {
+        syscall4(
+            SYS_CONNECT,
+            socket.raw() as usize,
+            addr.as_ptr() as usize,
+            addr.len(),
+            flags,
+        )
+    })
+}
+
+/// Envia dados; retorna bytes enviados (`SYS_SEND`).
+pub fn sys_send(socket: Handle, buf: &[u8], flags: usize) -> SysResult<usize> {
+    // SAFETY: buf é lido pelo kernel durante a chamada.
+    check_error(unsafe {
+        syscall4(
+            SYS_SEND,
+            socket.raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            flags,
+        )
+    })
+}
+
+/// Recebe dados; retorna bytes recebidos (`SYS_RECV`).
+pub fn sys_recv(socket: Handle, buf: &mut [u8], flags: usize) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe {
+        syscall4(
+            SYS_RECV,
+            socket.raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            flags,
+        )
+    })
+}
+
+/// Encerra leitura e/ou escrita (`SYS_SHUTDOWN`).
+pub fn sys_shutdown(socket: Handle, how: usize) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_SHUTDOWN, socket.raw() as usize, how) })
+}
+
+/// Define opção (`SYS_SETSOCKOPT`).
+pub fn sys_setsockopt(socket: Handle, option: usize, value: usize) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall3(SYS_SETSOCKOPT, socket.raw() as usize, option, value) })
+}
+
+/// Lê opção (`SYS_GETSOCKOPT`).
+pub fn sys_getsockopt(socket: Handle, option: usize) -> SysResult<usize> {
+    // SAFETY: argumentos escalares.
+    check_error(unsafe { syscall2(SYS_GETSOCKOPT, socket.raw() as usize, option) })
+}
+
+/// Escreve o endereço local (`which` 0) ou remoto (1) em `out`
+/// (`SYS_SOCKADDR`).
+pub fn sys_sockaddr(socket: Handle, which: usize, out: &mut [u8]) -> SysResult<()> {
+    // SAFETY: o kernel escreve no máximo out.len() bytes.
+    to_unit(unsafe {
+        syscall4(
+            SYS_SOCKADDR,
+            socket.raw() as usize,
+            which,
+            out.as_mut_ptr() as usize,
+            out.len(),
+        )
+    })
+}
+
+/// Mensagem com handles anexados (`SYS_SOCK_SENDMSG` / `SYS_SOCK_RECVMSG`).
+///
+/// Em `recvmsg`, o kernel atualiza `handles_len` com o número recebido.
+#[repr(C)]
+struct MsgHdr {
+    data_ptr: usize,
+    data_len: usize,
+    handles_ptr: usize,
+    handles_len: usize,
+}
+
+/// Envia dados e transfere `handles`; retorna bytes enviados
+/// (`SYS_SOCK_SENDMSG`).
+pub fn sys_sock_sendmsg(
+    socket: Handle,
+    data: &[u8],
+    handles: &[Handle],
+    flags: usize,
+) -> SysResult<usize> {
+    let hdr = MsgHdr {
+        data_ptr: data.as_ptr() as usize,
+        data_len: data.len(),
+        handles_ptr: handles.as_ptr() as usize,
+        handles_len: handles.len(),
+    };
+    // SAFETY: hdr e os slices apontados vivem até o fim da chamada.
+    check_error(unsafe {
+        syscall3(
+            SYS_SOCK_SENDMSG,
+            socket.raw() as usize,
+            &hdr as *const MsgHdr as usize,
+            flags,
+        )
+    })
+}
+
+/// Recebe dados e handles; retorna (bytes, handles recebidos)
+/// (`SYS_SOCK_RECVMSG`).
+pub fn sys_sock_recvmsg(
+    socket: Handle,
+    data: &mut [u8],
+    handles: &mut [Handle],
+    flags: usize,
+) -> SysResult<(usize, usize)> {
+    let mut hdr = MsgHdr {
+        data_ptr: data.as_mut_ptr() as usize,
+        data_len: data.len(),
+        handles_ptr: handles.as_mut_ptr() as usize,
+        handles_len: handles.len(),
+    };
+    // SAFETY: o kernel escreve no máximo data.len() bytes e handles.len()
+    // handles.
+    let len = check_error(unsafe {
+        syscall3(
+            SYS_SOCK_RECVMSG,
+            socket.raw() as usize,
+            &mut hdr as *mut MsgHdr as usize,
+            flags,
+        )
+    })?;
+    Ok((len, hdr.handles_len.min(handles.len())))
+}
+
+/// Credenciais do processo do outro lado (`SYS_PEERCRED`).
+pub fn sys_peercred(socket: Handle) -> SysResult<PeerCred> {
+    let mut cred = PeerCred::default();
+    // SAFETY: o kernel escreve um PeerCred em cred.
+    to_unit(unsafe {
+        syscall2(
+            SYS_PEERCRED,
+            socket.raw() as usize,
+            &mut cred as *mut PeerCred as usize,
+        )
+    })?;
+    Ok(cred)
+}
+
+// =============================================================================
+// SISTEMA / DEBUG (0xF0 - 0xFF)
+// =============================================================================
+
+/// Informações do sistema (`SYS_SYSINFO`).
+pub fn sys_sysinfo() -> SysResult<SysInfo> {
+    let mut info = SysInfo::default();
+    // SAFETY: o kernel escreve no máximo size_of::<SysInfo>() bytes.
+    to_unit(unsafe {
+        syscall2(
+            SYS_SYSINFO,
+            &mut info as *mut SysInfo as usize,
+            size_of::<SysInfo>(),
+        )
+    })?;
+    Ok(info)
+}
+
+/// Reinicia o sistema (`SYS_REBOOT`).
+pub fn sys_reboot() -> ! {
+    // SAFETY: sem argumentos.
+    unsafe { syscall0(SYS_REBOOT) };
+    loop {
+        unsafe { core::arch::asm!("hlt") };
+    }
+}
+
+/// Desliga o sistema (`SYS_POWEROFF`).
+pub fn sys_poweroff() -> ! {
+    // SAFETY: sem argumentos.
+    unsafe { syscall0(SYS_POWEROFF) };
+    loop {
+        unsafe { core::arch::asm!("hlt") };
+    }
+}
+
+/// Escreve na console; retorna bytes escritos (`SYS_CONSOLE_WRITE`).
+pub fn sys_console_write(buf: &[u8]) -> SysResult<usize> {
+    // SAFETY: buf é lido pelo kernel durante a chamada.
+    check_error(unsafe { syscall2(SYS_CONSOLE_WRITE, buf.as_ptr() as usize, buf.len()) })
+}
+
+/// Lê da console; retorna bytes lidos (`SYS_CONSOLE_READ`).
+pub fn sys_console_read(buf: &mut [u8]) -> SysResult<usize> {
+    // SAFETY: o kernel escreve no máximo buf.len() bytes.
+    check_error(unsafe { syscall2(SYS_CONSOLE_READ, buf.as_mut_ptr() as usize, buf.len()) })
+}
+
+/// Toca o beeper (`SYS_BEEP`).
+pub fn sys_beep(freq: u32, duration_ms: u32) -> SysResult<()> {
+    // SAFETY: argumentos escalares.
+    to_unit(unsafe { syscall2(SYS_BEEP, freq as usize, duration_ms as usize) })
+}
+
+/// Define o modo da console; retorna o anterior (`SYS_CONSOLE_SET_MODE`).
+pub fn sys_console_set_mode(flags: u32) -> SysResult<u32> {
+    // SAFETY: argumento escalar.
+    check_error(unsafe { syscall1(SYS_CONSOLE_SET_MODE, flags as usize) }).map(|v| v as u32)
+}
+
+/// Executa `ops` em ordem; retorna quantas executaram (`SYS_BATCH`).
+///
+/// O kernel escreve o retorno de cada uma em `BatchOp::result`.
+///
+/// # Safety
+/// Os argumentos de cada op devem ser válidos para a syscall `num` (como em
+/// `syscallN`). Prefira [`Batch`](super::batch::Batch).
+pub unsafe fn sys_batch(ops: &mut [BatchOp], flags: u32) -> SysResult<usize> {
+    check_error(syscall3(
+        SYS_BATCH,
+        ops.as_mut_ptr() as usize,
+        ops.len(),
+        flags as usize,
+    ))
+}
+
+/// Executa uma [`BatchOp`] como syscall isolada, gravando `op.result`
+/// (kernels sem `SYS_BATCH`).
+///
+/// # Safety
+/// Mesmo contrato de [`sys_batch`] para a op.
+pub(super) unsafe fn sys_batch_op(op: &mut BatchOp) -> isize {
+    let [a1, a2, a3, a4] = op.args;
+    op.result = syscall4(op.num as usize, a1, a2, a3, a4);
+    op.result
+}
+
+/// Operações de `SYS_DEBUG`.
+mod debug_op {
+    pub const WRITE: usize = 0x01;
+    pub const BREAKPOINT: usize = 0x04;
+}
+
+/// Escreve no log de debug do kernel (`SYS_DEBUG`).
+pub fn sys_debug_write(s: &str) -> SysResult<usize> {
+    // SAFETY: s é lido pelo kernel durante a chamada.
+    check_error(unsafe { syscall3(SYS_DEBUG, debug_op::WRITE, s.as_ptr() as usize, s.len()) })
+}
+
+/// Breakpoint de debug (`SYS_DEBUG`).
+pub fn sys_debug_breakpoint() {
+    // SAFETY: sem ponteiros.
+    unsafe { syscall3(SYS_DEBUG, debug_op::BREAKPOINT, 0, 0) };
+}
@@ -0,0 +1,464 @@
+//! # Syscall Trace
+//!
+//! Modo strace: cada syscall do processo sai em uma linha com nome,
+//! argumentos decodificados e resultado (feature `syscall-trace`).
+//!
+//! ```text
+//! [strace] pid 12: open("/apps/config.txt", 0x0, 0x0) = h3
+//! [strace] pid 12: read(h3, 0x7fff0040, 256) = 118
+//! [strace] pid 12: stat("/apps/nope", 0x7fff0100) = -6 NotFound
+//! ```
+//!
+//! Desligado por padrão: com a feature compilada, cada syscall custa uma
+//! leitura atômica até [`enable`]. A saída vai para o log do kernel
+//! (`SYS_DEBUG`) ou para um handle gravável ([`Output::Handle`]); as
+//! syscalls feitas pelo próprio trace não são registradas.
+//!
+//! Strings de entrada (caminhos, nomes de porta) são mostradas por extenso;
+//! buffers aparecem como ponteiro e tamanho. Syscalls de outras threads
+//! feitas enquanto uma linha está sendo escrita são descartadas.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::syscall::trace;
+//!
+//! trace::set_filter(trace::range::FILESYSTEM);
+//! trace::enable();
+//! let config = fs::read_to_string("/apps/config.txt");
+//! trace::disable();
+//! ```
+
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+
+use super::numbers::*;
+use super::raw::{syscall0, syscall3};
+use super::SysError;
+use crate::io::Handle;
+
+/// Faixas de números por categoria (ver [`numbers`](super::numbers)).
+pub mod range {
+    use core::ops::RangeInclusive;
+
+    pub const ALL: RangeInclusive<usize> = 0x00..=0xFF;
+    pub const PROCESS: RangeInclusive<usize> = 0x01..=0x0F;
+    pub const MEMORY: RangeInclusive<usize> = 0x10..=0x1F;
+    pub const HANDLES: RangeInclusive<usize> = 0x20..=0x2F;
+    pub const IPC: RangeInclusive<usize> = 0x30..=0x3F;
+    pub const GRAPHICS: RangeInclusive<usize> = 0x40..=0x4F;
+    pub const TIME: RangeInclusive<usize> = 0x50..=0x5F;
+    pub const FILESYSTEM: RangeInclusive<usize> = 0x60..=0x7F;
+    pub const EVENTS: RangeInclusive<usize> = 0x80..=0x8F;
+    pub const NET: RangeInclusive<usize> = 0x90..=0x9F;
+    pub const SYSTEM: RangeInclusive<usize> = 0xF0..=0xFF;
+}
+
+/// Destino das linhas de trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// Log do kernel (`SYS_DEBUG`).
+    Kernel,
+    /// Handle gravável (arquivo, pipe). Deve continuar aberto enquanto o
+    /// trace estiver ligado.
+    Handle(Handle),
+}
+
+// =============================================================================
+// CONFIGURAÇÃO
+// =============================================================================
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Trace em andamento (evita registrar as próprias syscalls).
+static BUSY: AtomicBool = AtomicBool::new(false);
+static FILTER_START: AtomicUsize = AtomicUsize::new(0x00);
+static FILTER_END: AtomicUsize = AtomicUsize::new(0xFF);
+/// Handle de saída (`u32::MAX` = kernel).
+static OUTPUT: AtomicU32 = AtomicU32::new(u32::MAX);
+static PID: AtomicUsize = AtomicUsize::new(0);
+
+/// Liga o trace para o processo.
+pub fn enable() {
+    // SAFETY: sem argumentos. Lido antes de ligar para não ser registrado.
+    let pid = unsafe { syscall0(SYS_GETPID) };
+    PID.store(pid.max(0) as usize, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Desliga o trace.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Release);
+}
+
+/// O trace está ligado.
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Registra só as syscalls com número em `range` (padrão: [`range::ALL`]).
+pub fn set_filter(range: RangeInclusive<usize>) {
+    FILTER_START.store(*range.start(), Ordering::Relaxed);
+    FILTER_END.store(*range.end(), Ordering::Relaxed);
+}
+
+/// Faixa registrada atualmente.
+pub fn filter() -> RangeInclusive<usize> {
+    FILTER_START.load(Ordering::Relaxed)..=FILTER_END.load(Ordering::Relaxed)
+}
+
+/// Troca o destino das linhas (padrão: [`Output::Kernel`]).
+pub fn set_output(output: Output) {
+    let raw = match output {
+        Output::Kernel => u32::MAX,
+        Output::Handle(handle) => handle.raw(),
+    };
+    OUTPUT.store(raw, Ordering::Relaxed);
+}
+
+/// Destino atual.
+pub fn output() -> Output {
+    match OUTPUT.load(Ordering::Relaxed) {
+        u32::MAX => Output::Kernel,
+        raw => Output::Handle(Handle::from_raw(raw)),
+    }
+}
+
+// =============================================================================
+// HOOKS (chamados por `raw`)
+// =============================================================================
+
+/// Syscalls que não retornam: registradas antes da chamada.
+#[inline]
+fn is_noreturn(num: usize) -> bool {
+    matches!(num, SYS_EXIT | SYS_THREAD_EXIT | SYS_REBOOT | SYS_POWEROFF)
+}
+
+/// Antes da syscall (só registra as que não retornam).
+#[inline]
+pub(crate) fn before(num: usize, args: &[usize]) {
+    if is_enabled() && is_noreturn(num) {
+        record(num, args, None);
+    }
+}
+
+/// Depois da syscall.
+#[inline]
+pub(crate) fn after(num: usize, args: &[usize], ret: isize) {
+    if is_enabled() && !is_noreturn(num) {
+        record(num, args, Some(ret));
+    }
+}
+
+#[cold]
+fn record(num: usize, args: &[usize], ret: Option<isize>) {
+    if !filter().contains(&num) || BUSY.swap(true, Ordering::Acquire) {
+        return;
+    }
+
+    let mut line = Line {
+        buf: [0; MAX_LINE],
+        len: 0,
+    };
+    let _ = write!(line, "[strace] pid {}: ", PID.load(Ordering::Relaxed));
+    let _ = write_call(&mut line, num, args);
+    let _ = match ret {
+        Some(ret) => write_result(&mut line, num, ret),
+        None => line.write_str(" = ?"),
+    };
+    line.finish();
+
+    let text = &line.buf[..line.len];
+    // SAFETY: text vive até o fim da chamada; BUSY impede recursão.
+    unsafe {
+        match output() {
+            Output::Kernel => {
+                // 0x01 = escrita no log (ver `safe::sys_debug_write`)
+                syscall3(SYS_DEBUG, 0x01, text.as_ptr() as usize, text.len());
+            }
+            Output::Handle(handle) => {
+                syscall3(
+                    SYS_WRITE,
+                    handle.raw() as usize,
+                    text.as_ptr() as usize,
+                    text.len(),
+                );
+            }
+        }
+    }
+
+    BUSY.store(false, Ordering::Release);
+}
+
+// =============================================================================
+// DECODIFICAÇÃO
+// =============================================================================
+
+/// Tamanho máximo de uma linha (excedente é truncado).
+const MAX_LINE: usize = 192;
+
+/// Bytes mostrados de uma string de entrada.
+const MAX_STR: usize = 64;
+
+/// Buffer de linha que trunca ao encher.
+struct Line {
+    buf: [u8; MAX_LINE],
+    len: usize,
+}
+
+impl Line {
+    fn finish(&mut self) {
+        let end = self.len.min(MAX_LINE - 1);
+        self.buf[end] = b'\n';
+        self.len = end + 1;
+    }
+}
+
+impl Write for Line {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Reservar 1 byte para o '\n' final
+        let room = MAX_LINE - 1 - self.len;
+        let mut take = s.len().min(room);
+        while !s.is_char_boundary(take) {
+            take -= 1;
+        }
+        self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+        self.len += take;
+        Ok(())
+    }
+}
+
+/// Formato de um argumento.
+#[derive(Clone, Copy)]
+enum Arg {
+    /// Inteiro decimal.
+    Int,
+    /// Flags/máscara em hexadecimal.
+    Hex,
+    /// Handle (`h3`).
+    Handle,
+    /// Ponteiro.
+    Ptr,
+    /// String de entrada: consome ponteiro e tamanho.
+    Str,
+    /// Inteiro com sinal (timeouts, offsets).
+    Signed,
+}
+
+/// Resultado que é um handle.
+fn returns_handle(num: usize) -> bool {
+    matches!(
+        num,
+        SYS_OPEN
+            | SYS_HANDLE_DUP
+            | SYS_CREATE_PORT
+            | SYS_PORT_CONNECT
+            | SYS_TIMER_CREATE
+            | SYS_EQ_CREATE
+            | SYS_EVENTFD_CREATE
+            | SYS_SOCKET
+            | SYS_ACCEPT
+    )
+}
+
+/// Nome e formato dos argumentos (os de [`safe`](super::safe)); `None`
+/// para números desconhecidos, cujos argumentos saem em hex.
+fn signature(num: usize) -> Option<(&'static str, &'static [Arg])> {
+    use Arg::*;
+    let sig: (&'static str, &'static [Arg]) = match num {
+        SYS_EXIT => ("exit", &[Signed]),
+        SYS_SPAWN => ("spawn", &[Str, Ptr, Int]),
+        SYS_WAIT => ("wait", &[Int, Int]),
+        SYS_YIELD => ("yield", &[]),
+        SYS_GETPID => ("getpid", &[]),
+        SYS_GETTASKINFO => ("gettaskinfo", &[Int, Ptr, Int]),
+        SYS_GETTID => ("gettid", &[]),
+        SYS_THREAD_CREATE => ("thread_create", &[Ptr, Ptr, Hex]),
+        SYS_THREAD_EXIT => ("thread_exit", &[Signed]),
+        SYS_SPAWN_EX => ("spawn_ex", &[Ptr, Int]),
+        SYS_GET_STARTUP_HANDLES => ("get_startup_handles", &[Ptr, Int]),
+        SYS_SET_TASK_NAME => ("set_task_name", &[Int, Str]),
+        SYS_SIGNAL_PORT => ("signal_port", &[Handle, Hex]),
+
+        SYS_ALLOC => ("alloc", &[Int, Hex]),
+        SYS_FREE => ("free", &[Ptr, Int]),
+        SYS_MAP => ("map", &[Ptr, Int, Hex, Handle]),
+        SYS_UNMAP => ("unmap", &[Ptr, Int]),
+        SYS_MPROTECT => ("mprotect", &[Ptr, Int, Hex]),
+        SYS_MEMINFO => ("meminfo", &[Ptr, Int]),
+        SYS_ALLOC_AT => ("alloc_at", &[Ptr, Int, Hex]),
+        SYS_SHM_CREATE => ("shm_create", &[Int]),
+        SYS_SHM_ATTACH => ("shm_attach", &[Int, Ptr]),
+        SYS_SHM_RELEASE => ("shm_release", &[Int]),
+        SYS_CLOSE_MAPPING => ("close_mapping", &[Ptr]),
+        SYS_MSYNC => ("msync", &[Ptr, Int, Hex]),
+        SYS_MADVISE => ("madvise", &[Ptr, Int, Int]),
+        SYS_SHM_GET_SIZE => ("shm_get_size", &[Int]),
+
+        SYS_HANDLE_DUP => ("handle_dup", &[Handle, Hex]),
+        SYS_HANDLE_CLOSE => ("handle_close", &[Handle]),
+        SYS_CHECK_RIGHTS => ("check_rights", &[Handle, Hex]),
+
+        SYS_CREATE_PORT => ("create_port", &[Str, Int, Hex]),
+        SYS_SEND_MSG => ("send_msg", &[Handle, Ptr, Int, Hex]),
+        SYS_RECV_MSG => ("recv_msg", &[Handle, Ptr, Int, Int]),
+        SYS_FUTEX_WAIT => ("futex_wait", &[Ptr, Int, Int]),
+        SYS_FUTEX_WAKE => ("futex_wake", &[Ptr, Int]),
+        SYS_PORT_CONNECT => ("port_connect", &[Str]),
+
+        SYS_FB_INFO => ("fb_info", &[Ptr]),
+        SYS_FB_WRITE => ("fb_write", &[Int, Ptr, Int]),
+        SYS_FB_CLEAR => ("fb_clear", &[Hex]),
+        SYS_MOUSE_READ => ("mouse_read", &[Ptr]),
+        SYS_KEYBOARD_READ => ("keyboard_read", &[Ptr, Int]),
+        SYS_TOUCH_READ => ("touch_read", &[Ptr, Int]),
+        SYS_GAMEPAD_READ => ("gamepad_read", &[Ptr, Int]),
+
+        SYS_CLOCK_GET => ("clock_get", &[Int, Ptr]),
+        SYS_SLEEP => ("sleep", &[Int]),
+        SYS_TIMER_CREATE => ("timer_create", &[Int, Hex]),
+        SYS_TIMER_SET => ("timer_set", &[Handle, Ptr, Hex]),
+
+        SYS_OPEN => ("open", &[Str, Hex, Hex]),
+        SYS_READ => ("read", &[Handle, Ptr, Int]),
+        SYS_WRITE => ("write", &[Handle, Ptr, Int]),
+        SYS_SEEK => ("seek", &[Handle, Signed, Int]),
+        SYS_PREAD => ("pread", &[Handle, Ptr, Int, Int]),
+        SYS_PWRITE => ("pwrite", &[Handle, Ptr, Int, Int]),
+        SYS_FLUSH => ("flush", &[Handle]),
+        SYS_TRUNCATE => ("truncate", &[Handle, Int]),
+        SYS_STAT => ("stat", &[Str, Ptr]),
+        SYS_FSTAT => ("fstat", &[Handle, Ptr]),
+        SYS_CHMOD => ("chmod", &[Str, Hex]),
+        SYS_CHOWN => ("chown", &[Str, Int, Int]),
+        SYS_GETDENTS => ("getdents", &[Handle, Ptr, Int]),
+        SYS_MKDIR => ("mkdir", &[Str, Hex]),
+        SYS_RMDIR => ("rmdir", &[Str]),
+        SYS_GETCWD => ("getcwd", &[Ptr, Int]),
+        SYS_CREATE => ("create", &[Str, Hex]),
+        SYS_UNLINK => ("unlink", &[Str]),
+        SYS_RENAME => ("rename", &[Str, Str]),
+        SYS_LINK => ("link", &[Str, Str]),
+        SYS_SYMLINK => ("symlink", &[Str, Str]),
+        SYS_READLINK => ("readlink", &[Str, Ptr, Int]),
+        SYS_REALPATH => ("realpath", &[Str, Ptr, Int]),
+        SYS_MOUNT => ("mount", &[Str, Str, Hex]),
+        SYS_UMOUNT => ("umount", &[Str]),
+        SYS_STATFS => ("statfs", &[Str, Ptr]),
+        SYS_SYNC => ("sync", &[]),
+        SYS_IOCTL => ("ioctl", &[Handle, Hex, Hex]),
+        SYS_FCNTL => ("fcntl", &[Handle, Int, Hex]),
+        SYS_FLOCK => ("flock", &[Handle, Hex]),
+        SYS_ACCESS => ("access", &[Str, Int]),
+        SYS_CHDIR => ("chdir", &[Str]),
+
+        SYS_POLL => ("poll", &[Ptr, Int, Signed]),
+        SYS_EQ_CREATE => ("eq_create", &[Hex]),
+        SYS_EQ_CTL => ("eq_ctl", &[Handle, Int, Handle, Ptr]),
+        SYS_EQ_WAIT => ("eq_wait", &[Handle, Ptr, Int, Signed]),
+        SYS_EVENTFD_CREATE => ("eventfd_create", &[Hex]),
+
+        SYS_SOCKET => ("socket", &[Int, Int, Hex]),
+        SYS_BIND => ("bind", &[Handle, Ptr, Int]),
+        SYS_LISTEN => ("listen", &[Handle, Int]),
+        SYS_ACCEPT => ("accept", &[Handle, Ptr, Int, Hex]),
+        SYS_CONNECT => ("connect", &[Handle, Ptr, Int, Hex]),
+        SYS_SEND => ("send", &[Handle, Ptr, Int, Hex]),
+        SYS_RECV => ("recv", &[Handle, Ptr, Int, Hex]),
+        SYS_SHUTDOWN => ("shutdown", &[Handle, Int]),
+        SYS_SETSOCKOPT => ("setsockopt", &[Handle, Int, Int]),
+        SYS_GETSOCKOPT => ("getsockopt", &[Handle, Int]),
+        SYS_SOCKADDR => ("sockaddr", &[Handle, Int, Ptr, Int]),
+        SYS_SOCK_SENDMSG => ("sock_sendmsg", &[Handle, Ptr, Hex]),
+        SYS_SOCK_RECVMSG => ("sock_recvmsg", &[Handle, Ptr, Hex]),
+        SYS_PEERCRED => ("peercred", &[Handle, Ptr]),
+
+        SYS_SYSINFO => ("sysinfo", &[Ptr, Int]),
+        SYS_REBOOT => ("reboot", &[]),
+        SYS_POWEROFF => ("poweroff", &[]),
+        SYS_CONSOLE_WRITE => ("console_write", &[Ptr, Int]),
+        SYS_CONSOLE_READ => ("console_read", &[Ptr, Int]),
+        SYS_BEEP => ("beep", &[Int, Int]),
+        SYS_CONSOLE_SET_MODE => ("console_set_mode", &[Hex]),
+        SYS_BATCH => ("batch", &[Ptr, Int, Hex]),
+        SYS_DEBUG => ("debug", &[Hex, Ptr, Int]),
+        _ => return None,
+    };
+    Some(sig)
+}
+
+fn write_call(line: &mut Line, num: usize, args: &[usize]) -> fmt::Result {
+    let (name, kinds) = match signature(num) {
+        Some(sig) => sig,
+        None => ("", &[][..]),
+    };
+    match name {
+        "" => write!(line, "syscall_{:#04x}(", num)?,
+        name => write!(line, "{}(", name)?,
+    }
+
+    if kinds.is_empty() {
+        // Formato desconhecido: todos os argumentos em hex
+        for (i, arg) in args.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            write!(line, "{}{:#x}", sep, arg)?;
+        }
+        return line.write_str(")");
+    }
+
+    let mut rest = args;
+    for (i, kind) in kinds.iter().enumerate() {
+        let Some(&arg) = rest.first() else {
+            break;
+        };
+        rest = &rest[1..];
+        if i > 0 {
+            line.write_str(", ")?;
+        }
+        match kind {
+            Arg::Int => write!(line, "{}", arg)?,
+            Arg::Hex => write!(line, "{:#x}", arg)?,
+            Arg::Handle => write!(line, "h{}", arg as u32)?,
+            Arg::Ptr => write!(line, "{:#x}", arg)?,
+            Arg::Signed => write!(line, "{}", arg as isize)?,
+            Arg::Str => {
+                let len = rest.first().copied().unwrap_or(0);
+                rest = rest.get(1..).unwrap_or(&[]);
+                write_str_arg(line, arg, len)?;
+            }
+        }
+    }
+    line.write_str(")")
+}
+
+/// Mostra uma string de entrada (`ptr`, `len`) entre aspas.
+fn write_str_arg(line: &mut Line, ptr: usize, len: usize) -> fmt::Result {
+    if ptr == 0 {
+        return line.write_str("NULL");
+    }
+    // SAFETY: args do tipo Str vêm de &str passados pelos wrappers de
+    // `safe`, ainda vivos no retorno da syscall.
+    let bytes = unsafe { core::slice::from_raw_parts(ptr as *const u8, len.min(MAX_STR)) };
+    match core::str::from_utf8(bytes) {
+        Ok(s) if len > MAX_STR => write!(line, "{:?}...", s),
+        Ok(s) => write!(line, "{:?}", s),
+        Err(_) => write!(line, "{:#x}", ptr),
+    }
+}
+
+fn write_result(line: &mut Line, num: usize, ret: isize) -> fmt::Result {
+    if ret < 0 {
+        return match SysError::from_code(ret) {
+            SysError::Unknown => write!(line, " = {}", ret),
+            err => write!(line, " = {} {:?}", ret, err),
+        };
+    }
+    if returns_handle(num) {
+        write!(line, " = h{}", ret as u32)
+    } else if matches!(num, SYS_ALLOC | SYS_MAP | SYS_SHM_ATTACH) {
+        write!(line, " = {:#x}", ret)
+    } else {
+        write!(line, " = {}", ret)
+    }
+}
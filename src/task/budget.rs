@@ -0,0 +1,124 @@
+//! # Budget
+//!
+//! Cessão cooperativa para trabalho longo (indexação, decodificação de
+//! imagens) em serviços single-thread: a cada fatia de tempo esgotada, o
+//! processo cede a CPU para que o resto do sistema não fique sem rodar em
+//! máquinas de um núcleo.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::task::{yield_budget, Budget};
+//!
+//! let mut budget = Budget::default();
+//! for file in files {
+//!     index(file)?;
+//!     yield_budget(&mut budget)?;
+//! }
+//! ```
+//!
+//! Dentro do executor, [`yield_budget_async`] também deixa as outras
+//! tarefas e o reactor avançarem.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use crate::process;
+use crate::syscall::SysResult;
+use crate::time::precise_ns;
+
+use super::reactor;
+
+/// Fatia padrão entre cessões.
+pub const DEFAULT_SLICE: Duration = Duration::from_millis(4);
+
+/// Fatia de tempo de CPU entre cessões.
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    slice_ns: u64,
+    /// Início da fatia atual (`precise_ns`).
+    started: u64,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::new(DEFAULT_SLICE)
+    }
+}
+
+impl Budget {
+    /// Budget que cede a cada `slice`, começando agora.
+    pub fn new(slice: Duration) -> Self {
+        Self {
+            slice_ns: slice.as_nanos().min(u64::MAX as u128) as u64,
+            started: precise_ns(),
+        }
+    }
+
+    /// Fatia configurada.
+    pub fn slice(&self) -> Duration {
+        Duration::from_nanos(self.slice_ns)
+    }
+
+    /// Tempo restante na fatia atual.
+    pub fn remaining(&self) -> Duration {
+        let used = precise_ns().saturating_sub(self.started);
+        Duration::from_nanos(self.slice_ns.saturating_sub(used))
+    }
+
+    /// A fatia atual acabou.
+    pub fn is_exhausted(&self) -> bool {
+        precise_ns().saturating_sub(self.started) >= self.slice_ns
+    }
+
+    /// Começa uma nova fatia.
+    pub fn reset(&mut self) {
+        self.started = precise_ns();
+    }
+}
+
+/// Cede a CPU (`yield_now`) se a fatia acabou; retorna se cedeu.
+pub fn yield_budget(budget: &mut Budget) -> SysResult<bool> {
+    if !budget.is_exhausted() {
+        return Ok(false);
+    }
+    process::yield_now()?;
+    budget.reset();
+    Ok(true)
+}
+
+/// [`yield_budget`] para tarefas: além de ceder a CPU, volta ao fim da
+/// fila do executor e processa eventos prontos do reactor.
+pub async fn yield_budget_async(budget: &mut Budget) -> SysResult<bool> {
+    if !budget.is_exhausted() {
+        return Ok(false);
+    }
+    process::yield_now()?;
+    YieldNow(false).await;
+    budget.reset();
+    Ok(true)
+}
+
+/// Fica pendente uma vez, reagendando a tarefa.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+
+        // Sem isso, tarefas esperando I/O só acordariam quando a fila de
+        // prontas esvaziasse
+        if reactor::has_waiters() {
+            let _ = reactor::turn(0, None);
+        }
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
@@ -0,0 +1,302 @@
+//! # Executor
+//!
+//! Executor single-thread de futures, dirigido pelo reactor (`SYS_POLL`).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::net::TcpListener;
+//! use redpowder::task::Executor;
+//!
+//! let mut executor = Executor::new();
+//! let spawner = executor.spawner();
+//!
+//! executor.block_on(async move {
+//!     let listener = TcpListener::bind(addr)?;
+//!     loop {
+//!         let (stream, _) = listener.accept_async().await?;
+//!         spawner.spawn(async move { handle(stream).await });
+//!     }
+//! });
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use crate::event::EventFd;
+
+use super::reactor;
+use super::Lock;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// ID reservado para a future de `block_on`.
+const MAIN: usize = usize::MAX;
+
+/// Estado compartilhado entre executor, wakers e spawners.
+struct Shared {
+    /// Tarefas acordadas, na ordem.
+    ready: Lock<VecDeque<usize>>,
+    /// Tarefas criadas ainda não adotadas pelo executor.
+    spawned: Lock<Vec<BoxFuture>>,
+    /// Acorda o executor bloqueado no reactor (wakers de outras threads).
+    /// `None` se o kernel não oferece `EventFd`.
+    wakeup: Option<EventFd>,
+    /// Executor bloqueado (ou prestes a bloquear) em `park`.
+    parked: AtomicBool,
+}
+
+impl Shared {
+    /// Acorda o executor se estiver bloqueado.
+    fn notify(&self) {
+        if self.parked.load(Ordering::SeqCst) {
+            if let Some(wakeup) = &self.wakeup {
+                let _ = wakeup.signal();
+            }
+        }
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    shared: Arc<Shared>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let id = self.id;
+        self.shared.ready.with(|q| q.push_back(id));
+        self.shared.notify();
+    }
+}
+
+// =============================================================================
+// EXECUTOR
+// =============================================================================
+
+/// Executor de tarefas assíncronas.
+pub struct Executor {
+    shared: Arc<Shared>,
+    tasks: Vec<Option<BoxFuture>>,
+    free: Vec<usize>,
+}
+
+impl Executor {
+    /// Cria executor vazio.
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                ready: Lock::new(VecDeque::new()),
+                spawned: Lock::new(Vec::new()),
+                wakeup: EventFd::new().ok(),
+                parked: AtomicBool::new(false),
+            }),
+            tasks: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Handle para criar tarefas de dentro de outras tarefas.
+    pub fn spawner(&self) -> Spawner {
+        Spawner {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Cria tarefa (executada durante `run`/`block_on`).
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.spawner().spawn(future)
+    }
+
+    /// Executa até `future` completar (tarefas criadas também avançam).
+    pub fn block_on<F: Future>(&mut self, future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(TaskWaker {
+            id: MAIN,
+            shared: self.shared.clone(),
+        }));
+        let mut cx = Context::from_waker(&waker);
+
+        self.shared.ready.with(|q| q.push_back(MAIN));
+        loop {
+            self.adopt_spawned();
+            while let Some(id) = self.shared.ready.with(|q| q.pop_front()) {
+                if id == MAIN {
+                    if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                        return output;
+                    }
+                } else {
+                    self.poll_task(id);
+                }
+                self.adopt_spawned();
+            }
+            self.park();
+        }
+    }
+
+    /// Executa até todas as tarefas completarem.
+    pub fn run(&mut self) {
+        loop {
+            self.adopt_spawned();
+            while let Some(id) = self.shared.ready.with(|q| q.pop_front()) {
+                self.poll_task(id);
+                self.adopt_spawned();
+            }
+            if self.tasks.iter().all(Option::is_none) {
+                return;
+            }
+            self.park();
+        }
+    }
+
+    /// Move tarefas recém-criadas para a tabela e as marca como prontas.
+    fn adopt_spawned(&mut self) {
+        let spawned = self.shared.spawned.with(core::mem::take);
+        for task in spawned {
+            let id = match self.free.pop() {
+                Some(id) => {
+                    self.tasks[id] = Some(task);
+                    id
+                }
+                None => {
+                    self.tasks.push(Some(task));
+                    self.tasks.len() - 1
+                }
+            };
+            self.shared.ready.with(|q| q.push_back(id));
+        }
+    }
+
+    fn poll_task(&mut self, id: usize) {
+        let Some(task) = self.tasks.get_mut(id).and_then(Option::as_mut) else {
+            return;
+        };
+        let waker = Waker::from(Arc::new(TaskWaker {
+            id,
+            shared: self.shared.clone(),
+        }));
+        if task
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_ready()
+        {
+            self.tasks[id] = None;
+            self.free.push(id);
+        }
+    }
+
+    /// Bloqueia até algo ficar pronto.
+    fn park(&self) {
+        let shared = &self.shared;
+        // Marcar antes de checar as filas: um wake concorrente ou aparece
+        // nelas ou vê `parked` e sinaliza o `wakeup`
+        shared.parked.store(true, Ordering::SeqCst);
+        let idle = shared.ready.with(|q| q.is_empty()) && shared.spawned.with(|s| s.is_empty());
+        if idle {
+            match &shared.wakeup {
+                Some(wakeup) => {
+                    let _ = reactor::turn(-1, Some(wakeup.handle()));
+                    let _ = wakeup.reset();
+                }
+                None if reactor::has_waiters() => {
+                    let _ = reactor::turn(-1, None);
+                }
+                // Nada registrado no reactor: ceder a CPU e tentar de novo
+                None => {
+                    let _ = crate::process::yield_now();
+                }
+            }
+        }
+        shared.parked.store(false, Ordering::SeqCst);
+    }
+}
+
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Executa a future até completar, em um executor novo.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    Executor::new().block_on(future)
+}
+
+// =============================================================================
+// SPAWNER / JOIN HANDLE
+// =============================================================================
+
+/// Handle clonável para criar tarefas no executor.
+#[derive(Clone)]
+pub struct Spawner {
+    shared: Arc<Shared>,
+}
+
+impl Spawner {
+    /// Cria tarefa, retornando handle para aguardar o resultado.
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let state = Arc::new(Lock::new(JoinState {
+            result: None,
+            waker: None,
+        }));
+        let task_state = state.clone();
+        let task = async move {
+            let output = future.await;
+            let waker = task_state.with(|s| {
+                s.result = Some(output);
+                s.waker.take()
+            });
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        };
+        self.shared.spawned.with(|s| s.push(Box::pin(task)));
+        self.shared.notify();
+        JoinHandle { state }
+    }
+}
+
+struct JoinState<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// Resultado de uma tarefa criada (pode ser descartado).
+pub struct JoinHandle<T> {
+    state: Arc<Lock<JoinState<T>>>,
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        self.state.with(|s| match s.result.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                s.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+}
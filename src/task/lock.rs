@@ -0,0 +1,44 @@
+//! # Lock
+//!
+//! Spinlock mínimo para o estado compartilhado do executor.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+pub(crate) struct Lock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Sync for Lock<T> {}
+unsafe impl<T: Send> Send for Lock<T> {}
+
+impl<T> Lock<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Executa `f` com acesso exclusivo ao valor.
+    ///
+    /// `f` não deve acordar wakers nem reentrar no lock.
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        // Um panic em `f` dentro de `catch_unwind` deixaria o lock preso
+        #[cfg(feature = "unwind")]
+        crate::runtime::panic::lock_acquired();
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Ordering::Release);
+        #[cfg(feature = "unwind")]
+        crate::runtime::panic::lock_released();
+        result
+    }
+}
@@ -0,0 +1,27 @@
+//! # Tasks
+//!
+//! Executor assíncrono single-thread e reactor sobre `SYS_POLL`, para
+//! servidores e clientes sem uma thread por conexão.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | `executor` | [`Executor`], [`block_on`], [`Spawner`], [`JoinHandle`] |
+//! | `budget` | [`yield_budget`]: cessão cooperativa em trabalho longo |
+//! | `reactor` | [`ready`]: espera de prontidão de handles |
+
+mod budget;
+mod executor;
+mod lock;
+mod reactor;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use budget::{yield_budget, yield_budget_async, Budget, DEFAULT_SLICE};
+pub use executor::{block_on, Executor, JoinHandle, Spawner};
+pub use reactor::{ready, Ready};
+
+pub(crate) use lock::Lock;
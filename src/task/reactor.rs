@@ -0,0 +1,154 @@
+//! # Reactor
+//!
+//! Espera de prontidão de handles via `SYS_POLL`.
+//!
+//! Futures registram interesse com [`ready`]; o executor chama [`turn`]
+//! quando não há tarefas prontas, bloqueando no kernel até algum handle
+//! registrado ficar pronto e acordando as tarefas correspondentes.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use crate::event::{events, poll, PollFd};
+use crate::io::Handle;
+use crate::syscall::SysResult;
+
+use super::Lock;
+
+/// Interesse registrado por uma future pendente.
+struct Registration {
+    id: u64,
+    handle: u32,
+    events: u16,
+    waker: Waker,
+}
+
+static REGISTRATIONS: Lock<Vec<Registration>> = Lock::new(Vec::new());
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+// =============================================================================
+// FUTURE DE PRONTIDÃO
+// =============================================================================
+
+/// Future que completa quando o handle tem algum dos `events`.
+///
+/// Resolve com os eventos ocorridos (inclui `ERR`/`HUP`, sempre reportados).
+pub struct Ready {
+    id: u64,
+    handle: u32,
+    events: u16,
+}
+
+/// Espera o handle ficar pronto (`events::IN`, `events::OUT`...).
+pub fn ready(handle: &Handle, events: u16) -> Ready {
+    Ready {
+        id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        handle: handle.raw(),
+        events,
+    }
+}
+
+impl Future for Ready {
+    type Output = SysResult<u16>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut fds = [PollFd {
+            handle: self.handle,
+            events: self.events,
+            revents: 0,
+        }];
+        match poll(&mut fds, 0) {
+            Err(e) => return Poll::Ready(Err(e)),
+            Ok(n) if n > 0 && fds[0].revents != 0 => {
+                unregister(self.id);
+                return Poll::Ready(Ok(fds[0].revents));
+            }
+            Ok(_) => {}
+        }
+
+        let (id, handle, events) = (self.id, self.handle, self.events);
+        let waker = cx.waker().clone();
+        REGISTRATIONS.with(|regs| match regs.iter_mut().find(|r| r.id == id) {
+            Some(reg) => reg.waker = waker,
+            None => regs.push(Registration {
+                id,
+                handle,
+                events,
+                waker,
+            }),
+        });
+        Poll::Pending
+    }
+}
+
+impl Drop for Ready {
+    fn drop(&mut self) {
+        unregister(self.id);
+    }
+}
+
+fn unregister(id: u64) {
+    REGISTRATIONS.with(|regs| regs.retain(|r| r.id != id));
+}
+
+// =============================================================================
+// TURN
+// =============================================================================
+
+/// Retorna se há futures esperando handles.
+pub(crate) fn has_waiters() -> bool {
+    REGISTRATIONS.with(|regs| !regs.is_empty())
+}
+
+/// Espera até `timeout_ms` (-1 = infinito) e acorda as futures prontas.
+///
+/// `wakeup` (o `EventFd` do executor) também encerra a espera, para
+/// wakers chamados de outras threads.
+///
+/// Retorna quantas futures foram acordadas.
+pub(crate) fn turn(timeout_ms: i64, wakeup: Option<&Handle>) -> SysResult<usize> {
+    let mut fds: Vec<PollFd> = REGISTRATIONS.with(|regs| {
+        regs.iter()
+            .map(|r| PollFd {
+                handle: r.handle,
+                events: r.events,
+                revents: 0,
+            })
+            .chain(wakeup.map(|handle| PollFd::new(handle, events::IN)))
+            .collect()
+    });
+    if fds.is_empty() {
+        return Ok(0);
+    }
+
+    if poll(&mut fds, timeout_ms)? == 0 {
+        return Ok(0);
+    }
+
+    // Retirar os registros prontos e acordar fora do lock
+    let mut woken: Vec<Waker> = Vec::new();
+    REGISTRATIONS.with(|regs| {
+        regs.retain(|reg| {
+            let fired = fds
+                .iter()
+                .any(|fd| fd.handle == reg.handle && fd.revents & (reg.events | ALWAYS) != 0);
+            if fired {
+                woken.push(reg.waker.clone());
+            }
+            !fired
+        })
+    });
+    let count = woken.len();
+    for waker in woken {
+        waker.wake();
+    }
+    Ok(count)
+}
+
+/// Eventos reportados mesmo sem interesse explícito.
+const ALWAYS: u16 = events::ERR | events::HUP | events::NVAL;
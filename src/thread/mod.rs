@@ -0,0 +1,18 @@
+//! # Threads
+//!
+//! Threads do processo atual (`SYS_THREAD_CREATE`).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::thread;
+//!
+//! let render = thread::Builder::new()
+//!     .name("firefly-render")
+//!     .spawn(|| draw_frames())?;
+//! render.join();
+//! ```
+
+mod spawn;
+
+pub use spawn::*;
@@ -0,0 +1,272 @@
+//! # Spawn
+//!
+//! Criação e espera de threads.
+//!
+//! A pilha de cada thread vem de [`mem::alloc`](crate::mem::alloc) com
+//! página de guarda. Ao terminar, a thread marca o fim e sai sem voltar a
+//! tocar na pilha ([`sys_thread_exit_wake`]); a partir daí
+//! [`JoinHandle::join`] a libera. Pilhas de threads cujo handle foi
+//! descartado são liberadas pelo próximo [`spawn`] depois que elas saem.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::mem::{self, flags};
+use crate::process::{set_thread_name, MAX_TASK_NAME_LEN};
+use crate::syscall::safe::{sys_futex_wait, sys_thread_create, sys_thread_exit_wake};
+use crate::syscall::{SysError, SysResult};
+use crate::task::Lock;
+
+/// Pilha padrão de uma thread (bytes).
+pub const DEFAULT_STACK_SIZE: usize = 64 * 1024;
+
+// =============================================================================
+// BUILDER
+// =============================================================================
+
+/// Configuração de uma nova thread.
+#[derive(Debug)]
+pub struct Builder {
+    name: Option<String>,
+    stack_size: usize,
+}
+
+impl Builder {
+    /// Thread sem nome com pilha de [`DEFAULT_STACK_SIZE`].
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            stack_size: DEFAULT_STACK_SIZE,
+        }
+    }
+
+    /// Nome da thread (ex.: `"firefly-render"`), definido pela própria
+    /// thread ao iniciar e exibido no gerenciador de tarefas, no tracer e
+    /// em relatórios de crash.
+    ///
+    /// Nomes vazios ou com mais de [`MAX_TASK_NAME_LEN`] bytes fazem
+    /// [`spawn`](Self::spawn) falhar com `InvalidArgument`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    /// Tamanho da pilha em bytes.
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = size;
+        self
+    }
+
+    /// Cria a thread executando `f`.
+    pub fn spawn<F, T>(self, f: F) -> SysResult<JoinHandle<T>>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        if let Some(name) = &self.name {
+            if name.is_empty() || name.len() > MAX_TASK_NAME_LEN {
+                return Err(SysError::InvalidArgument);
+            }
+        }
+        if self.stack_size < 16 {
+            return Err(SysError::InvalidArgument);
+        }
+        reap_detached();
+
+        let packet = Arc::new(Packet {
+            result: UnsafeCell::new(None),
+        });
+        let done = Arc::new(AtomicU32::new(0));
+        let their_packet = packet.clone();
+        let start = Box::new(Start {
+            name: self.name,
+            main: Box::new(move || {
+                let result = f();
+                unsafe { *their_packet.result.get() = Some(result) };
+            }),
+            done: Arc::as_ptr(&done),
+        });
+
+        let stack = Stack::alloc(self.stack_size)?;
+        let arg = Box::into_raw(start);
+        // SAFETY: a pilha é nova e só volta a ser usada depois que a thread
+        // publica done; arg é retomado por thread_start.
+        match unsafe { sys_thread_create(thread_start, stack.top(), arg as usize) } {
+            Ok(id) => Ok(JoinHandle {
+                id,
+                packet,
+                done,
+                stack: Some(stack),
+            }),
+            Err(err) => {
+                // SAFETY: a thread não foi criada; arg e a pilha são nossos.
+                unsafe {
+                    drop(Box::from_raw(arg));
+                    stack.free();
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cria uma thread sem nome executando `f` (ver [`Builder`]).
+///
+/// A pilha é liberada em [`JoinHandle::join`] ou, se o handle for
+/// descartado, num `spawn` posterior ao fim da thread.
+pub fn spawn<F, T>(f: F) -> SysResult<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn(f)
+}
+
+// =============================================================================
+// JOIN HANDLE
+// =============================================================================
+
+/// Resultado de `f`, escrito pela thread antes de publicar o fim.
+struct Packet<T> {
+    result: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: result é escrito só pela thread, antes de done = 1, e lido só
+// pelo JoinHandle, depois de ver done = 1.
+unsafe impl<T: Send> Sync for Packet<T> {}
+
+/// Dono de uma thread criada por [`spawn`] ou [`Builder::spawn`].
+///
+/// Descartar o handle não encerra a thread.
+pub struct JoinHandle<T> {
+    id: usize,
+    packet: Arc<Packet<T>>,
+    /// 0 = executando, 1 = pilha livre (palavra do futex).
+    done: Arc<AtomicU32>,
+    stack: Option<Stack>,
+}
+
+impl<T> JoinHandle<T> {
+    /// TID da thread.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Se a thread já terminou.
+    pub fn is_finished(&self) -> bool {
+        self.done.load(Ordering::Acquire) == 1
+    }
+
+    /// Espera a thread terminar, libera a pilha e retorna o valor de `f`.
+    pub fn join(mut self) -> T {
+        while !self.is_finished() {
+            let _ = sys_futex_wait(&self.done, 0, 0);
+        }
+        if let Some(stack) = self.stack.take() {
+            // SAFETY: done = 1, a thread não usa mais a pilha.
+            unsafe { stack.free() };
+        }
+        // SAFETY: done = 1, a thread não toca mais em result.
+        unsafe { (*self.packet.result.get()).take() }.expect("resultado da thread")
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        let Some(stack) = self.stack.take() else {
+            return;
+        };
+        if self.is_finished() {
+            // SAFETY: done = 1, a thread não usa mais a pilha.
+            unsafe { stack.free() };
+        } else {
+            DETACHED.with(|list| list.push((self.done.clone(), stack)));
+        }
+    }
+}
+
+// =============================================================================
+// PILHAS
+// =============================================================================
+
+/// Pilha de uma thread.
+struct Stack {
+    base: *mut u8,
+    size: usize,
+}
+
+// SAFETY: a região não é compartilhada; só o dono atual a libera.
+unsafe impl Send for Stack {}
+
+impl Stack {
+    fn alloc(size: usize) -> SysResult<Self> {
+        let base = mem::alloc(size, flags::GUARD)?;
+        Ok(Self { base, size })
+    }
+
+    /// Topo alinhado a 16 bytes.
+    fn top(&self) -> *mut u8 {
+        ((self.base as usize + self.size) & !15) as *mut u8
+    }
+
+    /// # Safety
+    /// Nenhuma thread pode estar usando a pilha.
+    unsafe fn free(self) {
+        let _ = mem::free(self.base, self.size);
+    }
+}
+
+/// Pilhas de threads cujo handle foi descartado antes do fim.
+static DETACHED: Lock<Vec<(Arc<AtomicU32>, Stack)>> = Lock::new(Vec::new());
+
+/// Libera as pilhas de threads descartadas que já terminaram.
+fn reap_detached() {
+    DETACHED.with(|list| {
+        let mut i = 0;
+        while i < list.len() {
+            if list[i].0.load(Ordering::Acquire) == 1 {
+                let (_, stack) = list.swap_remove(i);
+                // SAFETY: done = 1, a thread não usa mais a pilha.
+                unsafe { stack.free() };
+            } else {
+                i += 1;
+            }
+        }
+    });
+}
+
+// =============================================================================
+// ENTRADA DA THREAD
+// =============================================================================
+
+/// O que a nova thread recebe em `arg`.
+struct Start {
+    name: Option<String>,
+    main: Box<dyn FnOnce() + Send>,
+    /// Mantido vivo pelo `JoinHandle` (ou por `DETACHED`) até valer 1.
+    done: *const AtomicU32,
+}
+
+extern "C" fn thread_start(arg: usize) -> ! {
+    // SAFETY: arg veio de Box::into_raw em Builder::spawn.
+    let Start { name, main, done } = *unsafe { Box::from_raw(arg as *mut Start) };
+    if let Some(name) = name {
+        let _ = set_thread_name(&name);
+    }
+    main();
+    // SAFETY: done vive até ser visto valendo 1; dali em diante a pilha e
+    // done podem ser liberados.
+    unsafe { sys_thread_exit_wake(done, 1, 0) }
+}
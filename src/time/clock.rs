@@ -0,0 +1,117 @@
+//! # Clock
+//!
+//! Fonte de tempo injetável, para testar lógica dependente de tempo
+//! (animações, timeouts) de forma instantânea e determinística.
+//!
+//! Código de produção usa [`SystemClock`]; testes passam um [`MockClock`],
+//! cujo `sleep_until` apenas avança o relógio.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use redpowder::graphics::anim::Ticker;
+//! use redpowder::time::{Clock, MockClock};
+//!
+//! let clock = MockClock::new();
+//! let mut ticker = Ticker::new(60_000);
+//! let dt = ticker.wait_with(&clock)?; // não dorme
+//! assert_eq!(clock.now()?.since_boot(), ticker.interval());
+//! ```
+
+use core::cell::Cell;
+use core::time::Duration;
+
+use super::instant::{sleep_until, Instant};
+use crate::syscall::SysResult;
+
+/// Fonte de tempo monotônico.
+pub trait Clock {
+    /// Instante atual.
+    fn now(&self) -> SysResult<Instant>;
+
+    /// Dorme até `deadline` (retorna imediatamente se já passou).
+    fn sleep_until(&self, deadline: Instant) -> SysResult<()>;
+
+    /// Dorme por `d` (até [`Instant::MAX`] se o prazo estourar).
+    fn sleep_for(&self, d: Duration) -> SysResult<()> {
+        let deadline = self.now()?.checked_add(d).unwrap_or(Instant::MAX);
+        self.sleep_until(deadline)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> SysResult<Instant> {
+        (**self).now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> SysResult<()> {
+        (**self).sleep_until(deadline)
+    }
+}
+
+// =============================================================================
+// SYSTEM CLOCK
+// =============================================================================
+
+/// Clock monotônico do kernel.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SysResult<Instant> {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> SysResult<()> {
+        sleep_until(deadline)
+    }
+}
+
+// =============================================================================
+// MOCK CLOCK
+// =============================================================================
+
+/// Clock manual para testes: só anda com [`advance`](Self::advance),
+/// [`set`](Self::set) ou `sleep_until`.
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: Cell<Duration>,
+}
+
+impl MockClock {
+    /// Clock parado no boot (instante zero).
+    pub const fn new() -> Self {
+        Self::starting_at(Duration::ZERO)
+    }
+
+    /// Clock parado em `since_boot`.
+    pub const fn starting_at(since_boot: Duration) -> Self {
+        Self {
+            now: Cell::new(since_boot),
+        }
+    }
+
+    /// Avança o clock (parando em [`Instant::MAX`]).
+    pub fn advance(&self, d: Duration) {
+        let now = Instant::from_since_boot(self.now.get());
+        self.now
+            .set(now.checked_add(d).unwrap_or(Instant::MAX).since_boot());
+    }
+
+    /// Move o clock para `instant` (nunca para trás).
+    pub fn set(&self, instant: Instant) {
+        self.now.set(self.now.get().max(instant.since_boot()));
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SysResult<Instant> {
+        Ok(Instant::from_since_boot(self.now.get()))
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> SysResult<()> {
+        self.set(deadline);
+        Ok(())
+    }
+}
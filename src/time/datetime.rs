@@ -0,0 +1,370 @@
+//! # Date/Time
+//!
+//! Tempo de parede (`SystemTime`), data decomposta (`DateTime`, UTC) e
+//! formatação/parsing RFC 3339.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::time::{DateTime, SystemTime};
+//!
+//! let now = SystemTime::now()?.to_datetime();
+//! println!("{}", now); // 2026-10-16T12:34:56Z
+//!
+//! let dt = DateTime::parse_rfc3339("2026-10-16T09:00:00-03:00").unwrap();
+//! assert_eq!(dt.hour, 12);
+//! ```
+
+use core::fmt;
+use core::time::Duration;
+
+use super::time::{clock_get, ClockId};
+use crate::syscall::SysResult;
+
+// =============================================================================
+// SYSTEM TIME
+// =============================================================================
+
+/// Instante de tempo de parede (segundos + nanos desde a época Unix, UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemTime {
+    secs: i64,
+    nanos: u32,
+}
+
+impl SystemTime {
+    /// 1970-01-01T00:00:00Z.
+    pub const UNIX_EPOCH: Self = Self { secs: 0, nanos: 0 };
+
+    /// Tempo atual (`ClockId::Realtime`).
+    pub fn now() -> SysResult<Self> {
+        let ts = clock_get(ClockId::Realtime)?;
+        Ok(Self {
+            secs: ts.seconds as i64,
+            nanos: ts.nanoseconds % 1_000_000_000,
+        })
+    }
+
+    /// Cria a partir de segundos (e nanos) desde a época.
+    ///
+    /// `nanos` acima de um segundo é somado a `secs`, saturando em
+    /// `i64::MAX` segundos.
+    pub const fn from_unix(secs: i64, nanos: u32) -> Self {
+        Self {
+            secs: secs.saturating_add((nanos / 1_000_000_000) as i64),
+            nanos: nanos % 1_000_000_000,
+        }
+    }
+
+    /// Cria a partir de milissegundos desde a época (formato de `FileStat`).
+    pub const fn from_unix_millis(ms: u64) -> Self {
+        Self {
+            secs: (ms / 1000) as i64,
+            nanos: (ms % 1000) as u32 * 1_000_000,
+        }
+    }
+
+    /// Segundos desde a época.
+    #[inline]
+    pub const fn unix_secs(&self) -> i64 {
+        self.secs
+    }
+
+    /// Fração de segundo em nanossegundos.
+    #[inline]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Tempo decorrido desde `earlier` (None se `earlier` for posterior).
+    pub fn duration_since(&self, earlier: SystemTime) -> Option<Duration> {
+        if *self < earlier {
+            return None;
+        }
+        let (secs, nanos) = if self.nanos >= earlier.nanos {
+            (self.secs - earlier.secs, self.nanos - earlier.nanos)
+        } else {
+            (
+                self.secs - earlier.secs - 1,
+                self.nanos + 1_000_000_000 - earlier.nanos,
+            )
+        };
+        Some(Duration::new(secs as u64, nanos))
+    }
+
+    /// Soma uma duração.
+    pub fn checked_add(&self, d: Duration) -> Option<Self> {
+        let mut secs = self.secs.checked_add(i64::try_from(d.as_secs()).ok()?)?;
+        let mut nanos = self.nanos + d.subsec_nanos();
+        if nanos >= 1_000_000_000 {
+            secs = secs.checked_add(1)?;
+            nanos -= 1_000_000_000;
+        }
+        Some(Self { secs, nanos })
+    }
+
+    /// Converte para data/hora UTC.
+    pub fn to_datetime(&self) -> DateTime {
+        DateTime::from_unix(self.secs, self.nanos)
+    }
+}
+
+impl From<DateTime> for SystemTime {
+    fn from(dt: DateTime) -> Self {
+        Self::from_unix(dt.to_unix(), dt.nanosecond)
+    }
+}
+
+// =============================================================================
+// DATE TIME
+// =============================================================================
+
+/// Data e hora decompostas (calendário gregoriano, UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub year: i32,
+    /// 1–12.
+    pub month: u8,
+    /// 1–31.
+    pub day: u8,
+    /// 0–23.
+    pub hour: u8,
+    /// 0–59.
+    pub minute: u8,
+    /// 0–59.
+    pub second: u8,
+    /// 0–999_999_999.
+    pub nanosecond: u32,
+}
+
+impl DateTime {
+    /// Menor data representável (1º de janeiro do ano `i32::MIN`).
+    pub const MIN: Self = Self {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+    };
+
+    /// Maior data representável (31 de dezembro do ano `i32::MAX`).
+    pub const MAX: Self = Self {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+        hour: 23,
+        minute: 59,
+        second: 59,
+        nanosecond: 999_999_999,
+    };
+
+    /// Cria data/hora, validando os campos.
+    pub fn new(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        let valid = (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+            && hour < 24
+            && minute < 60
+            && second < 60;
+        valid.then_some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond: 0,
+        })
+    }
+
+    /// Converte segundos desde a época em data/hora.
+    ///
+    /// Instantes fora de [`MIN`](Self::MIN)..=[`MAX`](Self::MAX) (anos fora de
+    /// `i32`) saturam nesses limites; ver [`checked_from_unix`](Self::checked_from_unix).
+    pub fn from_unix(secs: i64, nanos: u32) -> Self {
+        match Self::checked_from_unix(secs, nanos) {
+            Some(dt) => dt,
+            None if secs < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Converte segundos desde a época em data/hora (None se o ano não couber
+    /// em `i32`).
+    pub fn checked_from_unix(secs: i64, nanos: u32) -> Option<Self> {
+        let days = secs.div_euclid(86_400);
+        let rem = secs.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days)?;
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: (rem / 3600) as u8,
+            minute: (rem % 3600 / 60) as u8,
+            second: (rem % 60) as u8,
+            nanosecond: nanos,
+        })
+    }
+
+    /// Segundos desde a época.
+    pub fn to_unix(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * 86_400
+            + self.hour as i64 * 3600
+            + self.minute as i64 * 60
+            + self.second as i64
+    }
+
+    /// Dia da semana (0 = segunda ... 6 = domingo).
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 foi uma quinta-feira
+        (days_from_civil(self.year, self.month, self.day) + 3).rem_euclid(7) as u8
+    }
+
+    /// Dia do ano (1–366).
+    pub fn ordinal(&self) -> u16 {
+        (days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1)
+            as u16
+    }
+
+    /// Faz parsing de RFC 3339 (`YYYY-MM-DDTHH:MM:SS[.frac](Z|±HH:MM)`),
+    /// convertendo o offset para UTC.
+    pub fn parse_rfc3339(s: &str) -> Option<Self> {
+        let b = s.as_bytes();
+        if b.len() < 20 || b[4] != b'-' || b[7] != b'-' || b[13] != b':' || b[16] != b':' {
+            return None;
+        }
+        if !matches!(b[10], b'T' | b't' | b' ') {
+            return None;
+        }
+
+        let mut dt = Self::new(
+            digits(&b[0..4])? as i32,
+            digits(&b[5..7])? as u8,
+            digits(&b[8..10])? as u8,
+            digits(&b[11..13])? as u8,
+            digits(&b[14..16])? as u8,
+            digits(&b[17..19])? as u8,
+        )?;
+
+        // Fração de segundo (até 9 dígitos significativos)
+        let mut i = 19;
+        if b[i] == b'.' {
+            i += 1;
+            let start = i;
+            let mut nanos = 0u32;
+            while i < b.len() && b[i].is_ascii_digit() {
+                if i - start < 9 {
+                    nanos = nanos * 10 + (b[i] - b'0') as u32;
+                }
+                i += 1;
+            }
+            if i == start {
+                return None;
+            }
+            for _ in (i - start)..9 {
+                nanos *= 10;
+            }
+            dt.nanosecond = nanos;
+        }
+
+        // Offset
+        let offset_secs = match b.get(i)? {
+            b'Z' | b'z' if i + 1 == b.len() => 0,
+            &sign @ (b'+' | b'-') if i + 6 == b.len() && b[i + 3] == b':' => {
+                let hours = digits(&b[i + 1..i + 3])? as i64;
+                let minutes = digits(&b[i + 4..i + 6])? as i64;
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = hours * 3600 + minutes * 60;
+                if sign == b'+' {
+                    offset
+                } else {
+                    -offset
+                }
+            }
+            _ => return None,
+        };
+
+        if offset_secs != 0 {
+            dt = Self::from_unix(dt.to_unix() - offset_secs, dt.nanosecond);
+        }
+        Some(dt)
+    }
+}
+
+/// Formata como RFC 3339 em UTC (`Z`); fração só se `nanosecond != 0`.
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            // Remover zeros à direita da fração
+            let mut frac = self.nanosecond;
+            let mut width = 9;
+            while frac.is_multiple_of(10) {
+                frac /= 10;
+                width -= 1;
+            }
+            write!(f, ".{:0width$}", frac, width = width)?;
+        }
+        f.write_str("Z")
+    }
+}
+
+// =============================================================================
+// CALENDÁRIO
+// =============================================================================
+
+/// Retorna se o ano é bissexto.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Número de dias do mês (0 se o mês for inválido).
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Dias desde 1970-01-01 (algoritmo de Howard Hinnant).
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let y = year as i64 - (month <= 2) as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverso de [`days_from_civil`] (None se o ano não couber em `i32`).
+fn civil_from_days(days: i64) -> Option<(i32, u8, u8)> {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    Some((i32::try_from(year).ok()?, month, day))
+}
+
+/// Converte dígitos ASCII em número.
+fn digits(b: &[u8]) -> Option<u32> {
+    b.iter().try_fold(0u32, |acc, &c| {
+        c.is_ascii_digit().then(|| acc * 10 + (c - b'0') as u32)
+    })
+}
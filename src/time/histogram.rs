@@ -0,0 +1,213 @@
+//! # Histogram
+//!
+//! Histograma de latências com buckets fixos, sem alocação: resume frames,
+//! benchmarks e chamadas IPC em percentis (p50/p95/p99).
+//!
+//! Os buckets são log-lineares: cada potência de 2 é dividida em 4, o que
+//! limita o erro relativo dos percentis a ~25% (valores abaixo de 8 ns são
+//! exatos). Cobre até ~2,4 horas; acima disso tudo cai no último bucket,
+//! mas [`Histogram::max`] continua exato.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::time::{Histogram, Stopwatch};
+//!
+//! let mut ipc = Histogram::new();
+//! for _ in 0..1000 {
+//!     let sw = Stopwatch::start();
+//!     client.ping()?;
+//!     ipc.record(sw.elapsed());
+//! }
+//! println!("p50={:?} p99={:?} max={:?}", ipc.p50(), ipc.p99(), ipc.max());
+//! ```
+
+use core::time::Duration;
+
+/// Subdivisões de cada potência de 2.
+const SUB_BUCKETS: usize = 4;
+/// Grupos de potências de 2 (até 2^43 ns ≈ 2,4 h).
+const MAX_EXPONENT: usize = 42;
+/// Número de buckets.
+pub const HISTOGRAM_BUCKETS: usize = SUB_BUCKETS * MAX_EXPONENT;
+
+/// Histograma de durações (resolução de nanossegundos).
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    buckets: [u32; HISTOGRAM_BUCKETS],
+    count: u64,
+    sum: u64,
+    min: u64,
+    max: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Histogram {
+    /// Histograma vazio.
+    pub const fn new() -> Self {
+        Self {
+            buckets: [0; HISTOGRAM_BUCKETS],
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    // =========================================================================
+    // REGISTRO
+    // =========================================================================
+
+    /// Registra uma amostra.
+    pub fn record(&mut self, value: Duration) {
+        self.record_ns(value.as_nanos().min(u64::MAX as u128) as u64);
+    }
+
+    /// Registra uma amostra em nanossegundos.
+    pub fn record_ns(&mut self, ns: u64) {
+        let bucket = &mut self.buckets[bucket_index(ns)];
+        *bucket = bucket.saturating_add(1);
+        self.count += 1;
+        self.sum = self.sum.saturating_add(ns);
+        self.min = self.min.min(ns);
+        self.max = self.max.max(ns);
+    }
+
+    /// Soma as amostras de `other` (ex.: histogramas por thread).
+    pub fn merge(&mut self, other: &Histogram) {
+        for (dst, src) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *dst = dst.saturating_add(*src);
+        }
+        self.count += other.count;
+        self.sum = self.sum.saturating_add(other.sum);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Descarta todas as amostras.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    // =========================================================================
+    // CONSULTA
+    // =========================================================================
+
+    /// Número de amostras.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Sem amostras.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Menor amostra (zero se vazio).
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(if self.count == 0 { 0 } else { self.min })
+    }
+
+    /// Maior amostra.
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max)
+    }
+
+    /// Média (zero se vazio).
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.sum.checked_div(self.count).unwrap_or(0))
+    }
+
+    /// Percentil `p` (0.0 a 100.0); zero se vazio.
+    ///
+    /// Retorna o limite superior do bucket, restrito a `[min, max]`.
+    pub fn percentile(&self, p: f32) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+        let p = p.clamp(0.0, 100.0) as f64;
+        let exact = p / 100.0 * self.count as f64;
+        let mut rank = exact as u64;
+        if (rank as f64) < exact {
+            rank += 1;
+        }
+        let rank = rank.clamp(1, self.count);
+
+        let mut seen = 0u64;
+        for (index, &n) in self.buckets.iter().enumerate() {
+            seen += n as u64;
+            if seen >= rank {
+                let ns = bucket_upper(index).clamp(self.min, self.max);
+                return Duration::from_nanos(ns);
+            }
+        }
+        self.max()
+    }
+
+    /// Mediana.
+    pub fn p50(&self) -> Duration {
+        self.percentile(50.0)
+    }
+
+    pub fn p95(&self) -> Duration {
+        self.percentile(95.0)
+    }
+
+    pub fn p99(&self) -> Duration {
+        self.percentile(99.0)
+    }
+
+    /// Buckets não vazios: `(limite inferior, limite superior, amostras)`.
+    pub fn buckets(&self) -> impl Iterator<Item = (Duration, Duration, u32)> + '_ {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, &n)| n > 0)
+            .map(|(index, &n)| {
+                (
+                    Duration::from_nanos(bucket_lower(index)),
+                    Duration::from_nanos(bucket_upper(index)),
+                    n,
+                )
+            })
+    }
+}
+
+// =============================================================================
+// BUCKETS
+// =============================================================================
+
+/// Valores `0..8` têm bucket próprio; acima disso, o expoente seleciona o
+/// grupo e os 2 bits seguintes ao mais significativo, o sub-bucket.
+fn bucket_index(ns: u64) -> usize {
+    if ns < 2 * SUB_BUCKETS as u64 {
+        return ns as usize;
+    }
+    let exponent = 63 - ns.leading_zeros() as usize;
+    let sub = ((ns >> (exponent - 2)) & 3) as usize;
+    (SUB_BUCKETS * (exponent - 1) + sub).min(HISTOGRAM_BUCKETS - 1)
+}
+
+fn bucket_lower(index: usize) -> u64 {
+    if index < 2 * SUB_BUCKETS {
+        return index as u64;
+    }
+    let exponent = index / SUB_BUCKETS + 1;
+    let sub = (index % SUB_BUCKETS) as u64;
+    (SUB_BUCKETS as u64 + sub) << (exponent - 2)
+}
+
+fn bucket_upper(index: usize) -> u64 {
+    if index < 2 * SUB_BUCKETS {
+        return index as u64;
+    }
+    let exponent = index / SUB_BUCKETS + 1;
+    bucket_lower(index) + (1 << (exponent - 2)) - 1
+}
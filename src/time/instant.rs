@@ -0,0 +1,140 @@
+//! # Instant
+//!
+//! Instante do clock monotônico e espera por deadline.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use redpowder::time::{sleep_until, Instant};
+//!
+//! let frame = Duration::from_millis(16);
+//! let mut next = Instant::now()?;
+//! loop {
+//!     render();
+//!     next += frame;
+//!     sleep_until(next)?; // sem drift acumulado
+//! }
+//! ```
+
+use core::ops::{Add, AddAssign, Sub};
+use core::time::Duration;
+
+use super::time::{monotonic, sleep};
+use crate::syscall::SysResult;
+
+// =============================================================================
+// INSTANT
+// =============================================================================
+
+/// Instante monotônico (tempo desde o boot).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(Duration);
+
+impl Instant {
+    /// Maior instante representável (destino de prazos que estouram).
+    pub const MAX: Self = Self(Duration::MAX);
+
+    /// Instante atual.
+    pub fn now() -> SysResult<Self> {
+        Ok(Self(monotonic()?.to_duration()))
+    }
+
+    /// Instante a `since_boot` do boot (ex.: timestamps de eventos).
+    #[inline]
+    pub const fn from_since_boot(since_boot: Duration) -> Self {
+        Self(since_boot)
+    }
+
+    /// Tempo desde o boot.
+    #[inline]
+    pub fn since_boot(&self) -> Duration {
+        self.0
+    }
+
+    /// Tempo decorrido desde este instante (zero se estiver no futuro).
+    pub fn elapsed(&self) -> SysResult<Duration> {
+        Ok(Self::now()?.saturating_duration_since(*self))
+    }
+
+    /// `self - earlier`, ou None se `earlier` for posterior.
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// `self - earlier`, ou zero se `earlier` for posterior.
+    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Soma uma duração.
+    pub fn checked_add(&self, d: Duration) -> Option<Self> {
+        self.0.checked_add(d).map(Self)
+    }
+
+    /// Subtrai uma duração.
+    pub fn checked_sub(&self, d: Duration) -> Option<Self> {
+        self.0.checked_sub(d).map(Self)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, d: Duration) -> Instant {
+        Self(self.0 + d)
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, d: Duration) {
+        self.0 += d;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, d: Duration) -> Instant {
+        Self(self.0.saturating_sub(d))
+    }
+}
+
+/// Diferença saturada (zero se `rhs` for posterior).
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, rhs: Instant) -> Duration {
+        self.saturating_duration_since(rhs)
+    }
+}
+
+// =============================================================================
+// SLEEP
+// =============================================================================
+
+/// Dorme até `deadline` (retorna imediatamente se já passou).
+///
+/// Acordadas antecipadas são tratadas dormindo de novo pelo restante.
+pub fn sleep_until(deadline: Instant) -> SysResult<()> {
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now()?);
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        sleep(duration_to_ms_ceil(remaining))?;
+    }
+}
+
+/// Dorme por uma `Duration` (arredondada para cima em ms).
+///
+/// Durações além do maior `Instant` representável dormem até ele.
+pub fn sleep_for(d: Duration) -> SysResult<()> {
+    let deadline = Instant::now()?.checked_add(d).unwrap_or(Instant::MAX);
+    sleep_until(deadline)
+}
+
+fn duration_to_ms_ceil(d: Duration) -> u64 {
+    let ms = d.as_millis() + !d.subsec_nanos().is_multiple_of(1_000_000) as u128;
+    ms.min(u64::MAX as u128) as u64
+}
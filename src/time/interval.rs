@@ -0,0 +1,139 @@
+//! # Interval
+//!
+//! Timer periódico entregue por um handle pollável (estilo timerfd), para
+//! ticks de frame e cursores piscantes.
+//!
+//! O timer é armado no clock monotônico e o kernel agenda cada expiração a
+//! partir do deadline anterior (não do instante em que o app acordou), então
+//! atrasos não acumulam drift: o app apenas recebe mais de um tick de uma vez.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use redpowder::time::Interval;
+//!
+//! let frames = Interval::every(Duration::from_millis(16))?;
+//! loop {
+//!     let ticks = frames.tick()?; // > 1 se frames foram perdidos
+//!     render();
+//! }
+//! ```
+
+use core::time::Duration;
+
+use crate::event::{events, poll, PollFd};
+use crate::io::Handle;
+use crate::syscall::safe::{sys_handle_close, sys_read, sys_timer_create, sys_timer_set};
+use crate::syscall::{SysError, SysResult};
+
+use super::time::{ClockId, TimeSpec};
+
+/// Flags de `SYS_TIMER_SET`.
+pub mod timer_flags {
+    /// `initial` é um instante absoluto (não relativo a agora).
+    pub const ABSTIME: usize = 1 << 0;
+}
+
+/// Configuração de timer passada ao kernel.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimerSpec {
+    /// Primeira expiração.
+    pub initial: TimeSpec,
+    /// Período entre expirações (zero = disparo único).
+    pub interval: TimeSpec,
+}
+
+// =============================================================================
+// INTERVAL
+// =============================================================================
+
+/// Timer periódico.
+pub struct Interval {
+    handle: Handle,
+    period: Duration,
+}
+
+impl Interval {
+    /// Cria timer que expira a cada `period`, a partir de agora + `period`.
+    pub fn every(period: Duration) -> SysResult<Self> {
+        if period.is_zero() {
+            return Err(SysError::InvalidArgument);
+        }
+
+        let handle = sys_timer_create(ClockId::Monotonic)?;
+        let interval = Self { handle, period };
+        interval.reset()?;
+        Ok(interval)
+    }
+
+    /// Período do timer.
+    #[inline]
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Rearma o timer: próxima expiração em agora + `period`.
+    pub fn reset(&self) -> SysResult<()> {
+        let spec = TimerSpec {
+            initial: TimeSpec::from_duration(self.period),
+            interval: TimeSpec::from_duration(self.period),
+        };
+        sys_timer_set(self.handle, &spec, 0)
+    }
+
+    /// Bloqueia até a próxima expiração.
+    ///
+    /// Retorna quantos períodos se passaram desde o último tick lido (≥ 1).
+    pub fn tick(&self) -> SysResult<u64> {
+        loop {
+            let mut fds = [self.poll_fd()];
+            poll(&mut fds, -1)?;
+            match self.try_tick()? {
+                0 => continue,
+                n => return Ok(n),
+            }
+        }
+    }
+
+    /// [`tick`](Self::tick) assíncrono (suspende a tarefa no executor).
+    pub async fn tick_async(&self) -> SysResult<u64> {
+        loop {
+            match self.try_tick()? {
+                0 => crate::task::ready(&self.handle, events::IN).await?,
+                n => return Ok(n),
+            };
+        }
+    }
+
+    /// Lê expirações pendentes sem bloquear (0 se nenhuma).
+    pub fn try_tick(&self) -> SysResult<u64> {
+        let mut fds = [self.poll_fd()];
+        if poll(&mut fds, 0)? == 0 || !fds[0].has_event(events::IN) {
+            return Ok(0);
+        }
+
+        let mut count = [0u8; 8];
+        match sys_read(self.handle, &mut count)? {
+            8 => Ok(u64::from_ne_bytes(count)),
+            _ => Ok(0),
+        }
+    }
+
+    /// Descritor para `event::poll` (pronto para leitura ao expirar).
+    pub fn poll_fd(&self) -> PollFd {
+        PollFd::new(&self.handle, events::IN)
+    }
+
+    /// Handle do timer.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
+
+impl Drop for Interval {
+    fn drop(&mut self) {
+        let _ = sys_handle_close(self.handle);
+    }
+}
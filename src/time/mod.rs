@@ -1,5 +1,19 @@
 //! # Time
 
+mod clock;
+mod datetime;
+mod histogram;
+mod instant;
+mod interval;
+mod precise;
+mod stopwatch;
 mod time;
 
+pub use clock::*;
+pub use datetime::*;
+pub use histogram::*;
+pub use instant::*;
+pub use interval::*;
+pub use precise::*;
+pub use stopwatch::*;
 pub use time::*;
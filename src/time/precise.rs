@@ -0,0 +1,153 @@
+//! # Precise Time
+//!
+//! Timestamp de alta resolução para micro-benchmarks (loops de render etc).
+//!
+//! Usa o TSC do processador quando ele é invariante (CPUID 0x8000_0007,
+//! EDX bit 8): a frequência vem do CPUID 0x15 quando disponível, ou de uma
+//! calibração de ~20 ms contra o clock monotônico na primeira chamada.
+//!
+//! Sem TSC invariante (VMs antigas, CPUs com frequência variável), cai para
+//! o clock monotônico do kernel — mesma escala, resolução do kernel.
+//! [`precise_source`] informa qual fonte está em uso.
+
+use core::arch::x86_64::{__cpuid, _rdtsc};
+use core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use super::time::monotonic;
+
+/// Duração da calibração contra o clock monotônico (ns).
+const CALIBRATION_NS: u64 = 20_000_000;
+
+const STATE_UNCALIBRATED: u8 = 0;
+const STATE_TSC: u8 = 1;
+const STATE_MONOTONIC: u8 = 2;
+
+static STATE: AtomicU8 = AtomicU8::new(STATE_UNCALIBRATED);
+/// TSC e ns (monotônico) no ponto de referência.
+static TSC_BASE: AtomicU64 = AtomicU64::new(0);
+static NS_BASE: AtomicU64 = AtomicU64::new(0);
+/// ns por tick do TSC, ponto fixo Q32.
+static SCALE_Q32: AtomicU64 = AtomicU64::new(0);
+
+/// Fonte do timestamp de alta resolução.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreciseSource {
+    /// TSC invariante calibrado.
+    Tsc,
+    /// Clock monotônico do kernel (fallback).
+    Monotonic,
+}
+
+/// Nanossegundos desde o boot, com a maior resolução disponível.
+///
+/// A primeira chamada pode levar ~20 ms (calibração); use
+/// [`calibrate_precise`] na inicialização para evitar isso no loop.
+pub fn precise_ns() -> u64 {
+    match STATE.load(Ordering::Acquire) {
+        STATE_TSC => tsc_ns(),
+        STATE_MONOTONIC => monotonic_ns(),
+        _ => {
+            calibrate_precise();
+            precise_ns()
+        }
+    }
+}
+
+/// Fonte em uso (calibra se necessário).
+pub fn precise_source() -> PreciseSource {
+    if STATE.load(Ordering::Acquire) == STATE_UNCALIBRATED {
+        calibrate_precise();
+    }
+    match STATE.load(Ordering::Acquire) {
+        STATE_TSC => PreciseSource::Tsc,
+        _ => PreciseSource::Monotonic,
+    }
+}
+
+/// Calibra o TSC (ou escolhe o fallback). Idempotente.
+pub fn calibrate_precise() {
+    if STATE.load(Ordering::Acquire) != STATE_UNCALIBRATED {
+        return;
+    }
+
+    let state = match calibrate_tsc() {
+        Some((tsc_base, ns_base, scale)) => {
+            TSC_BASE.store(tsc_base, Ordering::Relaxed);
+            NS_BASE.store(ns_base, Ordering::Relaxed);
+            SCALE_Q32.store(scale, Ordering::Relaxed);
+            STATE_TSC
+        }
+        None => STATE_MONOTONIC,
+    };
+    STATE.store(state, Ordering::Release);
+}
+
+fn tsc_ns() -> u64 {
+    let ticks = rdtsc().wrapping_sub(TSC_BASE.load(Ordering::Relaxed));
+    let ns = (ticks as u128 * SCALE_Q32.load(Ordering::Relaxed) as u128) >> 32;
+    NS_BASE.load(Ordering::Relaxed) + ns as u64
+}
+
+fn monotonic_ns() -> u64 {
+    monotonic().map_or(0, |ts| ts.to_duration().as_nanos() as u64)
+}
+
+/// Retorna (tsc base, ns base, escala Q32) ou None se o TSC não servir.
+fn calibrate_tsc() -> Option<(u64, u64, u64)> {
+    if !has_invariant_tsc() {
+        return None;
+    }
+
+    // Frequência informada pelo CPUID 0x15 (crystal * ebx / eax)
+    if let Some(hz) = cpuid_tsc_hz() {
+        let (tsc, ns) = aligned_sample()?;
+        let scale = ((1_000_000_000u128 << 32) / hz as u128) as u64;
+        return Some((tsc, ns, scale));
+    }
+
+    // Medir contra o clock monotônico, alinhando às bordas de tick do kernel
+    let (tsc0, ns0) = aligned_sample()?;
+    let (tsc1, ns1) = loop {
+        let (tsc, ns) = aligned_sample()?;
+        if ns.saturating_sub(ns0) >= CALIBRATION_NS {
+            break (tsc, ns);
+        }
+    };
+
+    let ticks = tsc1.checked_sub(tsc0).filter(|&t| t > 0)?;
+    let scale = (((ns1 - ns0) as u128) << 32) / ticks as u128;
+    Some((tsc1, ns1, scale as u64))
+}
+
+/// Espera o clock monotônico avançar e lê TSC junto com o novo valor.
+fn aligned_sample() -> Option<(u64, u64)> {
+    let start = monotonic().ok()?.to_duration().as_nanos() as u64;
+    loop {
+        let tsc = rdtsc();
+        let now = monotonic().ok()?.to_duration().as_nanos() as u64;
+        if now != start {
+            return Some((tsc, now));
+        }
+    }
+}
+
+fn has_invariant_tsc() -> bool {
+    let max_ext = __cpuid(0x8000_0000).eax;
+    max_ext >= 0x8000_0007 && __cpuid(0x8000_0007).edx & (1 << 8) != 0
+}
+
+fn cpuid_tsc_hz() -> Option<u64> {
+    if __cpuid(0).eax < 0x15 {
+        return None;
+    }
+    let leaf = __cpuid(0x15);
+    if leaf.eax == 0 || leaf.ebx == 0 || leaf.ecx == 0 {
+        return None;
+    }
+    Some(leaf.ecx as u64 * leaf.ebx as u64 / leaf.eax as u64)
+}
+
+#[inline]
+fn rdtsc() -> u64 {
+    unsafe { _rdtsc() }
+}
@@ -0,0 +1,102 @@
+//! # Stopwatch
+//!
+//! Cronômetro sobre [`precise_ns`] para medir trechos de código (frames,
+//! requisições IPC) sem syscalls quando o TSC está disponível.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::time::{Histogram, Stopwatch};
+//!
+//! let mut frames = Histogram::new();
+//! let mut sw = Stopwatch::start();
+//! loop {
+//!     render();
+//!     frames.record(sw.lap());
+//! }
+//! ```
+
+use core::time::Duration;
+
+use super::precise::precise_ns;
+
+/// Cronômetro com pausa e voltas.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stopwatch {
+    /// Tempo acumulado antes do trecho atual (ns).
+    accumulated: u64,
+    /// Início do trecho atual, se rodando.
+    started: Option<u64>,
+    /// Tempo total na última volta (ns).
+    last_lap: u64,
+}
+
+impl Stopwatch {
+    /// Cronômetro parado, zerado.
+    pub const fn new() -> Self {
+        Self {
+            accumulated: 0,
+            started: None,
+            last_lap: 0,
+        }
+    }
+
+    /// Cronômetro já rodando.
+    pub fn start() -> Self {
+        let mut sw = Self::new();
+        sw.resume();
+        sw
+    }
+
+    /// Está rodando.
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Tempo total medido.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns())
+    }
+
+    /// Tempo desde a última volta (ou o início); inicia a próxima volta.
+    pub fn lap(&mut self) -> Duration {
+        let now = self.elapsed_ns();
+        let lap = now - self.last_lap;
+        self.last_lap = now;
+        Duration::from_nanos(lap)
+    }
+
+    /// Pausa (o tempo acumulado é mantido).
+    pub fn stop(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.accumulated += precise_ns().saturating_sub(started);
+        }
+    }
+
+    /// Continua após [`stop`](Self::stop) (ou inicia).
+    pub fn resume(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(precise_ns());
+        }
+    }
+
+    /// Zera e para.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Zera e continua rodando; retorna o tempo medido até aqui.
+    pub fn restart(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        *self = Self::start();
+        elapsed
+    }
+
+    fn elapsed_ns(&self) -> u64 {
+        let running = self
+            .started
+            .map_or(0, |started| precise_ns().saturating_sub(started));
+        self.accumulated + running
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Operações de tempo.
 
-use crate::syscall::{check_error, syscall1, syscall2, SysResult};
-use crate::syscall::{SYS_CLOCK_GET, SYS_SLEEP};
+use core::time::Duration;
+
+use crate::syscall::safe::{sys_clock_get, sys_sleep};
+use crate::syscall::SysResult;
 
 /// Tipos de clock
 #[repr(u32)]
@@ -37,24 +39,37 @@ impl TimeSpec {
             _pad: 0,
         }
     }
+
+    /// Cria de uma `Duration`
+    pub fn from_duration(d: Duration) -> Self {
+        Self {
+            seconds: d.as_secs(),
+            nanoseconds: d.subsec_nanos(),
+            _pad: 0,
+        }
+    }
+
+    /// Converte para `Duration`
+    pub fn to_duration(&self) -> Duration {
+        Duration::new(self.seconds, self.nanoseconds)
+    }
 }
 
 /// Obtém tempo do clock especificado
 pub fn clock_get(clock: ClockId) -> SysResult<TimeSpec> {
-    let mut ts = TimeSpec::default();
-    let ret = syscall2(
-        SYS_CLOCK_GET,
-        clock as usize,
-        &mut ts as *mut TimeSpec as usize,
-    );
-    check_error(ret)?;
-    Ok(ts)
+    sys_clock_get(clock)
 }
 
+/// Maior duração aceita por `SYS_SLEEP` (o kernel interpreta como isize).
+pub const MAX_SLEEP_MS: u64 = isize::MAX as u64;
+
 /// Dorme por N milissegundos
+///
+/// Valores acima de [`MAX_SLEEP_MS`] são limitados. Retorna os milissegundos
+/// restantes se o processo acordou antes do prazo (0 = dormiu o tempo todo).
+/// Para loops de animação, prefira [`sleep_until`](super::sleep_until).
 pub fn sleep(ms: u64) -> SysResult<u64> {
-    let ret = syscall1(SYS_SLEEP, ms as usize);
-    check_error(ret).map(|v| v as u64)
+    sys_sleep(ms.min(MAX_SLEEP_MS))
 }
 
 /// Obtém tempo monotônico (desde boot)
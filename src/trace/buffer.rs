@@ -0,0 +1,288 @@
+//! # Buffer
+//!
+//! Criação do buffer de trace do processo, escrita e leitura.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
+
+use crate::ipc::{SharedMemory, ShmId};
+use crate::syscall::safe::sys_gettid;
+use crate::syscall::{SysError, SysResult};
+
+use super::format::*;
+
+/// Capacidade padrão de [`init`] (entradas).
+pub const DEFAULT_CAPACITY: usize = 16 * 1024;
+
+static HEADER: AtomicPtr<TraceHeader> = AtomicPtr::new(core::ptr::null_mut());
+static SHM_ID: AtomicU32 = AtomicU32::new(0);
+static NEXT_SPAN: AtomicU32 = AtomicU32::new(1);
+
+// =============================================================================
+// INICIALIZAÇÃO
+// =============================================================================
+
+/// Cria o buffer de trace com [`DEFAULT_CAPACITY`] entradas.
+///
+/// Retorna o ID da memória compartilhada, a ser passado ao profiler.
+pub fn init() -> SysResult<ShmId> {
+    init_with_capacity(DEFAULT_CAPACITY)
+}
+
+/// Cria o buffer de trace com `capacity` entradas.
+///
+/// Chamadas seguintes retornam o buffer já criado.
+pub fn init_with_capacity(capacity: usize) -> SysResult<ShmId> {
+    if let Some(id) = shm_id() {
+        return Ok(id);
+    }
+    if capacity == 0 || capacity > u32::MAX as usize {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let mut shm = SharedMemory::create(buffer_size(capacity))?;
+    let header = shm.as_mut_ptr() as *mut TraceHeader;
+    unsafe {
+        header.write(TraceHeader {
+            magic: TRACE_MAGIC,
+            version: TRACE_VERSION,
+            capacity: capacity as u32,
+            entry_size: core::mem::size_of::<TraceEntry>() as u32,
+            pid: crate::process::getpid() as u32,
+            _pad: 0,
+            write_index: core::sync::atomic::AtomicU64::new(0),
+        });
+    }
+
+    // A região fica mapeada até o fim do processo
+    SHM_ID.store(shm.id().0 as u32, Ordering::Relaxed);
+    HEADER.store(header, Ordering::Release);
+    Ok(shm.id())
+}
+
+/// ID do buffer, se criado.
+pub fn shm_id() -> Option<ShmId> {
+    if HEADER.load(Ordering::Acquire).is_null() {
+        return None;
+    }
+    Some(ShmId(SHM_ID.load(Ordering::Relaxed) as u64))
+}
+
+/// Retorna se o trace está ativo.
+#[inline]
+pub fn is_enabled() -> bool {
+    !HEADER.load(Ordering::Relaxed).is_null()
+}
+
+// =============================================================================
+// ESCRITA
+// =============================================================================
+
+/// Grava uma entrada no ring (no-op se o trace não foi iniciado).
+fn record(kind: u8, name: &str, span_id: u32, value: u64) {
+    let header = HEADER.load(Ordering::Acquire);
+    if header.is_null() {
+        return;
+    }
+    let header = unsafe { &*header };
+    let timestamp_ns = crate::time::precise_ns();
+
+    let n = header.write_index.fetch_add(1, Ordering::Relaxed);
+    let slot = (n % header.capacity as u64) as usize;
+
+    let mut entry = TraceEntry {
+        seq: 0,
+        timestamp_ns,
+        value,
+        span_id,
+        tid: sys_gettid() as u32,
+        kind,
+        name_len: 0,
+        _pad: [0; 6],
+        name: [0; NAME_MAX],
+    };
+    let mut len = name.len().min(NAME_MAX);
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    entry.name[..len].copy_from_slice(&name.as_bytes()[..len]);
+    entry.name_len = len as u8;
+
+    unsafe {
+        let base = (header as *const TraceHeader).add(1) as *mut TraceEntry;
+        let dst = base.add(slot);
+        // Invalidar, gravar o corpo e publicar o seq por último
+        core::ptr::write_volatile(&mut (*dst).seq, 0);
+        core::sync::atomic::fence(Ordering::Release);
+        core::ptr::write_volatile(dst, entry);
+        core::sync::atomic::fence(Ordering::Release);
+        core::ptr::write_volatile(&mut (*dst).seq, n + 1);
+    }
+}
+
+/// Registra um evento instantâneo.
+pub fn event(name: &str, value: u64) {
+    if is_enabled() {
+        record(kind::EVENT, name, 0, value);
+    }
+}
+
+// =============================================================================
+// SPANS
+// =============================================================================
+
+/// Span aberto; registra o fim ao ser descartado.
+#[must_use = "o span termina quando o guard é descartado"]
+pub struct SpanGuard {
+    /// 0 se o trace estava desligado na abertura.
+    id: u32,
+    name: &'static str,
+}
+
+/// Abre um span (use [`span!`](crate::trace::span)).
+pub fn enter(name: &'static str) -> SpanGuard {
+    if !is_enabled() {
+        return SpanGuard { id: 0, name };
+    }
+    let id = NEXT_SPAN.fetch_add(1, Ordering::Relaxed).max(1);
+    record(kind::BEGIN, name, id, 0);
+    SpanGuard { id, name }
+}
+
+impl SpanGuard {
+    /// ID do span (0 se não registrado).
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if self.id != 0 {
+            record(kind::END, self.name, self.id, 0);
+        }
+    }
+}
+
+// =============================================================================
+// LEITURA (PROFILER)
+// =============================================================================
+
+/// Leitor do buffer de trace de outro processo.
+pub struct TraceReader {
+    shm: SharedMemory,
+    /// Capacidade validada na abertura (o header fica na SHM do escritor).
+    capacity: u64,
+    /// Próximo `n` a ler.
+    next: u64,
+}
+
+impl TraceReader {
+    /// Mapeia o buffer pelo ID recebido do processo rastreado.
+    pub fn open(id: ShmId) -> SysResult<Self> {
+        let shm = SharedMemory::open(id)?;
+        if shm.size() < core::mem::size_of::<TraceHeader>() {
+            return Err(SysError::ProtocolError);
+        }
+        let mut reader = Self {
+            shm,
+            capacity: 0,
+            next: 0,
+        };
+        let header = reader.header();
+        let capacity = header.capacity;
+        if header.magic != TRACE_MAGIC
+            || header.version != TRACE_VERSION
+            || header.entry_size as usize != core::mem::size_of::<TraceEntry>()
+            || capacity == 0
+            || reader.shm.size() < buffer_size(capacity as usize)
+        {
+            return Err(SysError::ProtocolError);
+        }
+        reader.capacity = capacity as u64;
+        Ok(reader)
+    }
+
+    fn header(&self) -> &TraceHeader {
+        unsafe { &*(self.shm.as_ptr() as *const TraceHeader) }
+    }
+
+    /// PID do processo rastreado.
+    pub fn pid(&self) -> u32 {
+        self.header().pid
+    }
+
+    /// Lê as entradas novas desde a última chamada.
+    ///
+    /// Retorna quantas entradas foram perdidas (sobrescritas antes da
+    /// leitura).
+    pub fn read(&mut self, out: &mut Vec<TraceEntry>) -> u64 {
+        let capacity = self.capacity;
+        let end = self.header().write_index.load(Ordering::Acquire);
+
+        let mut lost = 0;
+        if end.saturating_sub(self.next) > capacity {
+            lost = end - capacity - self.next;
+            self.next = end - capacity;
+        }
+
+        let base = unsafe { (self.shm.as_ptr() as *const TraceHeader).add(1) as *const TraceEntry };
+        while self.next < end {
+            let n = self.next;
+            let src = unsafe { base.add((n % capacity) as usize) };
+            let entry = unsafe { core::ptr::read_volatile(src) };
+            core::sync::atomic::fence(Ordering::Acquire);
+            let seq = unsafe { core::ptr::read_volatile(&(*src).seq) };
+
+            if entry.seq != n + 1 || seq != n + 1 {
+                if seq > n + 1 {
+                    // Sobrescrita pelo escritor: perdida
+                    lost += 1;
+                } else {
+                    // Ainda em escrita: tentar na próxima chamada
+                    break;
+                }
+            } else {
+                out.push(entry);
+            }
+            self.next += 1;
+        }
+        lost
+    }
+}
+
+/// Profundidade de aninhamento de spans por thread, para montar um flame
+/// graph por TID a partir das entradas lidas em ordem.
+#[derive(Debug, Default)]
+pub struct ThreadDepths {
+    depths: BTreeMap<u32, u32>,
+}
+
+impl ThreadDepths {
+    /// Sem spans abertos.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Profundidade de `entry` na sua thread (0 = nível mais externo).
+    ///
+    /// BEGIN e END do mesmo span têm a mesma profundidade. Entradas
+    /// perdidas desalinham só a thread em que ocorreram.
+    pub fn depth(&mut self, entry: &TraceEntry) -> u32 {
+        let current = self.depths.entry(entry.tid).or_insert(0);
+        match entry.kind {
+            kind::BEGIN => {
+                *current += 1;
+                *current - 1
+            }
+            kind::END => {
+                *current = current.saturating_sub(1);
+                *current
+            }
+            _ => *current,
+        }
+    }
+}
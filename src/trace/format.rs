@@ -0,0 +1,87 @@
+//! # Formato do Buffer
+//!
+//! Layout do ring buffer de trace na memória compartilhada, lido pelo
+//! profiler em outro processo.
+//!
+//! ```text
+//! +-------------+---------+---------+-----+-----------------------+
+//! | TraceHeader | Entry 0 | Entry 1 | ... | Entry (capacity - 1)  |
+//! +-------------+---------+---------+-----+-----------------------+
+//! ```
+//!
+//! A entrada `n` (contando desde o início) fica no slot `n % capacity`. O
+//! escritor grava `seq = n + 1` por último; o leitor descarta entradas cujo
+//! `seq` não corresponde ao esperado (sobrescritas ou incompletas).
+//!
+//! Cada entrada traz o TID de quem a gravou; o aninhamento de spans é
+//! calculado por thread no leitor ([`ThreadDepths`](super::ThreadDepths)).
+
+use core::sync::atomic::AtomicU64;
+
+/// Assinatura do buffer (`"RTRC"`).
+pub const TRACE_MAGIC: u32 = 0x4352_5452;
+
+/// Versão do layout.
+pub const TRACE_VERSION: u32 = 2;
+
+/// Tamanho máximo do nome de uma entrada (truncado).
+pub const NAME_MAX: usize = 24;
+
+/// Tipos de entrada.
+pub mod kind {
+    /// Início de span.
+    pub const BEGIN: u8 = 1;
+    /// Fim de span.
+    pub const END: u8 = 2;
+    /// Evento instantâneo.
+    pub const EVENT: u8 = 3;
+}
+
+/// Cabeçalho do buffer.
+#[repr(C)]
+pub struct TraceHeader {
+    pub magic: u32,
+    pub version: u32,
+    /// Número de slots de entrada.
+    pub capacity: u32,
+    /// `size_of::<TraceEntry>()`.
+    pub entry_size: u32,
+    /// PID do processo rastreado.
+    pub pid: u32,
+    pub _pad: u32,
+    /// Total de entradas já reservadas (próximo `n`).
+    pub write_index: AtomicU64,
+}
+
+/// Entrada do trace.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TraceEntry {
+    /// `n + 1` quando a entrada está completa.
+    pub seq: u64,
+    /// Timestamp (ns, `time::precise_ns`).
+    pub timestamp_ns: u64,
+    /// Valor associado (argumento de evento).
+    pub value: u64,
+    /// ID do span (pareia BEGIN/END), 0 para eventos.
+    pub span_id: u32,
+    /// Thread que gravou a entrada (`SYS_GETTID`).
+    pub tid: u32,
+    pub kind: u8,
+    pub name_len: u8,
+    pub _pad: [u8; 6],
+    pub name: [u8; NAME_MAX],
+}
+
+impl TraceEntry {
+    /// Nome da entrada.
+    pub fn name(&self) -> &str {
+        let len = (self.name_len as usize).min(NAME_MAX);
+        core::str::from_utf8(&self.name[..len]).unwrap_or("")
+    }
+}
+
+/// Bytes necessários para `capacity` entradas.
+pub const fn buffer_size(capacity: usize) -> usize {
+    core::mem::size_of::<TraceHeader>() + capacity * core::mem::size_of::<TraceEntry>()
+}
@@ -0,0 +1,60 @@
+//! # Trace
+//!
+//! Spans e eventos com timestamp gravados em um ring buffer na memória
+//! compartilhada do processo, que um profiler mapeia para montar flame
+//! graphs.
+//!
+//! Sem [`init`], spans e eventos custam apenas uma leitura atômica.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::trace;
+//!
+//! let shm = trace::init()?; // enviar ao profiler
+//!
+//! fn compose_frame() {
+//!     let _span = trace::span!("compose_frame");
+//!     trace::event!("damage", rects.len());
+//! }
+//! ```
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`format`] | Layout do buffer compartilhado |
+//! | `buffer` | Escrita (spans, eventos), [`TraceReader`] e [`ThreadDepths`] |
+
+mod buffer;
+pub mod format;
+
+pub use buffer::{
+    enter, event, init, init_with_capacity, is_enabled, shm_id, SpanGuard, ThreadDepths,
+    TraceReader, DEFAULT_CAPACITY,
+};
+pub use format::TraceEntry;
+
+pub use crate::__trace_event as event;
+pub use crate::__trace_span as span;
+
+/// Abre um span até o fim do escopo: `let _s = trace::span!("nome");`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_span {
+    ($name:expr) => {
+        $crate::trace::enter($name)
+    };
+}
+
+/// Registra um evento: `trace::event!("nome")` ou `trace::event!("nome", valor)`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_event {
+    ($name:expr) => {
+        $crate::trace::event($name, 0)
+    };
+    ($name:expr, $value:expr) => {
+        $crate::trace::event($name, ($value) as u64)
+    };
+}
@@ -0,0 +1,197 @@
+//! # Dialog
+//!
+//! Diálogos prontos do SDK.
+//!
+//! [`report_error`] mostra um erro recuperável e oferece ao usuário enviar
+//! um relatório; o pacote vem de
+//! [`runtime::report`](crate::runtime::report) e o envio fica a cargo do
+//! app.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::runtime::report;
+//! use redpowder::ui::dialog::{self, ReportChoice};
+//!
+//! if let Err(err) = sync_notes() {
+//!     if dialog::report_error(&err)? == ReportChoice::Send {
+//!         upload(&report::bundle_with_error(&err))?;
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::format;
+use core::cell::{Cell, RefCell};
+use core::fmt;
+
+use gfx_types::color::Color;
+use gfx_types::geometry::{Point, Rect};
+use gfx_types::text::TextAlign;
+
+use crate::event::{event_type, InputEvent};
+use crate::graphics::Canvas;
+use crate::input::KeyCode;
+use crate::syscall::SysResult;
+use crate::window::{EventLoop, Window};
+
+use super::focus::FocusChain;
+
+const WIDTH: u32 = 440;
+const HEIGHT: u32 = 180;
+const PADDING: i32 = 16;
+const BUTTON_HEIGHT: u32 = 28;
+
+const BACKGROUND: Color = Color::from_hex(0x1E1E2E);
+const TEXT: Color = Color::from_hex(0xCDD6F4);
+const BUTTON: Color = Color::from_hex(0x313244);
+const BUTTON_PRIMARY: Color = Color::from_hex(0x45475A);
+
+/// Botões do diálogo (ids na cadeia de foco).
+const SEND: u32 = 1;
+const DISMISS: u32 = 2;
+
+/// Escolha do usuário em [`report_error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportChoice {
+    /// "Enviar relatório".
+    Send,
+    /// "Fechar", Esc ou janela fechada.
+    Dismiss,
+}
+
+// =============================================================================
+// RELATÓRIO DE ERRO
+// =============================================================================
+
+/// Mostra o erro num diálogo modal e espera a escolha do usuário.
+///
+/// Teclado: Tab/setas trocam o botão, Enter/Espaço confirmam, Esc fecha.
+pub fn report_error(error: &dyn fmt::Display) -> SysResult<ReportChoice> {
+    let window = Window::create(0, 0, WIDTH, HEIGHT, "Erro")?;
+    if let Ok(output) = window.output_info() {
+        let x = output.width.saturating_sub(WIDTH) / 2;
+        let y = output.height.saturating_sub(HEIGHT) / 3;
+        let _ = window.move_to(x as i32, y as i32);
+    }
+
+    let message = format!("Ocorreu um erro:\n{}", error);
+    let focus = RefCell::new(button_chain());
+    let choice = Cell::new(ReportChoice::Dismiss);
+    let shift = Cell::new(false);
+
+    let mut event_loop = EventLoop::new();
+    event_loop.add_window(window);
+    redraw(&mut event_loop.windows()[0], &message, &focus.borrow())?;
+
+    event_loop
+        .on_input(|ctl, input| {
+            let mut focus = focus.borrow_mut();
+            let activated = match handle_input(&mut focus, &shift, &input) {
+                Action::None => return,
+                Action::Redraw => None,
+                Action::Activate(id) => Some(id),
+                Action::Dismiss => Some(DISMISS),
+            };
+            match activated {
+                Some(id) => {
+                    if id == SEND {
+                        choice.set(ReportChoice::Send);
+                    }
+                    ctl.exit();
+                }
+                None => {
+                    let _ = redraw(ctl.window(), &message, &focus);
+                }
+            }
+        })
+        .on_close(|ctl| ctl.exit());
+    event_loop.run()?;
+
+    Ok(choice.get())
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+enum Action {
+    None,
+    Redraw,
+    Activate(u32),
+    Dismiss,
+}
+
+fn button_chain() -> FocusChain {
+    let y = HEIGHT as i32 - PADDING - BUTTON_HEIGHT as i32;
+    let dismiss = Rect::new(WIDTH as i32 - PADDING - 80, y, 80, BUTTON_HEIGHT);
+    let send = Rect::new(dismiss.x - 8 - 152, y, 152, BUTTON_HEIGHT);
+
+    let mut chain = FocusChain::new();
+    chain.register(SEND, send);
+    chain.register(DISMISS, dismiss);
+    chain.focus(SEND);
+    chain
+}
+
+fn handle_input(focus: &mut FocusChain, shift: &Cell<bool>, input: &InputEvent) -> Action {
+    match input.event_type {
+        event_type::KEY_DOWN => match KeyCode::from_scancode(input.param1 as u8) {
+            KeyCode::Shift => {
+                shift.set(true);
+                Action::None
+            }
+            KeyCode::Enter | KeyCode::Space => match focus.focused() {
+                Some(id) => Action::Activate(id),
+                None => Action::None,
+            },
+            KeyCode::Esc => Action::Dismiss,
+            key => match focus.handle_key(key, shift.get()) {
+                Some(_) => Action::Redraw,
+                None => Action::None,
+            },
+        },
+        event_type::KEY_UP => {
+            if KeyCode::from_scancode(input.param1 as u8) == KeyCode::Shift {
+                shift.set(false);
+            }
+            Action::None
+        }
+        event_type::MOUSE_DOWN => {
+            let point = Point::new(input.param1 as i32, input.param2 as i32);
+            match focus.focus_at(point) {
+                Some(id) => Action::Activate(id),
+                None => Action::None,
+            }
+        }
+        _ => Action::None,
+    }
+}
+
+fn redraw(window: &mut Window, message: &str, focus: &FocusChain) -> SysResult<()> {
+    let (width, height) = (window.width(), window.height());
+    let mut canvas = Canvas::new(window.buffer(), width, height);
+    canvas.clear(BACKGROUND);
+
+    let text_area = Rect::new(
+        PADDING,
+        PADDING,
+        width - 2 * PADDING as u32,
+        height - 3 * PADDING as u32 - BUTTON_HEIGHT,
+    );
+    canvas.draw_text_wrapped(text_area, message, TEXT, TextAlign::Left);
+
+    for (id, label, color) in [
+        (SEND, "Enviar relatório", BUTTON_PRIMARY),
+        (DISMISS, "Fechar", BUTTON),
+    ] {
+        let Some(rect) = focus.rect(id) else { continue };
+        canvas.fill_rect(rect, color);
+        let label_area = Rect::new(rect.x, rect.y + 6, rect.width, 16);
+        canvas.draw_text_wrapped(label_area, label, TEXT, TextAlign::Center);
+    }
+    focus.draw_ring(&mut canvas);
+
+    window.present()
+}
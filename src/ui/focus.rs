@@ -0,0 +1,344 @@
+//! # Focus
+//!
+//! Cadeia de foco entre widgets para uso só com teclado ou D-pad.
+//!
+//! Tab e Shift+Tab percorrem os widgets na ordem de registro (com volta
+//! ao início); as setas escolhem o widget mais próximo na direção, pela
+//! posição na tela. Widgets desabilitados são pulados.
+//!
+//! A cadeia não conhece os widgets: cada um é um [`FocusId`] do app com o
+//! retângulo onde está desenhado.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::ui::focus::FocusChain;
+//!
+//! const NAME: u32 = 1;
+//! const PASSWORD: u32 = 2;
+//! const LOGIN: u32 = 3;
+//!
+//! let mut focus = FocusChain::new();
+//! focus.register(NAME, Rect::new(20, 20, 200, 24));
+//! focus.register(PASSWORD, Rect::new(20, 52, 200, 24));
+//! focus.register(LOGIN, Rect::new(20, 84, 80, 28));
+//! focus.focus(NAME);
+//!
+//! // No tratamento de teclas
+//! if focus.handle_key(key, shift_down).is_some() {
+//!     redraw();
+//! }
+//!
+//! // Após desenhar os widgets
+//! focus.draw_ring(&mut canvas);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use gfx_types::color::Color;
+use gfx_types::geometry::{Point, Rect};
+
+use crate::graphics::Canvas;
+use crate::input::KeyCode;
+
+/// Identificador de widget definido pelo app.
+pub type FocusId = u32;
+
+// =============================================================================
+// DIREÇÃO
+// =============================================================================
+
+/// Direção de movimento do foco.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    /// Próximo na ordem de tabulação (Tab).
+    Next,
+    /// Anterior na ordem de tabulação (Shift+Tab).
+    Previous,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FocusDirection {
+    /// Direção associada à tecla, se houver.
+    pub fn from_key(key: KeyCode, shift: bool) -> Option<Self> {
+        match key {
+            KeyCode::Tab if shift => Some(Self::Previous),
+            KeyCode::Tab => Some(Self::Next),
+            KeyCode::Up => Some(Self::Up),
+            KeyCode::Down => Some(Self::Down),
+            KeyCode::Left => Some(Self::Left),
+            KeyCode::Right => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// ANEL DE FOCO
+// =============================================================================
+
+/// Aparência do anel desenhado em volta do widget focado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusRing {
+    pub color: Color,
+    /// Espessura da borda (px).
+    pub thickness: u32,
+    /// Distância entre o widget e o anel (px; negativo desenha por dentro).
+    pub offset: i32,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            color: Color::from_hex(0x89B4FA),
+            thickness: 2,
+            offset: 2,
+        }
+    }
+}
+
+// =============================================================================
+// CADEIA DE FOCO
+// =============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    id: FocusId,
+    rect: Rect,
+    enabled: bool,
+}
+
+/// Widgets focáveis em ordem de tabulação e o foco atual.
+#[derive(Debug, Clone, Default)]
+pub struct FocusChain {
+    entries: Vec<Entry>,
+    focused: Option<FocusId>,
+    ring: FocusRing,
+}
+
+impl FocusChain {
+    /// Cadeia vazia, sem foco.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // =========================================================================
+    // REGISTRO
+    // =========================================================================
+
+    /// Adiciona um widget ao fim da ordem de tabulação, ou atualiza o
+    /// retângulo de um já registrado.
+    pub fn register(&mut self, id: FocusId, rect: Rect) {
+        match self.entry_mut(id) {
+            Some(entry) => entry.rect = rect,
+            None => self.entries.push(Entry {
+                id,
+                rect,
+                enabled: true,
+            }),
+        }
+    }
+
+    /// Atualiza o retângulo do widget (após layout ou scroll).
+    pub fn set_rect(&mut self, id: FocusId, rect: Rect) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.rect = rect;
+        }
+    }
+
+    /// Habilita ou desabilita o widget; desabilitar remove o foco dele.
+    pub fn set_enabled(&mut self, id: FocusId, enabled: bool) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.enabled = enabled;
+        }
+        if !enabled && self.focused == Some(id) {
+            self.focused = None;
+        }
+    }
+
+    /// Remove o widget da cadeia.
+    pub fn remove(&mut self, id: FocusId) {
+        self.entries.retain(|e| e.id != id);
+        if self.focused == Some(id) {
+            self.focused = None;
+        }
+    }
+
+    /// Remove todos os widgets.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.focused = None;
+    }
+
+    /// Número de widgets registrados.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Cadeia vazia?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // =========================================================================
+    // FOCO PROGRAMÁTICO
+    // =========================================================================
+
+    /// Widget focado.
+    #[inline]
+    pub fn focused(&self) -> Option<FocusId> {
+        self.focused
+    }
+
+    /// O widget tem o foco?
+    #[inline]
+    pub fn is_focused(&self, id: FocusId) -> bool {
+        self.focused == Some(id)
+    }
+
+    /// Retângulo registrado do widget.
+    pub fn rect(&self, id: FocusId) -> Option<Rect> {
+        self.entries.iter().find(|e| e.id == id).map(|e| e.rect)
+    }
+
+    /// Retângulo do widget focado.
+    pub fn focused_rect(&self) -> Option<Rect> {
+        self.rect(self.focused?)
+    }
+
+    /// Foca o widget; retorna `false` se ele não existe ou está desabilitado.
+    pub fn focus(&mut self, id: FocusId) -> bool {
+        let focusable = self.entries.iter().any(|e| e.id == id && e.enabled);
+        if focusable {
+            self.focused = Some(id);
+        }
+        focusable
+    }
+
+    /// Remove o foco.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Foca o widget sob o ponto (clique); retorna o novo foco.
+    pub fn focus_at(&mut self, point: Point) -> Option<FocusId> {
+        let id = self
+            .entries
+            .iter()
+            .rev()
+            .find(|e| e.enabled && e.rect.contains_point(point))?
+            .id;
+        self.focused = Some(id);
+        Some(id)
+    }
+
+    // =========================================================================
+    // NAVEGAÇÃO
+    // =========================================================================
+
+    /// Move o foco; retorna o novo widget focado se o foco mudou.
+    pub fn move_focus(&mut self, direction: FocusDirection) -> Option<FocusId> {
+        let target = match direction {
+            FocusDirection::Next => self.step(1),
+            FocusDirection::Previous => self.step(-1),
+            _ => self.nearest(direction),
+        }?;
+        if self.focused == Some(target) {
+            return None;
+        }
+        self.focused = Some(target);
+        Some(target)
+    }
+
+    /// Trata Tab/Shift+Tab e setas; retorna o novo foco se ele mudou.
+    pub fn handle_key(&mut self, key: KeyCode, shift: bool) -> Option<FocusId> {
+        self.move_focus(FocusDirection::from_key(key, shift)?)
+    }
+
+    /// Próximo widget habilitado na ordem de tabulação, com volta.
+    fn step(&self, delta: isize) -> Option<FocusId> {
+        let len = self.entries.len() as isize;
+        let current = self.focused_index();
+        let start = match current {
+            Some(index) => index as isize,
+            // Sem foco: Tab vai ao primeiro e Shift+Tab ao último
+            None if delta > 0 => -1,
+            None => len,
+        };
+        (1..=len)
+            .map(|i| (start + delta * i).rem_euclid(len) as usize)
+            .map(|index| self.entries[index])
+            .find(|e| e.enabled)
+            .map(|e| e.id)
+    }
+
+    /// Widget habilitado mais próximo na direção espacial.
+    fn nearest(&self, direction: FocusDirection) -> Option<FocusId> {
+        let Some(from) = self.focused_rect() else {
+            return self.step(1);
+        };
+        let origin = from.center();
+
+        self.entries
+            .iter()
+            .filter(|e| e.enabled && Some(e.id) != self.focused)
+            .filter_map(|e| {
+                let c = e.rect.center();
+                let (dx, dy) = ((c.x - origin.x) as i64, (c.y - origin.y) as i64);
+                let (along, across) = match direction {
+                    FocusDirection::Up => (-dy, dx),
+                    FocusDirection::Down => (dy, dx),
+                    FocusDirection::Left => (-dx, dy),
+                    FocusDirection::Right => (dx, dy),
+                    _ => return None,
+                };
+                // Desvio lateral pesa mais para preferir o vizinho alinhado
+                (along > 0).then(|| (along + 2 * across.abs(), e.id))
+            })
+            .min_by_key(|&(score, _)| score)
+            .map(|(_, id)| id)
+    }
+
+    // =========================================================================
+    // DESENHO
+    // =========================================================================
+
+    /// Aparência do anel de foco.
+    #[inline]
+    pub fn ring(&self) -> FocusRing {
+        self.ring
+    }
+
+    /// Define a aparência do anel de foco.
+    pub fn set_ring(&mut self, ring: FocusRing) {
+        self.ring = ring;
+    }
+
+    /// Desenha o anel em volta do widget focado.
+    pub fn draw_ring(&self, canvas: &mut Canvas<'_>) {
+        if let Some(rect) = self.focused_rect() {
+            let ring = self.ring;
+            canvas.stroke_rect(rect.expand(ring.offset), ring.color, ring.thickness);
+        }
+    }
+
+    // =========================================================================
+    // HELPERS
+    // =========================================================================
+
+    fn entry_mut(&mut self, id: FocusId) -> Option<&mut Entry> {
+        self.entries.iter_mut().find(|e| e.id == id)
+    }
+
+    fn focused_index(&self) -> Option<usize> {
+        let id = self.focused?;
+        self.entries.iter().position(|e| e.id == id)
+    }
+}
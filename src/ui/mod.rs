@@ -0,0 +1,20 @@
+//! # UI
+//!
+//! Infraestrutura de interface independente de toolkit.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`focus`] | Navegação de foco por teclado/D-pad e anéis de foco |
+//! | [`dialog`] | Diálogos prontos (relatório de erro) |
+
+pub mod dialog;
+pub mod focus;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use dialog::{report_error, ReportChoice};
+pub use focus::{FocusChain, FocusDirection, FocusId, FocusRing};
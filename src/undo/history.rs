@@ -0,0 +1,354 @@
+//! # History
+//!
+//! Histórico de comandos reversíveis.
+//!
+//! Cada passo de desfazer é um grupo de comandos: um comando isolado vira
+//! um grupo de um; [`History::begin_group`]/[`History::end_group`] juntam
+//! vários (ex.: "Substituir tudo"). Comandos consecutivos podem se fundir
+//! via [`Command::merge`] (ex.: digitação letra a letra).
+//!
+//! [`History::mark_clean`] registra o ponto salvo; [`History::is_dirty`]
+//! compara o estado atual com ele, inclusive após desfazer/refazer.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::undo::{Command, History};
+//!
+//! struct Insert { at: usize, text: String }
+//!
+//! impl Command for Insert {
+//!     type Target = String;
+//!
+//!     fn apply(&mut self, doc: &mut String) {
+//!         doc.insert_str(self.at, &self.text);
+//!     }
+//!
+//!     fn revert(&mut self, doc: &mut String) {
+//!         doc.replace_range(self.at..self.at + self.text.len(), "");
+//!     }
+//!
+//!     fn merge(&mut self, next: &Self) -> bool {
+//!         let contiguous = next.at == self.at + self.text.len();
+//!         if contiguous {
+//!             self.text.push_str(&next.text);
+//!         }
+//!         contiguous
+//!     }
+//! }
+//!
+//! let mut doc = String::new();
+//! let mut history = History::with_limit(100);
+//! history.push(&mut doc, Insert { at: 0, text: "ab".into() });
+//! history.push(&mut doc, Insert { at: 2, text: "c".into() }); // funde
+//! history.undo(&mut doc); // doc == ""
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+// =============================================================================
+// COMMAND
+// =============================================================================
+
+/// Operação reversível sobre `Target`.
+pub trait Command {
+    /// Documento/estado alterado pelo comando.
+    type Target;
+
+    /// Aplica (ou reaplica, no refazer) o comando.
+    fn apply(&mut self, target: &mut Self::Target);
+
+    /// Desfaz o comando.
+    fn revert(&mut self, target: &mut Self::Target);
+
+    /// Tenta absorver `next` (já aplicado) neste comando.
+    ///
+    /// Retorna `true` se `next` foi absorvido e não deve ser guardado.
+    fn merge(&mut self, _next: &Self) -> bool {
+        false
+    }
+
+    /// Descrição para menus ("Desfazer digitação").
+    fn label(&self) -> &str {
+        ""
+    }
+}
+
+// =============================================================================
+// HISTORY
+// =============================================================================
+
+/// Passo de desfazer: comandos na ordem em que foram aplicados.
+struct Group<C> {
+    commands: Vec<C>,
+    label: Option<&'static str>,
+}
+
+impl<C: Command> Group<C> {
+    fn label(&self) -> &str {
+        match (self.label, self.commands.last()) {
+            (Some(label), _) => label,
+            (None, Some(command)) => command.label(),
+            (None, None) => "",
+        }
+    }
+}
+
+/// Pilhas de desfazer e refazer.
+pub struct History<C: Command> {
+    done: VecDeque<Group<C>>,
+    undone: Vec<Group<C>>,
+    /// Grupo aberto por `begin_group` e sua profundidade de aninhamento.
+    open: Option<(Group<C>, usize)>,
+    /// Máximo de passos guardados (0 = sem limite).
+    limit: usize,
+    /// Tamanho de `done` no ponto salvo (`None`: inalcançável).
+    clean: Option<usize>,
+}
+
+impl<C: Command> Default for History<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Command> History<C> {
+    /// Histórico sem limite, limpo.
+    pub fn new() -> Self {
+        Self::with_limit(0)
+    }
+
+    /// Histórico que guarda no máximo `limit` passos (0 = sem limite).
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            done: VecDeque::new(),
+            undone: Vec::new(),
+            open: None,
+            limit,
+            clean: Some(0),
+        }
+    }
+
+    /// Máximo de passos guardados (0 = sem limite).
+    #[inline]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Altera o limite, descartando os passos mais antigos se preciso.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        self.enforce_limit();
+    }
+
+    // =========================================================================
+    // REGISTRO
+    // =========================================================================
+
+    /// Aplica o comando e o registra.
+    pub fn push(&mut self, target: &mut C::Target, mut command: C) {
+        command.apply(target);
+        self.record(command);
+    }
+
+    /// Registra um comando já aplicado.
+    ///
+    /// Descarta a pilha de refazer.
+    pub fn record(&mut self, command: C) {
+        self.discard_redo();
+
+        if let Some((group, _)) = &mut self.open {
+            if !merge_into(&mut group.commands, &command) {
+                group.commands.push(command);
+            }
+            return;
+        }
+
+        // Não fundir com o passo do ponto salvo: o estado limpo sumiria
+        let at_clean = self.clean == Some(self.done.len());
+        if let Some(top) = self.done.back_mut() {
+            if !at_clean && top.label.is_none() && merge_into(&mut top.commands, &command) {
+                return;
+            }
+        }
+
+        self.push_group(Group {
+            commands: alloc::vec![command],
+            label: None,
+        });
+    }
+
+    /// Abre um grupo: os comandos até o `end_group` correspondente viram um
+    /// único passo. Grupos podem ser aninhados (vale o rótulo do externo).
+    pub fn begin_group(&mut self, label: &'static str) {
+        match &mut self.open {
+            Some((_, depth)) => *depth += 1,
+            None => {
+                let group = Group {
+                    commands: Vec::new(),
+                    label: Some(label),
+                };
+                self.open = Some((group, 1));
+            }
+        }
+    }
+
+    /// Fecha o grupo aberto por [`begin_group`](Self::begin_group).
+    pub fn end_group(&mut self) {
+        let Some((_, depth)) = &mut self.open else {
+            return;
+        };
+        *depth -= 1;
+        if *depth > 0 {
+            return;
+        }
+        if let Some((group, _)) = self.open.take() {
+            if !group.commands.is_empty() {
+                self.push_group(group);
+            }
+        }
+    }
+
+    // =========================================================================
+    // DESFAZER / REFAZER
+    // =========================================================================
+
+    /// Desfaz o último passo; retorna `false` se não havia passos.
+    ///
+    /// Fecha um grupo aberto antes de desfazer.
+    pub fn undo(&mut self, target: &mut C::Target) -> bool {
+        self.close_open_group();
+        let Some(mut group) = self.done.pop_back() else {
+            return false;
+        };
+        for command in group.commands.iter_mut().rev() {
+            command.revert(target);
+        }
+        self.undone.push(group);
+        true
+    }
+
+    /// Refaz o último passo desfeito; retorna `false` se não havia.
+    pub fn redo(&mut self, target: &mut C::Target) -> bool {
+        self.close_open_group();
+        let Some(mut group) = self.undone.pop() else {
+            return false;
+        };
+        for command in group.commands.iter_mut() {
+            command.apply(target);
+        }
+        self.done.push_back(group);
+        true
+    }
+
+    /// Há passos para desfazer?
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+            || self
+                .open
+                .as_ref()
+                .is_some_and(|(g, _)| !g.commands.is_empty())
+    }
+
+    /// Há passos para refazer?
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Rótulo do próximo passo a desfazer.
+    pub fn undo_label(&self) -> Option<&str> {
+        self.done.back().map(Group::label)
+    }
+
+    /// Rótulo do próximo passo a refazer.
+    pub fn redo_label(&self) -> Option<&str> {
+        self.undone.last().map(Group::label)
+    }
+
+    /// Número de passos para desfazer.
+    #[inline]
+    pub fn undo_count(&self) -> usize {
+        self.done.len()
+    }
+
+    /// Número de passos para refazer.
+    #[inline]
+    pub fn redo_count(&self) -> usize {
+        self.undone.len()
+    }
+
+    /// Descarta todo o histórico; o estado atual passa a ser o limpo.
+    pub fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+        self.open = None;
+        self.clean = Some(0);
+    }
+
+    // =========================================================================
+    // ESTADO SUJO
+    // =========================================================================
+
+    /// Marca o estado atual como salvo.
+    pub fn mark_clean(&mut self) {
+        self.close_open_group();
+        self.clean = Some(self.done.len());
+    }
+
+    /// O estado difere do último salvo?
+    pub fn is_dirty(&self) -> bool {
+        let pending = self
+            .open
+            .as_ref()
+            .is_some_and(|(g, _)| !g.commands.is_empty());
+        pending || self.clean != Some(self.done.len())
+    }
+
+    // =========================================================================
+    // HELPERS
+    // =========================================================================
+
+    fn push_group(&mut self, group: Group<C>) {
+        self.done.push_back(group);
+        self.enforce_limit();
+    }
+
+    fn close_open_group(&mut self) {
+        if let Some((group, _)) = self.open.take() {
+            if !group.commands.is_empty() {
+                self.push_group(group);
+            }
+        }
+    }
+
+    fn discard_redo(&mut self) {
+        if self.undone.is_empty() {
+            return;
+        }
+        self.undone.clear();
+        // O ponto salvo estava na pilha de refazer
+        if self.clean.is_some_and(|clean| clean > self.done.len()) {
+            self.clean = None;
+        }
+    }
+
+    fn enforce_limit(&mut self) {
+        if self.limit == 0 {
+            return;
+        }
+        while self.done.len() > self.limit {
+            self.done.pop_front();
+            self.clean = self.clean.and_then(|clean| clean.checked_sub(1));
+        }
+    }
+}
+
+/// Funde `command` no último comando de `commands`, se possível.
+fn merge_into<C: Command>(commands: &mut [C], command: &C) -> bool {
+    commands.last_mut().is_some_and(|last| last.merge(command))
+}
@@ -0,0 +1,18 @@
+//! # Undo
+//!
+//! Pilha de desfazer/refazer compartilhada por editores, apps de desenho e
+//! formulários de configuração.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | `history` | [`Command`] e [`History`] (grupos, limite, estado sujo) |
+
+mod history;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use history::{Command, History};
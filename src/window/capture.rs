@@ -0,0 +1,124 @@
+//! # Capture
+//!
+//! Consultas e capturas da tela composta pelo compositor, para ferramentas
+//! de screenshot e testes automatizados de UI.
+//!
+//! Exige a permissão de captura de tela: apps sem ela recebem
+//! `SysError::PermissionDenied`. Capturar a própria janela
+//! ([`Window::capture`](super::Window::capture)) não exige permissão.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::capture;
+//!
+//! // Conta-gotas: cor sob o cursor
+//! let color = capture::pixel_at(cursor)?;
+//!
+//! // Screenshot de todos os monitores
+//! let shot = capture::capture_screen()?;
+//! canvas.draw_image(&shot, Point::new(0, 0));
+//! ```
+
+use gfx_types::color::Color;
+use gfx_types::geometry::Point;
+
+use crate::graphics::image::ImageBuffer;
+use crate::graphics::Surface;
+use crate::ipc::{as_bytes, as_bytes_mut, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::error::{WindowError, WindowResult};
+use super::protocol::*;
+
+/// Tempo máximo de espera pela resposta do compositor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Cor do pixel em `point` (coordenadas globais) na tela composta.
+///
+/// Fora de todos os monitores retorna `SysError::InvalidArgument`.
+pub fn pixel_at(point: Point) -> SysResult<Color> {
+    let (reply_port, reply_name) = Port::create_unique("win.px.", 4)?;
+    let req = QueryPixelRequest {
+        op: opcodes::QUERY_PIXEL,
+        x: point.x,
+        y: point.y,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let mut msg = ProtocolMessage {
+        raw: [0; MAX_MSG_SIZE],
+    };
+    if reply_port.recv(as_bytes_mut(&mut msg), REPLY_TIMEOUT_MS)? == 0 {
+        return Err(SysError::Timeout);
+    }
+    match unsafe { msg.header } {
+        opcodes::PIXEL_VALUE => Ok(Color::from_raw(unsafe { msg.pixel_resp.color })),
+        opcodes::ERROR => Err(WindowError::from_response(unsafe { &msg.error }).into()),
+        _ => Err(SysError::ProtocolError),
+    }
+}
+
+/// Captura a tela composta inteira (todos os monitores, em coordenadas
+/// globais).
+pub fn capture_screen() -> WindowResult<ImageBuffer> {
+    capture_window(0)
+}
+
+/// Captura o conteúdo atual de `window_id`, sem decorações nem sombra.
+///
+/// Janelas minimizadas retornam o último conteúdo apresentado.
+pub fn capture_window(window_id: u32) -> WindowResult<ImageBuffer> {
+    let (reply_port, reply_name) = Port::create_unique("win.cap.", 4)?;
+    let req = CaptureRequest {
+        op: opcodes::CAPTURE,
+        window_id,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let mut msg = ProtocolMessage {
+        raw: [0; MAX_MSG_SIZE],
+    };
+    if reply_port.recv(as_bytes_mut(&mut msg), REPLY_TIMEOUT_MS)? == 0 {
+        return Err(SysError::Timeout.into());
+    }
+    match unsafe { msg.header } {
+        opcodes::CAPTURED => {
+            let resp = unsafe { msg.capture_resp };
+            let surface = read_shm_surface(resp.shm_handle, resp.width, resp.height, resp.stride)?;
+            Ok(ImageBuffer::from_surface(surface))
+        }
+        opcodes::ERROR => Err(WindowError::from_response(unsafe { &msg.error })),
+        _ => Err(SysError::ProtocolError.into()),
+    }
+}
+
+/// Copia pixels ARGB8888 de uma SHM do compositor para uma [`Surface`].
+pub(super) fn read_shm_surface(
+    shm_handle: u64,
+    width: u32,
+    height: u32,
+    stride: u32,
+) -> SysResult<Surface> {
+    let stride = stride as usize;
+    if stride < width as usize * 4 {
+        return Err(SysError::ProtocolError);
+    }
+    let shm = SharedMemory::open(ShmId(shm_handle))?;
+    let data = shm.as_slice();
+    if data.len() < stride * height as usize {
+        return Err(SysError::ProtocolError);
+    }
+
+    let mut surface = Surface::new(width, height);
+    let pixels = surface.pixels_mut();
+    for (y, line) in data.chunks_exact(stride).take(height as usize).enumerate() {
+        let out = &mut pixels[y * width as usize..(y + 1) * width as usize];
+        for (px, bytes) in out.iter_mut().zip(line.chunks_exact(4)) {
+            *px = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+    }
+    Ok(surface)
+}
@@ -2,14 +2,31 @@
 //!
 //! Cliente de janela para comunicação com o compositor Firefly.
 
-use crate::ipc::{Port, SharedMemory, ShmId};
+extern crate alloc;
+
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use crate::event::InputEvent;
+use crate::graphics::image::ImageBuffer;
+use crate::graphics::{DamageRegion, ScaleFactor};
+use crate::ipc::{as_bytes, as_bytes_mut, fixed_str, Port, SharedMemory, ShmId};
 use crate::syscall::{SysError, SysResult};
 
-use gfx_types::color::Color;
+use gfx_types::color::{Color, PixelFormat};
+use gfx_types::display::DisplayInfo;
 use gfx_types::geometry::{Point, Rect, Size};
-use gfx_types::window::WindowFlags;
+use gfx_types::input::CursorType;
+use gfx_types::window::{ResizeEdge, WindowFlags};
 
+use super::connection::Shared;
+use super::error::{WindowError, WindowResult};
 use super::protocol::*;
+use super::session::WindowGeometry;
+use super::tiling::TilingState;
+
+/// Máximo de retângulos enviados por `present_damage`.
+const MAX_COMMIT_RECTS: usize = 16;
 
 // =============================================================================
 // WINDOW
@@ -29,6 +46,14 @@ pub struct Window {
     compositor_port: Port,
     /// Porta de eventos (recebe input, resize, etc).
     event_port: Port,
+    /// SHM do ícone atual (lida pelo compositor).
+    icon: Option<SharedMemory>,
+    /// Conexão compartilhada (janelas de `Connection`).
+    connection: Option<Rc<Shared>>,
+    /// Escala do monitor atual (atualizada por `ScaleChanged`).
+    scale: Cell<ScaleFactor>,
+    /// Ponteiro travado (atualizado por `PointerLock`).
+    pointer_locked: Cell<bool>,
 }
 
 impl Window {
@@ -44,23 +69,48 @@ impl Window {
         height: u32,
         flags: WindowFlags,
         title: &str,
-    ) -> SysResult<Self> {
+    ) -> WindowResult<Self> {
         Self::create_internal(x, y, width, height, flags.bits(), title)
     }
 
     /// Cria nova janela padrão.
-    pub fn create(x: u32, y: u32, width: u32, height: u32, title: &str) -> SysResult<Self> {
+    pub fn create(x: u32, y: u32, width: u32, height: u32, title: &str) -> WindowResult<Self> {
         Self::create_internal(x, y, width, height, 0, title)
     }
 
-    fn create_internal(
+    pub(super) fn create_internal(
         x: u32,
         y: u32,
         width: u32,
         height: u32,
         flags: u32,
         title: &str,
-    ) -> SysResult<Self> {
+    ) -> WindowResult<Self> {
+        let mut title_buf = [0u8; 64];
+        let bytes = title.as_bytes();
+        let len = bytes.len().min(64);
+        title_buf[..len].copy_from_slice(&bytes[..len]);
+
+        Self::create_with(width, height, flags, |reply_port| CreateWindowRequest {
+            op: opcodes::CREATE_WINDOW,
+            x,
+            y,
+            width,
+            height,
+            flags,
+            reply_port,
+            title: title_buf,
+        })
+    }
+
+    /// Cria a porta de eventos, envia o request montado por `build` (que
+    /// recebe o nome da porta) e mapeia o buffer do `WINDOW_CREATED`.
+    pub(super) fn create_with<R: Copy>(
+        width: u32,
+        height: u32,
+        flags: u32,
+        build: impl FnOnce([u8; 32]) -> R,
+    ) -> WindowResult<Self> {
         // 1. Criar porta de resposta única
         let event_port;
         let mut port_name_buf = [0u8; 32];
@@ -112,7 +162,7 @@ impl Window {
                 Err(_) => {
                     seed += 1;
                     if seed > 100 {
-                        return Err(SysError::AlreadyExists);
+                        return Err(SysError::AlreadyExists.into());
                     }
                 }
             }
@@ -122,30 +172,7 @@ impl Window {
         let status_port = Port::connect(COMPOSITOR_PORT)?;
 
         // 3. Enviar request
-        let mut title_buf = [0u8; 64];
-        let bytes = title.as_bytes();
-        let len = bytes.len().min(64);
-        for i in 0..len {
-            title_buf[i] = bytes[i];
-        }
-
-        let req = CreateWindowRequest {
-            op: opcodes::CREATE_WINDOW,
-            x,
-            y,
-            width,
-            height,
-            flags,
-            reply_port: port_name_buf,
-            title: title_buf,
-        };
-
-        let req_bytes = unsafe {
-            core::slice::from_raw_parts(
-                &req as *const _ as *const u8,
-                core::mem::size_of::<CreateWindowRequest>(),
-            )
-        };
+        let req = build(port_name_buf);
 
         crate::println!(
             "[RedPower] Enviando CREATE_WINDOW ({}x{}, flags={:#x})...",
@@ -153,7 +180,7 @@ impl Window {
             height,
             flags
         );
-        status_port.send(req_bytes, 0)?;
+        status_port.send(as_bytes(&req), 0)?;
 
         // 4. Receber response
         let mut resp_msg = ProtocolMessage {
@@ -166,16 +193,22 @@ impl Window {
             )
         };
 
-        match event_port.recv(resp_bytes, 10000) {
-            Ok(len) if len < core::mem::size_of::<WindowCreatedResponse>() => {
-                crate::println!("[RedPower] Erro: Resposta muito curta (len={})", len);
-                return Err(SysError::ProtocolError);
-            }
+        let len = match event_port.recv(resp_bytes, 10000) {
+            Ok(len) => len,
             Err(e) => {
                 crate::println!("[RedPower] Erro ao receber resposta: {:?}", e);
-                return Err(e);
+                return Err(e.into());
             }
-            Ok(_) => {}
+        };
+
+        if unsafe { resp_msg.header } == opcodes::ERROR {
+            return Err(WindowError::from_response(unsafe { &resp_msg.error }));
+        }
+
+        // Compositores sem escala enviam a resposta sem `scale_milli`
+        if len < core::mem::offset_of!(WindowCreatedResponse, scale_milli) {
+            crate::println!("[RedPower] Erro: Resposta muito curta (len={})", len);
+            return Err(SysError::ProtocolError.into());
         }
 
         let resp = unsafe { resp_msg.win_resp };
@@ -185,7 +218,7 @@ impl Window {
                 "[RedPower] Erro: Opcode inválido na resposta (op={})",
                 resp.op
             );
-            return Err(SysError::ProtocolError);
+            return Err(SysError::ProtocolError.into());
         }
 
         // 5. Mapear SHM
@@ -198,6 +231,32 @@ impl Window {
             height,
             compositor_port: status_port,
             event_port,
+            icon: None,
+            connection: None,
+            scale: Cell::new(ScaleFactor::from_milli(resp.scale_milli)),
+            pointer_locked: Cell::new(false),
+        })
+    }
+
+    /// Janela criada por uma [`Connection`](super::Connection): usa as
+    /// portas da conexão e recebe eventos pela fila dela.
+    pub(super) fn from_connection(
+        resp: WindowCreatedResponse,
+        width: u32,
+        height: u32,
+        connection: Rc<Shared>,
+    ) -> SysResult<Self> {
+        Ok(Self {
+            id: resp.window_id,
+            shm: SharedMemory::open(ShmId(resp.shm_handle))?,
+            width,
+            height,
+            compositor_port: connection.compositor.clone(),
+            event_port: connection.events.clone(),
+            icon: None,
+            connection: Some(connection),
+            scale: Cell::new(ScaleFactor::from_milli(resp.scale_milli)),
+            pointer_locked: Cell::new(false),
         })
     }
 
@@ -229,6 +288,19 @@ impl Window {
         Rect::new(0, 0, self.width, self.height)
     }
 
+    /// Escala do monitor onde a janela está; o buffer está em pixels
+    /// físicos.
+    #[inline]
+    pub fn scale(&self) -> ScaleFactor {
+        self.scale.get()
+    }
+
+    /// Tamanho em coordenadas lógicas.
+    #[inline]
+    pub fn logical_size(&self) -> Size {
+        self.scale.get().size_to_logical(self.size())
+    }
+
     // =========================================================================
     // BUFFER
     // =========================================================================
@@ -299,26 +371,142 @@ impl Window {
 
     /// Notifica compositor que uma região foi atualizada.
     pub fn present_region(&self, dirty: Rect) -> SysResult<()> {
-        let req = CommitBufferRequest {
-            op: opcodes::COMMIT_BUFFER,
+        self.commit_buffer(0, dirty)
+    }
+
+    /// Notifica compositor das áreas de uma [`DamageRegion`].
+    ///
+    /// Regiões muito fragmentadas são enviadas como o bounding box.
+    pub fn present_damage(&self, damage: &DamageRegion) -> SysResult<()> {
+        if damage.len() > MAX_COMMIT_RECTS {
+            return self.present_region(damage.bounds());
+        }
+        for rect in damage {
+            self.present_region(rect)?;
+        }
+        Ok(())
+    }
+
+    /// Pede um [`Event::Frame`](crate::event::Event::Frame) quando o
+    /// próximo frame da janela for apresentado (disparo único).
+    pub fn request_frame(&self) -> SysResult<()> {
+        self.send_op_request(opcodes::REQUEST_FRAME)
+    }
+
+    /// Habilita [`Event::TextInput`](crate::event::Event::TextInput) (ex.:
+    /// campo de texto focado); `cursor` posiciona candidatos do IME.
+    pub fn start_text_input(&self, cursor: Rect) -> SysResult<()> {
+        self.send_text_input(true, cursor)
+    }
+
+    /// Desabilita a entrada de texto (campo perdeu o foco).
+    pub fn stop_text_input(&self) -> SysResult<()> {
+        self.send_text_input(false, Rect::new(0, 0, 0, 0))
+    }
+
+    fn send_text_input(&self, enabled: bool, cursor: Rect) -> SysResult<()> {
+        let req = SetTextInputRequest {
+            op: opcodes::SET_TEXT_INPUT,
             window_id: self.id,
-            x: dirty.x as u32,
-            y: dirty.y as u32,
-            width: dirty.width,
-            height: dirty.height,
+            enabled: enabled as u32,
+            cursor_x: cursor.x,
+            cursor_y: cursor.y,
+            cursor_width: cursor.width,
+            cursor_height: cursor.height,
         };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
 
-        let req_bytes = unsafe {
-            core::slice::from_raw_parts(
-                &req as *const _ as *const u8,
-                core::mem::size_of::<CommitBufferRequest>(),
-            )
+    /// Injeta um evento de input nesta janela (compositor em modo de teste).
+    ///
+    /// Usado por [`input::record::Player`](crate::input::record::Player).
+    pub fn inject_input(&self, event: &InputEvent) -> SysResult<()> {
+        let req = InjectInputRequest {
+            op: opcodes::TEST_INJECT_INPUT,
+            window_id: self.id,
+            event_type: event.event_type,
+            param1: event.param1,
+            param2: event.param2,
         };
-
-        self.compositor_port.send(req_bytes, 0)?;
+        self.compositor_port.send(as_bytes(&req), 0)?;
         Ok(())
     }
 
+    // =========================================================================
+    // MONITOR
+    // =========================================================================
+
+    /// Monitor onde a janela está (o de maior área visível).
+    ///
+    /// Apenas `id`, tamanho e `refresh_rate_mhz` são preenchidos.
+    pub fn output_info(&self) -> SysResult<DisplayInfo> {
+        let (reply_port, reply_name) = Port::create_unique("win.o.", 4)?;
+        let req = QueryOutputRequest {
+            op: opcodes::QUERY_OUTPUT,
+            window_id: self.id,
+            reply_port: reply_name,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+
+        let mut msg = ProtocolMessage {
+            raw: [0; MAX_MSG_SIZE],
+        };
+        if reply_port.recv(as_bytes_mut(&mut msg), 2000)? == 0 {
+            return Err(SysError::Timeout);
+        }
+        match unsafe { msg.header } {
+            opcodes::OUTPUT_INFO => {
+                let info = unsafe { msg.output_resp };
+                Ok(DisplayInfo::new(
+                    info.output_id,
+                    info.width,
+                    info.height,
+                    info.refresh_rate_mhz,
+                    PixelFormat::default(),
+                    0,
+                ))
+            }
+            opcodes::ERROR => Err(WindowError::from_response(unsafe { &msg.error }).into()),
+            _ => Err(SysError::ProtocolError),
+        }
+    }
+
+    /// Posição, tamanho e tiling atuais (ver [`WindowGeometry`]).
+    pub fn geometry(&self) -> WindowResult<WindowGeometry> {
+        let (reply_port, reply_name) = Port::create_unique("win.g.", 4)?;
+        let req = QueryGeometryRequest {
+            op: opcodes::QUERY_GEOMETRY,
+            window_id: self.id,
+            reply_port: reply_name,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+
+        let mut msg = ProtocolMessage {
+            raw: [0; MAX_MSG_SIZE],
+        };
+        if reply_port.recv(as_bytes_mut(&mut msg), 2000)? == 0 {
+            return Err(SysError::Timeout.into());
+        }
+        match unsafe { msg.header } {
+            opcodes::GEOMETRY => {
+                let geo = unsafe { msg.geometry_resp };
+                Ok(WindowGeometry {
+                    rect: Rect::new(geo.x, geo.y, geo.width, geo.height),
+                    tiling: TilingState::from_u32(geo.tiling),
+                })
+            }
+            opcodes::ERROR => Err(WindowError::from_response(unsafe { &msg.error })),
+            _ => Err(SysError::ProtocolError.into()),
+        }
+    }
+
+    /// Conteúdo apresentado da janela, copiado pelo compositor (para
+    /// screenshots e testes de UI).
+    pub fn capture(&self) -> WindowResult<ImageBuffer> {
+        super::capture::capture_window(self.id)
+    }
+
     // =========================================================================
     // EVENTOS
     // =========================================================================
@@ -326,27 +514,23 @@ impl Window {
     /// Lê eventos da fila (não bloqueante).
     pub fn poll_events(&self) -> impl Iterator<Item = crate::event::Event> + '_ {
         core::iter::from_fn(move || {
+            if let Some(connection) = &self.connection {
+                return connection.next_event(self.id);
+            }
+
             let mut msg = ProtocolMessage {
                 raw: [0; MAX_MSG_SIZE],
             };
-            let msg_bytes = unsafe {
-                core::slice::from_raw_parts_mut(
-                    &mut msg as *mut _ as *mut u8,
-                    core::mem::size_of::<ProtocolMessage>(),
-                )
-            };
-
-            match self.event_port.recv(msg_bytes, 0) {
-                Ok(len) if len > 0 => unsafe {
-                    match msg.header {
-                        opcodes::EVENT_INPUT => Some(crate::event::Event::Input(msg.input_evt)),
-                        opcodes::EVENT_RESIZE => Some(crate::event::Event::Resize(msg.resize_evt)),
-                        _ => Some(crate::event::Event::Unknown),
-                    }
-                },
+            match self.event_port.recv(as_bytes_mut(&mut msg), 0) {
+                Ok(len) if len > 0 => Some(decode_event(&msg)),
                 _ => None,
             }
         })
+        .inspect(|event| match event {
+            crate::event::Event::ScaleChanged(change) => self.scale.set(change.scale()),
+            crate::event::Event::PointerLock(lock) => self.pointer_locked.set(lock.is_locked()),
+            _ => {}
+        })
     }
 
     // =========================================================================
@@ -381,6 +565,218 @@ impl Window {
         self.send_op_request(opcodes::RESTORE_WINDOW)
     }
 
+    /// Pede para entrar (ou sair) de fullscreen.
+    ///
+    /// A decisão chega como [`Event::StateChanged`](crate::event::Event::StateChanged).
+    pub fn set_fullscreen(&self, enabled: bool) -> SysResult<()> {
+        let req = SetFullscreenRequest {
+            op: opcodes::SET_FULLSCREEN,
+            window_id: self.id,
+            enabled: enabled as u32,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Pede para maximizar (confirmado por `Event::StateChanged`).
+    pub fn maximize(&self) -> SysResult<()> {
+        self.send_op_request(opcodes::MAXIMIZE_WINDOW)
+    }
+
+    /// Volta de maximizada para a geometria anterior.
+    pub fn unmaximize(&self) -> SysResult<()> {
+        self.send_op_request(opcodes::UNMAXIMIZE_WINDOW)
+    }
+
+    /// Move a janela para `(x, y)` (coordenadas de tela).
+    pub fn move_to(&self, x: i32, y: i32) -> SysResult<()> {
+        let req = MoveWindowRequest {
+            op: opcodes::MOVE_WINDOW,
+            window_id: self.id,
+            x,
+            y,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Pede novo tamanho ao compositor.
+    ///
+    /// O tamanho efetivo chega como [`Event::Resize`](crate::event::Event::Resize).
+    pub fn resize(&self, width: u32, height: u32) -> SysResult<()> {
+        if width == 0 || height == 0 {
+            return Err(SysError::InvalidArgument);
+        }
+        let req = ResizeWindowRequest {
+            op: opcodes::RESIZE_WINDOW,
+            window_id: self.id,
+            width,
+            height,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Inicia um arraste da janela conduzido pelo compositor.
+    ///
+    /// Para decorações desenhadas pelo app: chamar ao receber `MOUSE_DOWN`
+    /// na barra de título. O compositor segue o ponteiro até o botão ser
+    /// solto (com snap e tiling como nas bordas do servidor).
+    pub fn begin_interactive_move(&self) -> SysResult<()> {
+        self.send_op_request(opcodes::BEGIN_INTERACTIVE_MOVE)
+    }
+
+    /// Inicia um redimensionamento pela borda `edge` conduzido pelo
+    /// compositor (ver [`begin_interactive_move`](Self::begin_interactive_move)).
+    pub fn begin_interactive_resize(&self, edge: ResizeEdge) -> SysResult<()> {
+        let req = InteractiveResizeRequest {
+            op: opcodes::BEGIN_INTERACTIVE_RESIZE,
+            window_id: self.id,
+            edge: edge as u32,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Sugere um estado de tiling ao compositor.
+    ///
+    /// O estado efetivo chega como [`Event::Tiling`](crate::event::Event::Tiling).
+    pub fn set_tiling_hint(&self, state: TilingState) -> SysResult<()> {
+        let req = SetTilingHintRequest {
+            op: opcodes::SET_TILING_HINT,
+            window_id: self.id,
+            state: state.as_u32(),
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // TÍTULO E PROPRIEDADES
+    // =========================================================================
+
+    /// Cursor mostrado com o ponteiro sobre a janela (ex.: `Text` sobre um
+    /// campo, `ResizeEW` sobre um divisor). Vale até a próxima troca.
+    pub fn set_cursor(&self, cursor: CursorType) -> SysResult<()> {
+        let req = SetCursorRequest {
+            op: opcodes::SET_CURSOR,
+            window_id: self.id,
+            cursor: cursor as u32,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Pede para travar o ponteiro na janela (jogos, visualizadores 3D).
+    ///
+    /// Concedido, o cursor some e fica confinado, e o movimento chega como
+    /// `InputEvent` `MOUSE_RELATIVE` (ver [`InputEvent::relative_motion`]).
+    /// A resposta vem em [`Event::PointerLock`](crate::event::Event::PointerLock);
+    /// o compositor só concede à janela focada e libera com Esc.
+    ///
+    /// [`InputEvent::relative_motion`]: crate::event::InputEvent::relative_motion
+    pub fn lock_pointer(&self) -> SysResult<()> {
+        self.send_pointer_lock(true)
+    }
+
+    /// Libera o ponteiro travado.
+    pub fn unlock_pointer(&self) -> SysResult<()> {
+        self.send_pointer_lock(false)?;
+        self.pointer_locked.set(false);
+        Ok(())
+    }
+
+    /// Ponteiro travado nesta janela.
+    pub fn is_pointer_locked(&self) -> bool {
+        self.pointer_locked.get()
+    }
+
+    fn send_pointer_lock(&self, locked: bool) -> SysResult<()> {
+        let req = SetPointerLockRequest {
+            op: opcodes::SET_POINTER_LOCK,
+            window_id: self.id,
+            locked: locked as u32,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Troca o título (truncado em [`MAX_TITLE_LEN`] bytes).
+    pub fn set_title(&self, title: &str) -> SysResult<()> {
+        let req = SetTitleRequest {
+            op: opcodes::SET_TITLE,
+            window_id: self.id,
+            title: fixed_str(title),
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Troca o ícone (taskbar, switcher); até [`MAX_ICON_SIZE`] por lado.
+    pub fn set_icon(&mut self, icon: &ImageBuffer) -> SysResult<()> {
+        let (width, height) = (icon.width(), icon.height());
+        if width == 0 || height == 0 || width > MAX_ICON_SIZE || height > MAX_ICON_SIZE {
+            return Err(SysError::InvalidArgument);
+        }
+
+        let pixels = icon.pixels();
+        let mut shm = SharedMemory::create(pixels.len() * 4)?;
+        for (dst, px) in shm.as_mut_slice().chunks_exact_mut(4).zip(pixels) {
+            dst.copy_from_slice(&px.to_ne_bytes());
+        }
+
+        let req = SetIconRequest {
+            op: opcodes::SET_ICON,
+            window_id: self.id,
+            shm_handle: shm.id().0,
+            width,
+            height,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+
+        // O compositor lê o ícone da SHM até o próximo SET_ICON
+        self.icon = Some(shm);
+        Ok(())
+    }
+
+    /// Define uma propriedade (ver [`window_properties`]); valor vazio
+    /// remove.
+    pub fn set_property(&self, key: &str, value: &str) -> SysResult<()> {
+        let req = SetPropertyRequest {
+            op: opcodes::SET_PROPERTY,
+            window_id: self.id,
+            key: fixed_str(key),
+            value: fixed_str(value),
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
+    /// Porta de eventos (para o event loop).
+    pub(super) fn event_port(&self) -> &Port {
+        &self.event_port
+    }
+
+    /// Porta do compositor (para a swapchain).
+    pub(super) fn compositor_port(&self) -> &Port {
+        &self.compositor_port
+    }
+
+    /// Envia o commit de `dirty` no buffer `buffer_index`.
+    pub(super) fn commit_buffer(&self, buffer_index: u32, dirty: Rect) -> SysResult<()> {
+        let req = CommitBufferRequest {
+            op: opcodes::COMMIT_BUFFER,
+            window_id: self.id,
+            x: dirty.x as u32,
+            y: dirty.y as u32,
+            width: dirty.width,
+            height: dirty.height,
+            buffer_index,
+        };
+        self.compositor_port.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+
     fn send_op_request(&self, op: u32) -> SysResult<()> {
         let req = WindowOpRequest {
             op,
@@ -399,8 +795,44 @@ impl Window {
     }
 }
 
+/// Converte uma mensagem de evento do compositor.
+pub(super) fn decode_event(msg: &ProtocolMessage) -> crate::event::Event {
+    use crate::event::Event;
+
+    unsafe {
+        match msg.header {
+            opcodes::EVENT_INPUT => Event::Input(msg.input_evt),
+            opcodes::EVENT_RESIZE => Event::Resize(msg.resize_evt),
+            opcodes::EVENT_FRAME => Event::Frame(msg.frame_evt),
+            opcodes::EVENT_TILING => Event::Tiling(msg.tiling_evt),
+            opcodes::EVENT_STATE_CHANGED => Event::StateChanged(msg.state_evt),
+            opcodes::EVENT_SCALE_CHANGED => Event::ScaleChanged(msg.scale_evt),
+            opcodes::EVENT_FOCUS => Event::Focus(msg.focus_evt),
+            opcodes::EVENT_TEXT_INPUT => Event::TextInput(msg.text_evt),
+            opcodes::EVENT_TOUCH => Event::Touch(msg.touch_evt),
+            opcodes::EVENT_GAMEPAD => Event::Gamepad(msg.gamepad_evt),
+            opcodes::EVENT_POINTER_LOCK => Event::PointerLock(msg.pointer_lock_evt),
+            opcodes::EVENT_DND => Event::Dnd(msg.dnd_evt),
+            opcodes::EVENT_WINDOW_LIFECYCLE
+                if msg.lifecycle_evt.event_type == lifecycle_events::CLOSE_REQUESTED =>
+            {
+                Event::Close
+            }
+            opcodes::EVENT_WINDOW_LIFECYCLE
+                if msg.lifecycle_evt.event_type == lifecycle_events::POPUP_DISMISSED =>
+            {
+                Event::PopupDismissed
+            }
+            _ => Event::Unknown,
+        }
+    }
+}
+
 impl Drop for Window {
     fn drop(&mut self) {
         let _ = self.destroy();
+        if let Some(connection) = &self.connection {
+            connection.detach(self.id);
+        }
     }
 }
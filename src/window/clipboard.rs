@@ -0,0 +1,238 @@
+//! # Clipboard
+//!
+//! Copiar e colar entre apps, mediado pelo compositor.
+//!
+//! O conteúdo pode ter várias representações (tipos MIME); quem cola pede
+//! os tipos que entende em ordem de preferência. Dados pequenos vão na
+//! própria mensagem e os grandes via memória compartilhada.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::clipboard;
+//!
+//! clipboard::set_text("olá")?;
+//! let text = clipboard::get_text()?;
+//!
+//! // Imagem com texto alternativo
+//! clipboard::set_many(&[("image/png", &png), (clipboard::TEXT_PLAIN, b"logo")])?;
+//!
+//! let changes = clipboard::events()?;
+//! while changes.wait(u64::MAX).is_some() {
+//!     paste_button.set_enabled(clipboard::get_text().is_ok());
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ipc::{
+    as_bytes, as_bytes_mut, fixed_str, recv_reply, str_from_fixed, Port, SharedMemory, ShmId,
+};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do compositor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Tipo MIME de texto (UTF-8).
+pub const TEXT_PLAIN: &str = "text/plain;charset=utf-8";
+
+/// Conteúdo lido do clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardData {
+    /// Tipo MIME da representação entregue.
+    pub mime: String,
+    pub data: Vec<u8>,
+}
+
+// =============================================================================
+// ESCRITA
+// =============================================================================
+
+/// Copia texto para o clipboard.
+pub fn set_text(text: &str) -> SysResult<()> {
+    set(TEXT_PLAIN, text.as_bytes())
+}
+
+/// Substitui o conteúdo do clipboard por `data` do tipo `mime`.
+pub fn set(mime: &str, data: &[u8]) -> SysResult<()> {
+    set_many(&[(mime, data)])
+}
+
+/// Substitui o conteúdo por várias representações do mesmo dado.
+pub fn set_many(items: &[(&str, &[u8])]) -> SysResult<()> {
+    if items.is_empty() {
+        return Err(SysError::InvalidArgument);
+    }
+    for (index, (mime, data)) in items.iter().enumerate() {
+        let flags = if index == 0 {
+            0
+        } else {
+            clipboard_flags::APPEND
+        };
+        send_item(mime, data, flags)?;
+    }
+    Ok(())
+}
+
+fn send_item(mime: &str, data: &[u8], flags: u32) -> SysResult<()> {
+    let len = u32::try_from(data.len()).map_err(|_| SysError::InvalidArgument)?;
+    let (reply_port, reply_name) = Port::create_unique("win.clip.", 4)?;
+    let mut req = ClipboardSetRequest {
+        op: opcodes::CLIPBOARD_SET,
+        flags,
+        mime: fixed_str(mime),
+        len,
+        _pad: 0,
+        shm_handle: 0,
+        reply_port: reply_name,
+        data: [0; CLIPBOARD_INLINE_MAX],
+    };
+
+    // A SHM precisa viver até o compositor confirmar a cópia
+    let _shm = if data.len() <= CLIPBOARD_INLINE_MAX {
+        req.data[..data.len()].copy_from_slice(data);
+        None
+    } else {
+        let mut shm = SharedMemory::create(data.len())?;
+        shm.as_mut_slice()[..data.len()].copy_from_slice(data);
+        req.shm_handle = shm.id().0;
+        Some(shm)
+    };
+
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    recv_reply::<ProtocolMessage>(&reply_port, opcodes::CLIPBOARD_STORED, 0, REPLY_TIMEOUT_MS)?;
+    Ok(())
+}
+
+// =============================================================================
+// LEITURA
+// =============================================================================
+
+/// Lê o texto do clipboard (`NotFound` se não há texto).
+pub fn get_text() -> SysResult<String> {
+    let clip = get(&[TEXT_PLAIN, "text/plain"])?;
+    String::from_utf8(clip.data).map_err(|_| SysError::InvalidArgument)
+}
+
+/// Lê a primeira representação disponível dentre `mimes` (em ordem de
+/// preferência, até [`CLIPBOARD_TYPES_PER_MSG`]).
+pub fn get(mimes: &[&str]) -> SysResult<ClipboardData> {
+    if mimes.is_empty() || mimes.len() > CLIPBOARD_TYPES_PER_MSG {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let (reply_port, reply_name) = Port::create_unique("win.clip.", 4)?;
+    let mut req = ClipboardGetRequest {
+        op: opcodes::CLIPBOARD_GET,
+        count: mimes.len() as u32,
+        mimes: [[0; 32]; CLIPBOARD_TYPES_PER_MSG],
+        reply_port: reply_name,
+    };
+    for (slot, mime) in req.mimes.iter_mut().zip(mimes) {
+        *slot = fixed_str(mime);
+    }
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = unsafe {
+        recv_reply::<ProtocolMessage>(
+            &reply_port,
+            opcodes::CLIPBOARD_DATA,
+            core::mem::size_of::<ClipboardDataResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?
+        .clip_data
+    };
+    let len = resp.len as usize;
+    let data = if resp.shm_handle == 0 {
+        let inline = resp.data.get(..len).ok_or(SysError::ProtocolError)?;
+        inline.to_vec()
+    } else {
+        let shm = SharedMemory::open(ShmId(resp.shm_handle))?;
+        let shared = shm.as_slice().get(..len).ok_or(SysError::ProtocolError)?;
+        shared.to_vec()
+    };
+
+    Ok(ClipboardData {
+        mime: String::from(str_from_fixed(&resp.mime)),
+        data,
+    })
+}
+
+/// Tipos MIME disponíveis no clipboard (vazio se ele está vazio).
+pub fn types() -> SysResult<Vec<String>> {
+    let (reply_port, reply_name) = Port::create_unique("win.clip.", 4)?;
+    let req = ClipboardQueryTypesRequest {
+        op: opcodes::CLIPBOARD_QUERY_TYPES,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = unsafe {
+        recv_reply::<ProtocolMessage>(
+            &reply_port,
+            opcodes::CLIPBOARD_TYPES,
+            core::mem::size_of::<ClipboardTypesResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?
+        .clip_types
+    };
+    let count = (resp.count as usize).min(CLIPBOARD_TYPES_PER_MSG);
+    Ok(resp.mimes[..count]
+        .iter()
+        .map(|mime| String::from(str_from_fixed(mime)))
+        .collect())
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Receptor de mudanças do clipboard.
+pub struct ClipboardListener {
+    port: Port,
+}
+
+/// Registra um receptor de mudanças do clipboard.
+pub fn events() -> SysResult<ClipboardListener> {
+    let (port, name) = Port::create_unique("win.clipev.", 8)?;
+    let req = SubscribeClipboardRequest {
+        op: opcodes::SUBSCRIBE_CLIPBOARD,
+        listener_port: name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(ClipboardListener { port })
+}
+
+impl ClipboardListener {
+    /// Retorna o serial da próxima mudança pendente, sem bloquear.
+    pub fn poll(&self) -> Option<u32> {
+        self.wait(0)
+    }
+
+    /// Espera uma mudança por até `timeout_ms`; retorna seu serial.
+    pub fn wait(&self, timeout_ms: u64) -> Option<u32> {
+        loop {
+            let mut msg = ProtocolMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<ClipboardEvent>() {
+                return None;
+            }
+            // Mensagem desconhecida: descartar e continuar
+            if unsafe { msg.header } == opcodes::EVENT_CLIPBOARD {
+                return Some(unsafe { msg.clipboard_evt }.serial);
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,262 @@
+//! # Connection
+//!
+//! Uma conexão com o compositor para várias janelas.
+//!
+//! Por padrão cada [`Window`] abre sua própria porta de eventos. Apps com
+//! muitas janelas (editor com paletas, IDE) podem usar uma única
+//! `Connection`: as janelas compartilham a porta do compositor e uma porta
+//! de eventos, e os eventos são separados por `window_id` (ver
+//! [`RoutedEvent`](super::protocol::RoutedEvent)).
+//!
+//! Cada janela continua lendo os seus com [`Window::poll_events`]; eventos
+//! de outras janelas lidos no caminho ficam na fila da conexão. Com
+//! [`EventLoop`](super::EventLoop), janelas de uma mesma conexão funcionam
+//! sem nada especial.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::{Connection, EventLoop};
+//!
+//! let conn = Connection::open()?;
+//! let mut event_loop = EventLoop::new();
+//! event_loop.add_window(conn.create_window(100, 100, 800, 600, "Editor")?);
+//! event_loop.add_window(conn.create_window(920, 100, 240, 600, "Camadas")?);
+//! event_loop.on_input(|ctl, ev| handle(ctl.window_index(), ev));
+//! event_loop.run()?;
+//! ```
+
+extern crate alloc;
+
+use gfx_types::window::WindowFlags;
+
+use crate::syscall::SysResult;
+
+use super::error::WindowResult;
+use super::Window;
+
+#[cfg(not(feature = "headless"))]
+use {
+    super::client::decode_event,
+    super::error::WindowError,
+    super::protocol::*,
+    crate::event::Event,
+    crate::ipc::{as_bytes, as_bytes_mut, fixed_str, Port},
+    crate::syscall::SysError,
+    alloc::collections::VecDeque,
+    alloc::rc::Rc,
+    alloc::vec::Vec,
+    core::cell::RefCell,
+};
+
+/// Capacidade da porta de eventos compartilhada.
+#[cfg(not(feature = "headless"))]
+const EVENT_PORT_CAPACITY: usize = 64;
+
+/// Espera pela resposta de criação (ms).
+#[cfg(not(feature = "headless"))]
+const CREATE_TIMEOUT_MS: u64 = 10_000;
+
+// =============================================================================
+// CONNECTION
+// =============================================================================
+
+/// Conexão compartilhada por várias janelas.
+pub struct Connection {
+    #[cfg(not(feature = "headless"))]
+    shared: Rc<Shared>,
+}
+
+impl Connection {
+    /// Conecta ao compositor.
+    #[cfg(not(feature = "headless"))]
+    pub fn open() -> SysResult<Self> {
+        let (events, name) = Port::create_unique("win.c.", EVENT_PORT_CAPACITY)?;
+        let compositor = Port::connect(COMPOSITOR_PORT)?;
+        Ok(Self {
+            shared: Rc::new(Shared {
+                compositor,
+                events,
+                name,
+                pending: RefCell::new(VecDeque::new()),
+                live: RefCell::new(Vec::new()),
+            }),
+        })
+    }
+
+    /// Sem compositor: as janelas headless já separam eventos por id.
+    #[cfg(feature = "headless")]
+    pub fn open() -> SysResult<Self> {
+        Ok(Self {})
+    }
+
+    /// Cria nova janela padrão nesta conexão.
+    pub fn create_window(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        title: &str,
+    ) -> WindowResult<Window> {
+        self.create(x, y, width, height, 0, title)
+    }
+
+    /// Cria nova janela com flags específicas nesta conexão.
+    pub fn create_window_with_flags(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        flags: WindowFlags,
+        title: &str,
+    ) -> WindowResult<Window> {
+        self.create(x, y, width, height, flags.bits(), title)
+    }
+
+    #[cfg(not(feature = "headless"))]
+    fn create(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        flags: u32,
+        title: &str,
+    ) -> WindowResult<Window> {
+        let shared = &self.shared;
+        let req = CreateWindowRequest {
+            op: opcodes::CREATE_WINDOW,
+            x,
+            y,
+            width,
+            height,
+            flags: flags | create_flags::ROUTED,
+            reply_port: shared.name,
+            title: fixed_str(title),
+        };
+        shared.compositor.send(as_bytes(&req), 0)?;
+
+        // Eventos de outras janelas podem chegar antes da resposta
+        loop {
+            let mut msg = ProtocolMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = shared
+                .events
+                .recv(as_bytes_mut(&mut msg), CREATE_TIMEOUT_MS)?;
+            if len == 0 {
+                return Err(SysError::Timeout.into());
+            }
+            match unsafe { msg.header } {
+                // Compositores sem escala enviam a resposta sem `scale_milli`
+                opcodes::WINDOW_CREATED
+                    if len < core::mem::offset_of!(WindowCreatedResponse, scale_milli) =>
+                {
+                    return Err(SysError::ProtocolError.into());
+                }
+                opcodes::WINDOW_CREATED => {
+                    let resp = unsafe { msg.win_resp };
+                    let window = Window::from_connection(resp, width, height, shared.clone())?;
+                    shared.live.borrow_mut().push(resp.window_id);
+                    return Ok(window);
+                }
+                opcodes::EVENT_ROUTED => shared.queue(&msg),
+                opcodes::ERROR => return Err(WindowError::from_response(unsafe { &msg.error })),
+                _ => {}
+            }
+        }
+    }
+
+    #[cfg(feature = "headless")]
+    fn create(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        flags: u32,
+        title: &str,
+    ) -> WindowResult<Window> {
+        Window::create_internal(x, y, width, height, flags, title)
+    }
+}
+
+// =============================================================================
+// ESTADO COMPARTILHADO
+// =============================================================================
+
+/// Portas e fila de eventos de uma conexão (uma referência por janela).
+#[cfg(not(feature = "headless"))]
+pub(super) struct Shared {
+    pub(super) compositor: Port,
+    pub(super) events: Port,
+    /// Nome da porta de eventos (`reply_port` das criações).
+    name: [u8; 32],
+    /// Eventos lidos da porta, aguardando a janela dona.
+    pending: RefCell<VecDeque<(u32, Event)>>,
+    /// Janelas vivas desta conexão; eventos das demais são descartados.
+    live: RefCell<Vec<u32>>,
+}
+
+#[cfg(not(feature = "headless"))]
+impl Shared {
+    /// Próximo evento de `window_id` (não bloqueante).
+    pub(super) fn next_event(&self, window_id: u32) -> Option<Event> {
+        {
+            let mut pending = self.pending.borrow_mut();
+            if let Some(index) = pending.iter().position(|(id, _)| *id == window_id) {
+                return pending.remove(index).map(|(_, event)| event);
+            }
+        }
+
+        loop {
+            let mut msg = ProtocolMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            match self.events.recv(as_bytes_mut(&mut msg), 0) {
+                Ok(len) if len > 0 => {}
+                _ => return None,
+            }
+            if unsafe { msg.header } != opcodes::EVENT_ROUTED {
+                continue;
+            }
+            let (id, event) = route(&msg);
+            if id == window_id {
+                return Some(event);
+            }
+            self.hold(id, event);
+        }
+    }
+
+    /// Guarda um `EVENT_ROUTED` para a janela dona.
+    fn queue(&self, msg: &ProtocolMessage) {
+        let (id, event) = route(msg);
+        self.hold(id, event);
+    }
+
+    /// Enfileira o evento se a janela `id` ainda existe.
+    fn hold(&self, id: u32, event: Event) {
+        if self.live.borrow().contains(&id) {
+            self.pending.borrow_mut().push_back((id, event));
+        }
+    }
+
+    /// Esquece a janela `window_id` (ao ser descartada) e seus eventos.
+    pub(super) fn detach(&self, window_id: u32) {
+        self.live.borrow_mut().retain(|&id| id != window_id);
+        self.pending.borrow_mut().retain(|(id, _)| *id != window_id);
+    }
+}
+
+/// Extrai janela e evento de um `EVENT_ROUTED`.
+#[cfg(not(feature = "headless"))]
+fn route(msg: &ProtocolMessage) -> (u32, Event) {
+    let routed = unsafe { msg.routed_evt };
+    let mut inner = ProtocolMessage {
+        raw: [0; MAX_MSG_SIZE],
+    };
+    unsafe { inner.raw[..routed.event.len()].copy_from_slice(&routed.event) };
+    (routed.window_id, decode_event(&inner))
+}
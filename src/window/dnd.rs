@@ -0,0 +1,260 @@
+//! # Drag and Drop
+//!
+//! Arrastar dados entre janelas (ex.: arquivos do gerenciador para o
+//! editor), mediado pelo compositor.
+//!
+//! ## Fluxo
+//!
+//! 1. A origem chama [`start_drag`] ao receber `MOUSE_DOWN` + movimento,
+//!    com os tipos MIME que sabe fornecer.
+//! 2. Janelas sob o ponteiro recebem [`DragEvent::Enter`]/[`DragEvent::Motion`]
+//!    e respondem com [`accept`] (ou [`reject`]).
+//! 3. No [`DragEvent::Drop`], o alvo chama [`receive`]; a origem recebe
+//!    [`DragEvent::Send`] e responde com [`send_data`]. Os dados trafegam
+//!    por memória compartilhada.
+//! 4. A origem recebe [`DragEvent::Finished`] (com a ação escolhida, ex.
+//!    apagar o original num `MOVE`) ou [`DragEvent::Cancelled`].
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::Event;
+//! use redpowder::window::dnd::{self, DragEvent, URI_LIST};
+//! use redpowder::window::dnd_actions;
+//!
+//! // Alvo (editor)
+//! for event in window.poll_events() {
+//!     let Event::Dnd(raw) = event else { continue };
+//!     match DragEvent::from_raw(&raw) {
+//!         Some(DragEvent::Enter { serial, offers, .. }) if offers.contains(URI_LIST) => {
+//!             dnd::accept(&window, serial, URI_LIST, dnd_actions::COPY)?;
+//!         }
+//!         Some(DragEvent::Drop { serial, .. }) => {
+//!             let uris = dnd::receive(serial, URI_LIST)?;
+//!             open_files(&uris);
+//!         }
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use gfx_types::geometry::Point;
+
+use crate::event::DndEvent;
+use crate::ipc::{as_bytes, fixed_str, recv_reply, str_from_fixed, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::protocol::*;
+use super::Window;
+
+/// Tempo máximo de espera pelos dados (a origem precisa responder) (ms).
+const DATA_TIMEOUT_MS: u64 = 5000;
+
+/// Lista de arquivos (`file:///...`, uma URI por linha).
+pub const URI_LIST: &str = "text/uri-list";
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Tipos MIME oferecidos num arraste.
+#[derive(Debug, Clone, Copy)]
+pub struct DragOffers {
+    mimes: [[u8; 32]; CLIPBOARD_TYPES_PER_MSG],
+    count: usize,
+}
+
+impl DragOffers {
+    /// Tipos oferecidos, em ordem de preferência da origem.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.mimes[..self.count].iter().map(|m| str_from_fixed(m))
+    }
+
+    /// O tipo é oferecido?
+    pub fn contains(&self, mime: &str) -> bool {
+        self.iter().any(|m| m == mime)
+    }
+}
+
+/// Evento de drag-and-drop decodificado.
+#[derive(Debug, Clone, Copy)]
+pub enum DragEvent {
+    Enter {
+        serial: u32,
+        pos: Point,
+        offers: DragOffers,
+        /// Ações permitidas pela origem ([`dnd_actions`]).
+        actions: u32,
+    },
+    Motion {
+        serial: u32,
+        pos: Point,
+    },
+    Leave {
+        serial: u32,
+    },
+    Drop {
+        serial: u32,
+        pos: Point,
+        /// Ação escolhida ([`dnd_actions`]).
+        action: u32,
+    },
+    /// A origem deve responder com [`send_data`] no tipo `mime`.
+    Send {
+        serial: u32,
+        mime: [u8; 32],
+    },
+    Finished {
+        serial: u32,
+        action: u32,
+    },
+    Cancelled {
+        serial: u32,
+    },
+}
+
+impl DragEvent {
+    /// Decodifica um [`Event::Dnd`](crate::event::Event::Dnd).
+    pub fn from_raw(raw: &DndEvent) -> Option<Self> {
+        let serial = raw.serial;
+        let pos = Point::new(raw.x, raw.y);
+        Some(match raw.event_type {
+            dnd_events::ENTER => Self::Enter {
+                serial,
+                pos,
+                offers: DragOffers {
+                    mimes: raw.mimes,
+                    count: (raw.mime_count as usize).min(CLIPBOARD_TYPES_PER_MSG),
+                },
+                actions: raw.actions,
+            },
+            dnd_events::MOTION => Self::Motion { serial, pos },
+            dnd_events::LEAVE => Self::Leave { serial },
+            dnd_events::DROP => Self::Drop {
+                serial,
+                pos,
+                action: raw.actions,
+            },
+            dnd_events::SEND => Self::Send {
+                serial,
+                mime: raw.mimes[0],
+            },
+            dnd_events::FINISHED => Self::Finished {
+                serial,
+                action: raw.actions,
+            },
+            dnd_events::CANCELLED => Self::Cancelled { serial },
+            _ => return None,
+        })
+    }
+}
+
+// =============================================================================
+// ORIGEM
+// =============================================================================
+
+/// Inicia um arraste oferecendo `mimes` (até [`CLIPBOARD_TYPES_PER_MSG`],
+/// em ordem de preferência) com as `actions` permitidas.
+///
+/// Deve ser chamado com o botão do mouse pressionado sobre a janela.
+pub fn start_drag(window: &Window, mimes: &[&str], actions: u32) -> SysResult<()> {
+    if mimes.is_empty() || mimes.len() > CLIPBOARD_TYPES_PER_MSG || actions == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+    let mut req = DndStartRequest {
+        op: opcodes::DND_START,
+        window_id: window.id,
+        actions,
+        mime_count: mimes.len() as u32,
+        mimes: [[0; 32]; CLIPBOARD_TYPES_PER_MSG],
+    };
+    for (slot, mime) in req.mimes.iter_mut().zip(mimes) {
+        *slot = fixed_str(mime);
+    }
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+/// Entrega os dados pedidos por [`DragEvent::Send`].
+pub fn send_data(serial: u32, data: &[u8]) -> SysResult<()> {
+    let len = u32::try_from(data.len()).map_err(|_| SysError::InvalidArgument)?;
+    let mut shm = SharedMemory::create(data.len().max(1))?;
+    shm.as_mut_slice()[..data.len()].copy_from_slice(data);
+
+    let (reply_port, reply_name) = Port::create_unique("win.dnd.", 4)?;
+    let req = DndSendDataRequest {
+        op: opcodes::DND_SEND_DATA,
+        serial,
+        len,
+        _pad: 0,
+        shm_handle: shm.id().0,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    // A SHM precisa viver até o compositor confirmar o repasse
+    recv_reply::<ProtocolMessage>(&reply_port, opcodes::DND_DATA_STORED, 0, DATA_TIMEOUT_MS)?;
+    Ok(())
+}
+
+// =============================================================================
+// ALVO
+// =============================================================================
+
+/// Aceita o drop no tipo `mime` com a ação `action` (em resposta a
+/// `Enter`/`Motion`).
+pub fn accept(window: &Window, serial: u32, mime: &str, action: u32) -> SysResult<()> {
+    let req = DndAcceptRequest {
+        op: opcodes::DND_ACCEPT,
+        window_id: window.id,
+        serial,
+        action,
+        mime: fixed_str(mime),
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+/// Recusa o drop na posição atual.
+pub fn reject(window: &Window, serial: u32) -> SysResult<()> {
+    accept(window, serial, "", 0)
+}
+
+/// Recebe os dados soltos no tipo `mime` (após [`DragEvent::Drop`]).
+pub fn receive(serial: u32, mime: &str) -> SysResult<Vec<u8>> {
+    let (reply_port, reply_name) = Port::create_unique("win.dnd.", 4)?;
+    let req = DndReceiveRequest {
+        op: opcodes::DND_RECEIVE,
+        serial,
+        mime: fixed_str(mime),
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = unsafe {
+        recv_reply::<ProtocolMessage>(
+            &reply_port,
+            opcodes::DND_DATA,
+            core::mem::size_of::<DndDataResponse>(),
+            DATA_TIMEOUT_MS,
+        )?
+        .dnd_data
+    };
+    let shm = SharedMemory::open(ShmId(resp.shm_handle))?;
+    let data = shm
+        .as_slice()
+        .get(..resp.len as usize)
+        .ok_or(SysError::ProtocolError)?;
+    Ok(data.to_vec())
+}
+
+/// Recebe texto solto (`text/plain`).
+pub fn receive_text(serial: u32) -> SysResult<String> {
+    let data = receive(serial, super::clipboard::TEXT_PLAIN)?;
+    String::from_utf8(data).map_err(|_| SysError::InvalidArgument)
+}
@@ -0,0 +1,147 @@
+//! # Window Error
+//!
+//! Erros detalhados do compositor: o que deu errado ([`WindowErrorKind`]) e
+//! qual request causou ([`WindowError::request_op`]).
+//!
+//! Converte-se em [`SysError`] com `?`, então funções que retornam
+//! `SysResult` continuam funcionando com as APIs de janela.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::{Window, WindowErrorKind};
+//!
+//! match Window::create(0, 0, 8000, 8000, "Grande") {
+//!     Ok(window) => run(window),
+//!     Err(e) if e.kind == WindowErrorKind::InvalidSize => retry_smaller(),
+//!     Err(e) => return Err(e.into()),
+//! }
+//! ```
+
+use crate::syscall::SysError;
+
+use super::protocol::{error_codes, opcodes, ErrorResponse};
+
+/// Resultado de operações de janela.
+pub type WindowResult<T> = Result<T, WindowError>;
+
+// =============================================================================
+// KIND
+// =============================================================================
+
+/// Motivo de um erro de janela.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowErrorKind {
+    /// A janela não existe (ou é de outro app).
+    InvalidWindow,
+    /// Tamanho zero ou acima do máximo do compositor.
+    InvalidSize,
+    /// Limite de janelas do app atingido.
+    TooManyWindows,
+    /// O compositor não conseguiu alocar o buffer compartilhado.
+    OutOfShm,
+    /// Falta permissão (shell, captura de tela, janela segura).
+    PermissionDenied,
+    /// Parâmetro inválido no request.
+    InvalidArgument,
+    /// Request não suportado por este compositor.
+    NotSupported,
+    /// Alvo do request não encontrado (workspace, monitor, tipo MIME).
+    NotFound,
+    /// Recurso ocupado (ex.: outra sessão de arraste).
+    Busy,
+    /// Request malformado (tamanho ou opcode).
+    Malformed,
+    /// Falha local (IPC, timeout, resposta inesperada).
+    Sys(SysError),
+    /// Código não conhecido por esta versão do SDK.
+    Unknown(u32),
+}
+
+impl WindowErrorKind {
+    /// Converte de [`error_codes`].
+    ///
+    /// Compositores antigos enviam um `SysError` negado; ele vira
+    /// [`Sys`](Self::Sys).
+    pub fn from_code(code: u32) -> Self {
+        if (code as i32) < 0 {
+            return Self::Sys(SysError::from_code(code as i32 as isize));
+        }
+        match code {
+            error_codes::INVALID_WINDOW => Self::InvalidWindow,
+            error_codes::INVALID_SIZE => Self::InvalidSize,
+            error_codes::TOO_MANY_WINDOWS => Self::TooManyWindows,
+            error_codes::OUT_OF_SHM => Self::OutOfShm,
+            error_codes::PERMISSION_DENIED => Self::PermissionDenied,
+            error_codes::INVALID_ARGUMENT => Self::InvalidArgument,
+            error_codes::NOT_SUPPORTED => Self::NotSupported,
+            error_codes::NOT_FOUND => Self::NotFound,
+            error_codes::BUSY => Self::Busy,
+            error_codes::MALFORMED => Self::Malformed,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// `SysError` equivalente.
+    pub fn to_sys_error(self) -> SysError {
+        match self {
+            Self::InvalidWindow => SysError::InvalidHandle,
+            Self::InvalidSize | Self::InvalidArgument => SysError::InvalidArgument,
+            Self::TooManyWindows => SysError::LimitReached,
+            Self::OutOfShm => SysError::OutOfMemory,
+            Self::PermissionDenied => SysError::PermissionDenied,
+            Self::NotSupported => SysError::NotSupported,
+            Self::NotFound => SysError::NotFound,
+            Self::Busy => SysError::Busy,
+            Self::Malformed | Self::Unknown(_) => SysError::ProtocolError,
+            Self::Sys(error) => error,
+        }
+    }
+}
+
+// =============================================================================
+// ERROR
+// =============================================================================
+
+/// Erro de uma operação de janela.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowError {
+    pub kind: WindowErrorKind,
+    /// Opcode do request recusado ([`opcodes`]); 0 para falhas locais.
+    pub request_op: u32,
+}
+
+impl WindowError {
+    /// Erro a partir da resposta `ERROR` do compositor.
+    pub fn from_response(resp: &ErrorResponse) -> Self {
+        Self {
+            kind: WindowErrorKind::from_code(resp.code),
+            request_op: resp.request_op,
+        }
+    }
+
+    /// Erro local (sem request associado).
+    pub const fn sys(error: SysError) -> Self {
+        Self {
+            kind: WindowErrorKind::Sys(error),
+            request_op: 0,
+        }
+    }
+
+    /// Opcode do request recusado, se o erro veio do compositor.
+    pub fn request_op(&self) -> Option<u32> {
+        (self.request_op != 0 && self.request_op != opcodes::ERROR).then_some(self.request_op)
+    }
+}
+
+impl From<SysError> for WindowError {
+    fn from(error: SysError) -> Self {
+        Self::sys(error)
+    }
+}
+
+impl From<WindowError> for SysError {
+    fn from(error: WindowError) -> Self {
+        error.kind.to_sys_error()
+    }
+}
@@ -0,0 +1,404 @@
+//! # Event Loop
+//!
+//! Loop de eventos para apps gráficos: é dono das janelas, multiplexa os
+//! eventos do compositor, timers e fontes registradas pelo app (portas,
+//! sockets, [`EventFd`](crate::event::EventFd)...) num único `SYS_POLL` e
+//! chama os callbacks correspondentes.
+//!
+//! [`EventLoop::run`] bloqueia a thread; [`EventLoop::run_async`] suspende a
+//! tarefa no executor de [`task`](crate::task), para apps que também
+//! atendem outras conexões.
+//!
+//! Sem callback `on_close`, um pedido de fechamento encerra o loop.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use redpowder::window::{EventLoop, Window};
+//!
+//! let mut event_loop = EventLoop::new();
+//! event_loop.add_window(Window::create(100, 100, 320, 240, "Relógio")?);
+//!
+//! event_loop
+//!     .on_input(|ctl, ev| handle_input(ctl.window(), ev))
+//!     .on_frame(|ctl, _| {
+//!         let win = ctl.window();
+//!         draw(win);
+//!         let _ = win.present();
+//!     })
+//!     .add_timer(Duration::from_secs(1), |ctl, _| {
+//!         let _ = ctl.window().request_frame();
+//!     })?;
+//!
+//! event_loop.run()?;
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use crate::event::{
+    events, poll, Event, FrameEvent, InputEvent, PollFd, ResizeEvent, Source, TouchEvent,
+};
+use crate::io::Handle;
+use crate::ipc::Port;
+use crate::syscall::SysResult;
+use crate::task::{ready, Ready};
+use crate::time::Interval;
+
+use super::Window;
+
+type Handler<'a, T> = Box<dyn FnMut(&mut LoopControl<'_>, T) + 'a>;
+type SourceHandler<'a> = Box<dyn FnMut(&mut LoopControl<'_>) + 'a>;
+
+// =============================================================================
+// CONTROLE
+// =============================================================================
+
+/// Acesso às janelas e controle do loop dentro dos callbacks.
+pub struct LoopControl<'l> {
+    windows: &'l mut [Window],
+    current: usize,
+    exit: bool,
+}
+
+impl LoopControl<'_> {
+    /// Janela que originou o evento (a primeira, para timers e portas).
+    ///
+    /// # Panics
+    ///
+    /// Se o loop não tem janelas.
+    pub fn window(&mut self) -> &mut Window {
+        &mut self.windows[self.current]
+    }
+
+    /// Índice da janela que originou o evento (ver [`EventLoop::add_window`]).
+    #[inline]
+    pub fn window_index(&self) -> usize {
+        self.current
+    }
+
+    /// Todas as janelas do loop.
+    pub fn windows(&mut self) -> &mut [Window] {
+        self.windows
+    }
+
+    /// Encerra o loop após o callback atual.
+    pub fn exit(&mut self) {
+        self.exit = true;
+    }
+}
+
+// =============================================================================
+// EVENT LOOP
+// =============================================================================
+
+/// Callbacks de eventos de janela.
+#[derive(Default)]
+struct Handlers<'a> {
+    input: Option<Handler<'a, InputEvent>>,
+    touch: Option<Handler<'a, TouchEvent>>,
+    resize: Option<Handler<'a, ResizeEvent>>,
+    frame: Option<Handler<'a, FrameEvent>>,
+    close: Option<Handler<'a, ()>>,
+    other: Option<Handler<'a, Event>>,
+}
+
+/// Loop de eventos com callbacks.
+#[derive(Default)]
+pub struct EventLoop<'a> {
+    windows: Vec<Window>,
+    handlers: Handlers<'a>,
+    timers: Vec<(Interval, Handler<'a, u64>)>,
+    /// Handle esperado e callback (que é dono da fonte).
+    sources: Vec<(Handle, SourceHandler<'a>)>,
+}
+
+impl<'a> EventLoop<'a> {
+    /// Cria loop sem janelas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // =========================================================================
+    // FONTES
+    // =========================================================================
+
+    /// Passa a janela para o loop; retorna seu índice.
+    pub fn add_window(&mut self, window: Window) -> usize {
+        self.windows.push(window);
+        self.windows.len() - 1
+    }
+
+    /// Janelas do loop.
+    pub fn windows(&mut self) -> &mut [Window] {
+        &mut self.windows
+    }
+
+    /// Remove as janelas do loop (após [`run`](Self::run)).
+    pub fn into_windows(self) -> Vec<Window> {
+        self.windows
+    }
+
+    /// Registra um timer periódico; o callback recebe os ticks acumulados.
+    pub fn add_timer(
+        &mut self,
+        period: Duration,
+        callback: impl FnMut(&mut LoopControl<'_>, u64) + 'a,
+    ) -> SysResult<&mut Self> {
+        self.timers
+            .push((Interval::every(period)?, Box::new(callback)));
+        Ok(self)
+    }
+
+    /// Registra uma porta do app; o callback é chamado quando ela tem
+    /// mensagens (e deve lê-las com `recv`).
+    pub fn add_port(
+        &mut self,
+        port: Port,
+        callback: impl FnMut(&mut LoopControl<'_>, &Port) + 'a,
+    ) -> &mut Self {
+        self.add_source(port, callback)
+    }
+
+    /// Registra qualquer [`Source`] (arquivo, socket, `EventFd`...); o
+    /// callback é chamado quando ela fica pronta para leitura.
+    pub fn add_source<S: Source + 'a>(
+        &mut self,
+        source: S,
+        mut callback: impl FnMut(&mut LoopControl<'_>, &S) + 'a,
+    ) -> &mut Self {
+        let handle = *source.handle();
+        self.sources
+            .push((handle, Box::new(move |ctl| callback(ctl, &source))));
+        self
+    }
+
+    // =========================================================================
+    // CALLBACKS
+    // =========================================================================
+
+    /// Callback de input (teclado, mouse).
+    pub fn on_input(&mut self, f: impl FnMut(&mut LoopControl<'_>, InputEvent) + 'a) -> &mut Self {
+        self.handlers.input = Some(Box::new(f));
+        self
+    }
+
+    /// Callback de toque (tablets, teclado virtual).
+    pub fn on_touch(&mut self, f: impl FnMut(&mut LoopControl<'_>, TouchEvent) + 'a) -> &mut Self {
+        self.handlers.touch = Some(Box::new(f));
+        self
+    }
+
+    /// Callback de redimensionamento.
+    pub fn on_resize(
+        &mut self,
+        f: impl FnMut(&mut LoopControl<'_>, ResizeEvent) + 'a,
+    ) -> &mut Self {
+        self.handlers.resize = Some(Box::new(f));
+        self
+    }
+
+    /// Callback de frame (resposta a `Window::request_frame`).
+    pub fn on_frame(&mut self, f: impl FnMut(&mut LoopControl<'_>, FrameEvent) + 'a) -> &mut Self {
+        self.handlers.frame = Some(Box::new(f));
+        self
+    }
+
+    /// Callback de pedido de fechamento.
+    ///
+    /// Substitui o comportamento padrão (encerrar o loop); chame
+    /// [`LoopControl::exit`] para sair.
+    pub fn on_close(&mut self, mut f: impl FnMut(&mut LoopControl<'_>) + 'a) -> &mut Self {
+        self.handlers.close = Some(Box::new(move |ctl, ()| f(ctl)));
+        self
+    }
+
+    /// Callback dos demais eventos (tiling, estado, popups fechados, desconhecidos).
+    pub fn on_event(&mut self, f: impl FnMut(&mut LoopControl<'_>, Event) + 'a) -> &mut Self {
+        self.handlers.other = Some(Box::new(f));
+        self
+    }
+
+    // =========================================================================
+    // EXECUÇÃO
+    // =========================================================================
+
+    /// Roda até [`LoopControl::exit`] ou um fechamento sem `on_close`.
+    ///
+    /// Também retorna quando não há mais nada a esperar (sem janelas,
+    /// timers ou fontes).
+    pub fn run(&mut self) -> SysResult<()> {
+        loop {
+            if self.dispatch()? {
+                return Ok(());
+            }
+
+            let mut fds = self.poll_fds();
+            if fds.is_empty() {
+                return Ok(());
+            }
+            poll(&mut fds, -1)?;
+        }
+    }
+
+    /// [`run`](Self::run) assíncrono (suspende a tarefa no executor).
+    pub async fn run_async(&mut self) -> SysResult<()> {
+        loop {
+            if self.dispatch()? {
+                return Ok(());
+            }
+
+            let fds = self.poll_fds();
+            if fds.is_empty() {
+                return Ok(());
+            }
+            let waits = fds
+                .iter()
+                .map(|fd| ready(&Handle::from_raw(fd.handle), fd.events))
+                .collect();
+            ReadyAny(waits).await?;
+        }
+    }
+
+    /// Despacha tudo o que está pendente; retorna se o loop deve sair.
+    fn dispatch(&mut self) -> SysResult<bool> {
+        let Self {
+            windows,
+            handlers,
+            timers,
+            sources,
+        } = self;
+
+        // Janelas de uma mesma `Connection` leem eventos umas das outras
+        // para a fila da conexão: repetir até nenhuma ter pendências
+        loop {
+            let mut dispatched = false;
+            for index in 0..windows.len() {
+                let pending: Vec<Event> = windows[index].poll_events().collect();
+                dispatched |= !pending.is_empty();
+                for event in pending {
+                    let mut ctl = LoopControl {
+                        windows,
+                        current: index,
+                        exit: false,
+                    };
+                    handlers.dispatch(&mut ctl, event);
+                    if ctl.exit {
+                        return Ok(true);
+                    }
+                }
+            }
+            if !dispatched {
+                break;
+            }
+        }
+
+        for (interval, callback) in timers.iter_mut() {
+            let ticks = interval.try_tick()?;
+            if ticks == 0 {
+                continue;
+            }
+            let mut ctl = LoopControl {
+                windows,
+                current: 0,
+                exit: false,
+            };
+            callback(&mut ctl, ticks);
+            if ctl.exit {
+                return Ok(true);
+            }
+        }
+
+        for (handle, callback) in sources.iter_mut() {
+            let mut fds = [PollFd::new(handle, events::IN)];
+            if poll(&mut fds, 0)? == 0 || !fds[0].has_event(events::IN) {
+                continue;
+            }
+            let mut ctl = LoopControl {
+                windows,
+                current: 0,
+                exit: false,
+            };
+            callback(&mut ctl);
+            if ctl.exit {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Descritores de todas as fontes que podem acordar o loop.
+    fn poll_fds(&self) -> Vec<PollFd> {
+        let windows = self.windows.iter().filter_map(event_handle);
+        let timers = self.timers.iter().map(|(interval, _)| *interval.handle());
+        let sources = self.sources.iter().map(|(handle, _)| *handle);
+        windows
+            .chain(timers)
+            .chain(sources)
+            .map(|handle| PollFd::new(&handle, events::IN))
+            .collect()
+    }
+}
+
+impl Handlers<'_> {
+    fn dispatch(&mut self, ctl: &mut LoopControl<'_>, event: Event) {
+        match event {
+            Event::Input(ev) => call(&mut self.input, ctl, ev),
+            Event::Touch(ev) => call(&mut self.touch, ctl, ev),
+            Event::Resize(ev) => call(&mut self.resize, ctl, ev),
+            Event::Frame(ev) => call(&mut self.frame, ctl, ev),
+            Event::Close => match &mut self.close {
+                Some(handler) => handler(ctl, ()),
+                None => ctl.exit(),
+            },
+            other => call(&mut self.other, ctl, other),
+        }
+    }
+}
+
+fn call<T>(handler: &mut Option<Handler<'_, T>>, ctl: &mut LoopControl<'_>, value: T) {
+    if let Some(handler) = handler {
+        handler(ctl, value);
+    }
+}
+
+/// Handle pollável de eventos da janela.
+#[cfg(not(feature = "headless"))]
+fn event_handle(window: &Window) -> Option<Handle> {
+    Some(*window.event_port().handle())
+}
+
+/// Janelas headless não têm porta: os eventos roteirizados são
+/// despachados antes de cada espera.
+#[cfg(feature = "headless")]
+fn event_handle(_window: &Window) -> Option<Handle> {
+    None
+}
+
+// =============================================================================
+// ESPERA ASSÍNCRONA
+// =============================================================================
+
+/// Completa quando qualquer um dos handles fica pronto.
+struct ReadyAny(Vec<Ready>);
+
+impl Future for ReadyAny {
+    type Output = SysResult<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        for wait in self.0.iter_mut() {
+            if let Poll::Ready(result) = Pin::new(wait).poll(cx) {
+                return Poll::Ready(result.map(|_| ()));
+            }
+        }
+        Poll::Pending
+    }
+}
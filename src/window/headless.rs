@@ -0,0 +1,860 @@
+//! # Headless Window
+//!
+//! Backend de janela sem compositor (feature `headless`), para rodar apps e
+//! suítes de teste de UI em CI.
+//!
+//! `Window::create` aloca um buffer em memória comum e os eventos vêm de uma
+//! fila roteirizada ([`push_event`], [`script`]). Frames e o monitor são
+//! virtuais (1920x1080 a 60 Hz) e os timestamps de frame avançam um
+//! intervalo por `request_frame`, então a execução é determinística.
+//!
+//! A API é a mesma de [`client::Window`](super::client), exceto pelo campo
+//! `shm`; o conteúdo apresentado pode ser inspecionado com
+//! [`Window::pixels`].
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::{event_type, Event, InputEvent};
+//! use redpowder::window::{headless, opcodes, Window};
+//!
+//! headless::push_event(Event::Input(InputEvent {
+//!     op: opcodes::EVENT_INPUT,
+//!     event_type: event_type::MOUSE_DOWN,
+//!     param1: 40,
+//!     param2: 12,
+//! }));
+//!
+//! let mut window = Window::create(0, 0, 320, 240, "teste")?;
+//! app.run_once(&mut window);
+//! assert_eq!(window.pixels()[12 * 320 + 40], EXPECTED);
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::{Cell, Ref, RefCell};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use gfx_types::color::{Color, PixelFormat};
+use gfx_types::display::DisplayInfo;
+use gfx_types::geometry::{Point, Rect, Size};
+use gfx_types::input::{CursorType, TouchPoint};
+use gfx_types::window::{BufferMode, ResizeEdge, WindowFlags, WindowState};
+
+use crate::event::{
+    event_type, Event, FrameEvent, GamepadEvent, InputEvent, PointerLockEvent, ResizeEvent,
+    ScaleChangedEvent, StateChangedEvent, TextInputEvent, TilingEvent, TouchEvent, TEXT_INPUT_MAX,
+    TOUCH_MAX_POINTS,
+};
+use crate::graphics::image::ImageBuffer;
+use crate::graphics::{Canvas, DamageRegion, ScaleFactor, Surface};
+use crate::input::RawTouchPoint;
+use crate::ipc::fixed_str;
+use crate::syscall::{SysError, SysResult};
+use crate::task::Lock;
+
+use super::error::{WindowError, WindowErrorKind, WindowResult};
+use super::protocol::{opcodes, MAX_ICON_SIZE, MAX_TITLE_LEN};
+use super::session::WindowGeometry;
+use super::tiling::TilingState;
+
+/// Monitor virtual.
+const OUTPUT_WIDTH: u32 = 1920;
+const OUTPUT_HEIGHT: u32 = 1080;
+
+/// Taxa do monitor virtual (mHz).
+pub const HEADLESS_REFRESH_MHZ: u32 = 60_000;
+
+/// Próximo id de janela.
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Eventos pendentes e a janela de destino (None = qualquer).
+static EVENTS: Lock<VecDeque<(Option<u32>, Event)>> = Lock::new(VecDeque::new());
+
+// =============================================================================
+// ROTEIRO DE EVENTOS
+// =============================================================================
+
+/// Enfileira um evento para a próxima janela que consultar eventos.
+pub fn push_event(event: Event) {
+    EVENTS.with(|q| q.push_back((None, event)));
+}
+
+/// Enfileira um evento para a janela `window_id`.
+pub fn push_event_to(window_id: u32, event: Event) {
+    EVENTS.with(|q| q.push_back((Some(window_id), event)));
+}
+
+/// Enfileira uma sequência de eventos, em ordem.
+pub fn script(events: impl IntoIterator<Item = Event>) {
+    for event in events {
+        push_event(event);
+    }
+}
+
+/// Descarta eventos pendentes (entre casos de teste).
+pub fn clear_events() {
+    EVENTS.with(|q| q.clear());
+}
+
+/// Remove o primeiro evento destinado a `window_id`.
+fn take_event(window_id: u32) -> Option<Event> {
+    EVENTS.with(|q| {
+        let pos = q
+            .iter()
+            .position(|(target, _)| target.is_none_or(|id| id == window_id))?;
+        q.remove(pos).map(|(_, event)| event)
+    })
+}
+
+// =============================================================================
+// WINDOW
+// =============================================================================
+
+/// Janela em memória (sem compositor).
+pub struct Window {
+    /// ID da janela (único no processo).
+    pub id: u32,
+    /// Posição na tela virtual.
+    origin: Cell<Point>,
+    /// Buffer de pixels (também o destino da [`SwapChain`]).
+    pixels: RefCell<Vec<u32>>,
+    /// Largura em pixels.
+    width: u32,
+    /// Altura em pixels.
+    height: u32,
+    /// Flags de criação.
+    flags: u32,
+    /// Frames apresentados.
+    presents: Cell<u64>,
+    /// Região do último `present_region`.
+    last_present: Cell<Option<Rect>>,
+    /// Frames entregues por `request_frame`.
+    frames: Cell<u64>,
+    /// Estado de minimização.
+    minimized: Cell<bool>,
+    /// Estado (normal, maximizada, fullscreen).
+    state: Cell<WindowState>,
+    /// Geometria a restaurar ao sair de maximizada/fullscreen.
+    normal_rect: Cell<Option<Rect>>,
+    /// Último estado de tiling aplicado.
+    tiling: Cell<TilingState>,
+    /// Título atual.
+    title: RefCell<String>,
+    /// Ícone atual.
+    icon: Option<Surface>,
+    /// Propriedades definidas por `set_property`.
+    properties: RefCell<Vec<(String, String)>>,
+    /// Cursor do ponteiro sobre a janela.
+    cursor: Cell<CursorType>,
+    /// Cursor do campo de texto, com a entrada de texto habilitada.
+    text_input: Cell<Option<Rect>>,
+    scale: Cell<ScaleFactor>,
+    /// Ponteiro travado por `lock_pointer`.
+    pointer_locked: Cell<bool>,
+}
+
+impl Window {
+    // =========================================================================
+    // CRIAÇÃO
+    // =========================================================================
+
+    /// Cria nova janela com flags específicas.
+    pub fn create_with_flags(
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        flags: WindowFlags,
+        title: &str,
+    ) -> WindowResult<Self> {
+        Self::create_internal(x, y, width, height, flags.bits(), title)
+    }
+
+    /// Cria nova janela padrão.
+    pub fn create(x: u32, y: u32, width: u32, height: u32, title: &str) -> WindowResult<Self> {
+        Self::create_internal(x, y, width, height, 0, title)
+    }
+
+    pub(super) fn create_internal(
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        flags: u32,
+        title: &str,
+    ) -> WindowResult<Self> {
+        // Mesma recusa do compositor
+        if width == 0 || height == 0 {
+            return Err(WindowError {
+                kind: WindowErrorKind::InvalidSize,
+                request_op: opcodes::CREATE_WINDOW,
+            });
+        }
+
+        Ok(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            origin: Cell::new(Point::new(x as i32, y as i32)),
+            pixels: RefCell::new(alloc::vec![0; (width * height) as usize]),
+            width,
+            height,
+            flags,
+            presents: Cell::new(0),
+            last_present: Cell::new(None),
+            frames: Cell::new(0),
+            minimized: Cell::new(false),
+            state: Cell::new(WindowState::Normal),
+            normal_rect: Cell::new(None),
+            tiling: Cell::new(TilingState::None),
+            title: RefCell::new(truncate_title(title).to_string()),
+            icon: None,
+            properties: RefCell::new(Vec::new()),
+            cursor: Cell::new(CursorType::Default),
+            text_input: Cell::new(None),
+            scale: Cell::new(ScaleFactor::ONE),
+            pointer_locked: Cell::new(false),
+        })
+    }
+
+    // =========================================================================
+    // PROPRIEDADES
+    // =========================================================================
+
+    /// Largura em pixels.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Altura em pixels.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Retorna Size.
+    #[inline]
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Retorna o retângulo da janela (origem em 0,0).
+    #[inline]
+    pub fn bounds(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height)
+    }
+
+    /// Escala atual (1x até [`change_scale`](Self::change_scale)).
+    #[inline]
+    pub fn scale(&self) -> ScaleFactor {
+        self.scale.get()
+    }
+
+    /// Tamanho em coordenadas lógicas.
+    #[inline]
+    pub fn logical_size(&self) -> Size {
+        self.scale.get().size_to_logical(self.size())
+    }
+
+    /// Simula a troca para um monitor com outra escala: enfileira o
+    /// `ScaleChanged` que o compositor enviaria.
+    pub fn change_scale(&self, scale: ScaleFactor) {
+        push_event_to(
+            self.id,
+            Event::ScaleChanged(ScaleChangedEvent {
+                op: opcodes::EVENT_SCALE_CHANGED,
+                window_id: self.id,
+                scale_milli: scale.milli(),
+            }),
+        );
+    }
+
+    /// Flags de criação.
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Estado de tiling atual.
+    pub fn tiling(&self) -> TilingState {
+        self.tiling.get()
+    }
+
+    /// Janela minimizada?
+    pub fn is_minimized(&self) -> bool {
+        self.minimized.get()
+    }
+
+    // =========================================================================
+    // BUFFER
+    // =========================================================================
+
+    /// Obtém buffer de pixels.
+    pub fn buffer(&mut self) -> &mut [u32] {
+        self.pixels.get_mut()
+    }
+
+    /// Conteúdo atual do buffer (para asserções).
+    pub fn pixels(&self) -> Ref<'_, [u32]> {
+        Ref::map(self.pixels.borrow(), Vec::as_slice)
+    }
+
+    /// Limpa o buffer com uma cor.
+    pub fn clear(&mut self, color: Color) {
+        self.pixels.get_mut().fill(color.as_u32());
+    }
+
+    /// Desenha um pixel.
+    pub fn put_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels.get_mut()[(y * self.width + x) as usize] = color.as_u32();
+        }
+    }
+
+    /// Desenha um pixel em Point.
+    pub fn put_pixel_at(&mut self, p: Point, color: Color) {
+        if p.x >= 0 && p.y >= 0 {
+            self.put_pixel(p.x as u32, p.y as u32, color);
+        }
+    }
+
+    /// Preenche retângulo.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let Some(clipped) = rect.intersection(&self.bounds()) else {
+            return;
+        };
+        for y in clipped.y as u32..(clipped.y as u32 + clipped.height) {
+            let start = (y * self.width + clipped.x as u32) as usize;
+            self.pixels.get_mut()[start..start + clipped.width as usize].fill(color.as_u32());
+        }
+    }
+
+    // =========================================================================
+    // APRESENTAÇÃO
+    // =========================================================================
+
+    /// Registra a apresentação do buffer inteiro.
+    pub fn present(&self) -> SysResult<()> {
+        self.present_region(self.bounds())
+    }
+
+    /// Registra a apresentação de uma região.
+    pub fn present_region(&self, dirty: Rect) -> SysResult<()> {
+        self.presents.set(self.presents.get() + 1);
+        self.last_present.set(dirty.intersection(&self.bounds()));
+        Ok(())
+    }
+
+    /// Registra a apresentação das áreas de uma [`DamageRegion`]
+    /// (`last_present` fica com o bounding box).
+    pub fn present_damage(&self, damage: &DamageRegion) -> SysResult<()> {
+        if damage.is_empty() {
+            return Ok(());
+        }
+        self.present_region(damage.bounds())
+    }
+
+    /// Número de apresentações.
+    pub fn present_count(&self) -> u64 {
+        self.presents.get()
+    }
+
+    /// Região da última apresentação.
+    pub fn last_present(&self) -> Option<Rect> {
+        self.last_present.get()
+    }
+
+    /// Enfileira um [`Event::Frame`] com o próximo timestamp virtual.
+    pub fn request_frame(&self) -> SysResult<()> {
+        let frame = self.frames.get() + 1;
+        self.frames.set(frame);
+        let interval_ns = 1_000_000_000_000 / HEADLESS_REFRESH_MHZ as u64;
+        push_event_to(
+            self.id,
+            Event::Frame(FrameEvent {
+                op: opcodes::EVENT_FRAME,
+                window_id: self.id,
+                timestamp_ns: frame * interval_ns,
+                refresh_rate_mhz: HEADLESS_REFRESH_MHZ,
+            }),
+        );
+        Ok(())
+    }
+
+    /// Habilita [`Event::TextInput`] (ver [`inject_text`](Self::inject_text)).
+    pub fn start_text_input(&self, cursor: Rect) -> SysResult<()> {
+        self.text_input.set(Some(cursor));
+        Ok(())
+    }
+
+    /// Desabilita a entrada de texto.
+    pub fn stop_text_input(&self) -> SysResult<()> {
+        self.text_input.set(None);
+        Ok(())
+    }
+
+    /// Cursor informado em `start_text_input`, se habilitada.
+    pub fn text_input_cursor(&self) -> Option<Rect> {
+        self.text_input.get()
+    }
+
+    /// Enfileira o texto como o compositor faria: em eventos de até
+    /// [`TEXT_INPUT_MAX`] bytes, divididos entre caracteres. Ignorado com a
+    /// entrada de texto desabilitada.
+    pub fn inject_text(&self, text: &str) -> SysResult<()> {
+        if self.text_input.get().is_none() {
+            return Ok(());
+        }
+        let mut rest = text;
+        while !rest.is_empty() {
+            let mut end = rest.len().min(TEXT_INPUT_MAX);
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            let mut event = TextInputEvent {
+                op: opcodes::EVENT_TEXT_INPUT,
+                window_id: self.id,
+                len: end as u32,
+                text: [0; TEXT_INPUT_MAX],
+            };
+            event.text[..end].copy_from_slice(&rest.as_bytes()[..end]);
+            push_event_to(self.id, Event::TextInput(event));
+            rest = &rest[end..];
+        }
+        Ok(())
+    }
+
+    /// Enfileira os pontos (coordenadas da janela) como o compositor faria:
+    /// em eventos de até [`TOUCH_MAX_POINTS`] pontos.
+    pub fn inject_touch(&self, points: &[TouchPoint]) -> SysResult<()> {
+        for chunk in points.chunks(TOUCH_MAX_POINTS) {
+            let mut event = TouchEvent {
+                op: opcodes::EVENT_TOUCH,
+                window_id: self.id,
+                count: chunk.len() as u32,
+                points: [RawTouchPoint::default(); TOUCH_MAX_POINTS],
+            };
+            for (slot, point) in event.points.iter_mut().zip(chunk) {
+                *slot = RawTouchPoint::from_point(point);
+            }
+            push_event_to(self.id, Event::Touch(event));
+        }
+        Ok(())
+    }
+
+    /// Enfileira a conexão (ou desconexão) de um controle.
+    pub fn inject_gamepad(&self, slot: u32, connected: bool, name: &str) -> SysResult<()> {
+        push_event_to(
+            self.id,
+            Event::Gamepad(GamepadEvent {
+                op: opcodes::EVENT_GAMEPAD,
+                window_id: self.id,
+                slot,
+                connected: connected as u32,
+                name: fixed_str(name),
+            }),
+        );
+        Ok(())
+    }
+
+    /// Enfileira um evento de input para esta janela.
+    pub fn inject_input(&self, event: &InputEvent) -> SysResult<()> {
+        push_event_to(self.id, Event::Input(*event));
+        Ok(())
+    }
+
+    // =========================================================================
+    // MONITOR
+    // =========================================================================
+
+    /// Monitor virtual.
+    pub fn output_info(&self) -> SysResult<DisplayInfo> {
+        Ok(DisplayInfo::new(
+            0,
+            OUTPUT_WIDTH,
+            OUTPUT_HEIGHT,
+            HEADLESS_REFRESH_MHZ,
+            PixelFormat::default(),
+            0,
+        ))
+    }
+
+    /// Posição de criação, tamanho e tiling atual.
+    pub fn geometry(&self) -> WindowResult<WindowGeometry> {
+        Ok(WindowGeometry {
+            rect: Rect::from_point_size(self.origin.get(), self.size()),
+            tiling: self.tiling.get(),
+        })
+    }
+
+    /// Cópia do conteúdo atual do buffer.
+    pub fn capture(&self) -> WindowResult<ImageBuffer> {
+        let pixels = self.pixels.borrow().clone();
+        let surface = Surface::from_pixels(self.width, self.height, pixels)
+            .ok_or(SysError::InvalidArgument)?;
+        Ok(ImageBuffer::from_surface(surface))
+    }
+
+    // =========================================================================
+    // EVENTOS
+    // =========================================================================
+
+    /// Lê eventos da fila roteirizada (não bloqueante).
+    pub fn poll_events(&self) -> impl Iterator<Item = Event> + '_ {
+        core::iter::from_fn(move || take_event(self.id)).inspect(|event| match event {
+            Event::ScaleChanged(change) => self.scale.set(change.scale()),
+            Event::PointerLock(lock) => self.pointer_locked.set(lock.is_locked()),
+            _ => {}
+        })
+    }
+
+    // =========================================================================
+    // OPERAÇÕES DE JANELA
+    // =========================================================================
+
+    /// Destrói a janela.
+    pub fn destroy(&self) -> SysResult<()> {
+        EVENTS.with(|q| q.retain(|(target, _)| *target != Some(self.id)));
+        Ok(())
+    }
+
+    /// Minimiza a janela.
+    pub fn minimize(&self) -> SysResult<()> {
+        self.minimized.set(true);
+        Ok(())
+    }
+
+    /// Restaura a janela.
+    pub fn restore(&self) -> SysResult<()> {
+        self.minimized.set(false);
+        Ok(())
+    }
+
+    /// Entra (ou sai) de fullscreen no monitor virtual; sempre concedido.
+    pub fn set_fullscreen(&self, enabled: bool) -> SysResult<()> {
+        if enabled {
+            self.enter_state(WindowState::Fullscreen);
+        } else if self.state.get() == WindowState::Fullscreen {
+            self.leave_state();
+        }
+        Ok(())
+    }
+
+    /// Maximiza no monitor virtual (sem painéis, ocupa a tela toda).
+    pub fn maximize(&self) -> SysResult<()> {
+        self.enter_state(WindowState::Maximized);
+        Ok(())
+    }
+
+    /// Volta de maximizada para a geometria anterior.
+    pub fn unmaximize(&self) -> SysResult<()> {
+        if self.state.get() == WindowState::Maximized {
+            self.leave_state();
+        }
+        Ok(())
+    }
+
+    /// Estado atual.
+    pub fn state(&self) -> WindowState {
+        self.state.get()
+    }
+
+    fn enter_state(&self, state: WindowState) {
+        if self.state.get() == WindowState::Normal {
+            self.normal_rect
+                .set(Some(Rect::from_point_size(self.origin.get(), self.size())));
+        }
+        self.apply_state(state, Rect::new(0, 0, OUTPUT_WIDTH, OUTPUT_HEIGHT));
+    }
+
+    fn leave_state(&self) {
+        let rect = self
+            .normal_rect
+            .take()
+            .unwrap_or_else(|| Rect::from_point_size(self.origin.get(), self.size()));
+        self.apply_state(WindowState::Normal, rect);
+    }
+
+    /// Enfileira o `StateChanged` e o `Resize` que o compositor enviaria.
+    fn apply_state(&self, state: WindowState, rect: Rect) {
+        self.state.set(state);
+        self.origin.set(Point::new(rect.x, rect.y));
+        push_event_to(
+            self.id,
+            Event::StateChanged(StateChangedEvent {
+                op: opcodes::EVENT_STATE_CHANGED,
+                window_id: self.id,
+                state: state as u32,
+                granted: 1,
+                x: rect.x,
+                y: rect.y,
+                width: rect.width,
+                height: rect.height,
+            }),
+        );
+        push_event_to(
+            self.id,
+            Event::Resize(ResizeEvent {
+                op: opcodes::EVENT_RESIZE,
+                width: rect.width,
+                height: rect.height,
+            }),
+        );
+    }
+
+    /// Move a janela para `(x, y)`.
+    pub fn move_to(&self, x: i32, y: i32) -> SysResult<()> {
+        self.origin.set(Point::new(x, y));
+        Ok(())
+    }
+
+    /// Enfileira o [`Event::Resize`] que o compositor enviaria.
+    ///
+    /// Como no compositor, o buffer mantém o tamanho de criação.
+    pub fn resize(&self, width: u32, height: u32) -> SysResult<()> {
+        if width == 0 || height == 0 {
+            return Err(SysError::InvalidArgument);
+        }
+        push_event_to(
+            self.id,
+            Event::Resize(ResizeEvent {
+                op: opcodes::EVENT_RESIZE,
+                width,
+                height,
+            }),
+        );
+        Ok(())
+    }
+
+    /// Sem ponteiro real: não faz nada.
+    pub fn begin_interactive_move(&self) -> SysResult<()> {
+        Ok(())
+    }
+
+    /// Sem ponteiro real: não faz nada.
+    pub fn begin_interactive_resize(&self, _edge: ResizeEdge) -> SysResult<()> {
+        Ok(())
+    }
+
+    /// Aplica o estado de tiling e enfileira o [`Event::Tiling`].
+    ///
+    /// O buffer não é redimensionado (não há monitor real).
+    pub fn set_tiling_hint(&self, state: TilingState) -> SysResult<()> {
+        self.tiling.set(state);
+        push_event_to(
+            self.id,
+            Event::Tiling(TilingEvent {
+                op: opcodes::EVENT_TILING,
+                window_id: self.id,
+                state: state.as_u32(),
+            }),
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // TÍTULO E PROPRIEDADES
+    // =========================================================================
+
+    /// Troca o cursor do ponteiro sobre a janela.
+    pub fn set_cursor(&self, cursor: CursorType) -> SysResult<()> {
+        self.cursor.set(cursor);
+        Ok(())
+    }
+
+    /// Cursor atual.
+    pub fn cursor(&self) -> CursorType {
+        self.cursor.get()
+    }
+
+    /// Trava o ponteiro (sempre concedido) e enfileira o `PointerLock`.
+    pub fn lock_pointer(&self) -> SysResult<()> {
+        self.set_pointer_lock(true);
+        Ok(())
+    }
+
+    /// Libera o ponteiro travado.
+    pub fn unlock_pointer(&self) -> SysResult<()> {
+        if self.pointer_locked.get() {
+            self.set_pointer_lock(false);
+        }
+        Ok(())
+    }
+
+    /// Ponteiro travado nesta janela.
+    pub fn is_pointer_locked(&self) -> bool {
+        self.pointer_locked.get()
+    }
+
+    fn set_pointer_lock(&self, locked: bool) {
+        self.pointer_locked.set(locked);
+        push_event_to(
+            self.id,
+            Event::PointerLock(PointerLockEvent {
+                op: opcodes::EVENT_POINTER_LOCK,
+                window_id: self.id,
+                locked: locked as u32,
+            }),
+        );
+    }
+
+    /// Enfileira um movimento relativo; ignorado sem o ponteiro travado.
+    pub fn inject_relative_motion(&self, dx: i32, dy: i32) -> SysResult<()> {
+        if self.pointer_locked.get() {
+            push_event_to(
+                self.id,
+                Event::Input(InputEvent {
+                    op: opcodes::EVENT_INPUT,
+                    event_type: event_type::MOUSE_RELATIVE,
+                    param1: dx as u32,
+                    param2: dy as u32,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// Troca o título (truncado em [`MAX_TITLE_LEN`] bytes).
+    pub fn set_title(&self, title: &str) -> SysResult<()> {
+        *self.title.borrow_mut() = truncate_title(title).to_string();
+        Ok(())
+    }
+
+    /// Título atual.
+    pub fn title(&self) -> String {
+        self.title.borrow().clone()
+    }
+
+    /// Troca o ícone; até [`MAX_ICON_SIZE`] por lado.
+    pub fn set_icon(&mut self, icon: &ImageBuffer) -> SysResult<()> {
+        let (width, height) = (icon.width(), icon.height());
+        if width == 0 || height == 0 || width > MAX_ICON_SIZE || height > MAX_ICON_SIZE {
+            return Err(SysError::InvalidArgument);
+        }
+        self.icon = Some(icon.as_surface().clone());
+        Ok(())
+    }
+
+    /// Ícone atual.
+    pub fn icon(&self) -> Option<&Surface> {
+        self.icon.as_ref()
+    }
+
+    /// Define uma propriedade; valor vazio remove.
+    pub fn set_property(&self, key: &str, value: &str) -> SysResult<()> {
+        let mut properties = self.properties.borrow_mut();
+        properties.retain(|(k, _)| k != key);
+        if !value.is_empty() {
+            properties.push((key.to_string(), value.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Valor de uma propriedade.
+    pub fn property(&self, key: &str) -> Option<String> {
+        let properties = self.properties.borrow();
+        properties
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    }
+}
+
+/// Corta o título em [`MAX_TITLE_LEN`] bytes, numa fronteira de caractere.
+fn truncate_title(title: &str) -> &str {
+    let mut end = title.len().min(MAX_TITLE_LEN);
+    while !title.is_char_boundary(end) {
+        end -= 1;
+    }
+    &title[..end]
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+// =============================================================================
+// SWAPCHAIN
+// =============================================================================
+
+/// Swapchain em memória: `present` copia o buffer de trás para a janela.
+pub struct SwapChain<'w> {
+    window: &'w Window,
+    buffers: Vec<Vec<u32>>,
+    back: u32,
+    presents: u64,
+}
+
+impl<'w> SwapChain<'w> {
+    /// Aloca os buffers de `mode` para `window`.
+    pub fn new(window: &'w Window, mode: BufferMode) -> SysResult<Self> {
+        let len = (window.width * window.height) as usize;
+        Ok(Self {
+            window,
+            buffers: (0..mode.buffer_count())
+                .map(|_| alloc::vec![0; len])
+                .collect(),
+            back: 0,
+            presents: 0,
+        })
+    }
+
+    /// Número de buffers.
+    #[inline]
+    pub fn buffer_count(&self) -> u32 {
+        self.buffers.len() as u32
+    }
+
+    /// Índice do buffer de trás.
+    #[inline]
+    pub fn back_index(&self) -> u32 {
+        self.back
+    }
+
+    /// Frames desde que o buffer de trás foi apresentado pela última vez
+    /// (0 = conteúdo indefinido, nunca apresentado).
+    pub fn buffer_age(&self) -> u32 {
+        if self.presents < self.buffer_count() as u64 {
+            0
+        } else {
+            self.buffer_count()
+        }
+    }
+
+    /// Pixels do buffer de trás.
+    pub fn back_buffer(&mut self) -> &mut [u32] {
+        &mut self.buffers[self.back as usize]
+    }
+
+    /// Canvas sobre o buffer de trás.
+    pub fn canvas(&mut self) -> Canvas<'_> {
+        let (width, height) = (self.window.width, self.window.height);
+        Canvas::new(&mut self.buffers[self.back as usize], width, height)
+    }
+
+    /// Apresenta o buffer de trás inteiro e passa ao próximo.
+    pub fn present(&mut self) -> SysResult<()> {
+        self.present_region(self.window.bounds())
+    }
+
+    /// Apresenta o buffer de trás indicando a área alterada.
+    pub fn present_region(&mut self, dirty: Rect) -> SysResult<()> {
+        self.window
+            .pixels
+            .borrow_mut()
+            .copy_from_slice(&self.buffers[self.back as usize]);
+        self.window.present_region(dirty)?;
+        self.back = (self.back + 1) % self.buffer_count();
+        self.presents += 1;
+        Ok(())
+    }
+
+    /// Apresenta o buffer de trás com o bounding box de `damage`.
+    pub fn present_damage(&mut self, damage: &DamageRegion) -> SysResult<()> {
+        self.present_region(damage.bounds())
+    }
+}
@@ -0,0 +1,385 @@
+//! # Input
+//!
+//! Roteamento de input para widgets: converte os [`InputEvent`] crus do
+//! compositor em eventos tipados ([`MouseEvent`], [`KeyEvent`]), faz hit
+//! testing contra as regiões registradas e chama o callback de cada uma.
+//!
+//! Regiões registradas depois ficam por cima. Quem recebe o `Down` captura
+//! o ponteiro até o `Up` (arrastar, cancelar um clique saindo do botão) e
+//! ganha o foco do teclado; as teclas vão para o callback de
+//! [`on_key`](InputRouter::on_key) com a região focada.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::input::{InputRouter, MouseKind};
+//!
+//! const OK: u32 = 1;
+//! const SLIDER: u32 = 2;
+//!
+//! let mut router = InputRouter::new();
+//! router.add(OK, Rect::new(20, 80, 80, 28), |event| {
+//!     if event.kind == MouseKind::Up && event.inside {
+//!         submit();
+//!     }
+//! });
+//! router.add(SLIDER, Rect::new(20, 40, 200, 16), |event| {
+//!     if event.is_dragging() {
+//!         set_volume(event.pos.x);
+//!     }
+//! });
+//! router.on_key(|focused, key| {
+//!     if key.pressed && focused == Some(OK) && key.keycode == KeyCode::Enter {
+//!         submit();
+//!     }
+//! });
+//!
+//! for event in window.poll_events() {
+//!     if let Event::Input(input) = event {
+//!         router.dispatch(&input);
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use gfx_types::geometry::{Point, Rect};
+
+use crate::event::{event_type, InputEvent};
+use crate::input::{KeyCode, Modifiers, MouseButton};
+
+/// Identificador de região definido pelo app.
+pub type RegionId = u32;
+
+// =============================================================================
+// EVENTOS TIPADOS
+// =============================================================================
+
+/// Tipo de evento de mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseKind {
+    Move,
+    Down,
+    Up,
+    /// Ponteiro entrou na região (sintetizado pelo [`InputRouter`]).
+    Enter,
+    /// Ponteiro saiu da região (sintetizado pelo [`InputRouter`]).
+    Leave,
+}
+
+/// Evento de mouse tipado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseEvent {
+    /// Posição em coordenadas da janela.
+    pub pos: Point,
+    /// O protocolo atual só informa o botão primário.
+    pub button: MouseButton,
+    pub kind: MouseKind,
+    /// Botão pressionado durante o evento.
+    pub pressed: bool,
+    /// `pos` dentro da região que recebeu o evento (falso em eventos
+    /// capturados fora dela).
+    pub inside: bool,
+}
+
+impl MouseEvent {
+    /// Movimento com o botão pressionado.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.kind == MouseKind::Move && self.pressed
+    }
+
+    /// Posição relativa à origem de `rect`.
+    #[inline]
+    pub fn local(&self, rect: Rect) -> Point {
+        Point::new(self.pos.x - rect.x, self.pos.y - rect.y)
+    }
+}
+
+/// Evento de tecla tipado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub keycode: KeyCode,
+    /// Caractere da tecla no layout US com os modificadores aplicados;
+    /// `None` para teclas sem caractere ou com Ctrl/Alt/Meta.
+    pub char: Option<char>,
+    pub modifiers: Modifiers,
+    /// Pressionada (true) ou solta (false).
+    pub pressed: bool,
+}
+
+/// Evento de input tipado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetInput {
+    Mouse(MouseEvent),
+    Key(KeyEvent),
+}
+
+/// Converte um [`InputEvent`] cru; tipos desconhecidos retornam `None`.
+///
+/// Eventos de mouse saem com `pressed` do próprio evento e `inside`
+/// verdadeiro; o [`InputRouter`] ajusta os dois.
+pub fn translate(input: &InputEvent) -> Option<WidgetInput> {
+    let mouse = |kind, pressed| {
+        WidgetInput::Mouse(MouseEvent {
+            pos: Point::new(input.param1 as i32, input.param2 as i32),
+            button: MouseButton::Left,
+            kind,
+            pressed,
+            inside: true,
+        })
+    };
+    let key = |pressed| {
+        let keycode = KeyCode::from_scancode(input.param1 as u8);
+        let modifiers = Modifiers::from_bits(input.param2);
+        WidgetInput::Key(KeyEvent {
+            keycode,
+            char: key_char(keycode, modifiers),
+            modifiers,
+            pressed,
+        })
+    };
+
+    match input.event_type {
+        event_type::MOUSE_MOVE => Some(mouse(MouseKind::Move, false)),
+        event_type::MOUSE_DOWN => Some(mouse(MouseKind::Down, true)),
+        event_type::MOUSE_UP => Some(mouse(MouseKind::Up, false)),
+        event_type::KEY_DOWN => Some(key(true)),
+        event_type::KEY_UP => Some(key(false)),
+        _ => None,
+    }
+}
+
+/// Caractere digitado por `keycode` (Caps Lock só afeta letras).
+fn key_char(keycode: KeyCode, modifiers: Modifiers) -> Option<char> {
+    if modifiers.has(Modifiers::CTRL)
+        || modifiers.has(Modifiers::ALT)
+        || modifiers.has(Modifiers::META)
+    {
+        return None;
+    }
+    let shift = modifiers.has(Modifiers::SHIFT);
+    let c = keycode.to_char(shift)?;
+    if c.is_ascii_alphabetic() && modifiers.has(Modifiers::CAPS_LOCK) {
+        return keycode.to_char(!shift);
+    }
+    Some(c)
+}
+
+// =============================================================================
+// ROUTER
+// =============================================================================
+
+type MouseHandler<'a> = Box<dyn FnMut(&MouseEvent) + 'a>;
+type KeyHandler<'a> = Box<dyn FnMut(Option<RegionId>, &KeyEvent) + 'a>;
+
+struct Region<'a> {
+    id: RegionId,
+    rect: Rect,
+    enabled: bool,
+    handler: MouseHandler<'a>,
+}
+
+/// Hit testing e despacho de input para regiões da janela.
+pub struct InputRouter<'a> {
+    regions: Vec<Region<'a>>,
+    key_handler: Option<KeyHandler<'a>>,
+    /// Região sob o ponteiro.
+    hovered: Option<RegionId>,
+    /// Região que capturou o ponteiro (recebeu o `Down`).
+    captured: Option<RegionId>,
+    /// Região com o foco do teclado.
+    focused: Option<RegionId>,
+}
+
+impl<'a> Default for InputRouter<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> InputRouter<'a> {
+    /// Router vazio.
+    pub fn new() -> Self {
+        Self {
+            regions: Vec::new(),
+            key_handler: None,
+            hovered: None,
+            captured: None,
+            focused: None,
+        }
+    }
+
+    // =========================================================================
+    // REGIÕES
+    // =========================================================================
+
+    /// Registra (ou substitui) a região `id`, acima das já registradas.
+    pub fn add(&mut self, id: RegionId, rect: Rect, handler: impl FnMut(&MouseEvent) + 'a) {
+        self.remove(id);
+        self.regions.push(Region {
+            id,
+            rect,
+            enabled: true,
+            handler: Box::new(handler),
+        });
+    }
+
+    /// Remove a região `id` (e o foco/captura dela).
+    pub fn remove(&mut self, id: RegionId) {
+        self.regions.retain(|region| region.id != id);
+        for slot in [&mut self.hovered, &mut self.captured, &mut self.focused] {
+            if *slot == Some(id) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Remove todas as regiões.
+    pub fn clear(&mut self) {
+        self.regions.clear();
+        self.hovered = None;
+        self.captured = None;
+        self.focused = None;
+    }
+
+    /// Move ou redimensiona a região `id` (após um relayout).
+    pub fn set_rect(&mut self, id: RegionId, rect: Rect) {
+        if let Some(region) = self.region_mut(id) {
+            region.rect = rect;
+        }
+    }
+
+    /// Retângulo da região `id`.
+    pub fn rect(&self, id: RegionId) -> Option<Rect> {
+        self.regions
+            .iter()
+            .find(|region| region.id == id)
+            .map(|region| region.rect)
+    }
+
+    /// Habilita ou desabilita a região; desabilitadas são transparentes
+    /// ao hit testing.
+    pub fn set_enabled(&mut self, id: RegionId, enabled: bool) {
+        if let Some(region) = self.region_mut(id) {
+            region.enabled = enabled;
+        }
+        if !enabled && self.focused == Some(id) {
+            self.focused = None;
+        }
+    }
+
+    /// Região habilitada mais acima em `point`.
+    pub fn hit_test(&self, point: Point) -> Option<RegionId> {
+        self.regions
+            .iter()
+            .rev()
+            .find(|region| region.enabled && region.rect.contains_point(point))
+            .map(|region| region.id)
+    }
+
+    // =========================================================================
+    // FOCO E TECLADO
+    // =========================================================================
+
+    /// Callback das teclas, com a região focada.
+    pub fn on_key(&mut self, handler: impl FnMut(Option<RegionId>, &KeyEvent) + 'a) {
+        self.key_handler = Some(Box::new(handler));
+    }
+
+    /// Região com o foco do teclado.
+    pub fn focused(&self) -> Option<RegionId> {
+        self.focused
+    }
+
+    /// Troca o foco (ex.: sincronizado com `ui::focus::FocusChain`).
+    pub fn set_focus(&mut self, id: Option<RegionId>) {
+        self.focused = id;
+    }
+
+    /// Região sob o ponteiro.
+    pub fn hovered(&self) -> Option<RegionId> {
+        self.hovered
+    }
+
+    // =========================================================================
+    // DESPACHO
+    // =========================================================================
+
+    /// Traduz e despacha `input`.
+    ///
+    /// Retorna a região que recebeu o evento de mouse, ou a focada para
+    /// teclas.
+    pub fn dispatch(&mut self, input: &InputEvent) -> Option<RegionId> {
+        match translate(input)? {
+            WidgetInput::Mouse(event) => self.dispatch_mouse(event),
+            WidgetInput::Key(event) => {
+                if let Some(handler) = &mut self.key_handler {
+                    handler(self.focused, &event);
+                }
+                self.focused
+            }
+        }
+    }
+
+    /// Despacha um evento de mouse já tipado.
+    pub fn dispatch_mouse(&mut self, mut event: MouseEvent) -> Option<RegionId> {
+        let hit = self.hit_test(event.pos);
+        event.pressed = match event.kind {
+            MouseKind::Down => true,
+            MouseKind::Up => false,
+            _ => self.captured.is_some(),
+        };
+
+        // Enter/Leave só sem captura (o dono do arraste não perde o hover)
+        if self.captured.is_none() && hit != self.hovered {
+            if let Some(old) = self.hovered {
+                self.send(
+                    old,
+                    MouseEvent {
+                        kind: MouseKind::Leave,
+                        ..event
+                    },
+                );
+            }
+            if let Some(new) = hit {
+                self.send(
+                    new,
+                    MouseEvent {
+                        kind: MouseKind::Enter,
+                        ..event
+                    },
+                );
+            }
+            self.hovered = hit;
+        }
+
+        let target = match event.kind {
+            MouseKind::Down => {
+                self.captured = hit;
+                if hit.is_some() {
+                    self.focused = hit;
+                }
+                hit
+            }
+            MouseKind::Up => self.captured.take().or(hit),
+            _ => self.captured.or(hit),
+        }?;
+        self.send(target, event);
+        Some(target)
+    }
+
+    fn send(&mut self, id: RegionId, mut event: MouseEvent) {
+        if let Some(region) = self.region_mut(id) {
+            event.inside = region.rect.contains_point(event.pos);
+            (region.handler)(&event);
+        }
+    }
+
+    fn region_mut(&mut self, id: RegionId) -> Option<&mut Region<'a>> {
+        self.regions.iter_mut().find(|region| region.id == id)
+    }
+}
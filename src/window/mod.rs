@@ -9,14 +9,50 @@
 //! | Módulo | Descrição |
 //! |--------|-----------|
 //! | [`protocol`] | Mensagens e opcodes do protocolo |
-//! | [`client`] | Cliente de janela (Window) |
+//! | [`connection`] | Várias janelas numa conexão (`Connection`) |
+//! | [`event_loop`] | Loop de eventos com callbacks (`EventLoop`) |
+//! | [`input`] | Eventos de mouse/teclado tipados e roteamento para widgets |
+//! | [`clipboard`] | Copiar e colar (tipos MIME, SHM) |
+//! | [`error`] | Erros detalhados do compositor (`WindowError`) |
+//! | [`dnd`] | Drag-and-drop entre janelas |
+//! | [`capture`] | Consultas e capturas da tela composta (conta-gotas, screenshots) |
+//! | `client` | Cliente de janela (Window) |
+//! | [`tiling`] | Estados de tiling (snap em metades/quadrantes) |
+//! | `popup` | Popups e menus de contexto (`Window::create_popup`) |
+//! | [`secure`] | Janelas seguras (bloqueio de tela, senhas) |
+//! | [`session`] | Salvar/restaurar geometria entre execuções |
+//! | [`shell`] | APIs do shell (miniaturas, taskbar, workspaces) |
+//! | `swapchain` | Double/triple buffering (`SwapChain`) |
+//! | `headless` | Janela em memória para CI (feature `headless`) |
+//!
+//! ## Modo headless
+//!
+//! Com a feature `headless`, [`Window`] é uma janela em memória com eventos
+//! roteirizados, para rodar testes de UI sem compositor.
 //!
 //! ## Re-exports de gfx_types
 //!
 //! Tipos de janela são re-exportados de `gfx_types::window`.
 
+pub mod capture;
+#[cfg(not(feature = "headless"))]
 pub mod client;
+pub mod clipboard;
+pub mod connection;
+pub mod dnd;
+pub mod error;
+pub mod event_loop;
+#[cfg(feature = "headless")]
+pub mod headless;
+pub mod input;
+pub mod popup;
 pub mod protocol;
+pub mod secure;
+pub mod session;
+pub mod shell;
+#[cfg(not(feature = "headless"))]
+pub mod swapchain;
+pub mod tiling;
 
 // =============================================================================
 // RE-EXPORTS DE GFX_TYPES
@@ -31,10 +67,34 @@ pub use gfx_types::window::{
 // EXPORTS DO MÓDULO
 // =============================================================================
 
+#[cfg(not(feature = "headless"))]
 pub use client::Window;
+pub use connection::Connection;
+pub use error::{WindowError, WindowErrorKind, WindowResult};
+pub use event_loop::{EventLoop, LoopControl};
+#[cfg(feature = "headless")]
+pub use headless::{SwapChain, Window};
+pub use input::{InputRouter, RegionId};
 pub use protocol::{
-    lifecycle_events, opcodes, CommitBufferRequest, CreateWindowRequest, DestroyWindowRequest,
-    ErrorResponse, MoveWindowRequest, ProtocolMessage, RegisterTaskbarRequest, ResizeWindowRequest,
-    SetWindowFlagsRequest, WindowCreatedResponse, WindowLifecycleEvent, WindowOpRequest,
-    COMPOSITOR_PORT, MAX_MSG_SIZE,
+    clipboard_flags, create_flags, dnd_actions, dnd_events, error_codes, lifecycle_events, opcodes,
+    tiling_states, window_properties, workspace_events, BuffersConfiguredResponse, CaptureRequest,
+    CaptureResponse, ClipboardDataResponse, ClipboardEvent, ClipboardGetRequest,
+    ClipboardQueryTypesRequest, ClipboardSetRequest, ClipboardTypesResponse, CommitBufferRequest,
+    ConfigureBuffersRequest, CreatePopupRequest, CreateWindowRequest, DestroyWindowRequest,
+    DndAcceptRequest, DndDataResponse, DndReceiveRequest, DndSendDataRequest, DndStartRequest,
+    ErrorResponse, GeometryResponse, InjectInputRequest, InteractiveResizeRequest,
+    ListWorkspacesRequest, MoveToWorkspaceRequest, MoveWindowRequest, OutputInfoResponse,
+    PixelValueResponse, ProtocolMessage, QueryGeometryRequest, QueryOutputRequest,
+    QueryPixelRequest, QueryWindowWorkspaceRequest, RegisterTaskbarRequest, ResizeWindowRequest,
+    RoutedEvent, SetCursorRequest, SetFullscreenRequest, SetIconRequest, SetPointerLockRequest,
+    SetPropertyRequest, SetTextInputRequest, SetTilingHintRequest, SetTitleRequest,
+    SetWindowFlagsRequest, SubscribeClipboardRequest, SubscribeWorkspacesRequest,
+    SwitchWorkspaceRequest, ThumbnailRequest, ThumbnailResponse, WindowCreatedResponse,
+    WindowLifecycleEvent, WindowOpRequest, WindowWorkspaceResponse, WorkspaceEntry, WorkspaceEvent,
+    WorkspaceListResponse, CLIPBOARD_INLINE_MAX, CLIPBOARD_TYPES_PER_MSG, COMPOSITOR_PORT,
+    MAX_ICON_SIZE, MAX_MSG_SIZE, MAX_TITLE_LEN, WORKSPACES_PER_MSG,
 };
+pub use secure::create_secure;
+#[cfg(not(feature = "headless"))]
+pub use swapchain::SwapChain;
+pub use tiling::{TiledEdges, TilingState};
@@ -0,0 +1,86 @@
+//! # Popups
+//!
+//! Menus de contexto, dropdowns e tooltips: janelas sem decoração nem
+//! entrada na taskbar, posicionadas pelo compositor relativo à janela pai.
+//!
+//! O compositor coloca o popup logo abaixo de `anchor` (retângulo em
+//! coordenadas da janela pai, ex.: o botão que abriu o menu) e o desloca ou
+//! inverte para cima quando não cabe no monitor.
+//!
+//! Um clique fora do popup, `Esc` ou a perda de foco do pai geram
+//! [`Event::PopupDismissed`](crate::event::Event::PopupDismissed) no popup;
+//! o app deve então destruí-lo. Com [`create_flags::POPUP_NO_DISMISS`] o
+//! popup só fecha quando o app o destrói.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::Event;
+//! use redpowder::window::Window;
+//!
+//! let menu = Window::create_popup(&window, button_rect, Size::new(160, 96))?;
+//! draw_menu(&menu);
+//! menu.present()?;
+//!
+//! for event in menu.poll_events() {
+//!     if let Event::PopupDismissed = event {
+//!         menu.destroy()?;
+//!     }
+//! }
+//! ```
+
+use gfx_types::geometry::{Rect, Size};
+
+use super::error::WindowResult;
+use super::protocol::create_flags;
+use super::Window;
+
+impl Window {
+    /// Cria um popup ancorado em `anchor` (coordenadas de `parent`).
+    pub fn create_popup(parent: &Window, anchor: Rect, size: Size) -> WindowResult<Window> {
+        Self::create_popup_with_flags(parent, anchor, size, 0)
+    }
+
+    /// Como [`create_popup`](Self::create_popup), com `WindowFlags` e
+    /// [`create_flags`] adicionais.
+    pub fn create_popup_with_flags(
+        parent: &Window,
+        anchor: Rect,
+        size: Size,
+        flags: u32,
+    ) -> WindowResult<Window> {
+        create(parent, anchor, size, flags | create_flags::POPUP)
+    }
+}
+
+#[cfg(not(feature = "headless"))]
+fn create(parent: &Window, anchor: Rect, size: Size, flags: u32) -> WindowResult<Window> {
+    use super::protocol::{opcodes, CreatePopupRequest};
+
+    Window::create_with(size.width, size.height, flags, |reply_port| {
+        CreatePopupRequest {
+            op: opcodes::CREATE_POPUP,
+            parent_id: parent.id,
+            anchor_x: anchor.x,
+            anchor_y: anchor.y,
+            anchor_width: anchor.width,
+            anchor_height: anchor.height,
+            width: size.width,
+            height: size.height,
+            flags,
+            reply_port,
+        }
+    })
+}
+
+/// Sem compositor: o popup fica sempre abaixo da âncora.
+#[cfg(feature = "headless")]
+fn create(parent: &Window, anchor: Rect, size: Size, flags: u32) -> WindowResult<Window> {
+    let origin = parent.geometry()?.rect;
+    let popup = Window::create_internal(0, 0, size.width, size.height, flags, "")?;
+    popup.move_to(
+        origin.x + anchor.x,
+        origin.y + anchor.y + anchor.height as i32,
+    )?;
+    Ok(popup)
+}
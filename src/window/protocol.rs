@@ -2,7 +2,10 @@
 //!
 //! Definições de mensagens do protocolo de comunicação com o compositor.
 
-use crate::event::{InputEvent, ResizeEvent};
+use crate::event::{
+    DndEvent, FocusEvent, FrameEvent, GamepadEvent, InputEvent, PointerLockEvent, ResizeEvent,
+    ScaleChangedEvent, StateChangedEvent, TextInputEvent, TilingEvent, TouchEvent,
+};
 
 // =============================================================================
 // CONSTANTES
@@ -31,16 +34,158 @@ pub mod opcodes {
     pub const SET_WINDOW_FLAGS: u32 = 0x08;
     pub const MOVE_WINDOW: u32 = 0x09;
     pub const RESIZE_WINDOW: u32 = 0x0A;
+    pub const QUERY_OUTPUT: u32 = 0x0B;
+    pub const REQUEST_FRAME: u32 = 0x0C;
+    /// Apenas com o compositor em modo de teste.
+    pub const TEST_INJECT_INPUT: u32 = 0x0D;
+    /// Requer a permissão de captura de tela.
+    pub const QUERY_PIXEL: u32 = 0x0E;
+    /// Apenas para o shell (switcher, taskbar).
+    pub const CAPTURE_THUMBNAIL: u32 = 0x0F;
 
     // Server -> Client
     pub const WINDOW_CREATED: u32 = 0x10;
+    pub const OUTPUT_INFO: u32 = 0x11;
+    pub const PIXEL_VALUE: u32 = 0x12;
+    pub const THUMBNAIL: u32 = 0x13;
+    pub const GEOMETRY: u32 = 0x14;
+    pub const BUFFERS_CONFIGURED: u32 = 0x15;
+    pub const CAPTURED: u32 = 0x16;
     pub const EVENT_INPUT: u32 = 0x20;
     pub const EVENT_RESIZE: u32 = 0x21;
     pub const EVENT_WINDOW_LIFECYCLE: u32 = 0x22;
     pub const EVENT_FOCUS: u32 = 0x23;
+    pub const EVENT_FRAME: u32 = 0x24;
+    pub const EVENT_WORKSPACE: u32 = 0x25;
+    pub const EVENT_TILING: u32 = 0x26;
+    pub const EVENT_CLIPBOARD: u32 = 0x27;
+    pub const EVENT_DND: u32 = 0x28;
+    pub const EVENT_STATE_CHANGED: u32 = 0x29;
+    /// Evento de uma janela de [`Connection`](super::super::Connection)
+    /// (ver [`RoutedEvent`](super::RoutedEvent)).
+    pub const EVENT_ROUTED: u32 = 0x2A;
+    pub const EVENT_TEXT_INPUT: u32 = 0x2B;
+    pub const EVENT_SCALE_CHANGED: u32 = 0x2C;
+    pub const EVENT_TOUCH: u32 = 0x2D;
+    pub const EVENT_GAMEPAD: u32 = 0x2E;
+    pub const EVENT_POINTER_LOCK: u32 = 0x2F;
+
+    // Workspaces (Client -> Server)
+    pub const LIST_WORKSPACES: u32 = 0x30;
+    pub const SWITCH_WORKSPACE: u32 = 0x31;
+    pub const MOVE_TO_WORKSPACE: u32 = 0x32;
+    pub const QUERY_WINDOW_WORKSPACE: u32 = 0x33;
+    pub const SUBSCRIBE_WORKSPACES: u32 = 0x34;
+
+    // Workspaces (Server -> Client)
+    pub const WORKSPACE_LIST: u32 = 0x38;
+    pub const WINDOW_WORKSPACE: u32 = 0x39;
+
+    // Tiling (Client -> Server)
+    pub const SET_TILING_HINT: u32 = 0x40;
+    pub const QUERY_GEOMETRY: u32 = 0x41;
+    pub const CONFIGURE_BUFFERS: u32 = 0x42;
+
+    // Propriedades (Client -> Server)
+    pub const SET_TITLE: u32 = 0x43;
+    pub const SET_ICON: u32 = 0x44;
+    pub const SET_PROPERTY: u32 = 0x45;
+
+    // Movimento interativo (Client -> Server)
+    pub const BEGIN_INTERACTIVE_MOVE: u32 = 0x46;
+    pub const BEGIN_INTERACTIVE_RESIZE: u32 = 0x47;
+
+    // Estado (Client -> Server)
+    pub const SET_FULLSCREEN: u32 = 0x48;
+    pub const MAXIMIZE_WINDOW: u32 = 0x49;
+    pub const UNMAXIMIZE_WINDOW: u32 = 0x4A;
+
+    // Entrada de texto (Client -> Server)
+    pub const SET_TEXT_INPUT: u32 = 0x4B;
+
+    // Cursor (Client -> Server)
+    pub const SET_CURSOR: u32 = 0x4C;
+
+    // Shell (Client -> Server)
+    /// Apenas para a taskbar (janela de outro app).
+    pub const FOCUS_WINDOW: u32 = 0x4D;
+    /// Requer a permissão de captura de tela (exceto para a própria janela).
+    pub const CAPTURE: u32 = 0x4E;
+
+    // Ponteiro (Client -> Server)
+    pub const SET_POINTER_LOCK: u32 = 0x4F;
+
+    // Clipboard (Client -> Server)
+    pub const CLIPBOARD_SET: u32 = 0x50;
+    pub const CLIPBOARD_GET: u32 = 0x51;
+    pub const CLIPBOARD_QUERY_TYPES: u32 = 0x52;
+    pub const SUBSCRIBE_CLIPBOARD: u32 = 0x53;
+
+    // Clipboard (Server -> Client)
+    pub const CLIPBOARD_DATA: u32 = 0x58;
+    pub const CLIPBOARD_STORED: u32 = 0x59;
+    pub const CLIPBOARD_TYPES: u32 = 0x5A;
+
+    // Drag-and-drop (Client -> Server)
+    pub const DND_START: u32 = 0x60;
+    pub const DND_ACCEPT: u32 = 0x61;
+    pub const DND_RECEIVE: u32 = 0x62;
+    pub const DND_SEND_DATA: u32 = 0x63;
+
+    // Drag-and-drop (Server -> Client)
+    pub const DND_DATA: u32 = 0x68;
+    pub const DND_DATA_STORED: u32 = 0x69;
+
+    // Popups (Client -> Server)
+    pub const CREATE_POPUP: u32 = 0x70;
     pub const ERROR: u32 = 0xFF;
 }
 
+/// Flags de criação reservadas ao protocolo (bits acima de `WindowFlags`).
+pub mod create_flags {
+    /// Janela segura (bloqueio de tela, senha): excluída de capturas e
+    /// miniaturas; outros apps não recebem input enquanto ela tem foco.
+    pub const SECURE: u32 = 1 << 31;
+    /// Popup (menu, dropdown): sem decoração nem taskbar, posicionado pelo
+    /// compositor relativo à janela pai. Implícito em `CREATE_POPUP`.
+    pub const POPUP: u32 = 1 << 30;
+    /// Popup que não fecha com clique fora (tooltips ficam sem ela).
+    pub const POPUP_NO_DISMISS: u32 = 1 << 29;
+    /// `reply_port` é compartilhada por várias janelas: a resposta de
+    /// criação chega nela e os eventos vêm em `EVENT_ROUTED`.
+    pub const ROUTED: u32 = 1 << 28;
+}
+
+/// Chaves conhecidas de `SET_PROPERTY` (apps podem usar outras).
+pub mod window_properties {
+    /// Identificador do app (agrupamento na taskbar, ícone padrão).
+    pub const APP_ID: &str = "app.id";
+    /// Caminho do documento aberto (menu da janela, proxy icon).
+    pub const DOCUMENT_PATH: &str = "document.path";
+    /// Documento com alterações não salvas (`"1"` ou `"0"`).
+    pub const DOCUMENT_MODIFIED: &str = "document.modified";
+}
+
+/// Tamanho máximo do título (bytes UTF-8).
+pub const MAX_TITLE_LEN: usize = 64;
+
+/// Dimensão máxima do ícone (px, por lado).
+pub const MAX_ICON_SIZE: u32 = 256;
+
+/// Códigos de erro do compositor (`ErrorResponse::code`).
+pub mod error_codes {
+    pub const INVALID_WINDOW: u32 = 1;
+    pub const INVALID_SIZE: u32 = 2;
+    pub const TOO_MANY_WINDOWS: u32 = 3;
+    pub const OUT_OF_SHM: u32 = 4;
+    pub const PERMISSION_DENIED: u32 = 5;
+    pub const INVALID_ARGUMENT: u32 = 6;
+    pub const NOT_SUPPORTED: u32 = 7;
+    pub const NOT_FOUND: u32 = 8;
+    pub const BUSY: u32 = 9;
+    pub const MALFORMED: u32 = 10;
+}
+
 /// Tipos de eventos de lifecycle.
 pub mod lifecycle_events {
     pub const CREATED: u32 = 0;
@@ -49,8 +194,72 @@ pub mod lifecycle_events {
     pub const RESTORED: u32 = 3;
     pub const FOCUSED: u32 = 4;
     pub const UNFOCUSED: u32 = 5;
+    /// Pedido de fechamento (botão fechar, Alt+F4).
+    pub const CLOSE_REQUESTED: u32 = 6;
+    /// Popup fechado pelo compositor (clique fora, Esc, pai perdeu o foco).
+    pub const POPUP_DISMISSED: u32 = 7;
+}
+
+/// Tipos de eventos de workspace.
+pub mod workspace_events {
+    pub const SWITCHED: u32 = 0;
+    pub const ADDED: u32 = 1;
+    pub const REMOVED: u32 = 2;
+    pub const WINDOW_MOVED: u32 = 3;
+}
+
+/// Estados de tiling.
+pub mod tiling_states {
+    pub const NONE: u32 = 0;
+    pub const LEFT_HALF: u32 = 1;
+    pub const RIGHT_HALF: u32 = 2;
+    pub const TOP_LEFT: u32 = 3;
+    pub const TOP_RIGHT: u32 = 4;
+    pub const BOTTOM_LEFT: u32 = 5;
+    pub const BOTTOM_RIGHT: u32 = 6;
+    pub const MAXIMIZED: u32 = 7;
+}
+
+/// Flags de `CLIPBOARD_SET`.
+pub mod clipboard_flags {
+    /// Adiciona uma representação ao conteúdo atual em vez de substituí-lo.
+    pub const APPEND: u32 = 1 << 0;
+}
+
+/// Tipos de eventos de drag-and-drop.
+pub mod dnd_events {
+    /// Ponteiro com arraste entrou na janela (alvo).
+    pub const ENTER: u32 = 0;
+    /// Ponteiro se moveu sobre a janela (alvo).
+    pub const MOTION: u32 = 1;
+    /// Ponteiro saiu da janela ou o arraste foi cancelado (alvo).
+    pub const LEAVE: u32 = 2;
+    /// Soltou sobre a janela após `DND_ACCEPT` (alvo).
+    pub const DROP: u32 = 3;
+    /// O alvo pediu os dados no tipo indicado (origem).
+    pub const SEND: u32 = 4;
+    /// O alvo recebeu os dados (origem).
+    pub const FINISHED: u32 = 5;
+    /// Soltou fora de um alvo ou foi cancelado (origem).
+    pub const CANCELLED: u32 = 6;
+}
+
+/// Ações de drag-and-drop (bits).
+pub mod dnd_actions {
+    pub const COPY: u32 = 1 << 0;
+    pub const MOVE: u32 = 1 << 1;
+    pub const LINK: u32 = 1 << 2;
 }
 
+/// Dados de clipboard enviados na própria mensagem (acima disso, via SHM).
+pub const CLIPBOARD_INLINE_MAX: usize = 128;
+
+/// Tipos MIME por mensagem de clipboard.
+pub const CLIPBOARD_TYPES_PER_MSG: usize = 4;
+
+/// Workspaces por mensagem de listagem.
+pub const WORKSPACES_PER_MSG: usize = 7;
+
 // =============================================================================
 // REQUESTS (Client -> Server)
 // =============================================================================
@@ -98,6 +307,8 @@ pub struct CommitBufferRequest {
     pub y: u32,      // Dirty Rect Y
     pub width: u32,  // Dirty Rect W
     pub height: u32, // Dirty Rect H
+    /// Buffer apresentado (swapchain); 0 no modo de buffer único.
+    pub buffer_index: u32,
 }
 
 /// Request genérico para operações de janela.
@@ -137,6 +348,362 @@ pub struct SetWindowFlagsRequest {
     pub flags: u32,
 }
 
+/// Request de informações do monitor onde a janela está.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct QueryOutputRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// Porta para a resposta (não usa a porta de eventos).
+    pub reply_port: [u8; 32],
+}
+
+/// Request de injeção de input (testes de UI).
+///
+/// O compositor entrega o evento à janela como se viesse do dispositivo;
+/// fora do modo de teste a mensagem é descartada.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct InjectInputRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub event_type: u32,
+    pub param1: u32,
+    pub param2: u32,
+}
+
+/// Request da cor de um pixel da tela composta.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct QueryPixelRequest {
+    pub op: u32,
+    /// Coordenadas globais (desktop).
+    pub x: i32,
+    pub y: i32,
+    /// Porta para a resposta.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de miniatura do conteúdo de uma janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ThumbnailRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// Tamanho máximo; o compositor mantém a proporção.
+    pub max_width: u32,
+    pub max_height: u32,
+    /// Porta para a resposta.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de captura da tela composta ou de uma janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CaptureRequest {
+    pub op: u32,
+    /// Janela a capturar; 0 captura a tela inteira (todos os monitores).
+    pub window_id: u32,
+    /// Porta para a resposta.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de tiling preferido (ver [`tiling_states`]).
+///
+/// É uma sugestão: o compositor confirma com `EVENT_TILING`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetTilingHintRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub state: u32,
+}
+
+/// Request de posição, tamanho e tiling da janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct QueryGeometryRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de buffers da janela (swapchain).
+///
+/// `buffer_count` 0 ou 1 volta ao buffer único criado com a janela.
+/// Com `reply_port` vazio o compositor não responde.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigureBuffersRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub buffer_count: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de redimensionamento interativo pela borda `edge`
+/// (`ResizeEdge as u32`).
+///
+/// Como `BEGIN_INTERACTIVE_MOVE`, só é aceito com um botão do mouse
+/// pressionado sobre a janela; o compositor segue o ponteiro até soltar.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct InteractiveResizeRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub edge: u32,
+}
+
+/// Request de criação de popup.
+///
+/// O compositor coloca o popup abaixo de `anchor` (coordenadas da janela
+/// pai) e o desloca ou inverte para cima se não couber no monitor. A
+/// resposta é um `WINDOW_CREATED` em `reply_port`, como em `CREATE_WINDOW`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CreatePopupRequest {
+    pub op: u32,
+    pub parent_id: u32,
+    pub anchor_x: i32,
+    pub anchor_y: i32,
+    pub anchor_width: u32,
+    pub anchor_height: u32,
+    pub width: u32,
+    pub height: u32,
+    /// `WindowFlags` e [`create_flags`].
+    pub flags: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de fullscreen.
+///
+/// O compositor responde com `EVENT_STATE_CHANGED` (concedido ou não).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetFullscreenRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// 1 para entrar, 0 para sair.
+    pub enabled: u32,
+}
+
+/// Request para habilitar/desabilitar `EVENT_TEXT_INPUT`.
+///
+/// O retângulo do cursor (coordenadas da janela) posiciona a janela de
+/// candidatos do IME e o teclado virtual.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetTextInputRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// 1 = habilitar, 0 = desabilitar.
+    pub enabled: u32,
+    pub cursor_x: i32,
+    pub cursor_y: i32,
+    pub cursor_width: u32,
+    pub cursor_height: u32,
+}
+
+/// Request de troca do cursor sobre a janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetCursorRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// `CursorType` (`CursorType::from_u8`).
+    pub cursor: u32,
+}
+
+/// Request para travar/liberar o ponteiro.
+///
+/// O compositor responde com `EVENT_POINTER_LOCK` (concedido ou não).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetPointerLockRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// 1 = travar, 0 = liberar.
+    pub locked: u32,
+}
+
+/// Request de troca de título.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetTitleRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub title: [u8; MAX_TITLE_LEN],
+}
+
+/// Request de troca de ícone (ARGB8888 na SHM indicada).
+///
+/// A SHM deve continuar válida até o próximo `SET_ICON` ou o fim da janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetIconRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub shm_handle: u64,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Request de propriedade genérica (ver [`window_properties`]).
+///
+/// Valor vazio remove a propriedade.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetPropertyRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub key: [u8; 32],
+    pub value: [u8; 128],
+}
+
+/// Request de listagem de workspaces (paginada).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListWorkspacesRequest {
+    pub op: u32,
+    /// Índice do primeiro workspace da página.
+    pub start: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Request de troca de workspace ativo.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SwitchWorkspaceRequest {
+    pub op: u32,
+    pub workspace: u32,
+}
+
+/// Request para mover uma janela de workspace.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MoveToWorkspaceRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub workspace: u32,
+}
+
+/// Request do workspace de uma janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct QueryWindowWorkspaceRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Registro para eventos de workspace.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeWorkspacesRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+/// Request de escrita no clipboard.
+///
+/// Até [`CLIPBOARD_INLINE_MAX`] bytes vão em `data`; acima disso em uma SHM
+/// (`shm_handle`), que o compositor copia antes de `CLIPBOARD_STORED`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardSetRequest {
+    pub op: u32,
+    /// Ver [`clipboard_flags`].
+    pub flags: u32,
+    pub mime: [u8; 32],
+    pub len: u32,
+    pub _pad: u32,
+    /// SHM com os dados (0 se inline).
+    pub shm_handle: u64,
+    pub reply_port: [u8; 32],
+    pub data: [u8; CLIPBOARD_INLINE_MAX],
+}
+
+/// Request de leitura do clipboard, com tipos MIME em ordem de preferência.
+///
+/// Responde com a primeira representação disponível ou `ERROR`
+/// (`NotFound`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardGetRequest {
+    pub op: u32,
+    /// Entradas usadas em `mimes`.
+    pub count: u32,
+    pub mimes: [[u8; 32]; CLIPBOARD_TYPES_PER_MSG],
+    pub reply_port: [u8; 32],
+}
+
+/// Request dos tipos MIME disponíveis no clipboard.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardQueryTypesRequest {
+    pub op: u32,
+    pub reply_port: [u8; 32],
+}
+
+/// Registro para eventos de mudança do clipboard.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscribeClipboardRequest {
+    pub op: u32,
+    pub listener_port: [u8; 32],
+}
+
+/// Início de arraste com os tipos oferecidos.
+///
+/// Só é aceito com um botão do mouse pressionado sobre a janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndStartRequest {
+    pub op: u32,
+    pub window_id: u32,
+    /// Ver [`dnd_actions`].
+    pub actions: u32,
+    pub mime_count: u32,
+    pub mimes: [[u8; 32]; CLIPBOARD_TYPES_PER_MSG],
+}
+
+/// Resposta do alvo a `ENTER`/`MOTION`: tipo e ação que aceitaria.
+///
+/// `mime` vazio recusa o drop nesta posição.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndAcceptRequest {
+    pub op: u32,
+    pub window_id: u32,
+    pub serial: u32,
+    pub action: u32,
+    pub mime: [u8; 32],
+}
+
+/// Pedido dos dados soltos (alvo, após `DROP`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndReceiveRequest {
+    pub op: u32,
+    pub serial: u32,
+    pub mime: [u8; 32],
+    pub reply_port: [u8; 32],
+}
+
+/// Dados do arraste em SHM (origem, após `SEND`).
+///
+/// O compositor repassa a SHM ao alvo e responde `DND_DATA_STORED`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndSendDataRequest {
+    pub op: u32,
+    pub serial: u32,
+    pub len: u32,
+    pub _pad: u32,
+    pub shm_handle: u64,
+    pub reply_port: [u8; 32],
+}
+
 // =============================================================================
 // RESPONSES (Server -> Client)
 // =============================================================================
@@ -149,6 +716,117 @@ pub struct WindowCreatedResponse {
     pub window_id: u32,
     pub shm_handle: u64,
     pub buffer_size: u64,
+    /// Escala do monitor inicial em milésimos (`graphics::ScaleFactor`);
+    /// 0 em compositores sem suporte (1x).
+    pub scale_milli: u32,
+    pub _pad: u32,
+}
+
+/// Monitor onde a janela está (maior área visível).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OutputInfoResponse {
+    pub op: u32,
+    pub output_id: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Taxa de atualização em milihertz (ex: 60000 = 60Hz).
+    pub refresh_rate_mhz: u32,
+}
+
+/// Cor de um pixel da tela composta.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PixelValueResponse {
+    pub op: u32,
+    /// ARGB (0xAARRGGBB).
+    pub color: u32,
+}
+
+/// Miniatura pronta na SHM indicada.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ThumbnailResponse {
+    pub op: u32,
+    pub window_id: u32,
+    pub shm_handle: u64,
+    pub width: u32,
+    pub height: u32,
+    /// Bytes por linha.
+    pub stride: u32,
+}
+
+/// Captura pronta na SHM indicada (ARGB8888).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CaptureResponse {
+    pub op: u32,
+    pub window_id: u32,
+    pub shm_handle: u64,
+    pub width: u32,
+    pub height: u32,
+    /// Bytes por linha.
+    pub stride: u32,
+}
+
+/// Geometria da janela.
+///
+/// Com a janela encaixada, o retângulo é o de antes do tiling.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GeometryResponse {
+    pub op: u32,
+    pub window_id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    /// Estado de tiling (ver [`tiling_states`]).
+    pub tiling: u32,
+}
+
+/// Buffers da swapchain alocados em uma SHM contígua.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct BuffersConfiguredResponse {
+    pub op: u32,
+    pub window_id: u32,
+    pub shm_handle: u64,
+    /// Tamanho de cada buffer (bytes).
+    pub buffer_size: u64,
+    pub buffer_count: u32,
+}
+
+/// Entrada da listagem de workspaces.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WorkspaceEntry {
+    pub index: u32,
+    pub window_count: u32,
+    pub name: [u8; 24],
+}
+
+/// Página da listagem de workspaces.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WorkspaceListResponse {
+    pub op: u32,
+    /// Total de workspaces.
+    pub total: u32,
+    /// Entradas válidas nesta página.
+    pub count: u32,
+    /// Índice do workspace ativo.
+    pub active: u32,
+    pub entries: [WorkspaceEntry; WORKSPACES_PER_MSG],
+}
+
+/// Workspace de uma janela.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WindowWorkspaceResponse {
+    pub op: u32,
+    pub window_id: u32,
+    pub workspace: u32,
 }
 
 /// Response de erro.
@@ -156,7 +834,10 @@ pub struct WindowCreatedResponse {
 #[derive(Clone, Copy, Debug)]
 pub struct ErrorResponse {
     pub op: u32,
+    /// Motivo ([`error_codes`]).
     pub code: u32,
+    /// Opcode do request recusado (0 em compositores antigos).
+    pub request_op: u32,
 }
 
 /// Evento de lifecycle de janela.
@@ -169,15 +850,81 @@ pub struct WindowLifecycleEvent {
     pub title: [u8; 64],
 }
 
+/// Evento entregue numa porta compartilhada ([`create_flags::ROUTED`]).
+///
+/// `event` é a mensagem original (`EVENT_INPUT`, `EVENT_RESIZE`...), que
+/// nem sempre identifica a janela por conta própria.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RoutedEvent {
+    pub op: u32,
+    pub window_id: u32,
+    pub event: [u8; MAX_MSG_SIZE - 8],
+}
+
+/// Evento de workspace (ver [`workspace_events`]).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WorkspaceEvent {
+    pub op: u32,
+    pub event_type: u32,
+    pub workspace: u32,
+    /// Janela movida (`WINDOW_MOVED`), senão 0.
+    pub window_id: u32,
+}
+
+/// Conteúdo do clipboard (inline ou em SHM, como em `ClipboardSetRequest`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardDataResponse {
+    pub op: u32,
+    pub len: u32,
+    /// SHM com os dados (0 se inline).
+    pub shm_handle: u64,
+    pub mime: [u8; 32],
+    pub data: [u8; CLIPBOARD_INLINE_MAX],
+}
+
+/// Tipos MIME disponíveis (os primeiros [`CLIPBOARD_TYPES_PER_MSG`]).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardTypesResponse {
+    pub op: u32,
+    pub count: u32,
+    pub mimes: [[u8; 32]; CLIPBOARD_TYPES_PER_MSG],
+}
+
+/// Dados de um drop (resposta a `DND_RECEIVE`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DndDataResponse {
+    pub op: u32,
+    pub serial: u32,
+    pub len: u32,
+    pub _pad: u32,
+    pub shm_handle: u64,
+}
+
+/// Conteúdo do clipboard mudou.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClipboardEvent {
+    pub op: u32,
+    /// Contador de mudanças (cresce a cada escrita).
+    pub serial: u32,
+}
+
 // =============================================================================
 // PROTOCOL MESSAGE UNION
 // =============================================================================
 
 /// União de todas as mensagens possíveis (para leitura genérica).
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub union ProtocolMessage {
     pub header: u32,
     pub create_req: CreateWindowRequest,
+    pub popup_req: CreatePopupRequest,
     pub buf_req: CommitBufferRequest,
     pub destroy_req: DestroyWindowRequest,
     pub op_req: WindowOpRequest,
@@ -189,5 +936,135 @@ pub union ProtocolMessage {
     pub input_evt: InputEvent,
     pub resize_evt: ResizeEvent,
     pub lifecycle_evt: WindowLifecycleEvent,
+    pub routed_evt: RoutedEvent,
+    pub output_resp: OutputInfoResponse,
+    pub pixel_resp: PixelValueResponse,
+    pub thumb_resp: ThumbnailResponse,
+    pub capture_resp: CaptureResponse,
+    pub geometry_resp: GeometryResponse,
+    pub buffers_resp: BuffersConfiguredResponse,
+    pub workspace_list: WorkspaceListResponse,
+    pub window_workspace: WindowWorkspaceResponse,
+    pub workspace_evt: WorkspaceEvent,
+    pub frame_evt: FrameEvent,
+    pub tiling_evt: TilingEvent,
+    pub state_evt: StateChangedEvent,
+    pub scale_evt: ScaleChangedEvent,
+    pub focus_evt: FocusEvent,
+    pub text_evt: TextInputEvent,
+    pub touch_evt: TouchEvent,
+    pub gamepad_evt: GamepadEvent,
+    pub pointer_lock_evt: PointerLockEvent,
+    pub clip_data: ClipboardDataResponse,
+    pub clip_types: ClipboardTypesResponse,
+    pub clipboard_evt: ClipboardEvent,
+    pub dnd_data: DndDataResponse,
+    pub dnd_evt: DndEvent,
+    pub error: ErrorResponse,
     pub raw: [u8; MAX_MSG_SIZE],
 }
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for ProtocolMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    /// O `ERROR` pode vir sem `request_op`.
+    const MIN_LEN: usize = core::mem::offset_of!(ErrorResponse, request_op);
+
+    fn error(&self) -> crate::syscall::SysError {
+        super::error::WindowError::from_response(unsafe { &self.error }).into()
+    }
+}
+
+// =============================================================================
+// VERIFICAÇÕES DE TAMANHO
+// =============================================================================
+
+/// Falha a compilação se alguma mensagem não couber em [`MAX_MSG_SIZE`].
+macro_rules! assert_fits {
+    ($($msg:ty),* $(,)?) => {
+        $(const _: () = assert!(
+            core::mem::size_of::<$msg>() <= MAX_MSG_SIZE,
+            concat!(stringify!($msg), " excede MAX_MSG_SIZE"),
+        );)*
+    };
+}
+
+assert_fits!(
+    CreateWindowRequest,
+    RegisterTaskbarRequest,
+    DestroyWindowRequest,
+    CommitBufferRequest,
+    WindowOpRequest,
+    MoveWindowRequest,
+    ResizeWindowRequest,
+    SetWindowFlagsRequest,
+    QueryOutputRequest,
+    InjectInputRequest,
+    QueryPixelRequest,
+    ThumbnailRequest,
+    CaptureRequest,
+    SetTilingHintRequest,
+    QueryGeometryRequest,
+    ConfigureBuffersRequest,
+    InteractiveResizeRequest,
+    CreatePopupRequest,
+    SetFullscreenRequest,
+    SetTextInputRequest,
+    SetCursorRequest,
+    SetPointerLockRequest,
+    SetTitleRequest,
+    SetIconRequest,
+    SetPropertyRequest,
+    ListWorkspacesRequest,
+    SwitchWorkspaceRequest,
+    MoveToWorkspaceRequest,
+    QueryWindowWorkspaceRequest,
+    SubscribeWorkspacesRequest,
+    ClipboardSetRequest,
+    ClipboardGetRequest,
+    ClipboardQueryTypesRequest,
+    SubscribeClipboardRequest,
+    DndStartRequest,
+    DndAcceptRequest,
+    DndReceiveRequest,
+    DndSendDataRequest,
+    WindowCreatedResponse,
+    OutputInfoResponse,
+    PixelValueResponse,
+    ThumbnailResponse,
+    CaptureResponse,
+    GeometryResponse,
+    BuffersConfiguredResponse,
+    WorkspaceListResponse,
+    WindowWorkspaceResponse,
+    ErrorResponse,
+    WindowLifecycleEvent,
+    RoutedEvent,
+    WorkspaceEvent,
+    ClipboardDataResponse,
+    ClipboardTypesResponse,
+    DndDataResponse,
+    ClipboardEvent,
+    InputEvent,
+    ResizeEvent,
+    FrameEvent,
+    TilingEvent,
+    FocusEvent,
+    TextInputEvent,
+    ScaleChangedEvent,
+    TouchEvent,
+    GamepadEvent,
+    PointerLockEvent,
+    StateChangedEvent,
+    DndEvent,
+);
+
+// O union é recebido com `raw`: nenhum membro pode aumentá-lo
+const _: () = assert!(core::mem::size_of::<ProtocolMessage>() == MAX_MSG_SIZE);
+
+// Eventos também viajam dentro de `RoutedEvent`
+const _: () = assert!(
+    core::mem::size_of::<TouchEvent>() <= MAX_MSG_SIZE - 8,
+    "TouchEvent excede RoutedEvent::event",
+);
@@ -0,0 +1,43 @@
+//! # Secure Windows
+//!
+//! Janelas seguras para bloqueio de tela e diálogos de senha.
+//!
+//! Enquanto existir, uma janela segura:
+//!
+//! - não aparece em capturas ([`capture`](super::capture)), miniaturas
+//!   ([`shell`](super::shell)) nem gravações de tela;
+//! - com foco, recebe todo o input: outros apps não podem capturar teclado
+//!   ou mouse (grabs) nem injetar eventos.
+//!
+//! O compositor só concede o flag a processos autorizados (tela de
+//! bloqueio, agente de senhas); os demais recebem `PermissionDenied`.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::sys::session::{self, SessionEvent};
+//! use redpowder::window;
+//!
+//! let events = session::events()?;
+//! while let Some(event) = events.wait(u64::MAX) {
+//!     if event == SessionEvent::Locked {
+//!         let lock = window::create_secure(0, 0, 1920, 1080, "Bloqueio")?;
+//!         run_lock_screen(&lock)?; // chama session::unlock() após a senha
+//!     }
+//! }
+//! ```
+
+use gfx_types::window::WindowFlags;
+
+use super::error::WindowResult;
+use super::protocol::create_flags;
+use super::Window;
+
+/// Cria uma janela segura sem bordas e sempre no topo.
+pub fn create_secure(x: u32, y: u32, width: u32, height: u32, title: &str) -> WindowResult<Window> {
+    let flags = WindowFlags::BORDERLESS
+        .with(WindowFlags::ALWAYS_ON_TOP)
+        .with(WindowFlags::SKIP_TASKBAR)
+        .bits();
+    Window::create_internal(x, y, width, height, flags | create_flags::SECURE, title)
+}
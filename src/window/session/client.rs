@@ -0,0 +1,177 @@
+//! # Session Client
+//!
+//! Geometria de janelas persistida por chave (ex: nome do app).
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::Rect;
+//! use redpowder::window::session;
+//!
+//! // Reabre onde estava (ou em 100,100 800x600 na primeira execução)
+//! let window = session::create_window("editor", Rect::new(100, 100, 800, 600), "Editor")?;
+//!
+//! // ... ao fechar
+//! session::save_geometry(&window, "editor")?;
+//! ```
+
+use gfx_types::geometry::Rect;
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::super::tiling::TilingState;
+use super::super::Window;
+use super::protocol::*;
+
+/// Tempo máximo de espera por respostas do serviço (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Prefixo das chaves no serviço de configurações.
+const KEY_PREFIX: &[u8] = b"window.geometry.";
+
+/// Versão do formato salvo.
+const GEOMETRY_VERSION: u32 = 1;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Posição, tamanho e tiling de uma janela.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowGeometry {
+    /// Retângulo flutuante (com tiling, o de antes do encaixe).
+    pub rect: Rect,
+    pub tiling: TilingState,
+}
+
+/// Formato persistido.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SavedGeometry {
+    version: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    tiling: u32,
+}
+
+// =============================================================================
+// API
+// =============================================================================
+
+/// Salva a geometria atual de `window` sob `key`.
+pub fn save_geometry(window: &Window, key: &str) -> SysResult<()> {
+    let geometry = window.geometry()?;
+    let saved = SavedGeometry {
+        version: GEOMETRY_VERSION,
+        x: geometry.rect.x,
+        y: geometry.rect.y,
+        width: geometry.rect.width,
+        height: geometry.rect.height,
+        tiling: geometry.tiling.as_u32(),
+    };
+    let bytes = as_bytes(&saved);
+
+    let (reply_port, reply_name) = Port::create_unique("settings.r.", 4)?;
+    let mut req = SetValueRequest {
+        op: opcodes::SET_VALUE,
+        key: settings_key(key)?,
+        len: bytes.len() as u32,
+        value: [0; MAX_VALUE_LEN],
+        reply_port: reply_name,
+    };
+    req.value[..bytes.len()].copy_from_slice(bytes);
+    Port::connect(SETTINGS_PORT)?.send(as_bytes(&req), 0)?;
+
+    recv_reply::<SettingsMessage>(&reply_port, opcodes::STORED, 0, REPLY_TIMEOUT_MS)?;
+    Ok(())
+}
+
+/// Geometria salva sob `key` (None se nunca foi salva).
+pub fn restore_geometry(key: &str) -> SysResult<Option<WindowGeometry>> {
+    let (reply_port, reply_name) = Port::create_unique("settings.r.", 4)?;
+    let req = GetValueRequest {
+        op: opcodes::GET_VALUE,
+        key: settings_key(key)?,
+        reply_port: reply_name,
+    };
+    Port::connect(SETTINGS_PORT)?.send(as_bytes(&req), 0)?;
+
+    let msg = match recv_reply::<SettingsMessage>(
+        &reply_port,
+        opcodes::VALUE,
+        core::mem::size_of::<ValueResponse>(),
+        REPLY_TIMEOUT_MS,
+    ) {
+        Ok(msg) => msg,
+        Err(SysError::NotFound) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let value = unsafe { msg.value };
+
+    let mut saved = SavedGeometry {
+        version: 0,
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+        tiling: 0,
+    };
+    let out = as_bytes_mut(&mut saved);
+    if value.len as usize != out.len() {
+        return Ok(None);
+    }
+    out.copy_from_slice(&value.value[..out.len()]);
+    if saved.version != GEOMETRY_VERSION || saved.width == 0 || saved.height == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(WindowGeometry {
+        rect: Rect::new(saved.x, saved.y, saved.width, saved.height),
+        tiling: TilingState::from_u32(saved.tiling),
+    }))
+}
+
+/// Cria uma janela com a geometria salva sob `key`, ou `default` se não
+/// houver (ou o serviço de configurações estiver indisponível).
+///
+/// O tiling salvo é reaplicado como hint.
+pub fn create_window(key: &str, default: Rect, title: &str) -> SysResult<Window> {
+    let geometry = restore_geometry(key)
+        .ok()
+        .flatten()
+        .unwrap_or(WindowGeometry {
+            rect: default,
+            tiling: TilingState::None,
+        });
+    let rect = geometry.rect;
+
+    let window = Window::create(
+        rect.x.max(0) as u32,
+        rect.y.max(0) as u32,
+        rect.width,
+        rect.height,
+        title,
+    )?;
+    if geometry.tiling.is_tiled() {
+        window.set_tiling_hint(geometry.tiling)?;
+    }
+    Ok(window)
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Chave completa (`window.geometry.<key>`).
+fn settings_key(key: &str) -> SysResult<[u8; MAX_KEY_LEN]> {
+    if key.is_empty() || KEY_PREFIX.len() + key.len() > MAX_KEY_LEN {
+        return Err(SysError::InvalidArgument);
+    }
+    let mut buf = [0u8; MAX_KEY_LEN];
+    buf[..KEY_PREFIX.len()].copy_from_slice(KEY_PREFIX);
+    buf[KEY_PREFIX.len()..KEY_PREFIX.len() + key.len()].copy_from_slice(key.as_bytes());
+    Ok(buf)
+}
@@ -0,0 +1,21 @@
+//! # Session
+//!
+//! Salva e restaura a geometria de janelas (posição, tamanho e tiling) no
+//! serviço de configurações, para que apps reabram onde o usuário deixou.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`protocol`] | Mensagens do serviço de configurações usadas aqui |
+//! | [`client`] | Salvar, restaurar e criar janela restaurada |
+
+pub mod client;
+pub mod protocol;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use client::{create_window, restore_geometry, save_geometry, WindowGeometry};
+pub use protocol::SETTINGS_PORT;
@@ -0,0 +1,103 @@
+//! # Protocolo de Configurações
+//!
+//! Subconjunto do protocolo do serviço de configurações: leitura e escrita
+//! de valores binários por chave.
+
+// =============================================================================
+// CONSTANTES
+// =============================================================================
+
+/// Nome da porta do serviço de configurações.
+pub const SETTINGS_PORT: &str = "settings.server";
+
+/// Tamanho máximo de mensagem.
+pub const MAX_MSG_SIZE: usize = 256;
+
+/// Tamanho máximo de uma chave (bytes).
+pub const MAX_KEY_LEN: usize = 64;
+
+/// Tamanho máximo de um valor (bytes).
+pub const MAX_VALUE_LEN: usize = 128;
+
+// =============================================================================
+// OPCODES
+// =============================================================================
+
+/// Identificadores de mensagem (OpCodes).
+pub mod opcodes {
+    // Client -> Server
+    pub const GET_VALUE: u32 = 0x01;
+    pub const SET_VALUE: u32 = 0x02;
+
+    // Server -> Client
+    pub const VALUE: u32 = 0x10;
+    pub const STORED: u32 = 0x11;
+    pub const ERROR: u32 = 0xFF;
+}
+
+// =============================================================================
+// REQUESTS (Client -> Server)
+// =============================================================================
+
+/// Request de leitura de um valor.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GetValueRequest {
+    pub op: u32,
+    pub key: [u8; MAX_KEY_LEN],
+    /// Nome da porta onde o servidor deve responder.
+    pub reply_port: [u8; 32],
+}
+
+/// Request de escrita de um valor (persistido pelo serviço).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SetValueRequest {
+    pub op: u32,
+    pub key: [u8; MAX_KEY_LEN],
+    pub len: u32,
+    pub value: [u8; MAX_VALUE_LEN],
+    pub reply_port: [u8; 32],
+}
+
+// =============================================================================
+// RESPONSES (Server -> Client)
+// =============================================================================
+
+/// Valor lido (chave inexistente responde `ERROR` com `NotFound`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ValueResponse {
+    pub op: u32,
+    pub len: u32,
+    pub value: [u8; MAX_VALUE_LEN],
+}
+
+/// Response de erro.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorResponse {
+    pub op: u32,
+    pub code: u32,
+}
+
+// =============================================================================
+// MESSAGE UNION
+// =============================================================================
+
+/// União das mensagens recebidas pelo cliente.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SettingsMessage {
+    pub header: u32,
+    pub value: ValueResponse,
+    pub error: ErrorResponse,
+    pub raw: [u8; MAX_MSG_SIZE],
+}
+
+// SAFETY: union `#[repr(C)]` de structs `Copy` que começam pelo opcode;
+// `ErrorResponse` traz o código logo após ele.
+unsafe impl crate::ipc::Message for SettingsMessage {
+    const ERROR: u32 = opcodes::ERROR;
+    const MIN_LEN: usize = core::mem::size_of::<ErrorResponse>();
+}
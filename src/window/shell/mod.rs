@@ -0,0 +1,28 @@
+//! # Shell
+//!
+//! Requisições do shell ao compositor: miniaturas de janelas para o
+//! switcher (Alt-Tab) e previews da taskbar, ciclo de vida das janelas
+//! (dock) e workspaces (desktops virtuais).
+//!
+//! Miniaturas e ações da taskbar só são atendidas para o shell; outros
+//! apps recebem `SysError::PermissionDenied`. Workspaces podem ser
+//! consultados por qualquer app.
+//!
+//! ## Submódulos
+//!
+//! | Módulo | Descrição |
+//! |--------|-----------|
+//! | [`taskbar`] | Eventos de ciclo de vida e ações sobre outras janelas |
+//! | `thumbnail` | Miniatura do conteúdo de uma janela |
+//! | [`workspaces`] | Listagem, troca e eventos de workspaces |
+
+pub mod taskbar;
+mod thumbnail;
+pub mod workspaces;
+
+// =============================================================================
+// EXPORTS DO MÓDULO
+// =============================================================================
+
+pub use taskbar::{LifecycleEvent, LifecycleKind, TaskbarClient};
+pub use thumbnail::thumbnail;
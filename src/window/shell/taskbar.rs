@@ -0,0 +1,177 @@
+//! # Taskbar
+//!
+//! Cliente de taskbar/dock: recebe o ciclo de vida de todas as janelas e
+//! pede ao compositor para minimizar, restaurar ou focar janelas de outros
+//! apps.
+//!
+//! Só o shell é aceito como taskbar; outros apps recebem
+//! `SysError::PermissionDenied` nas ações.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::shell::taskbar::{LifecycleKind, TaskbarClient};
+//!
+//! let taskbar = TaskbarClient::register()?;
+//! while let Some(event) = taskbar.wait(u64::MAX) {
+//!     match event.kind {
+//!         LifecycleKind::Created => dock.add(event.window_id, event.title()),
+//!         LifecycleKind::Destroyed => dock.remove(event.window_id),
+//!         LifecycleKind::Focused => dock.set_active(event.window_id),
+//!         _ => {}
+//!     }
+//! }
+//!
+//! // Clique num item do dock
+//! taskbar.focus(window_id)?;
+//! ```
+
+use crate::ipc::{as_bytes, as_bytes_mut, str_from_fixed, Port};
+use crate::syscall::SysResult;
+
+use super::super::protocol::*;
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Tipo de evento de ciclo de vida.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleKind {
+    Created,
+    Destroyed,
+    Minimized,
+    Restored,
+    Focused,
+    Unfocused,
+    /// Pedido de fechamento enviado à janela.
+    CloseRequested,
+    /// Tipo não conhecido por esta versão do SDK.
+    Other(u32),
+}
+
+impl LifecycleKind {
+    /// Converte de [`lifecycle_events`].
+    pub fn from_u32(value: u32) -> Self {
+        match value {
+            lifecycle_events::CREATED => Self::Created,
+            lifecycle_events::DESTROYED => Self::Destroyed,
+            lifecycle_events::MINIMIZED => Self::Minimized,
+            lifecycle_events::RESTORED => Self::Restored,
+            lifecycle_events::FOCUSED => Self::Focused,
+            lifecycle_events::UNFOCUSED => Self::Unfocused,
+            lifecycle_events::CLOSE_REQUESTED => Self::CloseRequested,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Evento de ciclo de vida de uma janela.
+#[derive(Clone, Copy)]
+pub struct LifecycleEvent {
+    pub kind: LifecycleKind,
+    pub window_id: u32,
+    title: [u8; 64],
+}
+
+impl LifecycleEvent {
+    /// Título da janela no momento do evento.
+    pub fn title(&self) -> &str {
+        str_from_fixed(&self.title)
+    }
+}
+
+impl core::fmt::Debug for LifecycleEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LifecycleEvent")
+            .field("kind", &self.kind)
+            .field("window_id", &self.window_id)
+            .field("title", &self.title())
+            .finish()
+    }
+}
+
+// =============================================================================
+// CLIENTE
+// =============================================================================
+
+/// Taskbar registrada no compositor.
+pub struct TaskbarClient {
+    port: Port,
+    compositor: Port,
+}
+
+impl TaskbarClient {
+    /// Registra uma porta de eventos como taskbar.
+    ///
+    /// O compositor envia `CREATED` para as janelas já existentes.
+    pub fn register() -> SysResult<Self> {
+        let (port, name) = Port::create_unique("win.tb.", 32)?;
+        let compositor = Port::connect(COMPOSITOR_PORT)?;
+        let req = RegisterTaskbarRequest {
+            op: opcodes::REGISTER_TASKBAR,
+            listener_port: name,
+        };
+        compositor.send(as_bytes(&req), 0)?;
+        Ok(Self { port, compositor })
+    }
+
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<LifecycleEvent> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<LifecycleEvent> {
+        loop {
+            let mut msg = ProtocolMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<WindowLifecycleEvent>() {
+                return None;
+            }
+            // Mensagens de outro tipo: descartar e continuar
+            if unsafe { msg.header } != opcodes::EVENT_WINDOW_LIFECYCLE {
+                continue;
+            }
+            let event = unsafe { msg.lifecycle_evt };
+            return Some(LifecycleEvent {
+                kind: LifecycleKind::from_u32(event.event_type),
+                window_id: event.window_id,
+                title: event.title,
+            });
+        }
+    }
+
+    // =========================================================================
+    // AÇÕES
+    // =========================================================================
+
+    /// Minimiza a janela `window_id`.
+    pub fn minimize(&self, window_id: u32) -> SysResult<()> {
+        self.send_op(opcodes::MINIMIZE_WINDOW, window_id)
+    }
+
+    /// Restaura a janela `window_id`.
+    pub fn restore(&self, window_id: u32) -> SysResult<()> {
+        self.send_op(opcodes::RESTORE_WINDOW, window_id)
+    }
+
+    /// Traz a janela `window_id` para frente e dá foco a ela (restaurando
+    /// se estiver minimizada).
+    pub fn focus(&self, window_id: u32) -> SysResult<()> {
+        self.send_op(opcodes::FOCUS_WINDOW, window_id)
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+
+    fn send_op(&self, op: u32, window_id: u32) -> SysResult<()> {
+        let req = WindowOpRequest { op, window_id };
+        self.compositor.send(as_bytes(&req), 0)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+//! # Thumbnail
+//!
+//! Miniatura do conteúdo atual de uma janela.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::graphics::Size;
+//! use redpowder::window::shell;
+//!
+//! let preview = shell::thumbnail(window_id, Size::new(240, 160))?;
+//! canvas.draw_surface(&preview, slot.origin(), 255);
+//! ```
+
+use gfx_types::geometry::Size;
+
+use crate::graphics::Surface;
+use crate::ipc::{as_bytes, as_bytes_mut, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::super::capture::read_shm_surface;
+use super::super::error::WindowError;
+use super::super::protocol::*;
+
+/// Tempo máximo de espera pela miniatura (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+/// Miniatura de `window_id` cabendo em `max_size` (proporção mantida).
+///
+/// Janelas minimizadas retornam o último conteúdo apresentado.
+pub fn thumbnail(window_id: u32, max_size: Size) -> SysResult<Surface> {
+    if max_size.width == 0 || max_size.height == 0 {
+        return Err(SysError::InvalidArgument);
+    }
+
+    let (reply_port, reply_name) = Port::create_unique("win.th.", 4)?;
+    let req = ThumbnailRequest {
+        op: opcodes::CAPTURE_THUMBNAIL,
+        window_id,
+        max_width: max_size.width,
+        max_height: max_size.height,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let mut msg = ProtocolMessage {
+        raw: [0; MAX_MSG_SIZE],
+    };
+    if reply_port.recv(as_bytes_mut(&mut msg), REPLY_TIMEOUT_MS)? == 0 {
+        return Err(SysError::Timeout);
+    }
+    match unsafe { msg.header } {
+        opcodes::THUMBNAIL => {
+            let resp = unsafe { msg.thumb_resp };
+            read_shm_surface(resp.shm_handle, resp.width, resp.height, resp.stride)
+        }
+        opcodes::ERROR => Err(WindowError::from_response(unsafe { &msg.error }).into()),
+        _ => Err(SysError::ProtocolError),
+    }
+}
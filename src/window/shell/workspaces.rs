@@ -0,0 +1,250 @@
+//! # Workspaces
+//!
+//! Desktops virtuais: listagem, troca do workspace ativo, movimentação de
+//! janelas e eventos de mudança.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::shell::workspaces::{self, WorkspaceChange};
+//!
+//! for ws in workspaces::list()? {
+//!     println!("{} {} ({} janelas)", ws.index(), ws.name(), ws.window_count());
+//! }
+//! workspaces::move_window(window.id, 1)?;
+//! workspaces::switch(1)?;
+//!
+//! let events = workspaces::events()?;
+//! while let Some(change) = events.wait(1000) {
+//!     if let WorkspaceChange::Switched(idx) = change {
+//!         taskbar.set_active(idx);
+//!     }
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::ipc::{as_bytes, as_bytes_mut, recv_reply, str_from_fixed, Port};
+use crate::syscall::{SysError, SysResult};
+
+use super::super::protocol::*;
+
+/// Tempo máximo de espera por respostas do compositor (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// TIPOS
+// =============================================================================
+
+/// Workspace (desktop virtual).
+#[derive(Clone, Copy)]
+pub struct Workspace {
+    entry: WorkspaceEntry,
+    active: bool,
+}
+
+impl Workspace {
+    /// Índice (0 = primeiro).
+    pub fn index(&self) -> u32 {
+        self.entry.index
+    }
+
+    /// Nome exibido (pode ser vazio).
+    pub fn name(&self) -> &str {
+        str_from_fixed(&self.entry.name)
+    }
+
+    /// Janelas neste workspace.
+    pub fn window_count(&self) -> u32 {
+        self.entry.window_count
+    }
+
+    /// Workspace ativo?
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl core::fmt::Debug for Workspace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Workspace")
+            .field("index", &self.index())
+            .field("name", &self.name())
+            .field("window_count", &self.window_count())
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+// =============================================================================
+// CONSULTAS
+// =============================================================================
+
+/// Workspaces existentes, em ordem.
+pub fn list() -> SysResult<Vec<Workspace>> {
+    let (reply_port, reply_name) = Port::create_unique("win.ws.", 4)?;
+    let compositor = Port::connect(COMPOSITOR_PORT)?;
+
+    let mut workspaces = Vec::new();
+    loop {
+        let req = ListWorkspacesRequest {
+            op: opcodes::LIST_WORKSPACES,
+            start: workspaces.len() as u32,
+            reply_port: reply_name,
+        };
+        compositor.send(as_bytes(&req), 0)?;
+
+        let resp = recv_reply::<ProtocolMessage>(
+            &reply_port,
+            opcodes::WORKSPACE_LIST,
+            core::mem::size_of::<WorkspaceListResponse>(),
+            REPLY_TIMEOUT_MS,
+        )?;
+        let page = unsafe { resp.workspace_list };
+
+        let count = (page.count as usize).min(WORKSPACES_PER_MSG);
+        workspaces.extend(page.entries[..count].iter().map(|&entry| Workspace {
+            entry,
+            active: entry.index == page.active,
+        }));
+        if count == 0 || workspaces.len() >= page.total as usize {
+            return Ok(workspaces);
+        }
+    }
+}
+
+/// Índice do workspace ativo.
+pub fn active() -> SysResult<u32> {
+    list()?
+        .iter()
+        .find(|ws| ws.is_active())
+        .map(Workspace::index)
+        .ok_or(SysError::ProtocolError)
+}
+
+/// Workspace onde a janela `window_id` está.
+pub fn of_window(window_id: u32) -> SysResult<u32> {
+    let (reply_port, reply_name) = Port::create_unique("win.ws.", 4)?;
+    let req = QueryWindowWorkspaceRequest {
+        op: opcodes::QUERY_WINDOW_WORKSPACE,
+        window_id,
+        reply_port: reply_name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+
+    let resp = recv_reply::<ProtocolMessage>(
+        &reply_port,
+        opcodes::WINDOW_WORKSPACE,
+        core::mem::size_of::<WindowWorkspaceResponse>(),
+        REPLY_TIMEOUT_MS,
+    )?;
+    Ok(unsafe { resp.window_workspace }.workspace)
+}
+
+// =============================================================================
+// AÇÕES
+// =============================================================================
+
+/// Torna `index` o workspace ativo.
+///
+/// Índices inexistentes são ignorados pelo compositor.
+pub fn switch(index: u32) -> SysResult<()> {
+    let req = SwitchWorkspaceRequest {
+        op: opcodes::SWITCH_WORKSPACE,
+        workspace: index,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+/// Move a janela `window_id` para o workspace `index`.
+pub fn move_window(window_id: u32, index: u32) -> SysResult<()> {
+    let req = MoveToWorkspaceRequest {
+        op: opcodes::MOVE_TO_WORKSPACE,
+        window_id,
+        workspace: index,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(())
+}
+
+// =============================================================================
+// EVENTOS
+// =============================================================================
+
+/// Mudança de workspaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceChange {
+    /// Novo workspace ativo.
+    Switched(u32),
+    /// Workspace criado.
+    Added(u32),
+    /// Workspace removido (janelas vão para o anterior).
+    Removed(u32),
+    /// Janela movida para o workspace.
+    WindowMoved { window_id: u32, workspace: u32 },
+}
+
+/// Receptor de eventos de workspace.
+pub struct WorkspaceListener {
+    port: Port,
+}
+
+/// Registra um receptor de eventos de workspace.
+pub fn events() -> SysResult<WorkspaceListener> {
+    let (port, name) = Port::create_unique("win.wsev.", 16)?;
+    let req = SubscribeWorkspacesRequest {
+        op: opcodes::SUBSCRIBE_WORKSPACES,
+        listener_port: name,
+    };
+    Port::connect(COMPOSITOR_PORT)?.send(as_bytes(&req), 0)?;
+    Ok(WorkspaceListener { port })
+}
+
+impl WorkspaceListener {
+    /// Retorna o próximo evento pendente, sem bloquear.
+    pub fn poll(&self) -> Option<WorkspaceChange> {
+        self.wait(0)
+    }
+
+    /// Espera um evento por até `timeout_ms`.
+    pub fn wait(&self, timeout_ms: u64) -> Option<WorkspaceChange> {
+        loop {
+            let mut msg = ProtocolMessage {
+                raw: [0; MAX_MSG_SIZE],
+            };
+            let len = self.port.recv(as_bytes_mut(&mut msg), timeout_ms).ok()?;
+            if len < core::mem::size_of::<WorkspaceEvent>() {
+                return None;
+            }
+            if unsafe { msg.header } != opcodes::EVENT_WORKSPACE {
+                continue;
+            }
+            let event = unsafe { msg.workspace_evt };
+            match event.event_type {
+                workspace_events::SWITCHED => {
+                    return Some(WorkspaceChange::Switched(event.workspace))
+                }
+                workspace_events::ADDED => return Some(WorkspaceChange::Added(event.workspace)),
+                workspace_events::REMOVED => {
+                    return Some(WorkspaceChange::Removed(event.workspace))
+                }
+                workspace_events::WINDOW_MOVED => {
+                    return Some(WorkspaceChange::WindowMoved {
+                        window_id: event.window_id,
+                        workspace: event.workspace,
+                    })
+                }
+                // Tipo desconhecido: descartar e continuar
+                _ => {}
+            }
+        }
+    }
+
+    /// Porta de eventos (para uso com `event::poll`).
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+}
@@ -0,0 +1,179 @@
+//! # SwapChain
+//!
+//! Double/triple buffering para janelas.
+//!
+//! Sem swapchain, o app desenha no mesmo buffer que o compositor está
+//! lendo (tearing). A `SwapChain` pede ao compositor 2 ou 3 buffers numa
+//! SHM contígua: o app desenha no buffer de trás e `present` envia o commit
+//! com o índice desse buffer, que passa a ser o da frente de uma vez.
+//!
+//! O buffer de trás após um `present` contém o frame de `buffer_count`
+//! apresentações atrás, não o último; apps que redesenham só o damage
+//! devem acumular o damage desse intervalo ([`SwapChain::buffer_age`]).
+//!
+//! Ao redimensionar a janela, a swapchain deve ser recriada.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::window::{BufferMode, SwapChain, Window};
+//!
+//! let window = Window::create(100, 100, 640, 480, "Demo")?;
+//! let mut swapchain = SwapChain::new(&window, BufferMode::Double)?;
+//! loop {
+//!     let mut canvas = swapchain.canvas();
+//!     draw_scene(&mut canvas);
+//!     swapchain.present()?;
+//! }
+//! ```
+
+use gfx_types::geometry::Rect;
+use gfx_types::window::BufferMode;
+
+use crate::graphics::{Canvas, DamageRegion};
+use crate::ipc::{as_bytes, as_bytes_mut, Port, SharedMemory, ShmId};
+use crate::syscall::{SysError, SysResult};
+
+use super::error::WindowError;
+use super::protocol::*;
+use super::Window;
+
+/// Tempo máximo de espera pela alocação dos buffers (ms).
+const REPLY_TIMEOUT_MS: u64 = 2000;
+
+// =============================================================================
+// SWAPCHAIN
+// =============================================================================
+
+/// Buffers de uma janela apresentados em rodízio.
+pub struct SwapChain<'w> {
+    window: &'w Window,
+    /// SHM com `buffer_count` buffers consecutivos.
+    shm: SharedMemory,
+    /// Pixels por buffer.
+    buffer_len: usize,
+    buffer_count: u32,
+    /// Buffer onde o app desenha.
+    back: u32,
+    /// Apresentações feitas (para `buffer_age`).
+    presents: u64,
+}
+
+impl<'w> SwapChain<'w> {
+    /// Aloca os buffers de `mode` para `window`.
+    pub fn new(window: &'w Window, mode: BufferMode) -> SysResult<Self> {
+        let (reply_port, reply_name) = Port::create_unique("win.sc.", 4)?;
+        let req = ConfigureBuffersRequest {
+            op: opcodes::CONFIGURE_BUFFERS,
+            window_id: window.id,
+            buffer_count: mode.buffer_count() as u32,
+            reply_port: reply_name,
+        };
+        window.compositor_port().send(as_bytes(&req), 0)?;
+
+        let mut msg = ProtocolMessage {
+            raw: [0; MAX_MSG_SIZE],
+        };
+        if reply_port.recv(as_bytes_mut(&mut msg), REPLY_TIMEOUT_MS)? == 0 {
+            return Err(SysError::Timeout);
+        }
+        let resp = match unsafe { msg.header } {
+            opcodes::BUFFERS_CONFIGURED => unsafe { msg.buffers_resp },
+            opcodes::ERROR => return Err(WindowError::from_response(unsafe { &msg.error }).into()),
+            _ => return Err(SysError::ProtocolError),
+        };
+
+        let pixels = window.width() as usize * window.height() as usize;
+        if resp.buffer_count == 0 || (resp.buffer_size as usize) < pixels * 4 {
+            return Err(SysError::ProtocolError);
+        }
+        let shm = SharedMemory::open(ShmId(resp.shm_handle))?;
+        if (shm.size() as u64) < resp.buffer_size * resp.buffer_count as u64 {
+            return Err(SysError::ProtocolError);
+        }
+
+        Ok(Self {
+            window,
+            shm,
+            buffer_len: resp.buffer_size as usize / 4,
+            buffer_count: resp.buffer_count,
+            back: 0,
+            presents: 0,
+        })
+    }
+
+    /// Número de buffers (o compositor pode conceder menos que o pedido).
+    #[inline]
+    pub fn buffer_count(&self) -> u32 {
+        self.buffer_count
+    }
+
+    /// Índice do buffer de trás.
+    #[inline]
+    pub fn back_index(&self) -> u32 {
+        self.back
+    }
+
+    /// Frames desde que o buffer de trás foi apresentado pela última vez
+    /// (0 = conteúdo indefinido, nunca apresentado).
+    pub fn buffer_age(&self) -> u32 {
+        if self.presents < self.buffer_count as u64 {
+            0
+        } else {
+            self.buffer_count
+        }
+    }
+
+    /// Pixels do buffer de trás.
+    pub fn back_buffer(&mut self) -> &mut [u32] {
+        let (len, back) = (self.buffer_len, self.back as usize);
+        let bytes = self.shm.as_mut_slice();
+        let pixels = unsafe {
+            core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut u32, bytes.len() / 4)
+        };
+        &mut pixels[back * len..(back + 1) * len]
+    }
+
+    /// Canvas sobre o buffer de trás.
+    ///
+    /// Se a janela cresceu desde a criação da swapchain, as linhas além do
+    /// buffer ficam de fora até a swapchain ser recriada.
+    pub fn canvas(&mut self) -> Canvas<'_> {
+        let width = self.window.width();
+        let rows = (self.buffer_len / width.max(1) as usize) as u32;
+        let height = self.window.height().min(rows);
+        let pixels = width as usize * height as usize;
+        Canvas::new(&mut self.back_buffer()[..pixels], width, height)
+    }
+
+    /// Apresenta o buffer de trás inteiro e passa ao próximo.
+    pub fn present(&mut self) -> SysResult<()> {
+        self.present_region(self.window.bounds())
+    }
+
+    /// Apresenta o buffer de trás indicando a área alterada.
+    pub fn present_region(&mut self, dirty: Rect) -> SysResult<()> {
+        self.window.commit_buffer(self.back, dirty)?;
+        self.back = (self.back + 1) % self.buffer_count;
+        self.presents += 1;
+        Ok(())
+    }
+
+    /// Apresenta o buffer de trás com o bounding box de `damage`.
+    pub fn present_damage(&mut self, damage: &DamageRegion) -> SysResult<()> {
+        self.present_region(damage.bounds())
+    }
+}
+
+impl Drop for SwapChain<'_> {
+    fn drop(&mut self) {
+        // Voltar ao buffer único da janela
+        let req = ConfigureBuffersRequest {
+            op: opcodes::CONFIGURE_BUFFERS,
+            window_id: self.window.id,
+            buffer_count: 1,
+            reply_port: [0; 32],
+        };
+        let _ = self.window.compositor_port().send(as_bytes(&req), 0);
+    }
+}
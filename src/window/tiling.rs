@@ -0,0 +1,106 @@
+//! # Tiling
+//!
+//! Estados de tiling (janela encaixada em metade ou quadrante do monitor).
+//!
+//! O app sugere um estado com `Window::set_tiling_hint`; quando o compositor
+//! encaixa a janela (pelo hint ou por arrasto até a borda), envia
+//! [`Event::Tiling`](crate::event::Event::Tiling) seguido do resize. Lados
+//! encostados na borda do monitor ([`TilingState::edges`]) podem dispensar
+//! sombra, cantos arredondados e margens.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use redpowder::event::Event;
+//! use redpowder::window::TilingState;
+//!
+//! window.set_tiling_hint(TilingState::LeftHalf)?;
+//!
+//! for event in window.poll_events() {
+//!     if let Event::Tiling(ev) = event {
+//!         let state = TilingState::from_u32(ev.state);
+//!         decorations.set_rounded(!state.is_tiled());
+//!     }
+//! }
+//! ```
+
+use super::protocol::tiling_states;
+
+/// Estado de tiling de uma janela.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TilingState {
+    /// Janela flutuante.
+    #[default]
+    None,
+    LeftHalf,
+    RightHalf,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Maximized,
+}
+
+/// Lados da janela encostados na borda do monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TiledEdges {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl TilingState {
+    /// Converte do valor do protocolo (desconhecido = `None`).
+    pub fn from_u32(value: u32) -> Self {
+        match value {
+            tiling_states::LEFT_HALF => Self::LeftHalf,
+            tiling_states::RIGHT_HALF => Self::RightHalf,
+            tiling_states::TOP_LEFT => Self::TopLeft,
+            tiling_states::TOP_RIGHT => Self::TopRight,
+            tiling_states::BOTTOM_LEFT => Self::BottomLeft,
+            tiling_states::BOTTOM_RIGHT => Self::BottomRight,
+            tiling_states::MAXIMIZED => Self::Maximized,
+            _ => Self::None,
+        }
+    }
+
+    /// Valor do protocolo.
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Self::None => tiling_states::NONE,
+            Self::LeftHalf => tiling_states::LEFT_HALF,
+            Self::RightHalf => tiling_states::RIGHT_HALF,
+            Self::TopLeft => tiling_states::TOP_LEFT,
+            Self::TopRight => tiling_states::TOP_RIGHT,
+            Self::BottomLeft => tiling_states::BOTTOM_LEFT,
+            Self::BottomRight => tiling_states::BOTTOM_RIGHT,
+            Self::Maximized => tiling_states::MAXIMIZED,
+        }
+    }
+
+    /// Janela encaixada (qualquer estado exceto `None`)?
+    pub fn is_tiled(self) -> bool {
+        self != Self::None
+    }
+
+    /// Lados encostados na borda do monitor.
+    pub fn edges(self) -> TiledEdges {
+        let (top, right, bottom, left) = match self {
+            Self::None => (false, false, false, false),
+            Self::LeftHalf => (true, false, true, true),
+            Self::RightHalf => (true, true, true, false),
+            Self::TopLeft => (true, false, false, true),
+            Self::TopRight => (true, true, false, false),
+            Self::BottomLeft => (false, false, true, true),
+            Self::BottomRight => (false, true, true, false),
+            Self::Maximized => (true, true, true, true),
+        };
+        TiledEdges {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}